@@ -2,8 +2,8 @@
 mod test_vectors;
 
 use super::*;
-use crate::ciphertext::{ClearString, GenericPattern};
 use std::time::Duration;
+use tfhe::strings::ciphertext::{ClearString, GenericPattern};
 
 fn result_message<T>(str: &str, expected: T, dec: T, dur: Duration)
 where
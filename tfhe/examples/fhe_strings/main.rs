@@ -1,19 +1,13 @@
-use crate::ciphertext::{FheString, UIntArg};
-use crate::client_key::ClientKey;
-use crate::server_key::{gen_keys, FheStringIsEmpty, FheStringIterator, FheStringLen, ServerKey};
 use clap::{value_parser, Arg, Command};
 use std::time::Instant;
-
-mod ciphertext;
-mod client_key;
-mod server_key;
+use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+use tfhe::shortint::prelude::PARAM_MESSAGE_2_CARRY_2;
+use tfhe::strings::ciphertext::{FheString, UIntArg};
+use tfhe::strings::server_key::{FheStringIsEmpty, FheStringIterator, FheStringLen};
+use tfhe::strings::{ClientKey, ServerKey};
 
 mod assert_functions;
 
-// Used as the const argument for StaticUnsignedBigInt, specifying the max u64 length of a
-// ClearString
-const N: usize = 4;
-
 fn main() {
     let matches = Command::new("FHE str API")
         .arg(
@@ -147,14 +141,18 @@ fn main() {
 }
 
 struct Keys {
-    ck: ClientKey,
-    sk: ServerKey,
+    ck: ClientKey<IntegerClientKey>,
+    sk: ServerKey<IntegerServerKey>,
 }
 
 impl Keys {
     fn new() -> Self {
-        let (ck, sk) = gen_keys();
+        let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+        let sk = IntegerServerKey::new_radix_server_key(&ck);
 
-        Keys { ck, sk }
+        Keys {
+            ck: ClientKey::new(ck),
+            sk: ServerKey::new(sk),
+        }
     }
 }
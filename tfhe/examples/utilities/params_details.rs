@@ -0,0 +1,138 @@
+use tfhe::boolean::parameters::{BooleanParameters, VEC_BOOLEAN_PARAM};
+use tfhe::core_crypto::commons::dispersion::{StandardDev, Variance};
+use tfhe::core_crypto::commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+use tfhe::shortint::parameters::classic::compact_pk::ALL_PARAMETER_VEC_COMPACT_PK;
+use tfhe::shortint::parameters::multi_bit::ALL_MULTI_BIT_PARAMETER_VEC;
+use tfhe::shortint::parameters::{
+    ShortintParameterSet, ALL_PARAMETER_VEC, PARAM_MESSAGE_1_CARRY_1_PBS_KS,
+    PARAM_MESSAGE_2_CARRY_2_PBS_KS, PARAM_MESSAGE_3_CARRY_3_PBS_KS, PARAM_MESSAGE_4_CARRY_4_PBS_KS,
+};
+
+pub trait ParamDetails {
+    fn lwe_dimension(&self) -> LweDimension;
+    fn glwe_dimension(&self) -> GlweDimension;
+    fn polynomial_size(&self) -> PolynomialSize;
+    fn log_ciphertext_modulus(&self) -> u32;
+    #[allow(dead_code)]
+    fn lwe_std_dev(&self) -> StandardDev;
+    #[allow(dead_code)]
+    fn glwe_std_dev(&self) -> StandardDev;
+    #[allow(dead_code)]
+    fn lwe_noise_variance(&self) -> Variance;
+    #[allow(dead_code)]
+    fn glwe_noise_variance(&self) -> Variance;
+}
+
+impl ParamDetails for BooleanParameters {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn log_ciphertext_modulus(&self) -> u32 {
+        32
+    }
+
+    fn lwe_std_dev(&self) -> StandardDev {
+        self.lwe_noise_distribution.gaussian_std_dev()
+    }
+
+    fn glwe_std_dev(&self) -> StandardDev {
+        self.glwe_noise_distribution.gaussian_std_dev()
+    }
+
+    fn lwe_noise_variance(&self) -> Variance {
+        self.lwe_noise_distribution
+            .variance(self.log_ciphertext_modulus())
+    }
+
+    fn glwe_noise_variance(&self) -> Variance {
+        self.glwe_noise_distribution
+            .variance(self.log_ciphertext_modulus())
+    }
+}
+
+impl ParamDetails for ShortintParameterSet {
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension()
+    }
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size()
+    }
+
+    fn log_ciphertext_modulus(&self) -> u32 {
+        assert!(self.ciphertext_modulus().is_native_modulus());
+        64
+    }
+
+    fn lwe_std_dev(&self) -> StandardDev {
+        self.lwe_noise_distribution().gaussian_std_dev()
+    }
+
+    fn glwe_std_dev(&self) -> StandardDev {
+        self.glwe_noise_distribution().gaussian_std_dev()
+    }
+
+    fn lwe_noise_variance(&self) -> Variance {
+        self.lwe_noise_distribution()
+            .variance(self.log_ciphertext_modulus())
+    }
+
+    fn glwe_noise_variance(&self) -> Variance {
+        self.glwe_noise_distribution()
+            .variance(self.log_ciphertext_modulus())
+    }
+}
+
+/// The boolean parameters to analyze
+pub fn boolean_parameters() -> Vec<BooleanParameters> {
+    VEC_BOOLEAN_PARAM.to_vec()
+}
+
+/// The shortint parameters using the classic PBS to analyze
+pub fn shortint_classic_parameters() -> Vec<ShortintParameterSet> {
+    let classic_params_small = vec![
+        PARAM_MESSAGE_1_CARRY_1_PBS_KS,
+        PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+        PARAM_MESSAGE_3_CARRY_3_PBS_KS,
+        PARAM_MESSAGE_4_CARRY_4_PBS_KS,
+    ];
+    let all_classic_pbs = [
+        ALL_PARAMETER_VEC.to_vec(),
+        ALL_PARAMETER_VEC_COMPACT_PK.to_vec(),
+        classic_params_small,
+    ]
+    .concat();
+    all_classic_pbs
+        .iter()
+        .map(|p| ShortintParameterSet::from(*p))
+        .collect()
+}
+
+/// The shortint parameters using the multi bit PBS to analyze
+pub fn shortint_multi_bit_parameters() -> Vec<ShortintParameterSet> {
+    ALL_MULTI_BIT_PARAMETER_VEC
+        .iter()
+        .map(|p| ShortintParameterSet::from(*p))
+        .collect()
+}
+
+// TODO gather the wopbs parameters later
+// pub fn shortint_wopbs_parameters() -> Vec<ShortintParameterSet> {
+//     ALL_PARAMETER_VEC_WOPBS
+//         .iter()
+//         .map(|p| ShortintParameterSet::from(*p))
+//         .collect()
+// }
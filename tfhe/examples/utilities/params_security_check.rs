@@ -0,0 +1,75 @@
+mod params_details;
+
+use params_details::{
+    boolean_parameters, shortint_classic_parameters, shortint_multi_bit_parameters, ParamDetails,
+};
+use tfhe::boolean::parameters::TFHE_LIB_PARAMETERS;
+use tfhe::core_crypto::commons::security::{check_lwe_security, DEFAULT_SECURITY_LEVEL};
+use tfhe::keycache::NamedParam;
+
+/// Check the security of the LWE and GLWE secret keys of the parameters, returning the reasons of
+/// the failures.
+fn check_params_security<T: ParamDetails + NamedParam>(params: &[T]) -> Vec<String> {
+    let mut failures = vec![];
+
+    for param in params.iter() {
+        let glwe_lwe_dimension = param
+            .glwe_dimension()
+            .to_equivalent_lwe_dimension(param.polynomial_size());
+
+        for (tag, lwe_dimension, noise_variance) in [
+            ("lwe", param.lwe_dimension(), param.lwe_noise_variance()),
+            ("glwe", glwe_lwe_dimension, param.glwe_noise_variance()),
+        ] {
+            let tag = format!("{}_{tag}", param.name());
+            print!("\t{tag}...\t");
+
+            match check_lwe_security(
+                lwe_dimension,
+                noise_variance,
+                param.log_ciphertext_modulus(),
+                DEFAULT_SECURITY_LEVEL,
+            ) {
+                Ok(security_level) => println!("OK\t(security level = {security_level} bits)"),
+                Err(err) => {
+                    println!("FAIL\t{err}");
+                    failures.push(format!("[{tag}] reason: {err}"));
+                }
+            }
+        }
+    }
+
+    failures
+}
+
+fn main() {
+    let mut failures = vec![];
+
+    println!("Checking boolean parameters");
+    // This third-party parameters set is known to be less secure, just skip the analysis.
+    let boolean_params = boolean_parameters()
+        .into_iter()
+        .filter(|p| *p != TFHE_LIB_PARAMETERS)
+        .collect::<Vec<_>>();
+    failures.extend(check_params_security(&boolean_params));
+
+    println!("Checking shortint classic parameters");
+    failures.extend(check_params_security(&shortint_classic_parameters()));
+
+    println!("Checking shortint multi bit parameters");
+    failures.extend(check_params_security(&shortint_multi_bit_parameters()));
+
+    // TODO perform this check later
+    // failures.extend(check_params_security(&shortint_wopbs_parameters()));
+
+    if failures.is_empty() {
+        println!("All parameters passed the security check");
+    } else {
+        println!("Some parameters need update");
+        println!("----------------------------");
+        for failure in failures.iter() {
+            println!("{failure}");
+        }
+        std::process::exit(1);
+    }
+}
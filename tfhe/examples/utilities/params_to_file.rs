@@ -1,76 +1,12 @@
+mod params_details;
+
+use params_details::{
+    boolean_parameters, shortint_classic_parameters, shortint_multi_bit_parameters, ParamDetails,
+};
 use std::fs::{File, OpenOptions};
 use std::io::Write;
 use std::path::Path;
-use tfhe::boolean::parameters::{BooleanParameters, VEC_BOOLEAN_PARAM};
-use tfhe::core_crypto::commons::dispersion::StandardDev;
-use tfhe::core_crypto::commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
 use tfhe::keycache::NamedParam;
-use tfhe::shortint::parameters::classic::compact_pk::ALL_PARAMETER_VEC_COMPACT_PK;
-use tfhe::shortint::parameters::multi_bit::ALL_MULTI_BIT_PARAMETER_VEC;
-use tfhe::shortint::parameters::{
-    ShortintParameterSet, ALL_PARAMETER_VEC, PARAM_MESSAGE_1_CARRY_1_PBS_KS,
-    PARAM_MESSAGE_2_CARRY_2_PBS_KS, PARAM_MESSAGE_3_CARRY_3_PBS_KS, PARAM_MESSAGE_4_CARRY_4_PBS_KS,
-};
-
-pub trait ParamDetails {
-    fn lwe_dimension(&self) -> LweDimension;
-    fn glwe_dimension(&self) -> GlweDimension;
-    fn lwe_std_dev(&self) -> StandardDev;
-    fn glwe_std_dev(&self) -> StandardDev;
-    fn polynomial_size(&self) -> PolynomialSize;
-    fn log_ciphertext_modulus(&self) -> usize;
-}
-
-impl ParamDetails for BooleanParameters {
-    fn lwe_dimension(&self) -> LweDimension {
-        self.lwe_dimension
-    }
-
-    fn glwe_dimension(&self) -> GlweDimension {
-        self.glwe_dimension
-    }
-
-    fn lwe_std_dev(&self) -> StandardDev {
-        self.lwe_noise_distribution.gaussian_std_dev()
-    }
-    fn glwe_std_dev(&self) -> StandardDev {
-        self.glwe_noise_distribution.gaussian_std_dev()
-    }
-
-    fn polynomial_size(&self) -> PolynomialSize {
-        self.polynomial_size
-    }
-
-    fn log_ciphertext_modulus(&self) -> usize {
-        32
-    }
-}
-
-impl ParamDetails for ShortintParameterSet {
-    fn lwe_dimension(&self) -> LweDimension {
-        self.lwe_dimension()
-    }
-
-    fn glwe_dimension(&self) -> GlweDimension {
-        self.glwe_dimension()
-    }
-
-    fn lwe_std_dev(&self) -> StandardDev {
-        self.lwe_noise_distribution().gaussian_std_dev()
-    }
-    fn glwe_std_dev(&self) -> StandardDev {
-        self.glwe_noise_distribution().gaussian_std_dev()
-    }
-
-    fn polynomial_size(&self) -> PolynomialSize {
-        self.polynomial_size()
-    }
-
-    fn log_ciphertext_modulus(&self) -> usize {
-        assert!(self.ciphertext_modulus().is_native_modulus());
-        64
-    }
-}
 
 ///Function to print in the lattice_estimator format the parameters
 /// Format:   LWE.Parameters(n=722, q=2^32, Xs=ND.UniformMod(2),
@@ -143,46 +79,22 @@ fn main() {
 
     write_all_params_in_file(
         "boolean_parameters_lattice_estimator.sage",
-        &VEC_BOOLEAN_PARAM,
+        &boolean_parameters(),
     );
 
-    let classic_params_small = vec![
-        PARAM_MESSAGE_1_CARRY_1_PBS_KS,
-        PARAM_MESSAGE_2_CARRY_2_PBS_KS,
-        PARAM_MESSAGE_3_CARRY_3_PBS_KS,
-        PARAM_MESSAGE_4_CARRY_4_PBS_KS,
-    ];
-    let all_classic_pbs = [
-        ALL_PARAMETER_VEC.to_vec(),
-        ALL_PARAMETER_VEC_COMPACT_PK.to_vec(),
-        classic_params_small,
-    ]
-    .concat();
-    let classic_pbs = all_classic_pbs
-        .iter()
-        .map(|p| ShortintParameterSet::from(*p))
-        .collect::<Vec<_>>();
     write_all_params_in_file(
         "shortint_classic_parameters_lattice_estimator.sage",
-        &classic_pbs,
+        &shortint_classic_parameters(),
     );
 
-    let multi_bit_pbs = ALL_MULTI_BIT_PARAMETER_VEC
-        .iter()
-        .map(|p| ShortintParameterSet::from(*p))
-        .collect::<Vec<_>>();
     write_all_params_in_file(
         "shortint_multi_bit_parameters_lattice_estimator.sage",
-        &multi_bit_pbs,
+        &shortint_multi_bit_parameters(),
     );
 
     // TODO perform this gathering later
-    // let wopbs = ALL_PARAMETER_VEC_WOPBS
-    //     .iter()
-    //     .map(|p| ShortintParameterSet::from(*p))
-    //     .collect::<Vec<_>>();
     // write_all_params_in_file(
     //     "shortint_wopbs_parameters_lattice_estimator.sage",
-    //     &wopbs,
+    //     &shortint_wopbs_parameters(),
     // );
 }
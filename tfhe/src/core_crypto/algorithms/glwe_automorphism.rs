@@ -0,0 +1,294 @@
+//! Module containing primitives pertaining to the application of ring automorphisms to
+//! [`GLWE ciphertexts`](`GlweCiphertext`).
+
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_automorphism;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::crypto::glwe_keyswitch::glwe_keyswitch_scratch;
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use aligned_vec::CACHELINE_ALIGN;
+use concrete_fft::c64;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+/// Apply the ring automorphism $X \mapsto X^k$ to each polynomial of an input
+/// [`GLWE ciphertext`](`GlweCiphertext`) and write the result in an output
+/// [`GLWE ciphertext`](`GlweCiphertext`).
+///
+/// # Note
+///
+/// If the input is encrypted under the [`GLWE secret key`](`GlweSecretKey`) $S$, the output is
+/// encrypted under the key $S(X^k)$. Use [`apply_automorphism_to_glwe_ciphertext`] to get a
+/// ciphertext encrypted under $S$.
+pub fn glwe_ciphertext_automorphism<Scalar, InputCont, OutputCont>(
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    automorphism_exponent: AutomorphismExponent,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert!(
+        input_glwe_ciphertext.glwe_size() == output_glwe_ciphertext.glwe_size(),
+        "Mismatched GlweSize between input ({:?}) and output ({:?}) GlweCiphertext.",
+        input_glwe_ciphertext.glwe_size(),
+        output_glwe_ciphertext.glwe_size()
+    );
+    assert!(
+        input_glwe_ciphertext.polynomial_size() == output_glwe_ciphertext.polynomial_size(),
+        "Mismatched PolynomialSize between input ({:?}) and output ({:?}) GlweCiphertext.",
+        input_glwe_ciphertext.polynomial_size(),
+        output_glwe_ciphertext.polynomial_size()
+    );
+    assert!(
+        input_glwe_ciphertext.ciphertext_modulus() == output_glwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus between input ({:?}) and output ({:?}) GlweCiphertext.",
+        input_glwe_ciphertext.ciphertext_modulus(),
+        output_glwe_ciphertext.ciphertext_modulus()
+    );
+    assert!(
+        input_glwe_ciphertext
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "This operation currently only supports power of 2 moduli"
+    );
+
+    for (mut output_polynomial, input_polynomial) in output_glwe_ciphertext
+        .as_mut_polynomial_list()
+        .iter_mut()
+        .zip(input_glwe_ciphertext.as_polynomial_list().iter())
+    {
+        polynomial_wrapping_automorphism(
+            &mut output_polynomial,
+            &input_polynomial,
+            automorphism_exponent,
+        );
+    }
+}
+
+/// Apply the ring automorphism $X \mapsto X^k$ to an input
+/// [`GLWE ciphertext`](`GlweCiphertext`) using a
+/// [`Fourier GLWE automorphism key`](`FourierGlweAutomorphismKey`) and write the result in an
+/// output [`GLWE ciphertext`](`GlweCiphertext`) encrypted under the same
+/// [`GLWE secret key`](`GlweSecretKey`) as the input.
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweAutomorphismKey creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let decomp_base_log = DecompositionBaseLog(23);
+/// let decomp_level_count = DecompositionLevelCount(1);
+/// let automorphism_exponent = AutomorphismExponent(3);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let auto_key = allocate_and_generate_new_glwe_automorphism_key(
+///     &glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     automorphism_exponent,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Convert the key to the Fourier domain
+/// let mut fourier_auto_key = FourierGlweAutomorphismKey::new(
+///     auto_key.glwe_size(),
+///     auto_key.polynomial_size(),
+///     auto_key.decomposition_base_log(),
+///     auto_key.decomposition_level_count(),
+///     auto_key.automorphism_exponent(),
+/// );
+///
+/// convert_standard_glwe_automorphism_key_to_fourier(&auto_key, &mut fourier_auto_key);
+///
+/// // Create the plaintext, encrypting the message 1 in the coefficient of degree 1
+/// let msg = 1u64;
+/// let mut plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// *plaintext_list.get_mut(1).0 = msg << 60;
+///
+/// // Create a new GlweCiphertext
+/// let mut input_glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut input_glwe,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// apply_automorphism_to_glwe_ciphertext(&fourier_auto_key, &input_glwe, &mut output_glwe);
+///
+/// let mut decrypted_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &output_glwe,
+///     &mut decrypted_plaintext_list,
+/// );
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// decrypted_plaintext_list
+///     .iter_mut()
+///     .for_each(|elt| *elt.0 = decomposer.closest_representable(*elt.0) >> 60);
+///
+/// // X is mapped to X^3, the message moved to the coefficient of degree 3
+/// for (degree, elt) in decrypted_plaintext_list.iter().enumerate() {
+///     let expected = if degree == 3 { msg } else { 0 };
+///     assert_eq!(*elt.0, expected);
+/// }
+/// ```
+pub fn apply_automorphism_to_glwe_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
+    glwe_automorphism_key: &FourierGlweAutomorphismKey<KeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(glwe_automorphism_key.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        apply_automorphism_to_glwe_ciphertext_mem_optimized_requirement::<Scalar>(
+            glwe_automorphism_key.glwe_size(),
+            glwe_automorphism_key.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    apply_automorphism_to_glwe_ciphertext_mem_optimized(
+        glwe_automorphism_key,
+        input_glwe_ciphertext,
+        output_glwe_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+/// Memory optimized version of [`apply_automorphism_to_glwe_ciphertext`], the caller must
+/// provide a properly configured [`FftView`] object and a `PodStack` used as a memory buffer
+/// having a capacity at least as large as the result of
+/// [`apply_automorphism_to_glwe_ciphertext_mem_optimized_requirement`].
+pub fn apply_automorphism_to_glwe_ciphertext_mem_optimized<Scalar, KeyCont, InputCont, OutputCont>(
+    glwe_automorphism_key: &FourierGlweAutomorphismKey<KeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert!(
+        glwe_automorphism_key.glwe_size() == input_glwe_ciphertext.glwe_size(),
+        "Mismatched GlweSize between the GlweAutomorphismKey ({:?}) \
+        and the input GlweCiphertext ({:?}).",
+        glwe_automorphism_key.glwe_size(),
+        input_glwe_ciphertext.glwe_size()
+    );
+    assert!(
+        glwe_automorphism_key.glwe_size() == output_glwe_ciphertext.glwe_size(),
+        "Mismatched GlweSize between the GlweAutomorphismKey ({:?}) \
+        and the output GlweCiphertext ({:?}).",
+        glwe_automorphism_key.glwe_size(),
+        output_glwe_ciphertext.glwe_size()
+    );
+    assert!(
+        glwe_automorphism_key.polynomial_size() == input_glwe_ciphertext.polynomial_size(),
+        "Mismatched PolynomialSize between the GlweAutomorphismKey ({:?}) \
+        and the input GlweCiphertext ({:?}).",
+        glwe_automorphism_key.polynomial_size(),
+        input_glwe_ciphertext.polynomial_size()
+    );
+    assert!(
+        glwe_automorphism_key.polynomial_size() == output_glwe_ciphertext.polynomial_size(),
+        "Mismatched PolynomialSize between the GlweAutomorphismKey ({:?}) \
+        and the output GlweCiphertext ({:?}).",
+        glwe_automorphism_key.polynomial_size(),
+        output_glwe_ciphertext.polynomial_size()
+    );
+
+    let (mut automorphism_buffer, stack) =
+        stack.make_aligned_raw(input_glwe_ciphertext.as_ref().len(), CACHELINE_ALIGN);
+    let mut automorphism_glwe = GlweCiphertextMutView::from_container(
+        &mut *automorphism_buffer,
+        input_glwe_ciphertext.polynomial_size(),
+        input_glwe_ciphertext.ciphertext_modulus(),
+    );
+
+    glwe_ciphertext_automorphism(
+        input_glwe_ciphertext,
+        &mut automorphism_glwe,
+        glwe_automorphism_key.automorphism_exponent(),
+    );
+
+    glwe_automorphism_key
+        .as_fourier_glwe_keyswitch_key()
+        .keyswitch(
+            output_glwe_ciphertext.as_mut_view(),
+            automorphism_glwe.as_view(),
+            fft,
+            stack,
+        );
+}
+
+/// Return the required memory for [`apply_automorphism_to_glwe_ciphertext_mem_optimized`].
+pub fn apply_automorphism_to_glwe_ciphertext_mem_optimized_requirement<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?.try_and(
+        glwe_keyswitch_scratch::<Scalar>(glwe_size, glwe_size, polynomial_size, fft)?,
+    )
+}
@@ -0,0 +1,111 @@
+//! Module containing primitives pertaining to the conversion of
+//! [`standard GLWE automorphism keys`](`GlweAutomorphismKey`) to the Fourier domain.
+
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::crypto::glwe_keyswitch::fill_with_forward_fourier_scratch;
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use concrete_fft::c64;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+/// Convert a [`GLWE automorphism key`](`GlweAutomorphismKey`) with standard coefficients to the
+/// Fourier domain.
+///
+/// See [`apply_automorphism_to_glwe_ciphertext`](`crate::core_crypto::algorithms::apply_automorphism_to_glwe_ciphertext`) for usage.
+pub fn convert_standard_glwe_automorphism_key_to_fourier<Scalar, InputCont, OutputCont>(
+    input_auto_key: &GlweAutomorphismKey<InputCont>,
+    output_auto_key: &mut FourierGlweAutomorphismKey<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(input_auto_key.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        convert_standard_glwe_automorphism_key_to_fourier_mem_optimized_requirement(fft)
+            .unwrap()
+            .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    convert_standard_glwe_automorphism_key_to_fourier_mem_optimized(
+        input_auto_key,
+        output_auto_key,
+        fft,
+        stack,
+    );
+}
+
+/// Memory optimized version of [`convert_standard_glwe_automorphism_key_to_fourier`].
+pub fn convert_standard_glwe_automorphism_key_to_fourier_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+>(
+    input_auto_key: &GlweAutomorphismKey<InputCont>,
+    output_auto_key: &mut FourierGlweAutomorphismKey<OutputCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    assert_eq!(
+        input_auto_key.polynomial_size(),
+        output_auto_key.polynomial_size(),
+        "Mismatched PolynomialSize between input_auto_key {:?} and output_auto_key {:?}",
+        input_auto_key.polynomial_size(),
+        output_auto_key.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_auto_key.glwe_size(),
+        output_auto_key.glwe_size(),
+        "Mismatched GlweSize between input_auto_key {:?} and output_auto_key {:?}",
+        input_auto_key.glwe_size(),
+        output_auto_key.glwe_size(),
+    );
+
+    assert_eq!(
+        input_auto_key.decomposition_base_log(),
+        output_auto_key.decomposition_base_log(),
+        "Mismatched DecompositionBaseLog between input_auto_key {:?} and output_auto_key {:?}",
+        input_auto_key.decomposition_base_log(),
+        output_auto_key.decomposition_base_log(),
+    );
+
+    assert_eq!(
+        input_auto_key.decomposition_level_count(),
+        output_auto_key.decomposition_level_count(),
+        "Mismatched DecompositionLevelCount between input_auto_key {:?} and output_auto_key {:?}",
+        input_auto_key.decomposition_level_count(),
+        output_auto_key.decomposition_level_count(),
+    );
+
+    assert_eq!(
+        input_auto_key.automorphism_exponent(),
+        output_auto_key.automorphism_exponent(),
+        "Mismatched AutomorphismExponent between input_auto_key {:?} and output_auto_key {:?}",
+        input_auto_key.automorphism_exponent(),
+        output_auto_key.automorphism_exponent(),
+    );
+
+    output_auto_key
+        .as_mut_view()
+        .fill_with_forward_fourier(input_auto_key.as_view(), fft, stack);
+}
+
+/// Return the required memory for
+/// [`convert_standard_glwe_automorphism_key_to_fourier_mem_optimized`].
+pub fn convert_standard_glwe_automorphism_key_to_fourier_mem_optimized_requirement(
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    fill_with_forward_fourier_scratch(fft)
+}
@@ -0,0 +1,211 @@
+//! Module containing primitives pertaining to [`GLWE automorphism keys
+//! generation`](`GlweAutomorphismKey`).
+
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_automorphism;
+use crate::core_crypto::algorithms::{
+    generate_glwe_keyswitch_key, lwe_ring_packing_automorphism_exponents,
+};
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::random::{Distribution, Uniform};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Fill a [`GLWE automorphism key`](`GlweAutomorphismKey`) with an actual key allowing to apply
+/// the automorphism $X \mapsto X^k$ to ciphertexts encrypted under the input
+/// [`GLWE secret key`](`GlweSecretKey`), $k$ being the
+/// [`AutomorphismExponent`] of the output key.
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweAutomorphismKey creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let decomp_base_log = DecompositionBaseLog(23);
+/// let decomp_level_count = DecompositionLevelCount(1);
+/// let automorphism_exponent = AutomorphismExponent(3);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let mut auto_key = GlweAutomorphismKey::new(
+///     0u64,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_dimension,
+///     polynomial_size,
+///     automorphism_exponent,
+///     ciphertext_modulus,
+/// );
+///
+/// generate_glwe_automorphism_key(
+///     &glwe_secret_key,
+///     &mut auto_key,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// assert!(auto_key.as_ref().iter().all(|&x| x == 0) == false);
+/// ```
+pub fn generate_glwe_automorphism_key<Scalar, NoiseDistribution, KeyCont, AutoKeyCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    glwe_automorphism_key: &mut GlweAutomorphismKey<AutoKeyCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    AutoKeyCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        glwe_automorphism_key.glwe_dimension() == glwe_secret_key.glwe_dimension(),
+        "The destination GlweAutomorphismKey GlweDimension is not equal \
+        to the GlweSecretKey GlweDimension. Destination: {:?}, key: {:?}",
+        glwe_automorphism_key.glwe_dimension(),
+        glwe_secret_key.glwe_dimension()
+    );
+    assert!(
+        glwe_automorphism_key.polynomial_size() == glwe_secret_key.polynomial_size(),
+        "The destination GlweAutomorphismKey PolynomialSize is not equal \
+        to the GlweSecretKey PolynomialSize. Destination: {:?}, key: {:?}",
+        glwe_automorphism_key.polynomial_size(),
+        glwe_secret_key.polynomial_size()
+    );
+
+    let polynomial_size = glwe_secret_key.polynomial_size();
+    let automorphism_exponent = glwe_automorphism_key.automorphism_exponent();
+
+    // Applying the automorphism to a ciphertext yields a ciphertext encrypted under the
+    // automorphism of the secret key, the key switches from that key back to the original one
+    let mut automorphism_secret_key = GlweSecretKey::new_empty_key(
+        Scalar::ZERO,
+        glwe_secret_key.glwe_dimension(),
+        polynomial_size,
+    );
+
+    for (mut automorphism_key_polynomial, key_polynomial) in automorphism_secret_key
+        .as_mut()
+        .chunks_exact_mut(polynomial_size.0)
+        .map(Polynomial::from_container)
+        .zip(glwe_secret_key.as_polynomial_list().iter())
+    {
+        polynomial_wrapping_automorphism(
+            &mut automorphism_key_polynomial,
+            &key_polynomial,
+            automorphism_exponent,
+        );
+    }
+
+    generate_glwe_keyswitch_key(
+        &automorphism_secret_key,
+        glwe_secret_key,
+        &mut glwe_automorphism_key.as_mut_glwe_keyswitch_key(),
+        noise_distribution,
+        generator,
+    );
+}
+
+/// Allocate a new [`GLWE automorphism key`](`GlweAutomorphismKey`) and fill it with an actual
+/// key allowing to apply the automorphism $X \mapsto X^k$ to ciphertexts encrypted under the
+/// input [`GLWE secret key`](`GlweSecretKey`).
+///
+/// See [`apply_automorphism_to_glwe_ciphertext`](`super::apply_automorphism_to_glwe_ciphertext`)
+/// for usage.
+pub fn allocate_and_generate_new_glwe_automorphism_key<Scalar, NoiseDistribution, KeyCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    automorphism_exponent: AutomorphismExponent,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> GlweAutomorphismKeyOwned<Scalar>
+where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut new_glwe_automorphism_key = GlweAutomorphismKeyOwned::new(
+        Scalar::ZERO,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_secret_key.glwe_dimension(),
+        glwe_secret_key.polynomial_size(),
+        automorphism_exponent,
+        ciphertext_modulus,
+    );
+
+    generate_glwe_automorphism_key(
+        glwe_secret_key,
+        &mut new_glwe_automorphism_key,
+        noise_distribution,
+        generator,
+    );
+
+    new_glwe_automorphism_key
+}
+
+/// Allocate and generate the [`GLWE automorphism keys`](`GlweAutomorphismKey`) required to pack
+/// LWE ciphertexts in a GLWE ciphertext with
+/// [`ring_pack_lwe_ciphertext_list_into_glwe_ciphertext`](`super::ring_pack_lwe_ciphertext_list_into_glwe_ciphertext`).
+///
+/// The returned keys are ordered following
+/// [`lwe_ring_packing_automorphism_exponents`](`super::lwe_ring_packing_automorphism_exponents`).
+///
+/// See [`ring_pack_lwe_ciphertext_list_into_glwe_ciphertext`](`super::ring_pack_lwe_ciphertext_list_into_glwe_ciphertext`)
+/// for usage.
+pub fn allocate_and_generate_new_lwe_ring_packing_glwe_automorphism_keys<
+    Scalar,
+    NoiseDistribution,
+    KeyCont,
+    Gen,
+>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> Vec<GlweAutomorphismKeyOwned<Scalar>>
+where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    lwe_ring_packing_automorphism_exponents(glwe_secret_key.polynomial_size())
+        .into_iter()
+        .map(|automorphism_exponent| {
+            allocate_and_generate_new_glwe_automorphism_key(
+                glwe_secret_key,
+                decomp_base_log,
+                decomp_level_count,
+                automorphism_exponent,
+                noise_distribution,
+                ciphertext_modulus,
+                generator,
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,444 @@
+//! Module containing primitives pertaining to [`GLWE ciphertext`](`GlweCiphertext`) keyswitch.
+
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_sub_mul_assign;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::crypto::glwe_keyswitch::glwe_keyswitch_scratch;
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use concrete_fft::c64;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+/// Check the compatibility of the various parameters involved in a GLWE keyswitch.
+fn check_glwe_keyswitch_parameters<Scalar: UnsignedInteger>(
+    ksk_input_glwe_dimension: GlweDimension,
+    ksk_output_glwe_size: GlweSize,
+    ksk_polynomial_size: PolynomialSize,
+    input_glwe_ciphertext: GlweCiphertextView<'_, Scalar>,
+    output_glwe_ciphertext: GlweCiphertextView<'_, Scalar>,
+) {
+    assert!(
+        ksk_input_glwe_dimension == input_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        "Mismatched input GlweDimension. \
+        GlweKeyswitchKey input GlweDimension: {:?}, input GlweCiphertext GlweDimension {:?}.",
+        ksk_input_glwe_dimension,
+        input_glwe_ciphertext.glwe_size().to_glwe_dimension()
+    );
+    assert!(
+        ksk_output_glwe_size == output_glwe_ciphertext.glwe_size(),
+        "Mismatched output GlweSize. \
+        GlweKeyswitchKey output GlweSize: {:?}, output GlweCiphertext GlweSize {:?}.",
+        ksk_output_glwe_size,
+        output_glwe_ciphertext.glwe_size()
+    );
+    assert!(
+        ksk_polynomial_size == input_glwe_ciphertext.polynomial_size(),
+        "Mismatched input PolynomialSize. \
+        GlweKeyswitchKey PolynomialSize: {:?}, input GlweCiphertext PolynomialSize {:?}.",
+        ksk_polynomial_size,
+        input_glwe_ciphertext.polynomial_size()
+    );
+    assert!(
+        ksk_polynomial_size == output_glwe_ciphertext.polynomial_size(),
+        "Mismatched output PolynomialSize. \
+        GlweKeyswitchKey PolynomialSize: {:?}, output GlweCiphertext PolynomialSize {:?}.",
+        ksk_polynomial_size,
+        output_glwe_ciphertext.polynomial_size()
+    );
+    assert!(
+        input_glwe_ciphertext.ciphertext_modulus() == output_glwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus. \
+        Input GlweCiphertext CiphertextModulus: {:?}, \
+        output GlweCiphertext CiphertextModulus {:?}.",
+        input_glwe_ciphertext.ciphertext_modulus(),
+        output_glwe_ciphertext.ciphertext_modulus()
+    );
+    assert!(
+        input_glwe_ciphertext
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "This operation currently only supports power of 2 moduli"
+    );
+}
+
+/// Apply a keyswitch on an input [`GLWE ciphertext`](`GlweCiphertext`) and write the result in an
+/// output [`GLWE ciphertext`](`GlweCiphertext`).
+///
+/// The input and output keys may have different [`GlweDimension`] but must share the same
+/// [`PolynomialSize`], see
+/// [`convert_glwe_ciphertext_polynomial_size`](`super::convert_glwe_ciphertext_polynomial_size`)
+/// to change the [`PolynomialSize`] of a ciphertext before keyswitching it.
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweKeyswitchKey creation
+/// let input_glwe_dimension = GlweDimension(2);
+/// let output_glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let decomp_base_log = DecompositionBaseLog(23);
+/// let decomp_level_count = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKeys
+/// let input_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     input_glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+/// let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     output_glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let ksk = allocate_and_generate_new_glwe_keyswitch_key(
+///     &input_glwe_secret_key,
+///     &output_glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let plaintext_list = PlaintextList::new(msg << 60, PlaintextCount(polynomial_size.0));
+///
+/// // Create a new GlweCiphertext
+/// let mut input_glwe = GlweCiphertext::new(
+///     0u64,
+///     input_glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_glwe_ciphertext(
+///     &input_glwe_secret_key,
+///     &mut input_glwe,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_glwe = GlweCiphertext::new(
+///     0u64,
+///     output_glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// keyswitch_glwe_ciphertext(&ksk, &input_glwe, &mut output_glwe);
+///
+/// let mut decrypted_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(
+///     &output_glwe_secret_key,
+///     &output_glwe,
+///     &mut decrypted_plaintext_list,
+/// );
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// decrypted_plaintext_list
+///     .iter_mut()
+///     .for_each(|elt| *elt.0 = decomposer.closest_representable(*elt.0) >> 60);
+///
+/// // Check we recovered the original message for each plaintext we encrypted
+/// assert!(decrypted_plaintext_list.iter().all(|elt| *elt.0 == msg));
+/// ```
+pub fn keyswitch_glwe_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
+    glwe_keyswitch_key: &GlweKeyswitchKey<KeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    check_glwe_keyswitch_parameters(
+        glwe_keyswitch_key.input_key_glwe_dimension(),
+        glwe_keyswitch_key.output_glwe_size(),
+        glwe_keyswitch_key.polynomial_size(),
+        input_glwe_ciphertext.as_view(),
+        output_glwe_ciphertext.as_view(),
+    );
+    assert!(
+        glwe_keyswitch_key.ciphertext_modulus() == input_glwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus. \
+        GlweKeyswitchKey CiphertextModulus: {:?}, input GlweCiphertext CiphertextModulus {:?}.",
+        glwe_keyswitch_key.ciphertext_modulus(),
+        input_glwe_ciphertext.ciphertext_modulus()
+    );
+
+    let polynomial_size = glwe_keyswitch_key.polynomial_size();
+    let decomp_level_count = glwe_keyswitch_key.decomposition_level_count();
+
+    // We reset the output
+    output_glwe_ciphertext.as_mut().fill(Scalar::ZERO);
+    output_glwe_ciphertext
+        .get_mut_body()
+        .as_mut()
+        .copy_from_slice(input_glwe_ciphertext.get_body().as_ref());
+
+    // We instantiate a decomposer
+    let decomposer = SignedDecomposer::new(
+        glwe_keyswitch_key.decomposition_base_log(),
+        decomp_level_count,
+    );
+
+    // Buffer holding the decomposition of an input mask polynomial, one polynomial per level
+    let mut decomposition_buffer = PolynomialListOwned::new(
+        Scalar::ZERO,
+        polynomial_size,
+        PolynomialCount(decomp_level_count.0),
+    );
+
+    for (keyswitch_key_block, input_mask_polynomial) in glwe_keyswitch_key
+        .iter()
+        .zip(input_glwe_ciphertext.get_mask().as_polynomial_list().iter())
+    {
+        // We decompose each coefficient of the mask polynomial, the decomposition iterator yields
+        // the levels from the last to the first, which matches the order of the key levels
+        for (coefficient_index, &input_coefficient) in
+            input_mask_polynomial.as_ref().iter().enumerate()
+        {
+            let rounded = decomposer.closest_representable(input_coefficient);
+            for (mut level_polynomial, decomposed) in decomposition_buffer
+                .iter_mut()
+                .zip(decomposer.decompose(rounded))
+            {
+                level_polynomial.as_mut()[coefficient_index] = decomposed.value();
+            }
+        }
+
+        // Loop over the number of levels:
+        // We compute the multiplication of a ciphertext from the keyswitching key with a
+        // polynomial of the decomposition and subtract it to the output
+        for (level_key_ciphertext, decomposed_polynomial) in
+            keyswitch_key_block.iter().zip(decomposition_buffer.iter())
+        {
+            for (mut output_polynomial, key_polynomial) in output_glwe_ciphertext
+                .as_mut_polynomial_list()
+                .iter_mut()
+                .zip(level_key_ciphertext.as_polynomial_list().iter())
+            {
+                polynomial_wrapping_sub_mul_assign(
+                    &mut output_polynomial,
+                    &key_polynomial,
+                    &decomposed_polynomial,
+                );
+            }
+        }
+    }
+}
+
+/// Apply a keyswitch on an input [`GLWE ciphertext`](`GlweCiphertext`) using a
+/// [`Fourier GLWE keyswitch key`](`FourierGlweKeyswitchKey`) and write the result in an output
+/// [`GLWE ciphertext`](`GlweCiphertext`).
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweKeyswitchKey creation
+/// let input_glwe_dimension = GlweDimension(2);
+/// let output_glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let decomp_base_log = DecompositionBaseLog(23);
+/// let decomp_level_count = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKeys
+/// let input_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     input_glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+/// let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     output_glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let ksk = allocate_and_generate_new_glwe_keyswitch_key(
+///     &input_glwe_secret_key,
+///     &output_glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Convert the key to the Fourier domain
+/// let mut fourier_ksk = FourierGlweKeyswitchKey::new(
+///     ksk.input_key_glwe_dimension(),
+///     ksk.output_glwe_size(),
+///     ksk.polynomial_size(),
+///     ksk.decomposition_base_log(),
+///     ksk.decomposition_level_count(),
+/// );
+///
+/// convert_standard_glwe_keyswitch_key_to_fourier(&ksk, &mut fourier_ksk);
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let plaintext_list = PlaintextList::new(msg << 60, PlaintextCount(polynomial_size.0));
+///
+/// // Create a new GlweCiphertext
+/// let mut input_glwe = GlweCiphertext::new(
+///     0u64,
+///     input_glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_glwe_ciphertext(
+///     &input_glwe_secret_key,
+///     &mut input_glwe,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_glwe = GlweCiphertext::new(
+///     0u64,
+///     output_glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// keyswitch_glwe_ciphertext_with_fourier_key(&fourier_ksk, &input_glwe, &mut output_glwe);
+///
+/// let mut decrypted_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(
+///     &output_glwe_secret_key,
+///     &output_glwe,
+///     &mut decrypted_plaintext_list,
+/// );
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// decrypted_plaintext_list
+///     .iter_mut()
+///     .for_each(|elt| *elt.0 = decomposer.closest_representable(*elt.0) >> 60);
+///
+/// // Check we recovered the original message for each plaintext we encrypted
+/// assert!(decrypted_plaintext_list.iter().all(|elt| *elt.0 == msg));
+/// ```
+pub fn keyswitch_glwe_ciphertext_with_fourier_key<Scalar, KeyCont, InputCont, OutputCont>(
+    glwe_keyswitch_key: &FourierGlweKeyswitchKey<KeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(glwe_keyswitch_key.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        keyswitch_glwe_ciphertext_with_fourier_key_mem_optimized_requirement::<Scalar>(
+            input_glwe_ciphertext.glwe_size(),
+            glwe_keyswitch_key.output_glwe_size(),
+            glwe_keyswitch_key.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    keyswitch_glwe_ciphertext_with_fourier_key_mem_optimized(
+        glwe_keyswitch_key,
+        input_glwe_ciphertext,
+        output_glwe_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+/// Memory optimized version of [`keyswitch_glwe_ciphertext_with_fourier_key`], the caller must
+/// provide a properly configured [`FftView`] object and a `PodStack` used as a memory buffer
+/// having a capacity at least as large as the result of
+/// [`keyswitch_glwe_ciphertext_with_fourier_key_mem_optimized_requirement`].
+pub fn keyswitch_glwe_ciphertext_with_fourier_key_mem_optimized<
+    Scalar,
+    KeyCont,
+    InputCont,
+    OutputCont,
+>(
+    glwe_keyswitch_key: &FourierGlweKeyswitchKey<KeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    check_glwe_keyswitch_parameters(
+        glwe_keyswitch_key.input_key_glwe_dimension(),
+        glwe_keyswitch_key.output_glwe_size(),
+        glwe_keyswitch_key.polynomial_size(),
+        input_glwe_ciphertext.as_view(),
+        output_glwe_ciphertext.as_view(),
+    );
+
+    glwe_keyswitch_key.as_view().keyswitch(
+        output_glwe_ciphertext.as_mut_view(),
+        input_glwe_ciphertext.as_view(),
+        fft,
+        stack,
+    );
+}
+
+/// Return the required memory for [`keyswitch_glwe_ciphertext_with_fourier_key_mem_optimized`].
+pub fn keyswitch_glwe_ciphertext_with_fourier_key_mem_optimized_requirement<Scalar>(
+    input_glwe_size: GlweSize,
+    output_glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    glwe_keyswitch_scratch::<Scalar>(input_glwe_size, output_glwe_size, polynomial_size, fft)
+}
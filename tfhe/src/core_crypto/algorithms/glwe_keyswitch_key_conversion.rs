@@ -0,0 +1,102 @@
+//! Module containing primitives pertaining to the conversion of
+//! [`standard GLWE keyswitch keys`](`GlweKeyswitchKey`) to the Fourier domain.
+
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::crypto::glwe_keyswitch::fill_with_forward_fourier_scratch;
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use concrete_fft::c64;
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+/// Convert a [`GLWE keyswitch key`](`GlweKeyswitchKey`) with standard coefficients to the Fourier
+/// domain.
+///
+/// See [`keyswitch_glwe_ciphertext_with_fourier_key`](`crate::core_crypto::algorithms::keyswitch_glwe_ciphertext_with_fourier_key`) for usage.
+pub fn convert_standard_glwe_keyswitch_key_to_fourier<Scalar, InputCont, OutputCont>(
+    input_ksk: &GlweKeyswitchKey<InputCont>,
+    output_ksk: &mut FourierGlweKeyswitchKey<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(input_ksk.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        convert_standard_glwe_keyswitch_key_to_fourier_mem_optimized_requirement(fft)
+            .unwrap()
+            .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    convert_standard_glwe_keyswitch_key_to_fourier_mem_optimized(input_ksk, output_ksk, fft, stack);
+}
+
+/// Memory optimized version of [`convert_standard_glwe_keyswitch_key_to_fourier`].
+pub fn convert_standard_glwe_keyswitch_key_to_fourier_mem_optimized<Scalar, InputCont, OutputCont>(
+    input_ksk: &GlweKeyswitchKey<InputCont>,
+    output_ksk: &mut FourierGlweKeyswitchKey<OutputCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = c64>,
+{
+    assert_eq!(
+        input_ksk.polynomial_size(),
+        output_ksk.polynomial_size(),
+        "Mismatched PolynomialSize between input_ksk {:?} and output_ksk {:?}",
+        input_ksk.polynomial_size(),
+        output_ksk.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_ksk.output_glwe_size(),
+        output_ksk.output_glwe_size(),
+        "Mismatched output GlweSize between input_ksk {:?} and output_ksk {:?}",
+        input_ksk.output_glwe_size(),
+        output_ksk.output_glwe_size(),
+    );
+
+    assert_eq!(
+        input_ksk.decomposition_base_log(),
+        output_ksk.decomposition_base_log(),
+        "Mismatched DecompositionBaseLog between input_ksk {:?} and output_ksk {:?}",
+        input_ksk.decomposition_base_log(),
+        output_ksk.decomposition_base_log(),
+    );
+
+    assert_eq!(
+        input_ksk.decomposition_level_count(),
+        output_ksk.decomposition_level_count(),
+        "Mismatched DecompositionLevelCount between input_ksk {:?} and output_ksk {:?}",
+        input_ksk.decomposition_level_count(),
+        output_ksk.decomposition_level_count(),
+    );
+
+    assert_eq!(
+        input_ksk.input_key_glwe_dimension(),
+        output_ksk.input_key_glwe_dimension(),
+        "Mismatched input GlweDimension between input_ksk {:?} and output_ksk {:?}",
+        input_ksk.input_key_glwe_dimension(),
+        output_ksk.input_key_glwe_dimension(),
+    );
+
+    output_ksk
+        .as_mut_view()
+        .fill_with_forward_fourier(input_ksk.as_view(), fft, stack);
+}
+
+/// Return the required memory for
+/// [`convert_standard_glwe_keyswitch_key_to_fourier_mem_optimized`].
+pub fn convert_standard_glwe_keyswitch_key_to_fourier_mem_optimized_requirement(
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    fill_with_forward_fourier_scratch(fft)
+}
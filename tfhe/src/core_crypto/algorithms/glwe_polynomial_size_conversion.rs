@@ -0,0 +1,359 @@
+//! Module containing primitives pertaining to the conversion of
+//! [`GLWE ciphertexts`](`GlweCiphertext`) and [`GLWE secret keys`](`GlweSecretKey`) from one
+//! [`PolynomialSize`] to another.
+//!
+//! Converting a ciphertext to a smaller [`PolynomialSize`] `N / d` splits each mask polynomial
+//! into `d` polynomials and keeps the body coefficients with an index multiple of `d`, the output
+//! [`GlweDimension`] is `k * d`. Converting a ciphertext to a bigger [`PolynomialSize`] `N * d`
+//! maps each polynomial `P(X)` to `P(X^d)` and keeps the input [`GlweDimension`].
+//!
+//! In both cases the converted ciphertext decrypts under the key returned by
+//! [`convert_glwe_secret_key_polynomial_size`], a
+//! [`GLWE keyswitch key`](`crate::core_crypto::entities::GlweKeyswitchKey`) from that key to any
+//! key with the output [`PolynomialSize`] can then be used to get back to a key of choice.
+
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_monic_monomial_mul_assign;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Return the [`GlweDimension`] of the output of a [`PolynomialSize`] conversion for a GLWE
+/// secret key or ciphertext with the given input [`GlweDimension`] and [`PolynomialSize`].
+///
+/// # Panics
+///
+/// Panics if the biggest [`PolynomialSize`] is not a multiple of the smallest.
+pub fn glwe_polynomial_size_conversion_output_glwe_dimension(
+    input_glwe_dimension: GlweDimension,
+    input_polynomial_size: PolynomialSize,
+    output_polynomial_size: PolynomialSize,
+) -> GlweDimension {
+    if output_polynomial_size.0 <= input_polynomial_size.0 {
+        assert_eq!(
+            input_polynomial_size.0 % output_polynomial_size.0,
+            0,
+            "The input PolynomialSize {input_polynomial_size:?} is not a multiple of \
+            the output PolynomialSize {output_polynomial_size:?}"
+        );
+        GlweDimension(input_glwe_dimension.0 * (input_polynomial_size.0 / output_polynomial_size.0))
+    } else {
+        assert_eq!(
+            output_polynomial_size.0 % input_polynomial_size.0,
+            0,
+            "The output PolynomialSize {output_polynomial_size:?} is not a multiple of \
+            the input PolynomialSize {input_polynomial_size:?}"
+        );
+        input_glwe_dimension
+    }
+}
+
+/// Split `input` in `output.len() / input.len()` interleaved parts (shrinking case) or spread it
+/// with a stride of `output.len() / input.len()` (growing case).
+fn convert_polynomial_list_polynomial_size<Scalar: UnsignedInteger>(
+    input: PolynomialListView<'_, Scalar>,
+    mut output: PolynomialListMutView<'_, Scalar>,
+) {
+    let input_polynomial_size = input.polynomial_size();
+    let output_polynomial_size = output.polynomial_size();
+
+    if output_polynomial_size.0 <= input_polynomial_size.0 {
+        let ratio = input_polynomial_size.0 / output_polynomial_size.0;
+        for (input_polynomial, mut output_polynomials) in
+            input.iter().zip(output.chunks_exact_mut(ratio))
+        {
+            for (part_index, mut output_polynomial) in output_polynomials.iter_mut().enumerate() {
+                for (dst, &src) in output_polynomial.as_mut().iter_mut().zip(
+                    input_polynomial
+                        .as_ref()
+                        .iter()
+                        .skip(part_index)
+                        .step_by(ratio),
+                ) {
+                    *dst = src;
+                }
+            }
+        }
+    } else {
+        let ratio = output_polynomial_size.0 / input_polynomial_size.0;
+        for (input_polynomial, mut output_polynomial) in input.iter().zip(output.iter_mut()) {
+            output_polynomial.as_mut().fill(Scalar::ZERO);
+            for (dst, &src) in output_polynomial
+                .as_mut()
+                .iter_mut()
+                .step_by(ratio)
+                .zip(input_polynomial.as_ref().iter())
+            {
+                *dst = src;
+            }
+        }
+    }
+}
+
+/// Fill the output [`GLWE secret key`](`GlweSecretKey`) with the key under which a
+/// [`GLWE ciphertext`](`GlweCiphertext`) converted using
+/// [`convert_glwe_ciphertext_polynomial_size`] decrypts, given the input key of the ciphertext.
+///
+/// The output key [`GlweDimension`] must be equal to the value returned by
+/// [`glwe_polynomial_size_conversion_output_glwe_dimension`].
+///
+/// See [`convert_glwe_ciphertext_polynomial_size`] for usage.
+pub fn convert_glwe_secret_key_polynomial_size<Scalar, InputCont, OutputCont>(
+    input_glwe_secret_key: &GlweSecretKey<InputCont>,
+    output_glwe_secret_key: &mut GlweSecretKey<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let input_polynomial_size = input_glwe_secret_key.polynomial_size();
+    let output_polynomial_size = output_glwe_secret_key.polynomial_size();
+    let expected_output_glwe_dimension = glwe_polynomial_size_conversion_output_glwe_dimension(
+        input_glwe_secret_key.glwe_dimension(),
+        input_polynomial_size,
+        output_polynomial_size,
+    );
+    assert_eq!(
+        output_glwe_secret_key.glwe_dimension(),
+        expected_output_glwe_dimension,
+        "Mismatched output GlweDimension. Expected {expected_output_glwe_dimension:?}, got {:?}.",
+        output_glwe_secret_key.glwe_dimension()
+    );
+
+    let mut output_polynomial_list = PolynomialListMutView::from_container(
+        output_glwe_secret_key.as_mut(),
+        output_polynomial_size,
+    );
+
+    if output_polynomial_size.0 < input_polynomial_size.0 {
+        // With Y = X^d, an input polynomial P(X) is split as the sum of X^t * P_t(Y) where
+        // P_t[m] = P[t + d * m]. The coefficients with an index multiple of d of the product
+        // A(X) * S(X) are then given by A_0 * S_0 + Y * sum_{t = 1}^{d - 1} A_t * S_{d - t}, so
+        // the output key polynomial associated with A_t is S_0 for t = 0 and Y * S_{d - t}
+        // otherwise.
+        let ratio = input_polynomial_size.0 / output_polynomial_size.0;
+        for (input_key_polynomial, mut output_key_polynomials) in input_glwe_secret_key
+            .as_polynomial_list()
+            .iter()
+            .zip(output_polynomial_list.chunks_exact_mut(ratio))
+        {
+            for (part_index, mut output_key_polynomial) in
+                output_key_polynomials.iter_mut().enumerate()
+            {
+                let source_part_index = (ratio - part_index) % ratio;
+                for (dst, &src) in output_key_polynomial.as_mut().iter_mut().zip(
+                    input_key_polynomial
+                        .as_ref()
+                        .iter()
+                        .skip(source_part_index)
+                        .step_by(ratio),
+                ) {
+                    *dst = src;
+                }
+
+                if part_index != 0 {
+                    polynomial_wrapping_monic_monomial_mul_assign(
+                        &mut output_key_polynomial,
+                        MonomialDegree(1),
+                    );
+                }
+            }
+        }
+    } else {
+        convert_polynomial_list_polynomial_size(
+            input_glwe_secret_key.as_polynomial_list(),
+            output_polynomial_list,
+        );
+    }
+}
+
+/// Allocate a new [`GLWE secret key`](`GlweSecretKey`) and fill it with the key under which a
+/// [`GLWE ciphertext`](`GlweCiphertext`) converted to the output [`PolynomialSize`] using
+/// [`convert_glwe_ciphertext_polynomial_size`] decrypts, given the input key of the ciphertext.
+///
+/// See [`convert_glwe_ciphertext_polynomial_size`] for usage.
+pub fn allocate_and_convert_glwe_secret_key_polynomial_size<Scalar, InputCont>(
+    input_glwe_secret_key: &GlweSecretKey<InputCont>,
+    output_polynomial_size: PolynomialSize,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    let mut output_glwe_secret_key = GlweSecretKeyOwned::new_empty_key(
+        Scalar::ZERO,
+        glwe_polynomial_size_conversion_output_glwe_dimension(
+            input_glwe_secret_key.glwe_dimension(),
+            input_glwe_secret_key.polynomial_size(),
+            output_polynomial_size,
+        ),
+        output_polynomial_size,
+    );
+
+    convert_glwe_secret_key_polynomial_size(input_glwe_secret_key, &mut output_glwe_secret_key);
+
+    output_glwe_secret_key
+}
+
+/// Convert an input [`GLWE ciphertext`](`GlweCiphertext`) to the [`PolynomialSize`] of the output
+/// [`GLWE ciphertext`](`GlweCiphertext`).
+///
+/// When converting from a [`PolynomialSize`] `N` to a smaller [`PolynomialSize`] `N / d` the
+/// output encrypts the input plaintext coefficients with an index multiple of `d`, when
+/// converting to a bigger [`PolynomialSize`] `N * d` the input plaintext coefficient `i` is
+/// encrypted in the output coefficient `i * d`. The output ciphertext decrypts under the key
+/// computed by [`convert_glwe_secret_key_polynomial_size`].
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweCiphertext creation
+/// let glwe_dimension = GlweDimension(1);
+/// let input_polynomial_size = PolynomialSize(2048);
+/// let output_polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let input_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     input_polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext
+/// let mut plaintext_list = PlaintextList::new(0u64, PlaintextCount(input_polynomial_size.0));
+/// plaintext_list
+///     .iter_mut()
+///     .enumerate()
+///     .for_each(|(idx, dst)| *dst.0 = (idx as u64 % 16) << 60);
+///
+/// // Create a new GlweCiphertext
+/// let mut input_glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     input_polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_glwe_ciphertext(
+///     &input_glwe_secret_key,
+///     &mut input_glwe,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let output_glwe_secret_key = allocate_and_convert_glwe_secret_key_polynomial_size(
+///     &input_glwe_secret_key,
+///     output_polynomial_size,
+/// );
+///
+/// let mut output_glwe = GlweCiphertext::new(
+///     0u64,
+///     output_glwe_secret_key.glwe_dimension().to_glwe_size(),
+///     output_polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// convert_glwe_ciphertext_polynomial_size(&input_glwe, &mut output_glwe);
+///
+/// let mut decrypted_plaintext_list =
+///     PlaintextList::new(0u64, PlaintextCount(output_polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(
+///     &output_glwe_secret_key,
+///     &output_glwe,
+///     &mut decrypted_plaintext_list,
+/// );
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// decrypted_plaintext_list
+///     .iter_mut()
+///     .for_each(|elt| *elt.0 = decomposer.closest_representable(*elt.0) >> 60);
+///
+/// // Check we recovered the even coefficients of the original message
+/// for (decrypted, expected) in decrypted_plaintext_list
+///     .iter()
+///     .zip(plaintext_list.iter().step_by(2))
+/// {
+///     assert_eq!(*decrypted.0, *expected.0 >> 60);
+/// }
+/// ```
+pub fn convert_glwe_ciphertext_polynomial_size<Scalar, InputCont, OutputCont>(
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let input_polynomial_size = input_glwe_ciphertext.polynomial_size();
+    let output_polynomial_size = output_glwe_ciphertext.polynomial_size();
+    let expected_output_glwe_dimension = glwe_polynomial_size_conversion_output_glwe_dimension(
+        input_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        input_polynomial_size,
+        output_polynomial_size,
+    );
+    assert_eq!(
+        output_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        expected_output_glwe_dimension,
+        "Mismatched output GlweDimension. Expected {expected_output_glwe_dimension:?}, got {:?}.",
+        output_glwe_ciphertext.glwe_size().to_glwe_dimension()
+    );
+    assert_eq!(
+        input_glwe_ciphertext.ciphertext_modulus(),
+        output_glwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus. \
+        Input GlweCiphertext CiphertextModulus: {:?}, \
+        output GlweCiphertext CiphertextModulus {:?}.",
+        input_glwe_ciphertext.ciphertext_modulus(),
+        output_glwe_ciphertext.ciphertext_modulus()
+    );
+
+    convert_polynomial_list_polynomial_size(
+        input_glwe_ciphertext.get_mask().as_polynomial_list(),
+        output_glwe_ciphertext
+            .get_mut_mask()
+            .as_mut_polynomial_list(),
+    );
+
+    let input_body = input_glwe_ciphertext.get_body();
+    let mut output_body = output_glwe_ciphertext.get_mut_body();
+
+    if output_polynomial_size.0 <= input_polynomial_size.0 {
+        // Only the coefficients with an index multiple of the ratio are kept
+        let ratio = input_polynomial_size.0 / output_polynomial_size.0;
+        for (dst, &src) in output_body
+            .as_mut()
+            .iter_mut()
+            .zip(input_body.as_ref().iter().step_by(ratio))
+        {
+            *dst = src;
+        }
+    } else {
+        let ratio = output_polynomial_size.0 / input_polynomial_size.0;
+        output_body.as_mut().fill(Scalar::ZERO);
+        for (dst, &src) in output_body
+            .as_mut()
+            .iter_mut()
+            .step_by(ratio)
+            .zip(input_body.as_ref().iter())
+        {
+            *dst = src;
+        }
+    }
+}
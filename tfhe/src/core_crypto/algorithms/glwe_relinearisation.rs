@@ -0,0 +1,212 @@
+//! Module containing primitives pertaining to the relinearisation of
+//! [`GLWE tensor products`](`super::glwe_tensor_product()`) using
+//! [`GLWE relinearisation keys`](`GlweRelinearisationKey`).
+
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_add_assign;
+use crate::core_crypto::algorithms::{
+    glwe_tensor_product, glwe_tensor_product_output_glwe_dimension, keyswitch_glwe_ciphertext,
+};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Relinearise the output of a [`GLWE tensor product`](`super::glwe_tensor_product()`) using a
+/// [`GLWE relinearisation key`](`GlweRelinearisationKey`) and write the result in the output
+/// [`GLWE ciphertext`](`GlweCiphertext`), encrypted under the original
+/// [`GLWE secret key`](`GlweSecretKey`).
+///
+/// The quadratic part of the input is keyswitched from the key made of the products of pairs of
+/// secret key polynomials to the original key and the linear part of the input is added to the
+/// mask of the result.
+///
+/// See [`glwe_tensor_product_with_relinearisation`] for usage.
+pub fn glwe_relinearisation<Scalar, KeyCont, InputCont, OutputCont>(
+    glwe_relinearisation_key: &GlweRelinearisationKey<KeyCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let glwe_dimension = glwe_relinearisation_key.glwe_dimension();
+    let polynomial_size = glwe_relinearisation_key.polynomial_size();
+
+    assert!(
+        input_glwe_ciphertext.glwe_size().to_glwe_dimension()
+            == glwe_tensor_product_output_glwe_dimension(glwe_dimension),
+        "The input GlweCiphertext GlweDimension ({:?}) is not the one of a tensor product of \
+        ciphertexts with the GlweRelinearisationKey GlweDimension {:?}, expected {:?}.",
+        input_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        glwe_dimension,
+        glwe_tensor_product_output_glwe_dimension(glwe_dimension)
+    );
+    assert!(
+        input_glwe_ciphertext.polynomial_size() == polynomial_size,
+        "Mismatched PolynomialSize between the GlweRelinearisationKey ({:?}) \
+        and the input GlweCiphertext ({:?}).",
+        polynomial_size,
+        input_glwe_ciphertext.polynomial_size()
+    );
+
+    let (linear_part, quadratic_part) = input_glwe_ciphertext
+        .as_ref()
+        .split_at(glwe_dimension.0 * polynomial_size.0);
+
+    // The quadratic terms followed by the body form a ciphertext under the key made of the
+    // products of pairs of secret key polynomials
+    let quadratic_glwe_ciphertext = GlweCiphertext::from_container(
+        quadratic_part,
+        polynomial_size,
+        input_glwe_ciphertext.ciphertext_modulus(),
+    );
+
+    keyswitch_glwe_ciphertext(
+        &glwe_relinearisation_key.as_glwe_keyswitch_key(),
+        &quadratic_glwe_ciphertext,
+        output_glwe_ciphertext,
+    );
+
+    slice_wrapping_add_assign(output_glwe_ciphertext.get_mut_mask().as_mut(), linear_part);
+}
+
+/// Compute the product of the messages encrypted in two [`GLWE ciphertexts`](`GlweCiphertext`)
+/// scaled by `scale`, the output [`GLWE ciphertext`](`GlweCiphertext`) encrypts the product
+/// scaled by `scale` under the same [`GLWE secret key`](`GlweSecretKey`) as the inputs.
+///
+/// This is a [`GLWE tensor product`](`super::glwe_tensor_product()`) followed by a
+/// [`relinearisation`](`glwe_relinearisation`), the `scale` follows the same constraints as for
+/// the tensor product. The noise of the product grows with the message modulus and the
+/// [`PolynomialSize`](`crate::core_crypto::commons::parameters::PolynomialSize`), it is suited to
+/// small message spaces and low depth computations.
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweRelinearisationKey creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let decomp_base_log = DecompositionBaseLog(8);
+/// let decomp_level_count = DecompositionLevelCount(7);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let relin_key = allocate_and_generate_new_glwe_relinearisation_key(
+///     &glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // 4 bits messages with a bit of padding
+/// let delta = 1u64 << 59;
+/// let msgs = [(2u64, 3u64), (1, 7), (3, 5), (0, 9)];
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 5 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+///
+/// for (lhs_msg, rhs_msg) in msgs {
+///     // Encrypt the messages as constant polynomials
+///     let [lhs, rhs] = [lhs_msg, rhs_msg].map(|msg| {
+///         let mut plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///         *plaintext_list.get_mut(0).0 = msg * delta;
+///
+///         let mut glwe = GlweCiphertext::new(
+///             0u64,
+///             glwe_dimension.to_glwe_size(),
+///             polynomial_size,
+///             ciphertext_modulus,
+///         );
+///         encrypt_glwe_ciphertext(
+///             &glwe_secret_key,
+///             &mut glwe,
+///             &plaintext_list,
+///             glwe_noise_distribution,
+///             &mut encryption_generator,
+///         );
+///         glwe
+///     });
+///
+///     let mut output_glwe = GlweCiphertext::new(
+///         0u64,
+///         glwe_dimension.to_glwe_size(),
+///         polynomial_size,
+///         ciphertext_modulus,
+///     );
+///
+///     glwe_tensor_product_with_relinearisation(&relin_key, &lhs, &rhs, &mut output_glwe, delta);
+///
+///     let mut decrypted_plaintext_list =
+///         PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+///     decrypt_glwe_ciphertext(
+///         &glwe_secret_key,
+///         &output_glwe,
+///         &mut decrypted_plaintext_list,
+///     );
+///
+///     // The product of constant polynomials is a constant polynomial
+///     for (degree, elt) in decrypted_plaintext_list.iter().enumerate() {
+///         let decoded = decomposer.closest_representable(*elt.0) >> 59;
+///         let expected = if degree == 0 { lhs_msg * rhs_msg } else { 0 };
+///         assert_eq!(decoded, expected);
+///     }
+/// }
+/// ```
+pub fn glwe_tensor_product_with_relinearisation<Scalar, KeyCont, LhsCont, RhsCont, OutputCont>(
+    glwe_relinearisation_key: &GlweRelinearisationKey<KeyCont>,
+    input_glwe_ciphertext_lhs: &GlweCiphertext<LhsCont>,
+    input_glwe_ciphertext_rhs: &GlweCiphertext<RhsCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    scale: Scalar,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let mut tensor_product_glwe_ciphertext = GlweCiphertext::new(
+        Scalar::ZERO,
+        glwe_tensor_product_output_glwe_dimension(
+            input_glwe_ciphertext_lhs.glwe_size().to_glwe_dimension(),
+        )
+        .to_glwe_size(),
+        input_glwe_ciphertext_lhs.polynomial_size(),
+        input_glwe_ciphertext_lhs.ciphertext_modulus(),
+    );
+
+    glwe_tensor_product(
+        input_glwe_ciphertext_lhs,
+        input_glwe_ciphertext_rhs,
+        &mut tensor_product_glwe_ciphertext,
+        scale,
+    );
+
+    glwe_relinearisation(
+        glwe_relinearisation_key,
+        &tensor_product_glwe_ciphertext,
+        output_glwe_ciphertext,
+    );
+}
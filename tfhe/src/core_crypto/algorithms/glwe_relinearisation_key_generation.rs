@@ -0,0 +1,148 @@
+//! Module containing primitives pertaining to [`GLWE relinearisation keys
+//! generation`](`GlweRelinearisationKey`).
+
+use crate::core_crypto::algorithms::{
+    allocate_and_compute_glwe_tensor_product_secret_key, generate_glwe_keyswitch_key,
+};
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::random::{Distribution, Uniform};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Fill a [`GLWE relinearisation key`](`GlweRelinearisationKey`) with an actual key allowing to
+/// relinearise the [`tensor product`](`super::glwe_tensor_product()`) of ciphertexts encrypted
+/// under the input [`GLWE secret key`](`GlweSecretKey`).
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweRelinearisationKey creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let decomp_base_log = DecompositionBaseLog(8);
+/// let decomp_level_count = DecompositionLevelCount(7);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let mut relin_key = GlweRelinearisationKey::new(
+///     0u64,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_dimension,
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// generate_glwe_relinearisation_key(
+///     &glwe_secret_key,
+///     &mut relin_key,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// assert!(relin_key.as_ref().iter().all(|&x| x == 0) == false);
+/// ```
+pub fn generate_glwe_relinearisation_key<Scalar, NoiseDistribution, KeyCont, RelinKeyCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    glwe_relinearisation_key: &mut GlweRelinearisationKey<RelinKeyCont>,
+    noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    RelinKeyCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        glwe_relinearisation_key.glwe_dimension() == glwe_secret_key.glwe_dimension(),
+        "The destination GlweRelinearisationKey GlweDimension is not equal \
+        to the GlweSecretKey GlweDimension. Destination: {:?}, key: {:?}",
+        glwe_relinearisation_key.glwe_dimension(),
+        glwe_secret_key.glwe_dimension()
+    );
+    assert!(
+        glwe_relinearisation_key.polynomial_size() == glwe_secret_key.polynomial_size(),
+        "The destination GlweRelinearisationKey PolynomialSize is not equal \
+        to the GlweSecretKey PolynomialSize. Destination: {:?}, key: {:?}",
+        glwe_relinearisation_key.polynomial_size(),
+        glwe_secret_key.polynomial_size()
+    );
+
+    let glwe_dimension = glwe_secret_key.glwe_dimension();
+    let polynomial_size = glwe_secret_key.polynomial_size();
+
+    // The tensor product key starts with the original key polynomials, the quadratic part made of
+    // the products S_i S_j is the key the relinearisation switches from
+    let tensor_product_secret_key =
+        allocate_and_compute_glwe_tensor_product_secret_key(glwe_secret_key);
+    let quadratic_secret_key = GlweSecretKey::from_container(
+        &tensor_product_secret_key.as_ref()[glwe_dimension.0 * polynomial_size.0..],
+        polynomial_size,
+    );
+
+    generate_glwe_keyswitch_key(
+        &quadratic_secret_key,
+        glwe_secret_key,
+        &mut glwe_relinearisation_key.as_mut_glwe_keyswitch_key(),
+        noise_distribution,
+        generator,
+    );
+}
+
+/// Allocate a new [`GLWE relinearisation key`](`GlweRelinearisationKey`) and fill it with an
+/// actual key allowing to relinearise the [`tensor product`](`super::glwe_tensor_product()`) of
+/// ciphertexts encrypted under the input [`GLWE secret key`](`GlweSecretKey`).
+///
+/// See [`super::glwe_tensor_product_with_relinearisation`] for usage.
+pub fn allocate_and_generate_new_glwe_relinearisation_key<Scalar, NoiseDistribution, KeyCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_distribution: NoiseDistribution,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> GlweRelinearisationKeyOwned<Scalar>
+where
+    Scalar: Encryptable<Uniform, NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut new_glwe_relinearisation_key = GlweRelinearisationKeyOwned::new(
+        Scalar::ZERO,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_secret_key.glwe_dimension(),
+        glwe_secret_key.polynomial_size(),
+        ciphertext_modulus,
+    );
+
+    generate_glwe_relinearisation_key(
+        glwe_secret_key,
+        &mut new_glwe_relinearisation_key,
+        noise_distribution,
+        generator,
+    );
+
+    new_glwe_relinearisation_key
+}
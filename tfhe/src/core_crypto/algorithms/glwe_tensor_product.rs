@@ -0,0 +1,396 @@
+//! Module containing primitives pertaining to the tensor product of
+//! [`GLWE ciphertexts`](`GlweCiphertext`), allowing to multiply the messages of two ciphertexts
+//! encrypted with a BFV-like encoding.
+//!
+//! The tensor product of two ciphertexts is encrypted under a larger key made of the polynomials
+//! of the original [`GLWE secret key`](`GlweSecretKey`) followed by the products of pairs of its
+//! polynomials, see [`allocate_and_compute_glwe_tensor_product_secret_key`]. It can be brought
+//! back to the original key with a [`GLWE relinearisation key`](`GlweRelinearisationKey`), see
+//! [`glwe_relinearisation`](`crate::core_crypto::algorithms::glwe_relinearisation()`).
+
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_mul;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft128::math::fft::{Fft128, Fft128View};
+use aligned_vec::CACHELINE_ALIGN;
+use concrete_fft::fft128::f128;
+use dyn_stack::{PodStack, ReborrowMut, SizeOverflow, StackReq};
+
+/// Return the [`GlweDimension`] of the output of the tensor product of two
+/// [`GLWE ciphertexts`](`GlweCiphertext`) with a given [`GlweDimension`].
+///
+/// For an input dimension $k$ this is $k + k(k+1)/2$: the $k$ linear terms followed by the
+/// products of pairs of secret key polynomials.
+pub fn glwe_tensor_product_output_glwe_dimension(
+    input_glwe_dimension: GlweDimension,
+) -> GlweDimension {
+    GlweDimension(
+        input_glwe_dimension.0
+            + glwe_relinearisation_key_input_key_glwe_dimension(input_glwe_dimension).0,
+    )
+}
+
+/// Allocate a new [`GLWE secret key`](`GlweSecretKey`) able to decrypt the output of a
+/// [`glwe_tensor_product`] of ciphertexts encrypted under the input key.
+///
+/// The output key contains the polynomials $S\_i$ of the input key followed by the products
+/// $S\_i S\_j$ for $i \leq j$ in lexicographic order.
+pub fn allocate_and_compute_glwe_tensor_product_secret_key<Scalar, KeyCont>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+{
+    let glwe_dimension = glwe_secret_key.glwe_dimension();
+    let polynomial_size = glwe_secret_key.polynomial_size();
+
+    let mut tensor_product_secret_key = GlweSecretKey::new_empty_key(
+        Scalar::ZERO,
+        glwe_tensor_product_output_glwe_dimension(glwe_dimension),
+        polynomial_size,
+    );
+
+    let (linear_part, quadratic_part) = tensor_product_secret_key
+        .as_mut()
+        .split_at_mut(glwe_dimension.0 * polynomial_size.0);
+
+    linear_part.copy_from_slice(glwe_secret_key.as_ref());
+
+    let key_polynomials = glwe_secret_key.as_polynomial_list();
+    let mut quadratic_polynomials = quadratic_part
+        .chunks_exact_mut(polynomial_size.0)
+        .map(Polynomial::from_container);
+
+    for i in 0..glwe_dimension.0 {
+        for j in i..glwe_dimension.0 {
+            let mut output_polynomial = quadratic_polynomials.next().unwrap();
+            polynomial_wrapping_mul(
+                &mut output_polynomial,
+                &key_polynomials.get(i),
+                &key_polynomials.get(j),
+            );
+        }
+    }
+
+    tensor_product_secret_key
+}
+
+/// Compute the tensor product of two [`GLWE ciphertexts`](`GlweCiphertext`) encrypting messages
+/// scaled by `scale` and write the result rescaled by `1 / scale` in the output
+/// [`GLWE ciphertext`](`GlweCiphertext`).
+///
+/// The output has a [`GlweDimension`] given by [`glwe_tensor_product_output_glwe_dimension`] and
+/// encrypts the product of the input messages scaled by `scale` under the key returned by
+/// [`allocate_and_compute_glwe_tensor_product_secret_key`].
+///
+/// The products are computed with a 128 bits FFT, the `scale` is expressed like the plaintexts
+/// encrypted under the [`CiphertextModulus`] of the inputs and must be a power of 2.
+///
+/// See [`crate::core_crypto::algorithms::glwe_tensor_product_with_relinearisation`] for usage.
+pub fn glwe_tensor_product<Scalar, LhsCont, RhsCont, OutputCont>(
+    input_glwe_ciphertext_lhs: &GlweCiphertext<LhsCont>,
+    input_glwe_ciphertext_rhs: &GlweCiphertext<RhsCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    scale: Scalar,
+) where
+    Scalar: UnsignedTorus,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft128::new(input_glwe_ciphertext_lhs.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        glwe_tensor_product_mem_optimized_requirement::<Scalar>(
+            input_glwe_ciphertext_lhs.glwe_size(),
+            input_glwe_ciphertext_lhs.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    glwe_tensor_product_mem_optimized(
+        input_glwe_ciphertext_lhs,
+        input_glwe_ciphertext_rhs,
+        output_glwe_ciphertext,
+        scale,
+        fft,
+        stack,
+    );
+}
+
+/// Memory optimized version of [`glwe_tensor_product`], the caller must provide a properly
+/// configured [`Fft128View`] object and a `PodStack` used as a memory buffer having a capacity at
+/// least as large as the result of [`glwe_tensor_product_mem_optimized_requirement`].
+pub fn glwe_tensor_product_mem_optimized<Scalar, LhsCont, RhsCont, OutputCont>(
+    input_glwe_ciphertext_lhs: &GlweCiphertext<LhsCont>,
+    input_glwe_ciphertext_rhs: &GlweCiphertext<RhsCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    scale: Scalar,
+    fft: Fft128View<'_>,
+    stack: PodStack<'_>,
+) where
+    Scalar: UnsignedTorus,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert!(
+        input_glwe_ciphertext_lhs.glwe_size() == input_glwe_ciphertext_rhs.glwe_size(),
+        "Mismatched GlweSize between lhs ({:?}) and rhs ({:?}) GlweCiphertext.",
+        input_glwe_ciphertext_lhs.glwe_size(),
+        input_glwe_ciphertext_rhs.glwe_size()
+    );
+    assert!(
+        input_glwe_ciphertext_lhs.polynomial_size() == input_glwe_ciphertext_rhs.polynomial_size(),
+        "Mismatched PolynomialSize between lhs ({:?}) and rhs ({:?}) GlweCiphertext.",
+        input_glwe_ciphertext_lhs.polynomial_size(),
+        input_glwe_ciphertext_rhs.polynomial_size()
+    );
+    assert!(
+        input_glwe_ciphertext_lhs.polynomial_size() == output_glwe_ciphertext.polynomial_size(),
+        "Mismatched PolynomialSize between input ({:?}) and output ({:?}) GlweCiphertext.",
+        input_glwe_ciphertext_lhs.polynomial_size(),
+        output_glwe_ciphertext.polynomial_size()
+    );
+    assert!(
+        input_glwe_ciphertext_lhs.ciphertext_modulus()
+            == input_glwe_ciphertext_rhs.ciphertext_modulus(),
+        "Mismatched CiphertextModulus between lhs ({:?}) and rhs ({:?}) GlweCiphertext.",
+        input_glwe_ciphertext_lhs.ciphertext_modulus(),
+        input_glwe_ciphertext_rhs.ciphertext_modulus()
+    );
+    assert!(
+        input_glwe_ciphertext_lhs.ciphertext_modulus()
+            == output_glwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus between input ({:?}) and output ({:?}) GlweCiphertext.",
+        input_glwe_ciphertext_lhs.ciphertext_modulus(),
+        output_glwe_ciphertext.ciphertext_modulus()
+    );
+
+    let glwe_dimension = input_glwe_ciphertext_lhs.glwe_size().to_glwe_dimension();
+    let output_glwe_dimension = glwe_tensor_product_output_glwe_dimension(glwe_dimension);
+
+    assert!(
+        output_glwe_ciphertext.glwe_size().to_glwe_dimension() == output_glwe_dimension,
+        "The output GlweCiphertext GlweDimension ({:?}) is not the one expected for a tensor \
+        product of inputs with GlweDimension {:?}, expected {:?}.",
+        output_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        glwe_dimension,
+        output_glwe_dimension
+    );
+
+    let ciphertext_modulus = output_glwe_ciphertext.ciphertext_modulus();
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "This operation currently only supports power of 2 moduli"
+    );
+    assert!(
+        scale.is_power_of_two(),
+        "The tensor product scale must be a power of 2, got {scale:?}."
+    );
+
+    let align = CACHELINE_ALIGN;
+    let polynomial_size = input_glwe_ciphertext_lhs.polynomial_size();
+    let fourier_polynomial_size = polynomial_size.0 / 2;
+    let fourier_len = input_glwe_ciphertext_lhs.glwe_size().0 * fourier_polynomial_size;
+
+    let (mut lhs_re0, stack) = stack.make_aligned_raw::<f64>(fourier_len, align);
+    let (mut lhs_re1, stack) = stack.make_aligned_raw::<f64>(fourier_len, align);
+    let (mut lhs_im0, stack) = stack.make_aligned_raw::<f64>(fourier_len, align);
+    let (mut lhs_im1, stack) = stack.make_aligned_raw::<f64>(fourier_len, align);
+    let (mut rhs_re0, stack) = stack.make_aligned_raw::<f64>(fourier_len, align);
+    let (mut rhs_re1, stack) = stack.make_aligned_raw::<f64>(fourier_len, align);
+    let (mut rhs_im0, stack) = stack.make_aligned_raw::<f64>(fourier_len, align);
+    let (mut rhs_im1, stack) = stack.make_aligned_raw::<f64>(fourier_len, align);
+    let (mut acc_re0, stack) = stack.make_aligned_raw::<f64>(fourier_polynomial_size, align);
+    let (mut acc_re1, stack) = stack.make_aligned_raw::<f64>(fourier_polynomial_size, align);
+    let (mut acc_im0, stack) = stack.make_aligned_raw::<f64>(fourier_polynomial_size, align);
+    let (mut acc_im1, mut stack) = stack.make_aligned_raw::<f64>(fourier_polynomial_size, align);
+
+    // The lhs is seen as an integer and the rhs as a torus element, so that the product of the
+    // two divided by the scale is the torus representation of the rescaled product
+    for (lhs_re0, lhs_re1, lhs_im0, lhs_im1, rhs_re0, rhs_re1, rhs_im0, rhs_im1, lhs, rhs) in izip!(
+        lhs_re0.chunks_exact_mut(fourier_polynomial_size),
+        lhs_re1.chunks_exact_mut(fourier_polynomial_size),
+        lhs_im0.chunks_exact_mut(fourier_polynomial_size),
+        lhs_im1.chunks_exact_mut(fourier_polynomial_size),
+        rhs_re0.chunks_exact_mut(fourier_polynomial_size),
+        rhs_re1.chunks_exact_mut(fourier_polynomial_size),
+        rhs_im0.chunks_exact_mut(fourier_polynomial_size),
+        rhs_im1.chunks_exact_mut(fourier_polynomial_size),
+        input_glwe_ciphertext_lhs.as_polynomial_list().iter(),
+        input_glwe_ciphertext_rhs.as_polynomial_list().iter(),
+    ) {
+        fft.forward_as_integer(lhs_re0, lhs_re1, lhs_im0, lhs_im1, lhs.as_ref());
+        fft.forward_as_torus(rhs_re0, rhs_re1, rhs_im0, rhs_im1, rhs.as_ref());
+    }
+
+    let lhs_fourier = [&*lhs_re0, &*lhs_re1, &*lhs_im0, &*lhs_im1];
+    let rhs_fourier = [&*rhs_re0, &*rhs_re1, &*rhs_im0, &*rhs_im1];
+
+    // The phase is body - <mask, key>, the product of the lhs and rhs phases gives linear terms
+    // B_l A_r_i + A_l_i B_r, quadratic terms -(A_l_i A_r_j + A_l_j A_r_i) for i <= j (a single
+    // product when i == j) and a body B_l B_r
+    let body_index = glwe_dimension.0;
+    let linear_terms =
+        (0..glwe_dimension.0).map(|i| vec![(body_index, i, false), (i, body_index, false)]);
+    let quadratic_terms = (0..glwe_dimension.0).flat_map(|i| {
+        (i..glwe_dimension.0).map(move |j| {
+            if i == j {
+                vec![(i, i, true)]
+            } else {
+                vec![(i, j, true), (j, i, true)]
+            }
+        })
+    });
+    let body_terms = core::iter::once(vec![(body_index, body_index, false)]);
+
+    // Values are stored on the native torus, the scale has to be brought there as well
+    let native_scale_log2 = scale.ilog2()
+        + ciphertext_modulus
+            .get_power_of_two_scaling_to_native_torus()
+            .ilog2();
+    let scale_inverse = 2.0f64.powi(-(native_scale_log2 as i32));
+
+    for (mut output_polynomial, terms) in output_glwe_ciphertext
+        .as_mut_polynomial_list()
+        .iter_mut()
+        .zip(linear_terms.chain(quadratic_terms).chain(body_terms))
+    {
+        acc_re0.fill(0.0);
+        acc_re1.fill(0.0);
+        acc_im0.fill(0.0);
+        acc_im1.fill(0.0);
+
+        for (lhs_index, rhs_index, subtract) in terms {
+            fourier_wrapping_mul_accumulate(
+                [&mut *acc_re0, &mut *acc_re1, &mut *acc_im0, &mut *acc_im1],
+                fourier_polynomial(lhs_fourier, lhs_index, fourier_polynomial_size),
+                fourier_polynomial(rhs_fourier, rhs_index, fourier_polynomial_size),
+                subtract,
+            );
+        }
+
+        // The scale is a power of 2, this multiplication is exact
+        for component in [&mut *acc_re0, &mut *acc_re1, &mut *acc_im0, &mut *acc_im1] {
+            component.iter_mut().for_each(|x| *x *= scale_inverse);
+        }
+
+        fft.backward_as_torus(
+            output_polynomial.as_mut(),
+            &acc_re0,
+            &acc_re1,
+            &acc_im0,
+            &acc_im1,
+            stack.rb_mut(),
+        );
+    }
+
+    if !ciphertext_modulus.is_native_modulus() {
+        // When we convert back from the fourier domain, integer values will contain information in
+        // the LSBs which are not part of our representation of power of 2 moduli < native
+        // modulus, this usage of the signed decomposer allows to round while keeping the data in
+        // the MSBs
+        let signed_decomposer = SignedDecomposer::new(
+            DecompositionBaseLog(ciphertext_modulus.get_custom_modulus().ilog2() as usize),
+            DecompositionLevelCount(1),
+        );
+        output_glwe_ciphertext
+            .as_mut()
+            .iter_mut()
+            .for_each(|x| *x = signed_decomposer.closest_representable(*x));
+    }
+}
+
+/// Return the required memory for [`glwe_tensor_product_mem_optimized`].
+pub fn glwe_tensor_product_mem_optimized_requirement<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: Fft128View<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let fourier_polynomial_size = polynomial_size.0 / 2;
+    let input_fourier =
+        StackReq::try_new_aligned::<f64>(glwe_size.0 * fourier_polynomial_size, align)?;
+    let accumulator = StackReq::try_new_aligned::<f64>(fourier_polynomial_size, align)?;
+
+    StackReq::try_all_of([
+        StackReq::try_all_of([input_fourier; 8])?,
+        StackReq::try_all_of([accumulator; 4])?,
+        fft.backward_scratch()?,
+    ])
+}
+
+/// Return the components of the polynomial at `index` in a list of Fourier polynomials.
+fn fourier_polynomial(
+    data: [&[f64]; 4],
+    index: usize,
+    fourier_polynomial_size: usize,
+) -> [&[f64]; 4] {
+    let range = index * fourier_polynomial_size..(index + 1) * fourier_polynomial_size;
+    data.map(|component| &component[range.clone()])
+}
+
+/// Add (or subtract) the product of two polynomials in the Fourier domain to an accumulator.
+fn fourier_wrapping_mul_accumulate(
+    accumulator: [&mut [f64]; 4],
+    lhs: [&[f64]; 4],
+    rhs: [&[f64]; 4],
+    subtract: bool,
+) {
+    let [acc_re0, acc_re1, acc_im0, acc_im1] = accumulator;
+    let [lhs_re0, lhs_re1, lhs_im0, lhs_im1] = lhs;
+    let [rhs_re0, rhs_re1, rhs_im0, rhs_im1] = rhs;
+
+    for (
+        acc_re0,
+        acc_re1,
+        acc_im0,
+        acc_im1,
+        lhs_re0,
+        lhs_re1,
+        lhs_im0,
+        lhs_im1,
+        rhs_re0,
+        rhs_re1,
+        rhs_im0,
+        rhs_im1,
+    ) in izip!(
+        acc_re0, acc_re1, acc_im0, acc_im1, lhs_re0, lhs_re1, lhs_im0, lhs_im1, rhs_re0, rhs_re1,
+        rhs_im0, rhs_im1,
+    ) {
+        let lhs_re = f128(*lhs_re0, *lhs_re1);
+        let lhs_im = f128(*lhs_im0, *lhs_im1);
+        let rhs_re = f128(*rhs_re0, *rhs_re1);
+        let rhs_im = f128(*rhs_im0, *rhs_im1);
+
+        let product_re = lhs_re * rhs_re - lhs_im * rhs_im;
+        let product_im = lhs_im * rhs_re + lhs_re * rhs_im;
+
+        let acc_re = f128(*acc_re0, *acc_re1);
+        let acc_im = f128(*acc_im0, *acc_im1);
+
+        let (acc_re, acc_im) = if subtract {
+            (acc_re - product_re, acc_im - product_im)
+        } else {
+            (acc_re + product_re, acc_im + product_im)
+        };
+
+        *acc_re0 = acc_re.0;
+        *acc_re1 = acc_re.1;
+        *acc_im0 = acc_im.0;
+        *acc_im1 = acc_im.1;
+    }
+}
@@ -1,3 +1,7 @@
+mod ntt64;
+
+pub use ntt64::*;
+
 use crate::core_crypto::algorithms::extract_lwe_sample_from_glwe_ciphertext;
 use crate::core_crypto::algorithms::polynomial_algorithms::*;
 use crate::core_crypto::algorithms::slice_algorithms::*;
@@ -148,6 +152,11 @@ pub fn prepare_multi_bit_ggsw_mem_optimized<GgswBufferCont, GgswGroupCont, Fouri
 /// table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE bootstrap
 /// key`](`LweMultiBitBootstrapKey`) in the fourier domain.
 ///
+/// The input [`LWE ciphertext`](`LweCiphertext`) can use a different integer type than the look-up
+/// table, e.g. a `u32` ciphertext output by
+/// [`keyswitch_lwe_ciphertext_with_scalar_change`](`super::keyswitch_lwe_ciphertext_with_scalar_change`)
+/// can be blind rotated with a `u64` look-up table.
+///
 /// # Example
 ///
 /// ```rust
@@ -307,7 +316,7 @@ pub fn prepare_multi_bit_ggsw_mem_optimized<GgswBufferCont, GgswGroupCont, Fouri
 ///     "Multiplication via PBS result is correct! Expected 6, got {pbs_multiplication_result}"
 /// );
 /// ```
-pub fn multi_bit_blind_rotate_assign<Scalar, InputCont, OutputCont, KeyCont>(
+pub fn multi_bit_blind_rotate_assign<InputScalar, OutputScalar, InputCont, OutputCont, KeyCont>(
     input: &LweCiphertext<InputCont>,
     accumulator: &mut GlweCiphertext<OutputCont>,
     multi_bit_bsk: &FourierLweMultiBitBootstrapKey<KeyCont>,
@@ -315,9 +324,10 @@ pub fn multi_bit_blind_rotate_assign<Scalar, InputCont, OutputCont, KeyCont>(
     deterministic_execution: bool,
 ) where
     // CastInto required for PBS modulus switch which returns a usize
-    Scalar: UnsignedTorus + CastInto<usize> + CastFrom<usize> + Sync,
-    InputCont: Container<Element = Scalar>,
-    OutputCont: ContainerMut<Element = Scalar>,
+    InputScalar: UnsignedTorus + CastInto<usize> + CastFrom<usize> + Sync,
+    OutputScalar: UnsignedTorus + Sync,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
     KeyCont: Container<Element = c64> + Sync,
 {
     assert_eq!(
@@ -329,12 +339,13 @@ pub fn multi_bit_blind_rotate_assign<Scalar, InputCont, OutputCont, KeyCont>(
         multi_bit_bsk.input_lwe_dimension(),
     );
 
-    assert_eq!(
-        input.ciphertext_modulus(),
-        accumulator.ciphertext_modulus(),
-        "Mismatched CiphertextModulus between input ({:?}) and accumulator ({:?})",
-        input.ciphertext_modulus(),
-        accumulator.ciphertext_modulus(),
+    assert!(
+        input.ciphertext_modulus().is_power_of_two(),
+        "This operation requires the input to have a power of two modulus."
+    );
+    assert!(
+        accumulator.ciphertext_modulus().is_power_of_two(),
+        "This operation requires the accumulator to have a power of two modulus."
     );
 
     let grouping_factor = multi_bit_bsk.grouping_factor();
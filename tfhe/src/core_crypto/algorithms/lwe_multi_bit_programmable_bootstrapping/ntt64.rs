@@ -0,0 +1,548 @@
+//! Module containing primitives pertaining to the [`LWE multi bit programmable
+//! bootstrap`](`LweMultiBitBootstrapKey`) using 64 bits NTT for polynomial multiplication.
+
+use super::selection_bit;
+use crate::core_crypto::algorithms::glwe_sample_extraction::extract_lwe_sample_from_glwe_ciphertext;
+use crate::core_crypto::algorithms::lwe_programmable_bootstrapping::ntt64::{
+    add_external_product_ntt64_assign, ntt64_add_external_product_assign_scratch,
+    pbs_modulus_switch_non_native,
+};
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::math::ntt::ntt64::{Ntt64, Ntt64View};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use itertools::Itertools;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Condvar, Mutex};
+use std::thread;
+
+/// Non native modulus variant of
+/// [`modulus_switch_multi_bit`](`super::modulus_switch_multi_bit`), the combinations of mask
+/// elements are computed modulo `modulus` before being switched to the `2 * polynomial_size`
+/// modulus used by the blind rotation.
+pub fn modulus_switch_multi_bit_non_native(
+    grouping_factor: LweBskGroupingFactor,
+    lwe_mask_elements: &[u64],
+    polynomial_size: PolynomialSize,
+    modulus: u64,
+) -> impl Iterator<Item = usize> + '_ {
+    // Start at 1, the first ggsw is not rotated
+    (1..grouping_factor.ggsw_per_multi_bit_element().0).map(move |power_set_index| {
+        let mut monomial_degree = 0u64;
+        for (&mask_element, selection_bit) in lwe_mask_elements
+            .iter()
+            .zip_eq(selection_bit(grouping_factor, power_set_index))
+        {
+            if selection_bit == 1 {
+                monomial_degree = monomial_degree.wrapping_add_custom_mod(mask_element, modulus);
+            }
+        }
+        pbs_modulus_switch_non_native(monomial_degree, polynomial_size, modulus)
+    })
+}
+
+/// Compute the [`NTT GGSW ciphertext`](`NttGgswCiphertext`) used for one step of a multi bit blind
+/// rotation from a group of GGSW ciphertexts of an [`NTT LWE multi bit bootstrap
+/// key`](`NttLweMultiBitBootstrapKey`) and the switched monomial degrees of the matching mask
+/// elements, the result is written in `ntt_ggsw_buffer`.
+///
+/// `ntt_a_monomial` is a buffer used to store the successive monomials in the NTT domain.
+pub fn prepare_multi_bit_ggsw_ntt64<GgswBufferCont, GgswGroupCont, PolyCont>(
+    ntt_ggsw_buffer: &mut NttGgswCiphertext<GgswBufferCont>,
+    ggsw_group: &[NttGgswCiphertext<GgswGroupCont>],
+    switched_degrees: impl Iterator<Item = usize>,
+    ntt_a_monomial: &mut Polynomial<PolyCont>,
+    ntt: Ntt64View<'_>,
+) where
+    GgswBufferCont: ContainerMut<Element = u64>,
+    GgswGroupCont: Container<Element = u64>,
+    PolyCont: ContainerMut<Element = u64>,
+{
+    let polynomial_size = ntt.polynomial_size();
+    let modulus = ntt.custom_modulus();
+    let mut ggsw_group_iter = ggsw_group.iter();
+
+    // Keygen guarantees the first term is a constant term of the polynomial, no
+    // polynomial multiplication required
+    let ggsw_a_none = ggsw_group_iter.next().unwrap();
+
+    ntt_ggsw_buffer
+        .as_mut()
+        .copy_from_slice(ggsw_a_none.as_ref());
+
+    for (ntt_ggsw, switched_degree) in ggsw_group_iter.zip_eq(switched_degrees) {
+        // X^degree for degree in [N, 2N) is -X^(degree - N) in the negacyclic ring
+        let switched_degree = switched_degree % (2 * polynomial_size.0);
+        ntt_a_monomial.as_mut().fill(0);
+        if switched_degree < polynomial_size.0 {
+            ntt_a_monomial.as_mut()[switched_degree] = 1;
+        } else {
+            ntt_a_monomial.as_mut()[switched_degree - polynomial_size.0] = modulus - 1;
+        }
+        // The key is normalized, the monomial must not be
+        ntt.plan.fwd(ntt_a_monomial.as_mut());
+
+        izip!(
+            ntt_ggsw_buffer.as_mut().into_chunks(polynomial_size.0),
+            ntt_ggsw.as_ref().into_chunks(polynomial_size.0)
+        )
+        .for_each(|(output_ntt, ggsw_poly)| {
+            ntt.plan
+                .mul_accumulate(output_ntt, ggsw_poly, ntt_a_monomial.as_ref());
+        });
+    }
+}
+
+/// Perform a blind rotation given an input [`LWE ciphertext`](`LweCiphertext`), modifying a look-up
+/// table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE multi bit bootstrap
+/// key`](`LweMultiBitBootstrapKey`) in the NTT domain see [`NTT LWE multi bit bootstrap
+/// key`](`NttLweMultiBitBootstrapKey`).
+///
+/// `thread_count` threads prepare the multi bit GGSW ciphertexts while the calling thread computes
+/// the external products. As the NTT is exact, the result does not depend on the scheduling of the
+/// threads.
+///
+/// See [`multi_bit_programmable_bootstrap_ntt64_lwe_ciphertext`] for usage.
+pub fn multi_bit_blind_rotate_ntt64_assign<InputCont, OutputCont, KeyCont>(
+    input: &LweCiphertext<InputCont>,
+    accumulator: &mut GlweCiphertext<OutputCont>,
+    multi_bit_bsk: &NttLweMultiBitBootstrapKey<KeyCont>,
+    thread_count: ThreadCount,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    KeyCont: Container<Element = u64>,
+{
+    assert_eq!(
+        input.lwe_size().to_lwe_dimension(),
+        multi_bit_bsk.input_lwe_dimension(),
+        "Mismatched input LweDimension. LweCiphertext input LweDimension {:?}. \
+        NttLweMultiBitBootstrapKey input LweDimension {:?}.",
+        input.lwe_size().to_lwe_dimension(),
+        multi_bit_bsk.input_lwe_dimension(),
+    );
+
+    assert_eq!(
+        accumulator.glwe_size(),
+        multi_bit_bsk.glwe_size(),
+        "Mismatched GlweSize. Accumulator GlweSize {:?}. \
+        NttLweMultiBitBootstrapKey GlweSize {:?}.",
+        accumulator.glwe_size(),
+        multi_bit_bsk.glwe_size(),
+    );
+
+    assert_eq!(
+        accumulator.polynomial_size(),
+        multi_bit_bsk.polynomial_size(),
+        "Mismatched PolynomialSize. Accumulator PolynomialSize {:?}. \
+        NttLweMultiBitBootstrapKey PolynomialSize {:?}.",
+        accumulator.polynomial_size(),
+        multi_bit_bsk.polynomial_size(),
+    );
+
+    assert_eq!(
+        input.ciphertext_modulus(),
+        accumulator.ciphertext_modulus(),
+        "Mismatched CiphertextModulus between input ({:?}) and accumulator ({:?})",
+        input.ciphertext_modulus(),
+        accumulator.ciphertext_modulus(),
+    );
+
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        multi_bit_bsk.ciphertext_modulus(),
+        "Mismatched CiphertextModulus between accumulator ({:?}) and \
+        NttLweMultiBitBootstrapKey ({:?})",
+        accumulator.ciphertext_modulus(),
+        multi_bit_bsk.ciphertext_modulus(),
+    );
+
+    assert!(
+        thread_count.0 != 0,
+        "Got thread_count == 0, this is not supported"
+    );
+
+    let ntt = Ntt64::new(
+        multi_bit_bsk.ciphertext_modulus(),
+        multi_bit_bsk.polynomial_size(),
+    );
+    let ntt = ntt.as_view();
+    let modulus = ntt.custom_modulus();
+
+    // No way to chunk the result of into_ggsw_iter at the moment
+    let ggsw_vec: Vec<_> = multi_bit_bsk.as_view().into_ggsw_iter().collect();
+
+    let grouping_factor = multi_bit_bsk.grouping_factor();
+    let ggsw_per_multi_bit_element = grouping_factor.ggsw_per_multi_bit_element();
+    let polynomial_size = multi_bit_bsk.polynomial_size();
+
+    let max_work_index = multi_bit_bsk.multi_bit_input_lwe_dimension().0;
+
+    let (lwe_body, lwe_mask) = input.as_ref().split_last().unwrap();
+
+    let monomial_degree = pbs_modulus_switch_non_native(*lwe_body, polynomial_size, modulus);
+
+    accumulator
+        .as_mut_polynomial_list()
+        .iter_mut()
+        .for_each(|mut poly| {
+            polynomial_wrapping_monic_monomial_div_assign_custom_mod(
+                &mut poly,
+                MonomialDegree(monomial_degree),
+                modulus,
+            );
+        });
+
+    let ntt_multi_bit_ggsw_buffers: Vec<_> = (0..thread_count.0)
+        .map(|_| {
+            (
+                Mutex::new(false),
+                Condvar::new(),
+                Mutex::new(NttGgswCiphertext::new(
+                    multi_bit_bsk.glwe_size(),
+                    multi_bit_bsk.polynomial_size(),
+                    multi_bit_bsk.decomposition_base_log(),
+                    multi_bit_bsk.decomposition_level_count(),
+                )),
+            )
+        })
+        .collect();
+
+    let (tx, rx) = mpsc::channel::<usize>();
+
+    let work_index_counter = AtomicUsize::new(0);
+
+    thread::scope(|s| {
+        let produce_multi_bit_ntt_ggsw = |thread_id: usize, tx: mpsc::Sender<usize>| {
+            let mut ntt_a_monomial = Polynomial::new(0u64, polynomial_size);
+
+            let dest_idx = thread_id;
+            let (ready_for_consumer_lock, condvar, ntt_ggsw_buffer) =
+                &ntt_multi_bit_ggsw_buffers[dest_idx];
+
+            loop {
+                let work_index = work_index_counter.fetch_add(1, Ordering::Relaxed);
+                if work_index >= max_work_index {
+                    break;
+                }
+
+                let lwe_mask_elements =
+                    &lwe_mask[work_index * grouping_factor.0..(work_index + 1) * grouping_factor.0];
+
+                let switched_degrees = modulus_switch_multi_bit_non_native(
+                    grouping_factor,
+                    lwe_mask_elements,
+                    polynomial_size,
+                    modulus,
+                );
+
+                let ggsw_group = &ggsw_vec[work_index * ggsw_per_multi_bit_element.0
+                    ..(work_index + 1) * ggsw_per_multi_bit_element.0];
+
+                let mut ready_for_consumer = ready_for_consumer_lock.lock().unwrap();
+
+                // Wait while the buffer is not ready for processing and wait on the condvar
+                // to get notified when we can start processing again
+                while *ready_for_consumer {
+                    ready_for_consumer = condvar.wait(ready_for_consumer).unwrap();
+                }
+
+                let mut ntt_ggsw_buffer = ntt_ggsw_buffer.lock().unwrap();
+
+                prepare_multi_bit_ggsw_ntt64(
+                    &mut ntt_ggsw_buffer,
+                    ggsw_group,
+                    switched_degrees,
+                    &mut ntt_a_monomial,
+                    ntt,
+                );
+
+                // Drop the lock before we wake other threads
+                drop(ntt_ggsw_buffer);
+
+                *ready_for_consumer = true;
+                tx.send(dest_idx).unwrap();
+
+                // Wake threads waiting on the condvar
+                condvar.notify_all();
+            }
+        };
+
+        // false positive as the mapping function has side effects (thread spawning)
+        #[allow(clippy::needless_collect)]
+        let threads: Vec<_> = (0..thread_count.0)
+            .map(|id| {
+                let tx = tx.clone();
+                s.spawn(move || produce_multi_bit_ntt_ggsw(id, tx))
+            })
+            .collect();
+
+        // We initialize ct0 for the successive external products
+        let ct0 = accumulator;
+        let mut ct1 = GlweCiphertext::new(
+            0u64,
+            ct0.glwe_size(),
+            ct0.polynomial_size(),
+            ct0.ciphertext_modulus(),
+        );
+        let ct1 = &mut ct1;
+
+        let mut buffers = ComputationBuffers::new();
+
+        buffers.resize(
+            ntt64_add_external_product_assign_scratch(
+                multi_bit_bsk.glwe_size(),
+                multi_bit_bsk.polynomial_size(),
+                ntt,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+
+        let mut src_idx = 1usize;
+
+        for _ in 0..max_work_index {
+            src_idx ^= 1;
+            let idx = rx.recv().unwrap();
+            let (ready_lock, condvar, multi_bit_ntt_ggsw) = &ntt_multi_bit_ggsw_buffers[idx];
+
+            let (src_ct, mut dst_ct) = if src_idx == 0 {
+                (ct0.as_view(), ct1.as_mut_view())
+            } else {
+                (ct1.as_view(), ct0.as_mut_view())
+            };
+
+            dst_ct.as_mut().fill(0u64);
+
+            let mut ready = ready_lock.lock().unwrap();
+            assert!(*ready);
+
+            let multi_bit_ntt_ggsw = multi_bit_ntt_ggsw.lock().unwrap();
+            add_external_product_ntt64_assign(
+                dst_ct,
+                multi_bit_ntt_ggsw.as_view(),
+                &src_ct,
+                ntt,
+                buffers.stack(),
+            );
+            drop(multi_bit_ntt_ggsw);
+
+            *ready = false;
+            // Wake a single producer thread sleeping on the condvar (only one will get to work
+            // anyways)
+            condvar.notify_one();
+        }
+
+        if src_idx == 0 {
+            ct0.as_mut().copy_from_slice(ct1.as_ref());
+        }
+
+        threads.into_iter().for_each(|t| t.join().unwrap());
+    });
+}
+
+/// Perform a programmable bootstrap given an input [`LWE ciphertext`](`LweCiphertext`), a
+/// look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE multi bit
+/// bootstrap key`](`LweMultiBitBootstrapKey`) in the NTT domain see [`NTT LWE multi bit bootstrap
+/// key`](`NttLweMultiBitBootstrapKey`). The result is written in the provided output
+/// [`LWE ciphertext`](`LweCiphertext`).
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define the parameters for a 4 bits message able to hold the doubled 2 bits message
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.000007069849454709433), 0.0);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let grouping_factor = LweBskGroupingFactor(2); // Group bits in pairs
+/// let ciphertext_modulus = CiphertextModulus::try_new((1 << 64) - (1 << 32) + 1).unwrap();
+///
+/// // Request the best seeder possible, starting with hardware entropy sources and falling back to
+/// // /dev/random on Unix systems if enabled via cargo features
+/// let mut boxed_seeder = new_seeder();
+/// // Get a mutable reference to the seeder as a trait object from the Box returned by new_seeder
+/// let seeder = boxed_seeder.as_mut();
+///
+/// // Create a generator which uses a CSPRNG to generate secret keys
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create a generator which uses two CSPRNGs to generate public masks and secret encryption
+/// // noise
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// println!("Generating keys...");
+///
+/// // Generate an LweSecretKey with binary coefficients
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+///
+/// // Generate a GlweSecretKey with binary coefficients
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+///
+/// // Create a copy of the GlweSecretKey re-interpreted as an LweSecretKey
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// let mut bsk = LweMultiBitBootstrapKey::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     pbs_base_log,
+///     pbs_level,
+///     small_lwe_dimension,
+///     grouping_factor,
+///     ciphertext_modulus,
+/// );
+///
+/// par_generate_lwe_multi_bit_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     &mut bsk,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut multi_bit_bsk = NttLweMultiBitBootstrapKey::new(
+///     0u64,
+///     bsk.input_lwe_dimension(),
+///     bsk.glwe_size(),
+///     bsk.polynomial_size(),
+///     bsk.decomposition_base_log(),
+///     bsk.decomposition_level_count(),
+///     bsk.grouping_factor(),
+///     bsk.ciphertext_modulus(),
+/// );
+///
+/// par_convert_standard_lwe_multi_bit_bootstrap_key_to_ntt64(&bsk, &mut multi_bit_bsk);
+///
+/// // We don't need the standard bootstrapping key anymore
+/// drop(bsk);
+///
+/// // Our 4 bits message space
+/// let message_modulus = 1u64 << 4;
+///
+/// // Our input message
+/// let input_message = 3u64;
+///
+/// // Delta used to encode 4 bits of message + a bit of padding on u64
+/// let delta = (1_u64 << 63) / message_modulus;
+///
+/// // Apply our encoding
+/// let plaintext = Plaintext(input_message * delta);
+///
+/// // Allocate a new LweCiphertext and encrypt our plaintext
+/// let lwe_ciphertext_in: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     plaintext,
+///     lwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Now we will use a PBS to compute a multiplication by 2, it is NOT the recommended way of
+/// // doing this operation in terms of performance as it's much more costly than a multiplication
+/// // with a cleartext, however it resets the noise in a ciphertext to a nominal level and allows
+/// // to evaluate arbitrary functions so depending on your use case it can be a better fit.
+///
+/// // Generate the accumulator for our multiplication by 2 using a simple closure
+/// let accumulator: GlweCiphertextOwned<u64> = generate_programmable_bootstrap_glwe_lut(
+///     polynomial_size,
+///     glwe_dimension.to_glwe_size(),
+///     message_modulus as usize,
+///     ciphertext_modulus,
+///     delta,
+///     |x: u64| 2 * x,
+/// );
+///
+/// // Allocate the LweCiphertext to store the result of the PBS
+/// let mut pbs_multiplication_ct = LweCiphertext::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+/// println!("Computing PBS...");
+/// // Use 4 threads for the multi-bit blind rotation for example
+/// multi_bit_programmable_bootstrap_ntt64_lwe_ciphertext(
+///     &lwe_ciphertext_in,
+///     &mut pbs_multiplication_ct,
+///     &accumulator,
+///     &multi_bit_bsk,
+///     ThreadCount(4),
+/// );
+///
+/// // Decrypt the PBS multiplication result
+/// let pbs_multiplication_plaintext: Plaintext<u64> =
+///     decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_multiplication_ct);
+///
+/// // Round and remove our encoding
+/// let pbs_multiplication_result: u64 = divide_round(pbs_multiplication_plaintext.0, delta);
+///
+/// println!("Checking result...");
+/// assert_eq!(6, pbs_multiplication_result);
+/// println!(
+///     "Multiplication via PBS result is correct! Expected 6, got {pbs_multiplication_result}"
+/// );
+/// ```
+pub fn multi_bit_programmable_bootstrap_ntt64_lwe_ciphertext<
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    multi_bit_bsk: &NttLweMultiBitBootstrapKey<KeyCont>,
+    thread_count: ThreadCount,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    AccCont: Container<Element = u64>,
+    KeyCont: Container<Element = u64>,
+{
+    assert_eq!(
+        output.lwe_size().to_lwe_dimension(),
+        multi_bit_bsk.output_lwe_dimension(),
+        "Mismatched output LweDimension. LweCiphertext output LweDimension {:?}. \
+        NttLweMultiBitBootstrapKey output LweDimension {:?}.",
+        output.lwe_size().to_lwe_dimension(),
+        multi_bit_bsk.output_lwe_dimension(),
+    );
+
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched CiphertextModulus between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+    );
+
+    let mut local_accumulator = GlweCiphertext::new(
+        0u64,
+        accumulator.glwe_size(),
+        accumulator.polynomial_size(),
+        accumulator.ciphertext_modulus(),
+    );
+    local_accumulator
+        .as_mut()
+        .copy_from_slice(accumulator.as_ref());
+
+    multi_bit_blind_rotate_ntt64_assign(input, &mut local_accumulator, multi_bit_bsk, thread_count);
+
+    extract_lwe_sample_from_glwe_ciphertext(&local_accumulator, output, MonomialDegree(0));
+}
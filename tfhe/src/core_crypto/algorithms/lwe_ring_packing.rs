@@ -0,0 +1,443 @@
+//! Module containing primitives pertaining to the packing of several
+//! [`LWE ciphertexts`](`LweCiphertext`) in a single [`GLWE ciphertext`](`GlweCiphertext`) using
+//! ring automorphisms.
+//!
+//! The packing follows the PackLWEs procedure from
+//! [`Efficient Homomorphic Conversion Between (Ring) LWE Ciphertexts`](https://eprint.iacr.org/2020/015),
+//! it requires $\log_2(N)$ [`GLWE automorphism keys`](`GlweAutomorphismKey`) and a number of
+//! automorphisms which is linear in the number of packed ciphertexts, compared to the
+//! per-coefficient keyswitches of the
+//! [`LWE packing keyswitch`](`super::keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext`)
+//! whose cost grows with the LWE dimension for each packed ciphertext.
+
+use crate::core_crypto::algorithms::misc::divide_round;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_monic_monomial_mul;
+use crate::core_crypto::algorithms::slice_algorithms::{
+    slice_wrapping_add_assign, slice_wrapping_sub_assign,
+};
+use crate::core_crypto::algorithms::{
+    apply_automorphism_to_glwe_ciphertext_mem_optimized,
+    apply_automorphism_to_glwe_ciphertext_mem_optimized_requirement,
+};
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use aligned_vec::CACHELINE_ALIGN;
+use concrete_fft::c64;
+use dyn_stack::{PodStack, ReborrowMut, SizeOverflow, StackReq};
+
+/// Return the [`AutomorphismExponent`] of each
+/// [`GLWE automorphism key`](`GlweAutomorphismKey`) required by
+/// [`ring_pack_lwe_ciphertext_list_into_glwe_ciphertext`], in the order the keys are expected.
+///
+/// These are the exponents $2^{\ell} + 1$ for $\ell$ going from 1 to $\log_2(N)$.
+pub fn lwe_ring_packing_automorphism_exponents(
+    polynomial_size: PolynomialSize,
+) -> Vec<AutomorphismExponent> {
+    (1..=polynomial_size.log2().0)
+        .map(|level| AutomorphismExponent((1 << level) + 1))
+        .collect()
+}
+
+/// Pack an input [`LWE ciphertext list`](`LweCiphertextList`) in an output
+/// [`GLWE ciphertext`](`GlweCiphertext`) using
+/// [`Fourier GLWE automorphism keys`](`FourierGlweAutomorphismKey`), the ciphertext at index $j$
+/// in the list ending up in the coefficient of degree $j$ of the output.
+///
+/// The input LWE ciphertexts must be encrypted under the
+/// [`LWE secret key`](`LweSecretKey`) obtained from the
+/// [`GLWE secret key`](`GlweSecretKey`) the automorphism keys were generated with, see
+/// [`GlweSecretKey::as_lwe_secret_key`].
+///
+/// The automorphism keys must be ordered following
+/// [`lwe_ring_packing_automorphism_exponents`].
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for the automorphism keys creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let decomp_base_log = DecompositionBaseLog(20);
+/// let decomp_level_count = DecompositionLevelCount(2);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey and the LweSecretKey sharing its coefficients
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+/// let lwe_secret_key = glwe_secret_key.as_lwe_secret_key();
+///
+/// let auto_keys = allocate_and_generate_new_lwe_ring_packing_glwe_automorphism_keys(
+///     &glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Convert the keys to the Fourier domain
+/// let fourier_auto_keys: Vec<_> = auto_keys
+///     .iter()
+///     .map(|auto_key| {
+///         let mut fourier_auto_key = FourierGlweAutomorphismKey::new(
+///             auto_key.glwe_size(),
+///             auto_key.polynomial_size(),
+///             auto_key.decomposition_base_log(),
+///             auto_key.decomposition_level_count(),
+///             auto_key.automorphism_exponent(),
+///         );
+///         convert_standard_glwe_automorphism_key_to_fourier(auto_key, &mut fourier_auto_key);
+///         fourier_auto_key
+///     })
+///     .collect();
+///
+/// // Encrypt some messages in LWE ciphertexts
+/// let msgs = [1u64, 2, 3, 4, 5, 6, 7];
+/// let plaintext_list =
+///     PlaintextList::from_container(msgs.iter().map(|msg| msg << 59).collect::<Vec<_>>());
+///
+/// let mut lwe_list = LweCiphertextList::new(
+///     0u64,
+///     lwe_secret_key.lwe_dimension().to_lwe_size(),
+///     LweCiphertextCount(msgs.len()),
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_lwe_ciphertext_list(
+///     &lwe_secret_key,
+///     &mut lwe_list,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// ring_pack_lwe_ciphertext_list_into_glwe_ciphertext(
+///     &fourier_auto_keys,
+///     &lwe_list,
+///     &mut output_glwe,
+/// );
+///
+/// let mut decrypted_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &output_glwe,
+///     &mut decrypted_plaintext_list,
+/// );
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 5 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+///
+/// decrypted_plaintext_list
+///     .iter_mut()
+///     .for_each(|elt| *elt.0 = decomposer.closest_representable(*elt.0) >> 59);
+///
+/// // Check we recovered the messages in the first coefficients and zeros elsewhere
+/// for (degree, elt) in decrypted_plaintext_list.iter().enumerate() {
+///     let expected = msgs.get(degree).copied().unwrap_or(0);
+///     assert_eq!(*elt.0, expected);
+/// }
+/// ```
+pub fn ring_pack_lwe_ciphertext_list_into_glwe_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
+    glwe_automorphism_keys: &[FourierGlweAutomorphismKey<KeyCont>],
+    input_lwe_ciphertext_list: &LweCiphertextList<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(output_glwe_ciphertext.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        ring_pack_lwe_ciphertext_list_into_glwe_ciphertext_mem_optimized_requirement::<Scalar>(
+            output_glwe_ciphertext.glwe_size(),
+            output_glwe_ciphertext.polynomial_size(),
+            input_lwe_ciphertext_list.lwe_ciphertext_count(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    ring_pack_lwe_ciphertext_list_into_glwe_ciphertext_mem_optimized(
+        glwe_automorphism_keys,
+        input_lwe_ciphertext_list,
+        output_glwe_ciphertext,
+        fft,
+        stack,
+    );
+}
+
+/// Memory optimized version of [`ring_pack_lwe_ciphertext_list_into_glwe_ciphertext`], the
+/// caller must provide a properly configured [`FftView`] object and a `PodStack` used as a memory
+/// buffer having a capacity at least as large as the result of
+/// [`ring_pack_lwe_ciphertext_list_into_glwe_ciphertext_mem_optimized_requirement`].
+pub fn ring_pack_lwe_ciphertext_list_into_glwe_ciphertext_mem_optimized<
+    Scalar,
+    KeyCont,
+    InputCont,
+    OutputCont,
+>(
+    glwe_automorphism_keys: &[FourierGlweAutomorphismKey<KeyCont>],
+    input_lwe_ciphertext_list: &LweCiphertextList<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = c64>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let glwe_size = output_glwe_ciphertext.glwe_size();
+    let polynomial_size = output_glwe_ciphertext.polynomial_size();
+    let ciphertext_modulus = output_glwe_ciphertext.ciphertext_modulus();
+    let lwe_ciphertext_count = input_lwe_ciphertext_list.lwe_ciphertext_count().0;
+
+    assert!(
+        input_lwe_ciphertext_list.lwe_size().to_lwe_dimension()
+            == glwe_size
+                .to_glwe_dimension()
+                .to_equivalent_lwe_dimension(polynomial_size),
+        "Mismatched LweDimension between the input LweCiphertextList ({:?}) \
+        and the equivalent LweDimension of the output GlweCiphertext ({:?}).",
+        input_lwe_ciphertext_list.lwe_size().to_lwe_dimension(),
+        glwe_size
+            .to_glwe_dimension()
+            .to_equivalent_lwe_dimension(polynomial_size)
+    );
+    assert!(
+        lwe_ciphertext_count > 0 && lwe_ciphertext_count <= polynomial_size.0,
+        "The number of LWE ciphertexts to pack ({lwe_ciphertext_count}) must be non zero \
+        and at most the output PolynomialSize ({polynomial_size:?})."
+    );
+    assert!(
+        input_lwe_ciphertext_list.ciphertext_modulus() == ciphertext_modulus,
+        "Mismatched CiphertextModulus between the input LweCiphertextList ({:?}) \
+        and the output GlweCiphertext ({:?}).",
+        input_lwe_ciphertext_list.ciphertext_modulus(),
+        ciphertext_modulus
+    );
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "This operation currently only supports power of 2 moduli"
+    );
+
+    let expected_exponents = lwe_ring_packing_automorphism_exponents(polynomial_size);
+    assert!(
+        glwe_automorphism_keys.len() == expected_exponents.len(),
+        "Expected {} GlweAutomorphismKeys, got {}.",
+        expected_exponents.len(),
+        glwe_automorphism_keys.len()
+    );
+    for (key, expected_exponent) in glwe_automorphism_keys.iter().zip(expected_exponents) {
+        assert!(
+            key.automorphism_exponent() == expected_exponent,
+            "Expected a GlweAutomorphismKey with AutomorphismExponent {expected_exponent:?}, \
+            got {:?}.",
+            key.automorphism_exponent()
+        );
+        assert!(
+            key.glwe_size() == glwe_size && key.polynomial_size() == polynomial_size,
+            "Mismatched parameters between the GlweAutomorphismKey (GlweSize: {:?}, \
+            PolynomialSize: {:?}) and the output GlweCiphertext (GlweSize: {glwe_size:?}, \
+            PolynomialSize: {polynomial_size:?}).",
+            key.glwe_size(),
+            key.polynomial_size()
+        );
+    }
+
+    let glwe_len = glwe_size.0 * polynomial_size.0;
+    let (mut slots_buffer, stack) =
+        stack.make_aligned_raw::<Scalar>(lwe_ciphertext_count * glwe_len, CACHELINE_ALIGN);
+    let (mut shifted_buffer, stack) = stack.make_aligned_raw::<Scalar>(glwe_len, CACHELINE_ALIGN);
+    let (mut difference_buffer, stack) =
+        stack.make_aligned_raw::<Scalar>(glwe_len, CACHELINE_ALIGN);
+    let (mut automorphism_buffer, mut stack) =
+        stack.make_aligned_raw::<Scalar>(glwe_len, CACHELINE_ALIGN);
+
+    // The trace computed by the packing multiplies the messages by N, so every coefficient is
+    // divided by N beforehand. Dividing the representatives of the coefficients modulo q leaves
+    // multiples of q / N in the phase which vanish once multiplied back by N.
+    let scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
+    let divisor = Scalar::cast_from(polynomial_size.0 as u128);
+    let divide_by_polynomial_size =
+        |value: Scalar| divide_round(value / scaling, divisor).wrapping_mul(scaling);
+
+    // Convert each LWE ciphertext to a GLWE ciphertext whose constant coefficient has the same
+    // phase
+    for (slot, lwe) in slots_buffer
+        .chunks_exact_mut(glwe_len)
+        .zip(input_lwe_ciphertext_list.iter())
+    {
+        let mut slot =
+            GlweCiphertextMutView::from_container(&mut *slot, polynomial_size, ciphertext_modulus);
+        slot.as_mut().fill(Scalar::ZERO);
+
+        let (lwe_mask, lwe_body) = (lwe.get_mask(), lwe.get_body());
+
+        for (mut glwe_mask_polynomial, lwe_mask_chunk) in slot
+            .get_mut_mask()
+            .as_mut_polynomial_list()
+            .iter_mut()
+            .zip(lwe_mask.as_ref().chunks_exact(polynomial_size.0))
+        {
+            let glwe_mask_polynomial = glwe_mask_polynomial.as_mut();
+            glwe_mask_polynomial[0] = divide_by_polynomial_size(lwe_mask_chunk[0]);
+            for (degree, &lwe_mask_element) in lwe_mask_chunk.iter().enumerate().skip(1) {
+                glwe_mask_polynomial[polynomial_size.0 - degree] =
+                    divide_by_polynomial_size(lwe_mask_element.wrapping_neg());
+            }
+        }
+
+        slot.get_mut_body().as_mut()[0] = divide_by_polynomial_size(*lwe_body.data);
+    }
+
+    // At each level, the slot r is merged with the slot r + h where h = N / 2^level, the
+    // automorphism X -> X^(2^level + 1) zeroing the coefficients which do not belong to the packed
+    // messages
+    let mut current_slot_count = lwe_ciphertext_count;
+    for (level, automorphism_key) in (1..=polynomial_size.log2().0).zip(glwe_automorphism_keys) {
+        let half_count = polynomial_size.0 >> level;
+        let monomial_degree = MonomialDegree(half_count);
+
+        let merged_slot_count = current_slot_count.min(half_count);
+        let (lower_slots, upper_slots) = slots_buffer.split_at_mut(merged_slot_count * glwe_len);
+
+        for (slot_index, even_slot) in lower_slots
+            .chunks_exact_mut(glwe_len)
+            .take(merged_slot_count)
+            .enumerate()
+        {
+            let mut even = GlweCiphertextMutView::from_container(
+                even_slot,
+                polynomial_size,
+                ciphertext_modulus,
+            );
+            let mut automorphism_output = GlweCiphertextMutView::from_container(
+                &mut *automorphism_buffer,
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            let odd_index = slot_index + half_count;
+            if odd_index < current_slot_count {
+                let odd_start = (odd_index - merged_slot_count) * glwe_len;
+                let odd = GlweCiphertextView::from_container(
+                    &upper_slots[odd_start..odd_start + glwe_len],
+                    polynomial_size,
+                    ciphertext_modulus,
+                );
+
+                let mut shifted = GlweCiphertextMutView::from_container(
+                    &mut *shifted_buffer,
+                    polynomial_size,
+                    ciphertext_modulus,
+                );
+                for (mut shifted_polynomial, odd_polynomial) in shifted
+                    .as_mut_polynomial_list()
+                    .iter_mut()
+                    .zip(odd.as_polynomial_list().iter())
+                {
+                    polynomial_wrapping_monic_monomial_mul(
+                        &mut shifted_polynomial,
+                        &odd_polynomial,
+                        monomial_degree,
+                    );
+                }
+
+                let mut difference = GlweCiphertextMutView::from_container(
+                    &mut *difference_buffer,
+                    polynomial_size,
+                    ciphertext_modulus,
+                );
+                difference.as_mut().copy_from_slice(even.as_ref());
+                slice_wrapping_sub_assign(difference.as_mut(), shifted.as_ref());
+                slice_wrapping_add_assign(even.as_mut(), shifted.as_ref());
+
+                apply_automorphism_to_glwe_ciphertext_mem_optimized(
+                    automorphism_key,
+                    &difference,
+                    &mut automorphism_output,
+                    fft,
+                    stack.rb_mut(),
+                );
+            } else {
+                apply_automorphism_to_glwe_ciphertext_mem_optimized(
+                    automorphism_key,
+                    &even,
+                    &mut automorphism_output,
+                    fft,
+                    stack.rb_mut(),
+                );
+            }
+
+            slice_wrapping_add_assign(even.as_mut(), automorphism_output.as_ref());
+        }
+
+        current_slot_count = merged_slot_count;
+    }
+
+    output_glwe_ciphertext
+        .as_mut()
+        .copy_from_slice(&slots_buffer[..glwe_len]);
+}
+
+/// Return the required memory for
+/// [`ring_pack_lwe_ciphertext_list_into_glwe_ciphertext_mem_optimized`].
+pub fn ring_pack_lwe_ciphertext_list_into_glwe_ciphertext_mem_optimized_requirement<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    lwe_ciphertext_count: LweCiphertextCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let glwe_len = glwe_size.0 * polynomial_size.0;
+    let slots =
+        StackReq::try_new_aligned::<Scalar>(lwe_ciphertext_count.0 * glwe_len, CACHELINE_ALIGN)?;
+    let glwe_buffer = StackReq::try_new_aligned::<Scalar>(glwe_len, CACHELINE_ALIGN)?;
+
+    StackReq::try_all_of([
+        slots,
+        glwe_buffer,
+        glwe_buffer,
+        glwe_buffer,
+        apply_automorphism_to_glwe_ciphertext_mem_optimized_requirement::<Scalar>(
+            glwe_size,
+            polynomial_size,
+            fft,
+        )?,
+    ])
+}
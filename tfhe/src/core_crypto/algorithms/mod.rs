@@ -5,9 +5,19 @@
 pub mod ggsw_conversion;
 pub mod ggsw_encryption;
 pub mod glwe_encryption;
+pub mod glwe_automorphism;
+pub mod glwe_automorphism_key_conversion;
+pub mod glwe_automorphism_key_generation;
+pub mod glwe_keyswitch;
+pub mod glwe_keyswitch_key_conversion;
+pub mod glwe_keyswitch_key_generation;
 pub mod glwe_linear_algebra;
+pub mod glwe_polynomial_size_conversion;
+pub mod glwe_relinearisation;
+pub mod glwe_relinearisation_key_generation;
 pub mod glwe_sample_extraction;
 pub mod glwe_secret_key_generation;
+pub mod glwe_tensor_product;
 pub mod lwe_bootstrap_key_conversion;
 pub mod lwe_bootstrap_key_generation;
 pub mod lwe_compact_ciphertext_list_expansion;
@@ -25,6 +35,7 @@ pub mod lwe_private_functional_packing_keyswitch;
 pub mod lwe_private_functional_packing_keyswitch_key_generation;
 pub mod lwe_programmable_bootstrapping;
 pub mod lwe_public_key_generation;
+pub mod lwe_ring_packing;
 pub mod lwe_secret_key_generation;
 pub mod lwe_wopbs;
 #[cfg(feature = "zk-pok-experimental")]
@@ -35,6 +46,7 @@ pub mod seeded_ggsw_ciphertext_decompression;
 pub mod seeded_ggsw_ciphertext_list_decompression;
 pub mod seeded_glwe_ciphertext_decompression;
 pub mod seeded_glwe_ciphertext_list_decompression;
+pub mod seeded_glwe_keyswitch_key_decompression;
 pub mod seeded_lwe_bootstrap_key_decompression;
 pub mod seeded_lwe_ciphertext_decompression;
 pub mod seeded_lwe_ciphertext_list_decompression;
@@ -53,9 +65,19 @@ pub(crate) mod test;
 pub use ggsw_conversion::*;
 pub use ggsw_encryption::*;
 pub use glwe_encryption::*;
+pub use glwe_automorphism::*;
+pub use glwe_automorphism_key_conversion::*;
+pub use glwe_automorphism_key_generation::*;
+pub use glwe_keyswitch::*;
+pub use glwe_keyswitch_key_conversion::*;
+pub use glwe_keyswitch_key_generation::*;
 pub use glwe_linear_algebra::*;
+pub use glwe_polynomial_size_conversion::*;
+pub use glwe_relinearisation::*;
+pub use glwe_relinearisation_key_generation::*;
 pub use glwe_sample_extraction::*;
 pub use glwe_secret_key_generation::*;
+pub use glwe_tensor_product::*;
 pub use lwe_bootstrap_key_conversion::*;
 pub use lwe_bootstrap_key_generation::*;
 pub use lwe_compact_ciphertext_list_expansion::*;
@@ -73,6 +95,7 @@ pub use lwe_private_functional_packing_keyswitch::*;
 pub use lwe_private_functional_packing_keyswitch_key_generation::*;
 pub use lwe_programmable_bootstrapping::*;
 pub use lwe_public_key_generation::*;
+pub use lwe_ring_packing::*;
 pub use lwe_secret_key_generation::*;
 pub use lwe_wopbs::*;
 #[cfg(feature = "zk-pok-experimental")]
@@ -81,6 +104,7 @@ pub use seeded_ggsw_ciphertext_decompression::*;
 pub use seeded_ggsw_ciphertext_list_decompression::*;
 pub use seeded_glwe_ciphertext_decompression::*;
 pub use seeded_glwe_ciphertext_list_decompression::*;
+pub use seeded_glwe_keyswitch_key_decompression::*;
 pub use seeded_lwe_bootstrap_key_decompression::*;
 pub use seeded_lwe_ciphertext_decompression::*;
 pub use seeded_lwe_ciphertext_list_decompression::*;
@@ -1,7 +1,7 @@
 //! Module providing algorithms to perform computations on polynomials modulo $X^{N} + 1$.
 
 use crate::core_crypto::algorithms::slice_algorithms::*;
-use crate::core_crypto::commons::parameters::MonomialDegree;
+use crate::core_crypto::commons::parameters::{AutomorphismExponent, MonomialDegree};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 
@@ -598,6 +598,60 @@ pub fn polynomial_wrapping_monic_monomial_mul<Scalar, OutputCont, InputCont>(
     }
 }
 
+/// Apply the ring automorphism $X \mapsto X^k$ (mod $(X^{N}+1)$) to the input polynomial and
+/// write the result in the output polynomial.
+///
+/// # Note
+///
+/// Computations wrap around (similar to computing modulo $2^{n\_{bits}}$) when exceeding the
+/// unsigned integer capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::commons::parameters::*;
+/// use tfhe::core_crypto::entities::*;
+/// let input = Polynomial::from_container(vec![1u8, 2, 3, 4]);
+/// let mut output = Polynomial::from_container(vec![0, 0, 0, 0]);
+/// polynomial_wrapping_automorphism(&mut output, &input, AutomorphismExponent(3));
+/// assert_eq!(output.as_ref(), &[1, 4, 253, 2]);
+/// ```
+pub fn polynomial_wrapping_automorphism<Scalar, OutputCont, InputCont>(
+    output: &mut Polynomial<OutputCont>,
+    input: &Polynomial<InputCont>,
+    automorphism_exponent: AutomorphismExponent,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    assert!(
+        output.polynomial_size() == input.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input polynomial size {:?}.",
+        output.polynomial_size(),
+        input.polynomial_size(),
+    );
+    assert!(
+        automorphism_exponent.0 % 2 == 1,
+        "The automorphism exponent must be odd, got {automorphism_exponent:?}."
+    );
+
+    let polynomial_size = output.polynomial_size().0;
+    let exponent = automorphism_exponent.0 % (2 * polynomial_size);
+
+    // As the exponent is odd, the map i -> i * k mod 2N is a bijection of the odd and even indices
+    // and each output coefficient is written exactly once
+    for (input_degree, &input_coefficient) in input.as_ref().iter().enumerate() {
+        let output_degree = (input_degree * exponent) % (2 * polynomial_size);
+        if output_degree < polynomial_size {
+            output.as_mut()[output_degree] = input_coefficient;
+        } else {
+            output.as_mut()[output_degree - polynomial_size] = input_coefficient.wrapping_neg();
+        }
+    }
+}
+
 /// Multiply (mod $(X^{N}+1)$), the input polynomial with a monic monomial of a given degree i.e.
 /// $X^{degree}$, then subtract the input from the result and assign to the output.
 ///
@@ -0,0 +1,49 @@
+//! Module with primitives pertaining to [`SeededGlweKeyswitchKey`] decompression.
+
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::generators::MaskRandomGenerator;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Convenience function to share the core logic of the decompression algorithm for
+/// [`SeededGlweKeyswitchKey`] between all functions needing it.
+pub fn decompress_seeded_glwe_keyswitch_key_with_existing_generator<
+    Scalar,
+    InputCont,
+    OutputCont,
+    Gen,
+>(
+    output_ksk: &mut GlweKeyswitchKey<OutputCont>,
+    input_ksk: &SeededGlweKeyswitchKey<InputCont>,
+    generator: &mut MaskRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    decompress_seeded_glwe_ciphertext_list_with_existing_generator(
+        &mut output_ksk.as_mut_glwe_ciphertext_list(),
+        &input_ksk.as_seeded_glwe_ciphertext_list(),
+        generator,
+    );
+}
+
+/// Decompress a [`SeededGlweKeyswitchKey`], without consuming it, into a standard
+/// [`GlweKeyswitchKey`].
+pub fn decompress_seeded_glwe_keyswitch_key<Scalar, InputCont, OutputCont, Gen>(
+    output_ksk: &mut GlweKeyswitchKey<OutputCont>,
+    input_ksk: &SeededGlweKeyswitchKey<InputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut generator = MaskRandomGenerator::<Gen>::new(input_ksk.compression_seed().seed);
+    decompress_seeded_glwe_keyswitch_key_with_existing_generator::<_, _, _, Gen>(
+        output_ksk,
+        input_ksk,
+        &mut generator,
+    );
+}
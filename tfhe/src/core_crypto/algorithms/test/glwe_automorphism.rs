@@ -0,0 +1,241 @@
+use super::*;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_automorphism;
+
+#[cfg(not(tarpaulin))]
+const NB_TESTS: usize = 10;
+#[cfg(tarpaulin)]
+const NB_TESTS: usize = 1;
+
+// The ring packing multiplies the keyswitch noise by up to the polynomial size, use a finer
+// decomposition than the parameter sets provide for the packing keyswitch
+const RING_PACKING_BASE_LOG: DecompositionBaseLog = DecompositionBaseLog(10);
+const RING_PACKING_LEVEL: DecompositionLevelCount = DecompositionLevelCount(4);
+
+fn decrypt_and_decode_glwe<Scalar: UnsignedTorus>(
+    glwe_sk: &GlweSecretKeyOwned<Scalar>,
+    glwe: &GlweCiphertextOwned<Scalar>,
+    delta: Scalar,
+    msg_modulus: Scalar,
+) -> Vec<Scalar> {
+    let mut plaintext_list =
+        PlaintextList::new(Scalar::ZERO, PlaintextCount(glwe.polynomial_size().0));
+
+    decrypt_glwe_ciphertext(glwe_sk, glwe, &mut plaintext_list);
+
+    plaintext_list
+        .iter()
+        .map(|x| round_decode(*x.0, delta) % msg_modulus)
+        .collect()
+}
+
+fn glwe_encrypt_automorphism_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    for automorphism_exponent in [3, 5, 2 * polynomial_size.0 - 1].map(AutomorphismExponent) {
+        for test_index in 0..NB_TESTS {
+            let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let auto_key = allocate_and_generate_new_glwe_automorphism_key(
+                &glwe_sk,
+                params.pfks_base_log,
+                params.pfks_level,
+                automorphism_exponent,
+                glwe_noise_distribution,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &auto_key,
+                ciphertext_modulus
+            ));
+
+            let mut fourier_auto_key = FourierGlweAutomorphismKey::new(
+                auto_key.glwe_size(),
+                auto_key.polynomial_size(),
+                auto_key.decomposition_base_log(),
+                auto_key.decomposition_level_count(),
+                auto_key.automorphism_exponent(),
+            );
+
+            convert_standard_glwe_automorphism_key_to_fourier(&auto_key, &mut fourier_auto_key);
+
+            let msgs = Polynomial::from_container(
+                (0..polynomial_size.0)
+                    .map(|i| Scalar::cast_from((i + test_index) as u128) % msg_modulus)
+                    .collect::<Vec<_>>(),
+            );
+
+            let plaintext_list = PlaintextList::from_container(
+                msgs.iter().map(|&msg| msg * delta).collect::<Vec<_>>(),
+            );
+
+            let mut input_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            encrypt_glwe_ciphertext(
+                &glwe_sk,
+                &mut input_glwe,
+                &plaintext_list,
+                glwe_noise_distribution,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut output_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            apply_automorphism_to_glwe_ciphertext(&fourier_auto_key, &input_glwe, &mut output_glwe);
+
+            assert!(check_encrypted_content_respects_mod(
+                &output_glwe,
+                ciphertext_modulus
+            ));
+
+            let decoded = decrypt_and_decode_glwe(&glwe_sk, &output_glwe, delta, msg_modulus);
+
+            let mut expected = Polynomial::new(Scalar::ZERO, polynomial_size);
+            polynomial_wrapping_automorphism(&mut expected, &msgs, automorphism_exponent);
+            let expected: Vec<_> = expected.iter().map(|&x| x % msg_modulus).collect();
+
+            assert_eq!(decoded, expected);
+        }
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_automorphism_decrypt_custom_mod);
+
+fn lwe_encrypt_ring_pack_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    for test_index in 0..NB_TESTS {
+        let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+        let lwe_sk = glwe_sk.as_lwe_secret_key();
+
+        let auto_keys = allocate_and_generate_new_lwe_ring_packing_glwe_automorphism_keys(
+            &glwe_sk,
+            RING_PACKING_BASE_LOG,
+            RING_PACKING_LEVEL,
+            glwe_noise_distribution,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let fourier_auto_keys: Vec<_> = auto_keys
+            .iter()
+            .map(|auto_key| {
+                assert!(check_encrypted_content_respects_mod(
+                    auto_key,
+                    ciphertext_modulus
+                ));
+
+                let mut fourier_auto_key = FourierGlweAutomorphismKey::new(
+                    auto_key.glwe_size(),
+                    auto_key.polynomial_size(),
+                    auto_key.decomposition_base_log(),
+                    auto_key.decomposition_level_count(),
+                    auto_key.automorphism_exponent(),
+                );
+                convert_standard_glwe_automorphism_key_to_fourier(auto_key, &mut fourier_auto_key);
+                fourier_auto_key
+            })
+            .collect();
+
+        // Check small batches, a batch for which only some of the first level slots get merged
+        // and a full batch
+        for lwe_ciphertext_count in [1, 3, polynomial_size.0 / 2 + 1, polynomial_size.0] {
+            let msgs: Vec<_> = (0..lwe_ciphertext_count)
+                .map(|i| Scalar::cast_from((i + test_index) as u128) % msg_modulus)
+                .collect();
+
+            let plaintext_list = PlaintextList::from_container(
+                msgs.iter().map(|&msg| msg * delta).collect::<Vec<_>>(),
+            );
+
+            let mut lwe_list = LweCiphertextList::new(
+                Scalar::ZERO,
+                lwe_sk.lwe_dimension().to_lwe_size(),
+                LweCiphertextCount(lwe_ciphertext_count),
+                ciphertext_modulus,
+            );
+
+            encrypt_lwe_ciphertext_list(
+                &lwe_sk,
+                &mut lwe_list,
+                &plaintext_list,
+                glwe_noise_distribution,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut output_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            ring_pack_lwe_ciphertext_list_into_glwe_ciphertext(
+                &fourier_auto_keys,
+                &lwe_list,
+                &mut output_glwe,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &output_glwe,
+                ciphertext_modulus
+            ));
+
+            let decoded = decrypt_and_decode_glwe(&glwe_sk, &output_glwe, delta, msg_modulus);
+
+            let expected: Vec<_> = msgs
+                .iter()
+                .copied()
+                .chain(core::iter::repeat(Scalar::ZERO))
+                .take(polynomial_size.0)
+                .collect();
+
+            assert_eq!(decoded, expected);
+        }
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_ring_pack_decrypt_custom_mod);
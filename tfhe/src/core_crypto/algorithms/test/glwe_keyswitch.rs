@@ -0,0 +1,382 @@
+use super::*;
+use crate::core_crypto::commons::generators::DeterministicSeeder;
+
+#[cfg(not(tarpaulin))]
+const NB_TESTS: usize = 10;
+#[cfg(tarpaulin)]
+const NB_TESTS: usize = 1;
+
+fn decrypt_and_decode_glwe<Scalar: UnsignedTorus>(
+    glwe_sk: &GlweSecretKeyOwned<Scalar>,
+    glwe: &GlweCiphertextOwned<Scalar>,
+    delta: Scalar,
+    msg_modulus: Scalar,
+) -> Vec<Scalar> {
+    let mut plaintext_list =
+        PlaintextList::new(Scalar::ZERO, PlaintextCount(glwe.polynomial_size().0));
+
+    decrypt_glwe_ciphertext(glwe_sk, glwe, &mut plaintext_list);
+
+    plaintext_list
+        .iter()
+        .map(|x| round_decode(*x.0, delta) % msg_modulus)
+        .collect()
+}
+
+fn glwe_encrypt_ks_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTestParams<Scalar>) {
+    let input_glwe_dimension = GlweDimension(params.glwe_dimension.0 + 1);
+    let output_glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let input_glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                input_glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+            let output_glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                output_glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let ksk = allocate_and_generate_new_glwe_keyswitch_key(
+                &input_glwe_sk,
+                &output_glwe_sk,
+                params.pfks_base_log,
+                params.pfks_level,
+                glwe_noise_distribution,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &ksk,
+                ciphertext_modulus
+            ));
+
+            let mut fourier_ksk = FourierGlweKeyswitchKey::new(
+                ksk.input_key_glwe_dimension(),
+                ksk.output_glwe_size(),
+                ksk.polynomial_size(),
+                ksk.decomposition_base_log(),
+                ksk.decomposition_level_count(),
+            );
+
+            convert_standard_glwe_keyswitch_key_to_fourier(&ksk, &mut fourier_ksk);
+
+            let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+
+            let mut input_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                input_glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            encrypt_glwe_ciphertext(
+                &input_glwe_sk,
+                &mut input_glwe,
+                &plaintext_list,
+                glwe_noise_distribution,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut output_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                output_glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            keyswitch_glwe_ciphertext(&ksk, &input_glwe, &mut output_glwe);
+
+            assert!(check_encrypted_content_respects_mod(
+                &output_glwe,
+                ciphertext_modulus
+            ));
+
+            let decoded =
+                decrypt_and_decode_glwe(&output_glwe_sk, &output_glwe, delta, msg_modulus);
+
+            assert!(decoded.iter().all(|&x| x == msg));
+
+            let mut fourier_output_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                output_glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            keyswitch_glwe_ciphertext_with_fourier_key(
+                &fourier_ksk,
+                &input_glwe,
+                &mut fourier_output_glwe,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &fourier_output_glwe,
+                ciphertext_modulus
+            ));
+
+            let decoded =
+                decrypt_and_decode_glwe(&output_glwe_sk, &fourier_output_glwe, delta, msg_modulus);
+
+            assert!(decoded.iter().all(|&x| x == msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(tarpaulin)]
+        break;
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_ks_decrypt_custom_mod);
+
+fn glwe_encrypt_polynomial_size_conversion_ks_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    for (ratio_log, shrink) in [(1, true), (2, true), (1, false)] {
+        let output_polynomial_size = if shrink {
+            PolynomialSize(polynomial_size.0 >> ratio_log)
+        } else {
+            PolynomialSize(polynomial_size.0 << ratio_log)
+        };
+        let ratio = 1 << ratio_log;
+
+        for _ in 0..NB_TESTS {
+            let input_glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+            let converted_glwe_sk = allocate_and_convert_glwe_secret_key_polynomial_size(
+                &input_glwe_sk,
+                output_polynomial_size,
+            );
+            let output_glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+                glwe_dimension,
+                output_polynomial_size,
+                &mut rsc.secret_random_generator,
+            );
+
+            let ksk = allocate_and_generate_new_glwe_keyswitch_key(
+                &converted_glwe_sk,
+                &output_glwe_sk,
+                params.pfks_base_log,
+                params.pfks_level,
+                glwe_noise_distribution,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+            plaintext_list
+                .iter_mut()
+                .enumerate()
+                .for_each(|(idx, dst)| {
+                    *dst.0 = (Scalar::cast_from(idx as u128) % msg_modulus).wrapping_mul(delta)
+                });
+
+            let mut input_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            encrypt_glwe_ciphertext(
+                &input_glwe_sk,
+                &mut input_glwe,
+                &plaintext_list,
+                glwe_noise_distribution,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut converted_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                converted_glwe_sk.glwe_dimension().to_glwe_size(),
+                output_polynomial_size,
+                ciphertext_modulus,
+            );
+
+            convert_glwe_ciphertext_polynomial_size(&input_glwe, &mut converted_glwe);
+
+            let mut output_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                output_polynomial_size,
+                ciphertext_modulus,
+            );
+
+            keyswitch_glwe_ciphertext(&ksk, &converted_glwe, &mut output_glwe);
+
+            assert!(check_encrypted_content_respects_mod(
+                &output_glwe,
+                ciphertext_modulus
+            ));
+
+            let decoded =
+                decrypt_and_decode_glwe(&output_glwe_sk, &output_glwe, delta, msg_modulus);
+
+            let expected: Vec<Scalar> = if shrink {
+                (0..output_polynomial_size.0)
+                    .map(|idx| Scalar::cast_from((idx * ratio) as u128) % msg_modulus)
+                    .collect()
+            } else {
+                (0..output_polynomial_size.0)
+                    .map(|idx| {
+                        if idx % ratio == 0 {
+                            Scalar::cast_from((idx / ratio) as u128) % msg_modulus
+                        } else {
+                            Scalar::ZERO
+                        }
+                    })
+                    .collect()
+            };
+
+            assert_eq!(decoded, expected);
+        }
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_polynomial_size_conversion_ks_decrypt_custom_mod);
+
+fn test_seeded_glwe_ksk_gen_equivalence<Scalar: UnsignedTorus>(
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) {
+    // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    // computations
+    // Define parameters for GlweKeyswitchKey creation
+    let input_glwe_dimension = GlweDimension(2);
+    let output_glwe_dimension = GlweDimension(1);
+    let polynomial_size = PolynomialSize(1024);
+    let glwe_noise_distribution = DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.00000000000000029403601535432533,
+    ));
+    let decomp_base_log = DecompositionBaseLog(3);
+    let decomp_level_count = DecompositionLevelCount(5);
+
+    // Create the PRNG
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mask_seed = seeder.seed();
+    let deterministic_seeder_seed = seeder.seed();
+    let mut secret_generator =
+        SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+
+    for _ in 0..NB_TESTS {
+        let input_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+            input_glwe_dimension,
+            polynomial_size,
+            &mut secret_generator,
+        );
+        let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+            output_glwe_dimension,
+            polynomial_size,
+            &mut secret_generator,
+        );
+
+        let mut ksk = GlweKeyswitchKey::new(
+            Scalar::ZERO,
+            decomp_base_log,
+            decomp_level_count,
+            input_glwe_dimension,
+            output_glwe_dimension,
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        let mut deterministic_seeder =
+            DeterministicSeeder::<ActivatedRandomGenerator>::new(deterministic_seeder_seed);
+        let mut encryption_generator = EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+            mask_seed,
+            &mut deterministic_seeder,
+        );
+
+        generate_glwe_keyswitch_key(
+            &input_glwe_secret_key,
+            &output_glwe_secret_key,
+            &mut ksk,
+            glwe_noise_distribution,
+            &mut encryption_generator,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &ksk,
+            ciphertext_modulus
+        ));
+
+        let mut seeded_ksk = SeededGlweKeyswitchKey::new(
+            Scalar::ZERO,
+            decomp_base_log,
+            decomp_level_count,
+            input_glwe_dimension,
+            output_glwe_dimension,
+            polynomial_size,
+            mask_seed.into(),
+            ciphertext_modulus,
+        );
+
+        let mut deterministic_seeder =
+            DeterministicSeeder::<ActivatedRandomGenerator>::new(deterministic_seeder_seed);
+
+        generate_seeded_glwe_keyswitch_key(
+            &input_glwe_secret_key,
+            &output_glwe_secret_key,
+            &mut seeded_ksk,
+            glwe_noise_distribution,
+            &mut deterministic_seeder,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &seeded_ksk,
+            ciphertext_modulus
+        ));
+
+        let decompressed_ksk = seeded_ksk.decompress_into_glwe_keyswitch_key();
+
+        assert_eq!(ksk, decompressed_ksk);
+    }
+}
+
+#[test]
+fn test_seeded_glwe_ksk_gen_equivalence_u32_native_mod() {
+    test_seeded_glwe_ksk_gen_equivalence::<u32>(CiphertextModulus::new_native());
+}
+
+#[test]
+fn test_seeded_glwe_ksk_gen_equivalence_u64_native_mod() {
+    test_seeded_glwe_ksk_gen_equivalence::<u64>(CiphertextModulus::new_native());
+}
+
+#[test]
+fn test_seeded_glwe_ksk_gen_equivalence_u64_custom_mod() {
+    test_seeded_glwe_ksk_gen_equivalence::<u64>(CiphertextModulus::try_new_power_of_2(63).unwrap());
+}
@@ -0,0 +1,199 @@
+use super::*;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_mul;
+
+#[cfg(not(tarpaulin))]
+const NB_TESTS: usize = 10;
+#[cfg(tarpaulin)]
+const NB_TESTS: usize = 1;
+
+// The relinearisation key switches from a key whose coefficients are not binary, use a
+// decomposition keeping most of the bits of the input to limit the approximation error
+const RELINEARISATION_BASE_LOG: DecompositionBaseLog = DecompositionBaseLog(8);
+const RELINEARISATION_LEVEL: DecompositionLevelCount = DecompositionLevelCount(7);
+
+fn encrypt_polynomial<Scalar: UnsignedTorus>(
+    glwe_sk: &GlweSecretKeyOwned<Scalar>,
+    msgs: &Polynomial<Vec<Scalar>>,
+    delta: Scalar,
+    params: &ClassicTestParams<Scalar>,
+    rsc: &mut TestResources,
+) -> GlweCiphertextOwned<Scalar> {
+    let plaintext_list =
+        PlaintextList::from_container(msgs.iter().map(|&msg| msg * delta).collect::<Vec<_>>());
+
+    let mut glwe = GlweCiphertext::new(
+        Scalar::ZERO,
+        glwe_sk.glwe_dimension().to_glwe_size(),
+        glwe_sk.polynomial_size(),
+        params.ciphertext_modulus,
+    );
+
+    encrypt_glwe_ciphertext(
+        glwe_sk,
+        &mut glwe,
+        &plaintext_list,
+        params.glwe_noise_distribution,
+        &mut rsc.encryption_random_generator,
+    );
+
+    glwe
+}
+
+fn decrypt_and_decode_glwe<Scalar: UnsignedTorus>(
+    glwe_sk: &GlweSecretKeyOwned<Scalar>,
+    glwe: &GlweCiphertextOwned<Scalar>,
+    delta: Scalar,
+    msg_modulus: Scalar,
+) -> Vec<Scalar> {
+    let mut plaintext_list =
+        PlaintextList::new(Scalar::ZERO, PlaintextCount(glwe.polynomial_size().0));
+
+    decrypt_glwe_ciphertext(glwe_sk, glwe, &mut plaintext_list);
+
+    plaintext_list
+        .iter()
+        .map(|x| round_decode(*x.0, delta) % msg_modulus)
+        .collect()
+}
+
+fn random_message_polynomial<Scalar: UnsignedTorus>(
+    polynomial_size: PolynomialSize,
+    msg_modulus: Scalar,
+    rsc: &mut TestResources,
+) -> Polynomial<Vec<Scalar>> {
+    let mut msgs = Polynomial::new(Scalar::ZERO, polynomial_size);
+    rsc.encryption_random_generator
+        .fill_slice_with_random_uniform_mask(msgs.as_mut());
+    msgs.iter_mut().for_each(|x| *x %= msg_modulus);
+    msgs
+}
+
+fn expected_product<Scalar: UnsignedTorus>(
+    lhs_msgs: &Polynomial<Vec<Scalar>>,
+    rhs_msgs: &Polynomial<Vec<Scalar>>,
+    msg_modulus: Scalar,
+) -> Vec<Scalar> {
+    let mut expected = Polynomial::new(Scalar::ZERO, lhs_msgs.polynomial_size());
+    polynomial_wrapping_mul(&mut expected, lhs_msgs, rhs_msgs);
+    expected.iter().map(|&x| x % msg_modulus).collect()
+}
+
+fn glwe_encrypt_tensor_product_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    for _ in 0..NB_TESTS {
+        let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+        let tensor_product_sk = allocate_and_compute_glwe_tensor_product_secret_key(&glwe_sk);
+
+        let lhs_msgs = random_message_polynomial(polynomial_size, msg_modulus, &mut rsc);
+        let rhs_msgs = random_message_polynomial(polynomial_size, msg_modulus, &mut rsc);
+
+        let lhs_glwe = encrypt_polynomial(&glwe_sk, &lhs_msgs, delta, &params, &mut rsc);
+        let rhs_glwe = encrypt_polynomial(&glwe_sk, &rhs_msgs, delta, &params, &mut rsc);
+
+        let mut output_glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_tensor_product_output_glwe_dimension(glwe_dimension).to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        glwe_tensor_product(&lhs_glwe, &rhs_glwe, &mut output_glwe, delta);
+
+        assert!(check_encrypted_content_respects_mod(
+            &output_glwe,
+            ciphertext_modulus
+        ));
+
+        let decoded = decrypt_and_decode_glwe(&tensor_product_sk, &output_glwe, delta, msg_modulus);
+
+        assert_eq!(decoded, expected_product(&lhs_msgs, &rhs_msgs, msg_modulus));
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_tensor_product_decrypt_custom_mod);
+
+fn glwe_encrypt_tensor_product_relinearise_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    for _ in 0..NB_TESTS {
+        let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+
+        let relin_key = allocate_and_generate_new_glwe_relinearisation_key(
+            &glwe_sk,
+            RELINEARISATION_BASE_LOG,
+            RELINEARISATION_LEVEL,
+            glwe_noise_distribution,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &relin_key,
+            ciphertext_modulus
+        ));
+
+        let lhs_msgs = random_message_polynomial(polynomial_size, msg_modulus, &mut rsc);
+        let rhs_msgs = random_message_polynomial(polynomial_size, msg_modulus, &mut rsc);
+
+        let lhs_glwe = encrypt_polynomial(&glwe_sk, &lhs_msgs, delta, &params, &mut rsc);
+        let rhs_glwe = encrypt_polynomial(&glwe_sk, &rhs_msgs, delta, &params, &mut rsc);
+
+        let mut output_glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        glwe_tensor_product_with_relinearisation(
+            &relin_key,
+            &lhs_glwe,
+            &rhs_glwe,
+            &mut output_glwe,
+            delta,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &output_glwe,
+            ciphertext_modulus
+        ));
+
+        let decoded = decrypt_and_decode_glwe(&glwe_sk, &output_glwe, delta, msg_modulus);
+
+        assert_eq!(decoded, expected_product(&lhs_msgs, &rhs_msgs, msg_modulus));
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_tensor_product_relinearise_decrypt_custom_mod);
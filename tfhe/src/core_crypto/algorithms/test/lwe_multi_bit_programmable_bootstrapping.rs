@@ -538,6 +538,159 @@ fn std_lwe_encrypt_multi_bit_deterministic_pbs_decrypt_custom_mod<Scalar>(
     }
 }
 
+fn lwe_encrypt_multi_bit_pbs_ntt64_decrypt_custom_mod(params: MultiBitTestParams<u64>) {
+    let input_lwe_dimension = params.input_lwe_dimension;
+    let lwe_noise_distribution = params.lwe_noise_distribution;
+    let glwe_noise_distribution = params.glwe_noise_distribution;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = 1u64 << message_modulus_log.0;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let grouping_factor = params.grouping_factor;
+    let thread_count = params.thread_count;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: u64| x;
+
+    let delta: u64 = encoding_with_padding / msg_modulus;
+    let mut msg = msg_modulus;
+
+    let accumulator = generate_programmable_bootstrap_glwe_lut(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    assert!(check_encrypted_content_respects_mod(
+        &accumulator,
+        ciphertext_modulus
+    ));
+
+    // Create the LweSecretKey
+    let input_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        input_lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let output_lwe_secret_key = output_glwe_secret_key.clone().into_lwe_secret_key();
+
+    let mut bsk = LweMultiBitBootstrapKey::new(
+        0u64,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        params.decomp_base_log,
+        params.decomp_level_count,
+        input_lwe_dimension,
+        grouping_factor,
+        ciphertext_modulus,
+    );
+
+    par_generate_lwe_multi_bit_bootstrap_key(
+        &input_lwe_secret_key,
+        &output_glwe_secret_key,
+        &mut bsk,
+        glwe_noise_distribution,
+        &mut rsc.encryption_random_generator,
+    );
+
+    assert!(check_encrypted_content_respects_mod(
+        &*bsk,
+        ciphertext_modulus
+    ));
+
+    let mut nbsk = NttLweMultiBitBootstrapKeyOwned::new(
+        0u64,
+        bsk.input_lwe_dimension(),
+        bsk.glwe_size(),
+        bsk.polynomial_size(),
+        bsk.decomposition_base_log(),
+        bsk.decomposition_level_count(),
+        bsk.grouping_factor(),
+        bsk.ciphertext_modulus(),
+    );
+
+    par_convert_standard_lwe_multi_bit_bootstrap_key_to_ntt64(&bsk, &mut nbsk);
+
+    // The sequential conversion must yield the same key
+    let mut sequential_nbsk = NttLweMultiBitBootstrapKeyOwned::new(
+        0u64,
+        bsk.input_lwe_dimension(),
+        bsk.glwe_size(),
+        bsk.polynomial_size(),
+        bsk.decomposition_base_log(),
+        bsk.decomposition_level_count(),
+        bsk.grouping_factor(),
+        bsk.ciphertext_modulus(),
+    );
+
+    convert_standard_lwe_multi_bit_bootstrap_key_to_ntt64(&bsk, &mut sequential_nbsk);
+
+    assert_eq!(nbsk, sequential_nbsk);
+
+    drop(bsk);
+    drop(sequential_nbsk);
+
+    while msg != 0u64 {
+        msg = msg.wrapping_sub(1u64);
+        for _ in 0..NB_TESTS {
+            let plaintext = Plaintext(msg * delta);
+
+            let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+                &input_lwe_secret_key,
+                plaintext,
+                lwe_noise_distribution,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &lwe_ciphertext_in,
+                ciphertext_modulus
+            ));
+
+            let mut out_pbs_ct = LweCiphertext::new(
+                0u64,
+                output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                ciphertext_modulus,
+            );
+
+            multi_bit_programmable_bootstrap_ntt64_lwe_ciphertext(
+                &lwe_ciphertext_in,
+                &mut out_pbs_ct,
+                &accumulator,
+                &nbsk,
+                thread_count,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &out_pbs_ct,
+                ciphertext_modulus
+            ));
+
+            let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(decoded, f(msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(tarpaulin)]
+        break;
+    }
+}
+
 #[test]
 pub fn test_lwe_encrypt_multi_bit_pbs_decrypt_factor_2_thread_5_native_mod() {
     lwe_encrypt_multi_bit_pbs_decrypt_custom_mod::<u64>(MULTI_BIT_2_2_2_PARAMS);
@@ -627,3 +780,13 @@ pub fn std_test_lwe_encrypt_multi_bit_deterministic_pbs_decrypt_factor_3_thread_
         MULTI_BIT_2_2_3_CUSTOM_MOD_PARAMS,
     );
 }
+
+#[test]
+pub fn test_lwe_encrypt_multi_bit_pbs_ntt64_decrypt_factor_2_thread_5_solinas_mod() {
+    lwe_encrypt_multi_bit_pbs_ntt64_decrypt_custom_mod(MULTI_BIT_2_2_2_SOLINAS_PARAMS);
+}
+
+#[test]
+pub fn test_lwe_encrypt_multi_bit_pbs_ntt64_decrypt_factor_3_thread_12_solinas_mod() {
+    lwe_encrypt_multi_bit_pbs_ntt64_decrypt_custom_mod(MULTI_BIT_2_2_3_SOLINAS_PARAMS);
+}
@@ -8,9 +8,12 @@ pub(crate) use crate::core_crypto::prelude::*;
 pub(crate) use std::fmt::Debug;
 
 mod ggsw_encryption;
+mod glwe_automorphism;
 mod glwe_encryption;
+mod glwe_keyswitch;
 mod glwe_linear_algebra;
 mod glwe_sample_extraction;
+mod glwe_tensor_product;
 mod lwe_bootstrap_key_generation;
 mod lwe_compact_public_key_generation;
 mod lwe_encryption;
@@ -286,6 +289,42 @@ pub const MULTI_BIT_2_2_3_CUSTOM_MOD_PARAMS: MultiBitTestParams<u64> = MultiBitT
     thread_count: ThreadCount(12),
 };
 
+pub const MULTI_BIT_2_2_2_SOLINAS_PARAMS: MultiBitTestParams<u64> = MultiBitTestParams {
+    input_lwe_dimension: LweDimension(818),
+    lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.000002226459789930014,
+    )),
+    decomp_base_log: DecompositionBaseLog(22),
+    decomp_level_count: DecompositionLevelCount(1),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.0000000000000003152931493498455,
+    )),
+    message_modulus_log: MessageModulusLog(3),
+    ciphertext_modulus: CiphertextModulus::new((1 << 64) - (1 << 32) + 1),
+    grouping_factor: LweBskGroupingFactor(2),
+    thread_count: ThreadCount(5),
+};
+
+pub const MULTI_BIT_2_2_3_SOLINAS_PARAMS: MultiBitTestParams<u64> = MultiBitTestParams {
+    input_lwe_dimension: LweDimension(888),
+    lwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.0000006125031601933181,
+    )),
+    decomp_base_log: DecompositionBaseLog(21),
+    decomp_level_count: DecompositionLevelCount(1),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    glwe_noise_distribution: DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+        0.0000000000000003152931493498455,
+    )),
+    message_modulus_log: MessageModulusLog(3),
+    ciphertext_modulus: CiphertextModulus::new((1 << 64) - (1 << 32) + 1),
+    grouping_factor: LweBskGroupingFactor(3),
+    thread_count: ThreadCount(12),
+};
+
 // DISCLAIMER: example parameters tailored for FFT implementation tests. There are not guaranteed
 // to be secure or yield correct computations.
 // Define the parameters for a 4 bits message able to hold the doubled 2 bits message.
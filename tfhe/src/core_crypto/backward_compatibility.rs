@@ -0,0 +1,275 @@
+//! Versions of the serialized core_crypto entities, see [`crate::versionable`].
+
+use crate::core_crypto::prelude::*;
+use crate::versionable::versions_dispatch;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+versions_dispatch! {
+    pub enum LweCiphertextVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for LweCiphertextOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum LweCiphertextListVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for LweCiphertextListOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum SeededLweCiphertextVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for SeededLweCiphertext<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum GlweCiphertextVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for GlweCiphertextOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum GlweCiphertextListVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for GlweCiphertextListOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum LweSecretKeyVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for LweSecretKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum GlweSecretKeyVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for GlweSecretKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum LweKeyswitchKeyVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for LweKeyswitchKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum SeededLweKeyswitchKeyVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for SeededLweKeyswitchKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum GlweKeyswitchKeyVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for GlweKeyswitchKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum SeededGlweKeyswitchKeyVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for SeededGlweKeyswitchKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum GlweAutomorphismKeyVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for GlweAutomorphismKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum GlweRelinearisationKeyVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for GlweRelinearisationKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum LwePackingKeyswitchKeyVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for LwePackingKeyswitchKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum SeededLwePackingKeyswitchKeyVersions<
+        Scalar: UnsignedInteger + Serialize + DeserializeOwned
+    > for SeededLwePackingKeyswitchKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum LweBootstrapKeyVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for LweBootstrapKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum SeededLweBootstrapKeyVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for SeededLweBootstrapKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum LweMultiBitBootstrapKeyVersions<
+        Scalar: UnsignedInteger + Serialize + DeserializeOwned
+    > for LweMultiBitBootstrapKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum SeededLweMultiBitBootstrapKeyVersions<
+        Scalar: UnsignedInteger + Serialize + DeserializeOwned
+    > for SeededLweMultiBitBootstrapKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum FourierLweBootstrapKeyVersions for FourierLweBootstrapKeyOwned {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum FourierGlweKeyswitchKeyVersions for FourierGlweKeyswitchKeyOwned {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum FourierGlweAutomorphismKeyVersions for FourierGlweAutomorphismKeyOwned {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum FourierLweMultiBitBootstrapKeyVersions for FourierLweMultiBitBootstrapKeyOwned {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum LwePublicKeyVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for LwePublicKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum SeededLwePublicKeyVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for SeededLwePublicKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum LweCompactPublicKeyVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+        for LweCompactPublicKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum SeededLweCompactPublicKeyVersions<
+        Scalar: UnsignedInteger + Serialize + DeserializeOwned
+    > for SeededLweCompactPublicKeyOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum LweCompactCiphertextListVersions<
+        Scalar: UnsignedInteger + Serialize + DeserializeOwned
+    > for LweCompactCiphertextListOwned<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedModulusSwitchedLweCiphertextVersions<
+        Scalar: UnsignedInteger + Serialize + DeserializeOwned
+    > for CompressedModulusSwitchedLweCiphertext<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedModulusSwitchedGlweCiphertextVersions<
+        Scalar: UnsignedInteger + Serialize + DeserializeOwned
+    > for CompressedModulusSwitchedGlweCiphertext<Scalar>
+    {
+        #[current]
+        V0,
+    }
+}
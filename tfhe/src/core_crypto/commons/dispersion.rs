@@ -12,7 +12,15 @@
 //! In any of those cases, the corresponding type implements the `DispersionParameter` trait,
 //! which makes if possible to use any of those representations generically when noise must be
 //! defined.
+//!
+//! This module also contains formulas estimating the variance of the noise after keyswitches,
+//! modulus switches and programmable bootstraps, as well as the [`failure_probability`] associated
+//! to a given variance.
 
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
+    LweDimension, PolynomialSize,
+};
 use serde::{Deserialize, Serialize};
 
 /// A trait for types representing distribution parameters, for a given unsigned integer type.
@@ -194,3 +202,218 @@ impl DispersionParameter for Variance {
         log2_modulus as f64 + self.0.sqrt().log2()
     }
 }
+
+// Noise formulas
+//
+// The formulas below estimate the variance of the noise after the usual FHE primitives, assuming
+// uniform binary secret keys. All variances are expressed on the torus, i.e. normalized by the
+// ciphertext modulus $q = 2^{log2\_modulus}$.
+
+/// Variance of the rounding error made when decomposing a value with the given base and level
+/// count, multiplied by a uniform binary secret key coefficient.
+fn binary_key_decomposition_rounding_variance(
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    log2_modulus: u32,
+) -> f64 {
+    let decomposition_precision_log = (decomp_base_log.0 * decomp_level_count.0) as i32;
+    let inverse_squared_precision = 2f64.powi(-2 * decomposition_precision_log);
+    let inverse_squared_modulus = 2f64.powi(-2 * log2_modulus as i32);
+
+    (inverse_squared_precision - inverse_squared_modulus) / 24.0 + inverse_squared_precision / 16.0
+}
+
+/// Return the variance added by an LWE keyswitch with a key of the given parameters.
+///
+/// The output variance of the keyswitch is the variance of the input ciphertext plus the value
+/// returned by this function.
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::dispersion::{
+///     lwe_keyswitch_additive_variance, DispersionParameter, StandardDev,
+/// };
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let variance = lwe_keyswitch_additive_variance(
+///     LweDimension(2048),
+///     DecompositionBaseLog(3),
+///     DecompositionLevelCount(5),
+///     StandardDev(3.1496674685772435e-06),
+///     64,
+/// );
+/// assert!(variance.get_log_standard_dev() < -10.0);
+/// ```
+pub fn lwe_keyswitch_additive_variance(
+    input_lwe_dimension: LweDimension,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    keyswitch_key_noise: impl DispersionParameter,
+    log2_modulus: u32,
+) -> Variance {
+    let input_lwe_dimension = input_lwe_dimension.0 as f64;
+    let base = 2f64.powi(decomp_base_log.0 as i32);
+    let level_count = decomp_level_count.0 as f64;
+
+    let key_variance = input_lwe_dimension * level_count * (base * base + 2.0) / 12.0
+        * keyswitch_key_noise.get_variance();
+    let rounding_variance = input_lwe_dimension
+        * binary_key_decomposition_rounding_variance(
+            decomp_base_log,
+            decomp_level_count,
+            log2_modulus,
+        );
+
+    Variance(key_variance + rounding_variance)
+}
+
+/// Return the variance added by the modulus switch from $q$ to $2N$ performed at the beginning of
+/// a programmable bootstrap.
+///
+/// The noise seen by the blind rotation is the variance of the input ciphertext plus the value
+/// returned by this function.
+pub fn modulus_switch_additive_variance(
+    lwe_dimension: LweDimension,
+    polynomial_size: PolynomialSize,
+    log2_modulus: u32,
+) -> Variance {
+    let lwe_dimension = lwe_dimension.0 as f64;
+    let switched_modulus = 2.0 * polynomial_size.0 as f64;
+    let inverse_squared_modulus = 2f64.powi(-2 * log2_modulus as i32);
+
+    let rounding_variance =
+        1.0 / (12.0 * switched_modulus * switched_modulus) - inverse_squared_modulus / 12.0;
+
+    Variance(rounding_variance * (1.0 + lwe_dimension / 2.0))
+}
+
+/// Return the variance of the output of a classic programmable bootstrap.
+///
+/// The output variance does not depend on the variance of the input ciphertext.
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::dispersion::{
+///     pbs_output_variance, DispersionParameter, StandardDev,
+/// };
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let variance = pbs_output_variance(
+///     LweDimension(841),
+///     GlweDimension(1),
+///     PolynomialSize(2048),
+///     DecompositionBaseLog(22),
+///     DecompositionLevelCount(1),
+///     StandardDev(2.845267479601915e-15),
+///     64,
+/// );
+/// assert!(variance.get_log_standard_dev() < -13.0);
+/// ```
+pub fn pbs_output_variance(
+    lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    bootstrapping_key_noise: impl DispersionParameter,
+    log2_modulus: u32,
+) -> Variance {
+    multi_bit_pbs_output_variance(
+        lwe_dimension,
+        glwe_dimension,
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+        LweBskGroupingFactor(1),
+        bootstrapping_key_noise,
+        log2_modulus,
+    )
+}
+
+/// Return the variance of the output of a multi bit programmable bootstrap.
+///
+/// Each of the $n / grouping\_factor$ external products uses a GGSW which is the sum of
+/// $2^{grouping\_factor} - 1$ GGSW ciphertexts, which multiplies the part of the noise coming
+/// from the bootstrapping key. A grouping factor of 1 corresponds to the classic PBS.
+///
+/// # Panics
+///
+/// Panics if the grouping factor is 0 or not smaller than 64.
+#[allow(clippy::too_many_arguments)]
+pub fn multi_bit_pbs_output_variance(
+    lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    grouping_factor: LweBskGroupingFactor,
+    bootstrapping_key_noise: impl DispersionParameter,
+    log2_modulus: u32,
+) -> Variance {
+    assert!(
+        (1..u64::BITS as usize).contains(&grouping_factor.0),
+        "The grouping factor must be between 1 and 63, got {grouping_factor:?}"
+    );
+
+    let external_product_count = (lwe_dimension.0 / grouping_factor.0) as f64;
+    let ggsw_per_external_product = ((1u64 << grouping_factor.0) - 1) as f64;
+
+    let glwe_size = glwe_dimension.to_glwe_size().0 as f64;
+    let polynomial_size = polynomial_size.0 as f64;
+    let glwe_key_lwe_dimension = glwe_dimension.0 as f64 * polynomial_size;
+    let base = 2f64.powi(decomp_base_log.0 as i32);
+    let level_count = decomp_level_count.0 as f64;
+
+    let key_variance =
+        ggsw_per_external_product * level_count * glwe_size * polynomial_size * (base * base + 2.0)
+            / 12.0
+            * bootstrapping_key_noise.get_variance();
+
+    let decomposition_precision_log = (decomp_base_log.0 * decomp_level_count.0) as i32;
+    let inverse_squared_precision = 2f64.powi(-2 * decomposition_precision_log);
+    let inverse_squared_modulus = 2f64.powi(-2 * log2_modulus as i32);
+    let rounding_variance = (inverse_squared_precision - inverse_squared_modulus) / 12.0
+        * (1.0 + glwe_key_lwe_dimension / 2.0)
+        + glwe_key_lwe_dimension * inverse_squared_precision / 16.0;
+
+    Variance(external_product_count * (key_variance + rounding_variance))
+}
+
+/// Return the probability that a centered gaussian noise with the given variance has an absolute
+/// value greater or equal to `bound`, with `bound` expressed on the torus.
+///
+/// For a ciphertext encoding values with a scaling factor $\Delta$, decryption (or a programmable
+/// bootstrap) fails when the noise is greater than $\frac{\Delta}{2}$.
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::dispersion::{failure_probability, Variance};
+///
+/// // The noise is outside of [-2 sigma, 2 sigma] with a probability close to 4.55%
+/// let probability = failure_probability(Variance(1.0), 2.0);
+/// assert!((probability - 0.0455).abs() < 1e-4);
+/// ```
+pub fn failure_probability(noise: impl DispersionParameter, bound: f64) -> f64 {
+    erfc(bound / (std::f64::consts::SQRT_2 * noise.get_standard_dev()))
+}
+
+/// Complementary error function, with a relative error smaller than $1.2 \cdot 10^{-7}$ over the
+/// whole real line, which keeps it accurate for very small failure probabilities.
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+
+    // Chebyshev fit from Numerical Recipes
+    let polynomial = -z * z - 1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let result = t * polynomial.exp();
+
+    if x >= 0.0 {
+        result
+    } else {
+        2.0 - result
+    }
+}
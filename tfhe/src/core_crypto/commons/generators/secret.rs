@@ -1,6 +1,8 @@
 //! Module containing primitives pertaining to random generation in the context of secret key
 //! generation.
 
+#[cfg(feature = "experimental")]
+use crate::core_crypto::commons::math::random::Uniform;
 use crate::core_crypto::commons::math::random::{
     ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, UniformBinary,
 };
@@ -25,4 +27,12 @@ impl<G: ByteRandomGenerator> SecretRandomGenerator<G> {
     {
         self.0.fill_slice_with_random_uniform_binary(slice);
     }
+
+    #[cfg(feature = "experimental")]
+    pub(crate) fn fill_slice_with_random_uniform<Scalar>(&mut self, slice: &mut [Scalar])
+    where
+        Scalar: RandomGenerable<Uniform>,
+    {
+        self.0.fill_slice_with_random_uniform(slice);
+    }
 }
@@ -258,6 +258,18 @@ impl<T: UnsignedInteger> DynamicDistribution<T> {
             }
         }
     }
+
+    /// Return the variance of the distribution on the torus, i.e. normalized by the modulus
+    /// $2^{log2\_modulus}$.
+    ///
+    /// Gaussian standard deviations are already expressed on the torus, the modulus is only used
+    /// to normalize the integer bound of [`TUniform`] distributions.
+    pub fn variance(&self, log2_modulus: u32) -> Variance {
+        match self {
+            Self::Gaussian(_) => self.gaussian_variance(),
+            Self::TUniform(t_uniform) => t_uniform.variance(log2_modulus),
+        }
+    }
 }
 
 impl<T: UnsignedInteger> std::fmt::Display for DynamicDistribution<T> {
@@ -55,6 +55,16 @@ impl<T: UnsignedInteger> TUniform<T> {
         self.bound_log2
     }
 
+    /// Return the variance of the distribution on the torus, i.e. normalized by the modulus
+    /// $2^{log2\_modulus}$.
+    ///
+    /// With $m = 2^b$ the variance of the integer distribution is $\frac{2m^2 + 1}{6}$.
+    pub fn variance(&self, log2_modulus: u32) -> Variance {
+        let bound = 2f64.powi(self.bound_log2 as i32);
+        let modular_variance = (2.0 * bound * bound + 1.0) / 6.0;
+        Variance::from_modular_variance(modular_variance, log2_modulus)
+    }
+
     pub const fn distinct_value_count(&self) -> usize {
         (1 << (self.bound_log2 + 1)) + 1
     }
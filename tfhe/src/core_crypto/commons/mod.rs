@@ -8,6 +8,9 @@
 //! # Parameters
 //! This module contains structures that wrap unsigned integer parameters like the ciphertext
 //! dimension or the polynomial degree.
+//!
+//! # Security
+//! This module contains an estimator of the security level of LWE instances.
 
 pub mod ciphertext_modulus;
 pub mod computation_buffers;
@@ -16,6 +19,7 @@ pub mod generators;
 pub mod math;
 pub mod numeric;
 pub mod parameters;
+pub mod security;
 pub mod utils;
 
 // Refactor modules
@@ -231,6 +231,13 @@ impl LweBskGroupingFactor {
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub struct GgswPerLweMultiBitBskElement(pub usize);
 
+/// The exponent of a ring automorphism.
+///
+/// Assuming an automorphism $X \mapsto X^k$ of the ring $\mathbb{Z}[X]/(X^N+1)$, this
+/// represents the $k$ value, which needs to be odd.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct AutomorphismExponent(pub usize);
+
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub enum EncryptionKeyChoice {
     Big,
@@ -0,0 +1,290 @@
+//! Module containing an estimator of the security of LWE instances.
+//!
+//! The estimation follows the methodology of the
+//! [lattice estimator](https://github.com/malb/lattice-estimator) with the `BDGL16` reduction cost
+//! model, which is the one used to check the parameters provided by **TFHE-rs**. The secret key is
+//! assumed to be uniform binary, and the number of LWE samples available to the attacker is
+//! unbounded.
+//!
+//! Two attacks are considered, both relying on the scaling of the secret introduced by Bai and
+//! Galbraith to take advantage of the small secret:
+//!
+//! + the primal attack, solving a unique-SVP instance built from the LWE samples;
+//! + the dual attack, distinguishing LWE samples from uniform ones using short vectors of the dual
+//!   lattice.
+//!
+//! The security level of an instance is the base 2 logarithm of the number of operations of the
+//! cheapest attack.
+
+use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::parameters::LweDimension;
+use std::fmt::{Display, Formatter};
+
+/// Instances with a dimension smaller or equal to this value are considered insecure without
+/// running the estimation.
+pub const MINIMUM_LWE_DIMENSION: LweDimension = LweDimension(450);
+/// Instances with a noise standard deviation smaller than this value, expressed on the integer
+/// representation of the torus, are considered insecure without running the estimation.
+pub const MINIMUM_MODULAR_STANDARD_DEV: f64 = 4.0;
+/// The estimation is run for at most this dimension, the security of larger instances being at
+/// least the one of the instance with this dimension.
+pub const MAXIMUM_ESTIMATED_LWE_DIMENSION: LweDimension = LweDimension(16384);
+
+/// The default security level targeted by the parameters of **TFHE-rs**.
+pub const DEFAULT_SECURITY_LEVEL: u32 = 128;
+
+// The parameters are checked with a margin of one bit, to absorb the imprecision of the estimation
+const SECURITY_LEVEL_MARGIN: f64 = 1.0;
+
+// Smallest block size for which the formula of the root hermite factor is used
+const MINIMUM_BLOCK_SIZE: usize = 40;
+// Standard deviation of a uniform binary secret coefficient
+const BINARY_SECRET_STANDARD_DEV: f64 = 0.5;
+// Length of the vectors output by the sieve, relative to the gaussian heuristic
+const SIEVE_VECTOR_LENGTH_FACTOR: f64 = 1.154_700_538_379_251_7;
+// Base 2 logarithm of the number of short vectors output by one call to the sieve, per block size
+const SIEVE_VECTOR_COUNT_LOG2_PER_BLOCK_SIZE: f64 = 0.2075;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LweSecurityError {
+    DimensionTooSmall {
+        lwe_dimension: LweDimension,
+    },
+    NoiseTooSmall {
+        modular_standard_dev: f64,
+    },
+    SecurityLevelTooLow {
+        estimated_security_level: f64,
+        security_level: u32,
+    },
+}
+
+impl Display for LweSecurityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DimensionTooSmall { lwe_dimension } => {
+                write!(
+                    f,
+                    "The LWE dimension {} is too small, it must be greater than {}",
+                    lwe_dimension.0, MINIMUM_LWE_DIMENSION.0
+                )
+            }
+            Self::NoiseTooSmall {
+                modular_standard_dev,
+            } => {
+                write!(
+                    f,
+                    "The modular standard deviation of the noise {modular_standard_dev} is too \
+                    small, it must be at least {MINIMUM_MODULAR_STANDARD_DEV}"
+                )
+            }
+            Self::SecurityLevelTooLow {
+                estimated_security_level,
+                security_level,
+            } => {
+                write!(
+                    f,
+                    "The estimated security level {estimated_security_level} bits is lower than \
+                    the targeted {security_level} bits"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LweSecurityError {}
+
+/// Return the root hermite factor $\delta$ reached by the BKZ algorithm with the given block size.
+fn root_hermite_factor(block_size: f64) -> f64 {
+    use std::f64::consts::{E, PI};
+
+    ((PI * block_size).powf(1.0 / block_size) * block_size / (2.0 * PI * E))
+        .powf(1.0 / (2.0 * (block_size - 1.0)))
+}
+
+/// Return the base 2 logarithm of the cost of running BKZ with the given block size on a lattice
+/// of the given dimension, with the `BDGL16` cost model.
+fn bdgl16_reduction_cost_log2(block_size: f64, lattice_dimension: f64, log2_modulus: u32) -> f64 {
+    let lll_cost_log2 = 3.0 * lattice_dimension.log2() + 2.0 * (log2_modulus as f64).log2();
+    let svp_calls_log2 = if block_size < lattice_dimension {
+        (8.0 * lattice_dimension).log2()
+    } else {
+        0.0
+    };
+    let sieve_cost_log2 = 0.292 * block_size + 16.4 + svp_calls_log2;
+
+    // log2(2^a + 2^b)
+    let (min, max) = if lll_cost_log2 < sieve_cost_log2 {
+        (lll_cost_log2, sieve_cost_log2)
+    } else {
+        (sieve_cost_log2, lll_cost_log2)
+    };
+    max + (1.0 + 2f64.powf(min - max)).log2()
+}
+
+/// Return the base 2 logarithm of the cost of the primal attack, or `None` if no block size
+/// smaller than the lattice dimension solves the instance.
+fn primal_usvp_cost_log2(
+    lwe_dimension: f64,
+    modular_standard_dev: f64,
+    log2_modulus: u32,
+) -> Option<f64> {
+    let ln_modulus = log2_modulus as f64 * std::f64::consts::LN_2;
+    let ln_scaling = (modular_standard_dev / BINARY_SECRET_STANDARD_DEV).ln();
+    // The lattice has volume q^m * scaling^n and dimension d = m + n + 1
+    let ln_volume_without_samples = (lwe_dimension + 1.0) * ln_modulus - lwe_dimension * ln_scaling;
+
+    let lattice_dimension = |block_size: f64| {
+        // Dimension maximizing the right hand side of the success condition
+        let ln_delta = root_hermite_factor(block_size).ln();
+        (ln_volume_without_samples / ln_delta)
+            .sqrt()
+            .max(lwe_dimension + 1.0)
+    };
+
+    // The 2016 estimate: the attack succeeds as soon as the projection of the short vector on the
+    // last block is shorter than the corresponding Gram-Schmidt vector. The short vector must also
+    // be shorter than the vectors of length q of the lattice, otherwise it is not unique.
+    let is_solved = |block_size: f64| {
+        let ln_delta = root_hermite_factor(block_size).ln();
+        let dimension = lattice_dimension(block_size);
+        let is_unique = (modular_standard_dev * dimension.sqrt()).ln() < ln_modulus;
+        let lhs = (modular_standard_dev * block_size.sqrt()).ln();
+        let rhs = (2.0 * block_size - dimension) * ln_delta + ln_modulus
+            - ln_volume_without_samples / dimension;
+        is_unique && lhs <= rhs
+    };
+
+    let mut low = MINIMUM_BLOCK_SIZE;
+    let mut high = lattice_dimension(MINIMUM_BLOCK_SIZE as f64) as usize;
+    if !is_solved(high as f64) {
+        return None;
+    }
+    if is_solved(low as f64) {
+        high = low;
+    }
+    // Invariant: low does not solve the instance, high does
+    while high - low > 1 {
+        let middle = (low + high) / 2;
+        if is_solved(middle as f64) {
+            high = middle;
+        } else {
+            low = middle;
+        }
+    }
+
+    let block_size = high as f64;
+    Some(bdgl16_reduction_cost_log2(
+        block_size,
+        lattice_dimension(block_size),
+        log2_modulus,
+    ))
+}
+
+/// Return the base 2 logarithm of the cost of the dual attack.
+fn dual_cost_log2(lwe_dimension: f64, modular_standard_dev: f64, log2_modulus: u32) -> f64 {
+    let ln_modulus = log2_modulus as f64 * std::f64::consts::LN_2;
+    let ln_scaling = (modular_standard_dev / BINARY_SECRET_STANDARD_DEV).ln();
+    // The scaled dual lattice has volume (q / scaling)^n
+    let ln_volume = lwe_dimension * (ln_modulus - ln_scaling);
+    let maximum_block_size = MAXIMUM_ESTIMATED_LWE_DIMENSION.0 * 2;
+
+    let mut best_cost = f64::INFINITY;
+    for block_size in MINIMUM_BLOCK_SIZE..maximum_block_size {
+        let block_size = block_size as f64;
+        let ln_delta = root_hermite_factor(block_size).ln();
+        // Dimension minimizing the length of the short vectors
+        let dimension = (ln_volume / ln_delta).sqrt().max(block_size);
+        let ln_length =
+            SIEVE_VECTOR_LENGTH_FACTOR.ln() + dimension * ln_delta + ln_volume / dimension;
+
+        let reduction_cost = bdgl16_reduction_cost_log2(block_size, dimension, log2_modulus);
+        // The reduction cost increases with the block size, larger block sizes cannot give a
+        // cheaper attack
+        if reduction_cost >= best_cost {
+            break;
+        }
+
+        // The inner product of the short vector with the samples is a gaussian of standard
+        // deviation length * sigma, the distinguishing advantage is
+        // exp(-2 pi^2 (length * sigma / q)^2)
+        let relative_standard_dev = (ln_length - ln_modulus).exp() * modular_standard_dev;
+        let ln_advantage = -2.0 * std::f64::consts::PI.powi(2) * relative_standard_dev.powi(2);
+        // 1 / advantage^2 short vectors are required to distinguish
+        let required_vectors_log2 = -2.0 * ln_advantage / std::f64::consts::LN_2;
+        let repetitions_log2 =
+            (required_vectors_log2 - SIEVE_VECTOR_COUNT_LOG2_PER_BLOCK_SIZE * block_size).max(0.0);
+
+        best_cost = best_cost.min(reduction_cost + repetitions_log2);
+    }
+
+    best_cost
+}
+
+/// Return the estimated security level in bits of an LWE instance with a uniform binary secret
+/// key, the given dimension and a noise with the given dispersion on the torus of modulus
+/// $2^{log2\_modulus}$.
+///
+/// The dimension is capped to [`MAXIMUM_ESTIMATED_LWE_DIMENSION`], as larger instances are at
+/// least as secure.
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::dispersion::StandardDev;
+/// use tfhe::core_crypto::commons::security::estimate_lwe_security_level;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let security_level =
+///     estimate_lwe_security_level(LweDimension(841), StandardDev(3.1496674685772435e-06), 64);
+/// assert!(security_level > 127.0);
+///
+/// let security_level =
+///     estimate_lwe_security_level(LweDimension(841), StandardDev(3.1496674685772435e-08), 64);
+/// assert!(security_level < 127.0);
+/// ```
+pub fn estimate_lwe_security_level(
+    lwe_dimension: LweDimension,
+    noise: impl DispersionParameter,
+    log2_modulus: u32,
+) -> f64 {
+    let lwe_dimension = lwe_dimension.0.min(MAXIMUM_ESTIMATED_LWE_DIMENSION.0) as f64;
+    let modular_standard_dev = noise.get_modular_standard_dev(log2_modulus);
+
+    let dual_cost = dual_cost_log2(lwe_dimension, modular_standard_dev, log2_modulus);
+    primal_usvp_cost_log2(lwe_dimension, modular_standard_dev, log2_modulus)
+        .map_or(dual_cost, |primal_cost| primal_cost.min(dual_cost))
+}
+
+/// Check that an LWE instance reaches the given security level, returning the estimated security
+/// level on success.
+///
+/// On top of the estimation of [`estimate_lwe_security_level`], instances with a dimension not
+/// greater than [`MINIMUM_LWE_DIMENSION`] or a modular standard deviation smaller than
+/// [`MINIMUM_MODULAR_STANDARD_DEV`] are rejected. An estimated security level is accepted if it
+/// is less than one bit below the target.
+pub fn check_lwe_security(
+    lwe_dimension: LweDimension,
+    noise: impl DispersionParameter,
+    log2_modulus: u32,
+    security_level: u32,
+) -> Result<f64, LweSecurityError> {
+    if lwe_dimension.0 <= MINIMUM_LWE_DIMENSION.0 {
+        return Err(LweSecurityError::DimensionTooSmall { lwe_dimension });
+    }
+
+    let modular_standard_dev = noise.get_modular_standard_dev(log2_modulus);
+    if modular_standard_dev < MINIMUM_MODULAR_STANDARD_DEV {
+        return Err(LweSecurityError::NoiseTooSmall {
+            modular_standard_dev,
+        });
+    }
+
+    let estimated_security_level = estimate_lwe_security_level(lwe_dimension, noise, log2_modulus);
+    if estimated_security_level < security_level as f64 - SECURITY_LEVEL_MARGIN {
+        return Err(LweSecurityError::SecurityLevelTooLow {
+            estimated_security_level,
+            security_level,
+        });
+    }
+
+    Ok(estimated_security_level)
+}
@@ -0,0 +1,283 @@
+use self::packed_integers::PackedIntegers;
+use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::fft_impl::common::modulus_switch;
+use crate::core_crypto::prelude::*;
+
+/// An object to store a GLWE ciphertext in little memory.
+///
+/// The modulus of the ciphertext is decreased by rounding and the result is stored in a compact way
+/// Only the first `bodies_count` coefficients of the body are kept, the others are assumed to be
+/// useless (e.g. when the GLWE was obtained by packing fewer LWEs than the polynomial size)
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.00000000000000029403601535432533), 0.0);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let log_modulus = CiphertextModulusLog(12);
+/// let bodies_count = LweCiphertextCount(10);
+///
+/// let delta = 1u64 << 60;
+/// let plaintext_list = PlaintextList::new(3 * delta, PlaintextCount(polynomial_size.0));
+///
+/// let mut glwe = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut glwe,
+///     &plaintext_list,
+///     glwe_noise_distribution,
+///     &mut encryption_generator,
+/// );
+///
+/// // Can be stored using much less space than the standard glwe ciphertexts
+/// let compressed =
+///     CompressedModulusSwitchedGlweCiphertext::compress(&glwe, log_modulus, bodies_count);
+///
+/// let glwe_ms_ed = compressed.extract();
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, plaintext_list.plaintext_count());
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &glwe_ms_ed, &mut output_plaintext_list);
+///
+/// // Only the first bodies_count coefficients are kept
+/// for (i, plaintext) in output_plaintext_list.iter().enumerate() {
+///     let decoded = (*plaintext.0).wrapping_add(delta / 2) / delta;
+///     if i < bodies_count.0 {
+///         assert_eq!(decoded, 3);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CompressedModulusSwitchedGlweCiphertext<Scalar: UnsignedInteger> {
+    packed_integers: PackedIntegers<Scalar>,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    bodies_count: LweCiphertextCount,
+    uncompressed_ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+impl<Scalar: UnsignedInteger> crate::named::Named for CompressedModulusSwitchedGlweCiphertext<Scalar> {
+    const NAME: &'static str = "core_crypto::CompressedModulusSwitchedGlweCiphertext";
+}
+
+impl<Scalar: UnsignedTorus> CompressedModulusSwitchedGlweCiphertext<Scalar> {
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn bodies_count(&self) -> LweCiphertextCount {
+        self.bodies_count
+    }
+
+    pub fn uncompressed_ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.uncompressed_ciphertext_modulus
+    }
+
+    /// Compresses a ciphertext by reducing its modulus
+    /// This operation adds a lot of noise
+    pub fn compress<Cont: Container<Element = Scalar>>(
+        ct: &GlweCiphertext<Cont>,
+        log_modulus: CiphertextModulusLog,
+        bodies_count: LweCiphertextCount,
+    ) -> Self {
+        let uncompressed_ciphertext_modulus = ct.ciphertext_modulus();
+
+        assert!(
+            ct.ciphertext_modulus().is_power_of_two(),
+            "Modulus switch compression doe not support non power of 2 input moduli",
+        );
+
+        let uncompressed_ciphertext_modulus_log =
+            if uncompressed_ciphertext_modulus.is_native_modulus() {
+                Scalar::BITS
+            } else {
+                uncompressed_ciphertext_modulus.get_custom_modulus().ilog2() as usize
+            };
+
+        let glwe_dimension = ct.glwe_size().to_glwe_dimension();
+        let polynomial_size = ct.polynomial_size();
+
+        assert!(
+            log_modulus.0 <= uncompressed_ciphertext_modulus_log,
+            "The log_modulus (={}) for modulus switch compression must be smaller than the uncompressed ciphertext_modulus_log (={})",
+            log_modulus.0,
+            uncompressed_ciphertext_modulus_log,
+        );
+
+        assert!(
+            bodies_count.0 <= polynomial_size.0,
+            "Number of stored bodies (asked {}) cannot be bigger than polynomial_size ({})",
+            bodies_count.0,
+            polynomial_size.0,
+        );
+
+        let modulus_switched: Vec<_> = ct
+            .as_ref()
+            .iter()
+            .take(
+                glwe_dimension
+                    .to_equivalent_lwe_dimension(polynomial_size)
+                    .0
+                    + bodies_count.0,
+            )
+            .map(|a| modulus_switch(*a, log_modulus))
+            .collect();
+
+        let packed_integers = PackedIntegers::pack(&modulus_switched, log_modulus);
+
+        Self {
+            packed_integers,
+            glwe_dimension,
+            polynomial_size,
+            bodies_count,
+            uncompressed_ciphertext_modulus,
+        }
+    }
+
+    /// Converts back a compressed ciphertext to its initial modulus
+    /// The noise added during the compression stays in the output
+    /// The output must got through a PBS to reduce the noise
+    /// The body coefficients which were not stored are set to zero
+    pub fn extract(&self) -> GlweCiphertextOwned<Scalar> {
+        let log_modulus = self.packed_integers.log_modulus.0;
+
+        let container: Vec<_> = self
+            .packed_integers
+            .unpack()
+            // Scaling
+            .map(|a| a << (Scalar::BITS - log_modulus))
+            .chain(std::iter::repeat(Scalar::ZERO))
+            .take(self.glwe_dimension.to_glwe_size().0 * self.polynomial_size.0)
+            .collect();
+
+        GlweCiphertextOwned::from_container(
+            container,
+            self.polynomial_size,
+            self.uncompressed_ciphertext_modulus,
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger> ParameterSetConformant
+    for CompressedModulusSwitchedGlweCiphertext<Scalar>
+{
+    type ParameterSet = GlweCiphertextParameters<Scalar>;
+
+    fn is_conformant(&self, params: &GlweCiphertextParameters<Scalar>) -> bool {
+        let mask_size = self
+            .glwe_dimension
+            .to_equivalent_lwe_dimension(self.polynomial_size)
+            .0;
+
+        let number_bits_to_pack =
+            (mask_size + self.bodies_count.0) * self.packed_integers.log_modulus.0;
+
+        let len = number_bits_to_pack.div_ceil(Scalar::BITS);
+
+        self.packed_integers.packed_coeffs.len() == len
+            && self.packed_integers.initial_len == mask_size + self.bodies_count.0
+            && self.bodies_count.0 <= self.polynomial_size.0
+            && self.glwe_dimension == params.glwe_dim
+            && self.polynomial_size == params.polynomial_size
+            && params.ct_modulus.is_power_of_two()
+            && self.uncompressed_ciphertext_modulus == params.ct_modulus
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core_crypto::prelude::test::TestResources;
+
+    #[test]
+    fn glwe_ms_compression_() {
+        glwe_ms_compression::<u32>(1, GlweDimension(1), PolynomialSize(512), 100);
+        glwe_ms_compression::<u32>(10, GlweDimension(2), PolynomialSize(64), 64);
+        glwe_ms_compression::<u32>(11, GlweDimension(1), PolynomialSize(1024), 1);
+        glwe_ms_compression::<u32>(12, GlweDimension(3), PolynomialSize(256), 0);
+
+        glwe_ms_compression::<u64>(1, GlweDimension(1), PolynomialSize(512), 100);
+        glwe_ms_compression::<u64>(10, GlweDimension(2), PolynomialSize(64), 64);
+        glwe_ms_compression::<u64>(11, GlweDimension(1), PolynomialSize(1024), 1);
+        glwe_ms_compression::<u64>(12, GlweDimension(4), PolynomialSize(256), 256);
+        glwe_ms_compression::<u64>(33, GlweDimension(1), PolynomialSize(2048), 17);
+        glwe_ms_compression::<u64>(63, GlweDimension(1), PolynomialSize(128), 127);
+    }
+
+    fn glwe_ms_compression<Scalar: UnsignedTorus + CastInto<usize> + CastFrom<usize>>(
+        log_modulus: usize,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        bodies_count: usize,
+    ) {
+        let mut rsc: TestResources = TestResources::new();
+
+        let ciphertext_modulus = CiphertextModulus::new_native();
+
+        let mut glwe = vec![Scalar::ZERO; glwe_dimension.to_glwe_size().0 * polynomial_size.0];
+
+        rsc.encryption_random_generator
+            .fill_slice_with_random_uniform_mask(&mut glwe);
+
+        let glwe = GlweCiphertextOwned::from_container(glwe, polynomial_size, ciphertext_modulus);
+
+        let compressed = CompressedModulusSwitchedGlweCiphertext::compress(
+            &glwe,
+            CiphertextModulusLog(log_modulus),
+            LweCiphertextCount(bodies_count),
+        );
+
+        assert!(compressed.is_conformant(&GlweCiphertextParameters {
+            glwe_dim: glwe_dimension,
+            polynomial_size,
+            ct_modulus: ciphertext_modulus,
+        }));
+
+        let glwe_ms_ed: Vec<Scalar> = compressed.extract().into_container();
+
+        let glwe = glwe.into_container();
+
+        let stored_len = glwe_dimension
+            .to_equivalent_lwe_dimension(polynomial_size)
+            .0
+            + bodies_count;
+
+        for (i, output) in glwe_ms_ed.into_iter().enumerate() {
+            if i < stored_len {
+                assert_eq!(
+                    output,
+                    (output >> (Scalar::BITS - log_modulus)) << (Scalar::BITS - log_modulus),
+                );
+
+                assert_eq!(
+                    output >> (Scalar::BITS - log_modulus),
+                    modulus_switch(glwe[i], CiphertextModulusLog(log_modulus))
+                );
+            } else {
+                assert_eq!(output, Scalar::ZERO);
+            }
+        }
+    }
+}
@@ -63,6 +63,10 @@ pub struct CompressedModulusSwitchedLweCiphertext<Scalar: UnsignedInteger> {
     uncompressed_ciphertext_modulus: CiphertextModulus<Scalar>,
 }
 
+impl<Scalar: UnsignedInteger> crate::named::Named for CompressedModulusSwitchedLweCiphertext<Scalar> {
+    const NAME: &'static str = "core_crypto::CompressedModulusSwitchedLweCiphertext";
+}
+
 impl<Scalar: UnsignedTorus> CompressedModulusSwitchedLweCiphertext<Scalar> {
     /// Compresses a ciphertext by reducing its modulus
     /// This operation adds a lot of noise
@@ -168,7 +168,7 @@ pub struct CompressedModulusSwitchedMultiBitLweCiphertext<
     Scalar: UnsignedInteger + CastInto<usize> + CastFrom<usize>,
 > {
     body: usize,
-    packed_mask: PackedIntegers<usize>,
+    packed_mask: PackedIntegers<Scalar>,
     packed_diffs: Option<PackedIntegers<usize>>,
     lwe_dimension: LweDimension,
     uncompressed_ciphertext_modulus: CiphertextModulus<Scalar>,
@@ -210,10 +210,10 @@ impl<Scalar: UnsignedInteger + CastInto<usize> + CastFrom<usize>>
 
         let body = modulus_switch(*input_lwe_body.data, log_modulus).cast_into();
 
-        let modulus_switched: Vec<usize> = ct
+        let modulus_switched: Vec<Scalar> = ct
             .as_ref()
             .iter()
-            .map(|a| modulus_switch(*a, log_modulus).cast_into())
+            .map(|a| modulus_switch(*a, log_modulus))
             .collect();
 
         let mut diffs = vec![];
@@ -310,7 +310,7 @@ impl<Scalar: UnsignedInteger + CastInto<usize> + CastFrom<usize>>
     /// The noise added during the compression stays in the output
     /// The output must got through a PBS to reduce the noise
     pub fn extract(&self) -> FromCompressionMultiBitModulusSwitchedCt {
-        let masks: Vec<usize> = self.packed_mask.unpack().collect();
+        let masks: Vec<usize> = self.packed_mask.unpack().map(CastInto::cast_into).collect();
 
         let mut diffs_two_complement: Vec<usize> = vec![];
 
@@ -400,9 +400,9 @@ impl MultiBitModulusSwitchedCt for FromCompressionMultiBitModulusSwitchedCt {
 impl<Scalar: UnsignedInteger + CastInto<usize> + CastFrom<usize>> ParameterSetConformant
     for CompressedModulusSwitchedMultiBitLweCiphertext<Scalar>
 {
-    type ParameterSet = LweCiphertextParameters<u64>;
+    type ParameterSet = LweCiphertextParameters<Scalar>;
 
-    fn is_conformant(&self, lwe_ct_parameters: &LweCiphertextParameters<u64>) -> bool {
+    fn is_conformant(&self, lwe_ct_parameters: &LweCiphertextParameters<Scalar>) -> bool {
         let lwe_dim = self.lwe_dimension.0;
 
         let number_mask_bits_to_pack = lwe_dim * self.packed_mask.log_modulus.0;
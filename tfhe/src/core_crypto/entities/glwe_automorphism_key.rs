@@ -0,0 +1,277 @@
+//! Module containing the definition of the [`GlweAutomorphismKey`].
+
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// A key allowing to apply the ring automorphism $X \mapsto X^k$ to
+/// [`a GLWE ciphertext`](super::GlweCiphertext) while keeping it encrypted under the same
+/// [`GLWE secret key`](super::GlweSecretKey).
+///
+/// Applying the automorphism to the polynomials of a GLWE ciphertext encrypted under a key $S$
+/// yields a GLWE ciphertext encrypted under the key $S(X^k)$, the automorphism key is a
+/// [`GlweKeyswitchKey`] from $S(X^k)$ back to $S$.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GlweAutomorphismKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    glwe_keyswitch_key: GlweKeyswitchKey<C>,
+    automorphism_exponent: AutomorphismExponent,
+}
+
+impl<C: Container> crate::named::Named for GlweAutomorphismKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::GlweAutomorphismKey";
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for GlweAutomorphismKey<C> {
+    fn as_ref(&self) -> &[T] {
+        self.glwe_keyswitch_key.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for GlweAutomorphismKey<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.glwe_keyswitch_key.as_mut()
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> GlweAutomorphismKey<C> {
+    /// Create a [`GlweAutomorphismKey`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to generate a
+    /// [`GlweAutomorphismKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_automorphism_key`] using this key as
+    /// output.
+    ///
+    /// This docstring exhibits [`GlweAutomorphismKey`] primitives usage.
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for GlweAutomorphismKey creation
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let decomp_base_log = DecompositionBaseLog(4);
+    /// let decomp_level_count = DecompositionLevelCount(5);
+    /// let automorphism_exponent = AutomorphismExponent(3);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// // Create a new GlweAutomorphismKey
+    /// let auto_key = GlweAutomorphismKey::new(
+    ///     0u64,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     automorphism_exponent,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(auto_key.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(auto_key.decomposition_level_count(), decomp_level_count);
+    /// assert_eq!(auto_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(auto_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(auto_key.automorphism_exponent(), automorphism_exponent);
+    /// assert_eq!(auto_key.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container: Vec<u64> = auto_key.into_container();
+    ///
+    /// // Recreate a key using from_container
+    /// let auto_key = GlweAutomorphismKey::from_container(
+    ///     underlying_container,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     automorphism_exponent,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(auto_key.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(auto_key.decomposition_level_count(), decomp_level_count);
+    /// assert_eq!(auto_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(auto_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(auto_key.automorphism_exponent(), automorphism_exponent);
+    /// assert_eq!(auto_key.ciphertext_modulus(), ciphertext_modulus);
+    /// ```
+    pub fn from_container(
+        container: C,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        automorphism_exponent: AutomorphismExponent,
+        ciphertext_modulus: CiphertextModulus<C::Element>,
+    ) -> Self {
+        assert!(
+            automorphism_exponent.0 % 2 == 1 && automorphism_exponent.0 < 2 * polynomial_size.0,
+            "The automorphism exponent needs to be odd and smaller than 2 * polynomial_size. \
+            Got automorphism_exponent: {automorphism_exponent:?} \
+            and polynomial_size: {polynomial_size:?}."
+        );
+
+        let glwe_keyswitch_key = GlweKeyswitchKey::from_container(
+            container,
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        assert!(
+            glwe_keyswitch_key.input_key_glwe_dimension() == glwe_size.to_glwe_dimension(),
+            "The provided container length is not valid for a GlweAutomorphismKey, \
+            the input and output GlweDimension must be equal. \
+            Got container length: {} and glwe_size: {glwe_size:?}.",
+            glwe_keyswitch_key.as_ref().len()
+        );
+
+        Self {
+            glwe_keyswitch_key,
+            automorphism_exponent,
+        }
+    }
+
+    /// Return the [`DecompositionBaseLog`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.glwe_keyswitch_key.decomposition_base_log()
+    }
+
+    /// Return the [`DecompositionLevelCount`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.glwe_keyswitch_key.decomposition_level_count()
+    }
+
+    /// Return the [`GlweDimension`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_keyswitch_key.output_key_glwe_dimension()
+    }
+
+    /// Return the [`GlweSize`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_keyswitch_key.output_glwe_size()
+    }
+
+    /// Return the [`PolynomialSize`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.glwe_keyswitch_key.polynomial_size()
+    }
+
+    /// Return the [`AutomorphismExponent`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn automorphism_exponent(&self) -> AutomorphismExponent {
+        self.automorphism_exponent
+    }
+
+    /// Return the [`CiphertextModulus`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
+        self.glwe_keyswitch_key.ciphertext_modulus()
+    }
+
+    /// Return a view of the [`GlweKeyswitchKey`] from the automorphism of the key to the key
+    /// itself.
+    pub fn as_glwe_keyswitch_key(&self) -> GlweKeyswitchKeyView<'_, Scalar> {
+        self.glwe_keyswitch_key.as_view()
+    }
+
+    /// Return a view of the [`GlweAutomorphismKey`]. This is useful if an algorithm takes a view
+    /// by value.
+    pub fn as_view(&self) -> GlweAutomorphismKey<&'_ [Scalar]> {
+        GlweAutomorphismKey {
+            glwe_keyswitch_key: self.glwe_keyswitch_key.as_view(),
+            automorphism_exponent: self.automorphism_exponent,
+        }
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.glwe_keyswitch_key.into_container()
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> GlweAutomorphismKey<C> {
+    /// Mutable variant of [`GlweAutomorphismKey::as_view`].
+    pub fn as_mut_view(&mut self) -> GlweAutomorphismKey<&'_ mut [Scalar]> {
+        GlweAutomorphismKey {
+            glwe_keyswitch_key: self.glwe_keyswitch_key.as_mut_view(),
+            automorphism_exponent: self.automorphism_exponent,
+        }
+    }
+
+    /// Mutable variant of [`GlweAutomorphismKey::as_glwe_keyswitch_key`].
+    pub fn as_mut_glwe_keyswitch_key(&mut self) -> GlweKeyswitchKeyMutView<'_, Scalar> {
+        self.glwe_keyswitch_key.as_mut_view()
+    }
+}
+
+/// A [`GlweAutomorphismKey`] owning the memory for its own storage.
+pub type GlweAutomorphismKeyOwned<Scalar> = GlweAutomorphismKey<Vec<Scalar>>;
+/// A [`GlweAutomorphismKey`] immutably borrowing memory for its own storage.
+pub type GlweAutomorphismKeyView<'data, Scalar> = GlweAutomorphismKey<&'data [Scalar]>;
+/// A [`GlweAutomorphismKey`] mutably borrowing memory for its own storage.
+pub type GlweAutomorphismKeyMutView<'data, Scalar> = GlweAutomorphismKey<&'data mut [Scalar]>;
+
+impl<Scalar: UnsignedInteger> GlweAutomorphismKeyOwned<Scalar> {
+    /// Allocate memory and create a new owned [`GlweAutomorphismKey`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to generate a [`GlweAutomorphismKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_automorphism_key`] using this key as
+    /// output.
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn new(
+        fill_with: Scalar,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        automorphism_exponent: AutomorphismExponent,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self::from_container(
+            vec![
+                fill_with;
+                glwe_dimension.0
+                    * glwe_keyswitch_key_input_key_element_encrypted_size(
+                        decomp_level_count,
+                        glwe_dimension.to_glwe_size(),
+                        polynomial_size,
+                    )
+            ],
+            decomp_base_log,
+            decomp_level_count,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            automorphism_exponent,
+            ciphertext_modulus,
+        )
+    }
+}
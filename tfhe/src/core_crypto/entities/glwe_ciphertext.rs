@@ -309,6 +309,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> crate::named::Named for GlweCiphertext<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::GlweCiphertext";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for GlweCiphertext<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
@@ -594,6 +601,16 @@ impl<Scalar: UnsignedInteger> GlweCiphertextOwned<Scalar> {
     }
 }
 
+/// Structure to store the expected properties of a GLWE ciphertext
+/// Can be used on a server to check if client inputs are well formed
+/// before running a computation on them
+#[derive(Copy, Clone)]
+pub struct GlweCiphertextParameters<T: UnsignedInteger> {
+    pub glwe_dim: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub ct_modulus: CiphertextModulus<T>,
+}
+
 /// Metadata used in the [`CreateFrom`] implementation to create [`GlweCiphertext`] entities.
 #[derive(Clone, Copy)]
 pub struct GlweCiphertextCreationMetadata<Scalar: UnsignedInteger>(
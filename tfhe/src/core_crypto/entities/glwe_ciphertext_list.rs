@@ -17,6 +17,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> crate::named::Named for GlweCiphertextList<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::GlweCiphertextList";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for GlweCiphertextList<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
@@ -0,0 +1,279 @@
+//! Module containing the definition of the [`GlweRelinearisationKey`].
+
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// A key allowing to relinearise the output of a
+/// [`GLWE tensor product`](`crate::core_crypto::algorithms::glwe_tensor_product()`) back to
+/// [`a GLWE ciphertext`](super::GlweCiphertext) encrypted under the
+/// [`GLWE secret key`](super::GlweSecretKey) of the multiplied ciphertexts.
+///
+/// The tensor product of two ciphertexts encrypted under a key $(S\_0, \ldots, S\_{k-1})$ is
+/// encrypted under a key containing the products $S\_i S\_j$ for $i \leq j$, the relinearisation
+/// key is a [`GlweKeyswitchKey`] from the GLWE secret key made of these products to the original
+/// key.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GlweRelinearisationKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    glwe_keyswitch_key: GlweKeyswitchKey<C>,
+}
+
+impl<C: Container> crate::named::Named for GlweRelinearisationKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::GlweRelinearisationKey";
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for GlweRelinearisationKey<C> {
+    fn as_ref(&self) -> &[T] {
+        self.glwe_keyswitch_key.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for GlweRelinearisationKey<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.glwe_keyswitch_key.as_mut()
+    }
+}
+
+/// Return the [`GlweDimension`] of the GLWE secret key made of the products of pairs of
+/// polynomials of a [`super::GlweSecretKey`] with a given [`GlweDimension`], i.e. the input key
+/// [`GlweDimension`] of the [`GlweRelinearisationKey`] for that key.
+pub fn glwe_relinearisation_key_input_key_glwe_dimension(
+    glwe_dimension: GlweDimension,
+) -> GlweDimension {
+    GlweDimension(glwe_dimension.0 * (glwe_dimension.0 + 1) / 2)
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> GlweRelinearisationKey<C> {
+    /// Create a [`GlweRelinearisationKey`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to generate a
+    /// [`GlweRelinearisationKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_relinearisation_key`] using this key as
+    /// output.
+    ///
+    /// This docstring exhibits [`GlweRelinearisationKey`] primitives usage.
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for GlweRelinearisationKey creation
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let decomp_base_log = DecompositionBaseLog(4);
+    /// let decomp_level_count = DecompositionLevelCount(5);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// // Create a new GlweRelinearisationKey
+    /// let relin_key = GlweRelinearisationKey::new(
+    ///     0u64,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(relin_key.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(relin_key.decomposition_level_count(), decomp_level_count);
+    /// assert_eq!(relin_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(
+    ///     relin_key.input_key_glwe_dimension(),
+    ///     glwe_relinearisation_key_input_key_glwe_dimension(glwe_dimension)
+    /// );
+    /// assert_eq!(relin_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(relin_key.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container: Vec<u64> = relin_key.into_container();
+    ///
+    /// // Recreate a key using from_container
+    /// let relin_key = GlweRelinearisationKey::from_container(
+    ///     underlying_container,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(relin_key.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(relin_key.decomposition_level_count(), decomp_level_count);
+    /// assert_eq!(relin_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(
+    ///     relin_key.input_key_glwe_dimension(),
+    ///     glwe_relinearisation_key_input_key_glwe_dimension(glwe_dimension)
+    /// );
+    /// assert_eq!(relin_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(relin_key.ciphertext_modulus(), ciphertext_modulus);
+    /// ```
+    pub fn from_container(
+        container: C,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        ciphertext_modulus: CiphertextModulus<C::Element>,
+    ) -> Self {
+        let glwe_keyswitch_key = GlweKeyswitchKey::from_container(
+            container,
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        assert!(
+            glwe_keyswitch_key.input_key_glwe_dimension()
+                == glwe_relinearisation_key_input_key_glwe_dimension(glwe_size.to_glwe_dimension()),
+            "The provided container length is not valid for a GlweRelinearisationKey, \
+            the input GlweDimension must be glwe_dimension * (glwe_dimension + 1) / 2. \
+            Got container length: {} and glwe_size: {glwe_size:?}.",
+            glwe_keyswitch_key.as_ref().len()
+        );
+
+        Self { glwe_keyswitch_key }
+    }
+
+    /// Return the [`DecompositionBaseLog`] of the [`GlweRelinearisationKey`].
+    ///
+    /// See [`GlweRelinearisationKey::from_container`] for usage.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.glwe_keyswitch_key.decomposition_base_log()
+    }
+
+    /// Return the [`DecompositionLevelCount`] of the [`GlweRelinearisationKey`].
+    ///
+    /// See [`GlweRelinearisationKey::from_container`] for usage.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.glwe_keyswitch_key.decomposition_level_count()
+    }
+
+    /// Return the [`GlweDimension`] of the [`GlweSecretKey`] the [`GlweRelinearisationKey`]
+    /// relinearises to.
+    ///
+    /// See [`GlweRelinearisationKey::from_container`] for usage.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_keyswitch_key.output_key_glwe_dimension()
+    }
+
+    /// Return the [`GlweSize`] of the ciphertexts output by the relinearisation.
+    ///
+    /// See [`GlweRelinearisationKey::from_container`] for usage.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_keyswitch_key.output_glwe_size()
+    }
+
+    /// Return the input key [`GlweDimension`] of the [`GlweRelinearisationKey`], i.e. the number
+    /// of products of pairs of secret key polynomials.
+    ///
+    /// See [`GlweRelinearisationKey::from_container`] for usage.
+    pub fn input_key_glwe_dimension(&self) -> GlweDimension {
+        self.glwe_keyswitch_key.input_key_glwe_dimension()
+    }
+
+    /// Return the [`PolynomialSize`] of the [`GlweRelinearisationKey`].
+    ///
+    /// See [`GlweRelinearisationKey::from_container`] for usage.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.glwe_keyswitch_key.polynomial_size()
+    }
+
+    /// Return the [`CiphertextModulus`] of the [`GlweRelinearisationKey`].
+    ///
+    /// See [`GlweRelinearisationKey::from_container`] for usage.
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
+        self.glwe_keyswitch_key.ciphertext_modulus()
+    }
+
+    /// Return a view of the [`GlweKeyswitchKey`] from the products of pairs of secret key
+    /// polynomials to the secret key.
+    pub fn as_glwe_keyswitch_key(&self) -> GlweKeyswitchKeyView<'_, Scalar> {
+        self.glwe_keyswitch_key.as_view()
+    }
+
+    /// Return a view of the [`GlweRelinearisationKey`]. This is useful if an algorithm takes a
+    /// view by value.
+    pub fn as_view(&self) -> GlweRelinearisationKey<&'_ [Scalar]> {
+        GlweRelinearisationKey {
+            glwe_keyswitch_key: self.glwe_keyswitch_key.as_view(),
+        }
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`GlweRelinearisationKey::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.glwe_keyswitch_key.into_container()
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> GlweRelinearisationKey<C> {
+    /// Mutable variant of [`GlweRelinearisationKey::as_view`].
+    pub fn as_mut_view(&mut self) -> GlweRelinearisationKey<&'_ mut [Scalar]> {
+        GlweRelinearisationKey {
+            glwe_keyswitch_key: self.glwe_keyswitch_key.as_mut_view(),
+        }
+    }
+
+    /// Mutable variant of [`GlweRelinearisationKey::as_glwe_keyswitch_key`].
+    pub fn as_mut_glwe_keyswitch_key(&mut self) -> GlweKeyswitchKeyMutView<'_, Scalar> {
+        self.glwe_keyswitch_key.as_mut_view()
+    }
+}
+
+/// A [`GlweRelinearisationKey`] owning the memory for its own storage.
+pub type GlweRelinearisationKeyOwned<Scalar> = GlweRelinearisationKey<Vec<Scalar>>;
+/// A [`GlweRelinearisationKey`] immutably borrowing memory for its own storage.
+pub type GlweRelinearisationKeyView<'data, Scalar> = GlweRelinearisationKey<&'data [Scalar]>;
+/// A [`GlweRelinearisationKey`] mutably borrowing memory for its own storage.
+pub type GlweRelinearisationKeyMutView<'data, Scalar> =
+    GlweRelinearisationKey<&'data mut [Scalar]>;
+
+impl<Scalar: UnsignedInteger> GlweRelinearisationKeyOwned<Scalar> {
+    /// Allocate memory and create a new owned [`GlweRelinearisationKey`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to generate a [`GlweRelinearisationKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_relinearisation_key`] using this key as
+    /// output.
+    ///
+    /// See [`GlweRelinearisationKey::from_container`] for usage.
+    pub fn new(
+        fill_with: Scalar,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self::from_container(
+            vec![
+                fill_with;
+                glwe_relinearisation_key_input_key_glwe_dimension(glwe_dimension).0
+                    * glwe_keyswitch_key_input_key_element_encrypted_size(
+                        decomp_level_count,
+                        glwe_dimension.to_glwe_size(),
+                        polynomial_size,
+                    )
+            ],
+            decomp_base_log,
+            decomp_level_count,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        )
+    }
+}
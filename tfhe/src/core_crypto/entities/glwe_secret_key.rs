@@ -24,6 +24,10 @@ pub struct GlweSecretKey<C: Container> {
     polynomial_size: PolynomialSize,
 }
 
+impl<C: Container> crate::named::Named for GlweSecretKey<C> {
+    const NAME: &'static str = "core_crypto::GlweSecretKey";
+}
+
 impl<T, C: Container<Element = T>> AsRef<[T]> for GlweSecretKey<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
@@ -105,6 +105,13 @@ where
     ggsw_list: GgswCiphertextList<C>,
 }
 
+impl<C: Container> crate::named::Named for LweBootstrapKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::LweBootstrapKey";
+}
+
 impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> std::ops::Deref
     for LweBootstrapKey<C>
 {
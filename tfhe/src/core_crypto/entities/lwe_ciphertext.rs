@@ -506,6 +506,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> crate::named::Named for LweCiphertext<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::LweCiphertext";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for LweCiphertext<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
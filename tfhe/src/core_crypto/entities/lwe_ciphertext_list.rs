@@ -18,6 +18,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> crate::named::Named for LweCiphertextList<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::LweCiphertextList";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for LweCiphertextList<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
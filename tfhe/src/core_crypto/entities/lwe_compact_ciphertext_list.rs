@@ -26,6 +26,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> crate::named::Named for LweCompactCiphertextList<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::LweCompactCiphertextList";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for LweCompactCiphertextList<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
@@ -16,6 +16,13 @@ where
     glwe_ciphertext: GlweCiphertext<C>,
 }
 
+impl<C: Container> crate::named::Named for LweCompactPublicKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::LweCompactPublicKey";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for LweCompactPublicKey<C> {
     fn as_ref(&self) -> &[T] {
         self.glwe_ciphertext.as_ref()
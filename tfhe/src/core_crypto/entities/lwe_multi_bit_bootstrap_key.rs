@@ -21,6 +21,13 @@ where
     grouping_factor: LweBskGroupingFactor,
 }
 
+impl<C: Container> crate::named::Named for LweMultiBitBootstrapKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::LweMultiBitBootstrapKey";
+}
+
 impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> std::ops::Deref
     for LweMultiBitBootstrapKey<C>
 {
@@ -393,6 +400,10 @@ pub struct FourierLweMultiBitBootstrapKey<C: Container<Element = c64>> {
     grouping_factor: LweBskGroupingFactor,
 }
 
+impl<C: Container<Element = c64>> crate::named::Named for FourierLweMultiBitBootstrapKey<C> {
+    const NAME: &'static str = "core_crypto::FourierLweMultiBitBootstrapKey";
+}
+
 pub type FourierLweMultiBitBootstrapKeyOwned = FourierLweMultiBitBootstrapKey<ABox<[c64]>>;
 pub type FourierLweMultiBitBootstrapKeyView<'a> = FourierLweMultiBitBootstrapKey<&'a [c64]>;
 pub type FourierLweMultiBitBootstrapKeyMutView<'a> = FourierLweMultiBitBootstrapKey<&'a mut [c64]>;
@@ -23,6 +23,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> crate::named::Named for LwePackingKeyswitchKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::LwePackingKeyswitchKey";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for LwePackingKeyswitchKey<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
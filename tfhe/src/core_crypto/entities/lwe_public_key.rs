@@ -28,6 +28,13 @@ where
     lwe_list: LweCiphertextList<C>,
 }
 
+impl<C: Container> crate::named::Named for LwePublicKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::LwePublicKey";
+}
+
 impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> std::ops::Deref for LwePublicKey<C> {
     type Target = LweCiphertextList<C>;
 
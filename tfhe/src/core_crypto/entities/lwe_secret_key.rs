@@ -21,6 +21,10 @@ pub struct LweSecretKey<C: Container> {
     data: C,
 }
 
+impl<C: Container> crate::named::Named for LweSecretKey<C> {
+    const NAME: &'static str = "core_crypto::LweSecretKey";
+}
+
 impl<T, C: Container<Element = T>> AsRef<[T]> for LweSecretKey<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
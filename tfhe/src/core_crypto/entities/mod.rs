@@ -4,12 +4,16 @@
 //! associated to the object, e.g., `get_mask` for the entity `LweCiphertext`.
 
 pub mod cleartext;
+pub mod compressed_modulus_switched_glwe_ciphertext;
 pub mod compressed_modulus_switched_lwe_ciphertext;
 pub mod compressed_modulus_switched_multi_bit_lwe_ciphertext;
 pub mod ggsw_ciphertext;
 pub mod ggsw_ciphertext_list;
+pub mod glwe_automorphism_key;
 pub mod glwe_ciphertext;
 pub mod glwe_ciphertext_list;
+pub mod glwe_keyswitch_key;
+pub mod glwe_relinearisation_key;
 pub mod glwe_secret_key;
 pub mod gsw_ciphertext;
 pub mod lwe_bootstrap_key;
@@ -27,6 +31,7 @@ pub mod lwe_secret_key;
 pub mod ntt_ggsw_ciphertext;
 pub mod ntt_ggsw_ciphertext_list;
 pub mod ntt_lwe_bootstrap_key;
+pub mod ntt_lwe_multi_bit_bootstrap_key;
 pub mod packed_integers;
 pub mod plaintext;
 pub mod plaintext_list;
@@ -36,6 +41,7 @@ pub mod seeded_ggsw_ciphertext;
 pub mod seeded_ggsw_ciphertext_list;
 pub mod seeded_glwe_ciphertext;
 pub mod seeded_glwe_ciphertext_list;
+pub mod seeded_glwe_keyswitch_key;
 pub mod seeded_lwe_bootstrap_key;
 pub mod seeded_lwe_ciphertext;
 pub mod seeded_lwe_ciphertext_list;
@@ -57,16 +63,26 @@ pub use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::{
 pub use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
     FourierGgswCiphertext, FourierGgswCiphertextList, FourierGgswLevelMatrix, FourierGgswLevelRow,
 };
+pub use crate::core_crypto::fft_impl::fft64::crypto::glwe_automorphism::{
+    FourierGlweAutomorphismKey, FourierGlweAutomorphismKeyOwned, FourierGlweAutomorphismKeyView,
+};
+pub use crate::core_crypto::fft_impl::fft64::crypto::glwe_keyswitch::{
+    FourierGlweKeyswitchKey, FourierGlweKeyswitchKeyOwned, FourierGlweKeyswitchKeyView,
+};
 pub use crate::core_crypto::fft_impl::fft64::math::polynomial::FourierPolynomial;
 #[cfg(feature = "zk-pok-experimental")]
 pub use crate::zk::*;
 pub use cleartext::*;
+pub use compressed_modulus_switched_glwe_ciphertext::*;
 pub use compressed_modulus_switched_lwe_ciphertext::*;
 pub use compressed_modulus_switched_multi_bit_lwe_ciphertext::*;
 pub use ggsw_ciphertext::*;
 pub use ggsw_ciphertext_list::*;
+pub use glwe_automorphism_key::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_list::*;
+pub use glwe_keyswitch_key::*;
+pub use glwe_relinearisation_key::*;
 pub use glwe_secret_key::*;
 pub use gsw_ciphertext::*;
 pub use lwe_bootstrap_key::*;
@@ -84,6 +100,7 @@ pub use lwe_secret_key::*;
 pub use ntt_ggsw_ciphertext::*;
 pub use ntt_ggsw_ciphertext_list::*;
 pub use ntt_lwe_bootstrap_key::*;
+pub use ntt_lwe_multi_bit_bootstrap_key::*;
 pub use plaintext::*;
 pub use plaintext_list::*;
 pub use polynomial::*;
@@ -92,6 +109,7 @@ pub use seeded_ggsw_ciphertext::*;
 pub use seeded_ggsw_ciphertext_list::*;
 pub use seeded_glwe_ciphertext::*;
 pub use seeded_glwe_ciphertext_list::*;
+pub use seeded_glwe_keyswitch_key::*;
 pub use seeded_lwe_bootstrap_key::*;
 pub use seeded_lwe_ciphertext::*;
 pub use seeded_lwe_ciphertext_list::*;
@@ -0,0 +1,333 @@
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::parameters::{
+    CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount,
+    GlweSize, LweBskGroupingFactor, LweDimension, PolynomialSize,
+};
+use crate::core_crypto::commons::traits::{Container, ContainerMut, Split};
+use crate::core_crypto::entities::ntt_ggsw_ciphertext::NttGgswCiphertext;
+use crate::core_crypto::entities::ntt_ggsw_ciphertext_list::NttGgswCiphertextList;
+use crate::core_crypto::entities::polynomial_list::{PolynomialListMutView, PolynomialListView};
+use aligned_vec::ABox;
+
+/// An [`LWE multi bit bootstrap key`](`crate::core_crypto::entities::LweMultiBitBootstrapKey`) in
+/// the NTT domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NttLweMultiBitBootstrapKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    ggsw_list: NttGgswCiphertextList<C>,
+    input_lwe_dimension: LweDimension,
+    grouping_factor: LweBskGroupingFactor,
+}
+
+/// Return the number of [`NttGgswCiphertext`] in an [`NttLweMultiBitBootstrapKey`] for a given
+/// input [`LweDimension`] and [`LweBskGroupingFactor`].
+fn ntt_lwe_multi_bit_bootstrap_key_ggsw_count(
+    input_lwe_dimension: LweDimension,
+    grouping_factor: LweBskGroupingFactor,
+) -> GgswCiphertextCount {
+    assert!(
+        input_lwe_dimension.0 % grouping_factor.0 == 0,
+        "Multi Bit BSK requires input LWE dimension ({}) to be a multiple of {}",
+        input_lwe_dimension.0,
+        grouping_factor.0
+    );
+    let equivalent_multi_bit_dimension = input_lwe_dimension.0 / grouping_factor.0;
+    GgswCiphertextCount(
+        equivalent_multi_bit_dimension * grouping_factor.ggsw_per_multi_bit_element().0,
+    )
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> NttLweMultiBitBootstrapKey<C> {
+    /// Create an [`NttLweMultiBitBootstrapKey`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to have useful
+    /// data in the [`NttLweMultiBitBootstrapKey`] you will first need to convert it from a
+    /// standard
+    /// [`LweMultiBitBootstrapKey`](`crate::core_crypto::entities::LweMultiBitBootstrapKey`) by
+    /// calling
+    /// [`convert_standard_lwe_multi_bit_bootstrap_key_to_ntt64`](crate::core_crypto::algorithms::convert_standard_lwe_multi_bit_bootstrap_key_to_ntt64).
+    ///
+    /// This docstring exhibits [`NttLweMultiBitBootstrapKey`] primitives usage.
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for NttLweMultiBitBootstrapKey creation
+    /// let glwe_size = GlweSize(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let decomp_base_log = DecompositionBaseLog(8);
+    /// let decomp_level_count = DecompositionLevelCount(3);
+    /// let input_lwe_dimension = LweDimension(600);
+    /// let grouping_factor = LweBskGroupingFactor(2);
+    /// let ciphertext_modulus = CiphertextModulus::try_new((1 << 64) - (1 << 32) + 1).unwrap();
+    ///
+    /// // Create a new NttLweMultiBitBootstrapKey
+    /// let bsk = NttLweMultiBitBootstrapKey::new(
+    ///     0u64,
+    ///     input_lwe_dimension,
+    ///     glwe_size,
+    ///     polynomial_size,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     grouping_factor,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(bsk.glwe_size(), glwe_size);
+    /// assert_eq!(bsk.polynomial_size(), polynomial_size);
+    /// assert_eq!(bsk.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(bsk.decomposition_level_count(), decomp_level_count);
+    /// assert_eq!(bsk.ciphertext_modulus(), ciphertext_modulus);
+    /// assert_eq!(bsk.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(
+    ///     bsk.multi_bit_input_lwe_dimension(),
+    ///     LweDimension(input_lwe_dimension.0 / grouping_factor.0)
+    /// );
+    /// assert_eq!(bsk.grouping_factor(), grouping_factor);
+    /// assert_eq!(
+    ///     bsk.output_lwe_dimension(),
+    ///     glwe_size
+    ///         .to_glwe_dimension()
+    ///         .to_equivalent_lwe_dimension(polynomial_size)
+    /// );
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container = bsk.into_container();
+    ///
+    /// // Recreate a key using from_container
+    /// let bsk = NttLweMultiBitBootstrapKey::from_container(
+    ///     underlying_container,
+    ///     input_lwe_dimension,
+    ///     glwe_size,
+    ///     polynomial_size,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     grouping_factor,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(bsk.glwe_size(), glwe_size);
+    /// assert_eq!(bsk.polynomial_size(), polynomial_size);
+    /// assert_eq!(bsk.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(bsk.decomposition_level_count(), decomp_level_count);
+    /// assert_eq!(bsk.ciphertext_modulus(), ciphertext_modulus);
+    /// assert_eq!(bsk.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(
+    ///     bsk.multi_bit_input_lwe_dimension(),
+    ///     LweDimension(input_lwe_dimension.0 / grouping_factor.0)
+    /// );
+    /// assert_eq!(bsk.grouping_factor(), grouping_factor);
+    /// assert_eq!(
+    ///     bsk.output_lwe_dimension(),
+    ///     glwe_size
+    ///         .to_glwe_dimension()
+    ///         .to_equivalent_lwe_dimension(polynomial_size)
+    /// );
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_container(
+        data: C,
+        input_lwe_dimension: LweDimension,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        grouping_factor: LweBskGroupingFactor,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        let ggsw_list = NttGgswCiphertextList::from_container(
+            data,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            ciphertext_modulus,
+        );
+
+        assert_eq!(
+            ggsw_list.ggsw_ciphertext_count(),
+            ntt_lwe_multi_bit_bootstrap_key_ggsw_count(input_lwe_dimension, grouping_factor),
+            "The provided container does not have the expected number of GGSW ciphertexts for \
+            an input LweDimension {input_lwe_dimension:?} and {grouping_factor:?}."
+        );
+
+        Self {
+            ggsw_list,
+            input_lwe_dimension,
+            grouping_factor,
+        }
+    }
+
+    /// Return an iterator over the contiguous [`NttGgswCiphertext`]. This consumes the entity,
+    /// consider calling [`NttLweMultiBitBootstrapKey::as_view`] or
+    /// [`NttLweMultiBitBootstrapKey::as_mut_view`] first to have an iterator over borrowed
+    /// contents instead of consuming the original entity.
+    pub fn into_ggsw_iter(self) -> impl DoubleEndedIterator<Item = NttGgswCiphertext<C>>
+    where
+        C: Split,
+    {
+        self.ggsw_list.into_ggsw_iter()
+    }
+
+    /// Return the [`LweDimension`] of the input
+    /// [`LweSecretKey`](`crate::core_crypto::entities::LweSecretKey`).
+    ///
+    /// See [`NttLweMultiBitBootstrapKey::from_container`] for usage.
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    /// Return the [`LweDimension`] of the input
+    /// [`LweSecretKey`](`crate::core_crypto::entities::LweSecretKey`) divided by the
+    /// [`LweBskGroupingFactor`], i.e. the number of external products of a blind rotation.
+    ///
+    /// See [`NttLweMultiBitBootstrapKey::from_container`] for usage.
+    pub fn multi_bit_input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.input_lwe_dimension.0 / self.grouping_factor.0)
+    }
+
+    /// Return the [`PolynomialSize`] of the [`NttLweMultiBitBootstrapKey`].
+    ///
+    /// See [`NttLweMultiBitBootstrapKey::from_container`] for usage.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.ggsw_list.polynomial_size()
+    }
+
+    /// Return the [`GlweSize`] of the [`NttLweMultiBitBootstrapKey`].
+    ///
+    /// See [`NttLweMultiBitBootstrapKey::from_container`] for usage.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.ggsw_list.glwe_size()
+    }
+
+    /// Return the [`DecompositionBaseLog`] of the [`NttLweMultiBitBootstrapKey`].
+    ///
+    /// See [`NttLweMultiBitBootstrapKey::from_container`] for usage.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.ggsw_list.decomposition_base_log()
+    }
+
+    /// Return the [`DecompositionLevelCount`] of the [`NttLweMultiBitBootstrapKey`].
+    ///
+    /// See [`NttLweMultiBitBootstrapKey::from_container`] for usage.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.ggsw_list.decomposition_level_count()
+    }
+
+    /// Return the [`LweBskGroupingFactor`] of the [`NttLweMultiBitBootstrapKey`].
+    ///
+    /// See [`NttLweMultiBitBootstrapKey::from_container`] for usage.
+    pub fn grouping_factor(&self) -> LweBskGroupingFactor {
+        self.grouping_factor
+    }
+
+    /// Return the [`LweDimension`] of the equivalent output
+    /// [`LweSecretKey`](`crate::core_crypto::entities::LweSecretKey`).
+    ///
+    /// See [`NttLweMultiBitBootstrapKey::from_container`] for usage.
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        self.glwe_size()
+            .to_glwe_dimension()
+            .to_equivalent_lwe_dimension(self.polynomial_size())
+    }
+
+    /// Return the [`CiphertextModulus`] of the [`NttLweMultiBitBootstrapKey`].
+    ///
+    /// See [`NttLweMultiBitBootstrapKey::from_container`] for usage.
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.ggsw_list.ciphertext_modulus()
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`NttLweMultiBitBootstrapKey::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.ggsw_list.into_container()
+    }
+
+    /// Return a view of the [`NttLweMultiBitBootstrapKey`]. This is useful if an algorithm takes a
+    /// view by value.
+    pub fn as_view(&self) -> NttLweMultiBitBootstrapKeyView<'_, Scalar> {
+        NttLweMultiBitBootstrapKeyView {
+            ggsw_list: self.ggsw_list.as_view(),
+            input_lwe_dimension: self.input_lwe_dimension,
+            grouping_factor: self.grouping_factor,
+        }
+    }
+
+    /// Interpret the [`NttLweMultiBitBootstrapKey`] as a
+    /// [`PolynomialList`](`crate::core_crypto::entities::PolynomialList`).
+    pub fn as_polynomial_list(&self) -> PolynomialListView<'_, Scalar> {
+        self.ggsw_list.as_polynomial_list()
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> NttLweMultiBitBootstrapKey<C> {
+    /// Mutable variant of [`NttLweMultiBitBootstrapKey::as_view`].
+    pub fn as_mut_view(&mut self) -> NttLweMultiBitBootstrapKeyMutView<'_, Scalar> {
+        NttLweMultiBitBootstrapKeyMutView {
+            ggsw_list: self.ggsw_list.as_mut_view(),
+            input_lwe_dimension: self.input_lwe_dimension,
+            grouping_factor: self.grouping_factor,
+        }
+    }
+
+    /// Mutable variant of [`NttLweMultiBitBootstrapKey::as_polynomial_list`].
+    pub fn as_mut_polynomial_list(&mut self) -> PolynomialListMutView<'_, Scalar> {
+        self.ggsw_list.as_mut_polynomial_list()
+    }
+}
+
+pub type NttLweMultiBitBootstrapKeyOwned<Scalar> = NttLweMultiBitBootstrapKey<ABox<[Scalar]>>;
+pub type NttLweMultiBitBootstrapKeyView<'data, Scalar> =
+    NttLweMultiBitBootstrapKey<&'data [Scalar]>;
+pub type NttLweMultiBitBootstrapKeyMutView<'data, Scalar> =
+    NttLweMultiBitBootstrapKey<&'data mut [Scalar]>;
+
+impl<Scalar: UnsignedInteger> NttLweMultiBitBootstrapKey<ABox<[Scalar]>> {
+    /// Allocate memory and create a new owned [`NttLweMultiBitBootstrapKey`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to have useful data in the [`NttLweMultiBitBootstrapKey`] you will first
+    /// need to convert it from a standard
+    /// [`LweMultiBitBootstrapKey`](`crate::core_crypto::entities::LweMultiBitBootstrapKey`) by
+    /// calling
+    /// [`convert_standard_lwe_multi_bit_bootstrap_key_to_ntt64`](crate::core_crypto::algorithms::convert_standard_lwe_multi_bit_bootstrap_key_to_ntt64).
+    ///
+    /// See [`NttLweMultiBitBootstrapKey::from_container`] for usage.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        fill_with: Scalar,
+        input_lwe_dimension: LweDimension,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        grouping_factor: LweBskGroupingFactor,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        let ggsw_list = NttGgswCiphertextList::new(
+            fill_with,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            ntt_lwe_multi_bit_bootstrap_key_ggsw_count(input_lwe_dimension, grouping_factor),
+            ciphertext_modulus,
+        );
+
+        Self {
+            ggsw_list,
+            input_lwe_dimension,
+            grouping_factor,
+        }
+    }
+}
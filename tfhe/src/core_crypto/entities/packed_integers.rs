@@ -1,6 +1,6 @@
 use crate::core_crypto::prelude::*;
 
-#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct PackedIntegers<Scalar: UnsignedInteger> {
     pub(super) packed_coeffs: Vec<Scalar>,
     pub(super) log_modulus: CiphertextModulusLog,
@@ -23,6 +23,13 @@ where
     ggsw_list: SeededGgswCiphertextList<C>,
 }
 
+impl<C: Container> crate::named::Named for SeededLweBootstrapKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::SeededLweBootstrapKey";
+}
+
 impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> std::ops::Deref
     for SeededLweBootstrapKey<C>
 {
@@ -17,6 +17,10 @@ pub struct SeededLweCiphertext<Scalar: UnsignedInteger> {
     ciphertext_modulus: CiphertextModulus<Scalar>,
 }
 
+impl<Scalar: UnsignedInteger> crate::named::Named for SeededLweCiphertext<Scalar> {
+    const NAME: &'static str = "core_crypto::SeededLweCiphertext";
+}
+
 impl<T: UnsignedInteger> ParameterSetConformant for SeededLweCiphertext<T> {
     type ParameterSet = LweCiphertextParameters<T>;
 
@@ -18,6 +18,13 @@ where
     seeded_glwe_ciphertext: SeededGlweCiphertext<C>,
 }
 
+impl<C: Container> crate::named::Named for SeededLweCompactPublicKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::SeededLweCompactPublicKey";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for SeededLweCompactPublicKey<C> {
     fn as_ref(&self) -> &[T] {
         self.seeded_glwe_ciphertext.as_ref()
@@ -20,6 +20,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> crate::named::Named for SeededLweKeyswitchKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::SeededLweKeyswitchKey";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for SeededLweKeyswitchKey<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
@@ -29,6 +29,13 @@ where
     grouping_factor: LweBskGroupingFactor,
 }
 
+impl<C: Container> crate::named::Named for SeededLweMultiBitBootstrapKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::SeededLweMultiBitBootstrapKey";
+}
+
 impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> std::ops::Deref
     for SeededLweMultiBitBootstrapKey<C>
 {
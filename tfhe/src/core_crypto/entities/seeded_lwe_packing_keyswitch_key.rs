@@ -21,6 +21,13 @@ where
     ciphertext_modulus: CiphertextModulus<C::Element>,
 }
 
+impl<C: Container> crate::named::Named for SeededLwePackingKeyswitchKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::SeededLwePackingKeyswitchKey";
+}
+
 impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for SeededLwePackingKeyswitchKey<C> {
     fn as_ref(&self) -> &[T] {
         self.data.as_ref()
@@ -32,6 +32,13 @@ where
     lwe_list: SeededLweCiphertextList<C>,
 }
 
+impl<C: Container> crate::named::Named for SeededLwePublicKey<C>
+where
+    C::Element: UnsignedInteger,
+{
+    const NAME: &'static str = "core_crypto::SeededLwePublicKey";
+}
+
 impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> std::ops::Deref
     for SeededLwePublicKey<C>
 {
@@ -0,0 +1,335 @@
+//! Module containing primitives pertaining to the threshold decryption of [`LWE
+//! ciphertexts`](`LweCiphertext`).
+
+use crate::core_crypto::algorithms::slice_algorithms::{
+    slice_wrapping_dot_product, slice_wrapping_sub_assign,
+};
+use crate::core_crypto::commons::generators::{EncryptionRandomGenerator, SecretRandomGenerator};
+use crate::core_crypto::commons::math::random::{Distribution, RandomGenerable, Uniform};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::{LweCiphertext, LweSecretKey, Plaintext};
+use crate::core_crypto::experimental::commons::parameters::{
+    DecryptionThreshold, PartyCount, PartyIndex,
+};
+use crate::core_crypto::experimental::entities::{
+    threshold_sharing_excluded_subsets, LweThresholdSecretKeyShare, LweThresholdSecretKeyShareOwned,
+};
+
+/// Share an [`LWE secret key`](`LweSecretKey`) among parties, filling one
+/// [`LweThresholdSecretKeyShare`] per party.
+///
+/// The shares must be ordered by [`PartyIndex`], and must all have been created for the same
+/// [`PartyCount`] and [`DecryptionThreshold`].
+///
+/// See [`partial_decrypt_lwe_ciphertext`] for usage.
+///
+/// # Formal Definition
+///
+/// See the [`LweThresholdSecretKeyShare`] formal definition.
+pub fn generate_lwe_threshold_secret_key_shares<Scalar, KeyCont, OutputCont, Gen>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    output_shares: &mut [LweThresholdSecretKeyShare<OutputCont>],
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedInteger + RandomGenerable<Uniform>,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let lwe_dimension = lwe_secret_key.lwe_dimension();
+    let party_count = PartyCount(output_shares.len());
+    let threshold = output_shares
+        .first()
+        .expect("At least one share is required")
+        .decryption_threshold();
+
+    for (index, share) in output_shares.iter().enumerate() {
+        assert_eq!(
+            share.party_index(),
+            PartyIndex(index),
+            "Shares must be ordered by party index, got {:?} at position {index}",
+            share.party_index()
+        );
+        assert_eq!(
+            share.party_count(),
+            party_count,
+            "Mismatch between the PartyCount of the share {:?} and the number of shares {:?}",
+            share.party_count(),
+            party_count
+        );
+        assert_eq!(
+            share.decryption_threshold(),
+            threshold,
+            "Mismatch between the DecryptionThreshold of the shares. Got {:?} and {threshold:?}",
+            share.decryption_threshold()
+        );
+        assert_eq!(
+            share.lwe_dimension(),
+            lwe_dimension,
+            "Mismatch between LweDimension of the share and the input secret key. \
+            Got {:?} in share, and {lwe_dimension:?} in secret key.",
+            share.lwe_dimension()
+        );
+    }
+
+    let excluded_subsets = threshold_sharing_excluded_subsets(party_count, threshold);
+
+    // All the sub-keys are uniformly random except the last one which is set so that they sum to
+    // the input secret key
+    let mut sub_keys = vec![Scalar::ZERO; excluded_subsets.len() * lwe_dimension.0];
+    let (random_sub_keys, last_sub_key) =
+        sub_keys.split_at_mut((excluded_subsets.len() - 1) * lwe_dimension.0);
+
+    // The sub-keys are secret, they are drawn from the secret generator
+    generator.fill_slice_with_random_uniform(random_sub_keys);
+
+    last_sub_key.copy_from_slice(lwe_secret_key.as_ref());
+    for random_sub_key in random_sub_keys.chunks_exact(lwe_dimension.0) {
+        slice_wrapping_sub_assign(last_sub_key, random_sub_key);
+    }
+
+    for share in output_shares.iter_mut() {
+        let party_index = share.party_index();
+
+        let held_sub_keys = excluded_subsets
+            .iter()
+            .zip(sub_keys.chunks_exact(lwe_dimension.0))
+            .filter(|(excluded, _)| !excluded.contains(&party_index))
+            .map(|(_, sub_key)| sub_key);
+
+        for ((_, mut output_sub_key), sub_key) in share.sub_key_iter_mut().zip(held_sub_keys) {
+            output_sub_key.as_mut().copy_from_slice(sub_key);
+        }
+    }
+}
+
+/// Allocate one [`LweThresholdSecretKeyShare`] per party and fill them with shares of the input
+/// [`LWE secret key`](`LweSecretKey`).
+///
+/// The returned shares are ordered by [`PartyIndex`].
+///
+/// See [`partial_decrypt_lwe_ciphertext`] for usage.
+pub fn allocate_and_generate_lwe_threshold_secret_key_shares<Scalar, KeyCont, Gen>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    party_count: PartyCount,
+    threshold: DecryptionThreshold,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> Vec<LweThresholdSecretKeyShareOwned<Scalar>>
+where
+    Scalar: UnsignedInteger + RandomGenerable<Uniform>,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut shares: Vec<_> = (0..party_count.0)
+        .map(|index| {
+            LweThresholdSecretKeyShareOwned::new(
+                Scalar::ZERO,
+                lwe_secret_key.lwe_dimension(),
+                PartyIndex(index),
+                party_count,
+                threshold,
+            )
+        })
+        .collect();
+
+    generate_lwe_threshold_secret_key_shares(lwe_secret_key, &mut shares, generator);
+
+    shares
+}
+
+/// Compute the partial decryption of an [`LWE ciphertext`](`LweCiphertext`) by one of the
+/// parties taking part in a threshold decryption.
+///
+/// `participants` is the strictly increasing list of the parties taking part in the decryption,
+/// it must contain at least [`DecryptionThreshold`] parties including the owner of the share, and
+/// must be the same for all the participants.
+///
+/// Noise drawn from the flooding noise distribution is added to the partial decryption so that
+/// the combined result does not leak the noise of the ciphertext, and with it information about
+/// the secret key. The flooding noise of all the participants adds up in the combined plaintext,
+/// so the distribution has to be chosen small enough for the decryption to remain correct.
+///
+/// # Formal Definition
+///
+/// Each sub-key $\vec{s}\_T$ is used by exactly one participant: the first one in `participants`
+/// that holds it. Given an LWE ciphertext $(\vec{a}, b)$ the partial decryption of a party is
+/// $$d\_i = \sum\_{T \text{ used by } i} \langle \vec{a}, \vec{s}\_T \rangle + e\_i$$
+/// where $e\_i$ is sampled from the flooding noise distribution.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::experimental::prelude::*;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_noise_distribution =
+///     Gaussian::from_dispersion_parameter(StandardDev(0.000007069849454709433), 0.0);
+/// let flooding_noise_distribution = TUniform::new(50);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// let party_count = PartyCount(5);
+/// let threshold = DecryptionThreshold(3);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey and share it among the parties
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+/// let shares = allocate_and_generate_lwe_threshold_secret_key_shares(
+///     &lwe_secret_key,
+///     party_count,
+///     threshold,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let plaintext = Plaintext(msg << 60);
+///
+/// // Create a new LweCiphertext
+/// let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     plaintext,
+///     lwe_noise_distribution,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Three parties cooperate to decrypt
+/// let participants = [PartyIndex(0), PartyIndex(2), PartyIndex(4)];
+/// let partial_decryptions: Vec<_> = participants
+///     .iter()
+///     .map(|party| {
+///         partial_decrypt_lwe_ciphertext(
+///             &shares[party.0],
+///             &lwe,
+///             &participants,
+///             flooding_noise_distribution,
+///             &mut encryption_generator,
+///         )
+///     })
+///     .collect();
+///
+/// let decrypted_plaintext = combine_lwe_partial_decryptions(&lwe, &partial_decryptions);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// let rounded = decomposer.closest_representable(decrypted_plaintext.0);
+///
+/// // Remove the encoding
+/// let cleartext = rounded >> 60;
+///
+/// // Check we recovered the original message
+/// assert_eq!(cleartext, msg);
+/// ```
+pub fn partial_decrypt_lwe_ciphertext<Scalar, NoiseDistribution, KeyCont, InputCont, Gen>(
+    lwe_secret_key_share: &LweThresholdSecretKeyShare<KeyCont>,
+    lwe_ciphertext: &LweCiphertext<InputCont>,
+    participants: &[PartyIndex],
+    flooding_noise_distribution: NoiseDistribution,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> Plaintext<Scalar>
+where
+    Scalar: UnsignedInteger + RandomGenerable<NoiseDistribution>,
+    NoiseDistribution: Distribution,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        lwe_ciphertext.lwe_size().to_lwe_dimension() == lwe_secret_key_share.lwe_dimension(),
+        "Mismatch between LweDimension of input ciphertext and secret key share. \
+        Got {:?} in input, and {:?} in secret key share.",
+        lwe_ciphertext.lwe_size().to_lwe_dimension(),
+        lwe_secret_key_share.lwe_dimension()
+    );
+    assert!(
+        lwe_ciphertext.ciphertext_modulus().is_native_modulus(),
+        "Threshold decryption only supports the native modulus"
+    );
+
+    let party_index = lwe_secret_key_share.party_index();
+    let party_count = lwe_secret_key_share.party_count();
+    let threshold = lwe_secret_key_share.decryption_threshold();
+
+    assert!(
+        participants.windows(2).all(|pair| pair[0] < pair[1]),
+        "participants must be strictly increasing, got {participants:?}"
+    );
+    assert!(
+        participants
+            .last()
+            .is_some_and(|last| last.0 < party_count.0),
+        "participants must be smaller than the party count {party_count:?}, got {participants:?}"
+    );
+    assert!(
+        participants.len() >= threshold.0,
+        "At least {threshold:?} participants are required to decrypt, got {participants:?}"
+    );
+    assert!(
+        participants.contains(&party_index),
+        "The owner of the share {party_index:?} must be in the participants {participants:?}"
+    );
+
+    let mask = lwe_ciphertext.get_mask();
+
+    let mask_key_dot_product = lwe_secret_key_share
+        .sub_key_iter()
+        .filter(|(excluded, _)| {
+            // The sub-key is used by the first participant that holds it
+            participants
+                .iter()
+                .find(|participant| !excluded.contains(participant))
+                == Some(&party_index)
+        })
+        .fold(Scalar::ZERO, |acc, (_, sub_key)| {
+            acc.wrapping_add(slice_wrapping_dot_product(mask.as_ref(), sub_key.as_ref()))
+        });
+
+    let flooding_noise: Scalar =
+        generator.random_noise_from_distribution(flooding_noise_distribution);
+
+    Plaintext(mask_key_dot_product.wrapping_add(flooding_noise))
+}
+
+/// Combine the partial decryptions of an [`LWE ciphertext`](`LweCiphertext`) computed by all the
+/// participants of a threshold decryption and return a noisy plaintext.
+///
+/// See [`partial_decrypt_lwe_ciphertext`] for usage.
+///
+/// # Formal Definition
+///
+/// Given an LWE ciphertext $(\vec{a}, b)$ and the partial decryptions $d\_i$, the plaintext is
+/// $$b - \sum\_i d\_i$$
+pub fn combine_lwe_partial_decryptions<Scalar, InputCont>(
+    lwe_ciphertext: &LweCiphertext<InputCont>,
+    partial_decryptions: &[Plaintext<Scalar>],
+) -> Plaintext<Scalar>
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    assert!(
+        lwe_ciphertext.ciphertext_modulus().is_native_modulus(),
+        "Threshold decryption only supports the native modulus"
+    );
+
+    let body = *lwe_ciphertext.get_body().data;
+
+    Plaintext(
+        partial_decryptions
+            .iter()
+            .fold(body, |acc, partial| acc.wrapping_sub(partial.0)),
+    )
+}
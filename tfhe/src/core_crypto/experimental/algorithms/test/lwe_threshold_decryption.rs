@@ -0,0 +1,165 @@
+use super::*;
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_add_assign;
+
+fn lwe_encrypt_threshold_decrypt(party_count: PartyCount, threshold: DecryptionThreshold) {
+    let lwe_dimension = LweDimension(742);
+    let lwe_noise_distribution =
+        DynamicDistribution::new_gaussian_from_std_dev(StandardDev(0.000007069849454709433));
+    let flooding_noise_distribution = DynamicDistribution::<u64>::new_t_uniform(50);
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+
+    let msg_modulus = 1u64 << 4;
+    let delta = get_encoding_with_padding(ciphertext_modulus) / msg_modulus;
+
+    let mut rsc = TestResources::new();
+
+    let lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+
+    let shares = allocate_and_generate_lwe_threshold_secret_key_shares(
+        &lwe_secret_key,
+        party_count,
+        threshold,
+        &mut rsc.secret_random_generator,
+    );
+
+    // Every subset of participants of the threshold size must be able to decrypt
+    let all_participants: Vec<_> = (0..party_count.0).map(PartyIndex).collect();
+    let mut participant_sets: Vec<Vec<PartyIndex>> = threshold_sharing_excluded_subsets(
+        party_count,
+        DecryptionThreshold(party_count.0 - threshold.0 + 1),
+    )
+    .into_iter()
+    .map(|excluded| {
+        all_participants
+            .iter()
+            .copied()
+            .filter(|party| !excluded.contains(party))
+            .collect()
+    })
+    .collect();
+    participant_sets.push(all_participants);
+
+    for participants in participant_sets {
+        for msg in 0..msg_modulus {
+            let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+                &lwe_secret_key,
+                Plaintext(msg * delta),
+                lwe_noise_distribution,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let partial_decryptions: Vec<_> = participants
+                .iter()
+                .map(|party| {
+                    partial_decrypt_lwe_ciphertext(
+                        &shares[party.0],
+                        &lwe,
+                        &participants,
+                        flooding_noise_distribution,
+                        &mut rsc.encryption_random_generator,
+                    )
+                })
+                .collect();
+
+            let decrypted = combine_lwe_partial_decryptions(&lwe, &partial_decryptions);
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(decoded, msg, "Err with participants {participants:?}");
+        }
+    }
+}
+
+#[test]
+fn lwe_encrypt_threshold_decrypt_1_out_of_1() {
+    lwe_encrypt_threshold_decrypt(PartyCount(1), DecryptionThreshold(1));
+}
+
+#[test]
+fn lwe_encrypt_threshold_decrypt_2_out_of_3() {
+    lwe_encrypt_threshold_decrypt(PartyCount(3), DecryptionThreshold(2));
+}
+
+#[test]
+fn lwe_encrypt_threshold_decrypt_3_out_of_5() {
+    lwe_encrypt_threshold_decrypt(PartyCount(5), DecryptionThreshold(3));
+}
+
+#[test]
+fn lwe_threshold_secret_key_shares_below_threshold_miss_a_sub_key() {
+    let party_count = PartyCount(4);
+    let threshold = DecryptionThreshold(3);
+
+    let mut rsc = TestResources::new();
+
+    let lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        LweDimension(16),
+        &mut rsc.secret_random_generator,
+    );
+
+    let shares = allocate_and_generate_lwe_threshold_secret_key_shares(
+        &lwe_secret_key,
+        party_count,
+        threshold,
+        &mut rsc.secret_random_generator,
+    );
+
+    let excluded_subsets = threshold_sharing_excluded_subsets(party_count, threshold);
+
+    // The parties of an excluded subset together never hold the corresponding sub-key
+    for excluded in &excluded_subsets {
+        let held_by_excluded = excluded.iter().any(|party| {
+            shares[party.0]
+                .sub_key_iter()
+                .any(|(sub_key_excluded, _)| &sub_key_excluded == excluded)
+        });
+        assert!(!held_by_excluded);
+    }
+
+    // Summing all the distinct sub-keys gives back the secret key
+    let mut sum = vec![0u64; lwe_secret_key.lwe_dimension().0];
+    for excluded in &excluded_subsets {
+        let holder = (0..party_count.0)
+            .map(PartyIndex)
+            .find(|party| !excluded.contains(party))
+            .unwrap();
+        let (_, sub_key) = shares[holder.0]
+            .sub_key_iter()
+            .find(|(sub_key_excluded, _)| sub_key_excluded == excluded)
+            .unwrap();
+        slice_wrapping_add_assign(&mut sum, sub_key.as_ref());
+    }
+
+    assert_eq!(sum, lwe_secret_key.as_ref());
+}
+
+#[test]
+fn lwe_threshold_excluded_subset_count() {
+    for party_count in 1..=8 {
+        for threshold in 1..=party_count {
+            let (party_count, threshold) =
+                (PartyCount(party_count), DecryptionThreshold(threshold));
+            assert_eq!(
+                threshold_sharing_excluded_subset_count(party_count, threshold),
+                Some(threshold_sharing_excluded_subsets(party_count, threshold).len())
+            );
+        }
+    }
+
+    assert_eq!(
+        threshold_sharing_excluded_subset_count(PartyCount(3), DecryptionThreshold(0)),
+        None
+    );
+    assert_eq!(
+        threshold_sharing_excluded_subset_count(PartyCount(3), DecryptionThreshold(4)),
+        None
+    );
+    // C(100, 50) does not fit in 64 bits
+    assert_eq!(
+        threshold_sharing_excluded_subset_count(PartyCount(100), DecryptionThreshold(51)),
+        None
+    );
+}
@@ -43,3 +43,17 @@ impl crate::core_crypto::commons::parameters::LweDimension {
         LweSecretKeyUnsharedCoefCount(self.0 - shared_coef_count.0)
     }
 }
+
+/// The number of parties among which a secret key is shared for threshold decryption.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct PartyCount(pub usize);
+
+/// The index of a party taking part in threshold decryption, between 0 (included) and the
+/// [`PartyCount`] (excluded).
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Serialize, Deserialize)]
+pub struct PartyIndex(pub usize);
+
+/// The minimum number of parties that have to cooperate to decrypt with a threshold shared secret
+/// key.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct DecryptionThreshold(pub usize);
@@ -0,0 +1,254 @@
+//! Module containing the definition of the [`LweThresholdSecretKeyShare`].
+
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::experimental::commons::parameters::{
+    DecryptionThreshold, PartyCount, PartyIndex,
+};
+
+/// The share of an [`LWE secret key`](`LweSecretKey`) owned by one party in a threshold
+/// decryption setting.
+///
+/// # Formal Definition
+///
+/// The secret key $\vec{s}$ is shared among $N$ parties with a threshold $t$ using replicated
+/// secret sharing: for each subset $T$ of $t - 1$ parties, a sub-key $\vec{s}\_T$ is drawn
+/// uniformly at random such that
+/// $$\vec{s} = \sum\_{T} \vec{s}\_T$$
+/// The sub-key $\vec{s}\_T$ is given to all the parties that are not in $T$.
+///
+/// Any set of $t$ parties holds all the sub-keys, while any set of $t - 1$ parties misses at least
+/// one of them and learns nothing about $\vec{s}$.
+///
+/// The share of a party is the concatenation of the
+/// $\binom{N - 1}{t - 1}$ sub-keys it holds, in the order given by
+/// [`LweThresholdSecretKeyShare::sub_key_iter`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LweThresholdSecretKeyShare<C: Container> {
+    data: C,
+    lwe_dimension: LweDimension,
+    party_index: PartyIndex,
+    party_count: PartyCount,
+    threshold: DecryptionThreshold,
+}
+
+impl<T, C: Container<Element = T>> AsRef<[T]> for LweThresholdSecretKeyShare<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T, C: ContainerMut<Element = T>> AsMut<[T]> for LweThresholdSecretKeyShare<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+/// Return the subsets of `threshold - 1` parties that index the sub-keys of a threshold shared
+/// secret key, in lexicographic order.
+///
+/// The sub-key at a given index is held by all the parties that are not in the corresponding
+/// subset.
+pub fn threshold_sharing_excluded_subsets(
+    party_count: PartyCount,
+    threshold: DecryptionThreshold,
+) -> Vec<Vec<PartyIndex>> {
+    assert!(
+        threshold.0 >= 1 && threshold.0 <= party_count.0,
+        "The threshold {threshold:?} must be between 1 and the party count {party_count:?}"
+    );
+
+    let subset_len = threshold.0 - 1;
+    let mut subsets = vec![];
+    let mut current: Vec<usize> = (0..subset_len).collect();
+
+    loop {
+        subsets.push(current.iter().copied().map(PartyIndex).collect());
+
+        // Find the rightmost element that can still be incremented
+        let Some(pos) = (0..subset_len)
+            .rev()
+            .find(|&i| current[i] < party_count.0 - subset_len + i)
+        else {
+            break;
+        };
+
+        current[pos] += 1;
+        for i in pos + 1..subset_len {
+            current[i] = current[i - 1] + 1;
+        }
+    }
+
+    subsets
+}
+
+/// Return the number of subsets of `threshold - 1` parties, that is the number of sub-keys of a
+/// threshold shared secret key, or [`None`] if computing it overflows a [`usize`].
+///
+/// Returns [`None`] as well if the threshold is not between 1 and the party count.
+pub fn threshold_sharing_excluded_subset_count(
+    party_count: PartyCount,
+    threshold: DecryptionThreshold,
+) -> Option<usize> {
+    if threshold.0 == 0 || threshold.0 > party_count.0 {
+        return None;
+    }
+
+    // C(n, k) is computed incrementally, C(n - k + i, i) is an integer at every step
+    let subset_len = (threshold.0 - 1).min(party_count.0 - threshold.0 + 1);
+    (1..=subset_len).try_fold(1usize, |count, i| {
+        count
+            .checked_mul(party_count.0 - subset_len + i)
+            .map(|product| product / i)
+    })
+}
+
+/// Return the number of sub-keys held by each party of a threshold shared secret key.
+pub fn threshold_secret_key_share_sub_key_count(
+    party_count: PartyCount,
+    threshold: DecryptionThreshold,
+) -> usize {
+    threshold_sharing_excluded_subsets(party_count, threshold)
+        .iter()
+        .filter(|subset| !subset.contains(&PartyIndex(0)))
+        .count()
+}
+
+impl<Scalar, C: Container<Element = Scalar>> LweThresholdSecretKeyShare<C> {
+    /// Create an [`LweThresholdSecretKeyShare`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to share an
+    /// existing [`LweSecretKey`] you need to use
+    /// [`crate::core_crypto::experimental::algorithms::allocate_and_generate_lwe_threshold_secret_key_shares`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the container does not hold exactly the number of sub-keys expected for the
+    /// given party count and threshold, or if the party index is out of range.
+    pub fn from_container(
+        container: C,
+        lwe_dimension: LweDimension,
+        party_index: PartyIndex,
+        party_count: PartyCount,
+        threshold: DecryptionThreshold,
+    ) -> Self {
+        assert!(
+            party_index.0 < party_count.0,
+            "The party index {party_index:?} must be smaller than the party count {party_count:?}"
+        );
+        let sub_key_count = threshold_secret_key_share_sub_key_count(party_count, threshold);
+        assert_eq!(
+            container.container_len(),
+            sub_key_count * lwe_dimension.0,
+            "The provided container length is not valid. \
+            It needs to be equal to the number of sub-keys ({sub_key_count}) \
+            times the LweDimension ({lwe_dimension:?})."
+        );
+
+        Self {
+            data: container,
+            lwe_dimension,
+            party_index,
+            party_count,
+            threshold,
+        }
+    }
+
+    /// Return the [`LweDimension`] of the shared [`LweSecretKey`].
+    pub fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    /// Return the [`PartyIndex`] of the party owning the [`LweThresholdSecretKeyShare`].
+    pub fn party_index(&self) -> PartyIndex {
+        self.party_index
+    }
+
+    /// Return the [`PartyCount`] of the parties the [`LweSecretKey`] is shared with.
+    pub fn party_count(&self) -> PartyCount {
+        self.party_count
+    }
+
+    /// Return the [`DecryptionThreshold`] of the sharing.
+    pub fn decryption_threshold(&self) -> DecryptionThreshold {
+        self.threshold
+    }
+
+    /// Return an iterator over the sub-keys held by the party.
+    ///
+    /// Each sub-key is returned along the subset of parties that do not hold it.
+    pub fn sub_key_iter<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (Vec<PartyIndex>, LweSecretKeyView<'a, Scalar>)>
+    where
+        Scalar: 'a,
+    {
+        let party_index = self.party_index;
+
+        threshold_sharing_excluded_subsets(self.party_count, self.threshold)
+            .into_iter()
+            .filter(move |subset| !subset.contains(&party_index))
+            .zip(self.data.as_ref().chunks_exact(self.lwe_dimension.0))
+            .map(|(subset, sub_key)| (subset, LweSecretKey::from_container(sub_key)))
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`LweThresholdSecretKeyShare::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.data
+    }
+}
+
+impl<Scalar, C: ContainerMut<Element = Scalar>> LweThresholdSecretKeyShare<C> {
+    /// Return an iterator over the mutable sub-keys held by the party.
+    ///
+    /// See [`LweThresholdSecretKeyShare::sub_key_iter`].
+    pub fn sub_key_iter_mut<'a>(
+        &'a mut self,
+    ) -> impl Iterator<Item = (Vec<PartyIndex>, LweSecretKeyMutView<'a, Scalar>)>
+    where
+        Scalar: 'a,
+    {
+        let party_index = self.party_index;
+
+        threshold_sharing_excluded_subsets(self.party_count, self.threshold)
+            .into_iter()
+            .filter(move |subset| !subset.contains(&party_index))
+            .zip(self.data.as_mut().chunks_exact_mut(self.lwe_dimension.0))
+            .map(|(subset, sub_key)| (subset, LweSecretKey::from_container(sub_key)))
+    }
+}
+
+/// An [`LweThresholdSecretKeyShare`] owning the memory for its own storage.
+pub type LweThresholdSecretKeyShareOwned<Scalar> = LweThresholdSecretKeyShare<Vec<Scalar>>;
+
+impl<Scalar: Copy> LweThresholdSecretKeyShareOwned<Scalar> {
+    /// Allocate memory and create a new owned [`LweThresholdSecretKeyShare`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to share an existing [`LweSecretKey`] you need to use
+    /// [`crate::core_crypto::experimental::algorithms::allocate_and_generate_lwe_threshold_secret_key_shares`].
+    pub fn new(
+        fill_with: Scalar,
+        lwe_dimension: LweDimension,
+        party_index: PartyIndex,
+        party_count: PartyCount,
+        threshold: DecryptionThreshold,
+    ) -> Self {
+        let sub_key_count = threshold_secret_key_share_sub_key_count(party_count, threshold);
+
+        Self::from_container(
+            vec![fill_with; sub_key_count * lwe_dimension.0],
+            lwe_dimension,
+            party_index,
+            party_count,
+            threshold,
+        )
+    }
+}
@@ -1,7 +1,9 @@
 pub mod fourier_pseudo_ggsw_ciphertext;
 pub mod lwe_shrinking_keyswitch_key;
+pub mod lwe_threshold_secret_key_share;
 pub mod pseudo_ggsw_ciphertext;
 
 pub use fourier_pseudo_ggsw_ciphertext::*;
 pub use lwe_shrinking_keyswitch_key::*;
+pub use lwe_threshold_secret_key_share::*;
 pub use pseudo_ggsw_ciphertext::*;
@@ -31,6 +31,10 @@ pub struct FourierLweBootstrapKey<C: Container<Element = c64>> {
     decomposition_level_count: DecompositionLevelCount,
 }
 
+impl<C: Container<Element = c64>> crate::named::Named for FourierLweBootstrapKey<C> {
+    const NAME: &'static str = "core_crypto::FourierLweBootstrapKey";
+}
+
 pub type FourierLweBootstrapKeyView<'a> = FourierLweBootstrapKey<&'a [c64]>;
 pub type FourierLweBootstrapKeyMutView<'a> = FourierLweBootstrapKey<&'a mut [c64]>;
 
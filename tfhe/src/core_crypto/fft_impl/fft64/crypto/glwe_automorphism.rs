@@ -0,0 +1,158 @@
+use super::super::math::fft::FftView;
+use super::glwe_keyswitch::{FourierGlweKeyswitchKey, FourierGlweKeyswitchKeyView};
+use crate::core_crypto::commons::math::torus::UnsignedTorus;
+use crate::core_crypto::commons::parameters::{
+    AutomorphismExponent, DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize,
+    PolynomialSize,
+};
+use crate::core_crypto::commons::traits::{Container, IntoContainerOwned};
+use crate::core_crypto::entities::*;
+use aligned_vec::ABox;
+use concrete_fft::c64;
+use dyn_stack::PodStack;
+
+/// A [`GLWE automorphism key`](`GlweAutomorphismKey`) in the Fourier domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+pub struct FourierGlweAutomorphismKey<C: Container<Element = c64>> {
+    fourier_glwe_keyswitch_key: FourierGlweKeyswitchKey<C>,
+    automorphism_exponent: AutomorphismExponent,
+}
+
+impl<C: Container<Element = c64>> crate::named::Named for FourierGlweAutomorphismKey<C> {
+    const NAME: &'static str = "core_crypto::FourierGlweAutomorphismKey";
+}
+
+pub type FourierGlweAutomorphismKeyView<'a> = FourierGlweAutomorphismKey<&'a [c64]>;
+pub type FourierGlweAutomorphismKeyMutView<'a> = FourierGlweAutomorphismKey<&'a mut [c64]>;
+
+impl<C: Container<Element = c64>> FourierGlweAutomorphismKey<C> {
+    pub fn from_container(
+        data: C,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        automorphism_exponent: AutomorphismExponent,
+    ) -> Self {
+        assert!(
+            automorphism_exponent.0 % 2 == 1 && automorphism_exponent.0 < 2 * polynomial_size.0,
+            "The automorphism exponent needs to be odd and smaller than 2 * polynomial_size. \
+            Got automorphism_exponent: {automorphism_exponent:?} \
+            and polynomial_size: {polynomial_size:?}."
+        );
+        Self {
+            fourier_glwe_keyswitch_key: FourierGlweKeyswitchKey::from_container(
+                data,
+                glwe_size.to_glwe_dimension(),
+                glwe_size,
+                polynomial_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            ),
+            automorphism_exponent,
+        }
+    }
+
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.fourier_glwe_keyswitch_key.output_key_glwe_dimension()
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.fourier_glwe_keyswitch_key.output_glwe_size()
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fourier_glwe_keyswitch_key.polynomial_size()
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.fourier_glwe_keyswitch_key.decomposition_base_log()
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.fourier_glwe_keyswitch_key.decomposition_level_count()
+    }
+
+    pub fn automorphism_exponent(&self) -> AutomorphismExponent {
+        self.automorphism_exponent
+    }
+
+    pub fn data(self) -> C {
+        self.fourier_glwe_keyswitch_key.data()
+    }
+
+    pub fn as_fourier_glwe_keyswitch_key(&self) -> FourierGlweKeyswitchKeyView<'_> {
+        self.fourier_glwe_keyswitch_key.as_view()
+    }
+
+    pub fn as_view(&self) -> FourierGlweAutomorphismKeyView<'_> {
+        FourierGlweAutomorphismKeyView {
+            fourier_glwe_keyswitch_key: self.fourier_glwe_keyswitch_key.as_view(),
+            automorphism_exponent: self.automorphism_exponent,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierGlweAutomorphismKeyMutView<'_>
+    where
+        C: AsMut<[c64]>,
+    {
+        FourierGlweAutomorphismKeyMutView {
+            fourier_glwe_keyswitch_key: self.fourier_glwe_keyswitch_key.as_mut_view(),
+            automorphism_exponent: self.automorphism_exponent,
+        }
+    }
+}
+
+pub type FourierGlweAutomorphismKeyOwned = FourierGlweAutomorphismKey<ABox<[c64]>>;
+
+impl FourierGlweAutomorphismKey<ABox<[c64]>> {
+    pub fn new(
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        automorphism_exponent: AutomorphismExponent,
+    ) -> Self {
+        let fourier_glwe_keyswitch_key = FourierGlweKeyswitchKey::new(
+            glwe_size.to_glwe_dimension(),
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        );
+
+        Self::from_container(
+            fourier_glwe_keyswitch_key.data(),
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            automorphism_exponent,
+        )
+    }
+}
+
+impl<'a> FourierGlweAutomorphismKeyMutView<'a> {
+    /// Fill a GLWE automorphism key with the Fourier transform of a GLWE automorphism key in the
+    /// standard domain.
+    ///
+    /// The caller must provide a [`PodStack`] with a capacity at least as large as the result of
+    /// [`super::glwe_keyswitch::fill_with_forward_fourier_scratch`].
+    pub fn fill_with_forward_fourier<Scalar: UnsignedTorus>(
+        self,
+        coef_auto_key: GlweAutomorphismKeyView<'_, Scalar>,
+        fft: FftView<'_>,
+        stack: PodStack<'_>,
+    ) {
+        assert_eq!(
+            self.automorphism_exponent,
+            coef_auto_key.automorphism_exponent()
+        );
+        self.fourier_glwe_keyswitch_key.fill_with_forward_fourier(
+            coef_auto_key.as_glwe_keyswitch_key(),
+            fft,
+            stack,
+        );
+    }
+}
@@ -0,0 +1,357 @@
+use super::super::math::fft::{FftView, FourierPolynomialList};
+use super::ggsw::{collect_next_term, update_with_fmadd};
+use crate::core_crypto::algorithms::slice_algorithms::{
+    slice_wrapping_add_assign, slice_wrapping_opposite_assign,
+};
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::math::torus::UnsignedTorus;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PolynomialSize,
+};
+use crate::core_crypto::commons::traits::{
+    Container, ContiguousEntityContainerMut, IntoContainerOwned, Split,
+};
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::decomposition::TensorSignedDecompositionLendingIter;
+use crate::core_crypto::fft_impl::fft64::math::polynomial::{
+    FourierPolynomialMutView, FourierPolynomialView,
+};
+use aligned_vec::{avec, ABox, CACHELINE_ALIGN};
+use concrete_fft::c64;
+use dyn_stack::{PodStack, ReborrowMut, SizeOverflow, StackReq};
+
+/// A [`GLWE keyswitch key`](`GlweKeyswitchKey`) in the Fourier domain.
+///
+/// The key stores, for each polynomial of the input key and each decomposition level (from the
+/// last level to the first), the Fourier transform of the polynomials of the corresponding GLWE
+/// ciphertext encrypted under the output key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+pub struct FourierGlweKeyswitchKey<C: Container<Element = c64>> {
+    fourier: FourierPolynomialList<C>,
+    input_glwe_dimension: GlweDimension,
+    output_glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
+impl<C: Container<Element = c64>> crate::named::Named for FourierGlweKeyswitchKey<C> {
+    const NAME: &'static str = "core_crypto::FourierGlweKeyswitchKey";
+}
+
+pub type FourierGlweKeyswitchKeyView<'a> = FourierGlweKeyswitchKey<&'a [c64]>;
+pub type FourierGlweKeyswitchKeyMutView<'a> = FourierGlweKeyswitchKey<&'a mut [c64]>;
+
+impl<C: Container<Element = c64>> FourierGlweKeyswitchKey<C> {
+    pub fn from_container(
+        data: C,
+        input_glwe_dimension: GlweDimension,
+        output_glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self {
+        assert_eq!(
+            data.container_len(),
+            input_glwe_dimension.0
+                * polynomial_size.to_fourier_polynomial_size().0
+                * decomposition_level_count.0
+                * output_glwe_size.0
+        );
+        Self {
+            fourier: FourierPolynomialList {
+                data,
+                polynomial_size,
+            },
+            input_glwe_dimension,
+            output_glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        }
+    }
+
+    pub fn input_key_glwe_dimension(&self) -> GlweDimension {
+        self.input_glwe_dimension
+    }
+
+    pub fn output_key_glwe_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    pub fn output_glwe_size(&self) -> GlweSize {
+        self.output_glwe_size
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fourier.polynomial_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn data(self) -> C {
+        self.fourier.data
+    }
+
+    pub fn as_view(&self) -> FourierGlweKeyswitchKeyView<'_> {
+        FourierGlweKeyswitchKeyView {
+            fourier: FourierPolynomialList {
+                data: self.fourier.data.as_ref(),
+                polynomial_size: self.fourier.polynomial_size,
+            },
+            input_glwe_dimension: self.input_glwe_dimension,
+            output_glwe_size: self.output_glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> FourierGlweKeyswitchKeyMutView<'_>
+    where
+        C: AsMut<[c64]>,
+    {
+        FourierGlweKeyswitchKeyMutView {
+            fourier: FourierPolynomialList {
+                data: self.fourier.data.as_mut(),
+                polynomial_size: self.fourier.polynomial_size,
+            },
+            input_glwe_dimension: self.input_glwe_dimension,
+            output_glwe_size: self.output_glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+}
+
+pub type FourierGlweKeyswitchKeyOwned = FourierGlweKeyswitchKey<ABox<[c64]>>;
+
+impl FourierGlweKeyswitchKey<ABox<[c64]>> {
+    pub fn new(
+        input_glwe_dimension: GlweDimension,
+        output_glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self {
+        let boxed = avec![
+            c64::default();
+            polynomial_size.to_fourier_polynomial_size().0
+                * input_glwe_dimension.0
+                * decomposition_level_count.0
+                * output_glwe_size.0
+        ]
+        .into_boxed_slice();
+
+        FourierGlweKeyswitchKey::from_container(
+            boxed,
+            input_glwe_dimension,
+            output_glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        )
+    }
+}
+
+/// Return the required memory for [`FourierGlweKeyswitchKeyMutView::fill_with_forward_fourier`].
+pub fn fill_with_forward_fourier_scratch(fft: FftView<'_>) -> Result<StackReq, SizeOverflow> {
+    fft.forward_scratch()
+}
+
+impl<'a> FourierGlweKeyswitchKeyMutView<'a> {
+    /// Fill a GLWE keyswitch key with the Fourier transform of a GLWE keyswitch key in the
+    /// standard domain.
+    pub fn fill_with_forward_fourier<Scalar: UnsignedTorus>(
+        self,
+        coef_ksk: GlweKeyswitchKeyView<'_, Scalar>,
+        fft: FftView<'_>,
+        mut stack: PodStack<'_>,
+    ) {
+        debug_assert_eq!(coef_ksk.polynomial_size(), self.polynomial_size());
+        let fourier_poly_size = coef_ksk.polynomial_size().to_fourier_polynomial_size().0;
+
+        for (fourier_poly, coef_poly) in izip!(
+            self.data().into_chunks(fourier_poly_size),
+            coef_ksk
+                .as_ref()
+                .chunks_exact(coef_ksk.polynomial_size().0)
+                .map(Polynomial::from_container)
+        ) {
+            fft.forward_as_torus(
+                FourierPolynomialMutView { data: fourier_poly },
+                coef_poly,
+                stack.rb_mut(),
+            );
+        }
+    }
+}
+
+/// Return the required memory for [`FourierGlweKeyswitchKeyView::keyswitch`].
+pub fn glwe_keyswitch_scratch<Scalar>(
+    input_glwe_size: GlweSize,
+    output_glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let input_mask_scratch = StackReq::try_new_aligned::<Scalar>(
+        input_glwe_size.to_glwe_dimension().0 * polynomial_size.0,
+        align,
+    )?;
+    let fourier_polynomial_size = polynomial_size.to_fourier_polynomial_size().0;
+    let fourier_scratch =
+        StackReq::try_new_aligned::<c64>(output_glwe_size.0 * fourier_polynomial_size, align)?;
+    let fourier_scratch_single = StackReq::try_new_aligned::<c64>(fourier_polynomial_size, align)?;
+
+    let substack3 = fft.forward_scratch()?;
+    let substack2 = substack3.try_and(fourier_scratch_single)?;
+    let substack1 = substack2.try_and(input_mask_scratch)?;
+    let substack0 = StackReq::try_any_of([
+        substack1.try_and(input_mask_scratch)?,
+        fft.backward_scratch()?,
+    ])?;
+    substack0.try_and(fourier_scratch)
+}
+
+impl<'a> FourierGlweKeyswitchKeyView<'a> {
+    /// Keyswitch the input GLWE ciphertext and write the result in `out`.
+    ///
+    /// The caller must provide a [`PodStack`] with a capacity at least as large as the result of
+    /// [`glwe_keyswitch_scratch`].
+    pub fn keyswitch<Scalar: UnsignedTorus>(
+        self,
+        mut out: GlweCiphertextMutView<'_, Scalar>,
+        input: GlweCiphertextView<'_, Scalar>,
+        fft: FftView<'_>,
+        stack: PodStack<'_>,
+    ) {
+        // we check that the polynomial sizes match
+        debug_assert_eq!(self.polynomial_size(), input.polynomial_size());
+        debug_assert_eq!(self.polynomial_size(), out.polynomial_size());
+        // we check that the glwe sizes match
+        debug_assert_eq!(
+            self.input_key_glwe_dimension(),
+            input.glwe_size().to_glwe_dimension()
+        );
+        debug_assert_eq!(self.output_glwe_size(), out.glwe_size());
+
+        let align = CACHELINE_ALIGN;
+        let polynomial_size = self.polynomial_size();
+        let fourier_poly_size = polynomial_size.to_fourier_polynomial_size().0;
+        let level_count = self.decomposition_level_count().0;
+        let ciphertext_modulus = out.ciphertext_modulus();
+
+        // The key is laid out as [input polynomial][level][output polynomial]
+        let level_stride = self.output_glwe_size().0 * fourier_poly_size;
+        let input_polynomial_stride = level_count * level_stride;
+        let key_data = self.fourier.data;
+
+        out.as_mut().fill(Scalar::ZERO);
+
+        // we round the input mask
+        let decomposer = SignedDecomposer::<Scalar>::new(
+            self.decomposition_base_log(),
+            self.decomposition_level_count(),
+        );
+        let (mut output_fft_buffer, mut substack0) =
+            stack.make_aligned_raw::<c64>(fourier_poly_size * self.output_glwe_size().0, align);
+        // output_fft_buffer is initially uninitialized, considered to be implicitly zero, to avoid
+        // the cost of filling it up with zeros. `is_output_uninit` is set to `false` once
+        // it has been fully initialized for the first time.
+        let output_fft_buffer = &mut *output_fft_buffer;
+        let mut is_output_uninit = true;
+
+        {
+            // ------------ KEYSWITCH IN FOURIER DOMAIN
+            // In this section, we compute the products between the decomposed input mask and the
+            // key in the fourier domain, and accumulate the result in the output_fft_buffer.
+            let (mut decomposition, mut substack1) = TensorSignedDecompositionLendingIter::new(
+                input
+                    .get_mask()
+                    .as_ref()
+                    .iter()
+                    .map(|s| decomposer.closest_representable(*s)),
+                DecompositionBaseLog(decomposer.base_log),
+                DecompositionLevelCount(decomposer.level_count),
+                substack0.rb_mut(),
+            );
+
+            // The decomposition iterator yields the levels from the last to the first, which
+            // matches the order of the levels in the key.
+            for level_index in 0..level_count {
+                let (_, mask_decomp_term, mut substack2) =
+                    collect_next_term(&mut decomposition, &mut substack1, align);
+
+                for (input_polynomial_index, mask_poly) in mask_decomp_term
+                    .chunks_exact(polynomial_size.0)
+                    .map(Polynomial::from_container)
+                    .enumerate()
+                {
+                    let (mut fourier, substack3) = substack2
+                        .rb_mut()
+                        .make_aligned_raw::<c64>(fourier_poly_size, align);
+
+                    // We perform the forward fft transform for the mask polynomial
+                    let fourier = fft
+                        .forward_as_integer(
+                            FourierPolynomialMutView { data: &mut fourier },
+                            mask_poly,
+                            substack3,
+                        )
+                        .data;
+
+                    let key_offset = input_polynomial_index * input_polynomial_stride
+                        + level_index * level_stride;
+
+                    update_with_fmadd(
+                        output_fft_buffer,
+                        &key_data[key_offset..key_offset + level_stride],
+                        fourier,
+                        is_output_uninit,
+                        fourier_poly_size,
+                    );
+
+                    // we initialized `output_fft_buffer, so we can set this to false
+                    is_output_uninit = false;
+                }
+            }
+        }
+
+        // --------------------------------------------  TRANSFORMATION OF RESULT TO STANDARD DOMAIN
+        if !is_output_uninit {
+            izip!(
+                out.as_mut_polynomial_list().iter_mut(),
+                output_fft_buffer
+                    .into_chunks(fourier_poly_size)
+                    .map(|slice| FourierPolynomialView { data: slice }),
+            )
+            .for_each(|(out, fourier)| {
+                fft.add_backward_as_torus(out, fourier, substack0.rb_mut());
+            });
+        }
+
+        // The output is (0, body) minus the accumulated products
+        slice_wrapping_opposite_assign(out.as_mut());
+        slice_wrapping_add_assign(out.get_mut_body().as_mut(), input.get_body().as_ref());
+
+        if !ciphertext_modulus.is_native_modulus() {
+            // When we convert back from the fourier domain, integer values will contain up to 53
+            // MSBs with information. In our representation of power of 2 moduli < native modulus
+            // we fill the MSBs and leave the LSBs empty, this usage of the signed decomposer
+            // allows to round while keeping the data in the MSBs
+            let signed_decomposer = SignedDecomposer::new(
+                DecompositionBaseLog(ciphertext_modulus.get_custom_modulus().ilog2() as usize),
+                DecompositionLevelCount(1),
+            );
+            out.as_mut()
+                .iter_mut()
+                .for_each(|x| *x = signed_decomposer.closest_representable(*x));
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub mod bootstrap;
 pub mod ggsw;
+pub mod glwe_automorphism;
+pub mod glwe_keyswitch;
 pub mod wop_pbs;
 
 #[cfg(test)]
@@ -11,6 +11,7 @@
 //! freedom of choice over a breadth of parameters, which can lead to less than 128 bits of security
 //! if chosen incorrectly
 pub mod algorithms;
+pub mod backward_compatibility;
 pub mod commons;
 pub mod entities;
 pub mod prelude;
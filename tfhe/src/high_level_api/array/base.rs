@@ -0,0 +1,369 @@
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::integer::{IntegerRadixCiphertext, RadixCiphertext, SignedRadixCiphertext};
+use crate::prelude::{FheDecrypt, FheTryEncrypt};
+use crate::{ClientKey, FheInt, FheUint};
+use std::ops::Range;
+
+/// A type that can be an element of a [FheArray]
+///
+/// It is implemented for [FheUint] and [FheInt].
+pub trait FheArrayElement: Clone + Send + Sync {
+    #[doc(hidden)]
+    type Ciphertext: IntegerRadixCiphertext;
+
+    #[doc(hidden)]
+    fn to_cpu_ciphertext(&self) -> Self::Ciphertext;
+
+    #[doc(hidden)]
+    fn from_cpu_ciphertext(ciphertext: Self::Ciphertext) -> Self;
+}
+
+impl<Id: FheUintId + Send + Sync> FheArrayElement for FheUint<Id> {
+    type Ciphertext = RadixCiphertext;
+
+    fn to_cpu_ciphertext(&self) -> Self::Ciphertext {
+        self.ciphertext.on_cpu().into_owned()
+    }
+
+    fn from_cpu_ciphertext(ciphertext: Self::Ciphertext) -> Self {
+        Self::new(ciphertext)
+    }
+}
+
+impl<Id: FheIntId + Send + Sync> FheArrayElement for FheInt<Id> {
+    type Ciphertext = SignedRadixCiphertext;
+
+    fn to_cpu_ciphertext(&self) -> Self::Ciphertext {
+        self.ciphertext.on_cpu().into_owned()
+    }
+
+    fn from_cpu_ciphertext(ciphertext: Self::Ciphertext) -> Self {
+        Self::new(ciphertext)
+    }
+}
+
+/// An n-dimensional array of encrypted integers
+///
+/// The elements are stored in row-major order, the last dimension of the shape being the
+/// contiguous one.
+///
+/// The element-wise operators (`+`, `-`, `*`, `&`, `|`, `^`) take either another [FheArray] or a
+/// [ClearArray] and broadcast their operands following the numpy rules: the shapes are aligned on
+/// their last dimension, and dimensions of size 1 are repeated to match the other operand.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::array::{ClearArray, FheArray};
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a =
+///     FheArray::<FheUint8>::try_encrypt(&[1u8, 2, 3, 4, 5, 6], &[2, 3], &client_key).unwrap();
+/// // Broadcast on the rows
+/// let b = FheArray::<FheUint8>::try_encrypt(&[10u8, 20, 30], &[3], &client_key).unwrap();
+///
+/// let c = &a + &b;
+/// assert_eq!(c.shape(), &[2, 3]);
+/// let decrypted: Vec<u8> = c.decrypt(&client_key);
+/// assert_eq!(decrypted, vec![11, 22, 33, 14, 25, 36]);
+///
+/// let weights = ClearArray::new(vec![1u8, 0, 0, 1, 1, 1], &[3, 2]).unwrap();
+/// let d = a.matmul(&weights);
+/// assert_eq!(d.shape(), &[2, 2]);
+/// let decrypted: Vec<u8> = d.decrypt(&client_key);
+/// assert_eq!(decrypted, vec![4, 5, 10, 11]);
+/// ```
+#[derive(Clone)]
+pub struct FheArray<T> {
+    pub(super) elements: Vec<T>,
+    pub(super) shape: Vec<usize>,
+}
+
+/// An n-dimensional array of clear values, used as an operand of [FheArray] operations
+///
+/// The elements are stored in row-major order, like in a [FheArray].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClearArray<C> {
+    pub(super) elements: Vec<C>,
+    pub(super) shape: Vec<usize>,
+}
+
+fn check_shape(num_elements: usize, shape: &[usize]) -> crate::Result<()> {
+    let expected_num_elements: usize = shape.iter().product();
+    if expected_num_elements != num_elements {
+        return Err(crate::Error::new(format!(
+            "An array of shape {shape:?} has {expected_num_elements} elements, \
+            but {num_elements} elements were given"
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the row-major strides of the shape
+pub(super) fn strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for i in (0..shape.len().saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1] * shape[i + 1];
+    }
+    strides
+}
+
+/// Returns the shape of the result of an operation between two arrays, following the numpy
+/// broadcasting rules
+///
+/// # Panics
+///
+/// Panics if the shapes cannot be broadcast together.
+pub(super) fn broadcast_shapes(lhs: &[usize], rhs: &[usize]) -> Vec<usize> {
+    let num_dims = lhs.len().max(rhs.len());
+    let dim = |shape: &[usize], i: usize| {
+        // Missing leading dimensions are considered to be of size 1
+        (i + shape.len())
+            .checked_sub(num_dims)
+            .map_or(1, |index| shape[index])
+    };
+
+    (0..num_dims)
+        .map(|i| match (dim(lhs, i), dim(rhs, i)) {
+            (l, r) if l == r => l,
+            (1, r) => r,
+            (l, 1) => l,
+            _ => panic!("Arrays of shapes {lhs:?} and {rhs:?} cannot be broadcast together"),
+        })
+        .collect()
+}
+
+/// Returns the index in an array of shape `shape` of the element used to compute the element at
+/// `index` of the broadcast result of shape `broadcast_shape`
+pub(super) fn broadcast_source_index(
+    mut index: usize,
+    broadcast_shape: &[usize],
+    shape: &[usize],
+) -> usize {
+    let offset = broadcast_shape.len() - shape.len();
+    let source_strides = strides(shape);
+
+    let mut source_index = 0;
+    for (i, dim) in broadcast_shape.iter().enumerate().rev() {
+        let coordinate = index % dim;
+        index /= dim;
+        if i >= offset && shape[i - offset] != 1 {
+            source_index += coordinate * source_strides[i - offset];
+        }
+    }
+    source_index
+}
+
+impl<C> ClearArray<C> {
+    /// Creates an array from elements in row-major order
+    ///
+    /// Returns an error if the number of elements does not match the shape.
+    pub fn new(elements: Vec<C>, shape: &[usize]) -> crate::Result<Self> {
+        check_shape(elements.len(), shape)?;
+        Ok(Self {
+            elements,
+            shape: shape.to_vec(),
+        })
+    }
+
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    pub fn as_slice(&self) -> &[C] {
+        &self.elements
+    }
+
+    pub fn into_vec(self) -> Vec<C> {
+        self.elements
+    }
+}
+
+impl<C> From<Vec<C>> for ClearArray<C> {
+    /// Creates a 1-dimensional array
+    fn from(elements: Vec<C>) -> Self {
+        let shape = vec![elements.len()];
+        Self { elements, shape }
+    }
+}
+
+impl<T> FheArray<T> {
+    /// Creates an array from elements in row-major order
+    ///
+    /// Returns an error if the number of elements does not match the shape.
+    pub fn new(elements: Vec<T>, shape: &[usize]) -> crate::Result<Self> {
+        check_shape(elements.len(), shape)?;
+        Ok(Self {
+            elements,
+            shape: shape.to_vec(),
+        })
+    }
+
+    /// Encrypts clear values given in row-major order
+    ///
+    /// Returns an error if the number of values does not match the shape, or if a value
+    /// cannot be encrypted.
+    pub fn try_encrypt<Clear, Key>(
+        values: &[Clear],
+        shape: &[usize],
+        key: &Key,
+    ) -> crate::Result<Self>
+    where
+        Clear: Copy,
+        T: FheTryEncrypt<Clear, Key>,
+    {
+        check_shape(values.len(), shape)?;
+        let elements = values
+            .iter()
+            .map(|value| T::try_encrypt(*value, key))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| crate::Error::new(err.to_string()))?;
+
+        Self::new(elements, shape)
+    }
+
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Returns the number of dimensions of the array
+    pub fn num_dims(&self) -> usize {
+        self.shape.len()
+    }
+
+    /// Returns the total number of elements of the array
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the elements in row-major order
+    pub fn as_slice(&self) -> &[T] {
+        &self.elements
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.elements
+    }
+
+    /// Returns the element at the given coordinates, or `None` if they are out of bounds
+    pub fn get(&self, coordinates: &[usize]) -> Option<&T> {
+        if coordinates.len() != self.shape.len()
+            || coordinates
+                .iter()
+                .zip(self.shape.iter())
+                .any(|(coordinate, dim)| coordinate >= dim)
+        {
+            return None;
+        }
+
+        let index = coordinates
+            .iter()
+            .zip(strides(&self.shape))
+            .map(|(coordinate, stride)| coordinate * stride)
+            .sum::<usize>();
+        self.elements.get(index)
+    }
+}
+
+impl<T: Clone> FheArray<T> {
+    /// Returns the same elements with a different shape
+    ///
+    /// Returns an error if the number of elements of the new shape is not the same.
+    pub fn reshape(&self, shape: &[usize]) -> crate::Result<Self> {
+        Self::new(self.elements.clone(), shape)
+    }
+
+    /// Returns the sub-array made of the given range in each dimension
+    ///
+    /// Returns an error if the number of ranges is not the number of dimensions, or if a range
+    /// is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::array::FheArray;
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, _) = generate_keys(ConfigBuilder::default());
+    ///
+    /// let a =
+    ///     FheArray::<FheUint8>::try_encrypt(&[1u8, 2, 3, 4, 5, 6], &[2, 3], &client_key).unwrap();
+    ///
+    /// let b = a.slice(&[0..2, 1..3]).unwrap();
+    /// assert_eq!(b.shape(), &[2, 2]);
+    /// let decrypted: Vec<u8> = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, vec![2, 3, 5, 6]);
+    /// ```
+    pub fn slice(&self, ranges: &[Range<usize>]) -> crate::Result<Self> {
+        if ranges.len() != self.shape.len() {
+            return Err(crate::Error::new(format!(
+                "Expected {} ranges to slice an array of shape {:?}, got {}",
+                self.shape.len(),
+                self.shape,
+                ranges.len()
+            )));
+        }
+        if let Some(range) = ranges
+            .iter()
+            .zip(self.shape.iter())
+            .find_map(|(range, dim)| (range.start > range.end || range.end > *dim).then_some(range))
+        {
+            return Err(crate::Error::new(format!(
+                "The range {range:?} is out of the bounds of the array of shape {:?}",
+                self.shape
+            )));
+        }
+
+        let shape = ranges
+            .iter()
+            .map(ExactSizeIterator::len)
+            .collect::<Vec<_>>();
+        let source_strides = strides(&self.shape);
+        let elements = (0..shape.iter().product())
+            .map(|mut index| {
+                let mut source_index = 0;
+                for (dim, (range, stride)) in shape
+                    .iter()
+                    .zip(ranges.iter().zip(source_strides.iter()))
+                    .rev()
+                {
+                    source_index += (range.start + index % dim) * stride;
+                    index /= dim;
+                }
+                self.elements[source_index].clone()
+            })
+            .collect();
+
+        Self::new(elements, &shape)
+    }
+}
+
+impl<T> From<Vec<T>> for FheArray<T> {
+    /// Creates a 1-dimensional array
+    fn from(elements: Vec<T>) -> Self {
+        let shape = vec![elements.len()];
+        Self { elements, shape }
+    }
+}
+
+impl<T, Clear> FheDecrypt<Vec<Clear>> for FheArray<T>
+where
+    T: FheDecrypt<Clear>,
+{
+    /// Decrypts the elements in row-major order
+    fn decrypt(&self, key: &ClientKey) -> Vec<Clear> {
+        self.elements
+            .iter()
+            .map(|element| element.decrypt(key))
+            .collect()
+    }
+}
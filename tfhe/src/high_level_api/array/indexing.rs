@@ -0,0 +1,135 @@
+use super::base::FheArrayElement;
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::FheUintId;
+use crate::integer::block_decomposition::Decomposable;
+use crate::prelude::CastInto;
+use crate::FheUint;
+
+/// Returns the value at the encrypted index
+///
+/// The index is never revealed: every value of the slice takes part in the selection.
+///
+/// - If the index is out of bounds, the returned value encrypts 0
+///
+/// # Panics
+///
+/// Panics if the slice is empty.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::array::read_at;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let values = [300u16, 100, 200]
+///     .iter()
+///     .map(|v| FheUint16::encrypt(*v, &client_key))
+///     .collect::<Vec<_>>();
+/// let index = FheUint8::encrypt(2u8, &client_key);
+///
+/// let value: u16 = read_at(&values, &index).decrypt(&client_key);
+/// assert_eq!(value, 200);
+/// ```
+pub fn read_at<T, Id>(values: &[T], index: &FheUint<Id>) -> T
+where
+    T: FheArrayElement,
+    Id: FheUintId,
+{
+    let ciphertexts = values.iter().map(T::to_cpu_ciphertext).collect::<Vec<_>>();
+    let result = global_state::with_cpu_internal_keys(|cpu_key| {
+        cpu_key
+            .pbs_key()
+            .read_at_index_parallelized(&ciphertexts, &index.ciphertext.on_cpu())
+    });
+    T::from_cpu_ciphertext(result)
+}
+
+/// Replaces the value at the encrypted index
+///
+/// The index is never revealed: every value of the slice is rewritten.
+///
+/// - If the index is out of bounds, the values are left unchanged
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::array::write_at;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let mut values = [300u16, 100, 200]
+///     .iter()
+///     .map(|v| FheUint16::encrypt(*v, &client_key))
+///     .collect::<Vec<_>>();
+/// let index = FheUint8::encrypt(1u8, &client_key);
+/// let value = FheUint16::encrypt(42u16, &client_key);
+///
+/// write_at(&mut values, &index, &value);
+/// let values: Vec<u16> = values.iter().map(|v| v.decrypt(&client_key)).collect();
+/// assert_eq!(values, vec![300, 42, 200]);
+/// ```
+pub fn write_at<T, Id>(values: &mut [T], index: &FheUint<Id>, value: &T)
+where
+    T: FheArrayElement,
+    Id: FheUintId,
+{
+    let mut ciphertexts = values.iter().map(T::to_cpu_ciphertext).collect::<Vec<_>>();
+    global_state::with_cpu_internal_keys(|cpu_key| {
+        cpu_key.pbs_key().write_at_index_parallelized(
+            &mut ciphertexts,
+            &index.ciphertext.on_cpu(),
+            &value.to_cpu_ciphertext(),
+        );
+    });
+    for (value, ciphertext) in values.iter_mut().zip(ciphertexts) {
+        *value = T::from_cpu_ciphertext(ciphertext);
+    }
+}
+
+/// Returns the encryption of the entry of a clear table at the encrypted index
+///
+/// The table can be larger than what a single lookup table can hold, and its entries can be
+/// wider than the index.
+///
+/// - If the index is out of bounds, the returned value encrypts 0
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::array::lookup;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let squares = (0..200u16).map(|i| i * i).collect::<Vec<_>>();
+/// let index = FheUint8::encrypt(150u8, &client_key);
+///
+/// let square: FheUint16 = lookup(&squares, &index);
+/// let square: u16 = square.decrypt(&client_key);
+/// assert_eq!(square, 150 * 150);
+/// ```
+pub fn lookup<Clear, Id, OutId>(table: &[Clear], index: &FheUint<Id>) -> FheUint<OutId>
+where
+    Clear: Decomposable + CastInto<usize> + Copy + Send + Sync,
+    Id: FheUintId,
+    OutId: FheUintId,
+{
+    global_state::with_cpu_internal_keys(|cpu_key| {
+        let sks = cpu_key.pbs_key();
+        let result = sks.lookup_in_clears_parallelized(
+            &index.ciphertext.on_cpu(),
+            table,
+            OutId::num_blocks(sks.message_modulus()),
+        );
+        FheUint::new(result)
+    })
+}
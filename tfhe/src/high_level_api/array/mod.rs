@@ -1,3 +1,15 @@
+pub use base::{ClearArray, FheArray, FheArrayElement};
+pub use indexing::{lookup, read_at, write_at};
+pub use sort::{argmax, argmin, sort, sort_by_key, top_k};
+
+mod base;
+mod indexing;
+mod ops;
+mod reductions;
+mod sort;
+#[cfg(test)]
+mod tests;
+
 use crate::high_level_api::global_state::with_cpu_internal_keys;
 use crate::high_level_api::integers::FheUintId;
 use crate::{FheBool, FheUint};
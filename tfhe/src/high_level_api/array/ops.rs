@@ -0,0 +1,202 @@
+use super::base::{
+    broadcast_shapes, broadcast_source_index, ClearArray, FheArray, FheArrayElement,
+};
+use crate::high_level_api::global_state;
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::server_key::{ScalarMultiplier, TwosComplementNegation};
+use crate::integer::ServerKey;
+use rayon::prelude::*;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Mul, Sub};
+
+/// Applies the operation to each pair of elements of the broadcast operands
+fn broadcast_zip<L, R, O, F>(
+    lhs: &[L],
+    lhs_shape: &[usize],
+    rhs: &[R],
+    rhs_shape: &[usize],
+    op: F,
+) -> (Vec<O>, Vec<usize>)
+where
+    L: Sync,
+    R: Sync,
+    O: Send,
+    F: Fn(&L, &R) -> O + Sync,
+{
+    let shape = broadcast_shapes(lhs_shape, rhs_shape);
+    let num_elements = shape.iter().product::<usize>();
+
+    let elements = (0..num_elements)
+        .into_par_iter()
+        .map(|index| {
+            let lhs_index = broadcast_source_index(index, &shape, lhs_shape);
+            let rhs_index = broadcast_source_index(index, &shape, rhs_shape);
+            op(&lhs[lhs_index], &rhs[rhs_index])
+        })
+        .collect();
+
+    (elements, shape)
+}
+
+impl<T: FheArrayElement> FheArray<T> {
+    pub(super) fn to_cpu_ciphertexts(&self) -> Vec<T::Ciphertext> {
+        self.elements
+            .par_iter()
+            .map(FheArrayElement::to_cpu_ciphertext)
+            .collect()
+    }
+
+    pub(super) fn from_cpu_ciphertexts(ciphertexts: Vec<T::Ciphertext>, shape: Vec<usize>) -> Self {
+        let elements = ciphertexts
+            .into_par_iter()
+            .map(T::from_cpu_ciphertext)
+            .collect();
+        Self { elements, shape }
+    }
+
+    fn broadcast_op<F>(&self, rhs: &Self, op: F) -> Self
+    where
+        F: Fn(&ServerKey, &T::Ciphertext, &T::Ciphertext) -> T::Ciphertext + Sync,
+    {
+        let lhs_ciphertexts = self.to_cpu_ciphertexts();
+        let rhs_ciphertexts = rhs.to_cpu_ciphertexts();
+
+        let (ciphertexts, shape) = global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            broadcast_zip(
+                &lhs_ciphertexts,
+                &self.shape,
+                &rhs_ciphertexts,
+                &rhs.shape,
+                |lhs, rhs| op(sks, lhs, rhs),
+            )
+        });
+
+        Self::from_cpu_ciphertexts(ciphertexts, shape)
+    }
+
+    fn broadcast_clear_op<C, F>(&self, rhs: &ClearArray<C>, op: F) -> Self
+    where
+        C: Copy + Sync,
+        F: Fn(&ServerKey, &T::Ciphertext, C) -> T::Ciphertext + Sync,
+    {
+        let lhs_ciphertexts = self.to_cpu_ciphertexts();
+
+        let (ciphertexts, shape) = global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            broadcast_zip(
+                &lhs_ciphertexts,
+                &self.shape,
+                &rhs.elements,
+                &rhs.shape,
+                |lhs, rhs| op(sks, lhs, *rhs),
+            )
+        });
+
+        Self::from_cpu_ciphertexts(ciphertexts, shape)
+    }
+}
+
+macro_rules! fhe_array_impl_operation (
+    (
+        $(#[$outer:meta])*
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident),
+        implem: $method:ident,
+        scalar_implem: $scalar_method:ident,
+        scalar_bounds: $($scalar_bound:path),+
+        $(,)?
+    ) => {
+        impl<T: FheArrayElement> $rust_trait_name<&FheArray<T>> for &FheArray<T> {
+            type Output = FheArray<T>;
+
+            $(#[$outer])*
+            fn $rust_trait_method(self, rhs: &FheArray<T>) -> Self::Output {
+                self.broadcast_op(rhs, |sks, lhs, rhs| sks.$method(lhs, rhs))
+            }
+        }
+
+        impl<T, C> $rust_trait_name<&ClearArray<C>> for &FheArray<T>
+        where
+            T: FheArrayElement,
+            C: Copy + Send + Sync $(+ $scalar_bound)+,
+        {
+            type Output = FheArray<T>;
+
+            $(#[$outer])*
+            fn $rust_trait_method(self, rhs: &ClearArray<C>) -> Self::Output {
+                self.broadcast_clear_op(rhs, |sks, lhs, rhs| sks.$scalar_method(lhs, rhs))
+            }
+        }
+    }
+);
+
+fhe_array_impl_operation!(
+    /// Adds two arrays element-wise, after broadcasting them
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes cannot be broadcast together.
+    rust_trait: Add(add),
+    implem: add_parallelized,
+    scalar_implem: scalar_add_parallelized,
+    scalar_bounds: DecomposableInto<u8>,
+);
+
+fhe_array_impl_operation!(
+    /// Subtracts two arrays element-wise, after broadcasting them
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes cannot be broadcast together.
+    rust_trait: Sub(sub),
+    implem: sub_parallelized,
+    scalar_implem: scalar_sub_parallelized,
+    scalar_bounds: DecomposableInto<u8>, TwosComplementNegation,
+);
+
+fhe_array_impl_operation!(
+    /// Multiplies two arrays element-wise, after broadcasting them
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes cannot be broadcast together.
+    rust_trait: Mul(mul),
+    implem: mul_parallelized,
+    scalar_implem: scalar_mul_parallelized,
+    scalar_bounds: DecomposableInto<u8>, ScalarMultiplier,
+);
+
+fhe_array_impl_operation!(
+    /// Computes the bitwise and of two arrays element-wise, after broadcasting them
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes cannot be broadcast together.
+    rust_trait: BitAnd(bitand),
+    implem: bitand_parallelized,
+    scalar_implem: scalar_bitand_parallelized,
+    scalar_bounds: DecomposableInto<u8>,
+);
+
+fhe_array_impl_operation!(
+    /// Computes the bitwise or of two arrays element-wise, after broadcasting them
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes cannot be broadcast together.
+    rust_trait: BitOr(bitor),
+    implem: bitor_parallelized,
+    scalar_implem: scalar_bitor_parallelized,
+    scalar_bounds: DecomposableInto<u8>,
+);
+
+fhe_array_impl_operation!(
+    /// Computes the bitwise xor of two arrays element-wise, after broadcasting them
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes cannot be broadcast together.
+    rust_trait: BitXor(bitxor),
+    implem: bitxor_parallelized,
+    scalar_implem: scalar_bitxor_parallelized,
+    scalar_bounds: DecomposableInto<u8>,
+);
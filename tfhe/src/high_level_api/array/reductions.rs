@@ -0,0 +1,267 @@
+use super::base::{ClearArray, FheArray, FheArrayElement};
+use crate::high_level_api::global_state;
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::server_key::ScalarMultiplier;
+use crate::integer::ServerKey;
+use rayon::prelude::*;
+
+#[derive(Copy, Clone)]
+enum Reduction {
+    Sum,
+    Product,
+    Min,
+    Max,
+}
+
+impl Reduction {
+    fn reduce<C>(self, sks: &ServerKey, ciphertexts: &[&C]) -> C
+    where
+        C: crate::integer::IntegerRadixCiphertext,
+    {
+        assert!(!ciphertexts.is_empty(), "Cannot reduce an empty array");
+
+        let op = match self {
+            Self::Sum => {
+                return sks
+                    .sum_ciphertexts_parallelized(ciphertexts.iter().copied())
+                    .unwrap()
+            }
+            Self::Product => ServerKey::mul_parallelized::<C>,
+            Self::Min => ServerKey::min_parallelized::<C>,
+            Self::Max => ServerKey::max_parallelized::<C>,
+        };
+
+        ciphertexts
+            .par_iter()
+            .map(|ct| (*ct).clone())
+            .reduce_with(|lhs, rhs| op(sks, &lhs, &rhs))
+            .unwrap()
+    }
+}
+
+impl<T: FheArrayElement> FheArray<T> {
+    fn reduce(&self, reduction: Reduction) -> T {
+        let ciphertexts = self.to_cpu_ciphertexts();
+        let ciphertexts = ciphertexts.iter().collect::<Vec<_>>();
+
+        let result = global_state::with_cpu_internal_keys(|cpu_key| {
+            reduction.reduce(cpu_key.pbs_key(), &ciphertexts)
+        });
+        T::from_cpu_ciphertext(result)
+    }
+
+    fn reduce_axis(&self, axis: usize, reduction: Reduction) -> Self {
+        assert!(
+            axis < self.shape.len(),
+            "Axis {axis} is out of bounds for an array of shape {:?}",
+            self.shape
+        );
+
+        let outer_size = self.shape[..axis].iter().product::<usize>();
+        let axis_size = self.shape[axis];
+        let inner_size = self.shape[axis + 1..].iter().product::<usize>();
+
+        let mut shape = self.shape.clone();
+        shape.remove(axis);
+
+        let ciphertexts = self.to_cpu_ciphertexts();
+        let result = global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            (0..outer_size * inner_size)
+                .into_par_iter()
+                .map(|index| {
+                    let (outer, inner) = (index / inner_size, index % inner_size);
+                    let lane = (0..axis_size)
+                        .map(|i| &ciphertexts[(outer * axis_size + i) * inner_size + inner])
+                        .collect::<Vec<_>>();
+                    reduction.reduce(sks, &lane)
+                })
+                .collect()
+        });
+
+        Self::from_cpu_ciphertexts(result, shape)
+    }
+
+    /// Returns the sum of all the elements
+    ///
+    /// The operation is modular, i.e on overflow it wraps around.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty.
+    pub fn sum(&self) -> T {
+        self.reduce(Reduction::Sum)
+    }
+
+    /// Returns the product of all the elements
+    ///
+    /// The operation is modular, i.e on overflow it wraps around.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty.
+    pub fn product(&self) -> T {
+        self.reduce(Reduction::Product)
+    }
+
+    /// Returns the smallest element
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty.
+    pub fn min(&self) -> T {
+        self.reduce(Reduction::Min)
+    }
+
+    /// Returns the largest element
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is empty.
+    pub fn max(&self) -> T {
+        self.reduce(Reduction::Max)
+    }
+
+    /// Sums the elements along the axis, the axis is removed from the shape of the result
+    ///
+    /// # Panics
+    ///
+    /// Panics if the axis is out of bounds or has a size of 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::array::FheArray;
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a =
+    ///     FheArray::<FheUint8>::try_encrypt(&[1u8, 2, 3, 4, 5, 6], &[2, 3], &client_key).unwrap();
+    ///
+    /// let decrypted: Vec<u8> = a.sum_axis(0).decrypt(&client_key);
+    /// assert_eq!(decrypted, vec![5, 7, 9]);
+    /// let decrypted: Vec<u8> = a.sum_axis(1).decrypt(&client_key);
+    /// assert_eq!(decrypted, vec![6, 15]);
+    /// ```
+    pub fn sum_axis(&self, axis: usize) -> Self {
+        self.reduce_axis(axis, Reduction::Sum)
+    }
+
+    /// Multiplies the elements along the axis, the axis is removed from the shape of the result
+    ///
+    /// # Panics
+    ///
+    /// Panics if the axis is out of bounds or has a size of 0.
+    pub fn product_axis(&self, axis: usize) -> Self {
+        self.reduce_axis(axis, Reduction::Product)
+    }
+
+    /// Returns the smallest elements along the axis, the axis is removed from the shape of the
+    /// result
+    ///
+    /// # Panics
+    ///
+    /// Panics if the axis is out of bounds or has a size of 0.
+    pub fn min_axis(&self, axis: usize) -> Self {
+        self.reduce_axis(axis, Reduction::Min)
+    }
+
+    /// Returns the largest elements along the axis, the axis is removed from the shape of the
+    /// result
+    ///
+    /// # Panics
+    ///
+    /// Panics if the axis is out of bounds or has a size of 0.
+    pub fn max_axis(&self, axis: usize) -> Self {
+        self.reduce_axis(axis, Reduction::Max)
+    }
+
+    /// Computes the dot product of two 1-dimensional arrays of the same length
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays are not 1-dimensional, or if they do not have the same length, or if
+    /// they are empty.
+    pub fn dot<C>(&self, rhs: &ClearArray<C>) -> T
+    where
+        C: Copy + Send + Sync + DecomposableInto<u8> + ScalarMultiplier,
+    {
+        assert!(
+            self.shape.len() == 1 && rhs.shape.len() == 1 && self.shape == rhs.shape,
+            "Cannot compute the dot product of arrays of shapes {:?} and {:?}",
+            self.shape,
+            rhs.shape
+        );
+
+        self.matmul(&ClearArray {
+            elements: rhs.elements.clone(),
+            shape: vec![rhs.elements.len(), 1],
+        })
+        .into_vec()
+        .remove(0)
+    }
+
+    /// Computes the matrix product of a `m x k` encrypted matrix with a `k x n` clear matrix
+    ///
+    /// A 1-dimensional array of length `k` is used as a `1 x k` matrix, and the result is then a
+    /// 1-dimensional array of length `n`.
+    ///
+    /// The operation is modular, i.e on overflow it wraps around.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays are not matrices, or if their inner dimensions are not the same or
+    /// are 0.
+    pub fn matmul<C>(&self, rhs: &ClearArray<C>) -> Self
+    where
+        C: Copy + Send + Sync + DecomposableInto<u8> + ScalarMultiplier,
+    {
+        let (m, k) = match self.shape.as_slice() {
+            [k] => (1, *k),
+            [m, k] => (*m, *k),
+            _ => panic!("Expected a matrix, got an array of shape {:?}", self.shape),
+        };
+        let n = match rhs.shape.as_slice() {
+            [rhs_k, n] if *rhs_k == k => *n,
+            _ => panic!(
+                "Cannot compute the matrix product of arrays of shapes {:?} and {:?}",
+                self.shape, rhs.shape
+            ),
+        };
+
+        assert_ne!(
+            k, 0,
+            "Cannot compute a matrix product with an inner dimension of 0"
+        );
+
+        let ciphertexts = self.to_cpu_ciphertexts();
+        let result = global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            (0..m * n)
+                .into_par_iter()
+                .map(|index| {
+                    let (row, column) = (index / n, index % n);
+                    let products = (0..k)
+                        .map(|i| {
+                            sks.scalar_mul_parallelized(
+                                &ciphertexts[row * k + i],
+                                rhs.elements[i * n + column],
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    sks.sum_ciphertexts_parallelized(products.iter()).unwrap()
+                })
+                .collect()
+        });
+
+        let shape = if self.shape.len() == 1 {
+            vec![n]
+        } else {
+            vec![m, n]
+        };
+        Self::from_cpu_ciphertexts(result, shape)
+    }
+}
@@ -0,0 +1,204 @@
+use super::base::FheArrayElement;
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::IntegerId;
+use crate::integer::{RadixCiphertext, ServerKey};
+use crate::{FheUint32, FheUint32Id};
+
+fn to_cpu_ciphertexts<T: FheArrayElement>(values: &[T]) -> Vec<T::Ciphertext> {
+    values.iter().map(T::to_cpu_ciphertext).collect()
+}
+
+fn from_cpu_ciphertexts<T: FheArrayElement>(ciphertexts: Vec<T::Ciphertext>) -> Vec<T> {
+    ciphertexts
+        .into_iter()
+        .map(T::from_cpu_ciphertext)
+        .collect()
+}
+
+fn to_fhe_uint32(sks: &ServerKey, index: RadixCiphertext) -> FheUint32 {
+    let index = sks.cast_to_unsigned(index, FheUint32Id::num_blocks(sks.message_modulus()));
+    FheUint32::new(index)
+}
+
+/// Returns the values sorted in ascending order
+///
+/// The values are sorted with a sorting network, whose comparisons are computed layer by layer,
+/// the comparisons of a layer being done in parallel.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::array::sort;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let values = [42u8, 7, 19]
+///     .iter()
+///     .map(|v| FheUint8::encrypt(*v, &client_key))
+///     .collect::<Vec<_>>();
+///
+/// let sorted = sort(&values);
+/// let sorted: Vec<u8> = sorted.iter().map(|v| v.decrypt(&client_key)).collect();
+/// assert_eq!(sorted, vec![7, 19, 42]);
+/// ```
+pub fn sort<T: FheArrayElement>(values: &[T]) -> Vec<T> {
+    let ciphertexts = to_cpu_ciphertexts(values);
+    let sorted = global_state::with_cpu_internal_keys(|cpu_key| {
+        cpu_key.pbs_key().sort_parallelized(&ciphertexts)
+    });
+    from_cpu_ciphertexts(sorted)
+}
+
+/// Sorts the key-value pairs in ascending order of their keys
+///
+/// Returns the sorted keys and the values in the same order. The order of the pairs having equal
+/// keys is unspecified.
+///
+/// # Panics
+///
+/// Panics if the slices do not have the same length.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::array::sort_by_key;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let bids = [300u16, 100, 200]
+///     .iter()
+///     .map(|v| FheUint16::encrypt(*v, &client_key))
+///     .collect::<Vec<_>>();
+/// let bidders = [1u8, 2, 3]
+///     .iter()
+///     .map(|v| FheUint8::encrypt(*v, &client_key))
+///     .collect::<Vec<_>>();
+///
+/// let (_, bidders) = sort_by_key(&bids, &bidders);
+/// let bidders: Vec<u8> = bidders.iter().map(|v| v.decrypt(&client_key)).collect();
+/// assert_eq!(bidders, vec![2, 3, 1]);
+/// ```
+pub fn sort_by_key<K, V>(keys: &[K], values: &[V]) -> (Vec<K>, Vec<V>)
+where
+    K: FheArrayElement,
+    V: FheArrayElement,
+{
+    let key_ciphertexts = to_cpu_ciphertexts(keys);
+    let value_ciphertexts = to_cpu_ciphertexts(values);
+    let (sorted_keys, sorted_values) = global_state::with_cpu_internal_keys(|cpu_key| {
+        cpu_key
+            .pbs_key()
+            .sort_by_key_parallelized(&key_ciphertexts, &value_ciphertexts)
+    });
+    (
+        from_cpu_ciphertexts(sorted_keys),
+        from_cpu_ciphertexts(sorted_values),
+    )
+}
+
+/// Returns the encrypted index of the smallest value, and the smallest value
+///
+/// - If several values are equal to the minimum, the index of the first one is returned
+/// - Returns `None` if the slice is empty
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::array::argmin;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let values = [3i8, -5, 12, -5]
+///     .iter()
+///     .map(|v| FheInt8::encrypt(*v, &client_key))
+///     .collect::<Vec<_>>();
+///
+/// let (index, min) = argmin(&values).unwrap();
+/// let index: u32 = index.decrypt(&client_key);
+/// let min: i8 = min.decrypt(&client_key);
+/// assert_eq!(index, 1);
+/// assert_eq!(min, -5);
+/// ```
+pub fn argmin<T: FheArrayElement>(values: &[T]) -> Option<(FheUint32, T)> {
+    let ciphertexts = to_cpu_ciphertexts(values);
+    global_state::with_cpu_internal_keys(|cpu_key| {
+        let sks = cpu_key.pbs_key();
+        sks.argmin_parallelized(&ciphertexts)
+            .map(|(index, min)| (to_fhe_uint32(sks, index), T::from_cpu_ciphertext(min)))
+    })
+}
+
+/// Returns the encrypted index of the greatest value, and the greatest value
+///
+/// - If several values are equal to the maximum, the index of the first one is returned
+/// - Returns `None` if the slice is empty
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::array::argmax;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let values = [3u8, 5, 12, 1]
+///     .iter()
+///     .map(|v| FheUint8::encrypt(*v, &client_key))
+///     .collect::<Vec<_>>();
+///
+/// let (index, max) = argmax(&values).unwrap();
+/// let index: u32 = index.decrypt(&client_key);
+/// let max: u8 = max.decrypt(&client_key);
+/// assert_eq!(index, 2);
+/// assert_eq!(max, 12);
+/// ```
+pub fn argmax<T: FheArrayElement>(values: &[T]) -> Option<(FheUint32, T)> {
+    let ciphertexts = to_cpu_ciphertexts(values);
+    global_state::with_cpu_internal_keys(|cpu_key| {
+        let sks = cpu_key.pbs_key();
+        sks.argmax_parallelized(&ciphertexts)
+            .map(|(index, max)| (to_fhe_uint32(sks, index), T::from_cpu_ciphertext(max)))
+    })
+}
+
+/// Returns the `k` greatest values, in descending order
+///
+/// If `k` is greater than the number of values, all of them are returned.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::array::top_k;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let values = [3u8, 5, 12, 1]
+///     .iter()
+///     .map(|v| FheUint8::encrypt(*v, &client_key))
+///     .collect::<Vec<_>>();
+///
+/// let top = top_k(&values, 2);
+/// let top: Vec<u8> = top.iter().map(|v| v.decrypt(&client_key)).collect();
+/// assert_eq!(top, vec![12, 5]);
+/// ```
+pub fn top_k<T: FheArrayElement>(values: &[T], k: usize) -> Vec<T> {
+    let ciphertexts = to_cpu_ciphertexts(values);
+    let top = global_state::with_cpu_internal_keys(|cpu_key| {
+        cpu_key.pbs_key().top_k_parallelized(&ciphertexts, k)
+    });
+    from_cpu_ciphertexts(top)
+}
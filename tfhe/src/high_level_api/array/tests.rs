@@ -0,0 +1,217 @@
+use super::{
+    argmax, argmin, lookup, read_at, sort, sort_by_key, top_k, write_at, ClearArray, FheArray,
+};
+use crate::high_level_api::prelude::*;
+use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder};
+use crate::{ClientKey, FheInt8, FheUint16, FheUint8};
+
+fn setup_default_cpu() -> ClientKey {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    client_key
+}
+
+#[test]
+fn test_array_shapes() {
+    let (client_key, _) = generate_keys(ConfigBuilder::default());
+
+    let clear = (0..24u8).collect::<Vec<_>>();
+    let array = FheArray::<FheUint8>::try_encrypt(&clear, &[2, 3, 4], &client_key).unwrap();
+    assert_eq!(array.shape(), &[2, 3, 4]);
+    assert_eq!(array.num_dims(), 3);
+    assert_eq!(array.len(), 24);
+
+    let element: u8 = array.get(&[1, 2, 3]).unwrap().decrypt(&client_key);
+    assert_eq!(element, 23);
+    assert!(array.get(&[2, 0, 0]).is_none());
+    assert!(array.get(&[0, 0]).is_none());
+
+    let sliced = array.slice(&[1..2, 0..3, 1..3]).unwrap();
+    assert_eq!(sliced.shape(), &[1, 3, 2]);
+    let decrypted: Vec<u8> = sliced.decrypt(&client_key);
+    assert_eq!(decrypted, vec![13, 14, 17, 18, 21, 22]);
+
+    let reshaped = sliced.reshape(&[3, 2]).unwrap();
+    assert_eq!(reshaped.shape(), &[3, 2]);
+    assert!(sliced.reshape(&[4, 2]).is_err());
+
+    assert!(array.slice(&[0..1, 0..1]).is_err());
+    assert!(array.slice(&[0..1, 0..4, 0..1]).is_err());
+    assert!(FheArray::<FheUint8>::try_encrypt(&clear, &[5, 5], &client_key).is_err());
+    assert!(ClearArray::new(vec![1u8, 2], &[3]).is_err());
+}
+
+#[test]
+fn test_array_element_wise_ops() {
+    let client_key = setup_default_cpu();
+
+    let a = FheArray::<FheUint8>::try_encrypt(&[1u8, 2, 3, 4, 5, 6], &[2, 3], &client_key).unwrap();
+    let b = FheArray::<FheUint8>::try_encrypt(&[10u8, 20], &[2, 1], &client_key).unwrap();
+
+    let decrypted: Vec<u8> = (&a + &b).decrypt(&client_key);
+    assert_eq!(decrypted, vec![11, 12, 13, 24, 25, 26]);
+    let decrypted: Vec<u8> = (&b - &a).decrypt(&client_key);
+    assert_eq!(decrypted, vec![9, 8, 7, 16, 15, 14]);
+    let decrypted: Vec<u8> = (&a * &b).decrypt(&client_key);
+    assert_eq!(decrypted, vec![10, 20, 30, 80, 100, 120]);
+    let decrypted: Vec<u8> = (&a & &b).decrypt(&client_key);
+    assert_eq!(decrypted, vec![0, 2, 2, 4, 4, 4]);
+
+    let c = ClearArray::from(vec![1u8, 2, 3]);
+    let decrypted: Vec<u8> = (&a | &c).decrypt(&client_key);
+    assert_eq!(decrypted, vec![1, 2, 3, 5, 7, 7]);
+    let decrypted: Vec<u8> = (&a ^ &c).decrypt(&client_key);
+    assert_eq!(decrypted, vec![0, 0, 0, 5, 7, 5]);
+    let decrypted: Vec<u8> = (&a - &c).decrypt(&client_key);
+    assert_eq!(decrypted, vec![0, 0, 0, 3, 3, 3]);
+
+    let d = ClearArray::new(vec![2u8], &[1, 1, 1]).unwrap();
+    let product = &a * &d;
+    assert_eq!(product.shape(), &[1, 2, 3]);
+    let decrypted: Vec<u8> = product.decrypt(&client_key);
+    assert_eq!(decrypted, vec![2, 4, 6, 8, 10, 12]);
+}
+
+#[test]
+#[should_panic(expected = "cannot be broadcast together")]
+fn test_array_incompatible_shapes() {
+    let client_key = setup_default_cpu();
+
+    let a = FheArray::<FheUint8>::try_encrypt(&[1u8, 2, 3], &[3], &client_key).unwrap();
+    let b = ClearArray::from(vec![1u8, 2]);
+
+    let _ = &a + &b;
+}
+
+#[test]
+fn test_array_reductions() {
+    let client_key = setup_default_cpu();
+
+    let a =
+        FheArray::<FheInt8>::try_encrypt(&[3i8, -2, 7, 1, -5, 4], &[2, 3], &client_key).unwrap();
+
+    let sum: i8 = a.sum().decrypt(&client_key);
+    assert_eq!(sum, 8);
+    let product: i8 = a
+        .slice(&[0..1, 0..3])
+        .unwrap()
+        .product()
+        .decrypt(&client_key);
+    assert_eq!(product, -42);
+    let min: i8 = a.min().decrypt(&client_key);
+    assert_eq!(min, -5);
+    let max: i8 = a.max().decrypt(&client_key);
+    assert_eq!(max, 7);
+
+    let sums = a.sum_axis(0);
+    assert_eq!(sums.shape(), &[3]);
+    let decrypted: Vec<i8> = sums.decrypt(&client_key);
+    assert_eq!(decrypted, vec![4, -7, 11]);
+    let decrypted: Vec<i8> = a.min_axis(1).decrypt(&client_key);
+    assert_eq!(decrypted, vec![-2, -5]);
+    let decrypted: Vec<i8> = a.max_axis(1).decrypt(&client_key);
+    assert_eq!(decrypted, vec![7, 4]);
+    let decrypted: Vec<i8> = a.product_axis(0).decrypt(&client_key);
+    assert_eq!(decrypted, vec![3, 10, 28]);
+}
+
+#[test]
+fn test_array_dot_and_matmul() {
+    let client_key = setup_default_cpu();
+
+    let a =
+        FheArray::<FheInt8>::try_encrypt(&[1i8, -2, 3, 4, 5, -6], &[2, 3], &client_key).unwrap();
+    let weights = ClearArray::new(vec![1i8, 2, 0, -1, 3, 1], &[3, 2]).unwrap();
+
+    let result = a.matmul(&weights);
+    assert_eq!(result.shape(), &[2, 2]);
+    let decrypted: Vec<i8> = result.decrypt(&client_key);
+    assert_eq!(decrypted, vec![10, 7, -14, -3]);
+
+    let row = a.slice(&[1..2, 0..3]).unwrap().reshape(&[3]).unwrap();
+    let result = row.matmul(&weights);
+    assert_eq!(result.shape(), &[2]);
+    let decrypted: Vec<i8> = result.decrypt(&client_key);
+    assert_eq!(decrypted, vec![-14, -3]);
+
+    let dot: i8 = row
+        .dot(&ClearArray::from(vec![2i8, 1, 1]))
+        .decrypt(&client_key);
+    assert_eq!(dot, 7);
+}
+
+#[test]
+fn test_sorting() {
+    let client_key = setup_default_cpu();
+
+    let values = [5i8, -3, 12, -3, 0]
+        .iter()
+        .map(|v| FheInt8::encrypt(*v, &client_key))
+        .collect::<Vec<_>>();
+
+    let sorted: Vec<i8> = sort(&values)
+        .iter()
+        .map(|v| v.decrypt(&client_key))
+        .collect();
+    assert_eq!(sorted, vec![-3, -3, 0, 5, 12]);
+
+    let (index, min) = argmin(&values).unwrap();
+    let index: u32 = index.decrypt(&client_key);
+    let min: i8 = min.decrypt(&client_key);
+    assert_eq!((index, min), (1, -3));
+    let (index, max) = argmax(&values).unwrap();
+    let index: u32 = index.decrypt(&client_key);
+    let max: i8 = max.decrypt(&client_key);
+    assert_eq!((index, max), (2, 12));
+    assert!(argmin::<FheInt8>(&[]).is_none());
+
+    let top: Vec<i8> = top_k(&values, 2)
+        .iter()
+        .map(|v| v.decrypt(&client_key))
+        .collect();
+    assert_eq!(top, vec![12, 5]);
+
+    let bids = [300u16, 100, 200]
+        .iter()
+        .map(|v| FheUint16::encrypt(*v, &client_key))
+        .collect::<Vec<_>>();
+    let bidders = [1u8, 2, 3]
+        .iter()
+        .map(|v| FheUint8::encrypt(*v, &client_key))
+        .collect::<Vec<_>>();
+    let (bids, bidders) = sort_by_key(&bids, &bidders);
+    let bids: Vec<u16> = bids.iter().map(|v| v.decrypt(&client_key)).collect();
+    let bidders: Vec<u8> = bidders.iter().map(|v| v.decrypt(&client_key)).collect();
+    assert_eq!(bids, vec![100, 200, 300]);
+    assert_eq!(bidders, vec![2, 3, 1]);
+}
+
+#[test]
+fn test_indexing() {
+    let client_key = setup_default_cpu();
+
+    let mut values = [5i8, -3, 12, 0]
+        .iter()
+        .map(|v| FheInt8::encrypt(*v, &client_key))
+        .collect::<Vec<_>>();
+
+    let index = FheUint8::encrypt(2u8, &client_key);
+    let value: i8 = read_at(&values, &index).decrypt(&client_key);
+    assert_eq!(value, 12);
+    let out_of_bounds = FheUint8::encrypt(4u8, &client_key);
+    let value: i8 = read_at(&values, &out_of_bounds).decrypt(&client_key);
+    assert_eq!(value, 0);
+
+    let new_value = FheInt8::encrypt(-7i8, &client_key);
+    write_at(&mut values, &index, &new_value);
+    write_at(&mut values, &out_of_bounds, &new_value);
+    let values: Vec<i8> = values.iter().map(|v| v.decrypt(&client_key)).collect();
+    assert_eq!(values, vec![5, -3, -7, 0]);
+
+    let squares = (0..20u16).map(|i| i * i).collect::<Vec<_>>();
+    let index = FheUint8::encrypt(17u8, &client_key);
+    let square: FheUint16 = lookup(&squares, &index);
+    let square: u16 = square.decrypt(&client_key);
+    assert_eq!(square, 17 * 17);
+}
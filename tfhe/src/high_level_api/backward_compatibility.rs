@@ -0,0 +1,258 @@
+//! Versions of the serialized types of the high level api, see [`crate::versionable`].
+
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::high_level_api::keys::{IntegerClientKey, IntegerCompressedServerKey, IntegerServerKey};
+use crate::versionable::{versions_dispatch, Upgrade};
+use crate::{
+    ClientKey, CompactCiphertextList, CompactPublicKey, CompressedCiphertextList,
+    CompressedCompactPublicKey, CompressedFheBool, CompressedFheInt, CompressedFheUint,
+    CompressedPublicKey, CompressedServerKey, FheBool, FheFixedI, FheFixedU, FheInt, FheUint,
+    PublicKey, ServerKey,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+versions_dispatch! {
+    pub enum FheUintVersions<Id: FheUintId + Serialize + DeserializeOwned> for FheUint<Id> {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedFheUintVersions<Id: FheUintId + Serialize + DeserializeOwned>
+        for CompressedFheUint<Id>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum FheIntVersions<Id: FheIntId + Serialize + DeserializeOwned> for FheInt<Id> {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedFheIntVersions<Id: FheIntId + Serialize + DeserializeOwned>
+        for CompressedFheInt<Id>
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum FheBoolVersions for FheBool {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedFheBoolVersions for CompressedFheBool {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum FheFixedUVersions<const INT: usize, const FRAC: usize> for FheFixedU<INT, FRAC> {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum FheFixedIVersions<const INT: usize, const FRAC: usize> for FheFixedI<INT, FRAC> {
+        #[current]
+        V0,
+    }
+}
+
+#[cfg(feature = "strings")]
+versions_dispatch! {
+    pub enum FheAsciiStringVersions for crate::FheAsciiString {
+        #[current]
+        V0,
+    }
+}
+
+#[cfg(feature = "strings")]
+versions_dispatch! {
+    pub enum CompressedFheAsciiStringVersions for crate::CompressedFheAsciiString {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompactCiphertextListVersions for CompactCiphertextList {
+        #[current]
+        V0,
+    }
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+versions_dispatch! {
+    pub enum ProvenCompactCiphertextListVersions for crate::ProvenCompactCiphertextList {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedCiphertextListVersions for CompressedCiphertextList {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum PublicKeyVersions for PublicKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedPublicKeyVersions for CompressedPublicKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompactPublicKeyVersions for CompactPublicKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedCompactPublicKeyVersions for CompressedCompactPublicKey {
+        #[current]
+        V0,
+    }
+}
+
+// Layout of the keys before the compression keys were added
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct IntegerClientKeyV0 {
+    pub(crate) key: crate::integer::ClientKey,
+    pub(crate) wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ClientKeyV0 {
+    pub(crate) key: IntegerClientKeyV0,
+}
+
+impl Upgrade<ClientKey> for ClientKeyV0 {
+    fn upgrade(self) -> Result<ClientKey, String> {
+        let IntegerClientKeyV0 {
+            key,
+            wopbs_block_parameters,
+        } = self.key;
+
+        Ok(ClientKey {
+            key: IntegerClientKey {
+                key,
+                wopbs_block_parameters,
+                compression_key: None,
+            },
+        })
+    }
+}
+
+versions_dispatch! {
+    pub enum ClientKeyVersions for ClientKey {
+        V0(ClientKeyV0),
+        #[current]
+        V1,
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct IntegerServerKeyV0 {
+    pub(crate) key: crate::integer::ServerKey,
+    pub(crate) wopbs_key: Option<crate::integer::wopbs::WopbsKey>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ServerKeyV0 {
+    pub(crate) integer_key: IntegerServerKeyV0,
+}
+
+impl Upgrade<ServerKey> for ServerKeyV0 {
+    fn upgrade(self) -> Result<ServerKey, String> {
+        let IntegerServerKeyV0 { key, wopbs_key } = self.integer_key;
+
+        Ok(ServerKey {
+            key: Arc::new(IntegerServerKey {
+                key,
+                wopbs_key,
+                compression_key: None,
+                decompression_key: None,
+            }),
+        })
+    }
+}
+
+versions_dispatch! {
+    pub enum ServerKeyVersions for ServerKey {
+        V0(ServerKeyV0),
+        #[current]
+        V1,
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct IntegerCompressedServerKeyV0 {
+    pub(crate) key: crate::integer::CompressedServerKey,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CompressedServerKeyV0 {
+    pub(crate) integer_key: IntegerCompressedServerKeyV0,
+}
+
+impl Upgrade<CompressedServerKey> for CompressedServerKeyV0 {
+    fn upgrade(self) -> Result<CompressedServerKey, String> {
+        Ok(CompressedServerKey {
+            integer_key: IntegerCompressedServerKey {
+                key: self.integer_key.key,
+                compression_key: None,
+                decompression_key: None,
+            },
+        })
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedServerKeyVersions for CompressedServerKey {
+        V0(CompressedServerKeyV0),
+        #[current]
+        V1,
+    }
+}
+
+#[cfg(feature = "experimental")]
+versions_dispatch! {
+    pub enum ThresholdClientKeyShareVersions for crate::ThresholdClientKeyShare {
+        #[current]
+        V0,
+    }
+}
+
+#[cfg(feature = "experimental")]
+versions_dispatch! {
+    pub enum DecryptionShareVersions for crate::DecryptionShare {
+        #[current]
+        V0,
+    }
+}
@@ -16,7 +16,7 @@ use crate::named::Named;
 use crate::shortint::ciphertext::NotTrivialCiphertextError;
 use crate::shortint::parameters::CiphertextConformanceParams;
 use crate::shortint::PBSParameters;
-use crate::{Device, ServerKey};
+use crate::{CompactPublicKey, Device, Seed, ServerKey};
 use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
 use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
@@ -164,6 +164,58 @@ impl FheBool {
     pub fn is_trivial(&self) -> bool {
         self.ciphertext.on_cpu().is_trivial()
     }
+
+    /// Re-randomizes the ciphertext, so that it cannot be linked to the ciphertexts it was
+    /// computed from
+    ///
+    /// A fresh encryption of zero, made with the compact public key, is added to the ciphertext,
+    /// which is then bootstrapped so that its noise does not depend on the inputs.
+    ///
+    /// The randomness of the encryption of zero is derived from the seed, so the same seed must
+    /// never be used twice.
+    ///
+    /// Returns an error if the LWE dimension of the ciphertext differs from the one of the
+    /// compact public key. The client key itself cannot be checked, re-randomizing with the
+    /// public key of another client key corrupts the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, CompactPublicKey, ConfigBuilder, FheBool, Seed};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    /// let public_key = CompactPublicKey::new(&client_key);
+    ///
+    /// let mut a = FheBool::encrypt(true, &client_key);
+    /// a.re_randomize(&public_key, Seed(0)).unwrap();
+    ///
+    /// let decrypted: bool = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, true);
+    /// ```
+    pub fn re_randomize(
+        &mut self,
+        compact_public_key: &CompactPublicKey,
+        seed: Seed,
+    ) -> crate::Result<()> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => {
+                let block = &mut self.ciphertext.as_cpu_mut().0;
+                compact_public_key
+                    .key
+                    .key
+                    .key
+                    .re_randomize_ciphertexts(std::slice::from_mut(block), seed)?;
+                cpu_key.pbs_key().key.message_extract_assign(block);
+                Ok(())
+            }
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => Err(crate::Error::new(
+                "Cuda devices do not support re_randomize yet".to_string(),
+            )),
+        })
+    }
 }
 
 impl<Id> IfThenElse<FheUint<Id>> for FheBool
@@ -31,8 +31,10 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[derive(Clone, Serialize, Deserialize)]
 pub enum CompressedFheBool {
-    Seeded(CompressedCiphertext),
-    ModulusSwitched(CompressedModulusSwitchedCiphertext),
+    Seeded(#[serde(with = "crate::versionable::nested")] CompressedCiphertext),
+    ModulusSwitched(
+        #[serde(with = "crate::versionable::nested")] CompressedModulusSwitchedCiphertext,
+    ),
 }
 
 impl CompressedFheBool {
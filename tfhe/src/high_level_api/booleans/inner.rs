@@ -29,9 +29,9 @@ impl serde::Serialize for InnerBoolean {
         S: Serializer,
     {
         match self {
-            Self::Cpu(cpu_ct) => cpu_ct.serialize(serializer),
+            Self::Cpu(cpu_ct) => crate::versionable::nested::serialize(cpu_ct, serializer),
             #[cfg(feature = "gpu")]
-            Self::Cuda(_) => self.on_cpu().serialize(serializer),
+            Self::Cuda(_) => crate::versionable::nested::serialize(&*self.on_cpu(), serializer),
         }
     }
 }
@@ -41,7 +41,7 @@ impl<'de> serde::Deserialize<'de> for InnerBoolean {
     where
         D: Deserializer<'de>,
     {
-        let mut deserialized = Self::Cpu(crate::integer::BooleanBlock::deserialize(deserializer)?);
+        let mut deserialized = Self::Cpu(crate::versionable::nested::deserialize(deserializer)?);
         deserialized.move_to_device_of_server_key_if_set();
         Ok(deserialized)
     }
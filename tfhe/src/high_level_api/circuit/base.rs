@@ -0,0 +1,278 @@
+use super::graph::{ComparisonOp, Graph, NodeId, Operation, Value};
+use crate::high_level_api::global_state::with_cpu_internal_keys;
+use crate::high_level_api::integers::FheUintId;
+use crate::{FheBool, FheUint};
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+/// A lazily evaluated homomorphic program
+///
+/// Values are added to the circuit using [Circuit::input] and [Circuit::input_bool], which
+/// return lazy handles ([LazyFheUint], [LazyFheBool]). Operations on these handles do not
+/// compute anything, they record the operation in the circuit and return a handle to its
+/// result.
+///
+/// The circuit is executed when the value of a handle is requested with `eval`,
+/// or explicitly using [Circuit::execute]. When executed:
+///
+/// - only the operations needed to compute the values of the live handles are executed, dropping
+///   a handle makes the operations only it depended on dead code,
+/// - identical operations are recorded only once, and thus computed only once,
+/// - carry propagations of consecutive additions/subtractions are deferred as long as there is
+///   enough carry space,
+/// - all the operations that are ready are computed in parallel.
+///
+/// Circuits only support the CPU server key.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::circuit::Circuit;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(3u8, &client_key);
+/// let b = FheUint8::encrypt(7u8, &client_key);
+///
+/// let circuit = Circuit::new();
+/// let lazy_a = circuit.input(&a);
+/// let lazy_b = circuit.input(&b);
+///
+/// // Nothing is computed yet
+/// let sum = &lazy_a + &lazy_b;
+/// let product = &sum * &lazy_a;
+/// let is_greater = product.gt(&lazy_b);
+/// let result = is_greater.if_then_else(&product, &sum);
+///
+/// // Computes result, and only the nodes it depends on
+/// let result = result.eval();
+/// let decrypted: u8 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, 30);
+/// ```
+#[derive(Clone, Default)]
+pub struct Circuit {
+    graph: Rc<RefCell<Graph>>,
+}
+
+impl Circuit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an encrypted unsigned integer to the circuit
+    pub fn input<Id: FheUintId>(&self, value: &FheUint<Id>) -> LazyFheUint<Id> {
+        let ciphertext = value.ciphertext.on_cpu().to_owned();
+        let node = self.graph.borrow_mut().push_input(Value::Radix(ciphertext));
+        LazyFheUint::new(Handle {
+            graph: self.graph.clone(),
+            node,
+        })
+    }
+
+    /// Adds an encrypted boolean to the circuit
+    pub fn input_bool(&self, value: &FheBool) -> LazyFheBool {
+        let ciphertext = value.ciphertext.on_cpu().to_owned();
+        let node = self
+            .graph
+            .borrow_mut()
+            .push_input(Value::Boolean(ciphertext));
+        LazyFheBool {
+            handle: Handle {
+                graph: self.graph.clone(),
+                node,
+            },
+        }
+    }
+
+    /// Computes the values of all the live handles of this circuit
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server key is not set, or if it is not a CPU server key.
+    pub fn execute(&self) {
+        execute(&self.graph);
+    }
+}
+
+fn execute(graph: &RefCell<Graph>) {
+    with_cpu_internal_keys(|cpu_key| graph.borrow_mut().execute(cpu_key.pbs_key()));
+}
+
+/// A reference to a node of a circuit graph, keeping the node alive
+pub(super) struct Handle {
+    graph: Rc<RefCell<Graph>>,
+    node: NodeId,
+}
+
+impl Handle {
+    /// Records an operation in the same circuit as this handle
+    ///
+    /// # Panics
+    ///
+    /// Panics if the operands of the operation are not all from the same circuit.
+    pub(super) fn record(&self, operation: Operation, operands: &[&Self]) -> Self {
+        assert!(
+            operands
+                .iter()
+                .all(|operand| Rc::ptr_eq(&operand.graph, &self.graph)),
+            "Operands belong to different circuits"
+        );
+        let node = self.graph.borrow_mut().push(operation);
+        Self {
+            graph: self.graph.clone(),
+            node,
+        }
+    }
+
+    pub(super) fn node(&self) -> NodeId {
+        self.node
+    }
+
+    fn value(&self) -> Value {
+        if self.graph.borrow().nodes[self.node].value.is_none() {
+            execute(&self.graph);
+        }
+        self.graph.borrow().nodes[self.node]
+            .value
+            .clone()
+            .expect("Circuit execution did not compute a live node")
+    }
+
+    #[cfg(test)]
+    pub(super) fn graph(&self) -> &RefCell<Graph> {
+        &self.graph
+    }
+}
+
+impl Clone for Handle {
+    fn clone(&self) -> Self {
+        self.graph.borrow_mut().acquire(self.node);
+        Self {
+            graph: self.graph.clone(),
+            node: self.node,
+        }
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        self.graph.borrow_mut().release(self.node);
+    }
+}
+
+/// A lazily computed [FheUint], created from a [Circuit]
+///
+/// The operators (`+`, `-`, `*`, `&`, `|`, `^`, `<<`, `>>`, `!`) are overloaded
+/// and record operations in the circuit.
+#[derive(Clone)]
+pub struct LazyFheUint<Id: FheUintId> {
+    pub(super) handle: Handle,
+    id: PhantomData<Id>,
+}
+
+impl<Id: FheUintId> LazyFheUint<Id> {
+    pub(super) fn new(handle: Handle) -> Self {
+        Self {
+            handle,
+            id: PhantomData,
+        }
+    }
+
+    /// Returns the value of this node, executing the circuit if needed
+    ///
+    /// # Panics
+    ///
+    /// Panics if the circuit has to be executed and the server key is not set,
+    /// or is not a CPU server key.
+    pub fn eval(&self) -> FheUint<Id> {
+        match self.handle.value() {
+            Value::Radix(ciphertext) => FheUint::new(ciphertext),
+            Value::Boolean(_) => unreachable!("LazyFheUint always points to an integer node"),
+        }
+    }
+
+    fn comparison(&self, op: ComparisonOp, lhs: &Self, rhs: &Self) -> LazyFheBool {
+        LazyFheBool {
+            handle: self.handle.record(
+                Operation::Comparison(op, lhs.handle.node(), rhs.handle.node()),
+                &[&lhs.handle, &rhs.handle],
+            ),
+        }
+    }
+
+    pub fn eq(&self, other: &Self) -> LazyFheBool {
+        self.comparison(ComparisonOp::Eq, self, other)
+    }
+
+    pub fn ne(&self, other: &Self) -> LazyFheBool {
+        self.comparison(ComparisonOp::Ne, self, other)
+    }
+
+    pub fn lt(&self, other: &Self) -> LazyFheBool {
+        self.comparison(ComparisonOp::Lt, self, other)
+    }
+
+    pub fn le(&self, other: &Self) -> LazyFheBool {
+        self.comparison(ComparisonOp::Le, self, other)
+    }
+
+    /// `a > b` is recorded as `b < a`, so that both are deduplicated
+    pub fn gt(&self, other: &Self) -> LazyFheBool {
+        self.comparison(ComparisonOp::Lt, other, self)
+    }
+
+    pub fn ge(&self, other: &Self) -> LazyFheBool {
+        self.comparison(ComparisonOp::Le, other, self)
+    }
+}
+
+/// A lazily computed [FheBool], created from a [Circuit]
+///
+/// The operators (`&`, `|`, `^`, `!`) are overloaded and record operations in the circuit.
+#[derive(Clone)]
+pub struct LazyFheBool {
+    pub(super) handle: Handle,
+}
+
+impl LazyFheBool {
+    /// Returns the value of this node, executing the circuit if needed
+    ///
+    /// # Panics
+    ///
+    /// Panics if the circuit has to be executed and the server key is not set,
+    /// or is not a CPU server key.
+    pub fn eval(&self) -> FheBool {
+        match self.handle.value() {
+            Value::Boolean(block) => FheBool::new(block),
+            Value::Radix(_) => unreachable!("LazyFheBool always points to a boolean node"),
+        }
+    }
+
+    /// Records the selection of `ct_then` if self is true, `ct_else` otherwise
+    pub fn if_then_else<Id: FheUintId>(
+        &self,
+        ct_then: &LazyFheUint<Id>,
+        ct_else: &LazyFheUint<Id>,
+    ) -> LazyFheUint<Id> {
+        LazyFheUint::new(self.handle.record(
+            Operation::IfThenElse(
+                self.handle.node(),
+                ct_then.handle.node(),
+                ct_else.handle.node(),
+            ),
+            &[&ct_then.handle, &ct_else.handle],
+        ))
+    }
+
+    pub fn select<Id: FheUintId>(
+        &self,
+        ct_when_true: &LazyFheUint<Id>,
+        ct_when_false: &LazyFheUint<Id>,
+    ) -> LazyFheUint<Id> {
+        self.if_then_else(ct_when_true, ct_when_false)
+    }
+}
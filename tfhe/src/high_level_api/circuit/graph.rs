@@ -0,0 +1,262 @@
+use crate::integer::block_decomposition::{BlockDecomposer, DecomposableInto};
+use crate::integer::{BooleanBlock, RadixCiphertext, U256};
+use std::collections::HashMap;
+
+pub(super) type NodeId = usize;
+
+/// A clear value used as the right-hand side of an operation
+///
+/// It is stored as a 256-bit little endian integer, which is enough to represent
+/// the clear values of all the unsigned types of the high level api.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(super) struct Scalar([u64; 4]);
+
+impl Scalar {
+    /// Creates a scalar from a clear value, keeping only its `num_bits` lowest bits
+    pub(super) fn new<Clear>(value: Clear, num_bits: usize) -> Self
+    where
+        Clear: DecomposableInto<u64>,
+    {
+        let mut words = [0u64; 4];
+        for (i, byte) in BlockDecomposer::new(value, u8::BITS)
+            .iter_as::<u64>()
+            .take(32)
+            .enumerate()
+        {
+            words[i / 8] |= byte << (8 * (i % 8));
+        }
+
+        for (i, word) in words.iter_mut().enumerate() {
+            let word_start = i * u64::BITS as usize;
+            if word_start >= num_bits {
+                *word = 0;
+            } else if num_bits - word_start < u64::BITS as usize {
+                *word &= (1u64 << (num_bits - word_start)) - 1;
+            }
+        }
+
+        Self(words)
+    }
+
+    pub(super) fn to_u256(self) -> U256 {
+        U256::from(self.0)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(super) enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Min,
+    Max,
+}
+
+impl BinaryOp {
+    fn is_commutative(self) -> bool {
+        !matches!(self, Self::Sub)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(super) enum UnaryOp {
+    Neg,
+    BitNot,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(super) enum ScalarOp {
+    Add,
+    Sub,
+    Mul,
+    BitAnd,
+    BitOr,
+    BitXor,
+    LeftShift,
+    RightShift,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(super) enum ComparisonOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(super) enum BooleanOp {
+    And,
+    Or,
+    Xor,
+}
+
+/// An operation recorded in a [Graph], the operands are the ids of previously recorded nodes
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(super) enum Operation {
+    /// An encrypted value given by the user, the payload is a unique input index so that
+    /// two inputs are never merged together
+    Input(usize),
+    Binary(BinaryOp, NodeId, NodeId),
+    Unary(UnaryOp, NodeId),
+    Scalar(ScalarOp, NodeId, Scalar),
+    Comparison(ComparisonOp, NodeId, NodeId),
+    Boolean(BooleanOp, NodeId, NodeId),
+    BooleanNot(NodeId),
+    IfThenElse(NodeId, NodeId, NodeId),
+}
+
+impl Operation {
+    /// Returns the operation in a canonical form so that equivalent operations
+    /// hash to the same value
+    fn canonicalize(self) -> Self {
+        match self {
+            Self::Binary(op, lhs, rhs) if op.is_commutative() && rhs < lhs => {
+                Self::Binary(op, rhs, lhs)
+            }
+            Self::Comparison(op @ (ComparisonOp::Eq | ComparisonOp::Ne), lhs, rhs) if rhs < lhs => {
+                Self::Comparison(op, rhs, lhs)
+            }
+            Self::Boolean(op, lhs, rhs) if rhs < lhs => Self::Boolean(op, rhs, lhs),
+            other => other,
+        }
+    }
+
+    pub(super) fn operands(&self) -> impl Iterator<Item = NodeId> {
+        let (operands, len) = match *self {
+            Self::Input(_) => ([0; 3], 0),
+            Self::Unary(_, a) | Self::Scalar(_, a, _) | Self::BooleanNot(a) => ([a, 0, 0], 1),
+            Self::Binary(_, a, b) | Self::Comparison(_, a, b) | Self::Boolean(_, a, b) => {
+                ([a, b, 0], 2)
+            }
+            Self::IfThenElse(a, b, c) => ([a, b, c], 3),
+        };
+        operands.into_iter().take(len)
+    }
+
+    /// Returns whether the operation is linear, that is, it can be computed
+    /// without any PBS if the operands have enough carry space
+    pub(super) fn is_linear(&self) -> bool {
+        matches!(
+            self,
+            Self::Binary(BinaryOp::Add | BinaryOp::Sub, _, _)
+                | Self::Unary(UnaryOp::Neg, _)
+                | Self::Scalar(ScalarOp::Add | ScalarOp::Sub, _, _)
+        )
+    }
+}
+
+/// The value held by a computed node
+#[derive(Clone)]
+pub(super) enum Value {
+    /// An integer which may have non-empty carries if it is the result of linear operations
+    Radix(RadixCiphertext),
+    Boolean(BooleanBlock),
+}
+
+impl Value {
+    pub(super) fn as_radix(&self) -> &RadixCiphertext {
+        match self {
+            Self::Radix(ct) => ct,
+            Self::Boolean(_) => panic!("Expected an integer value, found a boolean"),
+        }
+    }
+
+    pub(super) fn as_boolean(&self) -> &BooleanBlock {
+        match self {
+            Self::Boolean(block) => block,
+            Self::Radix(_) => panic!("Expected a boolean value, found an integer"),
+        }
+    }
+}
+
+pub(super) struct Node {
+    pub(super) operation: Operation,
+    /// Number of user handles pointing to this node, nodes with live handles
+    /// are the outputs of the circuit
+    pub(super) handle_count: usize,
+    /// The computed value, `None` if the node was not executed yet or if its value
+    /// was released because nothing needed it anymore
+    pub(super) value: Option<Value>,
+}
+
+/// The computation graph recorded by a [Circuit](super::Circuit)
+///
+/// Nodes are stored in creation order, which is a topological order as an operation
+/// can only refer to already existing nodes.
+#[derive(Default)]
+pub(super) struct Graph {
+    pub(super) nodes: Vec<Node>,
+    /// Maps an operation to the node computing it, used to deduplicate common subexpressions
+    dedup: HashMap<Operation, NodeId>,
+    input_count: usize,
+}
+
+impl Graph {
+    pub(super) fn push_input(&mut self, value: Value) -> NodeId {
+        let operation = Operation::Input(self.input_count);
+        self.input_count += 1;
+        self.nodes.push(Node {
+            operation,
+            handle_count: 1,
+            value: Some(value),
+        });
+        self.nodes.len() - 1
+    }
+
+    /// Records an operation, returning the id of the node computing it
+    ///
+    /// If an identical operation was already recorded, its node is returned instead
+    /// of creating a new one. The handle count of the returned node is incremented.
+    pub(super) fn push(&mut self, operation: Operation) -> NodeId {
+        let operation = operation.canonicalize();
+        let id = *self.dedup.entry(operation).or_insert_with(|| {
+            self.nodes.push(Node {
+                operation,
+                handle_count: 0,
+                value: None,
+            });
+            self.nodes.len() - 1
+        });
+        self.nodes[id].handle_count += 1;
+        id
+    }
+
+    pub(super) fn acquire(&mut self, id: NodeId) {
+        self.nodes[id].handle_count += 1;
+    }
+
+    pub(super) fn release(&mut self, id: NodeId) {
+        self.nodes[id].handle_count -= 1;
+    }
+
+    /// Returns, for each node, whether it has to be computed to get the value
+    /// of all the nodes having a live handle
+    ///
+    /// Nodes that are not reachable from a live handle are dead code and are not computed.
+    pub(super) fn nodes_to_compute(&self) -> Vec<bool> {
+        let mut needed = self
+            .nodes
+            .iter()
+            .map(|node| node.handle_count > 0 && node.value.is_none())
+            .collect::<Vec<_>>();
+
+        // Nodes are in topological order, so going backward visits
+        // all users of a node before the node itself
+        for id in (0..self.nodes.len()).rev() {
+            if !needed[id] {
+                continue;
+            }
+            for operand in self.nodes[id].operation.operands() {
+                if self.nodes[operand].value.is_none() {
+                    needed[operand] = true;
+                }
+            }
+        }
+
+        needed
+    }
+}
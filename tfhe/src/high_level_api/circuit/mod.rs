@@ -0,0 +1,20 @@
+//! Lazy evaluation of high level api programs.
+//!
+//! Operations on [FheUint](crate::FheUint) are executed eagerly, one after the other.
+//! A [Circuit] instead records operations on lazy handles into a graph, which is only
+//! executed when a result is needed. This allows to:
+//!
+//! - skip operations whose results are never used,
+//! - compute common subexpressions only once,
+//! - defer carry propagations across chains of additions and subtractions,
+//! - compute independent operations in parallel.
+//!
+//! See [Circuit] for an example.
+pub use base::{Circuit, LazyFheBool, LazyFheUint};
+
+mod base;
+mod graph;
+mod ops;
+mod scheduler;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,237 @@
+use super::base::{LazyFheBool, LazyFheUint};
+use super::graph::{BinaryOp, BooleanOp, Operation, Scalar, ScalarOp, UnaryOp};
+use crate::high_level_api::integers::FheUintId;
+use crate::high_level_api::traits::{FheMax, FheMin};
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::U256;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Mul, Neg, Not, Shl, Shr, Sub};
+
+fn record_binary<Id: FheUintId>(
+    op: BinaryOp,
+    lhs: &LazyFheUint<Id>,
+    rhs: &LazyFheUint<Id>,
+) -> LazyFheUint<Id> {
+    LazyFheUint::new(lhs.handle.record(
+        Operation::Binary(op, lhs.handle.node(), rhs.handle.node()),
+        &[&rhs.handle],
+    ))
+}
+
+fn record_scalar<Id: FheUintId>(
+    op: ScalarOp,
+    lhs: &LazyFheUint<Id>,
+    rhs: Scalar,
+) -> LazyFheUint<Id> {
+    LazyFheUint::new(
+        lhs.handle
+            .record(Operation::Scalar(op, lhs.handle.node(), rhs), &[]),
+    )
+}
+
+fn record_unary<Id: FheUintId>(op: UnaryOp, value: &LazyFheUint<Id>) -> LazyFheUint<Id> {
+    LazyFheUint::new(
+        value
+            .handle
+            .record(Operation::Unary(op, value.handle.node()), &[]),
+    )
+}
+
+fn record_boolean(op: BooleanOp, lhs: &LazyFheBool, rhs: &LazyFheBool) -> LazyFheBool {
+    LazyFheBool {
+        handle: lhs.handle.record(
+            Operation::Boolean(op, lhs.handle.node(), rhs.handle.node()),
+            &[&rhs.handle],
+        ),
+    }
+}
+
+macro_rules! impl_lazy_binary_op {
+    ($rust_trait:ident($rust_trait_method:ident) => $op:expr) => {
+        impl<Id: FheUintId> $rust_trait<&LazyFheUint<Id>> for &LazyFheUint<Id> {
+            type Output = LazyFheUint<Id>;
+
+            fn $rust_trait_method(self, rhs: &LazyFheUint<Id>) -> Self::Output {
+                record_binary($op, self, rhs)
+            }
+        }
+
+        impl<Id: FheUintId> $rust_trait<LazyFheUint<Id>> for &LazyFheUint<Id> {
+            type Output = LazyFheUint<Id>;
+
+            fn $rust_trait_method(self, rhs: LazyFheUint<Id>) -> Self::Output {
+                record_binary($op, self, &rhs)
+            }
+        }
+
+        impl<Id: FheUintId> $rust_trait<&LazyFheUint<Id>> for LazyFheUint<Id> {
+            type Output = LazyFheUint<Id>;
+
+            fn $rust_trait_method(self, rhs: &LazyFheUint<Id>) -> Self::Output {
+                record_binary($op, &self, rhs)
+            }
+        }
+
+        impl<Id: FheUintId> $rust_trait<LazyFheUint<Id>> for LazyFheUint<Id> {
+            type Output = LazyFheUint<Id>;
+
+            fn $rust_trait_method(self, rhs: LazyFheUint<Id>) -> Self::Output {
+                record_binary($op, &self, &rhs)
+            }
+        }
+    };
+}
+
+impl_lazy_binary_op!(Add(add) => BinaryOp::Add);
+impl_lazy_binary_op!(Sub(sub) => BinaryOp::Sub);
+impl_lazy_binary_op!(Mul(mul) => BinaryOp::Mul);
+impl_lazy_binary_op!(BitAnd(bitand) => BinaryOp::BitAnd);
+impl_lazy_binary_op!(BitOr(bitor) => BinaryOp::BitOr);
+impl_lazy_binary_op!(BitXor(bitxor) => BinaryOp::BitXor);
+
+macro_rules! impl_lazy_scalar_op {
+    ($rust_trait:ident($rust_trait_method:ident) => $op:expr, $num_bits:expr) => {
+        impl<Id, Clear> $rust_trait<Clear> for &LazyFheUint<Id>
+        where
+            Id: FheUintId,
+            Clear: DecomposableInto<u64>,
+        {
+            type Output = LazyFheUint<Id>;
+
+            fn $rust_trait_method(self, rhs: Clear) -> Self::Output {
+                record_scalar($op, self, Scalar::new(rhs, $num_bits))
+            }
+        }
+
+        impl<Id, Clear> $rust_trait<Clear> for LazyFheUint<Id>
+        where
+            Id: FheUintId,
+            Clear: DecomposableInto<u64>,
+        {
+            type Output = LazyFheUint<Id>;
+
+            fn $rust_trait_method(self, rhs: Clear) -> Self::Output {
+                record_scalar($op, &self, Scalar::new(rhs, $num_bits))
+            }
+        }
+    };
+}
+
+// Arithmetic and bitwise scalars are reduced modulo 2^num_bits, so that
+// e.g. `a + 1` and `a + 257` are deduplicated for 8 bits integers
+impl_lazy_scalar_op!(Add(add) => ScalarOp::Add, Id::num_bits());
+impl_lazy_scalar_op!(Sub(sub) => ScalarOp::Sub, Id::num_bits());
+impl_lazy_scalar_op!(Mul(mul) => ScalarOp::Mul, Id::num_bits());
+impl_lazy_scalar_op!(BitAnd(bitand) => ScalarOp::BitAnd, Id::num_bits());
+impl_lazy_scalar_op!(BitOr(bitor) => ScalarOp::BitOr, Id::num_bits());
+impl_lazy_scalar_op!(BitXor(bitxor) => ScalarOp::BitXor, Id::num_bits());
+// Shift amounts are kept as is, the server key takes care of reducing them
+impl_lazy_scalar_op!(Shl(shl) => ScalarOp::LeftShift, U256::BITS as usize);
+impl_lazy_scalar_op!(Shr(shr) => ScalarOp::RightShift, U256::BITS as usize);
+
+impl<Id: FheUintId> Neg for &LazyFheUint<Id> {
+    type Output = LazyFheUint<Id>;
+
+    fn neg(self) -> Self::Output {
+        record_unary(UnaryOp::Neg, self)
+    }
+}
+
+impl<Id: FheUintId> Neg for LazyFheUint<Id> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        record_unary(UnaryOp::Neg, &self)
+    }
+}
+
+impl<Id: FheUintId> Not for &LazyFheUint<Id> {
+    type Output = LazyFheUint<Id>;
+
+    fn not(self) -> Self::Output {
+        record_unary(UnaryOp::BitNot, self)
+    }
+}
+
+impl<Id: FheUintId> Not for LazyFheUint<Id> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        record_unary(UnaryOp::BitNot, &self)
+    }
+}
+
+impl<Id: FheUintId> FheMin<&Self> for LazyFheUint<Id> {
+    type Output = Self;
+
+    fn min(&self, rhs: &Self) -> Self::Output {
+        record_binary(BinaryOp::Min, self, rhs)
+    }
+}
+
+impl<Id: FheUintId> FheMax<&Self> for LazyFheUint<Id> {
+    type Output = Self;
+
+    fn max(&self, rhs: &Self) -> Self::Output {
+        record_binary(BinaryOp::Max, self, rhs)
+    }
+}
+
+macro_rules! impl_lazy_boolean_op {
+    ($rust_trait:ident($rust_trait_method:ident) => $op:expr) => {
+        impl $rust_trait<&LazyFheBool> for &LazyFheBool {
+            type Output = LazyFheBool;
+
+            fn $rust_trait_method(self, rhs: &LazyFheBool) -> Self::Output {
+                record_boolean($op, self, rhs)
+            }
+        }
+
+        impl $rust_trait<LazyFheBool> for &LazyFheBool {
+            type Output = LazyFheBool;
+
+            fn $rust_trait_method(self, rhs: LazyFheBool) -> Self::Output {
+                record_boolean($op, self, &rhs)
+            }
+        }
+
+        impl $rust_trait<&LazyFheBool> for LazyFheBool {
+            type Output = LazyFheBool;
+
+            fn $rust_trait_method(self, rhs: &LazyFheBool) -> Self::Output {
+                record_boolean($op, &self, rhs)
+            }
+        }
+
+        impl $rust_trait<LazyFheBool> for LazyFheBool {
+            type Output = LazyFheBool;
+
+            fn $rust_trait_method(self, rhs: LazyFheBool) -> Self::Output {
+                record_boolean($op, &self, &rhs)
+            }
+        }
+    };
+}
+
+impl_lazy_boolean_op!(BitAnd(bitand) => BooleanOp::And);
+impl_lazy_boolean_op!(BitOr(bitor) => BooleanOp::Or);
+impl_lazy_boolean_op!(BitXor(bitxor) => BooleanOp::Xor);
+
+impl Not for &LazyFheBool {
+    type Output = LazyFheBool;
+
+    fn not(self) -> Self::Output {
+        LazyFheBool {
+            handle: self
+                .handle
+                .record(Operation::BooleanNot(self.handle.node()), &[]),
+        }
+    }
+}
+
+impl Not for LazyFheBool {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        !&self
+    }
+}
@@ -0,0 +1,268 @@
+use super::graph::{
+    BinaryOp, BooleanOp, ComparisonOp, Graph, NodeId, Operation, ScalarOp, UnaryOp, Value,
+};
+use crate::integer::prelude::*;
+use crate::integer::{RadixCiphertext, ServerKey};
+use rayon::prelude::*;
+
+impl Graph {
+    /// Computes all the nodes needed to get the value of the nodes having a live handle
+    ///
+    /// Execution goes in waves: each wave computes, in parallel, all the nodes whose
+    /// operands are available. Before a wave, the carries of the operands that need it
+    /// are propagated, also in parallel, so that all the PBS of a wave are batched.
+    ///
+    /// Linear operations (additions, subtractions, negations) are computed without
+    /// propagating carries as long as the operands have enough room, the propagation
+    /// is then deferred to the first non-linear user or to the output.
+    pub(super) fn execute(&mut self, server_key: &ServerKey) {
+        let mut pending = self.nodes_to_compute();
+
+        // Number of pending users of each node, used to release intermediate values as soon
+        // as they are not needed anymore
+        let mut remaining_uses = vec![0usize; self.nodes.len()];
+        for (id, node) in self.nodes.iter().enumerate() {
+            if pending[id] {
+                for operand in node.operation.operands() {
+                    remaining_uses[operand] += 1;
+                }
+            }
+        }
+
+        loop {
+            let ready = (0..self.nodes.len())
+                .filter(|&id| {
+                    pending[id]
+                        && self.nodes[id]
+                            .operation
+                            .operands()
+                            .all(|operand| self.nodes[operand].value.is_some())
+                })
+                .collect::<Vec<_>>();
+
+            if ready.is_empty() {
+                break;
+            }
+
+            let mut to_propagate = ready
+                .iter()
+                .flat_map(|&id| self.operands_to_propagate(id, server_key))
+                .collect::<Vec<_>>();
+            to_propagate.sort_unstable();
+            to_propagate.dedup();
+            self.propagate_carries(&to_propagate, server_key);
+
+            let results = ready
+                .par_iter()
+                .map(|&id| self.compute(id, server_key))
+                .collect::<Vec<_>>();
+
+            for (id, value) in ready.into_iter().zip(results) {
+                self.nodes[id].value = Some(value);
+                pending[id] = false;
+
+                for operand in self.nodes[id].operation.operands() {
+                    remaining_uses[operand] -= 1;
+                    if remaining_uses[operand] == 0 && self.nodes[operand].handle_count == 0 {
+                        self.nodes[operand].value = None;
+                    }
+                }
+            }
+        }
+
+        assert!(
+            pending.iter().all(|is_pending| !is_pending),
+            "Some nodes of the circuit could not be computed as their operands were released"
+        );
+
+        // The values of the outputs are given back to the user, which expects clean carries
+        let outputs = (0..self.nodes.len())
+            .filter(|&id| {
+                self.nodes[id].handle_count > 0
+                    && matches!(
+                        &self.nodes[id].value,
+                        Some(Value::Radix(ct)) if !ct.block_carries_are_empty()
+                    )
+            })
+            .collect::<Vec<_>>();
+        self.propagate_carries(&outputs, server_key);
+
+        for node in self.nodes.iter_mut() {
+            if node.handle_count == 0 {
+                node.value = None;
+            }
+        }
+    }
+
+    fn radix_value(&self, id: NodeId) -> &RadixCiphertext {
+        self.nodes[id]
+            .value
+            .as_ref()
+            .expect("Operand value is not available")
+            .as_radix()
+    }
+
+    /// Returns the operands of the node which must have their carries propagated
+    /// before the node is computed
+    pub(super) fn operands_to_propagate(&self, id: NodeId, server_key: &ServerKey) -> Vec<NodeId> {
+        let operation = self.nodes[id].operation;
+        if operation.is_linear() && self.is_linear_op_possible(operation, server_key) {
+            return vec![];
+        }
+
+        operation
+            .operands()
+            .filter(|&operand| {
+                matches!(
+                    &self.nodes[operand].value,
+                    Some(Value::Radix(ct)) if !ct.block_carries_are_empty()
+                )
+            })
+            .collect()
+    }
+
+    /// Returns whether the linear operation can be computed without propagating the carries
+    /// of its operands
+    fn is_linear_op_possible(&self, operation: Operation, server_key: &ServerKey) -> bool {
+        let result = match operation {
+            Operation::Binary(BinaryOp::Add, lhs, rhs) => {
+                server_key.is_add_possible(self.radix_value(lhs), self.radix_value(rhs))
+            }
+            Operation::Binary(BinaryOp::Sub, lhs, rhs) => {
+                server_key.is_sub_possible(self.radix_value(lhs), self.radix_value(rhs))
+            }
+            Operation::Unary(UnaryOp::Neg, ct) => server_key.is_neg_possible(self.radix_value(ct)),
+            Operation::Scalar(ScalarOp::Add, ct, scalar) => {
+                server_key.is_scalar_add_possible(self.radix_value(ct), scalar.to_u256())
+            }
+            Operation::Scalar(ScalarOp::Sub, ct, scalar) => {
+                server_key.is_scalar_sub_possible(self.radix_value(ct), scalar.to_u256())
+            }
+            _ => return false,
+        };
+
+        result.is_ok()
+    }
+
+    fn propagate_carries(&mut self, ids: &[NodeId], server_key: &ServerKey) {
+        if ids.is_empty() {
+            return;
+        }
+
+        let mut values = ids
+            .iter()
+            .map(|&id| self.nodes[id].value.take())
+            .collect::<Vec<_>>();
+
+        values.par_iter_mut().for_each(|value| {
+            if let Some(Value::Radix(ct)) = value {
+                server_key.full_propagate_parallelized(ct);
+            }
+        });
+
+        for (&id, value) in ids.iter().zip(values) {
+            self.nodes[id].value = value;
+        }
+    }
+
+    pub(super) fn compute(&self, id: NodeId, server_key: &ServerKey) -> Value {
+        let radix = |id| self.radix_value(id);
+        let boolean = |id: NodeId| {
+            self.nodes[id]
+                .value
+                .as_ref()
+                .expect("Operand value is not available")
+                .as_boolean()
+        };
+
+        let operation = self.nodes[id].operation;
+        if operation.is_linear() && self.is_linear_op_possible(operation, server_key) {
+            let result = match operation {
+                Operation::Binary(BinaryOp::Add, lhs, rhs) => {
+                    server_key.unchecked_add(radix(lhs), radix(rhs))
+                }
+                Operation::Binary(BinaryOp::Sub, lhs, rhs) => {
+                    server_key.unchecked_sub(radix(lhs), radix(rhs))
+                }
+                Operation::Unary(UnaryOp::Neg, ct) => server_key.unchecked_neg(radix(ct)),
+                Operation::Scalar(ScalarOp::Add, ct, scalar) => {
+                    server_key.unchecked_scalar_add(radix(ct), scalar.to_u256())
+                }
+                Operation::Scalar(ScalarOp::Sub, ct, scalar) => {
+                    server_key.unchecked_scalar_sub(radix(ct), scalar.to_u256())
+                }
+                _ => unreachable!("{operation:?} is not a linear operation"),
+            };
+            return Value::Radix(result);
+        }
+
+        match operation {
+            Operation::Input(_) => unreachable!("Inputs always have a value"),
+            Operation::Binary(op, lhs, rhs) => {
+                let (lhs, rhs) = (radix(lhs), radix(rhs));
+                let result = match op {
+                    BinaryOp::Add => server_key.add_parallelized(lhs, rhs),
+                    BinaryOp::Sub => server_key.sub_parallelized(lhs, rhs),
+                    BinaryOp::Mul => server_key.mul_parallelized(lhs, rhs),
+                    BinaryOp::BitAnd => server_key.bitand_parallelized(lhs, rhs),
+                    BinaryOp::BitOr => server_key.bitor_parallelized(lhs, rhs),
+                    BinaryOp::BitXor => server_key.bitxor_parallelized(lhs, rhs),
+                    BinaryOp::Min => server_key.min_parallelized(lhs, rhs),
+                    BinaryOp::Max => server_key.max_parallelized(lhs, rhs),
+                };
+                Value::Radix(result)
+            }
+            Operation::Unary(op, ct) => {
+                let ct = radix(ct);
+                let result = match op {
+                    UnaryOp::Neg => server_key.neg_parallelized(ct),
+                    UnaryOp::BitNot => server_key.bitnot(ct),
+                };
+                Value::Radix(result)
+            }
+            Operation::Scalar(op, ct, scalar) => {
+                let (ct, scalar) = (radix(ct), scalar.to_u256());
+                let result = match op {
+                    ScalarOp::Add => server_key.scalar_add_parallelized(ct, scalar),
+                    ScalarOp::Sub => server_key.scalar_sub_parallelized(ct, scalar),
+                    ScalarOp::Mul => server_key.scalar_mul_parallelized(ct, scalar),
+                    ScalarOp::BitAnd => server_key.scalar_bitand_parallelized(ct, scalar),
+                    ScalarOp::BitOr => server_key.scalar_bitor_parallelized(ct, scalar),
+                    ScalarOp::BitXor => server_key.scalar_bitxor_parallelized(ct, scalar),
+                    ScalarOp::LeftShift => server_key.scalar_left_shift_parallelized(ct, scalar),
+                    ScalarOp::RightShift => server_key.scalar_right_shift_parallelized(ct, scalar),
+                };
+                Value::Radix(result)
+            }
+            Operation::Comparison(op, lhs, rhs) => {
+                let (lhs, rhs) = (radix(lhs), radix(rhs));
+                let result = match op {
+                    ComparisonOp::Eq => server_key.eq_parallelized(lhs, rhs),
+                    ComparisonOp::Ne => server_key.ne_parallelized(lhs, rhs),
+                    ComparisonOp::Lt => server_key.lt_parallelized(lhs, rhs),
+                    ComparisonOp::Le => server_key.le_parallelized(lhs, rhs),
+                };
+                Value::Boolean(result)
+            }
+            Operation::Boolean(op, lhs, rhs) => {
+                let (lhs, rhs) = (boolean(lhs), boolean(rhs));
+                let result = match op {
+                    BooleanOp::And => server_key.boolean_bitand(lhs, rhs),
+                    BooleanOp::Or => server_key.boolean_bitor(lhs, rhs),
+                    BooleanOp::Xor => server_key.boolean_bitxor(lhs, rhs),
+                };
+                Value::Boolean(result)
+            }
+            Operation::BooleanNot(block) => {
+                Value::Boolean(server_key.boolean_bitnot(boolean(block)))
+            }
+            Operation::IfThenElse(condition, ct_then, ct_else) => {
+                Value::Radix(server_key.if_then_else_parallelized(
+                    boolean(condition),
+                    radix(ct_then),
+                    radix(ct_else),
+                ))
+            }
+        }
+    }
+}
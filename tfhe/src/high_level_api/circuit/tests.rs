@@ -0,0 +1,218 @@
+use super::graph::{BinaryOp, Graph, Operation, Value};
+use super::Circuit;
+use crate::high_level_api::global_state::with_cpu_internal_keys;
+use crate::high_level_api::prelude::*;
+use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder};
+use crate::{ClientKey, FheBool, FheUint8};
+
+fn setup_default_cpu() -> ClientKey {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    client_key
+}
+
+#[test]
+fn test_circuit_ops() {
+    let client_key = setup_default_cpu();
+
+    let clear_a = 213u8;
+    let clear_b = 94u8;
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint8::encrypt(clear_b, &client_key);
+    let c = FheBool::encrypt(true, &client_key);
+
+    let circuit = Circuit::new();
+    let lazy_a = circuit.input(&a);
+    let lazy_b = circuit.input(&b);
+    let lazy_c = circuit.input_bool(&c);
+
+    let integer_results = [
+        (&lazy_a + &lazy_b, clear_a.wrapping_add(clear_b)),
+        (&lazy_a - &lazy_b, clear_a.wrapping_sub(clear_b)),
+        (&lazy_a * &lazy_b, clear_a.wrapping_mul(clear_b)),
+        (&lazy_a & &lazy_b, clear_a & clear_b),
+        (&lazy_a | &lazy_b, clear_a | clear_b),
+        (&lazy_a ^ &lazy_b, clear_a ^ clear_b),
+        (lazy_a.min(&lazy_b), clear_a.min(clear_b)),
+        (lazy_a.max(&lazy_b), clear_a.max(clear_b)),
+        (-&lazy_a, clear_a.wrapping_neg()),
+        (!&lazy_a, !clear_a),
+        (&lazy_a + 200u8, clear_a.wrapping_add(200)),
+        (&lazy_a - 250u8, clear_a.wrapping_sub(250)),
+        (&lazy_a * 7u8, clear_a.wrapping_mul(7)),
+        (&lazy_a & 0x0Fu8, clear_a & 0x0F),
+        (&lazy_a | 0x0Fu8, clear_a | 0x0F),
+        (&lazy_a ^ 0x0Fu8, clear_a ^ 0x0F),
+        (&lazy_a << 3u8, clear_a << 3),
+        (&lazy_a >> 3u8, clear_a >> 3),
+        (lazy_c.if_then_else(&lazy_a, &lazy_b), clear_a),
+        ((!&lazy_c).if_then_else(&lazy_a, &lazy_b), clear_b),
+    ];
+
+    let boolean_results = [
+        (lazy_a.eq(&lazy_b), clear_a == clear_b),
+        (lazy_a.ne(&lazy_b), clear_a != clear_b),
+        (lazy_a.lt(&lazy_b), clear_a < clear_b),
+        (lazy_a.le(&lazy_b), clear_a <= clear_b),
+        (lazy_a.gt(&lazy_b), clear_a > clear_b),
+        (lazy_a.ge(&lazy_b), clear_a >= clear_b),
+        (lazy_a.gt(&lazy_b) & &lazy_c, clear_a > clear_b),
+        (lazy_a.lt(&lazy_b) | &lazy_c, true),
+        (lazy_a.gt(&lazy_b) ^ &lazy_c, clear_a <= clear_b),
+    ];
+
+    circuit.execute();
+
+    for (i, (lazy, expected)) in integer_results.iter().enumerate() {
+        let decrypted: u8 = lazy.eval().decrypt(&client_key);
+        assert_eq!(
+            decrypted, *expected,
+            "Invalid result for integer operation {i}"
+        );
+    }
+
+    for (i, (lazy, expected)) in boolean_results.iter().enumerate() {
+        let decrypted = lazy.eval().decrypt(&client_key);
+        assert_eq!(
+            decrypted, *expected,
+            "Invalid result for boolean operation {i}"
+        );
+    }
+}
+
+#[test]
+fn test_circuit_common_subexpressions_and_dead_code() {
+    let client_key = setup_default_cpu();
+
+    let a = FheUint8::encrypt(12u8, &client_key);
+    let b = FheUint8::encrypt(30u8, &client_key);
+
+    let circuit = Circuit::new();
+    let lazy_a = circuit.input(&a);
+    let lazy_b = circuit.input(&b);
+
+    let sum = &lazy_a + &lazy_b;
+    let same_sum = &lazy_b + &lazy_a;
+    assert_eq!(sum.handle.node(), same_sum.handle.node());
+    assert_eq!(
+        lazy_a.gt(&lazy_b).handle.node(),
+        lazy_b.lt(&lazy_a).handle.node()
+    );
+    assert_ne!(
+        (&lazy_a - &lazy_b).handle.node(),
+        (&lazy_b - &lazy_a).handle.node()
+    );
+
+    let product = &sum * &same_sum;
+    let dead = &product * &lazy_a;
+    let dead_node = dead.handle.node();
+    drop(dead);
+
+    let result: u8 = product.eval().decrypt(&client_key);
+    assert_eq!(result, 42u8.wrapping_mul(42));
+
+    {
+        let graph = product.handle.graph().borrow();
+        // Nodes without handles are never kept
+        assert!(graph.nodes[dead_node].value.is_none());
+        assert!(graph.nodes[sum.handle.node()].value.is_some());
+    }
+
+    // Recording again an executed operation reuses its value
+    let same_product = &same_sum * &sum;
+    assert_eq!(same_product.handle.node(), product.handle.node());
+    assert!(
+        product.handle.graph().borrow().nodes[same_product.handle.node()]
+            .value
+            .is_some()
+    );
+
+    // Recording a previously dead operation computes it
+    let dead = &product * &lazy_a;
+    assert_eq!(dead.handle.node(), dead_node);
+    let result: u8 = dead.eval().decrypt(&client_key);
+    assert_eq!(result, 42u8.wrapping_mul(42).wrapping_mul(12));
+}
+
+#[test]
+fn test_circuit_deferred_carry_propagation() {
+    let client_key = setup_default_cpu();
+
+    let clears = [200u8, 100, 57, 3];
+    let values = clears
+        .iter()
+        .map(|clear| FheUint8::encrypt(*clear, &client_key))
+        .collect::<Vec<_>>();
+
+    with_cpu_internal_keys(|cpu_key| {
+        let server_key = cpu_key.pbs_key();
+
+        let mut graph = Graph::default();
+        let inputs = values
+            .iter()
+            .map(|value| graph.push_input(Value::Radix(value.ciphertext.on_cpu().to_owned())))
+            .collect::<Vec<_>>();
+
+        let first_sum = graph.push(Operation::Binary(BinaryOp::Add, inputs[0], inputs[1]));
+        assert!(graph
+            .operands_to_propagate(first_sum, server_key)
+            .is_empty());
+        graph.nodes[first_sum].value = Some(graph.compute(first_sum, server_key));
+        assert!(!graph.nodes[first_sum]
+            .value
+            .as_ref()
+            .unwrap()
+            .as_radix()
+            .block_carries_are_empty());
+
+        // Additions are chained without propagating the carries
+        let second_sum = graph.push(Operation::Binary(BinaryOp::Add, first_sum, inputs[2]));
+        assert!(graph
+            .operands_to_propagate(second_sum, server_key)
+            .is_empty());
+        graph.nodes[second_sum].value = Some(graph.compute(second_sum, server_key));
+
+        // Non linear operations need propagated inputs
+        let product = graph.push(Operation::Binary(BinaryOp::Mul, second_sum, inputs[3]));
+        assert_eq!(
+            graph.operands_to_propagate(product, server_key),
+            vec![second_sum]
+        );
+    });
+
+    let circuit = Circuit::new();
+    let lazy_values = values
+        .iter()
+        .map(|value| circuit.input(value))
+        .collect::<Vec<_>>();
+    let mut sum = lazy_values[0].clone();
+    for _ in 0..4 {
+        for lazy_value in lazy_values.iter() {
+            sum = &sum + lazy_value;
+        }
+    }
+    let result = (&sum * &lazy_values[3]).eval();
+    assert!(result.ciphertext.on_cpu().block_carries_are_empty());
+
+    let expected_sum = clears.iter().fold(clears[0], |acc, clear| {
+        acc.wrapping_add(clear.wrapping_mul(4))
+    });
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted, expected_sum.wrapping_mul(clears[3]));
+
+    let decrypted: u8 = sum.eval().decrypt(&client_key);
+    assert_eq!(decrypted, expected_sum);
+}
+
+#[test]
+#[should_panic(expected = "Operands belong to different circuits")]
+fn test_circuit_mixing_circuits() {
+    let (client_key, _) = generate_keys(ConfigBuilder::default());
+
+    let a = FheUint8::encrypt(1u8, &client_key);
+    let lhs = Circuit::new().input(&a);
+    let rhs = Circuit::new().input(&a);
+
+    let _ = &lhs + &rhs;
+}
@@ -2,20 +2,24 @@ use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::commons::math::random::{Deserialize, Serialize};
 use crate::core_crypto::prelude::Numeric;
 use crate::high_level_api::global_state;
-use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::high_level_api::integers::crt::check_moduli;
+use crate::high_level_api::integers::{FheIntId, FheUintCrtId, FheUintId};
 use crate::high_level_api::keys::InternalServerKey;
-use crate::integer::ciphertext::{Compactable, DataKind, Expandable};
+use crate::integer::ciphertext::{crt_from_expanded_blocks, Compactable, DataKind, Expandable};
 use crate::integer::encryption::KnowsMessageModulus;
 use crate::integer::parameters::CompactCiphertextListConformanceParams;
 use crate::integer::BooleanBlock;
 use crate::named::Named;
+use crate::prelude::FheKeyswitch;
 use crate::shortint::{Ciphertext, MessageModulus};
 #[cfg(feature = "zk-pok-experimental")]
 use crate::zk::{CompactPkePublicParams, ZkComputeLoad};
-use crate::{CompactPublicKey, FheBool, FheInt, FheUint};
+use crate::{CompactPublicKey, FheBool, FheInt, FheUint, FheUintCrt, KeySwitchingKey, Seed};
 
 #[derive(Clone, Serialize, Deserialize)]
-pub struct CompactCiphertextList(crate::integer::ciphertext::CompactCiphertextList);
+pub struct CompactCiphertextList(
+    #[serde(with = "crate::versionable::nested")] crate::integer::ciphertext::CompactCiphertextList,
+);
 
 impl Named for CompactCiphertextList {
     const NAME: &'static str = "high_level_api::CompactCiphertextList";
@@ -52,6 +56,52 @@ impl CompactCiphertextList {
             })
         }
     }
+
+    /// Re-randomizes the list, so that it cannot be linked to the list it was copied from
+    ///
+    /// A fresh compact encryption of zeros, made with the compact public key, is added to the
+    /// list.
+    ///
+    /// The randomness of the encryptions of zero is derived from the seed, so the same seed must
+    /// never be used twice.
+    ///
+    /// Returns an error if the LWE dimension or the message and carry moduli of the list differ
+    /// from the ones of the compact public key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{
+    ///     generate_keys, set_server_key, CompactCiphertextList, CompactPublicKey, ConfigBuilder,
+    ///     FheUint8, Seed,
+    /// };
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    /// let public_key = CompactPublicKey::new(&client_key);
+    ///
+    /// let mut list = CompactCiphertextList::builder(&public_key)
+    ///     .push(17u8)
+    ///     .push(false)
+    ///     .build();
+    /// list.re_randomize(&public_key, Seed(0)).unwrap();
+    ///
+    /// let expander = list.expand().unwrap();
+    /// let a: FheUint8 = expander.get(0).unwrap().unwrap();
+    /// let decrypted: u8 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, 17);
+    /// ```
+    pub fn re_randomize(
+        &mut self,
+        compact_public_key: &CompactPublicKey,
+        seed: Seed,
+    ) -> crate::Result<()> {
+        compact_public_key
+            .key
+            .key
+            .re_randomize_compact_ciphertext_list(&mut self.0, seed)
+    }
 }
 impl ParameterSetConformant for CompactCiphertextList {
     type ParameterSet = CompactCiphertextListConformanceParams;
@@ -63,7 +113,10 @@ impl ParameterSetConformant for CompactCiphertextList {
 
 #[cfg(feature = "zk-pok-experimental")]
 #[derive(Clone, Serialize, Deserialize)]
-pub struct ProvenCompactCiphertextList(crate::integer::ciphertext::ProvenCompactCiphertextList);
+pub struct ProvenCompactCiphertextList(
+    #[serde(with = "crate::versionable::nested")]
+    crate::integer::ciphertext::ProvenCompactCiphertextList,
+);
 
 #[cfg(feature = "zk-pok-experimental")]
 impl Named for ProvenCompactCiphertextList {
@@ -146,6 +199,10 @@ impl<Id: FheUintId> Expandable for FheUint<Id> {
                 "Tried to expand a FheUint{} while a FheBool is stored in this slot",
                 Id::num_bits(),
             ))),
+            DataKind::Crt(_) => Err(crate::Error::new(format!(
+                "Tried to expand a FheUint{} while a FheUintCrt is stored in this slot",
+                Id::num_bits(),
+            ))),
         }
     }
 }
@@ -179,6 +236,10 @@ impl<Id: FheIntId> Expandable for FheInt<Id> {
                 "Tried to expand a FheUint{} while a FheBool is stored in this slot",
                 Id::num_bits(),
             ))),
+            DataKind::Crt(_) => Err(crate::Error::new(format!(
+                "Tried to expand a FheInt{} while a FheUintCrt is stored in this slot",
+                Id::num_bits(),
+            ))),
         }
     }
 }
@@ -199,12 +260,71 @@ impl Expandable for FheBool {
                 )))
             }
             DataKind::Boolean => Ok(Self::new(BooleanBlock::new_unchecked(blocks[0].clone()))),
+            DataKind::Crt(_) => Err(crate::Error::new(
+                "Tried to expand a FheBool while a FheUintCrt is stored in this slot".to_string(),
+            )),
         }
     }
 }
 
+impl<Id: FheUintCrtId> Expandable for FheUintCrt<Id> {
+    fn from_expanded_blocks(blocks: &[Ciphertext], kind: DataKind) -> crate::Result<Self> {
+        crt_from_expanded_blocks(blocks, kind, Id::MODULI).map(Self::new)
+    }
+}
+
+pub(in crate::high_level_api) fn data_kind_to_fhe_type(
+    kind: DataKind,
+    message_modulus: MessageModulus,
+) -> Option<crate::FheTypes> {
+    Some(match kind {
+        DataKind::Unsigned(n) => {
+            let num_bits_per_block = message_modulus.0.ilog2() as usize;
+            let num_bits = n * num_bits_per_block;
+            match num_bits {
+                2 => crate::FheTypes::Uint2,
+                4 => crate::FheTypes::Uint4,
+                6 => crate::FheTypes::Uint6,
+                8 => crate::FheTypes::Uint8,
+                10 => crate::FheTypes::Uint10,
+                12 => crate::FheTypes::Uint12,
+                14 => crate::FheTypes::Uint14,
+                16 => crate::FheTypes::Uint16,
+                32 => crate::FheTypes::Uint32,
+                64 => crate::FheTypes::Uint64,
+                128 => crate::FheTypes::Uint128,
+                160 => crate::FheTypes::Uint160,
+                256 => crate::FheTypes::Uint256,
+                _ => return None,
+            }
+        }
+        DataKind::Signed(n) => {
+            let num_bits_per_block = message_modulus.0.ilog2() as usize;
+            let num_bits = n * num_bits_per_block;
+            match num_bits {
+                2 => crate::FheTypes::Int2,
+                4 => crate::FheTypes::Int4,
+                6 => crate::FheTypes::Int6,
+                8 => crate::FheTypes::Int8,
+                10 => crate::FheTypes::Int10,
+                12 => crate::FheTypes::Int12,
+                14 => crate::FheTypes::Int14,
+                16 => crate::FheTypes::Int16,
+                32 => crate::FheTypes::Int32,
+                64 => crate::FheTypes::Int64,
+                128 => crate::FheTypes::Int128,
+                160 => crate::FheTypes::Int160,
+                256 => crate::FheTypes::Int256,
+                _ => return None,
+            }
+        }
+        DataKind::Boolean => crate::FheTypes::Bool,
+        DataKind::Crt(_) => return None,
+    })
+}
+
 pub struct CompactCiphertextListExpander {
-    inner: crate::integer::ciphertext::CompactCiphertextListExpander,
+    pub(in crate::high_level_api) inner: crate::integer::ciphertext::CompactCiphertextListExpander,
 }
 
 impl CompactCiphertextListExpander {
@@ -217,49 +337,7 @@ impl CompactCiphertextListExpander {
     }
 
     pub fn get_kind_of(&self, index: usize) -> Option<crate::FheTypes> {
-        Some(match self.inner.get_kind_of(index)? {
-            DataKind::Unsigned(n) => {
-                let num_bits_per_block = self.inner.message_modulus().0.ilog2() as usize;
-                let num_bits = n * num_bits_per_block;
-                match num_bits {
-                    2 => crate::FheTypes::Uint2,
-                    4 => crate::FheTypes::Uint4,
-                    6 => crate::FheTypes::Uint6,
-                    8 => crate::FheTypes::Uint8,
-                    10 => crate::FheTypes::Uint10,
-                    12 => crate::FheTypes::Uint12,
-                    14 => crate::FheTypes::Uint14,
-                    16 => crate::FheTypes::Uint16,
-                    32 => crate::FheTypes::Uint32,
-                    64 => crate::FheTypes::Uint64,
-                    128 => crate::FheTypes::Uint128,
-                    160 => crate::FheTypes::Uint160,
-                    256 => crate::FheTypes::Uint256,
-                    _ => return None,
-                }
-            }
-            DataKind::Signed(n) => {
-                let num_bits_per_block = self.inner.message_modulus().0.ilog2() as usize;
-                let num_bits = n * num_bits_per_block;
-                match num_bits {
-                    2 => crate::FheTypes::Int2,
-                    4 => crate::FheTypes::Int4,
-                    6 => crate::FheTypes::Int6,
-                    8 => crate::FheTypes::Int8,
-                    10 => crate::FheTypes::Int10,
-                    12 => crate::FheTypes::Int12,
-                    14 => crate::FheTypes::Int14,
-                    16 => crate::FheTypes::Int16,
-                    32 => crate::FheTypes::Int32,
-                    64 => crate::FheTypes::Int64,
-                    128 => crate::FheTypes::Int128,
-                    160 => crate::FheTypes::Int160,
-                    256 => crate::FheTypes::Int256,
-                    _ => return None,
-                }
-            }
-            DataKind::Boolean => crate::FheTypes::Bool,
-        })
+        data_kind_to_fhe_type(self.inner.get_kind_of(index)?, self.inner.message_modulus())
     }
 
     pub fn get<T>(&self, index: usize) -> Option<crate::Result<T>>
@@ -270,6 +348,54 @@ impl CompactCiphertextListExpander {
     }
 }
 
+impl FheKeyswitch<CompactCiphertextListExpander> for KeySwitchingKey {
+    /// Key switches all the ciphertexts of an expanded compact list
+    ///
+    /// The integers are re-encoded if the message moduli of the two key sets are different, so
+    /// their number of blocks may change.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_1_CARRY_1_KS_PBS;
+    /// use tfhe::{
+    ///     generate_keys, CompactCiphertextList, CompactPublicKey, ConfigBuilder, FheBool,
+    ///     FheInt8, KeySwitchingKey,
+    /// };
+    ///
+    /// let (client_key_1, server_key_1) = generate_keys(ConfigBuilder::default());
+    /// let (client_key_2, server_key_2) = generate_keys(
+    ///     ConfigBuilder::default().use_custom_parameters(PARAM_MESSAGE_1_CARRY_1_KS_PBS, None),
+    /// );
+    /// let ksk = KeySwitchingKey::new(
+    ///     (&client_key_1, &server_key_1),
+    ///     (&client_key_2, &server_key_2),
+    /// )
+    /// .unwrap();
+    ///
+    /// let public_key = CompactPublicKey::new(&client_key_1);
+    /// let list = CompactCiphertextList::builder(&public_key)
+    ///     .push(-113i8)
+    ///     .push(true)
+    ///     .build();
+    ///
+    /// let expander = list.expand_with_key(&server_key_1).unwrap();
+    /// let expander = ksk.keyswitch(&expander);
+    ///
+    /// let a: FheInt8 = expander.get(0).unwrap().unwrap();
+    /// let decrypted: i8 = a.decrypt(&client_key_2);
+    /// assert_eq!(decrypted, -113);
+    /// let b: FheBool = expander.get(1).unwrap().unwrap();
+    /// assert!(b.decrypt(&client_key_2));
+    /// ```
+    fn keyswitch(&self, input: &CompactCiphertextListExpander) -> CompactCiphertextListExpander {
+        CompactCiphertextListExpander {
+            inner: self.key.cast_expander(&input.inner),
+        }
+    }
+}
+
 fn num_bits_to_strict_num_blocks(
     num_bits: usize,
     message_modulus: MessageModulus,
@@ -333,6 +459,54 @@ impl CompactCiphertextListBuilder {
         Ok(self)
     }
 
+    /// Pushes a [FheUintCrt], the value is reduced modulo the product of the moduli
+    ///
+    /// Returns an error if the moduli cannot be used with the parameters of the key.
+    ///
+    /// A list holding CRT integers cannot be packed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{
+    ///     generate_keys, set_server_key, CompactCiphertextList, CompactPublicKey, ConfigBuilder,
+    ///     FheUint8, FheUintCrt, FheUintCrtId,
+    /// };
+    ///
+    /// #[derive(Copy, Clone, Default)]
+    /// struct Basis420;
+    ///
+    /// impl FheUintCrtId for Basis420 {
+    ///     const MODULI: &'static [u64] = &[3, 4, 5, 7];
+    /// }
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    /// let public_key = CompactPublicKey::new(&client_key);
+    ///
+    /// let list = CompactCiphertextList::builder(&public_key)
+    ///     .push(17u8)
+    ///     .push_crt::<Basis420>(313)
+    ///     .unwrap()
+    ///     .build();
+    ///
+    /// let expander = list.expand().unwrap();
+    /// let a: FheUint8 = expander.get(0).unwrap().unwrap();
+    /// let b: FheUintCrt<Basis420> = expander.get(1).unwrap().unwrap();
+    ///
+    /// let decrypted: u8 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, 17);
+    /// let decrypted: u64 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, 313);
+    /// ```
+    pub fn push_crt<Id: FheUintCrtId>(&mut self, value: u64) -> crate::Result<&mut Self> {
+        let params = self.inner.pk.key.parameters;
+        check_moduli::<Id>(params.message_modulus, params.carry_modulus)?;
+        self.inner.push_crt(value, Id::MODULI);
+        Ok(self)
+    }
+
     pub fn build(&self) -> CompactCiphertextList {
         CompactCiphertextList(self.inner.build())
     }
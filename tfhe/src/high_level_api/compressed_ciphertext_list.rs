@@ -0,0 +1,277 @@
+use crate::core_crypto::commons::math::random::{Deserialize, Serialize};
+use crate::high_level_api::compact_list::data_kind_to_fhe_type;
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::high_level_api::keys::InternalServerKey;
+use crate::integer::ciphertext::{Compressible, DataKind, Expandable};
+use crate::integer::BooleanBlock;
+use crate::named::Named;
+use crate::shortint::Ciphertext;
+use crate::{FheBool, FheInt, FheUint};
+
+impl<Id: FheUintId> Compressible for FheUint<Id> {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        self.ciphertext.into_cpu().compress_into(messages)
+    }
+}
+
+impl<Id: FheIntId> Compressible for FheInt<Id> {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        self.ciphertext.into_cpu().compress_into(messages)
+    }
+}
+
+impl Compressible for FheBool {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        BooleanBlock::new_unchecked(self.into_raw_parts()).compress_into(messages)
+    }
+}
+
+/// Builder to create a [CompressedCiphertextList] from ciphertexts resulting from computations.
+///
+/// The compression key contained in the server key set with
+/// [set_server_key](crate::set_server_key) is used, compression has to be enabled with
+/// [ConfigBuilder::enable_compression](crate::ConfigBuilder::enable_compression).
+pub struct CompressedCiphertextListBuilder {
+    inner: crate::integer::ciphertext::CompressedCiphertextListBuilder,
+}
+
+impl CompressedCiphertextListBuilder {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            inner: crate::integer::ciphertext::CompressedCiphertextListBuilder::new(),
+        }
+    }
+
+    pub fn push<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Compressible,
+    {
+        self.inner.push(value);
+        self
+    }
+
+    pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
+    where
+        T: Compressible,
+    {
+        self.inner.extend(values);
+        self
+    }
+
+    pub fn build(&self) -> crate::Result<CompressedCiphertextList> {
+        if self.inner.info.is_empty() {
+            return Err(crate::Error::new(
+                "Cannot build a CompressedCiphertextList from an empty builder".to_string(),
+            ));
+        }
+
+        global_state::try_with_internal_keys(|keys| match keys {
+            None => Err(crate::high_level_api::errors::UninitializedServerKey.into()),
+            Some(InternalServerKey::Cpu(cpu_key)) => cpu_key
+                .compression_key
+                .as_ref()
+                .ok_or_else(|| {
+                    crate::Error::new("Compression key not set in server key".to_owned())
+                })
+                .map(|compression_key| CompressedCiphertextList(self.inner.build(compression_key))),
+            #[cfg(feature = "gpu")]
+            Some(_) => Err(crate::Error::new("Expected a CPU server key".to_string())),
+        })
+    }
+}
+
+/// A list of FheUint, FheInt and FheBool stored in a compressed form.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::shortint::parameters::COMP_PARAM_MESSAGE_2_CARRY_2;
+/// use tfhe::{
+///     set_server_key, CompressedCiphertextList, CompressedCiphertextListBuilder, ConfigBuilder,
+///     FheBool, FheInt64, FheUint16, FheUint32,
+/// };
+///
+/// let config = ConfigBuilder::default()
+///     .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2)
+///     .build();
+///
+/// let ck = tfhe::ClientKey::generate(config);
+/// let sk = tfhe::ServerKey::new(&ck);
+/// set_server_key(sk);
+///
+/// let a = FheUint32::encrypt(17_u32, &ck);
+/// let b = FheInt64::encrypt(-1i64, &ck);
+/// let c = FheBool::encrypt(false, &ck);
+///
+/// // Results of computations can be compressed
+/// let d = &a + &a;
+///
+/// let compressed_list = CompressedCiphertextListBuilder::new()
+///     .push(a)
+///     .push(b)
+///     .push(c)
+///     .push(d)
+///     .build()
+///     .unwrap();
+///
+/// let serialized = bincode::serialize(&compressed_list).unwrap();
+/// let compressed_list: CompressedCiphertextList = bincode::deserialize(&serialized).unwrap();
+///
+/// let a: FheUint32 = compressed_list.get(0).unwrap().unwrap();
+/// let b: FheInt64 = compressed_list.get(1).unwrap().unwrap();
+/// let c: FheBool = compressed_list.get(2).unwrap().unwrap();
+/// let d: FheUint32 = compressed_list.get(3).unwrap().unwrap();
+///
+/// let a: u32 = a.decrypt(&ck);
+/// assert_eq!(a, 17);
+/// let b: i64 = b.decrypt(&ck);
+/// assert_eq!(b, -1);
+/// let c = c.decrypt(&ck);
+/// assert!(!c);
+/// let d: u32 = d.decrypt(&ck);
+/// assert_eq!(d, 34);
+///
+/// // Incorrect type
+/// assert!(compressed_list.get::<FheInt64>(0).unwrap().is_err());
+///
+/// // Correct type but wrong number of bits
+/// assert!(compressed_list.get::<FheUint16>(0).unwrap().is_err());
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CompressedCiphertextList(
+    #[serde(with = "crate::versionable::nested")]
+    crate::integer::ciphertext::CompressedCiphertextList,
+);
+
+impl Named for CompressedCiphertextList {
+    const NAME: &'static str = "high_level_api::CompressedCiphertextList";
+}
+
+impl CompressedCiphertextList {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get_kind_of(&self, index: usize) -> Option<crate::FheTypes> {
+        data_kind_to_fhe_type(self.0.get_kind_of(index)?, self.0.message_modulus())
+    }
+
+    /// Decompresses the element stored at `index` using the decompression key contained in the
+    /// server key set with [set_server_key](crate::set_server_key).
+    ///
+    /// Returns `None` if `index` is out of bounds, and an error if the server key does not
+    /// contain a decompression key or if the stored element does not have the requested type.
+    pub fn get<T>(&self, index: usize) -> Option<crate::Result<T>>
+    where
+        T: Expandable,
+    {
+        global_state::try_with_internal_keys(|keys| match keys {
+            None => Some(Err(
+                crate::high_level_api::errors::UninitializedServerKey.into()
+            )),
+            Some(InternalServerKey::Cpu(cpu_key)) => {
+                cpu_key.decompression_key.as_ref().map_or_else(
+                    || {
+                        Some(Err(crate::Error::new(
+                            "Decompression key not set in server key".to_owned(),
+                        )))
+                    },
+                    |decompression_key| self.0.get(index, decompression_key),
+                )
+            }
+            #[cfg(feature = "gpu")]
+            Some(_) => Some(Err(crate::Error::new(
+                "Expected a CPU server key".to_string(),
+            ))),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2;
+    use crate::{set_server_key, FheInt64, FheUint16, FheUint2, FheUint32};
+
+    #[test]
+    fn test_compressed_ct_list() {
+        let config = crate::ConfigBuilder::default()
+            .enable_compression(COMP_PARAM_MESSAGE_2_CARRY_2)
+            .build();
+
+        let ck = crate::ClientKey::generate(config);
+        let sk = crate::CompressedServerKey::new(&ck);
+
+        set_server_key(sk.decompress());
+
+        let ct1 = FheUint32::encrypt(17_u32, &ck);
+
+        let ct2 = FheInt64::encrypt(-1i64, &ck);
+
+        let ct3 = FheBool::encrypt(false, &ck);
+
+        let ct4 = FheUint2::encrypt(3u8, &ck);
+
+        // Comparisons produce FheBool with degree 1
+        let ct5 = ct1.gt(12u32);
+
+        let compressed_list = CompressedCiphertextListBuilder::new()
+            .push(ct1)
+            .push(ct2)
+            .push(ct3)
+            .push(ct4)
+            .push(ct5)
+            .build()
+            .unwrap();
+
+        let serialized = bincode::serialize(&compressed_list).unwrap();
+
+        let compressed_list: CompressedCiphertextList = bincode::deserialize(&serialized).unwrap();
+
+        assert_eq!(compressed_list.len(), 5);
+        assert_eq!(
+            compressed_list.get_kind_of(0),
+            Some(crate::FheTypes::Uint32)
+        );
+        assert_eq!(compressed_list.get_kind_of(1), Some(crate::FheTypes::Int64));
+        assert_eq!(compressed_list.get_kind_of(2), Some(crate::FheTypes::Bool));
+        assert_eq!(compressed_list.get_kind_of(5), None);
+
+        {
+            let a: FheUint32 = compressed_list.get(0).unwrap().unwrap();
+            let b: FheInt64 = compressed_list.get(1).unwrap().unwrap();
+            let c: FheBool = compressed_list.get(2).unwrap().unwrap();
+            let d: FheUint2 = compressed_list.get(3).unwrap().unwrap();
+            let e: FheBool = compressed_list.get(4).unwrap().unwrap();
+
+            let a: u32 = a.decrypt(&ck);
+            assert_eq!(a, 17);
+            let b: i64 = b.decrypt(&ck);
+            assert_eq!(b, -1);
+            let c = c.decrypt(&ck);
+            assert!(!c);
+            let d: u8 = d.decrypt(&ck);
+            assert_eq!(d, 3);
+            let e = e.decrypt(&ck);
+            assert!(e);
+
+            assert!(compressed_list.get::<FheBool>(5).is_none());
+        }
+
+        {
+            // Incorrect type
+            assert!(compressed_list.get::<FheInt64>(0).unwrap().is_err());
+
+            // Correct type but wrong number of bits
+            assert!(compressed_list.get::<FheUint16>(0).unwrap().is_err());
+        }
+    }
+}
@@ -28,6 +28,18 @@ impl ConfigBuilder {
         self
     }
 
+    /// Enable the compression of ciphertexts into a
+    /// [CompressedCiphertextList](crate::CompressedCiphertextList) using the given parameters.
+    ///
+    /// The parameters must be compatible with the parameters used for computations.
+    pub fn enable_compression(
+        mut self,
+        compression_parameters: crate::shortint::parameters::list_compression::CompressionParameters,
+    ) -> Self {
+        self.config.inner.enable_compression(compression_parameters);
+        self
+    }
+
     /// Use default parameters with big encryption
     ///
     /// For more information see [crate::core_crypto::prelude::PBSOrder::KeyswitchBootstrap]
@@ -0,0 +1,398 @@
+use crate::core_crypto::prelude::CastFrom;
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::{FheIntId, FheUintId};
+use crate::integer::{
+    IntegerCiphertext, IntegerRadixCiphertext, RadixCiphertext, SignedRadixCiphertext,
+};
+use crate::named::Named;
+use crate::shortint::MessageModulus;
+use crate::{FheInt, FheUint};
+use serde::{Deserialize, Serialize};
+
+/// How the bits that do not fit in the fractional part of a result are discarded.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Rounds toward zero, the fractional bits are dropped
+    TowardZero,
+    /// Rounds toward negative infinity
+    Floor,
+    /// Rounds toward positive infinity
+    Ceil,
+    /// Rounds to the nearest representable value, ties are rounded toward positive infinity
+    Nearest,
+}
+
+/// A FHE unsigned fixed point number
+///
+/// The number has `INT` integer bits and `FRAC` fractional bits, and is stored as an unsigned
+/// radix integer of `INT + FRAC` bits which is the number scaled by `2^FRAC`. `INT + FRAC` must be
+/// a multiple of the number of message bits per block of the parameters, and at most 128.
+///
+/// Like [FheUint], the type overloads the operators (`+`, `-`, `*`, `/`) and the operations wrap
+/// around on overflow.
+/// The product is rounded toward negative infinity (see [FheFixedU::mul_with_rounding] for other
+/// rounding modes) and the quotient toward zero.
+///
+/// [FheUint]: crate::FheUint
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedU};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheFixedU::<8, 8>::encrypt(2.5f64, &client_key);
+/// let b = FheFixedU::<8, 8>::encrypt(1.25f64, &client_key);
+///
+/// let c = &a * &b;
+///
+/// let decrypted: f64 = c.decrypt(&client_key);
+/// assert_eq!(decrypted, 3.125);
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FheFixedU<const INT: usize, const FRAC: usize> {
+    #[serde(with = "crate::versionable::nested")]
+    pub(in crate::high_level_api) ciphertext: RadixCiphertext,
+}
+
+/// A FHE signed fixed point number
+///
+/// The number has `INT` integer bits, the sign bit included, and `FRAC` fractional bits. It is
+/// stored as a signed radix integer of `INT + FRAC` bits which is the number scaled by `2^FRAC`.
+/// `INT + FRAC` must be a multiple of the number of message bits per block of the parameters, and
+/// at most 128.
+///
+/// Like [FheInt], the type overloads the operators (`+`, `-`, `*`, `/`) and the operations wrap
+/// around on overflow.
+/// The product is rounded toward negative infinity (see [FheFixedI::mul_with_rounding] for other
+/// rounding modes) and the quotient toward zero.
+///
+/// [FheInt]: crate::FheInt
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheFixedI::<8, 8>::encrypt(-2.5f64, &client_key);
+/// let b = FheFixedI::<8, 8>::encrypt(1.25f64, &client_key);
+///
+/// let c = &a - &b;
+///
+/// let decrypted: f64 = c.decrypt(&client_key);
+/// assert_eq!(decrypted, -3.75);
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FheFixedI<const INT: usize, const FRAC: usize> {
+    #[serde(with = "crate::versionable::nested")]
+    pub(in crate::high_level_api) ciphertext: SignedRadixCiphertext,
+}
+
+impl<const INT: usize, const FRAC: usize> Named for FheFixedU<INT, FRAC> {
+    const NAME: &'static str = "high_level_api::FheFixedU";
+}
+
+impl<const INT: usize, const FRAC: usize> Named for FheFixedI<INT, FRAC> {
+    const NAME: &'static str = "high_level_api::FheFixedI";
+}
+
+macro_rules! impl_fixed_base {
+    ($fhe_type:ident, $ciphertext_type:ty) => {
+        impl<const INT: usize, const FRAC: usize> $fhe_type<INT, FRAC> {
+            pub(in crate::high_level_api) const NUM_BITS: usize = {
+                assert!(
+                    INT + FRAC > 0 && INT + FRAC <= 128,
+                    "A fixed point number must have between 1 and 128 bits"
+                );
+                INT + FRAC
+            };
+
+            pub(in crate::high_level_api) fn new(ciphertext: $ciphertext_type) -> Self {
+                Self { ciphertext }
+            }
+
+            pub(in crate::high_level_api) fn num_blocks(
+                message_modulus: MessageModulus,
+            ) -> crate::Result<usize> {
+                let bits_per_block = message_modulus.0.ilog2() as usize;
+                if Self::NUM_BITS % bits_per_block != 0 {
+                    return Err(crate::Error::new(format!(
+                        "A fixed point number of {} bits cannot be stored in blocks of {} bits",
+                        Self::NUM_BITS,
+                        bits_per_block
+                    )));
+                }
+                Ok(Self::NUM_BITS / bits_per_block)
+            }
+
+            pub fn into_raw_parts(self) -> $ciphertext_type {
+                self.ciphertext
+            }
+
+            /// Creates a fixed point number from a radix ciphertext holding the number scaled
+            /// by `2^FRAC`
+            pub fn from_raw_parts(ciphertext: $ciphertext_type) -> Self {
+                Self::new(ciphertext)
+            }
+
+            /// Rounds the number to an integer, according to the rounding mode
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// use tfhe::prelude::*;
+            /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI, RoundingMode};
+            ///
+            /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+            /// set_server_key(server_key);
+            ///
+            /// let a = FheFixedI::<8, 8>::encrypt(-2.5f64, &client_key);
+            ///
+            /// let decrypted: f64 = a.round(RoundingMode::TowardZero).decrypt(&client_key);
+            /// assert_eq!(decrypted, -2.0);
+            /// let decrypted: f64 = a.round(RoundingMode::Floor).decrypt(&client_key);
+            /// assert_eq!(decrypted, -3.0);
+            /// let decrypted: f64 = a.round(RoundingMode::Nearest).decrypt(&client_key);
+            /// assert_eq!(decrypted, -2.0);
+            /// ```
+            pub fn round(&self, mode: RoundingMode) -> Self {
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    let sks = cpu_key.pbs_key();
+                    let integer_part = shift_right_rounded(sks, &self.ciphertext, FRAC, mode);
+                    Self::new(shift_left(sks, &integer_part, FRAC))
+                })
+            }
+
+            /// Multiplies two numbers, rounding the result according to the rounding mode
+            ///
+            /// The `*` operator rounds the result toward negative infinity.
+            pub fn mul_with_rounding(&self, rhs: &Self, mode: RoundingMode) -> Self {
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    let sks = cpu_key.pbs_key();
+                    let num_blocks = self.ciphertext.blocks().len();
+
+                    // The product is computed with twice the precision, then scaled back
+                    let lhs = resize(sks, self.ciphertext.clone(), 2 * num_blocks);
+                    let rhs = resize(sks, rhs.ciphertext.clone(), 2 * num_blocks);
+                    let product = sks.mul_parallelized(&lhs, &rhs);
+                    let product = shift_right_rounded(sks, &product, FRAC, mode);
+
+                    Self::new(resize(sks, product, num_blocks))
+                })
+            }
+        }
+    };
+}
+
+impl_fixed_base!(FheFixedU, RadixCiphertext);
+impl_fixed_base!(FheFixedI, SignedRadixCiphertext);
+
+/// Changes the number of blocks of the ciphertext, sign extending signed ciphertexts
+pub(super) fn resize<T>(sks: &crate::integer::ServerKey, ct: T, num_blocks: usize) -> T
+where
+    T: IntegerRadixCiphertext,
+{
+    if T::IS_SIGNED {
+        T::from_blocks(sks.cast_to_signed(ct, num_blocks).into_blocks())
+    } else {
+        T::from_blocks(sks.cast_to_unsigned(ct, num_blocks).into_blocks())
+    }
+}
+
+/// Shifts the ciphertext to the right, rounding the discarded bits according to the rounding mode
+pub(super) fn shift_right_rounded<T>(
+    sks: &crate::integer::ServerKey,
+    ct: &T,
+    shift: usize,
+    mode: RoundingMode,
+) -> T
+where
+    T: IntegerRadixCiphertext,
+{
+    if shift == 0 {
+        return ct.clone();
+    }
+
+    let num_blocks = ct.blocks().len();
+    let num_bits = num_blocks * sks.message_modulus().0.ilog2() as usize;
+    if shift >= num_bits {
+        // The scalar shifts take the shift modulo the number of bits, and adding the bias could
+        // overflow, so the number is rounded with (at least) two more bits
+        let num_extra_blocks = 2usize.div_ceil(sks.message_modulus().0.ilog2() as usize);
+        let wide = resize(sks, ct.clone(), num_blocks + num_extra_blocks);
+        let rounded = shift_right_rounded(sks, &wide, shift, mode);
+        return resize(sks, rounded, num_blocks);
+    }
+
+    // The right shift rounds toward negative infinity (it is arithmetic for signed ciphertexts),
+    // so the other modes add a bias before shifting
+    let discarded_mask = u128::MAX >> (128 - shift);
+    let biased = match mode {
+        RoundingMode::Floor => ct.clone(),
+        RoundingMode::TowardZero if !T::IS_SIGNED => ct.clone(),
+        RoundingMode::TowardZero => {
+            // The bias is only added to negative numbers, the sign is spread to all the bits
+            let sign = sks.scalar_right_shift_parallelized(ct, (num_bits - 1) as u64);
+            let bias = sks.scalar_bitand_parallelized(&sign, discarded_mask);
+            sks.add_parallelized(ct, &bias)
+        }
+        RoundingMode::Ceil => sks.scalar_add_parallelized(ct, discarded_mask),
+        RoundingMode::Nearest => sks.scalar_add_parallelized(ct, 1u128 << (shift - 1)),
+    };
+
+    sks.scalar_right_shift_parallelized(&biased, shift as u64)
+}
+
+/// Shifts the ciphertext to the left, shifting all the bits out if the shift is not smaller than
+/// the number of bits of the ciphertext
+fn shift_left<T>(sks: &crate::integer::ServerKey, ct: &T, shift: usize) -> T
+where
+    T: IntegerRadixCiphertext,
+{
+    let num_blocks = ct.blocks().len();
+    if shift >= num_blocks * sks.message_modulus().0.ilog2() as usize {
+        return sks.create_trivial_zero_radix(num_blocks);
+    }
+    sks.scalar_left_shift_parallelized(ct, shift as u64)
+}
+
+impl<Id, const INT: usize, const FRAC: usize> CastFrom<FheUint<Id>> for FheFixedU<INT, FRAC>
+where
+    Id: FheUintId,
+{
+    /// Cast a FheUint to a FheFixedU
+    ///
+    /// The integer bits that do not fit in the integer part are discarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedU, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(42u8, &client_key);
+    /// let b = FheFixedU::<16, 8>::cast_from(a);
+    ///
+    /// let decrypted: f64 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, 42.0);
+    /// ```
+    fn cast_from(input: FheUint<Id>) -> Self {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let num_blocks = Self::num_blocks(cpu_key.message_modulus()).unwrap();
+            let ciphertext = sks.cast_to_unsigned(input.ciphertext.into_cpu(), num_blocks);
+            Self::new(shift_left(sks, &ciphertext, FRAC))
+        })
+    }
+}
+
+impl<Id, const INT: usize, const FRAC: usize> CastFrom<FheInt<Id>> for FheFixedI<INT, FRAC>
+where
+    Id: FheIntId,
+{
+    /// Cast a FheInt to a FheFixedI
+    ///
+    /// The integer bits that do not fit in the integer part are discarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI, FheInt8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt8::encrypt(-42i8, &client_key);
+    /// let b = FheFixedI::<16, 8>::cast_from(a);
+    ///
+    /// let decrypted: f64 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, -42.0);
+    /// ```
+    fn cast_from(input: FheInt<Id>) -> Self {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let num_blocks = Self::num_blocks(cpu_key.message_modulus()).unwrap();
+            let ciphertext = sks.cast_to_signed(input.ciphertext.into_cpu(), num_blocks);
+            Self::new(shift_left(sks, &ciphertext, FRAC))
+        })
+    }
+}
+
+impl<Id, const INT: usize, const FRAC: usize> CastFrom<FheFixedU<INT, FRAC>> for FheUint<Id>
+where
+    Id: FheUintId,
+{
+    /// Cast a FheFixedU to a FheUint
+    ///
+    /// The fractional part is discarded, use [FheFixedU::round] to choose how the number is
+    /// rounded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedU, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFixedU::<16, 8>::encrypt(42.75f64, &client_key);
+    /// let b = FheUint8::cast_from(a);
+    ///
+    /// let decrypted: u8 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, 42);
+    /// ```
+    fn cast_from(input: FheFixedU<INT, FRAC>) -> Self {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let integer_part =
+                shift_right_rounded(sks, &input.ciphertext, FRAC, RoundingMode::Floor);
+            Self::new(sks.cast_to_unsigned(integer_part, Id::num_blocks(cpu_key.message_modulus())))
+        })
+    }
+}
+
+impl<Id, const INT: usize, const FRAC: usize> CastFrom<FheFixedI<INT, FRAC>> for FheInt<Id>
+where
+    Id: FheIntId,
+{
+    /// Cast a FheFixedI to a FheInt
+    ///
+    /// The fractional part is discarded (the number is rounded toward zero), use
+    /// [FheFixedI::round] to choose how the number is rounded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFixedI, FheInt8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFixedI::<16, 8>::encrypt(-42.75f64, &client_key);
+    /// let b = FheInt8::cast_from(a);
+    ///
+    /// let decrypted: i8 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, -42);
+    /// ```
+    fn cast_from(input: FheFixedI<INT, FRAC>) -> Self {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            let integer_part =
+                shift_right_rounded(sks, &input.ciphertext, FRAC, RoundingMode::TowardZero);
+            Self::new(sks.cast_to_signed(integer_part, Id::num_blocks(cpu_key.message_modulus())))
+        })
+    }
+}
@@ -0,0 +1,187 @@
+use super::base::{FheFixedI, FheFixedU};
+use crate::high_level_api::global_state;
+use crate::prelude::{FheDecrypt, FheTryEncrypt, FheTryTrivialEncrypt};
+use crate::ClientKey;
+
+/// Scales the value by `2^frac` and rounds it to the nearest integer, checking that it fits in the
+/// range `[min, max]`
+fn scale_and_check_range(value: f64, frac: usize, min: f64, max: f64) -> crate::Result<f64> {
+    let scaled = (value * 2f64.powi(frac as i32)).round();
+    if !scaled.is_finite() || scaled < min || scaled > max {
+        return Err(crate::Error::new(format!(
+            "The value {value} cannot be represented by a fixed point number with {frac} \
+            fractional bits in the range [{}, {}]",
+            min / 2f64.powi(frac as i32),
+            max / 2f64.powi(frac as i32),
+        )));
+    }
+    Ok(scaled)
+}
+
+impl<const INT: usize, const FRAC: usize> FheFixedU<INT, FRAC> {
+    /// Encrypts a number given as its bits, that is the number scaled by `2^FRAC`
+    ///
+    /// Returns an error if the bits do not fit in `INT + FRAC` bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, ConfigBuilder, FheFixedU};
+    ///
+    /// let (client_key, _) = generate_keys(ConfigBuilder::default());
+    ///
+    /// // 0b1010_1100 with 4 fractional bits is 10.75
+    /// let a = FheFixedU::<8, 4>::try_encrypt_from_bits(0b1010_1100, &client_key).unwrap();
+    ///
+    /// let decrypted: f64 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, 10.75);
+    /// assert_eq!(a.decrypt_bits(&client_key), 0b1010_1100);
+    /// ```
+    pub fn try_encrypt_from_bits(bits: u128, key: &ClientKey) -> crate::Result<Self> {
+        if Self::NUM_BITS < 128 && bits >> Self::NUM_BITS != 0 {
+            return Err(crate::Error::new(format!(
+                "The bits {bits:#x} do not fit in {} bits",
+                Self::NUM_BITS
+            )));
+        }
+
+        let num_blocks = Self::num_blocks(key.message_modulus())?;
+        Ok(Self::new(key.key.key.encrypt_radix(bits, num_blocks)))
+    }
+
+    /// Decrypts the bits of the number, that is the number scaled by `2^FRAC`
+    pub fn decrypt_bits(&self, key: &ClientKey) -> u128 {
+        key.key.key.decrypt_radix(&self.ciphertext)
+    }
+}
+
+impl<const INT: usize, const FRAC: usize> FheFixedI<INT, FRAC> {
+    /// Encrypts a number given as its bits, that is the number scaled by `2^FRAC`
+    ///
+    /// Returns an error if the bits do not fit in `INT + FRAC` bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, ConfigBuilder, FheFixedI};
+    ///
+    /// let (client_key, _) = generate_keys(ConfigBuilder::default());
+    ///
+    /// // -172 with 4 fractional bits is -10.75
+    /// let a = FheFixedI::<8, 4>::try_encrypt_from_bits(-172, &client_key).unwrap();
+    ///
+    /// let decrypted: f64 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, -10.75);
+    /// assert_eq!(a.decrypt_bits(&client_key), -172);
+    /// ```
+    pub fn try_encrypt_from_bits(bits: i128, key: &ClientKey) -> crate::Result<Self> {
+        // The bits above the sign bit must all be copies of the sign bit
+        let high_bits = bits >> (Self::NUM_BITS - 1);
+        if high_bits != 0 && high_bits != -1 {
+            return Err(crate::Error::new(format!(
+                "The bits {bits:#x} do not fit in {} bits",
+                Self::NUM_BITS
+            )));
+        }
+
+        let num_blocks = Self::num_blocks(key.message_modulus())?;
+        Ok(Self::new(
+            key.key.key.encrypt_signed_radix(bits, num_blocks),
+        ))
+    }
+
+    /// Decrypts the bits of the number, that is the number scaled by `2^FRAC`
+    pub fn decrypt_bits(&self, key: &ClientKey) -> i128 {
+        key.key.key.decrypt_signed_radix(&self.ciphertext)
+    }
+}
+
+impl<const INT: usize, const FRAC: usize> FheTryEncrypt<f64, ClientKey> for FheFixedU<INT, FRAC> {
+    type Error = crate::Error;
+
+    /// Encrypts the value rounded to the nearest number with `FRAC` fractional bits
+    ///
+    /// Returns an error if the value is not in the range of the type.
+    fn try_encrypt(value: f64, key: &ClientKey) -> Result<Self, Self::Error> {
+        let max = 2f64.powi(Self::NUM_BITS as i32) - 1.0;
+        let scaled = scale_and_check_range(value, FRAC, 0.0, max)?;
+        Self::try_encrypt_from_bits(scaled as u128, key)
+    }
+}
+
+impl<const INT: usize, const FRAC: usize> FheTryEncrypt<f64, ClientKey> for FheFixedI<INT, FRAC> {
+    type Error = crate::Error;
+
+    /// Encrypts the value rounded to the nearest number with `FRAC` fractional bits
+    ///
+    /// Returns an error if the value is not in the range of the type.
+    fn try_encrypt(value: f64, key: &ClientKey) -> Result<Self, Self::Error> {
+        let min = -(2f64.powi(Self::NUM_BITS as i32 - 1));
+        let max = 2f64.powi(Self::NUM_BITS as i32 - 1) - 1.0;
+        let scaled = scale_and_check_range(value, FRAC, min, max)?;
+        Self::try_encrypt_from_bits(scaled as i128, key)
+    }
+}
+
+impl<const INT: usize, const FRAC: usize> FheTryTrivialEncrypt<f64> for FheFixedU<INT, FRAC> {
+    type Error = crate::Error;
+
+    /// Creates a trivial encryption of the value rounded to the nearest number with `FRAC`
+    /// fractional bits
+    ///
+    /// # Warning
+    ///
+    /// Trivial encryptions are not real encryptions, as a trivially encrypted
+    /// ciphertext can be decrypted by any key (in fact, no key is actually needed).
+    fn try_encrypt_trivial(value: f64) -> Result<Self, Self::Error> {
+        let max = 2f64.powi(Self::NUM_BITS as i32) - 1.0;
+        let scaled = scale_and_check_range(value, FRAC, 0.0, max)?;
+        global_state::with_cpu_internal_keys(|sks| {
+            let num_blocks = Self::num_blocks(sks.message_modulus())?;
+            Ok(Self::new(
+                sks.pbs_key()
+                    .create_trivial_radix(scaled as u128, num_blocks),
+            ))
+        })
+    }
+}
+
+impl<const INT: usize, const FRAC: usize> FheTryTrivialEncrypt<f64> for FheFixedI<INT, FRAC> {
+    type Error = crate::Error;
+
+    /// Creates a trivial encryption of the value rounded to the nearest number with `FRAC`
+    /// fractional bits
+    ///
+    /// # Warning
+    ///
+    /// Trivial encryptions are not real encryptions, as a trivially encrypted
+    /// ciphertext can be decrypted by any key (in fact, no key is actually needed).
+    fn try_encrypt_trivial(value: f64) -> Result<Self, Self::Error> {
+        let min = -(2f64.powi(Self::NUM_BITS as i32 - 1));
+        let max = 2f64.powi(Self::NUM_BITS as i32 - 1) - 1.0;
+        let scaled = scale_and_check_range(value, FRAC, min, max)?;
+        global_state::with_cpu_internal_keys(|sks| {
+            let num_blocks = Self::num_blocks(sks.message_modulus())?;
+            Ok(Self::new(
+                sks.pbs_key()
+                    .create_trivial_radix(scaled as i128, num_blocks),
+            ))
+        })
+    }
+}
+
+impl<const INT: usize, const FRAC: usize> FheDecrypt<f64> for FheFixedU<INT, FRAC> {
+    /// Decrypts the number to the closest [f64]
+    fn decrypt(&self, key: &ClientKey) -> f64 {
+        self.decrypt_bits(key) as f64 / 2f64.powi(FRAC as i32)
+    }
+}
+
+impl<const INT: usize, const FRAC: usize> FheDecrypt<f64> for FheFixedI<INT, FRAC> {
+    /// Decrypts the number to the closest [f64]
+    fn decrypt(&self, key: &ClientKey) -> f64 {
+        self.decrypt_bits(key) as f64 / 2f64.powi(FRAC as i32)
+    }
+}
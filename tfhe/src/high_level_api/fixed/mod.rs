@@ -0,0 +1,7 @@
+pub use base::{FheFixedI, FheFixedU, RoundingMode};
+
+mod base;
+mod encrypt;
+mod ops;
+#[cfg(test)]
+mod tests;
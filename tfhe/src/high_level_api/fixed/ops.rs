@@ -0,0 +1,253 @@
+use super::base::{resize, FheFixedI, FheFixedU, RoundingMode};
+use crate::high_level_api::global_state;
+use crate::high_level_api::traits::{FheEq, FheMax, FheMin, FheOrd};
+use crate::integer::IntegerCiphertext;
+use crate::FheBool;
+use std::borrow::Borrow;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// Ciphertext/Ciphertext operators, shared by the unsigned and signed types
+macro_rules! fixed_impl_operation (
+    (
+        $fhe_type:ident,
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident),
+        rust_trait_assign: $rust_trait_assign_name:ident($rust_trait_assign_method:ident),
+        implem: {
+            $closure:expr
+        }
+        $(,)?
+    ) => {
+        impl<const INT: usize, const FRAC: usize, B> $rust_trait_name<B> for $fhe_type<INT, FRAC>
+        where
+            B: Borrow<Self>,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                <&Self as $rust_trait_name<B>>::$rust_trait_method(&self, rhs)
+            }
+        }
+
+        impl<const INT: usize, const FRAC: usize, B> $rust_trait_name<B> for &$fhe_type<INT, FRAC>
+        where
+            B: Borrow<$fhe_type<INT, FRAC>>,
+        {
+            type Output = $fhe_type<INT, FRAC>;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                let ciphertext = global_state::with_cpu_internal_keys(|cpu_key| {
+                    $closure(cpu_key.pbs_key(), &self.ciphertext, &rhs.borrow().ciphertext, FRAC)
+                });
+                $fhe_type::new(ciphertext)
+            }
+        }
+
+        impl<const INT: usize, const FRAC: usize, B> $rust_trait_assign_name<B>
+            for $fhe_type<INT, FRAC>
+        where
+            B: Borrow<Self>,
+        {
+            fn $rust_trait_assign_method(&mut self, rhs: B) {
+                *self = <&Self as $rust_trait_name<B>>::$rust_trait_method(self, rhs);
+            }
+        }
+    }
+);
+
+macro_rules! fixed_impl_operations (
+    ($fhe_type:ident, $ciphertext_type:ty) => {
+        fixed_impl_operation!(
+            $fhe_type,
+            rust_trait: Add(add),
+            rust_trait_assign: AddAssign(add_assign),
+            implem: {
+                |sks: &crate::integer::ServerKey,
+                 lhs: &$ciphertext_type,
+                 rhs: &$ciphertext_type,
+                 _frac| sks.add_parallelized(lhs, rhs)
+            },
+        );
+
+        fixed_impl_operation!(
+            $fhe_type,
+            rust_trait: Sub(sub),
+            rust_trait_assign: SubAssign(sub_assign),
+            implem: {
+                |sks: &crate::integer::ServerKey,
+                 lhs: &$ciphertext_type,
+                 rhs: &$ciphertext_type,
+                 _frac| sks.sub_parallelized(lhs, rhs)
+            },
+        );
+
+        impl<const INT: usize, const FRAC: usize, B> Mul<B> for $fhe_type<INT, FRAC>
+        where
+            B: Borrow<Self>,
+        {
+            type Output = Self;
+
+            fn mul(self, rhs: B) -> Self::Output {
+                <&Self as Mul<B>>::mul(&self, rhs)
+            }
+        }
+
+        impl<const INT: usize, const FRAC: usize, B> Mul<B> for &$fhe_type<INT, FRAC>
+        where
+            B: Borrow<$fhe_type<INT, FRAC>>,
+        {
+            type Output = $fhe_type<INT, FRAC>;
+
+            /// Multiplies two fixed point numbers, the result is rounded toward negative infinity
+            fn mul(self, rhs: B) -> Self::Output {
+                self.mul_with_rounding(rhs.borrow(), RoundingMode::Floor)
+            }
+        }
+
+        impl<const INT: usize, const FRAC: usize, B> MulAssign<B> for $fhe_type<INT, FRAC>
+        where
+            B: Borrow<Self>,
+        {
+            fn mul_assign(&mut self, rhs: B) {
+                *self = self.mul_with_rounding(rhs.borrow(), RoundingMode::Floor);
+            }
+        }
+
+        fixed_impl_operation!(
+            $fhe_type,
+            rust_trait: Div(div),
+            rust_trait_assign: DivAssign(div_assign),
+            implem: {
+                |sks: &crate::integer::ServerKey,
+                 lhs: &$ciphertext_type,
+                 rhs: &$ciphertext_type,
+                 frac: usize| {
+                    // The numerator is scaled by 2^FRAC so that the quotient keeps FRAC
+                    // fractional bits, which requires twice the precision
+                    let num_blocks = lhs.blocks().len();
+                    let numerator = resize(sks, lhs.clone(), 2 * num_blocks);
+                    let numerator = sks.scalar_left_shift_parallelized(&numerator, frac as u64);
+                    let divisor = resize(sks, rhs.clone(), 2 * num_blocks);
+                    let quotient = sks.div_parallelized(&numerator, &divisor);
+                    resize(sks, quotient, num_blocks)
+                }
+            },
+        );
+
+        impl<const INT: usize, const FRAC: usize> FheEq<Self> for $fhe_type<INT, FRAC> {
+            fn eq(&self, rhs: Self) -> FheBool {
+                self.eq(&rhs)
+            }
+
+            fn ne(&self, rhs: Self) -> FheBool {
+                self.ne(&rhs)
+            }
+        }
+
+        impl<const INT: usize, const FRAC: usize> FheEq<&Self> for $fhe_type<INT, FRAC> {
+            fn eq(&self, rhs: &Self) -> FheBool {
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    let sks = cpu_key.pbs_key();
+                    FheBool::new(sks.eq_parallelized(&self.ciphertext, &rhs.ciphertext))
+                })
+            }
+
+            fn ne(&self, rhs: &Self) -> FheBool {
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    let sks = cpu_key.pbs_key();
+                    FheBool::new(sks.ne_parallelized(&self.ciphertext, &rhs.ciphertext))
+                })
+            }
+        }
+
+        impl<const INT: usize, const FRAC: usize> FheOrd<Self> for $fhe_type<INT, FRAC> {
+            fn lt(&self, rhs: Self) -> FheBool {
+                self.lt(&rhs)
+            }
+
+            fn le(&self, rhs: Self) -> FheBool {
+                self.le(&rhs)
+            }
+
+            fn gt(&self, rhs: Self) -> FheBool {
+                self.gt(&rhs)
+            }
+
+            fn ge(&self, rhs: Self) -> FheBool {
+                self.ge(&rhs)
+            }
+        }
+
+        impl<const INT: usize, const FRAC: usize> FheOrd<&Self> for $fhe_type<INT, FRAC> {
+            fn lt(&self, rhs: &Self) -> FheBool {
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    let sks = cpu_key.pbs_key();
+                    FheBool::new(sks.lt_parallelized(&self.ciphertext, &rhs.ciphertext))
+                })
+            }
+
+            fn le(&self, rhs: &Self) -> FheBool {
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    let sks = cpu_key.pbs_key();
+                    FheBool::new(sks.le_parallelized(&self.ciphertext, &rhs.ciphertext))
+                })
+            }
+
+            fn gt(&self, rhs: &Self) -> FheBool {
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    let sks = cpu_key.pbs_key();
+                    FheBool::new(sks.gt_parallelized(&self.ciphertext, &rhs.ciphertext))
+                })
+            }
+
+            fn ge(&self, rhs: &Self) -> FheBool {
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    let sks = cpu_key.pbs_key();
+                    FheBool::new(sks.ge_parallelized(&self.ciphertext, &rhs.ciphertext))
+                })
+            }
+        }
+
+        impl<const INT: usize, const FRAC: usize> FheMin<&Self> for $fhe_type<INT, FRAC> {
+            type Output = Self;
+
+            fn min(&self, rhs: &Self) -> Self::Output {
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    let sks = cpu_key.pbs_key();
+                    Self::new(sks.min_parallelized(&self.ciphertext, &rhs.ciphertext))
+                })
+            }
+        }
+
+        impl<const INT: usize, const FRAC: usize> FheMax<&Self> for $fhe_type<INT, FRAC> {
+            type Output = Self;
+
+            fn max(&self, rhs: &Self) -> Self::Output {
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    let sks = cpu_key.pbs_key();
+                    Self::new(sks.max_parallelized(&self.ciphertext, &rhs.ciphertext))
+                })
+            }
+        }
+    }
+);
+
+fixed_impl_operations!(FheFixedU, crate::integer::RadixCiphertext);
+fixed_impl_operations!(FheFixedI, crate::integer::SignedRadixCiphertext);
+
+impl<const INT: usize, const FRAC: usize> Neg for FheFixedI<INT, FRAC> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        <&Self as Neg>::neg(&self)
+    }
+}
+
+impl<const INT: usize, const FRAC: usize> Neg for &FheFixedI<INT, FRAC> {
+    type Output = FheFixedI<INT, FRAC>;
+
+    fn neg(self) -> Self::Output {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            FheFixedI::new(cpu_key.pbs_key().neg_parallelized(&self.ciphertext))
+        })
+    }
+}
@@ -0,0 +1,219 @@
+use crate::high_level_api::prelude::*;
+use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder};
+use crate::safe_deserialization::safe_deserialize;
+use crate::shortint::parameters::PARAM_MESSAGE_1_CARRY_1_KS_PBS;
+use crate::{ClientKey, FheFixedI, FheFixedU, FheInt16, FheUint16, RoundingMode};
+
+type FixedU = FheFixedU<8, 8>;
+type FixedI = FheFixedI<8, 8>;
+
+fn setup_default_cpu() -> ClientKey {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    client_key
+}
+
+#[test]
+fn test_fixed_encrypt_decrypt() {
+    let (client_key, _) = generate_keys(ConfigBuilder::default());
+
+    for value in [0.0, 1.5, 3.140625, 255.99609375] {
+        let a = FixedU::try_encrypt(value, &client_key).unwrap();
+        let decrypted: f64 = a.decrypt(&client_key);
+        assert_eq!(decrypted, value);
+    }
+
+    for value in [0.0, -1.5, 3.140625, -128.0, 127.99609375] {
+        let a = FixedI::try_encrypt(value, &client_key).unwrap();
+        let decrypted: f64 = a.decrypt(&client_key);
+        assert_eq!(decrypted, value);
+    }
+
+    // Values are rounded to the nearest representable number
+    let a = FixedU::try_encrypt(0.1, &client_key).unwrap();
+    assert_eq!(a.decrypt_bits(&client_key), 26);
+
+    assert!(FixedU::try_encrypt(-1.0, &client_key).is_err());
+    assert!(FixedU::try_encrypt(256.0, &client_key).is_err());
+    assert!(FixedU::try_encrypt(f64::NAN, &client_key).is_err());
+    assert!(FixedI::try_encrypt(128.0, &client_key).is_err());
+    assert!(FixedI::try_encrypt(-128.5, &client_key).is_err());
+    assert!(FixedU::try_encrypt_from_bits(1 << 16, &client_key).is_err());
+    assert!(FixedI::try_encrypt_from_bits(1 << 15, &client_key).is_err());
+
+    // 9 bits do not fit in blocks of 2 bits
+    assert!(FheFixedU::<5, 4>::try_encrypt(1.0, &client_key).is_err());
+
+    let config = ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_1_CARRY_1_KS_PBS, None);
+    let (client_key, _) = generate_keys(config);
+    let a = FheFixedU::<5, 4>::try_encrypt(1.0, &client_key).unwrap();
+    let decrypted: f64 = a.decrypt(&client_key);
+    assert_eq!(decrypted, 1.0);
+}
+
+#[test]
+fn test_fixed_unsigned_arithmetic() {
+    let client_key = setup_default_cpu();
+
+    let a = FixedU::encrypt(12.75, &client_key);
+    let b = FixedU::encrypt(2.5, &client_key);
+
+    let decrypted: f64 = (&a + &b).decrypt(&client_key);
+    assert_eq!(decrypted, 15.25);
+    let decrypted: f64 = (&a - &b).decrypt(&client_key);
+    assert_eq!(decrypted, 10.25);
+    let decrypted: f64 = (&a * &b).decrypt(&client_key);
+    assert_eq!(decrypted, 31.875);
+    // 5.1 is not representable, the quotient is rounded toward zero
+    let quotient = &a / &b;
+    assert_eq!(quotient.decrypt_bits(&client_key), 1305);
+
+    // 1/3 * 1/3 has bits below the precision
+    let c = FixedU::try_encrypt_from_bits(0x55, &client_key).unwrap();
+    assert_eq!((&c * &c).decrypt_bits(&client_key), 0x1c);
+    let rounded = c.mul_with_rounding(&c, RoundingMode::Ceil);
+    assert_eq!(rounded.decrypt_bits(&client_key), 0x1d);
+    let rounded = c.mul_with_rounding(&c, RoundingMode::Nearest);
+    assert_eq!(rounded.decrypt_bits(&client_key), 0x1c);
+
+    let mut d = a.clone();
+    d += &b;
+    d *= &b;
+    let decrypted: f64 = d.decrypt(&client_key);
+    assert_eq!(decrypted, 38.125);
+}
+
+#[test]
+fn test_fixed_signed_arithmetic() {
+    let client_key = setup_default_cpu();
+
+    let a = FixedI::encrypt(-12.75, &client_key);
+    let b = FixedI::encrypt(2.5, &client_key);
+
+    let decrypted: f64 = (&a + &b).decrypt(&client_key);
+    assert_eq!(decrypted, -10.25);
+    let decrypted: f64 = (&b - &a).decrypt(&client_key);
+    assert_eq!(decrypted, 15.25);
+    let decrypted: f64 = (&a * &b).decrypt(&client_key);
+    assert_eq!(decrypted, -31.875);
+    let decrypted: f64 = (-&a).decrypt(&client_key);
+    assert_eq!(decrypted, 12.75);
+
+    // -5.1 is not representable, the quotient is rounded toward zero
+    let quotient = &a / &b;
+    assert_eq!(quotient.decrypt_bits(&client_key), -1305);
+
+    for (mode, expected) in [
+        (RoundingMode::TowardZero, -12.0),
+        (RoundingMode::Floor, -13.0),
+        (RoundingMode::Ceil, -12.0),
+        (RoundingMode::Nearest, -13.0),
+    ] {
+        let decrypted: f64 = a.round(mode).decrypt(&client_key);
+        assert_eq!(decrypted, expected, "{mode:?}");
+    }
+}
+
+#[test]
+fn test_fixed_comparisons() {
+    let client_key = setup_default_cpu();
+
+    let a = FixedI::encrypt(-1.25, &client_key);
+    let b = FixedI::encrypt(0.5, &client_key);
+
+    assert!(a.lt(&b).decrypt(&client_key));
+    assert!(a.le(&b).decrypt(&client_key));
+    assert!(!a.gt(&b).decrypt(&client_key));
+    assert!(!a.ge(&b).decrypt(&client_key));
+    assert!(a.eq(&a).decrypt(&client_key));
+    assert!(a.ne(&b).decrypt(&client_key));
+
+    let decrypted: f64 = a.min(&b).decrypt(&client_key);
+    assert_eq!(decrypted, -1.25);
+    let decrypted: f64 = a.max(&b).decrypt(&client_key);
+    assert_eq!(decrypted, 0.5);
+
+    let a = FixedU::encrypt(1.25, &client_key);
+    let b = FixedU::encrypt(0.5, &client_key);
+    assert!(a.gt(&b).decrypt(&client_key));
+}
+
+#[test]
+fn test_fixed_casts() {
+    let client_key = setup_default_cpu();
+
+    let a = FheUint16::encrypt(300u16, &client_key);
+    let fixed = FheFixedU::<12, 4>::cast_from(a);
+    let decrypted: f64 = fixed.decrypt(&client_key);
+    assert_eq!(decrypted, 300.0);
+
+    let fixed = FheFixedU::<12, 4>::encrypt(300.9375, &client_key);
+    let decrypted: u16 = FheUint16::cast_from(fixed).decrypt(&client_key);
+    assert_eq!(decrypted, 300);
+
+    let a = FheInt16::encrypt(-300i16, &client_key);
+    let fixed = FheFixedI::<12, 4>::cast_from(a);
+    let decrypted: f64 = fixed.decrypt(&client_key);
+    assert_eq!(decrypted, -300.0);
+
+    let fixed = FheFixedI::<12, 4>::encrypt(-300.9375, &client_key);
+    let decrypted: i16 = FheInt16::cast_from(fixed.clone()).decrypt(&client_key);
+    assert_eq!(decrypted, -300);
+    let decrypted: i16 =
+        FheInt16::cast_from(fixed.round(RoundingMode::Nearest)).decrypt(&client_key);
+    assert_eq!(decrypted, -301);
+}
+
+#[test]
+fn test_fixed_without_integer_part() {
+    let client_key = setup_default_cpu();
+
+    // All the bits are fractional, shifting by FRAC shifts all the bits out
+    let a = FheFixedU::<0, 8>::encrypt(0.75, &client_key);
+    let b = FheFixedU::<0, 8>::encrypt(0.5, &client_key);
+    let decrypted: f64 = (&a * &b).decrypt(&client_key);
+    assert_eq!(decrypted, 0.375);
+    for mode in [
+        RoundingMode::TowardZero,
+        RoundingMode::Floor,
+        RoundingMode::Ceil,
+        RoundingMode::Nearest,
+    ] {
+        // The only integer that can be represented is 0, the others wrap around
+        let decrypted: f64 = a.round(mode).decrypt(&client_key);
+        assert_eq!(decrypted, 0.0, "{mode:?}");
+    }
+    let decrypted: u16 = FheUint16::cast_from(a).decrypt(&client_key);
+    assert_eq!(decrypted, 0);
+    let fixed = FheFixedU::<0, 8>::cast_from(FheUint16::encrypt(3u16, &client_key));
+    let decrypted: f64 = fixed.decrypt(&client_key);
+    assert_eq!(decrypted, 0.0);
+
+    let a = FheFixedI::<0, 8>::encrypt(-0.25, &client_key);
+    let b = FheFixedI::<0, 8>::encrypt(0.375, &client_key);
+    let decrypted: f64 = (&a * &b).decrypt(&client_key);
+    assert_eq!(decrypted, -0.09375);
+    let decrypted: f64 = a.round(RoundingMode::Floor).decrypt(&client_key);
+    assert_eq!(decrypted, 0.0);
+    let decrypted: i16 = FheInt16::cast_from(a).decrypt(&client_key);
+    assert_eq!(decrypted, 0);
+    let decrypted: i16 = FheInt16::cast_from(b.clone()).decrypt(&client_key);
+    assert_eq!(decrypted, 0);
+    let decrypted: f64 = b.round(RoundingMode::Nearest).decrypt(&client_key);
+    assert_eq!(decrypted, 0.0);
+}
+
+#[test]
+fn test_fixed_serialization() {
+    let (client_key, _) = generate_keys(ConfigBuilder::default());
+
+    let a = FixedI::encrypt(-3.5, &client_key);
+
+    let mut serialized = vec![];
+    crate::safe_serialize(&a, &mut serialized, 1 << 20).unwrap();
+    let deserialized: FixedI = safe_deserialize(serialized.as_slice(), 1 << 20).unwrap();
+
+    let decrypted: f64 = deserialized.decrypt(&client_key);
+    assert_eq!(decrypted, -3.5);
+}
@@ -0,0 +1,270 @@
+//! Cryptographic hash functions over encrypted data.
+//!
+//! - [sha256] and [sha3_256] hash messages packed in [FheUint32](crate::FheUint32) and
+//!   [FheUint64](crate::FheUint64) words, the words using the byte order of each hash function
+//!   (big endian for SHA-256, little endian for SHA-3).
+//! - [sha256_bools] and [sha3_256_bools] hash messages given bit by bit as
+//!   [FheBool](crate::FheBool), the most significant bit of each byte first, and return the
+//!   digest in the same order.
+//! - [keccak_f1600] is the permutation underlying SHA-3, to build other sponge constructions.
+//!
+//! The length of the messages is clear, and their padding is appended by the server with trivial
+//! ciphertexts, the content of the messages stays encrypted. As the digests are regular
+//! ciphertexts, they can be hashed again, to compute commitments or Merkle roots.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::hashes::sha256;
+//! use tfhe::prelude::*;
+//! use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32};
+//!
+//! let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+//! set_server_key(server_key);
+//!
+//! // The message "abc", trivially encrypted to keep the example fast
+//! let message = [FheUint32::encrypt_trivial(u32::from_be_bytes(*b"abc\0"))];
+//!
+//! let digest = sha256(&message, 3);
+//! let digest: Vec<u32> = digest
+//!     .iter()
+//!     .map(|word| word.try_decrypt_trivial().unwrap())
+//!     .collect();
+//! assert_eq!(
+//!     digest,
+//!     [
+//!         0xba7816bf, 0x8f01cfea, 0x414140de, 0x5dae2223, 0xb00361a3, 0x96177a9c, 0xb410ff61,
+//!         0xf20015ad
+//!     ]
+//! );
+//! ```
+mod sha256;
+mod sha3;
+#[cfg(test)]
+mod tests;
+
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey};
+use rayon::prelude::*;
+
+pub use sha256::{sha256, sha256_bools};
+pub use sha3::{keccak_f1600, sha3_256, sha3_256_bools};
+
+/// The operations the hash functions need on their words
+///
+/// The operations are done with the integer server key rather than the high level types, as the
+/// server key of the high level api is not set in the threads of rayon.
+trait WordOps: Sync {
+    type Word: Clone + Send + Sync;
+
+    fn bits(&self) -> u32;
+
+    fn trivial(&self, value: u64) -> Self::Word;
+
+    fn xor(&self, lhs: &Self::Word, rhs: &Self::Word) -> Self::Word;
+
+    fn and(&self, lhs: &Self::Word, rhs: &Self::Word) -> Self::Word;
+
+    fn not(&self, word: &Self::Word) -> Self::Word;
+
+    fn xor_scalar(&self, word: &Self::Word, value: u64) -> Self::Word;
+
+    fn rotate_right(&self, word: &Self::Word, n: u32) -> Self::Word;
+
+    fn rotate_left(&self, word: &Self::Word, n: u32) -> Self::Word {
+        self.rotate_right(word, (self.bits() - n) % self.bits())
+    }
+
+    fn shift_right(&self, word: &Self::Word, n: u32) -> Self::Word;
+
+    /// Wrapping sum of the terms
+    fn sum(&self, terms: &[&Self::Word]) -> Self::Word;
+}
+
+/// Words encrypted in radix ciphertexts, as in [FheUint32](crate::FheUint32) and
+/// [FheUint64](crate::FheUint64)
+struct RadixWords<'a> {
+    server_key: &'a ServerKey,
+    bits: u32,
+}
+
+impl<'a> RadixWords<'a> {
+    fn new(server_key: &'a ServerKey, bits: u32) -> Self {
+        Self { server_key, bits }
+    }
+}
+
+impl WordOps for RadixWords<'_> {
+    type Word = RadixCiphertext;
+
+    fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    fn trivial(&self, value: u64) -> RadixCiphertext {
+        let num_blocks = self.bits / self.server_key.message_modulus().0.ilog2();
+        self.server_key
+            .create_trivial_radix(value, num_blocks as usize)
+    }
+
+    fn xor(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
+        self.server_key.bitxor_parallelized(lhs, rhs)
+    }
+
+    fn and(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
+        self.server_key.bitand_parallelized(lhs, rhs)
+    }
+
+    fn not(&self, word: &RadixCiphertext) -> RadixCiphertext {
+        self.server_key.bitnot(word)
+    }
+
+    fn xor_scalar(&self, word: &RadixCiphertext, value: u64) -> RadixCiphertext {
+        self.server_key.scalar_bitxor_parallelized(word, value)
+    }
+
+    fn rotate_right(&self, word: &RadixCiphertext, n: u32) -> RadixCiphertext {
+        self.server_key.scalar_rotate_right_parallelized(word, n)
+    }
+
+    fn shift_right(&self, word: &RadixCiphertext, n: u32) -> RadixCiphertext {
+        self.server_key.scalar_right_shift_parallelized(word, n)
+    }
+
+    fn sum(&self, terms: &[&RadixCiphertext]) -> RadixCiphertext {
+        self.server_key
+            .sum_ciphertexts_parallelized(terms.iter().copied())
+            .expect("Cannot sum an empty slice")
+    }
+}
+
+/// Words of `N` encrypted bits, the least significant bit being the first one
+struct BitWords<'a, const N: usize> {
+    server_key: &'a ServerKey,
+}
+
+impl<'a, const N: usize> BitWords<'a, N> {
+    fn new(server_key: &'a ServerKey) -> Self {
+        Self { server_key }
+    }
+
+    /// Adds two words with a Kogge-Stone adder, which computes the carries in `log2(N)` steps
+    /// of independent operations
+    fn add(&self, lhs: &[BooleanBlock; N], rhs: &[BooleanBlock; N]) -> [BooleanBlock; N] {
+        let sks = self.server_key;
+
+        // A carry is generated at bit i when both bits are set, and propagated when exactly one
+        // is
+        let (mut generate, propagate): (Vec<_>, Vec<_>) = lhs
+            .par_iter()
+            .zip(rhs.par_iter())
+            .map(|(a, b)| (sks.boolean_bitand(a, b), sks.boolean_bitxor(a, b)))
+            .unzip();
+
+        // After the step with distance d, generate[i] and group_propagate[i] describe the bits
+        // i + 1 - 2d..=i
+        let mut group_propagate = propagate.clone();
+        let mut distance = 1;
+        while distance < N {
+            (generate, group_propagate) = (0..N)
+                .into_par_iter()
+                .map(|i| {
+                    if i < distance {
+                        (generate[i].clone(), group_propagate[i].clone())
+                    } else {
+                        let carried =
+                            sks.boolean_bitand(&group_propagate[i], &generate[i - distance]);
+                        (
+                            sks.boolean_bitor(&generate[i], &carried),
+                            sks.boolean_bitand(&group_propagate[i], &group_propagate[i - distance]),
+                        )
+                    }
+                })
+                .unzip();
+            distance *= 2;
+        }
+
+        // The carry into bit i is the one generated by the bits below it
+        collect_array(propagate.par_iter().enumerate().map(|(i, propagate)| {
+            if i == 0 {
+                propagate.clone()
+            } else {
+                sks.boolean_bitxor(propagate, &generate[i - 1])
+            }
+        }))
+    }
+}
+
+impl<const N: usize> WordOps for BitWords<'_, N> {
+    type Word = [BooleanBlock; N];
+
+    fn bits(&self) -> u32 {
+        N as u32
+    }
+
+    fn trivial(&self, value: u64) -> Self::Word {
+        std::array::from_fn(|i| {
+            self.server_key
+                .create_trivial_boolean_block(i < 64 && (value >> i) & 1 == 1)
+        })
+    }
+
+    fn xor(&self, lhs: &Self::Word, rhs: &Self::Word) -> Self::Word {
+        collect_array(
+            lhs.par_iter()
+                .zip(rhs.par_iter())
+                .map(|(a, b)| self.server_key.boolean_bitxor(a, b)),
+        )
+    }
+
+    fn and(&self, lhs: &Self::Word, rhs: &Self::Word) -> Self::Word {
+        collect_array(
+            lhs.par_iter()
+                .zip(rhs.par_iter())
+                .map(|(a, b)| self.server_key.boolean_bitand(a, b)),
+        )
+    }
+
+    fn not(&self, word: &Self::Word) -> Self::Word {
+        collect_array(
+            word.par_iter()
+                .map(|bit| self.server_key.boolean_bitnot(bit)),
+        )
+    }
+
+    fn xor_scalar(&self, word: &Self::Word, value: u64) -> Self::Word {
+        collect_array(word.par_iter().enumerate().map(|(i, bit)| {
+            if i < 64 && (value >> i) & 1 == 1 {
+                self.server_key.boolean_bitnot(bit)
+            } else {
+                bit.clone()
+            }
+        }))
+    }
+
+    fn rotate_right(&self, word: &Self::Word, n: u32) -> Self::Word {
+        std::array::from_fn(|i| word[(i + n as usize) % N].clone())
+    }
+
+    fn shift_right(&self, word: &Self::Word, n: u32) -> Self::Word {
+        std::array::from_fn(|i| {
+            word.get(i + n as usize)
+                .cloned()
+                .unwrap_or_else(|| self.server_key.create_trivial_boolean_block(false))
+        })
+    }
+
+    fn sum(&self, terms: &[&Self::Word]) -> Self::Word {
+        let (first, others) = terms.split_first().expect("Cannot sum an empty slice");
+        others
+            .iter()
+            .fold((*first).clone(), |sum, term| self.add(&sum, term))
+    }
+}
+
+fn collect_array<T, const N: usize>(items: impl IndexedParallelIterator<Item = T>) -> [T; N]
+where
+    T: Send,
+{
+    let items = items.collect::<Vec<_>>();
+    items.try_into().unwrap_or_else(|_| unreachable!())
+}
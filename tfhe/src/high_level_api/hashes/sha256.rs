@@ -0,0 +1,172 @@
+use super::{BitWords, RadixWords, WordOps};
+use crate::high_level_api::global_state::with_cpu_internal_keys;
+use crate::{FheBool, FheUint32};
+use std::array;
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const INIT: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Computes the SHA-256 digest of a message of `len` bytes, packed in big endian words
+///
+/// The bytes of the last word beyond the length of the message are ignored.
+///
+/// # Panics
+///
+/// Panics if the message does not have `len.div_ceil(4)` words.
+pub fn sha256(message: &[FheUint32], len: usize) -> [FheUint32; 8] {
+    assert_eq!(
+        message.len(),
+        len.div_ceil(4),
+        "A message of {len} bytes is packed in {} words, got {} words",
+        len.div_ceil(4),
+        message.len()
+    );
+
+    with_cpu_internal_keys(|keys| {
+        let sks = keys.pbs_key();
+        let mut words = message
+            .iter()
+            .map(|word| word.ciphertext.on_cpu().into_owned())
+            .collect::<Vec<_>>();
+        if len % 4 != 0 {
+            let last = words.last_mut().unwrap();
+            *last = sks.scalar_bitand_parallelized(last, u32::MAX << (8 * (4 - len % 4)));
+        }
+
+        let digest = hash(&RadixWords::new(sks, 32), words, len as u64 * 8);
+        digest.map(FheUint32::new)
+    })
+}
+
+/// Computes the SHA-256 digest of a message given bit by bit
+pub fn sha256_bools(message: &[FheBool]) -> [FheBool; 256] {
+    with_cpu_internal_keys(|keys| {
+        let sks = keys.pbs_key();
+        let words = message
+            .chunks(32)
+            .map(|bits| {
+                // The first bit is the most significant one
+                array::from_fn(|i| {
+                    bits.get(31 - i).map_or_else(
+                        || sks.create_trivial_boolean_block(false),
+                        |bit| bit.ciphertext.on_cpu().into_owned(),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let digest = hash(&BitWords::<32>::new(sks), words, message.len() as u64);
+        let bits = digest
+            .iter()
+            .flat_map(|word| word.iter().rev().cloned().map(FheBool::new))
+            .collect::<Vec<_>>();
+        bits.try_into().unwrap_or_else(|_| unreachable!())
+    })
+}
+
+/// Pads the message of `num_bits` bits and hashes it, the bits of the last word beyond the
+/// message must be zeros
+fn hash<O: WordOps>(ops: &O, mut words: Vec<O::Word>, num_bits: u64) -> [O::Word; 8] {
+    let remainder = num_bits % 32;
+    if remainder == 0 {
+        words.push(ops.trivial(1 << 31));
+    } else {
+        let last = words.last_mut().unwrap();
+        *last = ops.xor_scalar(last, 1 << (31 - remainder));
+    }
+    while words.len() % 16 != 14 {
+        words.push(ops.trivial(0));
+    }
+    words.push(ops.trivial(num_bits >> 32));
+    words.push(ops.trivial(num_bits & u64::from(u32::MAX)));
+
+    let mut state = INIT.map(|value| ops.trivial(value.into()));
+    for block in words.chunks_exact(16) {
+        compress(ops, &mut state, block);
+    }
+    state
+}
+
+fn sigma<O: WordOps>(ops: &O, word: &O::Word, rotations: [u32; 2], shift: u32) -> O::Word {
+    let ((a, b), c) = rayon::join(
+        || {
+            rayon::join(
+                || ops.rotate_right(word, rotations[0]),
+                || ops.rotate_right(word, rotations[1]),
+            )
+        },
+        || ops.shift_right(word, shift),
+    );
+    ops.xor(&ops.xor(&a, &b), &c)
+}
+
+fn big_sigma<O: WordOps>(ops: &O, word: &O::Word, rotations: [u32; 3]) -> O::Word {
+    let ((a, b), c) = rayon::join(
+        || {
+            rayon::join(
+                || ops.rotate_right(word, rotations[0]),
+                || ops.rotate_right(word, rotations[1]),
+            )
+        },
+        || ops.rotate_right(word, rotations[2]),
+    );
+    ops.xor(&ops.xor(&a, &b), &c)
+}
+
+fn compress<O: WordOps>(ops: &O, state: &mut [O::Word; 8], block: &[O::Word]) {
+    let mut w = block.to_vec();
+    for i in 16..64 {
+        let (s0, s1) = rayon::join(
+            || sigma(ops, &w[i - 15], [7, 18], 3),
+            || sigma(ops, &w[i - 2], [17, 19], 10),
+        );
+        w.push(ops.sum(&[&w[i - 16], &s0, &w[i - 7], &s1]));
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state.clone();
+    for (k, w) in K.iter().zip(&w) {
+        // ch = (e & f) ^ (!e & g) and maj = (a & b) ^ (a & c) ^ (b & c) are rewritten to need
+        // fewer operations
+        let ((s1, ch), (s0, maj)) = rayon::join(
+            || {
+                rayon::join(
+                    || big_sigma(ops, &e, [6, 11, 25]),
+                    || ops.xor(&g, &ops.and(&e, &ops.xor(&f, &g))),
+                )
+            },
+            || {
+                rayon::join(
+                    || big_sigma(ops, &a, [2, 13, 22]),
+                    || ops.xor(&ops.and(&a, &ops.xor(&b, &c)), &ops.and(&b, &c)),
+                )
+            },
+        );
+
+        let t1 = ops.sum(&[&h, &s1, &ch, &ops.trivial((*k).into()), w]);
+        let (new_e, new_a) = rayon::join(|| ops.sum(&[&d, &t1]), || ops.sum(&[&t1, &s0, &maj]));
+
+        h = g;
+        g = f;
+        f = e;
+        e = new_e;
+        d = c;
+        c = b;
+        b = a;
+        a = new_a;
+    }
+
+    let new_state = [a, b, c, d, e, f, g, h];
+    *state = array::from_fn(|i| ops.sum(&[&state[i], &new_state[i]]));
+}
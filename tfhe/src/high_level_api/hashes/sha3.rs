@@ -0,0 +1,210 @@
+use super::{collect_array, BitWords, RadixWords, WordOps};
+use crate::high_level_api::global_state::with_cpu_internal_keys;
+use crate::{FheBool, FheUint64};
+use rayon::prelude::*;
+use std::array;
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Rotation offsets of the rho step, for the lane `x + 5 * y`
+const ROTATIONS: [u32; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// Number of lanes absorbed per permutation by SHA3-256, whose capacity is 512 bits
+const RATE: usize = 17;
+
+/// Applies the Keccak-f\[1600\] permutation to the state, the lane `(x, y)` being at index
+/// `x + 5 * y`
+pub fn keccak_f1600(state: &mut [FheUint64; 25]) {
+    with_cpu_internal_keys(|keys| {
+        let mut lanes = array::from_fn(|i| state[i].ciphertext.on_cpu().into_owned());
+        permute(&RadixWords::new(keys.pbs_key(), 64), &mut lanes);
+        *state = lanes.map(FheUint64::new);
+    });
+}
+
+/// Computes the SHA3-256 digest of a message of `len` bytes, packed in little endian words
+///
+/// The bytes of the last word beyond the length of the message are ignored. The digest is
+/// returned in little endian words as well.
+///
+/// # Panics
+///
+/// Panics if the message does not have `len.div_ceil(8)` words.
+pub fn sha3_256(message: &[FheUint64], len: usize) -> [FheUint64; 4] {
+    assert_eq!(
+        message.len(),
+        len.div_ceil(8),
+        "A message of {len} bytes is packed in {} words, got {} words",
+        len.div_ceil(8),
+        message.len()
+    );
+
+    with_cpu_internal_keys(|keys| {
+        let sks = keys.pbs_key();
+        let mut lanes = message
+            .iter()
+            .map(|lane| lane.ciphertext.on_cpu().into_owned())
+            .collect::<Vec<_>>();
+        if len % 8 != 0 {
+            let last = lanes.last_mut().unwrap();
+            *last = sks.scalar_bitand_parallelized(last, u64::MAX >> (8 * (8 - len % 8)));
+        }
+
+        let digest = hash(&RadixWords::new(sks, 64), lanes, len);
+        digest.map(FheUint64::new)
+    })
+}
+
+/// Computes the SHA3-256 digest of a message given bit by bit
+///
+/// # Panics
+///
+/// Panics if the message is not made of whole bytes.
+pub fn sha3_256_bools(message: &[FheBool]) -> [FheBool; 256] {
+    assert_eq!(
+        message.len() % 8,
+        0,
+        "SHA3-256 is only implemented for messages of whole bytes"
+    );
+
+    with_cpu_internal_keys(|keys| {
+        let sks = keys.pbs_key();
+        // The bytes are in little endian order in the lanes, while the bits of each byte are
+        // given most significant first
+        let lanes = message
+            .chunks(64)
+            .map(|bits| {
+                array::from_fn(|i| {
+                    bits.get(8 * (i / 8) + 7 - i % 8).map_or_else(
+                        || sks.create_trivial_boolean_block(false),
+                        |bit| bit.ciphertext.on_cpu().into_owned(),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let digest = hash(&BitWords::<64>::new(sks), lanes, message.len() / 8);
+        collect_array((0..256).into_par_iter().map(|i| {
+            let lane = &digest[i / 64];
+            FheBool::new(lane[8 * ((i % 64) / 8) + 7 - i % 8].clone())
+        }))
+    })
+}
+
+/// Pads the message of `len` bytes and hashes it, the bytes of the last lane beyond the message
+/// must be zeros
+fn hash<O: WordOps>(ops: &O, mut lanes: Vec<O::Word>, len: usize) -> [O::Word; 4] {
+    // The message is followed by the bits 01 of the domain and the 10*1 padding
+    let remainder = len % 8;
+    if remainder == 0 {
+        lanes.push(ops.trivial(0x06));
+    } else {
+        let last = lanes.last_mut().unwrap();
+        *last = ops.xor_scalar(last, 0x06 << (8 * remainder));
+    }
+    while lanes.len() % RATE != 0 {
+        lanes.push(ops.trivial(0));
+    }
+    let last = lanes.last_mut().unwrap();
+    *last = ops.xor_scalar(last, 0x80 << 56);
+
+    let mut state: Option<[O::Word; 25]> = None;
+    for block in lanes.chunks_exact(RATE) {
+        // The initial state is zero, the first block is simply copied
+        let mut new_state = state.map_or_else(
+            || array::from_fn(|i| block.get(i).cloned().unwrap_or_else(|| ops.trivial(0))),
+            |state| {
+                collect_array(state.into_par_iter().enumerate().map(|(i, lane)| {
+                    block
+                        .get(i)
+                        .map_or_else(|| lane.clone(), |word| ops.xor(&lane, word))
+                }))
+            },
+        );
+        permute(ops, &mut new_state);
+        state = Some(new_state);
+    }
+
+    let state = state.unwrap();
+    array::from_fn(|i| state[i].clone())
+}
+
+fn permute<O: WordOps>(ops: &O, state: &mut [O::Word; 25]) {
+    for round_constant in ROUND_CONSTANTS {
+        // Theta, each lane is xored with the parities of two columns
+        let parities = (0..5)
+            .into_par_iter()
+            .map(|x| {
+                let (a, b) = rayon::join(
+                    || ops.xor(&state[x], &state[x + 5]),
+                    || ops.xor(&state[x + 10], &state[x + 15]),
+                );
+                ops.xor(&ops.xor(&a, &b), &state[x + 20])
+            })
+            .collect::<Vec<_>>();
+        let effects = (0..5)
+            .into_par_iter()
+            .map(|x| {
+                let rotated = ops.rotate_left(&parities[(x + 1) % 5], 1);
+                ops.xor(&parities[(x + 4) % 5], &rotated)
+            })
+            .collect::<Vec<_>>();
+
+        // Rho and pi, the lanes are rotated and moved to (y, 2x + 3y)
+        let lanes = state
+            .par_iter()
+            .enumerate()
+            .map(|(i, lane)| {
+                let lane = ops.xor(lane, &effects[i % 5]);
+                if ROTATIONS[i] == 0 {
+                    lane
+                } else {
+                    ops.rotate_left(&lane, ROTATIONS[i])
+                }
+            })
+            .collect::<Vec<_>>();
+        let moved: [&O::Word; 25] = array::from_fn(|i| {
+            let (x, y) = (i % 5, i / 5);
+            // (x, y) is where the lane (x', y') = ((x + 3y) % 5, x) lands
+            &lanes[(x + 3 * y) % 5 + 5 * x]
+        });
+
+        // Chi, the only non linear step
+        *state = collect_array((0..25).into_par_iter().map(|i| {
+            let (x, y) = (i % 5, i / 5);
+            let next = moved[(x + 1) % 5 + 5 * y];
+            let next_next = moved[(x + 2) % 5 + 5 * y];
+            ops.xor(moved[i], &ops.and(&ops.not(next), next_next))
+        }));
+
+        // Iota
+        state[0] = ops.xor_scalar(&state[0], round_constant);
+    }
+}
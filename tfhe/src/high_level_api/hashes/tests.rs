@@ -0,0 +1,171 @@
+use super::*;
+use crate::high_level_api::prelude::*;
+use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder};
+use crate::{FheBool, FheUint32, FheUint64};
+
+/// The messages and their SHA-256 and SHA3-256 digests, the longest ones needing several blocks
+const TEST_VECTORS: [(&[u8], &str, &str); 4] = [
+    (
+        b"",
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a",
+    ),
+    (
+        b"abc",
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+    ),
+    (
+        b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+        "41c0dba2a9d6240849100376a8235e2c82e1b9998a999e21db32dd97496d3376",
+    ),
+    (
+        &[0xa3; 200],
+        "8edfea24cad8f0e6da7fb5ebde442300fdf913be8a3bdfce06557e34d8f4efa3",
+        "79f38adec5c20307a98ef76e8324afbfd46cfd81b22e3973c65fa1bd9de31787",
+    ),
+];
+
+// The hashes need many sequential operations, so most tests use trivial ciphertexts to stay
+// fast. Operations on trivial ciphertexts are computed in clear instead of with bootstraps, the
+// `_encrypted` tests check one block of each hash on encrypted inputs.
+fn setup_default_cpu() {
+    let (_, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+}
+
+fn hex(bytes: impl IntoIterator<Item = u8>) -> String {
+    bytes
+        .into_iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Pads the bytes with ones, which must be ignored by the hash functions
+fn padded_words<const N: usize>(message: &[u8]) -> impl Iterator<Item = [u8; N]> + '_ {
+    message
+        .chunks(N)
+        .map(|bytes| std::array::from_fn(|i| bytes.get(i).copied().unwrap_or(0xff)))
+}
+
+fn to_bools(message: &[u8]) -> Vec<FheBool> {
+    message
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+        .map(FheBool::encrypt_trivial)
+        .collect()
+}
+
+fn from_bools(bits: &[FheBool]) -> Vec<u8> {
+    bits.chunks(8)
+        .map(|bits| {
+            bits.iter().fold(0, |byte, bit| {
+                (byte << 1) | u8::from(bit.try_decrypt_trivial().unwrap())
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn test_sha256() {
+    setup_default_cpu();
+
+    for (message, expected, _) in TEST_VECTORS {
+        let words = padded_words(message)
+            .map(|bytes| FheUint32::encrypt_trivial(u32::from_be_bytes(bytes)))
+            .collect::<Vec<_>>();
+        let digest = sha256(&words, message.len());
+        let digest = digest
+            .iter()
+            .flat_map(|word| word.try_decrypt_trivial::<u32>().unwrap().to_be_bytes());
+        assert_eq!(hex(digest), expected);
+    }
+
+    // A node of a Merkle tree is the hash of the digests of its children
+    let [left, right] = [&b"left"[..], b"right"].map(|leaf| {
+        let words = padded_words(leaf)
+            .map(|bytes| FheUint32::encrypt_trivial(u32::from_be_bytes(bytes)))
+            .collect::<Vec<_>>();
+        sha256(&words, leaf.len())
+    });
+    let children = left.into_iter().chain(right).collect::<Vec<_>>();
+    let root = sha256(&children, 64);
+    let root = root
+        .iter()
+        .flat_map(|word| word.try_decrypt_trivial::<u32>().unwrap().to_be_bytes());
+    assert_eq!(
+        hex(root),
+        "2a9870f5b7eb1cd732d95224cfea825a7b8772136cb497b20d2e3c612dfc90fe"
+    );
+}
+
+#[test]
+fn test_sha256_encrypted() {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    let (message, expected, _) = TEST_VECTORS[1];
+    let words = padded_words(message)
+        .map(|bytes| FheUint32::encrypt(u32::from_be_bytes(bytes), &client_key))
+        .collect::<Vec<_>>();
+    let digest = sha256(&words, message.len());
+    let digest = digest.iter().flat_map(|word| {
+        let word: u32 = word.decrypt(&client_key);
+        word.to_be_bytes()
+    });
+    assert_eq!(hex(digest), expected);
+}
+
+#[test]
+fn test_sha256_bools() {
+    setup_default_cpu();
+
+    for (message, expected, _) in &TEST_VECTORS[..3] {
+        let digest = sha256_bools(&to_bools(message));
+        assert_eq!(hex(from_bools(&digest)), *expected);
+    }
+}
+
+#[test]
+fn test_sha3_256() {
+    setup_default_cpu();
+
+    for (message, _, expected) in TEST_VECTORS {
+        let words = padded_words(message)
+            .map(|bytes| FheUint64::encrypt_trivial(u64::from_le_bytes(bytes)))
+            .collect::<Vec<_>>();
+        let digest = sha3_256(&words, message.len());
+        let digest = digest
+            .iter()
+            .flat_map(|word| word.try_decrypt_trivial::<u64>().unwrap().to_le_bytes());
+        assert_eq!(hex(digest), expected);
+    }
+}
+
+#[test]
+fn test_sha3_256_encrypted() {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    let (message, _, expected) = TEST_VECTORS[1];
+    let words = padded_words(message)
+        .map(|bytes| FheUint64::encrypt(u64::from_le_bytes(bytes), &client_key))
+        .collect::<Vec<_>>();
+    let digest = sha3_256(&words, message.len());
+    let digest = digest.iter().flat_map(|word| {
+        let word: u64 = word.decrypt(&client_key);
+        word.to_le_bytes()
+    });
+    assert_eq!(hex(digest), expected);
+}
+
+#[test]
+fn test_sha3_256_bools() {
+    setup_default_cpu();
+
+    for (message, _, expected) in &TEST_VECTORS[..3] {
+        let digest = sha3_256_bools(&to_bools(message));
+        assert_eq!(hex(from_bools(&digest)), *expected);
+    }
+}
@@ -0,0 +1,279 @@
+use crate::core_crypto::prelude::CastFrom;
+use crate::high_level_api::global_state;
+use crate::high_level_api::integers::FheUintId;
+use crate::integer::ciphertext::max_crt_modulus;
+use crate::integer::CrtCiphertext;
+use crate::named::Named;
+use crate::shortint::{CarryModulus, MessageModulus};
+use crate::FheUint;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Trait to mark ID type for CRT integers
+///
+/// The ID gives the moduli of the CRT basis, which must be pairwise coprime, and each small
+/// enough so that two residues can be added in a block without a carry propagation
+/// (at most 7 with the default parameters).
+/// The integer is computed modulo the product of the moduli.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::FheUintCrtId;
+///
+/// // The product of the moduli is 420, which can hold values with a bit more than 8 bits
+/// #[derive(Copy, Clone, Default)]
+/// struct Basis420;
+///
+/// impl FheUintCrtId for Basis420 {
+///     const MODULI: &'static [u64] = &[3, 4, 5, 7];
+/// }
+/// ```
+// The 'static restrains implementor from holding non-static refs
+// which is ok as it is meant to be impld by zero sized types.
+pub trait FheUintCrtId: Copy + Default + Send + Sync + 'static {
+    const MODULI: &'static [u64];
+}
+
+/// Checks that the moduli of the basis can be used with the given parameters
+pub(in crate::high_level_api) fn check_moduli<Id: FheUintCrtId>(
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+) -> crate::Result<()> {
+    let moduli = Id::MODULI;
+    if moduli.is_empty() {
+        return Err(crate::Error::new(
+            "A CRT basis must have at least one modulus".to_string(),
+        ));
+    }
+
+    let max_modulus = max_crt_modulus(message_modulus, carry_modulus);
+    if let Some(modulus) = moduli
+        .iter()
+        .find(|&&modulus| !(2..=max_modulus).contains(&modulus))
+    {
+        return Err(crate::Error::new(format!(
+            "The CRT modulus {modulus} is not in the range [2, {max_modulus}] allowed by the \
+            parameters"
+        )));
+    }
+
+    for (i, &lhs) in moduli.iter().enumerate() {
+        for &rhs in &moduli[i + 1..] {
+            if gcd(lhs, rhs) != 1 {
+                return Err(crate::Error::new(format!(
+                    "The CRT moduli {lhs} and {rhs} are not coprime"
+                )));
+            }
+        }
+    }
+
+    if moduli
+        .iter()
+        .try_fold(1u64, |product, modulus| product.checked_mul(*modulus))
+        .is_none()
+    {
+        return Err(crate::Error::new(
+            "The product of the CRT moduli does not fit in a u64".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// A FHE unsigned integer in CRT representation
+///
+/// The integer is stored as its residues modulo each modulus of the basis given by the `Id`
+/// (see [FheUintCrtId]), and computations are done modulo the product of the moduli.
+///
+/// Additions, subtractions and multiplications are done independently on each residue, so they
+/// are much cheaper than on a [FheUint] of similar precision, especially the multiplication.
+/// Other operations are done by casting to a [FheUint].
+///
+/// [FheUint]: crate::FheUint
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUintCrt, FheUintCrtId};
+///
+/// #[derive(Copy, Clone, Default)]
+/// struct Basis420;
+///
+/// impl FheUintCrtId for Basis420 {
+///     const MODULI: &'static [u64] = &[3, 4, 5, 7];
+/// }
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheUintCrt::<Basis420>::encrypt(27u64, &client_key);
+/// let b = FheUintCrt::<Basis420>::encrypt(31u64, &client_key);
+///
+/// let c = &a * &b + 2u64;
+///
+/// let decrypted: u64 = c.decrypt(&client_key);
+/// assert_eq!(decrypted, (27 * 31 + 2) % 420);
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "FheUintCrtData<Id>")]
+pub struct FheUintCrt<Id: FheUintCrtId> {
+    pub(in crate::high_level_api) ciphertext: CrtCiphertext,
+    pub(in crate::high_level_api) id: PhantomData<Id>,
+}
+
+/// The fields of a [FheUintCrt], deserialized before checking the moduli of the ciphertext
+#[derive(Deserialize)]
+struct FheUintCrtData<Id> {
+    ciphertext: CrtCiphertext,
+    id: PhantomData<Id>,
+}
+
+impl<Id: FheUintCrtId> TryFrom<FheUintCrtData<Id>> for FheUintCrt<Id> {
+    type Error = crate::Error;
+
+    fn try_from(data: FheUintCrtData<Id>) -> Result<Self, Self::Error> {
+        let FheUintCrtData { ciphertext, id } = data;
+        if ciphertext.moduli != Id::MODULI {
+            return Err(crate::Error::new(format!(
+                "The moduli {:?} of the ciphertext do not match the moduli {:?} of the type",
+                ciphertext.moduli,
+                Id::MODULI
+            )));
+        }
+        Ok(Self { ciphertext, id })
+    }
+}
+
+impl<Id: FheUintCrtId> Named for FheUintCrt<Id> {
+    const NAME: &'static str = "high_level_api::FheUintCrt";
+}
+
+impl<Id: FheUintCrtId> FheUintCrt<Id> {
+    pub(in crate::high_level_api) fn new(ciphertext: CrtCiphertext) -> Self {
+        Self {
+            ciphertext,
+            id: PhantomData,
+        }
+    }
+
+    /// Returns the product of the moduli, the values are computed modulo this number
+    pub fn modulus() -> u64 {
+        Id::MODULI.iter().product()
+    }
+
+    pub fn into_raw_parts(self) -> CrtCiphertext {
+        self.ciphertext
+    }
+
+    /// Creates a CRT integer from a ciphertext
+    ///
+    /// # Panics
+    ///
+    /// Panics if the moduli of the ciphertext are not the ones of the `Id`.
+    pub fn from_raw_parts(ciphertext: CrtCiphertext) -> Self {
+        assert_eq!(
+            ciphertext.moduli,
+            Id::MODULI,
+            "The moduli of the ciphertext do not match the moduli of the type"
+        );
+        Self::new(ciphertext)
+    }
+
+    /// Returns the moduli of the basis
+    pub fn moduli(&self) -> &[u64] {
+        Id::MODULI
+    }
+}
+
+impl<FromId, IntoId> CastFrom<FheUint<FromId>> for FheUintCrt<IntoId>
+where
+    FromId: FheUintId,
+    IntoId: FheUintCrtId,
+{
+    /// Cast a FheUint to a FheUintCrt
+    ///
+    /// The value is reduced modulo the product of the moduli.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUintCrt, FheUintCrtId};
+    ///
+    /// #[derive(Copy, Clone, Default)]
+    /// struct Basis420;
+    ///
+    /// impl FheUintCrtId for Basis420 {
+    ///     const MODULI: &'static [u64] = &[3, 4, 5, 7];
+    /// }
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(1000u16, &client_key);
+    /// let a = FheUintCrt::<Basis420>::cast_from(a);
+    ///
+    /// let decrypted: u64 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, 1000 % 420);
+    /// ```
+    fn cast_from(input: FheUint<FromId>) -> Self {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let sks = cpu_key.pbs_key();
+            check_moduli::<IntoId>(sks.message_modulus(), sks.carry_modulus()).unwrap();
+            let ciphertext =
+                sks.radix_to_crt_parallelized(&input.ciphertext.into_cpu(), IntoId::MODULI);
+            Self::new(ciphertext)
+        })
+    }
+}
+
+impl<FromId, IntoId> CastFrom<FheUintCrt<FromId>> for FheUint<IntoId>
+where
+    FromId: FheUintCrtId,
+    IntoId: FheUintId,
+{
+    /// Cast a FheUintCrt to a FheUint
+    ///
+    /// The value is truncated if it does not fit in the FheUint.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUintCrt, FheUintCrtId};
+    ///
+    /// #[derive(Copy, Clone, Default)]
+    /// struct Basis420;
+    ///
+    /// impl FheUintCrtId for Basis420 {
+    ///     const MODULI: &'static [u64] = &[3, 4, 5, 7];
+    /// }
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUintCrt::<Basis420>::encrypt(313u64, &client_key);
+    /// let a = FheUint16::cast_from(a);
+    ///
+    /// let decrypted: u16 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, 313);
+    /// ```
+    fn cast_from(input: FheUintCrt<FromId>) -> Self {
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            let ciphertext = cpu_key.pbs_key().crt_to_radix_parallelized(
+                &input.ciphertext,
+                IntoId::num_blocks(cpu_key.message_modulus()),
+            );
+            Self::new(ciphertext)
+        })
+    }
+}
@@ -0,0 +1,98 @@
+use super::base::{check_moduli, FheUintCrt, FheUintCrtId};
+use crate::integer::ciphertext::CompactCiphertextList;
+use crate::prelude::{FheDecrypt, FheTryEncrypt};
+use crate::{ClientKey, CompactPublicKey, CompressedPublicKey, PublicKey};
+
+impl<Id: FheUintCrtId> FheDecrypt<u64> for FheUintCrt<Id> {
+    /// Decrypts a [FheUintCrt], the result is in the range [0, product of the moduli)
+    fn decrypt(&self, key: &ClientKey) -> u64 {
+        key.key.key.decrypt_crt(&self.ciphertext)
+    }
+}
+
+impl<Id: FheUintCrtId> FheTryEncrypt<u64, ClientKey> for FheUintCrt<Id> {
+    type Error = crate::Error;
+
+    /// Encrypts the value reduced modulo the product of the moduli
+    ///
+    /// Returns an error if the moduli cannot be used with the parameters of the key.
+    fn try_encrypt(value: u64, key: &ClientKey) -> Result<Self, Self::Error> {
+        let params = key.key.block_parameters();
+        check_moduli::<Id>(params.message_modulus(), params.carry_modulus())?;
+        Ok(Self::new(
+            key.key.key.encrypt_crt(value, Id::MODULI.to_vec()),
+        ))
+    }
+}
+
+impl<Id: FheUintCrtId> FheTryEncrypt<u64, PublicKey> for FheUintCrt<Id> {
+    type Error = crate::Error;
+
+    /// Encrypts the value reduced modulo the product of the moduli
+    ///
+    /// Returns an error if the moduli cannot be used with the parameters of the key.
+    fn try_encrypt(value: u64, key: &PublicKey) -> Result<Self, Self::Error> {
+        let params = key.key.parameters();
+        check_moduli::<Id>(params.message_modulus(), params.carry_modulus())?;
+        Ok(Self::new(key.key.encrypt_crt(value, Id::MODULI.to_vec())))
+    }
+}
+
+impl<Id: FheUintCrtId> FheTryEncrypt<u64, CompressedPublicKey> for FheUintCrt<Id> {
+    type Error = crate::Error;
+
+    /// Encrypts the value reduced modulo the product of the moduli
+    ///
+    /// Returns an error if the moduli cannot be used with the parameters of the key.
+    fn try_encrypt(value: u64, key: &CompressedPublicKey) -> Result<Self, Self::Error> {
+        let params = key.key.parameters();
+        check_moduli::<Id>(params.message_modulus(), params.carry_modulus())?;
+        Ok(Self::new(key.key.encrypt_crt(value, Id::MODULI.to_vec())))
+    }
+}
+
+impl<Id: FheUintCrtId> FheTryEncrypt<u64, CompactPublicKey> for FheUintCrt<Id> {
+    type Error = crate::Error;
+
+    /// Encrypts the value reduced modulo the product of the moduli
+    ///
+    /// The value is encrypted in a compact list, which is expanded right away. To encrypt many
+    /// values, pushing them in a [CompactCiphertextList](crate::CompactCiphertextList) is more
+    /// efficient.
+    ///
+    /// Returns an error if the moduli cannot be used with the parameters of the key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, CompactPublicKey, ConfigBuilder, FheUintCrt, FheUintCrtId};
+    ///
+    /// #[derive(Copy, Clone, Default)]
+    /// struct Basis420;
+    ///
+    /// impl FheUintCrtId for Basis420 {
+    ///     const MODULI: &'static [u64] = &[3, 4, 5, 7];
+    /// }
+    ///
+    /// let (client_key, _) = generate_keys(ConfigBuilder::default());
+    /// let public_key = CompactPublicKey::new(&client_key);
+    ///
+    /// let a = FheUintCrt::<Basis420>::encrypt(313u64, &public_key);
+    ///
+    /// let decrypted: u64 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, 313);
+    /// ```
+    fn try_encrypt(value: u64, key: &CompactPublicKey) -> Result<Self, Self::Error> {
+        let params = key.key.key.key.parameters;
+        check_moduli::<Id>(params.message_modulus, params.carry_modulus)?;
+        let list = CompactCiphertextList::builder(&key.key.key)
+            .push_crt(value, Id::MODULI)
+            .build();
+        let ciphertext = list
+            .expand_without_unpacking()
+            .get_crt(0, Id::MODULI)
+            .unwrap()?;
+        Ok(Self::new(ciphertext))
+    }
+}
@@ -0,0 +1,8 @@
+pub(in crate::high_level_api) use base::check_moduli;
+pub use base::{FheUintCrt, FheUintCrtId};
+
+mod base;
+mod encrypt;
+mod ops;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,208 @@
+use super::base::{FheUintCrt, FheUintCrtId};
+use crate::high_level_api::global_state;
+use crate::integer::{CrtCiphertext, ServerKey};
+use crate::shortint::ciphertext::NoiseLevel;
+use std::borrow::Borrow;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Extracts the message of the residues of `ct` unless they are all clean
+///
+/// The correcting term of a subtraction and the packing of a multiplication may exceed the degree
+/// allowed by radix server keys while still fitting in the blocks, so these operations are done
+/// with unchecked operations on clean residues.
+fn clean_residues(sks: &ServerKey, ct: &mut CrtCiphertext) {
+    if ct
+        .blocks
+        .iter()
+        .any(|block| !block.carry_is_empty() || block.noise_level() > NoiseLevel::NOMINAL)
+    {
+        sks.full_extract_message_assign_parallelized(ct);
+    }
+}
+
+// Ciphertext/Ciphertext operators
+macro_rules! crt_impl_operation (
+    (
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident),
+        rust_trait_assign: $rust_trait_assign_name:ident($rust_trait_assign_method:ident),
+        implem: {
+            $closure:expr
+        }
+        $(,)?
+    ) => {
+        impl<Id, B> $rust_trait_name<B> for FheUintCrt<Id>
+        where
+            Id: FheUintCrtId,
+            B: Borrow<Self>,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(mut self, rhs: B) -> Self::Output {
+                <Self as $rust_trait_assign_name<B>>::$rust_trait_assign_method(&mut self, rhs);
+                self
+            }
+        }
+
+        impl<Id, B> $rust_trait_name<B> for &FheUintCrt<Id>
+        where
+            Id: FheUintCrtId,
+            B: Borrow<FheUintCrt<Id>>,
+        {
+            type Output = FheUintCrt<Id>;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                let mut result = self.clone();
+                <FheUintCrt<Id> as $rust_trait_assign_name<B>>::$rust_trait_assign_method(
+                    &mut result,
+                    rhs,
+                );
+                result
+            }
+        }
+
+        impl<Id, B> $rust_trait_assign_name<B> for FheUintCrt<Id>
+        where
+            Id: FheUintCrtId,
+            B: Borrow<Self>,
+        {
+            fn $rust_trait_assign_method(&mut self, rhs: B) {
+                // Smart operations may clean the carries of both operands
+                let mut rhs = rhs.borrow().ciphertext.clone();
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    $closure(cpu_key.pbs_key(), &mut self.ciphertext, &mut rhs)
+                });
+            }
+        }
+    }
+);
+
+// Ciphertext/Scalar operators
+macro_rules! crt_impl_scalar_operation (
+    (
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident),
+        rust_trait_assign: $rust_trait_assign_name:ident($rust_trait_assign_method:ident),
+        implem: {
+            $closure:expr
+        }
+        $(,)?
+    ) => {
+        impl<Id: FheUintCrtId> $rust_trait_name<u64> for FheUintCrt<Id> {
+            type Output = Self;
+
+            fn $rust_trait_method(mut self, rhs: u64) -> Self::Output {
+                <Self as $rust_trait_assign_name<u64>>::$rust_trait_assign_method(&mut self, rhs);
+                self
+            }
+        }
+
+        impl<Id: FheUintCrtId> $rust_trait_name<u64> for &FheUintCrt<Id> {
+            type Output = FheUintCrt<Id>;
+
+            fn $rust_trait_method(self, rhs: u64) -> Self::Output {
+                let mut result = self.clone();
+                <FheUintCrt<Id> as $rust_trait_assign_name<u64>>::$rust_trait_assign_method(
+                    &mut result,
+                    rhs,
+                );
+                result
+            }
+        }
+
+        impl<Id: FheUintCrtId> $rust_trait_assign_name<u64> for FheUintCrt<Id> {
+            fn $rust_trait_assign_method(&mut self, rhs: u64) {
+                global_state::with_cpu_internal_keys(|cpu_key| {
+                    $closure(cpu_key.pbs_key(), &mut self.ciphertext, rhs)
+                });
+            }
+        }
+    }
+);
+
+crt_impl_operation!(
+    rust_trait: Add(add),
+    rust_trait_assign: AddAssign(add_assign),
+    implem: {
+        |sks: &crate::integer::ServerKey, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext| {
+            sks.smart_crt_add_assign_parallelized(lhs, rhs);
+        }
+    },
+);
+
+crt_impl_operation!(
+    rust_trait: Sub(sub),
+    rust_trait_assign: SubAssign(sub_assign),
+    implem: {
+        |sks: &crate::integer::ServerKey, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext| {
+            rayon::join(|| clean_residues(sks, lhs), || clean_residues(sks, rhs));
+            sks.unchecked_crt_sub_assign_parallelized(lhs, rhs);
+        }
+    },
+);
+
+crt_impl_operation!(
+    rust_trait: Mul(mul),
+    rust_trait_assign: MulAssign(mul_assign),
+    implem: {
+        |sks: &crate::integer::ServerKey, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext| {
+            rayon::join(|| clean_residues(sks, lhs), || clean_residues(sks, rhs));
+            sks.unchecked_crt_mul_assign_parallelized(lhs, rhs);
+        }
+    },
+);
+
+crt_impl_scalar_operation!(
+    rust_trait: Add(add),
+    rust_trait_assign: AddAssign(add_assign),
+    implem: {
+        |sks: &crate::integer::ServerKey, lhs: &mut CrtCiphertext, rhs: u64| {
+            sks.smart_crt_scalar_add_assign_parallelized(lhs, rhs);
+        }
+    },
+);
+
+crt_impl_scalar_operation!(
+    rust_trait: Sub(sub),
+    rust_trait_assign: SubAssign(sub_assign),
+    implem: {
+        |sks: &crate::integer::ServerKey, lhs: &mut CrtCiphertext, rhs: u64| {
+            sks.smart_crt_scalar_sub_assign_parallelized(lhs, rhs);
+        }
+    },
+);
+
+crt_impl_scalar_operation!(
+    rust_trait: Mul(mul),
+    rust_trait_assign: MulAssign(mul_assign),
+    implem: {
+        |sks: &crate::integer::ServerKey, lhs: &mut CrtCiphertext, rhs: u64| {
+            // Residues whose product with the scalar overflows are multiplied with a PBS, which
+            // requires their carries to be empty
+            if sks.is_crt_scalar_mul_possible(lhs, rhs).is_err() {
+                sks.full_extract_message_assign_parallelized(lhs);
+            }
+            sks.unchecked_crt_scalar_mul_assign_parallelized(lhs, rhs);
+        }
+    },
+);
+
+impl<Id: FheUintCrtId> Neg for FheUintCrt<Id> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<Id: FheUintCrtId> Neg for &FheUintCrt<Id> {
+    type Output = FheUintCrt<Id>;
+
+    fn neg(self) -> Self::Output {
+        let mut ciphertext = self.ciphertext.clone();
+        global_state::with_cpu_internal_keys(|cpu_key| {
+            cpu_key
+                .pbs_key()
+                .smart_crt_neg_assign_parallelized(&mut ciphertext);
+        });
+        FheUintCrt::new(ciphertext)
+    }
+}
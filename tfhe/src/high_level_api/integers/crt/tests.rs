@@ -0,0 +1,225 @@
+use crate::conformance::ListSizeConstraint;
+use crate::high_level_api::prelude::*;
+use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder};
+use crate::safe_deserialization::safe_deserialize_conformant;
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+use crate::{
+    ClientKey, CompactCiphertextList, CompactCiphertextListConformanceParams, CompactPublicKey,
+    CompressedPublicKey, FheBool, FheUint16, FheUint8, FheUintCrt, FheUintCrtId, PublicKey,
+};
+
+#[derive(Copy, Clone, Default)]
+struct Basis420;
+
+impl FheUintCrtId for Basis420 {
+    const MODULI: &'static [u64] = &[3, 4, 5, 7];
+}
+
+#[derive(Copy, Clone, Default)]
+struct NotCoprimeBasis;
+
+impl FheUintCrtId for NotCoprimeBasis {
+    const MODULI: &'static [u64] = &[3, 6];
+}
+
+#[derive(Copy, Clone, Default)]
+struct TooLargeBasis;
+
+impl FheUintCrtId for TooLargeBasis {
+    const MODULI: &'static [u64] = &[3, 11];
+}
+
+type Crt420 = FheUintCrt<Basis420>;
+
+const MODULUS: u64 = 420;
+
+fn setup_default_cpu() -> ClientKey {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    client_key
+}
+
+#[test]
+fn test_crt_encrypt_decrypt() {
+    let client_key = setup_default_cpu();
+
+    let public_key = PublicKey::new(&client_key);
+    let compressed_public_key = CompressedPublicKey::new(&client_key);
+    let compact_public_key = CompactPublicKey::new(&client_key);
+
+    for value in [0u64, 1, 313, 839, 1000] {
+        let a = Crt420::encrypt(value, &client_key);
+        let decrypted: u64 = a.decrypt(&client_key);
+        assert_eq!(decrypted, value % MODULUS);
+
+        let a = Crt420::encrypt(value, &public_key);
+        let decrypted: u64 = a.decrypt(&client_key);
+        assert_eq!(decrypted, value % MODULUS);
+
+        let a = Crt420::encrypt(value, &compressed_public_key);
+        let decrypted: u64 = a.decrypt(&client_key);
+        assert_eq!(decrypted, value % MODULUS);
+
+        let a = Crt420::encrypt(value, &compact_public_key);
+        let decrypted: u64 = a.decrypt(&client_key);
+        assert_eq!(decrypted, value % MODULUS);
+    }
+
+    // Moduli must be coprime and fit in the blocks of the default parameters
+    assert!(FheUintCrt::<NotCoprimeBasis>::try_encrypt(1u64, &client_key).is_err());
+    assert!(FheUintCrt::<TooLargeBasis>::try_encrypt(1u64, &client_key).is_err());
+}
+
+#[test]
+fn test_crt_serialization() {
+    let client_key = setup_default_cpu();
+
+    let a = Crt420::encrypt(313u64, &client_key);
+    let serialized = bincode::serialize(&a).unwrap();
+
+    let a: Crt420 = bincode::deserialize(&serialized).unwrap();
+    let decrypted: u64 = a.decrypt(&client_key);
+    assert_eq!(decrypted, 313);
+
+    // The moduli of the ciphertext must be the ones of the type
+    assert!(bincode::deserialize::<FheUintCrt<TooLargeBasis>>(&serialized).is_err());
+}
+
+#[test]
+fn test_crt_arithmetic() {
+    let client_key = setup_default_cpu();
+
+    let clear_a = 313u64;
+    let clear_b = 397u64;
+    let a = Crt420::encrypt(clear_a, &client_key);
+    let b = Crt420::encrypt(clear_b, &client_key);
+
+    let c = &a + &b;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a + clear_b) % MODULUS);
+
+    let c = &b - &a;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (MODULUS + clear_b - clear_a) % MODULUS);
+
+    let c = &a * &b;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a * clear_b) % MODULUS);
+
+    let c = -&a;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, MODULUS - clear_a);
+
+    let c = &a + 300u64;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a + 300) % MODULUS);
+
+    let c = &a - 350u64;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (MODULUS + clear_a - 350) % MODULUS);
+
+    let c = &a * 123u64;
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (clear_a * 123) % MODULUS);
+
+    // Chain operations so that carries have to be cleaned
+    let mut c = a.clone();
+    let mut expected = clear_a;
+    for _ in 0..4 {
+        c += &b;
+        c *= &a;
+        c -= 5u64;
+        c *= 7u64;
+        expected = ((expected + clear_b) * clear_a % MODULUS + MODULUS - 5) * 7 % MODULUS;
+    }
+    let decrypted: u64 = c.decrypt(&client_key);
+    assert_eq!(decrypted, expected);
+}
+
+#[test]
+fn test_crt_radix_casts() {
+    let client_key = setup_default_cpu();
+
+    let a = FheUint16::encrypt(50_000u16, &client_key);
+    let a = Crt420::cast_from(a);
+    let decrypted: u64 = a.decrypt(&client_key);
+    assert_eq!(decrypted, 50_000 % MODULUS);
+
+    let b = Crt420::encrypt(313u64, &client_key);
+    let c = &a * &b;
+
+    let c = FheUint16::cast_from(c);
+    let decrypted: u16 = c.decrypt(&client_key);
+    assert_eq!(u64::from(decrypted), (50_000 % MODULUS) * 313 % MODULUS);
+
+    // The value is truncated to the number of bits of the FheUint
+    let c = FheUint8::cast_from(b);
+    let decrypted: u8 = c.decrypt(&client_key);
+    assert_eq!(decrypted, (313 % 256) as u8);
+}
+
+#[test]
+fn test_crt_compact_list() {
+    let client_key = setup_default_cpu();
+    let compact_public_key = CompactPublicKey::new(&client_key);
+
+    let compact_list = CompactCiphertextList::builder(&compact_public_key)
+        .push(17u8)
+        .push_crt::<Basis420>(313)
+        .unwrap()
+        .push(true)
+        .push_crt::<Basis420>(1000)
+        .unwrap()
+        .build();
+    assert!(CompactCiphertextList::builder(&compact_public_key)
+        .push_crt::<TooLargeBasis>(1)
+        .is_err());
+
+    // Lists holding CRT integers have their own degree, which does not depend on the moduli
+    let conformance_params = CompactCiphertextListConformanceParams {
+        shortint_params: PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param(),
+        num_elements_constraint: ListSizeConstraint::exact_size(4),
+    };
+    let mut serialized_list = vec![];
+    crate::safe_serialize(&compact_list, &mut serialized_list, 1 << 20).unwrap();
+    let compact_list: CompactCiphertextList =
+        safe_deserialize_conformant(serialized_list.as_slice(), 1 << 20, &conformance_params)
+            .unwrap();
+
+    let expander = compact_list.expand().unwrap();
+    assert_eq!(expander.len(), 4);
+    assert_eq!(expander.get_kind_of(1), None);
+
+    let a: FheUint8 = expander.get(0).unwrap().unwrap();
+    let b: Crt420 = expander.get(1).unwrap().unwrap();
+    let c: FheBool = expander.get(2).unwrap().unwrap();
+    let d: Crt420 = expander.get(3).unwrap().unwrap();
+    // The radix blocks of the list are not marked as having carries
+    assert!(a.ciphertext.on_cpu().block_carries_are_empty());
+
+    let decrypted: u8 = a.decrypt(&client_key);
+    assert_eq!(decrypted, 17);
+    let decrypted: u64 = b.decrypt(&client_key);
+    assert_eq!(decrypted, 313);
+    assert!(c.decrypt(&client_key));
+    let decrypted: u64 = d.decrypt(&client_key);
+    assert_eq!(decrypted, 1000 % MODULUS);
+
+    // Expanded values can be used in computations
+    let e = &b * &d + 1u64;
+    let decrypted: u64 = e.decrypt(&client_key);
+    assert_eq!(decrypted, (313 * (1000 % MODULUS) + 1) % MODULUS);
+    let f = a + 1u8;
+    let decrypted: u8 = f.decrypt(&client_key);
+    assert_eq!(decrypted, 18);
+
+    // A CRT integer cannot be expanded as another type, and the other way around
+    assert!(expander.get::<FheUint8>(1).unwrap().is_err());
+    assert!(expander.get::<FheBool>(3).unwrap().is_err());
+    assert!(expander.get::<Crt420>(0).unwrap().is_err());
+    assert!(expander
+        .get::<FheUintCrt<NotCoprimeBasis>>(1)
+        .unwrap()
+        .is_err());
+}
@@ -16,9 +16,11 @@ pub(in crate::high_level_api) use signed::FheIntId;
 pub(in crate::high_level_api) use unsigned::FheUintId;
 // These are pub-exported so that their doc can appear in generated rust docs
 use crate::shortint::MessageModulus;
+pub use crt::{FheUintCrt, FheUintCrtId};
 pub use signed::{CompressedFheInt, FheInt};
 pub use unsigned::{CompressedFheUint, FheUint};
 
+pub(in crate::high_level_api) mod crt;
 pub mod oprf;
 mod signed;
 mod unsigned;
@@ -9,7 +9,7 @@ use crate::named::Named;
 use crate::prelude::CastFrom;
 use crate::shortint::ciphertext::NotTrivialCiphertextError;
 use crate::shortint::PBSParameters;
-use crate::{Device, FheBool, ServerKey};
+use crate::{CompactPublicKey, Device, FheBool, Seed, ServerKey};
 use std::marker::PhantomData;
 
 #[cfg(feature = "gpu")]
@@ -414,6 +414,53 @@ where
     {
         self.ciphertext.on_cpu().decrypt_trivial()
     }
+
+    /// Re-randomizes the ciphertext, so that it cannot be linked to the ciphertexts it was
+    /// computed from
+    ///
+    /// A fresh encryption of zero, made with the compact public key, is added to the ciphertext,
+    /// which is then bootstrapped so that its noise does not depend on the inputs.
+    ///
+    /// The randomness of the encryption of zero is derived from the seed, so the same seed must
+    /// never be used twice.
+    ///
+    /// Returns an error if the LWE dimension of the ciphertext differs from the one of the
+    /// compact public key. The client key itself cannot be checked, re-randomizing with the
+    /// public key of another client key corrupts the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, CompactPublicKey, ConfigBuilder, FheInt8, Seed};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    /// let public_key = CompactPublicKey::new(&client_key);
+    ///
+    /// let mut a = FheInt8::encrypt(-73i8, &client_key);
+    /// a.re_randomize(&public_key, Seed(0)).unwrap();
+    ///
+    /// let decrypted: i8 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, -73i8);
+    /// ```
+    pub fn re_randomize(
+        &mut self,
+        compact_public_key: &CompactPublicKey,
+        seed: Seed,
+    ) -> crate::Result<()> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => cpu_key.pbs_key().re_randomize_parallelized(
+                self.ciphertext.as_cpu_mut(),
+                &compact_public_key.key.key,
+                seed,
+            ),
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => Err(crate::Error::new(
+                "Cuda devices do not support re_randomize yet".to_string(),
+            )),
+        })
+    }
 }
 
 impl<FromId, IntoId> CastFrom<FheInt<FromId>> for FheInt<IntoId>
@@ -112,8 +112,11 @@ impl<Id: FheIntId> Named for CompressedFheInt<Id> {
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub enum CompressedSignedRadixCiphertext {
-    Seeded(IntegerCompressedSignedRadixCiphertext),
-    ModulusSwitched(CompressedModulusSwitchedSignedRadixCiphertext),
+    Seeded(#[serde(with = "crate::versionable::nested")] IntegerCompressedSignedRadixCiphertext),
+    ModulusSwitched(
+        #[serde(with = "crate::versionable::nested")]
+        CompressedModulusSwitchedSignedRadixCiphertext,
+    ),
 }
 
 impl ParameterSetConformant for CompressedSignedRadixCiphertext {
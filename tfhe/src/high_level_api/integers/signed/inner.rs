@@ -45,7 +45,7 @@ impl serde::Serialize for RadixCiphertext {
     where
         S: Serializer,
     {
-        self.on_cpu().serialize(serializer)
+        crate::versionable::nested::serialize(&*self.on_cpu(), serializer)
     }
 }
 
@@ -54,9 +54,7 @@ impl<'de> serde::Deserialize<'de> for RadixCiphertext {
     where
         D: Deserializer<'de>,
     {
-        let mut deserialized = Self::Cpu(crate::integer::SignedRadixCiphertext::deserialize(
-            deserializer,
-        )?);
+        let mut deserialized = Self::Cpu(crate::versionable::nested::deserialize(deserializer)?);
         deserialized.move_to_device_of_server_key_if_set();
         Ok(deserialized)
     }
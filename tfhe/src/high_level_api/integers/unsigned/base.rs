@@ -12,7 +12,7 @@ use crate::integer::parameters::RadixCiphertextConformanceParams;
 use crate::named::Named;
 use crate::shortint::ciphertext::NotTrivialCiphertextError;
 use crate::shortint::PBSParameters;
-use crate::{FheBool, ServerKey};
+use crate::{CompactPublicKey, FheBool, Seed, ServerKey};
 use std::marker::PhantomData;
 
 #[derive(Debug)]
@@ -477,6 +477,53 @@ where
             }
         })
     }
+
+    /// Re-randomizes the ciphertext, so that it cannot be linked to the ciphertexts it was
+    /// computed from
+    ///
+    /// A fresh encryption of zero, made with the compact public key, is added to the ciphertext,
+    /// which is then bootstrapped so that its noise does not depend on the inputs.
+    ///
+    /// The randomness of the encryption of zero is derived from the seed, so the same seed must
+    /// never be used twice.
+    ///
+    /// Returns an error if the LWE dimension of the ciphertext differs from the one of the
+    /// compact public key. The client key itself cannot be checked, re-randomizing with the
+    /// public key of another client key corrupts the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, CompactPublicKey, ConfigBuilder, FheUint8, Seed};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    /// let public_key = CompactPublicKey::new(&client_key);
+    ///
+    /// let mut a = FheUint8::encrypt(213u8, &client_key);
+    /// a.re_randomize(&public_key, Seed(0)).unwrap();
+    ///
+    /// let decrypted: u8 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, 213u8);
+    /// ```
+    pub fn re_randomize(
+        &mut self,
+        compact_public_key: &CompactPublicKey,
+        seed: Seed,
+    ) -> crate::Result<()> {
+        global_state::with_internal_keys(|key| match key {
+            InternalServerKey::Cpu(cpu_key) => cpu_key.pbs_key().re_randomize_parallelized(
+                self.ciphertext.as_cpu_mut(),
+                &compact_public_key.key.key,
+                seed,
+            ),
+            #[cfg(feature = "gpu")]
+            InternalServerKey::Cuda(_) => Err(crate::Error::new(
+                "Cuda devices do not support re_randomize yet".to_string(),
+            )),
+        })
+    }
 }
 
 impl<Id> TryFrom<crate::integer::RadixCiphertext> for FheUint<Id>
@@ -116,8 +116,10 @@ impl<Id: FheUintId> Named for CompressedFheUint<Id> {
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub enum CompressedRadixCiphertext {
-    Seeded(IntegerCompressedRadixCiphertext),
-    ModulusSwitched(CompressedModulusSwitchedRadixCiphertext),
+    Seeded(#[serde(with = "crate::versionable::nested")] IntegerCompressedRadixCiphertext),
+    ModulusSwitched(
+        #[serde(with = "crate::versionable::nested")] CompressedModulusSwitchedRadixCiphertext,
+    ),
 }
 
 impl ParameterSetConformant for CompressedRadixCiphertext {
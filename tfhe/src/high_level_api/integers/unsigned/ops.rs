@@ -2,6 +2,7 @@
 // this is the pattern we use for the macros
 #![allow(clippy::redundant_closure_call)]
 use super::inner::RadixCiphertext;
+use super::wopbs::MAX_LUT_TREE_INPUT_BITS_WITH_WOPBS;
 #[cfg(feature = "gpu")]
 use crate::high_level_api::details::MaybeCloned;
 use crate::high_level_api::global_state;
@@ -553,20 +554,45 @@ where
 impl<Id> FheBootstrap for FheUint<Id>
 where
     Id: FheUintId,
-    crate::integer::wopbs::WopbsKey: super::wopbs::WopbsEvaluationKey<
-        crate::integer::ServerKey,
-        crate::integer::RadixCiphertext,
-    >,
 {
+    /// Computes a function over the encrypted value
+    ///
+    /// The function is evaluated with the WoPBS when it is enabled in the config and the
+    /// integer is wide, otherwise a tree of lookup tables is used, which works with any
+    /// parameter set but whose cost grows exponentially with the number of bits.
+    ///
+    /// - The output of the function is reduced modulo 2^num_bits
+    ///
+    /// # Panics
+    ///
+    /// Panics if the integer has more than 16 bits and the WoPBS is not enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(13u8, &client_key);
+    ///
+    /// let result = a.map(|x| x * x + 1);
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 13 * 13 + 1);
+    /// ```
     fn map<F: Fn(u64) -> u64>(&self, func: F) -> Self {
         use super::wopbs::WopbsEvaluationKey;
         global_state::with_internal_keys(|key| match key {
             InternalServerKey::Cpu(cpu_key) => {
-                let res = cpu_key
-                    .wopbs_key
-                    .as_ref()
-                    .expect("Function evaluation on integers was not enabled in the config")
-                    .apply_wopbs(cpu_key.pbs_key(), &*self.ciphertext.on_cpu(), func);
+                let ct = self.ciphertext.on_cpu();
+                let res = match cpu_key.wopbs_key.as_ref() {
+                    Some(wopbs_key) if Id::num_bits() > MAX_LUT_TREE_INPUT_BITS_WITH_WOPBS => {
+                        wopbs_key.apply_wopbs(cpu_key.pbs_key(), &*ct, func)
+                    }
+                    _ => cpu_key.pbs_key().map_parallelized(&ct, func),
+                };
                 Self::new(res)
             }
             #[cfg(feature = "gpu")]
@@ -585,11 +611,35 @@ where
 impl<Id> FheUint<Id>
 where
     Id: FheUintId,
-    crate::integer::wopbs::WopbsKey: super::wopbs::WopbsEvaluationKey<
-        crate::integer::ServerKey,
-        crate::integer::RadixCiphertext,
-    >,
 {
+    /// Computes a bivariate function over the encrypted values
+    ///
+    /// The function is evaluated with the WoPBS when it is enabled in the config and the
+    /// integers are wide, otherwise a tree of lookup tables is used, which works with any
+    /// parameter set but whose cost grows exponentially with the number of bits.
+    ///
+    /// - The output of the function is reduced modulo 2^num_bits
+    ///
+    /// # Panics
+    ///
+    /// Panics if both integers together have more than 16 bits and the WoPBS is not enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint4};
+    ///
+    /// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint4::encrypt(13u8, &client_key);
+    /// let b = FheUint4::encrypt(6u8, &client_key);
+    ///
+    /// let result = a.bivariate_function(&b, |x, y| x.max(y) - x.min(y));
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 13 - 6);
+    /// ```
     pub fn bivariate_function<F>(&self, other: &Self, func: F) -> Self
     where
         F: Fn(u64, u64) -> u64,
@@ -599,11 +649,14 @@ where
             InternalServerKey::Cpu(cpu_key) => {
                 let lhs = self.ciphertext.on_cpu();
                 let rhs = other.ciphertext.on_cpu();
-                let res = cpu_key
-                    .wopbs_key
-                    .as_ref()
-                    .expect("Function evaluation on integers was not enabled in the config")
-                    .apply_bivariate_wopbs(cpu_key.pbs_key(), &*lhs, &*rhs, func);
+                let res = match cpu_key.wopbs_key.as_ref() {
+                    Some(wopbs_key) if 2 * Id::num_bits() > MAX_LUT_TREE_INPUT_BITS_WITH_WOPBS => {
+                        wopbs_key.apply_bivariate_wopbs(cpu_key.pbs_key(), &*lhs, &*rhs, func)
+                    }
+                    _ => cpu_key
+                        .pbs_key()
+                        .bivariate_function_parallelized(&lhs, &rhs, func),
+                };
                 Self::new(res)
             }
             #[cfg(feature = "gpu")]
@@ -394,6 +394,12 @@ fn test_sum() {
     super::test_case_sum(&client_key);
 }
 
+#[test]
+fn test_map_and_bivariate_function() {
+    let client_key = setup_default_cpu();
+    super::test_case_map_and_bivariate_function(&client_key);
+}
+
 #[test]
 fn test_safe_deserialize_conformant_fhe_uint32() {
     let block_params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
@@ -1,6 +1,6 @@
 use crate::integer::U256;
 use crate::prelude::*;
-use crate::{ClientKey, FheUint256, FheUint32, FheUint64, FheUint8};
+use crate::{ClientKey, FheUint256, FheUint32, FheUint4, FheUint64, FheUint8};
 use rand::{thread_rng, Rng};
 
 mod cpu;
@@ -492,3 +492,32 @@ fn test_case_sum(client_key: &ClientKey) {
         assert_eq!(sum, expected_result);
     }
 }
+
+fn test_case_map_and_bivariate_function(client_key: &ClientKey) {
+    let mut rng = thread_rng();
+
+    for _ in 0..2 {
+        let clear_a = rng.gen::<u8>();
+        let a = FheUint8::encrypt(clear_a, client_key);
+
+        let result: u8 = a.map(|x| x * 7 + 3).decrypt(client_key);
+        assert_eq!(result, clear_a.wrapping_mul(7).wrapping_add(3));
+
+        let mut b = a.clone();
+        b.apply(|x| u64::from(x.count_ones()));
+        let result: u8 = b.decrypt(client_key);
+        assert_eq!(u32::from(result), clear_a.count_ones());
+    }
+
+    for _ in 0..2 {
+        let clear_a = rng.gen_range(0..16u8);
+        let clear_b = rng.gen_range(0..16u8);
+        let a = FheUint4::encrypt(clear_a, client_key);
+        let b = FheUint4::encrypt(clear_b, client_key);
+
+        let result: u8 = a
+            .bivariate_function(&b, |x, y| x.max(y) - x.min(y))
+            .decrypt(client_key);
+        assert_eq!(result, clear_a.abs_diff(clear_b));
+    }
+}
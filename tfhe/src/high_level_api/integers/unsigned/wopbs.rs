@@ -1,6 +1,10 @@
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::wopbs::WopbsKey;
 
+/// Number of input bits up to which functions are evaluated with a tree of lookup tables,
+/// even if the WoPBS is enabled
+pub(in crate::high_level_api) const MAX_LUT_TREE_INPUT_BITS_WITH_WOPBS: usize = 8;
+
 pub(in crate::high_level_api) fn wopbs_radix(
     wopbs_key: &WopbsKey,
     server_key: &crate::integer::ServerKey,
@@ -5,6 +5,7 @@
 use super::{CompressedServerKey, ServerKey};
 use crate::high_level_api::config::Config;
 use crate::high_level_api::keys::IntegerClientKey;
+use crate::named::Named;
 use crate::shortint::MessageModulus;
 use concrete_csprng::seeders::Seed;
 
@@ -19,6 +20,10 @@ pub struct ClientKey {
     pub(crate) key: IntegerClientKey,
 }
 
+impl Named for ClientKey {
+    const NAME: &'static str = "high_level_api::ClientKey";
+}
+
 impl ClientKey {
     /// Generates a new key from the given config.
     pub fn generate<C: Into<Config>>(config: C) -> Self {
@@ -66,6 +71,7 @@ impl ClientKey {
     ) -> (
         crate::integer::ClientKey,
         Option<crate::shortint::WopbsParameters>,
+        Option<crate::shortint::list_compression::CompressionPrivateKeys>,
     ) {
         self.key.into_raw_parts()
     }
@@ -73,9 +79,10 @@ impl ClientKey {
     pub fn from_raw_parts(
         key: crate::integer::ClientKey,
         wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+        compression_key: Option<crate::shortint::list_compression::CompressionPrivateKeys>,
     ) -> Self {
         Self {
-            key: IntegerClientKey::from_raw_parts(key, wopbs_block_parameters),
+            key: IntegerClientKey::from_raw_parts(key, wopbs_block_parameters, compression_key),
         }
     }
 
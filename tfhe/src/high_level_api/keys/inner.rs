@@ -1,16 +1,24 @@
 use crate::core_crypto::commons::generators::DeterministicSeeder;
 use crate::core_crypto::prelude::ActivatedRandomGenerator;
+use crate::integer::compression_keys::{
+    CompressedCompressionKey, CompressedDecompressionKey, CompressionKey, CompressionPrivateKeys,
+    DecompressionKey,
+};
 use crate::integer::public_key::CompactPublicKey;
 use crate::integer::CompressedCompactPublicKey;
+use crate::shortint::list_compression::CompressionPrivateKeys as ShortintCompressionPrivateKeys;
+use crate::shortint::parameters::list_compression::CompressionParameters;
 use crate::shortint::{EncryptionKeyChoice, MessageModulus};
 use crate::Error;
 use concrete_csprng::seeders::Seed;
 use serde::{Deserialize, Serialize};
 
+#[allow(clippy::struct_field_names)]
 #[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct IntegerConfig {
     pub(crate) block_parameters: crate::shortint::PBSParameters,
     pub(crate) wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+    pub(crate) compression_parameters: Option<CompressionParameters>,
 }
 
 impl IntegerConfig {
@@ -21,6 +29,7 @@ impl IntegerConfig {
         Self {
             block_parameters,
             wopbs_block_parameters,
+            compression_parameters: None,
         }
     }
 
@@ -28,6 +37,7 @@ impl IntegerConfig {
         Self {
             block_parameters: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS.into(),
             wopbs_block_parameters: None,
+            compression_parameters: None,
         }
     }
 
@@ -35,6 +45,7 @@ impl IntegerConfig {
         Self {
             block_parameters: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_PBS_KS.into(),
             wopbs_block_parameters: None,
+            compression_parameters: None,
         }
     }
 
@@ -46,12 +57,19 @@ impl IntegerConfig {
 
         self.wopbs_block_parameters = Some(wopbs_block_parameters);
     }
+
+    pub fn enable_compression(&mut self, compression_parameters: CompressionParameters) {
+        self.compression_parameters = Some(compression_parameters);
+    }
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct IntegerClientKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) key: crate::integer::ClientKey,
     pub(crate) wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+    #[serde(with = "crate::versionable::nested_option")]
+    pub(crate) compression_key: Option<CompressionPrivateKeys>,
 }
 
 impl IntegerClientKey {
@@ -61,12 +79,20 @@ impl IntegerClientKey {
             "This API only supports parameters for which the MessageModulus is 2 or 4 (1 or 2 bits per block)",
         );
         let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(seed);
-        let cks = crate::shortint::engine::ShortintEngine::new_from_seeder(&mut seeder)
-            .new_client_key(config.block_parameters.into());
+        let mut engine = crate::shortint::engine::ShortintEngine::new_from_seeder(&mut seeder);
+        let cks = engine.new_client_key(config.block_parameters.into());
+
+        let compression_key = config
+            .compression_parameters
+            .map(|params| CompressionPrivateKeys {
+                key: engine.new_compression_private_key(&cks, params),
+            });
+
         let key = crate::integer::ClientKey::from(cks);
         Self {
             key,
             wopbs_block_parameters: config.wopbs_block_parameters,
+            compression_key,
         }
     }
 
@@ -76,12 +102,18 @@ impl IntegerClientKey {
     ) -> (
         crate::integer::ClientKey,
         Option<crate::shortint::WopbsParameters>,
+        Option<ShortintCompressionPrivateKeys>,
     ) {
         let Self {
             key,
             wopbs_block_parameters,
+            compression_key,
         } = self;
-        (key, wopbs_block_parameters)
+        (
+            key,
+            wopbs_block_parameters,
+            compression_key.map(CompressionPrivateKeys::into_raw_parts),
+        )
     }
 
     /// Construct a, [`IntegerClientKey`] from its constituents.
@@ -92,6 +124,7 @@ impl IntegerClientKey {
     pub fn from_raw_parts(
         key: crate::integer::ClientKey,
         wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+        compression_key: Option<ShortintCompressionPrivateKeys>,
     ) -> Self {
         let shortint_cks: &crate::shortint::ClientKey = key.as_ref();
         if let Some(wop_params) = wopbs_block_parameters.as_ref() {
@@ -108,6 +141,7 @@ impl IntegerClientKey {
         Self {
             key,
             wopbs_block_parameters,
+            compression_key: compression_key.map(CompressionPrivateKeys::from_raw_parts),
         }
     }
 
@@ -123,17 +157,29 @@ impl From<IntegerConfig> for IntegerClientKey {
             "This API only supports parameters for which the MessageModulus is 2 or 4 (1 or 2 bits per block)",
         );
         let key = crate::integer::ClientKey::new(config.block_parameters);
+
+        let compression_key = config
+            .compression_parameters
+            .map(|params| key.new_compression_private_key(params));
+
         Self {
             key,
             wopbs_block_parameters: config.wopbs_block_parameters,
+            compression_key,
         }
     }
 }
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct IntegerServerKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) key: crate::integer::ServerKey,
+    #[serde(with = "crate::versionable::nested_option")]
     pub(crate) wopbs_key: Option<crate::integer::wopbs::WopbsKey>,
+    #[serde(with = "crate::versionable::nested_option")]
+    pub(crate) compression_key: Option<CompressionKey>,
+    #[serde(with = "crate::versionable::nested_option")]
+    pub(crate) decompression_key: Option<DecompressionKey>,
 }
 
 impl IntegerServerKey {
@@ -146,9 +192,21 @@ impl IntegerServerKey {
             .map(|wopbs_params| {
                 crate::integer::wopbs::WopbsKey::new_wopbs_key(cks, &base_integer_key, wopbs_params)
             });
+
+        let (compression_key, decompression_key) = client_key.compression_key.as_ref().map_or_else(
+            || (None, None),
+            |a| {
+                let (compression_key, decompression_key) =
+                    cks.new_compression_decompression_keys(a);
+                (Some(compression_key), Some(decompression_key))
+            },
+        );
+
         Self {
             key: base_integer_key,
             wopbs_key,
+            compression_key,
+            decompression_key,
         }
     }
 
@@ -163,7 +221,12 @@ impl IntegerServerKey {
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct IntegerCompressedServerKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) key: crate::integer::CompressedServerKey,
+    #[serde(with = "crate::versionable::nested_option")]
+    pub(crate) compression_key: Option<CompressedCompressionKey>,
+    #[serde(with = "crate::versionable::nested_option")]
+    pub(crate) decompression_key: Option<CompressedDecompressionKey>,
 }
 
 impl IntegerCompressedServerKey {
@@ -179,21 +242,57 @@ impl IntegerCompressedServerKey {
                    "
         );
         let key = crate::integer::CompressedServerKey::new_radix_compressed_server_key(integer_key);
-        Self { key }
+
+        let (compression_key, decompression_key) = client_key.compression_key.as_ref().map_or_else(
+            || (None, None),
+            |a| {
+                let (compression_key, decompression_key) =
+                    integer_key.new_compressed_compression_decompression_keys(a);
+                (Some(compression_key), Some(decompression_key))
+            },
+        );
+
+        Self {
+            key,
+            compression_key,
+            decompression_key,
+        }
     }
 
-    pub fn into_raw_parts(self) -> crate::integer::CompressedServerKey {
-        self.key
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        crate::integer::CompressedServerKey,
+        Option<CompressedCompressionKey>,
+        Option<CompressedDecompressionKey>,
+    ) {
+        (self.key, self.compression_key, self.decompression_key)
     }
 
-    pub fn from_raw_parts(key: crate::integer::CompressedServerKey) -> Self {
-        Self { key }
+    pub fn from_raw_parts(
+        key: crate::integer::CompressedServerKey,
+        compression_key: Option<CompressedCompressionKey>,
+        decompression_key: Option<CompressedDecompressionKey>,
+    ) -> Self {
+        Self {
+            key,
+            compression_key,
+            decompression_key,
+        }
     }
 
     pub(in crate::high_level_api) fn decompress(&self) -> IntegerServerKey {
         IntegerServerKey {
             key: self.key.decompress(),
             wopbs_key: None,
+            compression_key: self
+                .compression_key
+                .as_ref()
+                .map(CompressedCompressionKey::decompress),
+            decompression_key: self
+                .decompression_key
+                .as_ref()
+                .map(CompressedDecompressionKey::decompress),
         }
     }
 }
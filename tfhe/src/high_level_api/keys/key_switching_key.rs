@@ -2,6 +2,7 @@ use crate::high_level_api::integers::{FheIntId, FheUintId};
 use crate::integer::BooleanBlock;
 use crate::prelude::FheKeyswitch;
 pub use crate::shortint::parameters::key_switching::ShortintKeySwitchingParameters;
+use crate::shortint::EncryptionKeyChoice;
 use crate::{ClientKey, FheBool, FheInt, FheUint, ServerKey};
 use std::fmt::{Display, Formatter};
 
@@ -16,11 +17,47 @@ impl Display for IncompatibleParameters {
 
 impl std::error::Error for IncompatibleParameters {}
 
+/// A key to switch ciphertexts from the keys of a client to the keys of another client
+///
+/// The parameters of the two key sets may be different, including their message and carry moduli
+/// and their PBS kind: the integers are then re-encoded into blocks of the destination
+/// parameters. Both key sets must encrypt under their big LWE key, i.e. use the
+/// [`EncryptionKeyChoice::Big`] encryption key choice.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_1_CARRY_1_KS_PBS;
+/// use tfhe::{generate_keys, ConfigBuilder, FheInt16, KeySwitchingKey};
+///
+/// let (client_key_1, server_key_1) = generate_keys(ConfigBuilder::default());
+/// let (client_key_2, server_key_2) = generate_keys(
+///     ConfigBuilder::default().use_custom_parameters(PARAM_MESSAGE_1_CARRY_1_KS_PBS, None),
+/// );
+///
+/// let ksk = KeySwitchingKey::new(
+///     (&client_key_1, &server_key_1),
+///     (&client_key_2, &server_key_2),
+/// )
+/// .unwrap();
+///
+/// let a = FheInt16::encrypt(-1234i16, &client_key_1);
+/// let b = ksk.keyswitch(&a);
+///
+/// let decrypted: i16 = b.decrypt(&client_key_2);
+/// assert_eq!(decrypted, -1234);
+/// ```
 pub struct KeySwitchingKey {
-    key: crate::integer::key_switching_key::KeySwitchingKey,
+    pub(in crate::high_level_api) key: crate::integer::key_switching_key::KeySwitchingKey,
 }
 
 impl KeySwitchingKey {
+    /// Creates a key switching key, the key switching parameters being the ones of the
+    /// destination keys
+    ///
+    /// Returns an error if the ciphertext moduli of the two key sets are different, or if one of
+    /// them does not encrypt under its big LWE key.
     pub fn new(
         key_pair_from: (&ClientKey, &ServerKey),
         key_pair_to: (&ClientKey, &ServerKey),
@@ -28,13 +65,18 @@ impl KeySwitchingKey {
         let params_from = key_pair_from.0.key.block_parameters();
         let params_to = key_pair_to.0.key.block_parameters();
 
-        if params_to != params_from {
+        // The key switching key goes from the big LWE key of the source to the one of the
+        // destination
+        if params_to.ciphertext_modulus() != params_from.ciphertext_modulus()
+            || params_from.encryption_key_choice() != EncryptionKeyChoice::Big
+            || params_to.encryption_key_choice() != EncryptionKeyChoice::Big
+        {
             return Err(IncompatibleParameters);
         }
 
         let params = ShortintKeySwitchingParameters {
-            ks_base_log: key_pair_to.0.key.block_parameters().ks_base_log(),
-            ks_level: key_pair_to.0.key.block_parameters().ks_level(),
+            ks_base_log: params_to.ks_base_log(),
+            ks_level: params_to.ks_level(),
         };
 
         Ok(Self::with_parameters(key_pair_from, key_pair_to, params))
@@ -46,7 +88,7 @@ impl KeySwitchingKey {
         params: ShortintKeySwitchingParameters,
     ) -> Self {
         Self {
-            key: crate::integer::key_switching_key::KeySwitchingKey::new(
+            key: crate::integer::key_switching_key::KeySwitchingKey::new_radix(
                 (&key_pair_from.0.key.key, &key_pair_from.1.key.key),
                 (&key_pair_to.0.key.key, &key_pair_to.1.key.key),
                 params,
@@ -61,7 +103,7 @@ where
 {
     fn keyswitch(&self, input: &FheUint<Id>) -> FheUint<Id> {
         let radix = input.ciphertext.on_cpu();
-        let casted = self.key.cast(&*radix);
+        let casted = self.key.cast_radix(&*radix);
         FheUint::new(casted)
     }
 }
@@ -72,7 +114,7 @@ where
 {
     fn keyswitch(&self, input: &FheInt<Id>) -> FheInt<Id> {
         let radix = input.ciphertext.on_cpu();
-        let casted = self.key.cast(&*radix);
+        let casted = self.key.cast_radix(&*radix);
         FheInt::new(casted)
     }
 }
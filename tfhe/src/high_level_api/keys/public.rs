@@ -15,6 +15,7 @@
 //! - [CompressedCompactPublicKey]
 use super::ClientKey;
 use crate::high_level_api::keys::{IntegerCompactPublicKey, IntegerCompressedCompactPublicKey};
+use crate::named::Named;
 use crate::shortint::MessageModulus;
 use crate::Error;
 
@@ -23,9 +24,14 @@ use crate::Error;
 /// Works for any parameters, but uses a lot of memory / disk space
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct PublicKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(in crate::high_level_api) key: crate::integer::PublicKey,
 }
 
+impl Named for PublicKey {
+    const NAME: &'static str = "high_level_api::PublicKey";
+}
+
 impl PublicKey {
     /// Creates a PublicKey, derived from the given client key
     pub fn new(client_key: &ClientKey) -> Self {
@@ -51,9 +57,14 @@ impl PublicKey {
 /// Compressed classical public key.
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct CompressedPublicKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(in crate::high_level_api) key: crate::integer::CompressedPublicKey,
 }
 
+impl Named for CompressedPublicKey {
+    const NAME: &'static str = "high_level_api::CompressedPublicKey";
+}
+
 impl CompressedPublicKey {
     pub fn new(client_key: &ClientKey) -> Self {
         let base_integer_key = crate::integer::CompressedPublicKey::new(&client_key.key.key);
@@ -90,6 +101,10 @@ pub struct CompactPublicKey {
     pub(in crate::high_level_api) key: IntegerCompactPublicKey,
 }
 
+impl Named for CompactPublicKey {
+    const NAME: &'static str = "high_level_api::CompactPublicKey";
+}
+
 impl CompactPublicKey {
     /// Creates a CompactPublicKey, derived from the given client key
     ///
@@ -126,6 +141,10 @@ pub struct CompressedCompactPublicKey {
     pub(in crate::high_level_api) key: IntegerCompressedCompactPublicKey,
 }
 
+impl Named for CompressedCompactPublicKey {
+    const NAME: &'static str = "high_level_api::CompressedCompactPublicKey";
+}
+
 impl CompressedCompactPublicKey {
     /// Creates a CompressedCompactPublicKey
     ///
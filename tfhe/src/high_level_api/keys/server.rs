@@ -1,6 +1,10 @@
 #[cfg(feature = "gpu")]
 use crate::core_crypto::gpu::{synchronize_devices, CudaStreams};
 use crate::high_level_api::keys::{IntegerCompressedServerKey, IntegerServerKey};
+use crate::integer::compression_keys::{
+    CompressedCompressionKey, CompressedDecompressionKey, CompressionKey, DecompressionKey,
+};
+use crate::named::Named;
 use std::sync::Arc;
 
 use super::ClientKey;
@@ -20,6 +24,10 @@ pub struct ServerKey {
     pub(crate) key: Arc<IntegerServerKey>,
 }
 
+impl Named for ServerKey {
+    const NAME: &'static str = "high_level_api::ServerKey";
+}
+
 impl ServerKey {
     pub fn new(keys: &ClientKey) -> Self {
         Self {
@@ -32,18 +40,32 @@ impl ServerKey {
     ) -> (
         crate::integer::ServerKey,
         Option<crate::integer::wopbs::WopbsKey>,
+        Option<CompressionKey>,
+        Option<DecompressionKey>,
     ) {
-        let IntegerServerKey { key, wopbs_key } = (*self.key).clone();
-
-        (key, wopbs_key)
+        let IntegerServerKey {
+            key,
+            wopbs_key,
+            compression_key,
+            decompression_key,
+        } = (*self.key).clone();
+
+        (key, wopbs_key, compression_key, decompression_key)
     }
 
     pub fn from_raw_parts(
         key: crate::integer::ServerKey,
         wopbs_key: Option<crate::integer::wopbs::WopbsKey>,
+        compression_key: Option<CompressionKey>,
+        decompression_key: Option<DecompressionKey>,
     ) -> Self {
         Self {
-            key: Arc::new(IntegerServerKey { key, wopbs_key }),
+            key: Arc::new(IntegerServerKey {
+                key,
+                wopbs_key,
+                compression_key,
+                decompression_key,
+            }),
         }
     }
 }
@@ -113,6 +135,10 @@ pub struct CompressedServerKey {
     pub(crate) integer_key: IntegerCompressedServerKey,
 }
 
+impl Named for CompressedServerKey {
+    const NAME: &'static str = "high_level_api::CompressedServerKey";
+}
+
 impl CompressedServerKey {
     pub fn new(keys: &ClientKey) -> Self {
         Self {
@@ -120,13 +146,27 @@ impl CompressedServerKey {
         }
     }
 
-    pub fn into_raw_parts(self) -> crate::integer::CompressedServerKey {
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        crate::integer::CompressedServerKey,
+        Option<CompressedCompressionKey>,
+        Option<CompressedDecompressionKey>,
+    ) {
         self.integer_key.into_raw_parts()
     }
 
-    pub fn from_raw_parts(integer_key: crate::integer::CompressedServerKey) -> Self {
+    pub fn from_raw_parts(
+        integer_key: crate::integer::CompressedServerKey,
+        compression_key: Option<CompressedCompressionKey>,
+        decompression_key: Option<CompressedDecompressionKey>,
+    ) -> Self {
         Self {
-            integer_key: IntegerCompressedServerKey::from_raw_parts(integer_key),
+            integer_key: IntegerCompressedServerKey::from_raw_parts(
+                integer_key,
+                compression_key,
+                decompression_key,
+            ),
         }
     }
 
@@ -28,7 +28,9 @@ pub use crate::integer::oprf::SignedRandomizationSpec;
 pub use config::{Config, ConfigBuilder};
 pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
 
-pub use integers::{CompressedFheInt, CompressedFheUint, FheInt, FheUint, IntegerId};
+pub use integers::{
+    CompressedFheInt, CompressedFheUint, FheInt, FheUint, FheUintCrt, FheUintCrtId, IntegerId,
+};
 #[cfg(feature = "gpu")]
 pub use keys::CudaServerKey;
 pub use keys::{
@@ -56,7 +58,16 @@ pub use compact_list::ProvenCompactCiphertextList;
 pub use compact_list::{
     CompactCiphertextList, CompactCiphertextListBuilder, CompactCiphertextListExpander,
 };
+pub use compressed_ciphertext_list::{CompressedCiphertextList, CompressedCiphertextListBuilder};
+pub use fixed::{FheFixedI, FheFixedU, RoundingMode};
 pub use safe_serialize::safe_serialize;
+#[cfg(feature = "strings")]
+pub use strings::{
+    AsciiEncryptionKey, CompressedFheAsciiString, FheAsciiString, FheStringCount, FheStringIsEmpty,
+    FheStringIterator, FheStringLen, FheStringPattern, FheStringSplit,
+};
+#[cfg(feature = "experimental")]
+pub use threshold_decryption::{DecryptionShare, ThresholdClientKeyShare};
 
 mod config;
 mod global_state;
@@ -65,13 +76,24 @@ mod traits;
 
 mod booleans;
 mod errors;
+mod fixed;
 mod integers;
+#[cfg(feature = "strings")]
+mod strings;
 
 pub mod array;
+pub mod backward_compatibility;
+pub mod circuit;
 mod compact_list;
+mod compressed_ciphertext_list;
 pub(in crate::high_level_api) mod details;
+pub mod hashes;
 /// The tfhe prelude.
 pub mod prelude;
+pub mod regex;
+#[cfg(feature = "experimental")]
+mod threshold_decryption;
+pub mod transciphering;
 #[cfg(feature = "zk-pok-experimental")]
 mod zk;
 
@@ -116,7 +138,7 @@ pub enum FheTypes {
 
 pub mod safe_serialize {
     use crate::named::Named;
-    use serde::Serialize;
+    use crate::versionable::Versionize;
 
     pub fn safe_serialize<T>(
         a: &T,
@@ -124,7 +146,7 @@ pub mod safe_serialize {
         serialized_size_limit: u64,
     ) -> Result<(), String>
     where
-        T: Named + Serialize,
+        T: Named + Versionize,
     {
         crate::safe_deserialization::safe_serialize(a, writer, serialized_size_limit)
             .map_err(|err| err.to_string())
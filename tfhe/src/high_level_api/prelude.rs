@@ -12,5 +12,8 @@ pub use crate::high_level_api::traits::{
     RotateRightAssign,
 };
 
+#[cfg(feature = "experimental")]
+pub use crate::high_level_api::threshold_decryption::{FhePartialDecrypt, FheThresholdDecrypt};
+
 pub use crate::conformance::ParameterSetConformant;
 pub use crate::core_crypto::prelude::{CastFrom, CastInto};
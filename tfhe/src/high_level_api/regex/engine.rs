@@ -0,0 +1,207 @@
+use super::parser::ByteClass;
+use super::program::{Context, Program};
+use crate::prelude::*;
+use crate::{FheBool, FheUint32, FheUint8};
+
+/// A boolean which is either known in clear or encrypted
+///
+/// Most of the state activations of a program are known in clear (e.g. the states
+/// reachable from the start before any byte is read), keeping them in clear avoids
+/// computing encrypted operations on trivial values.
+#[derive(Clone)]
+pub(super) enum Bit {
+    Clear(bool),
+    Encrypted(FheBool),
+}
+
+impl Bit {
+    pub(super) fn is_false(&self) -> bool {
+        matches!(self, Self::Clear(false))
+    }
+
+    fn and(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Self::Clear(false), _) | (_, Self::Clear(false)) => Self::Clear(false),
+            (Self::Clear(true), value) | (value, Self::Clear(true)) => value.clone(),
+            (Self::Encrypted(lhs), Self::Encrypted(rhs)) => Self::Encrypted(lhs & rhs),
+        }
+    }
+
+    fn or(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Self::Clear(true), _) | (_, Self::Clear(true)) => Self::Clear(true),
+            (Self::Clear(false), value) | (value, Self::Clear(false)) => value.clone(),
+            (Self::Encrypted(lhs), Self::Encrypted(rhs)) => Self::Encrypted(lhs | rhs),
+        }
+    }
+
+    pub(super) fn not(&self) -> Self {
+        match self {
+            Self::Clear(value) => Self::Clear(!value),
+            Self::Encrypted(value) => Self::Encrypted(!value),
+        }
+    }
+
+    fn equals(&self, value: bool) -> Self {
+        if value {
+            self.clone()
+        } else {
+            self.not()
+        }
+    }
+
+    pub(super) fn into_fhe_bool(self) -> FheBool {
+        match self {
+            Self::Clear(value) => FheBool::encrypt_trivial(value),
+            Self::Encrypted(value) => value,
+        }
+    }
+}
+
+/// Returns whether the encrypted byte belongs to the class
+fn evaluate_class(class: &ByteClass, byte: &FheUint8) -> Bit {
+    if class.is_empty() {
+        return Bit::Clear(false);
+    }
+    if class.is_full() {
+        return Bit::Clear(true);
+    }
+
+    // Evaluate the class or its complement, whichever has the fewer ranges
+    let (ranges, negate) = {
+        let ranges = class.ranges();
+        let complement_ranges = class.negated().ranges();
+        if complement_ranges.len() < ranges.len() {
+            (complement_ranges, true)
+        } else {
+            (ranges, false)
+        }
+    };
+
+    let result = ranges
+        .into_iter()
+        .map(|(from, to)| match (from, to) {
+            (from, to) if from == to => byte.eq(from),
+            (0, to) => byte.le(to),
+            (from, u8::MAX) => byte.ge(from),
+            (from, to) => byte.ge(from) & byte.le(to),
+        })
+        .reduce(|lhs, rhs| lhs | rhs)
+        .expect("class is not empty");
+
+    Bit::Encrypted(if negate { !result } else { result })
+}
+
+/// The content a program runs on, in the order it is read
+pub(super) struct Content<'a> {
+    pub(super) bytes: Vec<&'a FheUint8>,
+    /// For each byte, whether it is part of the content, false for padding bytes
+    pub(super) is_valid: Vec<Bit>,
+    /// For each index in `0..=bytes.len()`, whether it is the start of the content
+    pub(super) at_start: Vec<Bit>,
+    /// For each index in `0..=bytes.len()`, whether it is the end of the content
+    pub(super) at_end: Vec<Bit>,
+}
+
+/// Runs the program over the content, starting a new match attempt at every index
+///
+/// Returns, for each index `i` in `0..=content.bytes.len()`, whether a match ends at `i`.
+pub(super) fn match_ends(program: &Program, content: &Content) -> Vec<Bit> {
+    let len = content.bytes.len();
+    let mut match_ends = Vec::with_capacity(len + 1);
+
+    // Activation of the states reached after consuming a byte,
+    // before following their epsilon transitions
+    let mut reached = vec![Bit::Clear(false); program.num_states()];
+
+    for i in 0..=len {
+        // Activation of the consuming states, the closures depend on the anchors, so the
+        // closures of all the possible contexts are combined, weighted by whether the
+        // context is the current one (which is known in clear for contents without padding)
+        let mut active = vec![Bit::Clear(false); program.num_states()];
+        for context in Context::all() {
+            let is_current_context = content.at_start[i]
+                .equals(context.at_start)
+                .and(&content.at_end[i].equals(context.at_end));
+            if is_current_context.is_false() {
+                continue;
+            }
+
+            let mut context_active = vec![Bit::Clear(false); program.num_states()];
+            for &state in program.closure(program.start(), context) {
+                context_active[state] = Bit::Clear(true);
+            }
+            for (state, activation) in reached.iter().enumerate() {
+                if activation.is_false() {
+                    continue;
+                }
+                for &target in program.closure(state, context) {
+                    context_active[target] = context_active[target].or(activation);
+                }
+            }
+
+            for (activation, context_activation) in active.iter_mut().zip(context_active) {
+                if !context_activation.is_false() {
+                    *activation = activation.or(&context_activation.and(&is_current_context));
+                }
+            }
+        }
+
+        let match_end = active
+            .iter()
+            .enumerate()
+            .filter(|(state, _)| program.is_match_state(*state))
+            .fold(Bit::Clear(false), |acc, (_, activation)| acc.or(activation));
+        match_ends.push(match_end);
+
+        if i == len {
+            break;
+        }
+
+        // Each distinct class is evaluated only once per byte
+        let mut class_values: Vec<Option<Bit>> = vec![None; program.classes().len()];
+        reached = vec![Bit::Clear(false); program.num_states()];
+        for (state, activation) in active.iter().enumerate() {
+            if activation.is_false() {
+                continue;
+            }
+            let Some((class, next)) = program.transition(state) else {
+                continue;
+            };
+            let class_value = class_values[class].get_or_insert_with(|| {
+                let class = &program.classes()[class];
+                let value = evaluate_class(class, content.bytes[i]);
+                // Padding bytes are null, they can only be consumed by classes containing 0
+                if class.contains(0) {
+                    value.and(&content.is_valid[i])
+                } else {
+                    value
+                }
+            });
+            let moved = activation.and(class_value);
+            reached[next] = reached[next].or(&moved);
+        }
+    }
+
+    match_ends
+}
+
+/// Returns the encrypted index of the first true bit, 0 if all bits are false
+pub(super) fn first_true_index(bits: &[Bit]) -> FheUint32 {
+    let mut index = FheUint32::encrypt_trivial(0u32);
+    for (i, bit) in bits.iter().enumerate().rev() {
+        match bit {
+            Bit::Clear(false) => {}
+            Bit::Clear(true) => index = FheUint32::encrypt_trivial(i as u32),
+            Bit::Encrypted(bit) => {
+                index = bit.select(&FheUint32::encrypt_trivial(i as u32), &index);
+            }
+        }
+    }
+    index
+}
+
+/// Returns whether at least one bit is true
+pub(super) fn any(bits: &[Bit]) -> Bit {
+    bits.iter().fold(Bit::Clear(false), |acc, bit| acc.or(bit))
+}
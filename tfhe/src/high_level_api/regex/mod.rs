@@ -0,0 +1,332 @@
+//! Matching of encrypted strings against clear regular expressions.
+//!
+//! A [Regex] is compiled once from a clear pattern, and can then be evaluated on any
+//! encrypted content, given as a slice of [FheUint8] or as a
+//! [FheAsciiString](crate::FheAsciiString) (with the `strings` feature).
+//!
+//! The content length is public (for padded strings, only the length with the padding is),
+//! the content itself and the result of the match remain encrypted.
+//!
+//! # Supported syntax
+//!
+//! - literal bytes, `\` escapes meta characters
+//! - `.` any byte
+//! - `[abc]`, `[a-z]` and `[^a-z]` character classes
+//! - `\d`, `\w`, `\s`, their negations `\D`, `\W`, `\S`, and `\n`, `\r`, `\t`, `\xHH`
+//! - `(expr)` and `(?:expr)` groups
+//! - `expr1|expr2` alternation
+//! - `*`, `+`, `?`, `{n}`, `{n,}` and `{n,m}` repetitions
+//! - `^` and `$` anchors
+//! - a leading `(?i)` makes the whole pattern case insensitive for ascii letters
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::prelude::*;
+//! use tfhe::regex::Regex;
+//! use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+//!
+//! let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+//! set_server_key(server_key);
+//!
+//! let content = b"id: 042"
+//!     .iter()
+//!     .map(|byte| FheUint8::encrypt(*byte, &client_key))
+//!     .collect::<Vec<_>>();
+//!
+//! let regex = Regex::new(r"\d{2}$").unwrap();
+//!
+//! let (is_match, position) = regex.find(&content);
+//! assert!(is_match.decrypt(&client_key));
+//! let position: u32 = position.decrypt(&client_key);
+//! assert_eq!(position, 5);
+//! ```
+mod engine;
+mod parser;
+mod program;
+#[cfg(test)]
+mod tests;
+
+use crate::{FheBool, FheUint32, FheUint8};
+use engine::{Bit, Content};
+use program::Program;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+struct CompiledRegex {
+    pattern: String,
+    /// Program used to know where matches end
+    forward: Program,
+    /// Program of the reversed expression, ran on the reversed content
+    /// to know where matches start
+    backward: Program,
+}
+
+/// A compiled regular expression
+///
+/// Compiling a pattern is done in clear and only once, the compiled regex can then be used
+/// to match any number of encrypted contents. Cloning a [Regex] is cheap.
+///
+/// See the [module documentation](self) for the supported syntax.
+#[derive(Clone)]
+pub struct Regex {
+    inner: Arc<CompiledRegex>,
+}
+
+impl Regex {
+    /// Compiles a regular expression
+    ///
+    /// Returns an error if the pattern is not valid, or if its compiled program would be
+    /// too large (e.g. because of nested repetitions)
+    pub fn new(pattern: &str) -> crate::Result<Self> {
+        let expr = parser::parse(pattern)?;
+        Ok(Self {
+            inner: Arc::new(CompiledRegex {
+                pattern: pattern.to_string(),
+                forward: Program::compile(&expr)?,
+                backward: Program::compile(&expr.reversed())?,
+            }),
+        })
+    }
+
+    /// Returns the pattern this regex was compiled from
+    pub fn as_str(&self) -> &str {
+        &self.inner.pattern
+    }
+
+    /// Returns whether the regex matches somewhere in the content
+    pub fn is_match(&self, content: &[FheUint8]) -> FheBool {
+        let content = content.iter().collect::<Vec<_>>();
+        self.is_match_(content, false)
+    }
+
+    /// Returns whether the regex matches somewhere in the content, and the index where the
+    /// leftmost match starts
+    ///
+    /// The index is 0 if there is no match.
+    pub fn find(&self, content: &[FheUint8]) -> (FheBool, FheUint32) {
+        let content = content.iter().collect::<Vec<_>>();
+        self.find_(content, false)
+    }
+
+    fn is_match_(&self, bytes: Vec<&FheUint8>, is_padded: bool) -> FheBool {
+        let content = forward_content(bytes, is_padded);
+        let match_ends = engine::match_ends(&self.inner.forward, &content);
+        engine::any(&match_ends).into_fhe_bool()
+    }
+
+    fn find_(&self, mut bytes: Vec<&FheUint8>, is_padded: bool) -> (FheBool, FheUint32) {
+        bytes.reverse();
+        let content = backward_content(bytes, is_padded);
+        // The reversed program ran on the reversed content finds
+        // the starts of the matches, from the end of the content
+        let mut match_starts = engine::match_ends(&self.inner.backward, &content);
+        match_starts.reverse();
+
+        let is_match = engine::any(&match_starts).into_fhe_bool();
+        let start = engine::first_true_index(&match_starts);
+        (is_match, start)
+    }
+}
+
+#[cfg(feature = "strings")]
+impl Regex {
+    /// Returns whether the regex matches somewhere in the string
+    ///
+    /// Padding characters are not part of the string, and can not be matched.
+    pub fn is_match_str(&self, content: &crate::FheAsciiString) -> FheBool {
+        let bytes = ascii_string_bytes(content);
+        self.is_match_(bytes.iter().collect(), content.inner.is_padded())
+    }
+
+    /// Returns whether the regex matches somewhere in the string, and the index where the
+    /// leftmost match starts
+    ///
+    /// The index is 0 if there is no match.
+    pub fn find_str(&self, content: &crate::FheAsciiString) -> (FheBool, FheUint32) {
+        let bytes = ascii_string_bytes(content);
+        self.find_(bytes.iter().collect(), content.inner.is_padded())
+    }
+}
+
+#[cfg(feature = "strings")]
+fn ascii_string_bytes(content: &crate::FheAsciiString) -> Vec<FheUint8> {
+    content
+        .inner
+        .chars()
+        .iter()
+        .map(|char| FheUint8::new(char.ciphertext().clone()))
+        .collect()
+}
+
+/// Returns, for each byte, whether it is a padding null byte
+fn padding_bits(bytes: &[&FheUint8], is_padded: bool) -> Vec<Bit> {
+    use crate::prelude::FheEq;
+
+    bytes
+        .iter()
+        .map(|byte| {
+            if is_padded {
+                Bit::Encrypted(byte.eq(0u8))
+            } else {
+                Bit::Clear(false)
+            }
+        })
+        .collect()
+}
+
+fn forward_content(bytes: Vec<&FheUint8>, is_padded: bool) -> Content<'_> {
+    let len = bytes.len();
+    let is_padding = padding_bits(&bytes, is_padded);
+
+    Content {
+        is_valid: is_padding.iter().map(Bit::not).collect(),
+        at_start: (0..=len).map(|i| Bit::Clear(i == 0)).collect(),
+        // The content ends at the first padding byte
+        at_end: is_padding
+            .into_iter()
+            .chain(std::iter::once(Bit::Clear(true)))
+            .collect(),
+        bytes,
+    }
+}
+
+fn backward_content(reversed_bytes: Vec<&FheUint8>, is_padded: bool) -> Content<'_> {
+    let len = reversed_bytes.len();
+    let is_padding = padding_bits(&reversed_bytes, is_padded);
+
+    Content {
+        is_valid: is_padding.iter().map(Bit::not).collect(),
+        // The reversed content starts after the padding
+        at_start: std::iter::once(Bit::Clear(true))
+            .chain(is_padding)
+            .collect(),
+        at_end: (0..=len).map(|i| Bit::Clear(i == len)).collect(),
+        bytes: reversed_bytes,
+    }
+}
+
+/// A cache of compiled regular expressions
+///
+/// Useful when the patterns come from an external source (e.g. requests to a server),
+/// so that a pattern that was already seen is not compiled again.
+///
+/// The cache holds at most [`capacity`](Self::capacity) regexes, when it is full the least
+/// recently used regex is evicted to make room for a new one.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::regex::RegexCache;
+///
+/// let cache = RegexCache::with_capacity(2);
+/// let regex = cache.get("^[a-z]+$").unwrap();
+/// let same_regex = cache.get("^[a-z]+$").unwrap();
+/// assert_eq!(regex.as_str(), same_regex.as_str());
+/// assert_eq!(cache.len(), 1);
+/// assert!(cache.get("[a-z").is_err());
+///
+/// // The least recently used regex is evicted once the cache is full
+/// let _ = cache.get("a+").unwrap();
+/// let _ = cache.get("^[a-z]+$").unwrap();
+/// let _ = cache.get("b+").unwrap();
+/// assert_eq!(cache.len(), 2);
+/// assert!(cache.contains("^[a-z]+$"));
+/// assert!(!cache.contains("a+"));
+/// ```
+pub struct RegexCache {
+    capacity: usize,
+    inner: Mutex<RegexCacheInner>,
+}
+
+#[derive(Default)]
+struct RegexCacheInner {
+    // The regexes with the tick of their last use
+    regexes: HashMap<String, (Regex, u64)>,
+    tick: u64,
+}
+
+impl Default for RegexCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegexCache {
+    /// Default number of regexes held by a [`RegexCache`]
+    pub const DEFAULT_CAPACITY: usize = 128;
+
+    /// Creates a cache holding at most [`DEFAULT_CAPACITY`](Self::DEFAULT_CAPACITY) regexes
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Creates a cache holding at most `capacity` regexes
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "The capacity of a RegexCache must be at least 1"
+        );
+
+        Self {
+            capacity,
+            inner: Mutex::new(RegexCacheInner::default()),
+        }
+    }
+
+    /// Returns the compiled regex of the pattern, compiling it if it is not in the cache
+    pub fn get(&self, pattern: &str) -> crate::Result<Regex> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.tick += 1;
+        let tick = inner.tick;
+
+        if let Some((regex, last_use)) = inner.regexes.get_mut(pattern) {
+            *last_use = tick;
+            return Ok(regex.clone());
+        }
+
+        let regex = Regex::new(pattern)?;
+
+        if inner.regexes.len() >= self.capacity {
+            let least_recently_used = inner
+                .regexes
+                .iter()
+                .min_by_key(|(_, (_, last_use))| *last_use)
+                .map(|(pattern, _)| pattern.clone())
+                .unwrap();
+            inner.regexes.remove(&least_recently_used);
+        }
+
+        inner
+            .regexes
+            .insert(pattern.to_string(), (regex.clone(), tick));
+        Ok(regex)
+    }
+
+    /// Returns whether the compiled regex of the pattern is in the cache
+    pub fn contains(&self, pattern: &str) -> bool {
+        self.inner.lock().unwrap().regexes.contains_key(pattern)
+    }
+
+    /// Returns the maximum number of compiled regexes in the cache
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of compiled regexes in the cache
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().regexes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.inner.lock().unwrap().regexes.clear();
+    }
+}
@@ -0,0 +1,451 @@
+use crate::Error;
+
+/// Maximum number of times a sub-expression can be repeated by a bounded repetition
+///
+/// Each repetition duplicates the states of the sub-expression in the compiled program,
+/// whose total size is bounded by [MAX_STATES](super::program::MAX_STATES).
+pub(super) const MAX_REPETITIONS: usize = 256;
+
+/// A set of bytes
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub(super) struct ByteClass([u64; 4]);
+
+impl ByteClass {
+    pub(super) const fn empty() -> Self {
+        Self([0; 4])
+    }
+
+    pub(super) const fn any() -> Self {
+        Self([u64::MAX; 4])
+    }
+
+    pub(super) fn single(byte: u8) -> Self {
+        let mut class = Self::empty();
+        class.insert(byte);
+        class
+    }
+
+    pub(super) fn range(from: u8, to: u8) -> Self {
+        let mut class = Self::empty();
+        for byte in from..=to {
+            class.insert(byte);
+        }
+        class
+    }
+
+    pub(super) fn insert(&mut self, byte: u8) {
+        self.0[byte as usize / 64] |= 1 << (byte % 64);
+    }
+
+    pub(super) fn contains(&self, byte: u8) -> bool {
+        (self.0[byte as usize / 64] >> (byte % 64)) & 1 == 1
+    }
+
+    pub(super) fn union(&mut self, other: &Self) {
+        for (word, other_word) in self.0.iter_mut().zip(other.0.iter()) {
+            *word |= other_word;
+        }
+    }
+
+    #[must_use]
+    pub(super) fn negated(&self) -> Self {
+        Self(self.0.map(|word| !word))
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        *self == Self::empty()
+    }
+
+    pub(super) fn is_full(&self) -> bool {
+        *self == Self::any()
+    }
+
+    /// Adds the other case of the ascii letters of the class
+    #[must_use]
+    pub(super) fn case_insensitive(&self) -> Self {
+        let mut result = *self;
+        for byte in 0..=u8::MAX {
+            if self.contains(byte) && byte.is_ascii_alphabetic() {
+                result.insert(byte.to_ascii_lowercase());
+                result.insert(byte.to_ascii_uppercase());
+            }
+        }
+        result
+    }
+
+    /// Returns the class as a list of disjoint inclusive ranges, in increasing order
+    pub(super) fn ranges(&self) -> Vec<(u8, u8)> {
+        let mut ranges = Vec::new();
+        let mut current_start = None;
+        for byte in 0..=u8::MAX {
+            match (self.contains(byte), current_start) {
+                (true, None) => current_start = Some(byte),
+                (false, Some(start)) => {
+                    ranges.push((start, byte - 1));
+                    current_start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(start) = current_start {
+            ranges.push((start, u8::MAX));
+        }
+        ranges
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) enum Anchor {
+    /// `^`, matches at the start of the content
+    Start,
+    /// `$`, matches at the end of the content
+    End,
+}
+
+/// The parsed representation of a regular expression
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) enum RegExpr {
+    /// Matches the empty string
+    Empty,
+    /// Matches one byte of the class
+    Class(ByteClass),
+    Anchor(Anchor),
+    Concat(Vec<Self>),
+    Alternation(Vec<Self>),
+    Repeat {
+        expr: Box<Self>,
+        min: usize,
+        /// `None` if there is no upper bound
+        max: Option<usize>,
+    },
+}
+
+impl RegExpr {
+    /// Returns the expression matching the reversed strings matched by self
+    pub(super) fn reversed(&self) -> Self {
+        match self {
+            Self::Empty => Self::Empty,
+            Self::Class(class) => Self::Class(*class),
+            Self::Anchor(Anchor::Start) => Self::Anchor(Anchor::End),
+            Self::Anchor(Anchor::End) => Self::Anchor(Anchor::Start),
+            Self::Concat(exprs) => Self::Concat(exprs.iter().rev().map(Self::reversed).collect()),
+            Self::Alternation(exprs) => {
+                Self::Alternation(exprs.iter().map(Self::reversed).collect())
+            }
+            Self::Repeat { expr, min, max } => Self::Repeat {
+                expr: Box::new(expr.reversed()),
+                min: *min,
+                max: *max,
+            },
+        }
+    }
+}
+
+/// Parses a regular expression
+///
+/// The supported syntax is:
+///
+/// - literal bytes, `\` escapes any meta character
+/// - `.` any byte
+/// - `[abc]`, `[a-z]`, `[^a-z]` character classes
+/// - `\d`, `\w`, `\s` (and their negated upper case versions), `\n`, `\r`, `\t`, `\xHH`
+/// - `(expr)` and `(?:expr)` groups
+/// - `expr1|expr2` alternation
+/// - `*`, `+`, `?`, `{n}`, `{n,}`, `{n,m}` repetitions
+/// - `^` and `$` anchors
+/// - a leading `(?i)` makes the whole expression case insensitive (for ascii letters)
+pub(super) fn parse(pattern: &str) -> Result<RegExpr, Error> {
+    let (pattern, case_insensitive) = pattern
+        .strip_prefix("(?i)")
+        .map_or((pattern, false), |pattern| (pattern, true));
+
+    let mut parser = Parser {
+        pattern: pattern.as_bytes(),
+        position: 0,
+        case_insensitive,
+    };
+    let expr = parser.parse_alternation()?;
+    if let Some(byte) = parser.peek() {
+        return Err(parser.error(&format!("unexpected '{}'", byte as char)));
+    }
+    Ok(expr)
+}
+
+struct Parser<'a> {
+    pattern: &'a [u8],
+    position: usize,
+    case_insensitive: bool,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> Error {
+        Error::new(format!(
+            "Invalid regular expression at position {}: {message}",
+            self.position
+        ))
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.pattern.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.position += 1;
+        Some(byte)
+    }
+
+    fn eat(&mut self, expected: u8) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), Error> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", expected as char)))
+        }
+    }
+
+    fn class(&self, class: ByteClass) -> RegExpr {
+        if self.case_insensitive {
+            RegExpr::Class(class.case_insensitive())
+        } else {
+            RegExpr::Class(class)
+        }
+    }
+
+    fn parse_alternation(&mut self) -> Result<RegExpr, Error> {
+        let mut branches = vec![self.parse_concat()?];
+        while self.eat(b'|') {
+            branches.push(self.parse_concat()?);
+        }
+
+        Ok(if branches.len() == 1 {
+            branches.pop().unwrap()
+        } else {
+            RegExpr::Alternation(branches)
+        })
+    }
+
+    fn parse_concat(&mut self) -> Result<RegExpr, Error> {
+        let mut exprs = vec![];
+        while !matches!(self.peek(), None | Some(b'|' | b')')) {
+            exprs.push(self.parse_repeat()?);
+        }
+
+        Ok(match exprs.len() {
+            0 => RegExpr::Empty,
+            1 => exprs.pop().unwrap(),
+            _ => RegExpr::Concat(exprs),
+        })
+    }
+
+    fn parse_repeat(&mut self) -> Result<RegExpr, Error> {
+        let mut expr = self.parse_atom()?;
+
+        loop {
+            let (min, max) = match self.peek() {
+                Some(b'*') => (0, None),
+                Some(b'+') => (1, None),
+                Some(b'?') => (0, Some(1)),
+                Some(b'{') => {
+                    self.position += 1;
+                    self.parse_bounds()?
+                }
+                _ => break,
+            };
+            // Consumes the repetition operator, or the closing brace of the bounds
+            self.position += 1;
+
+            if matches!(expr, RegExpr::Anchor(_)) {
+                return Err(self.error("anchors cannot be repeated"));
+            }
+            if max.is_some_and(|max| max < min) {
+                return Err(self.error(&format!("invalid repetition bounds {{{min},{max:?}}}")));
+            }
+            if min.max(max.unwrap_or(0)) > MAX_REPETITIONS {
+                return Err(self.error(&format!(
+                    "repetition bounds cannot exceed {MAX_REPETITIONS}"
+                )));
+            }
+
+            expr = RegExpr::Repeat {
+                expr: Box::new(expr),
+                min,
+                max,
+            };
+        }
+
+        Ok(expr)
+    }
+
+    /// Parses `n}`, `n,}` or `n,m}`, leaving the position on the closing brace
+    fn parse_bounds(&mut self) -> Result<(usize, Option<usize>), Error> {
+        let min = self.parse_number()?;
+        let max = if self.eat(b',') {
+            if self.peek() == Some(b'}') {
+                None
+            } else {
+                Some(self.parse_number()?)
+            }
+        } else {
+            Some(min)
+        };
+        if self.peek() != Some(b'}') {
+            return Err(self.error("expected '}'"));
+        }
+        Ok((min, max))
+    }
+
+    fn parse_number(&mut self) -> Result<usize, Error> {
+        let start = self.position;
+        while self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            self.position += 1;
+        }
+        std::str::from_utf8(&self.pattern[start..self.position])
+            .unwrap()
+            .parse()
+            .map_err(|_| self.error("expected a number"))
+    }
+
+    fn parse_atom(&mut self) -> Result<RegExpr, Error> {
+        let Some(byte) = self.next() else {
+            return Err(self.error("unexpected end of pattern"));
+        };
+
+        match byte {
+            b'(' => {
+                if self.eat(b'?') {
+                    self.expect(b':')?;
+                }
+                let expr = self.parse_alternation()?;
+                self.expect(b')')?;
+                Ok(expr)
+            }
+            b'[' => {
+                let class = self.parse_class()?;
+                Ok(self.class(class))
+            }
+            b'.' => Ok(RegExpr::Class(ByteClass::any())),
+            b'^' => Ok(RegExpr::Anchor(Anchor::Start)),
+            b'$' => Ok(RegExpr::Anchor(Anchor::End)),
+            b'\\' => {
+                let class = self.parse_escape()?;
+                Ok(self.class(class))
+            }
+            b'*' | b'+' | b'?' | b'{' => {
+                self.position -= 1;
+                Err(self.error("repetition without an expression to repeat"))
+            }
+            b')' | b']' | b'}' | b'|' => {
+                self.position -= 1;
+                Err(self.error(&format!("unexpected '{}'", byte as char)))
+            }
+            byte => Ok(self.class(ByteClass::single(byte))),
+        }
+    }
+
+    /// Parses the content of a `[...]` class, the opening bracket being already consumed
+    fn parse_class(&mut self) -> Result<ByteClass, Error> {
+        let negated = self.eat(b'^');
+        let mut class = ByteClass::empty();
+        let mut is_first = true;
+
+        loop {
+            let Some(byte) = self.next() else {
+                return Err(self.error("unterminated character class"));
+            };
+
+            let item = match byte {
+                b']' if !is_first => break,
+                b'\\' => self.parse_escape()?,
+                byte => ByteClass::single(byte),
+            };
+            is_first = false;
+
+            // A range, unless the '-' is the last character of the class
+            let is_range = self.peek() == Some(b'-')
+                && self
+                    .pattern
+                    .get(self.position + 1)
+                    .is_some_and(|b| *b != b']');
+            if is_range {
+                self.position += 1;
+                let from = single_byte(&item).ok_or_else(|| self.error("invalid range start"))?;
+                let to = match self.next() {
+                    Some(b'\\') => single_byte(&self.parse_escape()?),
+                    to => to,
+                }
+                .ok_or_else(|| self.error("invalid range end"))?;
+                if to < from {
+                    return Err(
+                        self.error(&format!("invalid range {}-{}", from as char, to as char))
+                    );
+                }
+                class.union(&ByteClass::range(from, to));
+            } else {
+                class.union(&item);
+            }
+        }
+
+        Ok(if negated { class.negated() } else { class })
+    }
+
+    /// Parses an escape sequence, the backslash being already consumed
+    fn parse_escape(&mut self) -> Result<ByteClass, Error> {
+        let Some(byte) = self.next() else {
+            return Err(self.error("unterminated escape sequence"));
+        };
+
+        let digits = ByteClass::range(b'0', b'9');
+        let mut word = ByteClass::range(b'a', b'z');
+        word.union(&ByteClass::range(b'A', b'Z'));
+        word.union(&digits);
+        word.insert(b'_');
+        let mut spaces = ByteClass::empty();
+        for space in [b' ', b'\t', b'\n', b'\r', 0x0B, 0x0C] {
+            spaces.insert(space);
+        }
+
+        Ok(match byte {
+            b'd' => digits,
+            b'D' => digits.negated(),
+            b'w' => word,
+            b'W' => word.negated(),
+            b's' => spaces,
+            b'S' => spaces.negated(),
+            b'n' => ByteClass::single(b'\n'),
+            b'r' => ByteClass::single(b'\r'),
+            b't' => ByteClass::single(b'\t'),
+            b'x' => {
+                let start = self.position;
+                let hex = self
+                    .pattern
+                    .get(start..start + 2)
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or_else(|| self.error("expected two hexadecimal digits"))?;
+                self.position += 2;
+                ByteClass::single(hex)
+            }
+            byte if byte.is_ascii_alphanumeric() => {
+                self.position -= 1;
+                return Err(self.error(&format!("unknown escape sequence '\\{}'", byte as char)));
+            }
+            byte => ByteClass::single(byte),
+        })
+    }
+}
+
+fn single_byte(class: &ByteClass) -> Option<u8> {
+    match class.ranges().as_slice() {
+        [(from, to)] if from == to => Some(*from),
+        _ => None,
+    }
+}
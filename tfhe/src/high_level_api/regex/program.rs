@@ -0,0 +1,242 @@
+use super::parser::{Anchor, ByteClass, RegExpr};
+use crate::Error;
+
+pub(super) type StateId = usize;
+
+/// Maximum number of states of a compiled program
+///
+/// Nested or stacked repetitions multiply the number of states of the program, and the
+/// epsilon closures precomputation is quadratic in the number of states, so programs larger
+/// than this are rejected instead of being built.
+pub(super) const MAX_STATES: usize = 1024;
+
+/// A state of a Thompson NFA
+#[derive(Copy, Clone, Debug)]
+enum State {
+    /// Consumes one byte of the class (given as an index in [Program::classes])
+    /// and goes to the next state
+    Class {
+        class: usize,
+        next: StateId,
+    },
+    /// Goes to both states without consuming anything
+    Split(StateId, StateId),
+    /// Goes to the next state without consuming anything, only if the anchor holds
+    Assert {
+        anchor: Anchor,
+        next: StateId,
+    },
+    /// Goes to the next state without consuming anything
+    Epsilon(StateId),
+    Match,
+}
+
+/// Which anchors hold at a given index of the content
+#[derive(Copy, Clone, Debug)]
+pub(super) struct Context {
+    pub(super) at_start: bool,
+    pub(super) at_end: bool,
+}
+
+impl Context {
+    fn index(self) -> usize {
+        usize::from(self.at_start) | (usize::from(self.at_end) << 1)
+    }
+
+    pub(super) fn all() -> [Self; 4] {
+        [(false, false), (true, false), (false, true), (true, true)]
+            .map(|(at_start, at_end)| Self { at_start, at_end })
+    }
+
+    fn satisfies(self, anchor: Anchor) -> bool {
+        match anchor {
+            Anchor::Start => self.at_start,
+            Anchor::End => self.at_end,
+        }
+    }
+}
+
+/// A compiled regular expression
+///
+/// The epsilon closures of all states are precomputed for every [Context], so that
+/// executing the program only requires to evaluate the (encrypted) byte classes and to
+/// combine the (encrypted) state activations.
+pub(super) struct Program {
+    states: Vec<State>,
+    start: StateId,
+    /// The distinct byte classes of the program
+    classes: Vec<ByteClass>,
+    /// For each context, the consuming states (classes and match) reachable
+    /// from each state without consuming anything
+    closures: [Vec<Vec<StateId>>; 4],
+}
+
+impl Program {
+    /// Compiles the expression
+    ///
+    /// Returns an error if the program would have more than [MAX_STATES] states
+    pub(super) fn compile(expr: &RegExpr) -> Result<Self, Error> {
+        let mut compiler = Compiler {
+            states: vec![],
+            classes: vec![],
+        };
+        let match_state = compiler.push(State::Match)?;
+        let start = compiler.compile(expr, match_state)?;
+
+        let Compiler { states, classes } = compiler;
+        let closures = Context::all().map(|context| {
+            (0..states.len())
+                .map(|state| epsilon_closure(&states, state, context))
+                .collect()
+        });
+
+        Ok(Self {
+            states,
+            start,
+            classes,
+            closures,
+        })
+    }
+
+    pub(super) fn start(&self) -> StateId {
+        self.start
+    }
+
+    pub(super) fn num_states(&self) -> usize {
+        self.states.len()
+    }
+
+    pub(super) fn classes(&self) -> &[ByteClass] {
+        &self.classes
+    }
+
+    pub(super) fn is_match_state(&self, state: StateId) -> bool {
+        matches!(self.states[state], State::Match)
+    }
+
+    /// Returns the class and the next state of a consuming state, `None` for the match state
+    pub(super) fn transition(&self, state: StateId) -> Option<(usize, StateId)> {
+        match self.states[state] {
+            State::Class { class, next } => Some((class, next)),
+            _ => None,
+        }
+    }
+
+    pub(super) fn closure(&self, state: StateId, context: Context) -> &[StateId] {
+        &self.closures[context.index()][state]
+    }
+}
+
+struct Compiler {
+    states: Vec<State>,
+    classes: Vec<ByteClass>,
+}
+
+impl Compiler {
+    fn push(&mut self, state: State) -> Result<StateId, Error> {
+        if self.states.len() == MAX_STATES {
+            return Err(Error::new(format!(
+                "Invalid regular expression: the compiled program cannot have more than \
+                {MAX_STATES} states"
+            )));
+        }
+        self.states.push(state);
+        Ok(self.states.len() - 1)
+    }
+
+    /// Compiles the expression so that it continues to `next` once matched,
+    /// returning the entry state of the expression
+    fn compile(&mut self, expr: &RegExpr, next: StateId) -> Result<StateId, Error> {
+        Ok(match expr {
+            RegExpr::Empty => next,
+            RegExpr::Class(class) => {
+                let class = self
+                    .classes
+                    .iter()
+                    .position(|c| c == class)
+                    .unwrap_or_else(|| {
+                        self.classes.push(*class);
+                        self.classes.len() - 1
+                    });
+                self.push(State::Class { class, next })?
+            }
+            RegExpr::Anchor(anchor) => self.push(State::Assert {
+                anchor: *anchor,
+                next,
+            })?,
+            RegExpr::Concat(exprs) => exprs
+                .iter()
+                .rev()
+                .try_fold(next, |next, expr| self.compile(expr, next))?,
+            RegExpr::Alternation(exprs) => {
+                let entries = exprs
+                    .iter()
+                    .map(|expr| self.compile(expr, next))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let mut entries = entries.into_iter().rev();
+                let mut entry = entries.next().unwrap_or(next);
+                for previous in entries {
+                    entry = self.push(State::Split(previous, entry))?;
+                }
+                entry
+            }
+            RegExpr::Repeat { expr, min, max } => {
+                let mut entry = match max {
+                    None => {
+                        // The loop state is patched once the body is compiled
+                        let loop_state = self.push(State::Epsilon(next))?;
+                        let body = self.compile(expr, loop_state)?;
+                        self.states[loop_state] = State::Split(body, next);
+                        loop_state
+                    }
+                    Some(max) => {
+                        // Optional copies, nested so that a copy can only
+                        // be matched if the previous one was
+                        let mut entry = next;
+                        for _ in *min..*max {
+                            let body = self.compile(expr, entry)?;
+                            entry = self.push(State::Split(body, next))?;
+                        }
+                        entry
+                    }
+                };
+                for _ in 0..*min {
+                    let num_states = self.states.len();
+                    entry = self.compile(expr, entry)?;
+                    // A copy without states only matches the empty string,
+                    // repeating it would not change the program
+                    if self.states.len() == num_states {
+                        break;
+                    }
+                }
+                entry
+            }
+        })
+    }
+}
+
+fn epsilon_closure(states: &[State], start: StateId, context: Context) -> Vec<StateId> {
+    let mut visited = vec![false; states.len()];
+    let mut stack = vec![start];
+    let mut closure = vec![];
+
+    while let Some(state) = stack.pop() {
+        if std::mem::replace(&mut visited[state], true) {
+            continue;
+        }
+
+        match states[state] {
+            State::Class { .. } | State::Match => closure.push(state),
+            State::Split(first, second) => stack.extend([second, first]),
+            State::Assert { anchor, next } => {
+                if context.satisfies(anchor) {
+                    stack.push(next);
+                }
+            }
+            State::Epsilon(next) => stack.push(next),
+        }
+    }
+
+    closure.sort_unstable();
+    closure
+}
@@ -0,0 +1,213 @@
+use super::parser::{parse, ByteClass};
+use super::{Regex, RegexCache};
+use crate::high_level_api::prelude::*;
+use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder};
+use crate::{ClientKey, FheUint8};
+
+fn setup_default_cpu() -> ClientKey {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    client_key
+}
+
+fn encrypt_content(content: &str, client_key: &ClientKey) -> Vec<FheUint8> {
+    content
+        .bytes()
+        .map(|byte| FheUint8::encrypt(byte, client_key))
+        .collect()
+}
+
+#[test]
+fn test_regex_parse_errors() {
+    for pattern in [
+        "(ab", "ab)", "[a-", "[z-a]", "*a", "a{3,1}", "a{1", "a{300}", r"\q", r"\x4", "ab\\",
+    ] {
+        assert!(parse(pattern).is_err(), "{pattern} should not be valid");
+    }
+
+    for pattern in [
+        "",
+        "abc",
+        "^a(b|c)*d$",
+        "(?i)[a-z0-9_]+",
+        r"\d{2,4}\.\w+",
+        "[^]a]",
+        r"\x41|\t",
+        "a{2}b{1,}c{0,3}",
+        "(?:ab)?",
+    ] {
+        assert!(parse(pattern).is_ok(), "{pattern} should be valid");
+    }
+}
+
+#[test]
+fn test_regex_program_size() {
+    for pattern in [
+        "a{256}{256}{256}",
+        "((a{256}){256}){256}",
+        "(a{2,256}){256}",
+        "(a|b|c|d){256}",
+        "(?:(?:(?:(?:(){256}){256}){256}){256}a{256}){256}",
+    ] {
+        assert!(
+            Regex::new(pattern).is_err(),
+            "{pattern} should be too large"
+        );
+    }
+
+    for pattern in ["a{256}", "(?:(?:(?:(){256}){256}){256})*", r"\w{5,100}"] {
+        assert!(
+            Regex::new(pattern).is_ok(),
+            "{pattern} should be small enough"
+        );
+    }
+}
+
+#[test]
+fn test_regex_byte_class() {
+    let mut class = ByteClass::range(b'a', b'f');
+    class.insert(b'0');
+    assert!(class.contains(b'c'));
+    assert!(class.contains(b'0'));
+    assert!(!class.contains(b'g'));
+    assert_eq!(class.ranges(), vec![(b'0', b'0'), (b'a', b'f')]);
+
+    let negated = class.negated();
+    assert!(!negated.contains(b'c'));
+    assert!(negated.contains(0));
+    assert!(negated.contains(u8::MAX));
+
+    let insensitive = ByteClass::single(b'q').case_insensitive();
+    assert!(insensitive.contains(b'q'));
+    assert!(insensitive.contains(b'Q'));
+
+    assert!(ByteClass::any().is_full());
+    assert!(ByteClass::empty().is_empty());
+}
+
+#[test]
+fn test_regex_is_match() {
+    let client_key = setup_default_cpu();
+
+    let content = encrypt_content("abc12", &client_key);
+    for (pattern, expected) in [
+        ("abc", true),
+        ("^abc", true),
+        ("^bc", false),
+        ("12$", true),
+        ("1$", false),
+        ("^abc12$", true),
+        ("b.2", false),
+        ("c.2", true),
+        ("(?i)ABC", true),
+        ("ABC", false),
+        (r"^[a-c]+\d*$", true),
+        (r"^\w{5}$", true),
+        (r"^\w{6}$", false),
+        ("x|3|c1", true),
+        ("ab?c", true),
+        ("b(c1)*2", true),
+        ("b(cc)*1", false),
+        ("(ab){2}", false),
+        (r"[^a-z]{3}", false),
+        ("", true),
+    ] {
+        let regex = Regex::new(pattern).unwrap();
+        let is_match = regex.is_match(&content).decrypt(&client_key);
+        assert_eq!(is_match, expected, "pattern: {pattern}");
+    }
+}
+
+#[test]
+fn test_regex_find() {
+    let client_key = setup_default_cpu();
+
+    let content = encrypt_content("aXbaXb", &client_key);
+    for (pattern, expected) in [
+        ("Xb", Some(1)),
+        ("b", Some(2)),
+        ("^a", Some(0)),
+        ("a.b$", Some(3)),
+        ("ba+", Some(2)),
+        ("$", Some(6)),
+        ("c", None),
+        ("^X", None),
+    ] {
+        let regex = Regex::new(pattern).unwrap();
+        let (is_match, position) = regex.find(&content);
+        let is_match = is_match.decrypt(&client_key);
+        let position: u32 = position.decrypt(&client_key);
+        assert_eq!(is_match, expected.is_some(), "pattern: {pattern}");
+        assert_eq!(position, expected.unwrap_or(0), "pattern: {pattern}");
+    }
+}
+
+#[cfg(feature = "strings")]
+#[test]
+fn test_regex_padded_string() {
+    use crate::FheAsciiString;
+
+    let client_key = setup_default_cpu();
+
+    let string = FheAsciiString::try_encrypt_with_padding("ab1", 2, &client_key).unwrap();
+    for (pattern, expected) in [
+        (r"\d$", Some(2)),
+        ("^ab1$", Some(0)),
+        (r"^ab1[\x00-\xff]", None),
+        ("b.$", Some(1)),
+        ("$", Some(3)),
+        ("^$", None),
+    ] {
+        let regex = Regex::new(pattern).unwrap();
+
+        let is_match = regex.is_match_str(&string).decrypt(&client_key);
+        assert_eq!(is_match, expected.is_some(), "pattern: {pattern}");
+
+        let (is_match, position) = regex.find_str(&string);
+        assert_eq!(
+            is_match.decrypt(&client_key),
+            expected.is_some(),
+            "pattern: {pattern}"
+        );
+        let position: u32 = position.decrypt(&client_key);
+        assert_eq!(position, expected.unwrap_or(0), "pattern: {pattern}");
+    }
+}
+
+#[test]
+fn test_regex_cache() {
+    let cache = RegexCache::new();
+    assert!(cache.is_empty());
+
+    let regex = cache.get("a+b").unwrap();
+    assert_eq!(regex.as_str(), "a+b");
+    let _ = cache.get("a+b").unwrap();
+    let _ = cache.get("c").unwrap();
+    assert_eq!(cache.len(), 2);
+
+    assert!(cache.get("(").is_err());
+    assert_eq!(cache.len(), 2);
+
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_regex_cache_eviction() {
+    let cache = RegexCache::with_capacity(2);
+    assert_eq!(cache.capacity(), 2);
+
+    let _ = cache.get("a").unwrap();
+    let _ = cache.get("b").unwrap();
+    // "a" becomes the most recently used
+    let _ = cache.get("a").unwrap();
+    let _ = cache.get("c").unwrap();
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.contains("a"));
+    assert!(!cache.contains("b"));
+    assert!(cache.contains("c"));
+
+    assert_eq!(RegexCache::new().capacity(), RegexCache::DEFAULT_CAPACITY);
+}
@@ -0,0 +1,435 @@
+use crate::high_level_api::global_state;
+use crate::high_level_api::keys::InternalServerKey;
+use crate::named::Named;
+use crate::prelude::{FheEq, FheOrd};
+use crate::strings::ciphertext::{ClearString, FheString, GenericPattern, UIntArg};
+use crate::strings::client_key::EncU16;
+use crate::strings::server_key::{
+    self as string_server_key, RSplit, RSplitN, RSplitTerminator, Split, SplitAsciiWhitespace,
+    SplitInclusive, SplitN, SplitTerminator,
+};
+use crate::strings::ServerKeyRef;
+use crate::{FheBool, FheUint16, FheUint32};
+use serde::{Deserialize, Serialize};
+use std::ops::Add;
+
+/// An encrypted ASCII string.
+///
+/// Each character is encrypted separately as an 8 bits integer. A string can optionally end with
+/// encrypted null characters (padding), in which case its length is hidden, and the operations on
+/// it return encrypted lengths and indices.
+///
+/// The operations require the server key to use parameters with a message modulus of 4, which is
+/// the case of the default parameters.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// let a = FheAsciiString::try_encrypt_with_padding("Hello", 2, &client_key).unwrap();
+/// let b = FheAsciiString::try_encrypt(" World", &client_key).unwrap();
+///
+/// let c = (&a + &b).to_uppercase();
+///
+/// let decrypted: String = c.decrypt(&client_key);
+/// assert_eq!(decrypted, "HELLO WORLD");
+///
+/// let has_world = c.contains("WORLD");
+/// assert!(has_world.decrypt(&client_key));
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FheAsciiString {
+    pub(in crate::high_level_api) inner: FheString,
+}
+
+impl Named for FheAsciiString {
+    const NAME: &'static str = "high_level_api::FheAsciiString";
+}
+
+/// The length of a [FheAsciiString].
+///
+/// The length of a string without padding is known in the clear, the length of a padded string
+/// is encrypted.
+pub enum FheStringLen {
+    NoPadding(usize),
+    Padding(FheUint32),
+}
+
+/// Whether a [FheAsciiString] is empty.
+///
+/// The emptiness of a string without padding is known in the clear, the emptiness of a padded
+/// string is encrypted.
+pub enum FheStringIsEmpty {
+    NoPadding(bool),
+    Padding(FheBool),
+}
+
+/// A pattern that can be searched for in a [FheAsciiString].
+///
+/// It is implemented for encrypted strings, and for clear strings that are ASCII, do not contain
+/// null characters and are at most 32 characters long.
+pub trait FheStringPattern {
+    fn to_generic_pattern(&self) -> GenericPattern;
+}
+
+impl FheStringPattern for FheAsciiString {
+    fn to_generic_pattern(&self) -> GenericPattern {
+        GenericPattern::Enc(self.inner.clone())
+    }
+}
+
+impl FheStringPattern for ClearString {
+    fn to_generic_pattern(&self) -> GenericPattern {
+        GenericPattern::Clear(self.clone())
+    }
+}
+
+impl FheStringPattern for str {
+    fn to_generic_pattern(&self) -> GenericPattern {
+        GenericPattern::Clear(ClearString::new(self.to_string()))
+    }
+}
+
+/// A number of repetitions, replacements or splits, either clear or encrypted.
+///
+/// As the operations have to run for the worst case, an encrypted count comes with a clear upper
+/// bound.
+#[derive(Copy, Clone)]
+pub enum FheStringCount<'a> {
+    Clear(u16),
+    Encrypted { count: &'a FheUint16, max: u16 },
+}
+
+impl From<u16> for FheStringCount<'_> {
+    fn from(count: u16) -> Self {
+        Self::Clear(count)
+    }
+}
+
+impl FheStringCount<'_> {
+    fn to_uint_arg(self) -> UIntArg {
+        match self {
+            Self::Clear(count) => UIntArg::Clear(count),
+            Self::Encrypted { count, max } => UIntArg::Enc(EncU16::new(
+                count.ciphertext.on_cpu().into_owned(),
+                Some(max),
+            )),
+        }
+    }
+}
+
+/// Iterator over the substrings of a [FheAsciiString].
+///
+/// Each call to `next` returns the next substring and an encrypted boolean telling whether the
+/// substring is a value (`Some`) or not (`None`).
+pub trait FheStringIterator {
+    fn next(&mut self) -> (FheAsciiString, FheBool);
+}
+
+/// Iterator returned by the split methods of [FheAsciiString].
+pub struct FheStringSplit<I> {
+    inner: I,
+}
+
+impl<I: string_server_key::FheStringIterator> FheStringIterator for FheStringSplit<I> {
+    fn next(&mut self) -> (FheAsciiString, FheBool) {
+        let (string, is_some) = with_string_server_key(|sk| self.inner.next(sk));
+
+        (FheAsciiString::new(string), FheBool::new(is_some))
+    }
+}
+
+#[track_caller]
+fn with_string_server_key<R, F>(func: F) -> R
+where
+    F: FnOnce(&ServerKeyRef<'_>) -> R,
+{
+    global_state::with_internal_keys(|key| match key {
+        InternalServerKey::Cpu(cpu_key) => func(&crate::strings::ServerKey::new(cpu_key.pbs_key())),
+        #[cfg(feature = "gpu")]
+        InternalServerKey::Cuda(_) => {
+            panic!("Cuda devices do not support strings yet");
+        }
+    })
+}
+
+impl FheAsciiString {
+    pub(in crate::high_level_api) fn new(inner: FheString) -> Self {
+        Self { inner }
+    }
+
+    /// Returns whether the string ends with padding null characters
+    pub fn is_padded(&self) -> bool {
+        self.inner.is_padded()
+    }
+
+    /// Returns the length of the string
+    pub fn len(&self) -> FheStringLen {
+        with_string_server_key(|sk| match sk.len(&self.inner) {
+            string_server_key::FheStringLen::NoPadding(len) => FheStringLen::NoPadding(len),
+            string_server_key::FheStringLen::Padding(len) => {
+                FheStringLen::Padding(FheUint32::new(len))
+            }
+        })
+    }
+
+    /// Returns whether the string is empty
+    pub fn is_empty(&self) -> FheStringIsEmpty {
+        with_string_server_key(|sk| match sk.is_empty(&self.inner) {
+            string_server_key::FheStringIsEmpty::NoPadding(is_empty) => {
+                FheStringIsEmpty::NoPadding(is_empty)
+            }
+            string_server_key::FheStringIsEmpty::Padding(is_empty) => {
+                FheStringIsEmpty::Padding(FheBool::new(is_empty))
+            }
+        })
+    }
+
+    pub fn to_lowercase(&self) -> Self {
+        with_string_server_key(|sk| Self::new(sk.to_lowercase(&self.inner)))
+    }
+
+    pub fn to_uppercase(&self) -> Self {
+        with_string_server_key(|sk| Self::new(sk.to_uppercase(&self.inner)))
+    }
+
+    pub fn eq_ignore_case<P: FheStringPattern + ?Sized>(&self, other: &P) -> FheBool {
+        let other = other.to_generic_pattern();
+        with_string_server_key(|sk| FheBool::new(sk.eq_ignore_case(&self.inner, &other)))
+    }
+
+    pub fn concat(&self, other: &Self) -> Self {
+        with_string_server_key(|sk| Self::new(sk.concat(&self.inner, &other.inner)))
+    }
+
+    pub fn repeat<'a>(&self, count: impl Into<FheStringCount<'a>>) -> Self {
+        let count = count.into().to_uint_arg();
+        with_string_server_key(|sk| Self::new(sk.repeat(&self.inner, &count)))
+    }
+
+    pub fn trim(&self) -> Self {
+        with_string_server_key(|sk| Self::new(sk.trim(&self.inner)))
+    }
+
+    pub fn trim_start(&self) -> Self {
+        with_string_server_key(|sk| Self::new(sk.trim_start(&self.inner)))
+    }
+
+    pub fn trim_end(&self) -> Self {
+        with_string_server_key(|sk| Self::new(sk.trim_end(&self.inner)))
+    }
+
+    pub fn contains<P: FheStringPattern + ?Sized>(&self, pattern: &P) -> FheBool {
+        let pattern = pattern.to_generic_pattern();
+        with_string_server_key(|sk| FheBool::new(sk.contains(&self.inner, &pattern)))
+    }
+
+    pub fn starts_with<P: FheStringPattern + ?Sized>(&self, pattern: &P) -> FheBool {
+        let pattern = pattern.to_generic_pattern();
+        with_string_server_key(|sk| FheBool::new(sk.starts_with(&self.inner, &pattern)))
+    }
+
+    pub fn ends_with<P: FheStringPattern + ?Sized>(&self, pattern: &P) -> FheBool {
+        let pattern = pattern.to_generic_pattern();
+        with_string_server_key(|sk| FheBool::new(sk.ends_with(&self.inner, &pattern)))
+    }
+
+    /// Returns the index of the first match of the pattern, and whether there is a match
+    pub fn find<P: FheStringPattern + ?Sized>(&self, pattern: &P) -> (FheUint32, FheBool) {
+        let pattern = pattern.to_generic_pattern();
+        with_string_server_key(|sk| {
+            let (index, is_some) = sk.find(&self.inner, &pattern);
+            (FheUint32::new(index), FheBool::new(is_some))
+        })
+    }
+
+    /// Returns the index of the last match of the pattern, and whether there is a match
+    pub fn rfind<P: FheStringPattern + ?Sized>(&self, pattern: &P) -> (FheUint32, FheBool) {
+        let pattern = pattern.to_generic_pattern();
+        with_string_server_key(|sk| {
+            let (index, is_some) = sk.rfind(&self.inner, &pattern);
+            (FheUint32::new(index), FheBool::new(is_some))
+        })
+    }
+
+    /// Returns the string without the prefix, and whether the string started with the prefix
+    ///
+    /// If the string does not start with the prefix, it is returned unchanged.
+    pub fn strip_prefix<P: FheStringPattern + ?Sized>(&self, pattern: &P) -> (Self, FheBool) {
+        let pattern = pattern.to_generic_pattern();
+        with_string_server_key(|sk| {
+            let (result, is_some) = sk.strip_prefix(&self.inner, &pattern);
+            (Self::new(result), FheBool::new(is_some))
+        })
+    }
+
+    /// Returns the string without the suffix, and whether the string ended with the suffix
+    ///
+    /// If the string does not end with the suffix, it is returned unchanged.
+    pub fn strip_suffix<P: FheStringPattern + ?Sized>(&self, pattern: &P) -> (Self, FheBool) {
+        let pattern = pattern.to_generic_pattern();
+        with_string_server_key(|sk| {
+            let (result, is_some) = sk.strip_suffix(&self.inner, &pattern);
+            (Self::new(result), FheBool::new(is_some))
+        })
+    }
+
+    pub fn replace<P: FheStringPattern + ?Sized>(&self, from: &P, to: &Self) -> Self {
+        let from = from.to_generic_pattern();
+        with_string_server_key(|sk| Self::new(sk.replace(&self.inner, &from, &to.inner)))
+    }
+
+    pub fn replacen<'a, P: FheStringPattern + ?Sized>(
+        &self,
+        from: &P,
+        to: &Self,
+        count: impl Into<FheStringCount<'a>>,
+    ) -> Self {
+        let from = from.to_generic_pattern();
+        let count = count.into().to_uint_arg();
+        with_string_server_key(|sk| Self::new(sk.replacen(&self.inner, &from, &to.inner, &count)))
+    }
+
+    /// Splits the string at the first match of the pattern
+    ///
+    /// Returns the parts before and after the match, and whether there is a match.
+    pub fn split_once<P: FheStringPattern + ?Sized>(&self, pattern: &P) -> (Self, Self, FheBool) {
+        let pattern = pattern.to_generic_pattern();
+        with_string_server_key(|sk| {
+            let (lhs, rhs, is_some) = sk.split_once(&self.inner, &pattern);
+            (Self::new(lhs), Self::new(rhs), FheBool::new(is_some))
+        })
+    }
+
+    /// Splits the string at the last match of the pattern
+    ///
+    /// Returns the parts before and after the match, and whether there is a match.
+    pub fn rsplit_once<P: FheStringPattern + ?Sized>(&self, pattern: &P) -> (Self, Self, FheBool) {
+        let pattern = pattern.to_generic_pattern();
+        with_string_server_key(|sk| {
+            let (lhs, rhs, is_some) = sk.rsplit_once(&self.inner, &pattern);
+            (Self::new(lhs), Self::new(rhs), FheBool::new(is_some))
+        })
+    }
+
+    pub fn split<P: FheStringPattern + ?Sized>(&self, pattern: &P) -> FheStringSplit<Split> {
+        let pattern = pattern.to_generic_pattern();
+        let inner = with_string_server_key(|sk| sk.split(&self.inner, &pattern));
+        FheStringSplit { inner }
+    }
+
+    pub fn rsplit<P: FheStringPattern + ?Sized>(&self, pattern: &P) -> FheStringSplit<RSplit> {
+        let pattern = pattern.to_generic_pattern();
+        let inner = with_string_server_key(|sk| sk.rsplit(&self.inner, &pattern));
+        FheStringSplit { inner }
+    }
+
+    pub fn splitn<'a, P: FheStringPattern + ?Sized>(
+        &self,
+        pattern: &P,
+        count: impl Into<FheStringCount<'a>>,
+    ) -> FheStringSplit<SplitN> {
+        let pattern = pattern.to_generic_pattern();
+        let count = count.into().to_uint_arg();
+        let inner = with_string_server_key(|sk| sk.splitn(&self.inner, &pattern, count));
+        FheStringSplit { inner }
+    }
+
+    pub fn rsplitn<'a, P: FheStringPattern + ?Sized>(
+        &self,
+        pattern: &P,
+        count: impl Into<FheStringCount<'a>>,
+    ) -> FheStringSplit<RSplitN> {
+        let pattern = pattern.to_generic_pattern();
+        let count = count.into().to_uint_arg();
+        let inner = with_string_server_key(|sk| sk.rsplitn(&self.inner, &pattern, count));
+        FheStringSplit { inner }
+    }
+
+    pub fn split_terminator<P: FheStringPattern + ?Sized>(
+        &self,
+        pattern: &P,
+    ) -> FheStringSplit<SplitTerminator> {
+        let pattern = pattern.to_generic_pattern();
+        let inner = with_string_server_key(|sk| sk.split_terminator(&self.inner, &pattern));
+        FheStringSplit { inner }
+    }
+
+    pub fn rsplit_terminator<P: FheStringPattern + ?Sized>(
+        &self,
+        pattern: &P,
+    ) -> FheStringSplit<RSplitTerminator> {
+        let pattern = pattern.to_generic_pattern();
+        let inner = with_string_server_key(|sk| sk.rsplit_terminator(&self.inner, &pattern));
+        FheStringSplit { inner }
+    }
+
+    pub fn split_inclusive<P: FheStringPattern + ?Sized>(
+        &self,
+        pattern: &P,
+    ) -> FheStringSplit<SplitInclusive> {
+        let pattern = pattern.to_generic_pattern();
+        let inner = with_string_server_key(|sk| sk.split_inclusive(&self.inner, &pattern));
+        FheStringSplit { inner }
+    }
+
+    pub fn split_ascii_whitespace(&self) -> FheStringSplit<SplitAsciiWhitespace> {
+        let inner = with_string_server_key(|sk| sk.split_ascii_whitespace(&self.inner));
+        FheStringSplit { inner }
+    }
+}
+
+impl Add<&FheAsciiString> for &FheAsciiString {
+    type Output = FheAsciiString;
+
+    fn add(self, rhs: &FheAsciiString) -> Self::Output {
+        self.concat(rhs)
+    }
+}
+
+impl FheEq<&Self> for FheAsciiString {
+    fn eq(&self, other: &Self) -> FheBool {
+        let other = other.to_generic_pattern();
+        with_string_server_key(|sk| FheBool::new(sk.eq(&self.inner, &other)))
+    }
+
+    fn ne(&self, other: &Self) -> FheBool {
+        let other = other.to_generic_pattern();
+        with_string_server_key(|sk| FheBool::new(sk.ne(&self.inner, &other)))
+    }
+}
+
+impl FheEq<&ClearString> for FheAsciiString {
+    fn eq(&self, other: &ClearString) -> FheBool {
+        let other = other.to_generic_pattern();
+        with_string_server_key(|sk| FheBool::new(sk.eq(&self.inner, &other)))
+    }
+
+    fn ne(&self, other: &ClearString) -> FheBool {
+        let other = other.to_generic_pattern();
+        with_string_server_key(|sk| FheBool::new(sk.ne(&self.inner, &other)))
+    }
+}
+
+impl FheOrd<&Self> for FheAsciiString {
+    fn lt(&self, other: &Self) -> FheBool {
+        with_string_server_key(|sk| FheBool::new(sk.lt(&self.inner, &other.inner)))
+    }
+
+    fn le(&self, other: &Self) -> FheBool {
+        with_string_server_key(|sk| FheBool::new(sk.le(&self.inner, &other.inner)))
+    }
+
+    fn gt(&self, other: &Self) -> FheBool {
+        with_string_server_key(|sk| FheBool::new(sk.gt(&self.inner, &other.inner)))
+    }
+
+    fn ge(&self, other: &Self) -> FheBool {
+        with_string_server_key(|sk| FheBool::new(sk.ge(&self.inner, &other.inner)))
+    }
+}
@@ -0,0 +1,98 @@
+use super::base::FheAsciiString;
+use crate::integer::ciphertext::CompressedRadixCiphertext;
+use crate::named::Named;
+use crate::prelude::FheTryEncrypt;
+use crate::strings::ciphertext::FheString;
+use crate::strings::client_key::EncryptOutput;
+use crate::ClientKey;
+use serde::{Deserialize, Serialize};
+
+/// Compressed [FheAsciiString]
+///
+/// Meant to save in storage space / transfer.
+///
+/// - A Compressed type must be decompressed before it can be used.
+/// - It is not possible to compress an existing [FheAsciiString], compression can only be
+///   achieved at encryption time
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, CompressedFheAsciiString, ConfigBuilder};
+///
+/// let (client_key, _) = generate_keys(ConfigBuilder::default());
+/// let compressed =
+///     CompressedFheAsciiString::try_encrypt_with_padding("tfhe-rs", 3, &client_key).unwrap();
+///
+/// let decompressed = compressed.decompress();
+/// let decrypted: String = decompressed.decrypt(&client_key);
+/// assert_eq!(decrypted, "tfhe-rs");
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CompressedFheAsciiString {
+    #[serde(with = "crate::versionable::nested_vec")]
+    enc_chars: Vec<CompressedRadixCiphertext>,
+    padded: bool,
+}
+
+impl Named for CompressedFheAsciiString {
+    const NAME: &'static str = "high_level_api::CompressedFheAsciiString";
+}
+
+impl CompressedFheAsciiString {
+    /// Encrypts a string followed by `padding` encrypted null characters.
+    ///
+    /// See [FheAsciiString::try_encrypt_with_padding].
+    pub fn try_encrypt_with_padding(
+        str: &str,
+        padding: u32,
+        key: &ClientKey,
+    ) -> crate::Result<Self> {
+        if !str.is_ascii() || str.contains('\0') {
+            return Err(crate::Error::new(
+                "Strings must be ASCII and must not contain null characters".to_string(),
+            ));
+        }
+
+        let integer_client_key = &key.key.key;
+
+        if integer_client_key.parameters().message_modulus().0 != 4 {
+            return Err(crate::Error::new(
+                "Strings require parameters with a message modulus of 4".to_string(),
+            ));
+        }
+
+        let enc_chars = str
+            .bytes()
+            .chain(std::iter::repeat(0).take(padding as usize))
+            .map(|byte| integer_client_key.encrypt_radix_compressed(byte, 4))
+            .collect();
+
+        Ok(Self {
+            enc_chars,
+            padded: padding != 0,
+        })
+    }
+
+    /// Decompresses itself into a [FheAsciiString]
+    ///
+    /// See [CompressedFheAsciiString] example.
+    pub fn decompress(&self) -> FheAsciiString {
+        let enc_chars = self
+            .enc_chars
+            .iter()
+            .map(CompressedRadixCiphertext::decompress)
+            .collect();
+
+        FheAsciiString::new(FheString::from(EncryptOutput::new(enc_chars, self.padded)))
+    }
+}
+
+impl FheTryEncrypt<&str, ClientKey> for CompressedFheAsciiString {
+    type Error = crate::Error;
+
+    fn try_encrypt(value: &str, key: &ClientKey) -> Result<Self, Self::Error> {
+        Self::try_encrypt_with_padding(value, 0, key)
+    }
+}
@@ -0,0 +1,131 @@
+use super::base::FheAsciiString;
+use crate::integer::RadixCiphertext;
+use crate::prelude::{FheDecrypt, FheTryEncrypt};
+use crate::strings::ciphertext::FheString;
+use crate::strings::client_key::EncryptOutput;
+use crate::{ClientKey, CompactPublicKey};
+
+/// A key that can encrypt a [FheAsciiString].
+///
+/// It is implemented by the [ClientKey] and the [CompactPublicKey].
+pub trait AsciiEncryptionKey {
+    /// Encrypts each byte as a radix ciphertext of 4 blocks
+    fn encrypt_ascii_bytes(&self, bytes: &[u8]) -> crate::Result<Vec<RadixCiphertext>>;
+}
+
+impl AsciiEncryptionKey for ClientKey {
+    fn encrypt_ascii_bytes(&self, bytes: &[u8]) -> crate::Result<Vec<RadixCiphertext>> {
+        let integer_client_key = &self.key.key;
+
+        if integer_client_key.parameters().message_modulus().0 != 4 {
+            return Err(crate::Error::new(
+                "Strings require parameters with a message modulus of 4".to_string(),
+            ));
+        }
+
+        Ok(bytes
+            .iter()
+            .map(|byte| integer_client_key.encrypt_radix(*byte, 4))
+            .collect())
+    }
+}
+
+impl AsciiEncryptionKey for CompactPublicKey {
+    fn encrypt_ascii_bytes(&self, bytes: &[u8]) -> crate::Result<Vec<RadixCiphertext>> {
+        let integer_public_key = &self.key.key;
+
+        if integer_public_key.key.parameters.message_modulus.0 != 4 {
+            return Err(crate::Error::new(
+                "Strings require parameters with a message modulus of 4".to_string(),
+            ));
+        }
+
+        if bytes.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let expander = integer_public_key
+            .encrypt_slice_radix_compact(bytes, 4)
+            .expand_without_unpacking();
+
+        (0..bytes.len())
+            .map(|i| expander.get::<RadixCiphertext>(i).unwrap())
+            .collect()
+    }
+}
+
+impl FheAsciiString {
+    /// Encrypts a string followed by `padding` encrypted null characters.
+    ///
+    /// The padding hides the length of the string: only the total number of characters, string
+    /// and padding included, is visible.
+    ///
+    /// Returns an error if the string is not ASCII or contains null characters.
+    pub fn try_encrypt_with_padding<K: AsciiEncryptionKey>(
+        str: &str,
+        padding: u32,
+        key: &K,
+    ) -> crate::Result<Self> {
+        if !str.is_ascii() || str.contains('\0') {
+            return Err(crate::Error::new(
+                "Strings must be ASCII and must not contain null characters".to_string(),
+            ));
+        }
+
+        let mut bytes = str.as_bytes().to_vec();
+        bytes.resize(bytes.len() + padding as usize, 0);
+
+        let enc_chars = key.encrypt_ascii_bytes(&bytes)?;
+
+        Ok(Self::new(FheString::from(EncryptOutput::new(
+            enc_chars,
+            padding != 0,
+        ))))
+    }
+
+    /// Encrypts a string padded with encrypted null characters up to `size` characters.
+    ///
+    /// Returns an error if the string is longer than `size`, not ASCII or contains null
+    /// characters.
+    pub fn try_encrypt_with_fixed_size<K: AsciiEncryptionKey>(
+        str: &str,
+        size: u32,
+        key: &K,
+    ) -> crate::Result<Self> {
+        let padding = (size as usize).checked_sub(str.len()).ok_or_else(|| {
+            crate::Error::new(format!(
+                "String of length {} does not fit in {size} characters",
+                str.len()
+            ))
+        })?;
+
+        Self::try_encrypt_with_padding(str, padding as u32, key)
+    }
+}
+
+impl FheTryEncrypt<&str, ClientKey> for FheAsciiString {
+    type Error = crate::Error;
+
+    fn try_encrypt(value: &str, key: &ClientKey) -> Result<Self, Self::Error> {
+        Self::try_encrypt_with_padding(value, 0, key)
+    }
+}
+
+impl FheTryEncrypt<&str, CompactPublicKey> for FheAsciiString {
+    type Error = crate::Error;
+
+    fn try_encrypt(value: &str, key: &CompactPublicKey) -> Result<Self, Self::Error> {
+        Self::try_encrypt_with_padding(value, 0, key)
+    }
+}
+
+impl FheDecrypt<String> for FheAsciiString {
+    /// Decrypts the string, without its padding
+    ///
+    /// # Panics
+    ///
+    /// Panics if the decrypted characters are not ASCII, or if the padding is malformed.
+    fn decrypt(&self, key: &ClientKey) -> String {
+        crate::strings::ClientKey::new(&key.key.key).decrypt_ascii(&self.inner)
+    }
+}
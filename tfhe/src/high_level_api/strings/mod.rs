@@ -0,0 +1,12 @@
+pub use base::{
+    FheAsciiString, FheStringCount, FheStringIsEmpty, FheStringIterator, FheStringLen,
+    FheStringPattern, FheStringSplit,
+};
+pub use compressed::CompressedFheAsciiString;
+pub use encrypt::AsciiEncryptionKey;
+
+mod base;
+mod compressed;
+mod encrypt;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,157 @@
+use crate::high_level_api::prelude::*;
+use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder};
+use crate::safe_deserialization::safe_deserialize;
+use crate::shortint::parameters::classic::compact_pk::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+use crate::shortint::parameters::PARAM_MESSAGE_1_CARRY_1_KS_PBS;
+use crate::{
+    ClientKey, CompactPublicKey, CompressedFheAsciiString, FheAsciiString, FheStringIsEmpty,
+    FheStringIterator, FheStringLen,
+};
+
+fn setup_default_cpu() -> ClientKey {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    client_key
+}
+
+#[test]
+fn test_ascii_string_encrypt_decrypt() {
+    let (client_key, _) = generate_keys(ConfigBuilder::default());
+
+    for (str, padding) in [("", 0), ("", 2), ("tfhe-rs", 0), ("tfhe-rs", 3)] {
+        let string = FheAsciiString::try_encrypt_with_padding(str, padding, &client_key).unwrap();
+        assert_eq!(string.is_padded(), padding != 0);
+
+        let decrypted: String = string.decrypt(&client_key);
+        assert_eq!(decrypted, str);
+    }
+
+    let string = FheAsciiString::try_encrypt_with_fixed_size("zama", 8, &client_key).unwrap();
+    assert!(string.is_padded());
+    let decrypted: String = string.decrypt(&client_key);
+    assert_eq!(decrypted, "zama");
+
+    assert!(FheAsciiString::try_encrypt_with_fixed_size("zama", 2, &client_key).is_err());
+    assert!(FheAsciiString::try_encrypt("caf\u{e9}", &client_key).is_err());
+    assert!(FheAsciiString::try_encrypt("a\0b", &client_key).is_err());
+}
+
+#[test]
+fn test_ascii_string_requires_message_modulus_4() {
+    let config = ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_1_CARRY_1_KS_PBS, None);
+    let (client_key, _) = generate_keys(config);
+
+    assert!(FheAsciiString::try_encrypt("tfhe-rs", &client_key).is_err());
+    assert!(CompressedFheAsciiString::try_encrypt("tfhe-rs", &client_key).is_err());
+}
+
+#[test]
+fn test_ascii_string_compact_public_key() {
+    let config = ConfigBuilder::default()
+        .use_custom_parameters(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS, None)
+        .build();
+    let (client_key, _) = generate_keys(config);
+    let public_key = CompactPublicKey::new(&client_key);
+
+    let string = FheAsciiString::try_encrypt("tfhe-rs", &public_key).unwrap();
+    let decrypted: String = string.decrypt(&client_key);
+    assert_eq!(decrypted, "tfhe-rs");
+
+    let string = FheAsciiString::try_encrypt_with_padding("", 1, &public_key).unwrap();
+    let decrypted: String = string.decrypt(&client_key);
+    assert_eq!(decrypted, "");
+}
+
+#[test]
+fn test_ascii_string_len_and_is_empty() {
+    let client_key = setup_default_cpu();
+
+    let string = FheAsciiString::try_encrypt("tfhe", &client_key).unwrap();
+    assert!(matches!(string.len(), FheStringLen::NoPadding(4)));
+    assert!(matches!(
+        string.is_empty(),
+        FheStringIsEmpty::NoPadding(false)
+    ));
+
+    let string = FheAsciiString::try_encrypt_with_padding("tfhe", 2, &client_key).unwrap();
+    match string.len() {
+        FheStringLen::Padding(len) => {
+            let len: u32 = len.decrypt(&client_key);
+            assert_eq!(len, 4);
+        }
+        FheStringLen::NoPadding(_) => panic!("Expected an encrypted length"),
+    }
+    match string.is_empty() {
+        FheStringIsEmpty::Padding(is_empty) => assert!(!is_empty.decrypt(&client_key)),
+        FheStringIsEmpty::NoPadding(_) => panic!("Expected an encrypted boolean"),
+    }
+}
+
+#[test]
+fn test_ascii_string_patterns() {
+    let client_key = setup_default_cpu();
+
+    let string = FheAsciiString::try_encrypt_with_padding("tfhe-rs", 1, &client_key).unwrap();
+    let pattern = FheAsciiString::try_encrypt("rs", &client_key).unwrap();
+
+    assert!(string.contains(&pattern).decrypt(&client_key));
+    assert!(string.ends_with(&pattern).decrypt(&client_key));
+    assert!(!string.starts_with("rs").decrypt(&client_key));
+
+    let (index, found) = string.find("-");
+    assert!(found.decrypt(&client_key));
+    let index: u32 = index.decrypt(&client_key);
+    assert_eq!(index, 4);
+
+    let (_, found) = string.find("zama");
+    assert!(!found.decrypt(&client_key));
+
+    let other = FheAsciiString::try_encrypt("tfhe-rs", &client_key).unwrap();
+    assert!(string.eq(&other).decrypt(&client_key));
+
+    let concatenated = &string + &pattern;
+    let decrypted: String = concatenated.decrypt(&client_key);
+    assert_eq!(decrypted, "tfhe-rsrs");
+}
+
+#[test]
+fn test_ascii_string_split() {
+    let client_key = setup_default_cpu();
+
+    let string = FheAsciiString::try_encrypt("a,b", &client_key).unwrap();
+    let mut split = string.split(",");
+
+    for expected in [Some("a"), Some("b"), None] {
+        let (substring, is_some) = split.next();
+
+        assert_eq!(is_some.decrypt(&client_key), expected.is_some());
+        if let Some(expected) = expected {
+            let decrypted: String = substring.decrypt(&client_key);
+            assert_eq!(decrypted, expected);
+        }
+    }
+}
+
+#[test]
+fn test_compressed_ascii_string_serialization() {
+    let (client_key, _) = generate_keys(ConfigBuilder::default());
+
+    let compressed =
+        CompressedFheAsciiString::try_encrypt_with_padding("tfhe-rs", 2, &client_key).unwrap();
+
+    let mut serialized = vec![];
+    crate::safe_serialize(&compressed, &mut serialized, 1 << 20).unwrap();
+    let deserialized: CompressedFheAsciiString =
+        safe_deserialize(serialized.as_slice(), 1 << 20).unwrap();
+
+    let string = deserialized.decompress();
+    assert!(string.is_padded());
+
+    let mut serialized = vec![];
+    crate::safe_serialize(&string, &mut serialized, 1 << 20).unwrap();
+    let deserialized: FheAsciiString = safe_deserialize(serialized.as_slice(), 1 << 20).unwrap();
+
+    let decrypted: String = deserialized.decrypt(&client_key);
+    assert_eq!(decrypted, "tfhe-rs");
+}
@@ -169,3 +169,216 @@ fn test_serialize_deserialize_are_implemented() {
     can_be_deserialized(&csks);
     can_be_deserialized(&pksz);
 }
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_threshold_decryption() {
+    use crate::{set_server_key, DecryptionShare, ThresholdClientKeyShare};
+
+    let (cks, sks) = generate_keys(ConfigBuilder::default());
+    set_server_key(sks);
+
+    let key_shares = cks.generate_threshold_key_shares(3, 5).unwrap();
+    assert_eq!(key_shares.len(), 5);
+
+    let clear_a = 213u8;
+    let clear_b = 97u8;
+    let a = FheUint8::encrypt(clear_a, &cks);
+    let b = FheUint8::encrypt(clear_b, &cks);
+
+    // The result of a computation is decrypted as well as a fresh ciphertext
+    let sum = &a + &b;
+    let is_greater = a.gt(&b);
+
+    for participants in [vec![0, 1, 2], vec![1, 3, 4], vec![0, 1, 2, 3, 4]] {
+        let shares: Vec<DecryptionShare> = participants
+            .iter()
+            .map(|&party| {
+                sum.partial_decrypt(&key_shares[party], &participants)
+                    .unwrap()
+            })
+            .collect();
+        let decrypted: u8 = sum.combine_decryption_shares(&shares).unwrap();
+        assert_eq!(decrypted, clear_a.wrapping_add(clear_b));
+
+        let shares: Vec<DecryptionShare> = participants
+            .iter()
+            .map(|&party| {
+                is_greater
+                    .partial_decrypt(&key_shares[party], &participants)
+                    .unwrap()
+            })
+            .collect();
+        let decrypted = is_greater.combine_decryption_shares(&shares).unwrap();
+        assert!(decrypted);
+    }
+
+    // Not enough participants
+    assert!(a.partial_decrypt(&key_shares[0], &[0, 1]).is_err());
+    // The party is not a participant
+    assert!(a.partial_decrypt(&key_shares[0], &[1, 2, 3]).is_err());
+    // Missing share
+    let participants = [0, 1, 2];
+    let shares: Vec<_> = participants[..2]
+        .iter()
+        .map(|&party| {
+            a.partial_decrypt(&key_shares[party], &participants)
+                .unwrap()
+        })
+        .collect();
+    assert!(FheThresholdDecrypt::<u8>::combine_decryption_shares(&a, &shares).is_err());
+
+    // Shares can be sent to their parties
+    let mut serialized = vec![];
+    crate::safe_serialize(&key_shares[4], &mut serialized, 1 << 30).unwrap();
+    let key_share: ThresholdClientKeyShare =
+        crate::safe_deserialization::safe_deserialize(serialized.as_slice(), 1 << 30).unwrap();
+    assert_eq!(key_share.party_index(), 4);
+
+    assert!(cks.generate_threshold_key_shares(0, 3).is_err());
+    assert!(cks.generate_threshold_key_shares(4, 3).is_err());
+    // C(40, 19) sub-keys
+    assert!(cks.generate_threshold_key_shares(20, 40).is_err());
+    // The flooding noise of that many parties does not fit in the decryption margin
+    assert!(cks.generate_threshold_key_shares(1, usize::MAX).is_err());
+}
+
+#[test]
+fn test_re_randomization() {
+    use crate::{set_server_key, CompactCiphertextList, FheInt8, Seed};
+
+    let (cks, sks) = generate_keys(ConfigBuilder::default());
+    set_server_key(sks);
+    let cpk = CompactPublicKey::new(&cks);
+
+    let a = FheUint8::encrypt(213u8, &cks);
+    let b = FheUint8::encrypt(97u8, &cks);
+    let sum = &a + &b;
+
+    let mut re_randomized = sum.clone();
+    re_randomized.re_randomize(&cpk, Seed(0)).unwrap();
+    assert_ne!(
+        bincode::serialize(&re_randomized).unwrap(),
+        bincode::serialize(&sum).unwrap()
+    );
+    let decrypted: u8 = re_randomized.decrypt(&cks);
+    assert_eq!(decrypted, 213u8.wrapping_add(97));
+
+    // The randomness only comes from the seed
+    let mut same_seed = sum.clone();
+    same_seed.re_randomize(&cpk, Seed(0)).unwrap();
+    assert_eq!(
+        bincode::serialize(&same_seed).unwrap(),
+        bincode::serialize(&re_randomized).unwrap()
+    );
+    let mut other_seed = sum;
+    other_seed.re_randomize(&cpk, Seed(1)).unwrap();
+    assert_ne!(
+        bincode::serialize(&other_seed).unwrap(),
+        bincode::serialize(&re_randomized).unwrap()
+    );
+
+    let mut c = -FheInt8::encrypt(73i8, &cks);
+    c.re_randomize(&cpk, Seed(2)).unwrap();
+    let decrypted: i8 = c.decrypt(&cks);
+    assert_eq!(decrypted, -73);
+
+    let mut d = a.gt(&b);
+    d.re_randomize(&cpk, Seed(3)).unwrap();
+    assert!(d.decrypt(&cks));
+
+    let list = CompactCiphertextList::builder(&cpk)
+        .push(17u8)
+        .push(-5i8)
+        .push(true)
+        .build();
+    let mut re_randomized_list = list.clone();
+    re_randomized_list.re_randomize(&cpk, Seed(4)).unwrap();
+    assert_ne!(
+        bincode::serialize(&re_randomized_list).unwrap(),
+        bincode::serialize(&list).unwrap()
+    );
+
+    let expander = re_randomized_list.expand().unwrap();
+    let e: FheUint8 = expander.get(0).unwrap().unwrap();
+    let f: FheInt8 = expander.get(1).unwrap().unwrap();
+    let g: FheBool = expander.get(2).unwrap().unwrap();
+    let decrypted: u8 = e.decrypt(&cks);
+    assert_eq!(decrypted, 17);
+    let decrypted: i8 = f.decrypt(&cks);
+    assert_eq!(decrypted, -5);
+    assert!(g.decrypt(&cks));
+}
+
+#[test]
+fn test_key_switching() {
+    use crate::shortint::parameters::{
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+    };
+    use crate::{
+        set_server_key, CompactCiphertextList, FheInt8, FheUint16, KeySwitchingKey, ServerKey,
+    };
+
+    let (cks_1, sks_1) = generate_keys(ConfigBuilder::default());
+    let (cks_2, sks_2) = generate_keys(
+        ConfigBuilder::default().use_custom_parameters(PARAM_MESSAGE_1_CARRY_1_KS_PBS, None),
+    );
+    let (cks_3, sks_3) = generate_keys(
+        ConfigBuilder::default().use_custom_parameters(PARAM_MESSAGE_2_CARRY_2_PBS_KS, None),
+    );
+
+    let new_ksk = |from: (&ClientKey, &ServerKey), to: (&ClientKey, &ServerKey)| {
+        KeySwitchingKey::new(from, to).unwrap()
+    };
+
+    // Blocks of 2 bits are split into blocks of 1 bit
+    let ksk = new_ksk((&cks_1, &sks_1), (&cks_2, &sks_2));
+    let a = FheUint16::encrypt(51966u16, &cks_1);
+    let b = FheInt8::encrypt(-97i8, &cks_1);
+    let c = FheBool::encrypt(true, &cks_1);
+
+    let a = ksk.keyswitch(&a);
+    let b = ksk.keyswitch(&b);
+    let c = ksk.keyswitch(&c);
+    let decrypted: u16 = a.decrypt(&cks_2);
+    assert_eq!(decrypted, 51966);
+    let decrypted: i8 = b.decrypt(&cks_2);
+    assert_eq!(decrypted, -97);
+    assert!(c.decrypt(&cks_2));
+
+    // The results can be used in computations with the destination keys
+    set_server_key(sks_2.clone());
+    let d = &b + FheInt8::encrypt(-3i8, &cks_2);
+    let decrypted: i8 = d.decrypt(&cks_2);
+    assert_eq!(decrypted, -100);
+
+    // Blocks of 1 bit are merged into blocks of 2 bits
+    let ksk = new_ksk((&cks_2, &sks_2), (&cks_1, &sks_1));
+    let d = ksk.keyswitch(&d);
+    let decrypted: i8 = d.decrypt(&cks_1);
+    assert_eq!(decrypted, -100);
+
+    // Parameters that do not encrypt under the big LWE key are rejected
+    assert!(KeySwitchingKey::new((&cks_1, &sks_1), (&cks_3, &sks_3)).is_err());
+    assert!(KeySwitchingKey::new((&cks_3, &sks_3), (&cks_1, &sks_1)).is_err());
+
+    // Compact lists are key switched once expanded
+    let cpk = CompactPublicKey::new(&cks_1);
+    let list = CompactCiphertextList::builder(&cpk)
+        .push(17u8)
+        .push(-5i8)
+        .push(false)
+        .build();
+    let ksk = new_ksk((&cks_1, &sks_1), (&cks_2, &sks_2));
+    let expander = ksk.keyswitch(&list.expand_with_key(&sks_1).unwrap());
+    assert_eq!(expander.len(), 3);
+
+    let f: FheUint8 = expander.get(0).unwrap().unwrap();
+    let g: FheInt8 = expander.get(1).unwrap().unwrap();
+    let h: FheBool = expander.get(2).unwrap().unwrap();
+    let decrypted: u8 = f.decrypt(&cks_2);
+    assert_eq!(decrypted, 17);
+    let decrypted: i8 = g.decrypt(&cks_2);
+    assert_eq!(decrypted, -5);
+    assert!(!h.decrypt(&cks_2));
+}
@@ -0,0 +1,411 @@
+//! Threshold decryption of high level api ciphertexts.
+//!
+//! The secret key of a [ClientKey] can be shared among `party_count` parties so that any
+//! `threshold` of them can decrypt a ciphertext together, while fewer parties learn nothing about
+//! the key.
+//!
+//! Each participant computes a [DecryptionShare] of the ciphertext with its
+//! [ThresholdClientKeyShare], the shares are then combined into the cleartext. The participants
+//! must agree on the list of parties taking part in a given decryption before computing their
+//! share.
+
+use crate::core_crypto::commons::math::random::TUniform;
+use crate::core_crypto::commons::parameters::PBSOrder;
+use crate::core_crypto::entities::Plaintext;
+use crate::core_crypto::experimental::prelude::{
+    allocate_and_generate_lwe_threshold_secret_key_shares, combine_lwe_partial_decryptions,
+    partial_decrypt_lwe_ciphertext, threshold_sharing_excluded_subset_count, DecryptionThreshold,
+    LweThresholdSecretKeyShareOwned, PartyCount, PartyIndex,
+};
+use crate::high_level_api::integers::FheUintId;
+use crate::integer::block_decomposition::{BlockRecomposer, RecomposableFrom};
+use crate::named::Named;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::EncryptionKeyChoice;
+use crate::shortint::Ciphertext;
+use crate::{ClientKey, FheBool, FheUint};
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of sub-keys a secret key can be shared into.
+///
+/// The key is split in one sub-key per subset of `threshold - 1` parties, so their number grows
+/// combinatorially with the party count.
+const MAX_SUB_KEY_COUNT: usize = 1 << 12;
+
+/// The share of the secret key of a [ClientKey] owned by one party of a threshold decryption.
+///
+/// Shares are created by [ClientKey::generate_threshold_key_shares].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, ConfigBuilder, FheUint8};
+///
+/// let (client_key, _) = generate_keys(ConfigBuilder::default());
+///
+/// // 2 out of 3 parties are needed to decrypt
+/// let key_shares = client_key.generate_threshold_key_shares(2, 3).unwrap();
+///
+/// let a = FheUint8::encrypt(42u8, &client_key);
+///
+/// // Parties 0 and 2 decrypt together
+/// let participants = [0, 2];
+/// let decryption_shares = [
+///     a.partial_decrypt(&key_shares[0], &participants).unwrap(),
+///     a.partial_decrypt(&key_shares[2], &participants).unwrap(),
+/// ];
+///
+/// let decrypted: u8 = a.combine_decryption_shares(&decryption_shares).unwrap();
+/// assert_eq!(decrypted, 42);
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ThresholdClientKeyShare {
+    key_share: LweThresholdSecretKeyShareOwned<u64>,
+    pbs_order: PBSOrder,
+    flooding_noise_bound_log2: u32,
+}
+
+impl Named for ThresholdClientKeyShare {
+    const NAME: &'static str = "high_level_api::ThresholdClientKeyShare";
+}
+
+impl ThresholdClientKeyShare {
+    /// Returns the index of the party owning this share
+    pub fn party_index(&self) -> usize {
+        self.key_share.party_index().0
+    }
+
+    /// Returns the number of parties the key is shared with
+    pub fn party_count(&self) -> usize {
+        self.key_share.party_count().0
+    }
+
+    /// Returns the minimum number of parties needed to decrypt
+    pub fn threshold(&self) -> usize {
+        self.key_share.decryption_threshold().0
+    }
+}
+
+/// The partial decryption of a ciphertext computed by one party.
+///
+/// See [ThresholdClientKeyShare] for an example.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DecryptionShare {
+    party_index: usize,
+    participants: Vec<usize>,
+    partial_decryptions: Vec<u64>,
+}
+
+impl Named for DecryptionShare {
+    const NAME: &'static str = "high_level_api::DecryptionShare";
+}
+
+impl DecryptionShare {
+    /// Returns the index of the party that computed this share
+    pub fn party_index(&self) -> usize {
+        self.party_index
+    }
+
+    /// Returns the parties taking part in the decryption
+    pub fn participants(&self) -> &[usize] {
+        &self.participants
+    }
+}
+
+impl ClientKey {
+    /// Shares the secret key among `party_count` parties, so that any `threshold` of them can
+    /// decrypt together.
+    ///
+    /// The returned shares are ordered by party index, each one must be sent to its party. Only
+    /// the key that encrypts the ciphertexts outside of computations is shared, that is the large
+    /// LWE key (derived from the GLWE secret key) for parameters that encrypt with the big key,
+    /// and the small LWE key otherwise.
+    ///
+    /// Partial decryptions are flooded with noise as large as the decryption margin of the
+    /// parameters allows for `party_count` parties.
+    ///
+    /// Returns an error if:
+    /// - `threshold` is 0 or greater than `party_count`
+    /// - the key would be split into more than 4096 sub-keys, one per subset of `threshold - 1`
+    ///   parties
+    /// - `party_count` is too large for the flooding noise to fit in the decryption margin
+    pub fn generate_threshold_key_shares(
+        &self,
+        threshold: usize,
+        party_count: usize,
+    ) -> crate::Result<Vec<ThresholdClientKeyShare>> {
+        if threshold == 0 || threshold > party_count {
+            return Err(crate::Error::new(format!(
+                "The threshold ({threshold}) must be between 1 and the party count ({party_count})"
+            )));
+        }
+
+        let sub_key_count = threshold_sharing_excluded_subset_count(
+            PartyCount(party_count),
+            DecryptionThreshold(threshold),
+        );
+        if !sub_key_count.is_some_and(|count| count <= MAX_SUB_KEY_COUNT) {
+            return Err(crate::Error::new(format!(
+                "Sharing a key among {party_count} parties with a threshold of {threshold} \
+                requires more than {MAX_SUB_KEY_COUNT} sub-keys"
+            )));
+        }
+
+        let shortint_key = &self.key.key.key;
+        let params = shortint_key.parameters;
+
+        let (lwe_secret_key, pbs_order) = match params.encryption_key_choice() {
+            EncryptionKeyChoice::Big => (
+                shortint_key.large_lwe_secret_key(),
+                PBSOrder::KeyswitchBootstrap,
+            ),
+            EncryptionKeyChoice::Small => (
+                shortint_key.small_lwe_secret_key(),
+                PBSOrder::BootstrapKeyswitch,
+            ),
+        };
+
+        if !params.ciphertext_modulus().is_native_modulus() {
+            return Err(crate::Error::new(
+                "Threshold decryption only supports the native ciphertext modulus".to_string(),
+            ));
+        }
+
+        // The flooding noise of all the parties adds up, we keep it below a quarter of delta so
+        // that half of the decryption margin is left for the noise of the ciphertext
+        let delta_log2 = 63 - (params.message_modulus().0 * params.carry_modulus().0).ilog2();
+        let Some(flooding_noise_bound_log2) = party_count
+            .checked_next_power_of_two()
+            .and_then(|count| delta_log2.checked_sub(2 + count.ilog2()))
+            .filter(|&bound_log2| bound_log2 > 0)
+        else {
+            return Err(crate::Error::new(format!(
+                "The decryption margin of the parameters is too small for {party_count} parties"
+            )));
+        };
+
+        let key_shares = ShortintEngine::with_thread_local_mut(|engine| {
+            allocate_and_generate_lwe_threshold_secret_key_shares(
+                &lwe_secret_key,
+                PartyCount(party_count),
+                DecryptionThreshold(threshold),
+                &mut engine.secret_generator,
+            )
+        });
+
+        Ok(key_shares
+            .into_iter()
+            .map(|key_share| ThresholdClientKeyShare {
+                key_share,
+                pbs_order,
+                flooding_noise_bound_log2,
+            })
+            .collect())
+    }
+}
+
+/// Computes the partial decryption of a ciphertext for a threshold decryption.
+pub trait FhePartialDecrypt {
+    /// Computes the [DecryptionShare] of `self` for the party owning `key_share`.
+    ///
+    /// `participants` are the indices of the parties taking part in the decryption, the party
+    /// owning `key_share` included. All the participants must use the same list.
+    fn partial_decrypt(
+        &self,
+        key_share: &ThresholdClientKeyShare,
+        participants: &[usize],
+    ) -> crate::Result<DecryptionShare>;
+}
+
+/// Combines the [DecryptionShare]s of a ciphertext into its cleartext.
+pub trait FheThresholdDecrypt<ClearType> {
+    /// Combines the shares computed by all the participants of the decryption.
+    ///
+    /// Returns an error if shares are missing or if they were computed for different
+    /// participants.
+    fn combine_decryption_shares(&self, shares: &[DecryptionShare]) -> crate::Result<ClearType>;
+}
+
+fn partial_decrypt_blocks(
+    blocks: &[Ciphertext],
+    key_share: &ThresholdClientKeyShare,
+    participants: &[usize],
+) -> crate::Result<DecryptionShare> {
+    let party_index = key_share.party_index();
+
+    if !participants.windows(2).all(|pair| pair[0] < pair[1]) {
+        return Err(crate::Error::new(format!(
+            "Participants must be sorted and unique, got {participants:?}"
+        )));
+    }
+    if participants
+        .last()
+        .is_some_and(|last| *last >= key_share.party_count())
+    {
+        return Err(crate::Error::new(format!(
+            "Participants must be smaller than the party count ({}), got {participants:?}",
+            key_share.party_count()
+        )));
+    }
+    if participants.len() < key_share.threshold() {
+        return Err(crate::Error::new(format!(
+            "At least {} participants are needed to decrypt, got {}",
+            key_share.threshold(),
+            participants.len()
+        )));
+    }
+    if !participants.contains(&party_index) {
+        return Err(crate::Error::new(format!(
+            "The party {party_index} owning the key share is not in the participants"
+        )));
+    }
+    if blocks
+        .iter()
+        .any(|block| block.pbs_order != key_share.pbs_order)
+    {
+        return Err(crate::Error::new(
+            "The ciphertext is not encrypted under the shared key".to_string(),
+        ));
+    }
+
+    let participant_indices: Vec<_> = participants.iter().copied().map(PartyIndex).collect();
+    let flooding_noise_distribution = TUniform::new(key_share.flooding_noise_bound_log2);
+
+    let partial_decryptions = ShortintEngine::with_thread_local_mut(|engine| {
+        blocks
+            .iter()
+            .map(|block| {
+                partial_decrypt_lwe_ciphertext(
+                    &key_share.key_share,
+                    &block.ct,
+                    &participant_indices,
+                    flooding_noise_distribution,
+                    &mut engine.encryption_generator,
+                )
+                .0
+            })
+            .collect()
+    });
+
+    Ok(DecryptionShare {
+        party_index,
+        participants: participants.to_vec(),
+        partial_decryptions,
+    })
+}
+
+// Returns the decrypted message and carry of each block
+fn combine_blocks(blocks: &[Ciphertext], shares: &[DecryptionShare]) -> crate::Result<Vec<u64>> {
+    let Some(first_share) = shares.first() else {
+        return Err(crate::Error::new(
+            "No decryption share to combine".to_string(),
+        ));
+    };
+    let participants = &first_share.participants;
+
+    if shares
+        .iter()
+        .any(|share| &share.participants != participants)
+    {
+        return Err(crate::Error::new(
+            "Decryption shares were computed for different participants".to_string(),
+        ));
+    }
+
+    let mut share_parties: Vec<_> = shares.iter().map(|share| share.party_index).collect();
+    share_parties.sort_unstable();
+    if &share_parties != participants {
+        return Err(crate::Error::new(format!(
+            "Expected one decryption share from each of the participants {participants:?}, \
+            got shares from {share_parties:?}"
+        )));
+    }
+
+    if shares
+        .iter()
+        .any(|share| share.partial_decryptions.len() != blocks.len())
+    {
+        return Err(crate::Error::new(
+            "Decryption shares do not match the ciphertext".to_string(),
+        ));
+    }
+
+    Ok(blocks
+        .iter()
+        .enumerate()
+        .map(|(block_index, block)| {
+            let partial_decryptions: Vec<_> = shares
+                .iter()
+                .map(|share| Plaintext(share.partial_decryptions[block_index]))
+                .collect();
+
+            let decrypted = combine_lwe_partial_decryptions(&block.ct, &partial_decryptions).0;
+
+            // Same decoding as the shortint ClientKey
+            let delta = (1_u64 << 63) / (block.message_modulus.0 * block.carry_modulus.0) as u64;
+            let rounding = (decrypted & (delta >> 1)) << 1;
+
+            decrypted.wrapping_add(rounding) / delta
+        })
+        .collect())
+}
+
+impl<Id: FheUintId> FhePartialDecrypt for FheUint<Id> {
+    fn partial_decrypt(
+        &self,
+        key_share: &ThresholdClientKeyShare,
+        participants: &[usize],
+    ) -> crate::Result<DecryptionShare> {
+        partial_decrypt_blocks(&self.ciphertext.on_cpu().blocks, key_share, participants)
+    }
+}
+
+impl<Id, ClearType> FheThresholdDecrypt<ClearType> for FheUint<Id>
+where
+    Id: FheUintId,
+    ClearType: RecomposableFrom<u64>,
+{
+    fn combine_decryption_shares(&self, shares: &[DecryptionShare]) -> crate::Result<ClearType> {
+        let ciphertext = self.ciphertext.on_cpu();
+        let decrypted_blocks = combine_blocks(&ciphertext.blocks, shares)?;
+
+        let Some(first_block) = ciphertext.blocks.first() else {
+            return Ok(ClearType::ZERO);
+        };
+
+        let bits_in_block = first_block.message_modulus.0.ilog2();
+        let mut recomposer = BlockRecomposer::<ClearType>::new(bits_in_block);
+
+        for decrypted_block in decrypted_blocks {
+            if !recomposer.add_unmasked(decrypted_block) {
+                // End of ClearType::BITS reached no need to try more
+                // recomposition
+                break;
+            }
+        }
+
+        Ok(recomposer.value())
+    }
+}
+
+impl FhePartialDecrypt for FheBool {
+    fn partial_decrypt(
+        &self,
+        key_share: &ThresholdClientKeyShare,
+        participants: &[usize],
+    ) -> crate::Result<DecryptionShare> {
+        let ciphertext = self.ciphertext.on_cpu();
+
+        partial_decrypt_blocks(std::slice::from_ref(&ciphertext.0), key_share, participants)
+    }
+}
+
+impl FheThresholdDecrypt<bool> for FheBool {
+    fn combine_decryption_shares(&self, shares: &[DecryptionShare]) -> crate::Result<bool> {
+        let ciphertext = self.ciphertext.on_cpu();
+        let decrypted = combine_blocks(std::slice::from_ref(&ciphertext.0), shares)?;
+
+        Ok(decrypted[0] % ciphertext.0.message_modulus.0 as u64 != 0)
+    }
+}
@@ -0,0 +1,426 @@
+//! The AES block cipher, evaluated as a boolean circuit over clear or encrypted bits.
+//!
+//! The S-box is evaluated on each byte with lookup tables, the linear layers (ShiftRows,
+//! MixColumns, AddRoundKey) only need xors.
+
+use super::bits::{BitOps, ClearBits, EncryptedBits};
+use crate::high_level_api::global_state::with_cpu_internal_keys;
+use crate::integer::RadixCiphertext;
+use crate::shortint::Ciphertext;
+use crate::FheUint8;
+use rayon::prelude::*;
+use std::array;
+
+pub(super) const BLOCK_LEN: usize = 16;
+
+/// The bits of a byte, least significant bit first
+pub(super) type Byte<T> = [T; 8];
+
+/// The 16 bytes of a block, the state being stored column by column
+pub(super) type State<T> = [Byte<T>; BLOCK_LEN];
+
+pub(super) fn byte_to_bits(byte: u8) -> Byte<bool> {
+    array::from_fn(|i| (byte >> i) & 1 == 1)
+}
+
+pub(super) fn bits_to_byte(bits: Byte<bool>) -> u8 {
+    bits.iter()
+        .enumerate()
+        .fold(0, |byte, (i, bit)| byte | (u8::from(*bit) << i))
+}
+
+/// Splits bits, least significant bit of each byte first, into states
+pub(super) fn bits_to_states<T: Clone>(bits: &[T]) -> Vec<State<T>> {
+    bits.chunks_exact(BLOCK_LEN * 8)
+        .map(|state| array::from_fn(|i| array::from_fn(|j| state[8 * i + j].clone())))
+        .collect()
+}
+
+fn xor_bytes<B: BitOps>(ops: &B, lhs: &Byte<B::Bit>, rhs: &Byte<B::Bit>) -> Byte<B::Bit> {
+    array::from_fn(|i| ops.xor(&lhs[i], &rhs[i]))
+}
+
+fn xor_constant<B: BitOps>(ops: &B, byte: &Byte<B::Bit>, constant: u8) -> Byte<B::Bit> {
+    array::from_fn(|i| {
+        if (constant >> i) & 1 == 1 {
+            ops.not(&byte[i])
+        } else {
+            byte[i].clone()
+        }
+    })
+}
+
+/// Multiplication by x in GF(2^8)
+fn xtime<B: BitOps>(ops: &B, byte: &Byte<B::Bit>) -> Byte<B::Bit> {
+    let b = byte;
+    [
+        b[7].clone(),
+        ops.xor(&b[0], &b[7]),
+        b[1].clone(),
+        ops.xor(&b[2], &b[7]),
+        ops.xor(&b[3], &b[7]),
+        b[4].clone(),
+        b[5].clone(),
+        b[6].clone(),
+    ]
+}
+
+fn clear_xtime(byte: u8) -> u8 {
+    (byte << 1) ^ if byte & 0x80 == 0 { 0 } else { 0x1b }
+}
+
+/// The AES S-box, the inversion in GF(2^8) followed by an affine transformation
+const SBOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+/// The inverse of the AES S-box
+const INV_SBOX: [u8; 256] = [
+    0x52, 0x09, 0x6a, 0xd5, 0x30, 0x36, 0xa5, 0x38, 0xbf, 0x40, 0xa3, 0x9e, 0x81, 0xf3, 0xd7, 0xfb,
+    0x7c, 0xe3, 0x39, 0x82, 0x9b, 0x2f, 0xff, 0x87, 0x34, 0x8e, 0x43, 0x44, 0xc4, 0xde, 0xe9, 0xcb,
+    0x54, 0x7b, 0x94, 0x32, 0xa6, 0xc2, 0x23, 0x3d, 0xee, 0x4c, 0x95, 0x0b, 0x42, 0xfa, 0xc3, 0x4e,
+    0x08, 0x2e, 0xa1, 0x66, 0x28, 0xd9, 0x24, 0xb2, 0x76, 0x5b, 0xa2, 0x49, 0x6d, 0x8b, 0xd1, 0x25,
+    0x72, 0xf8, 0xf6, 0x64, 0x86, 0x68, 0x98, 0x16, 0xd4, 0xa4, 0x5c, 0xcc, 0x5d, 0x65, 0xb6, 0x92,
+    0x6c, 0x70, 0x48, 0x50, 0xfd, 0xed, 0xb9, 0xda, 0x5e, 0x15, 0x46, 0x57, 0xa7, 0x8d, 0x9d, 0x84,
+    0x90, 0xd8, 0xab, 0x00, 0x8c, 0xbc, 0xd3, 0x0a, 0xf7, 0xe4, 0x58, 0x05, 0xb8, 0xb3, 0x45, 0x06,
+    0xd0, 0x2c, 0x1e, 0x8f, 0xca, 0x3f, 0x0f, 0x02, 0xc1, 0xaf, 0xbd, 0x03, 0x01, 0x13, 0x8a, 0x6b,
+    0x3a, 0x91, 0x11, 0x41, 0x4f, 0x67, 0xdc, 0xea, 0x97, 0xf2, 0xcf, 0xce, 0xf0, 0xb4, 0xe6, 0x73,
+    0x96, 0xac, 0x74, 0x22, 0xe7, 0xad, 0x35, 0x85, 0xe2, 0xf9, 0x37, 0xe8, 0x1c, 0x75, 0xdf, 0x6e,
+    0x47, 0xf1, 0x1a, 0x71, 0x1d, 0x29, 0xc5, 0x89, 0x6f, 0xb7, 0x62, 0x0e, 0xaa, 0x18, 0xbe, 0x1b,
+    0xfc, 0x56, 0x3e, 0x4b, 0xc6, 0xd2, 0x79, 0x20, 0x9a, 0xdb, 0xc0, 0xfe, 0x78, 0xcd, 0x5a, 0xf4,
+    0x1f, 0xdd, 0xa8, 0x33, 0x88, 0x07, 0xc7, 0x31, 0xb1, 0x12, 0x10, 0x59, 0x27, 0x80, 0xec, 0x5f,
+    0x60, 0x51, 0x7f, 0xa9, 0x19, 0xb5, 0x4a, 0x0d, 0x2d, 0xe5, 0x7a, 0x9f, 0x93, 0xc9, 0x9c, 0xef,
+    0xa0, 0xe0, 0x3b, 0x4d, 0xae, 0x2a, 0xf5, 0xb0, 0xc8, 0xeb, 0xbb, 0x3c, 0x83, 0x53, 0x99, 0x61,
+    0x17, 0x2b, 0x04, 0x7e, 0xba, 0x77, 0xd6, 0x26, 0xe1, 0x69, 0x14, 0x63, 0x55, 0x21, 0x0c, 0x7d,
+];
+
+fn sbox<B: BitOps>(ops: &B, byte: &Byte<B::Bit>) -> Byte<B::Bit> {
+    ops.lookup_byte(byte, &SBOX)
+}
+
+fn inv_sbox<B: BitOps>(ops: &B, byte: &Byte<B::Bit>) -> Byte<B::Bit> {
+    ops.lookup_byte(byte, &INV_SBOX)
+}
+
+/// Applies the S-box to each byte of the state, or the inverse S-box if `inverse` is true
+fn sub_bytes<B: BitOps>(ops: &B, state: &State<B::Bit>, inverse: bool) -> State<B::Bit> {
+    state
+        .par_iter()
+        .map(|byte| {
+            if inverse {
+                inv_sbox(ops, byte)
+            } else {
+                sbox(ops, byte)
+            }
+        })
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap_or_else(|_| unreachable!())
+}
+
+/// Rotates the row `r` of the state by `r` columns, to the left if `inverse` is false
+fn shift_rows<T: Clone>(state: &State<T>, inverse: bool) -> State<T> {
+    array::from_fn(|i| {
+        let (row, column) = (i % 4, i / 4);
+        let column = if inverse {
+            (column + 4 - row) % 4
+        } else {
+            (column + row) % 4
+        };
+        state[row + 4 * column].clone()
+    })
+}
+
+fn mix_columns<B: BitOps>(ops: &B, state: &State<B::Bit>) -> State<B::Bit> {
+    let columns = state
+        .par_chunks_exact(4)
+        .map(|column| {
+            let sum = xor_bytes(
+                ops,
+                &xor_bytes(ops, &column[0], &column[1]),
+                &xor_bytes(ops, &column[2], &column[3]),
+            );
+            // b_i = a_i + (a_0 + a_1 + a_2 + a_3) + x * (a_i + a_(i + 1))
+            (0..4)
+                .map(|i| {
+                    let doubled = xtime(ops, &xor_bytes(ops, &column[i], &column[(i + 1) % 4]));
+                    xor_bytes(ops, &xor_bytes(ops, &column[i], &sum), &doubled)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    array::from_fn(|i| columns[i / 4][i % 4].clone())
+}
+
+/// InvMixColumns is MixColumns preceded by a multiplication by `4x^2 + 5`
+fn inv_mix_columns<B: BitOps>(ops: &B, state: &State<B::Bit>) -> State<B::Bit> {
+    let premultiplied = state
+        .par_chunks_exact(4)
+        .map(|column| {
+            let u = xtime(ops, &xtime(ops, &xor_bytes(ops, &column[0], &column[2])));
+            let v = xtime(ops, &xtime(ops, &xor_bytes(ops, &column[1], &column[3])));
+            [
+                xor_bytes(ops, &column[0], &u),
+                xor_bytes(ops, &column[1], &v),
+                xor_bytes(ops, &column[2], &u),
+                xor_bytes(ops, &column[3], &v),
+            ]
+        })
+        .collect::<Vec<_>>();
+    mix_columns(
+        ops,
+        &array::from_fn(|i| premultiplied[i / 4][i % 4].clone()),
+    )
+}
+
+fn add_round_key<B: BitOps>(
+    ops: &B,
+    state: &State<B::Bit>,
+    round_key: &State<B::Bit>,
+) -> State<B::Bit> {
+    array::from_fn(|i| xor_bytes(ops, &state[i], &round_key[i]))
+}
+
+fn clean_state<B: BitOps>(ops: &B, state: &State<B::Bit>) -> State<B::Bit> {
+    state
+        .par_iter()
+        .map(|byte| array::from_fn(|i| ops.clean(&byte[i])))
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap_or_else(|_| unreachable!())
+}
+
+/// Expands a 16 or 32 bytes key into the round keys of AES-128 or AES-256
+pub(super) fn expand_key<B: BitOps>(ops: &B, key: &[Byte<B::Bit>]) -> Vec<State<B::Bit>> {
+    assert!(key.len() == 16 || key.len() == 32);
+
+    let key_words = key.len() / 4;
+    let num_rounds = key_words + 6;
+
+    let mut words = key
+        .chunks_exact(4)
+        .map(|word| array::from_fn::<_, 4, _>(|i| word[i].clone()))
+        .collect::<Vec<_>>();
+    let mut round_constant = 1u8;
+    for i in key_words..4 * (num_rounds + 1) {
+        let mut word = words[i - 1].clone();
+        if i % key_words == 0 {
+            word.rotate_left(1);
+            word = array::from_fn(|j| sbox(ops, &word[j]));
+            word[0] = xor_constant(ops, &word[0], round_constant);
+            round_constant = clear_xtime(round_constant);
+        } else if key_words > 6 && i % key_words == 4 {
+            word = array::from_fn(|j| sbox(ops, &word[j]));
+        }
+        let word = array::from_fn(|j| xor_bytes(ops, &words[i - key_words][j], &word[j]));
+        words.push(word);
+    }
+
+    words
+        .chunks_exact(4)
+        .map(|round_words| array::from_fn(|i| round_words[i / 4][i % 4].clone()))
+        .collect()
+}
+
+pub(super) fn encrypt_block<B: BitOps>(
+    ops: &B,
+    round_keys: &[State<B::Bit>],
+    block: &State<B::Bit>,
+) -> State<B::Bit> {
+    let num_rounds = round_keys.len() - 1;
+
+    let mut state = add_round_key(ops, block, &round_keys[0]);
+    for round_key in &round_keys[1..num_rounds] {
+        state = shift_rows(&sub_bytes(ops, &state, false), false);
+        state = add_round_key(ops, &mix_columns(ops, &state), round_key);
+    }
+    state = shift_rows(&sub_bytes(ops, &state, false), false);
+    state = add_round_key(ops, &state, &round_keys[num_rounds]);
+
+    clean_state(ops, &state)
+}
+
+pub(super) fn decrypt_block<B: BitOps>(
+    ops: &B,
+    round_keys: &[State<B::Bit>],
+    block: &State<B::Bit>,
+) -> State<B::Bit> {
+    let num_rounds = round_keys.len() - 1;
+
+    let mut state = add_round_key(ops, block, &round_keys[num_rounds]);
+    for round_key in round_keys[1..num_rounds].iter().rev() {
+        state = sub_bytes(ops, &shift_rows(&state, true), true);
+        state = inv_mix_columns(ops, &add_round_key(ops, &state, round_key));
+    }
+    state = sub_bytes(ops, &shift_rows(&state, true), true);
+    state = add_round_key(ops, &state, &round_keys[0]);
+
+    clean_state(ops, &state)
+}
+
+/// Computes `num_blocks` blocks of the AES-CTR keystream, the counter being the nonce
+/// interpreted as a big endian integer
+pub(super) fn ctr_key_stream<B: BitOps>(
+    ops: &B,
+    round_keys: &[State<B::Bit>],
+    nonce: &[u8; BLOCK_LEN],
+    num_blocks: usize,
+) -> Vec<B::Bit> {
+    let counter = u128::from_be_bytes(*nonce);
+    (0..num_blocks as u128)
+        .into_par_iter()
+        .flat_map_iter(|i| {
+            let counter = counter.wrapping_add(i).to_be_bytes();
+            let block = array::from_fn(|j| byte_to_bits(counter[j]).map(|bit| ops.trivial(bit)));
+            encrypt_block(ops, round_keys, &block).into_iter().flatten()
+        })
+        .collect()
+}
+
+/// Returns the expanded round keys of a clear key, as bytes
+pub(super) fn clear_expand_key(key: &[u8]) -> Vec<u8> {
+    let key = key.iter().copied().map(byte_to_bits).collect::<Vec<_>>();
+    expand_key(&ClearBits, &key)
+        .iter()
+        .flatten()
+        .copied()
+        .map(bits_to_byte)
+        .collect()
+}
+
+/// An AES-128 or AES-256 key encrypted under the FHE key, to encrypt and decrypt blocks of
+/// [FheUint8] homomorphically
+///
+/// The round keys are expanded from the encrypted key when it is created, and kept for the
+/// successive blocks.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::transciphering::FheAesKey;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+/// set_server_key(server_key);
+///
+/// // Trivial ciphertexts keep the example fast, encrypted ones are used the same way
+/// let key = (0..16u8).map(FheUint8::encrypt_trivial).collect::<Vec<_>>();
+/// let block = [
+///     0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+///     0xff,
+/// ]
+/// .map(FheUint8::encrypt_trivial);
+///
+/// let aes_key = FheAesKey::new(&key).unwrap();
+/// let encrypted = aes_key.encrypt_block(&block);
+///
+/// let encrypted: Vec<u8> = encrypted.iter().map(|byte| byte.decrypt(&client_key)).collect();
+/// assert_eq!(
+///     encrypted,
+///     [
+///         0x69, 0xc4, 0xe0, 0xd8, 0x6a, 0x7b, 0x04, 0x30, 0xd8, 0xcd, 0xb7, 0x80, 0x70, 0xb4,
+///         0xc5, 0x5a
+///     ]
+/// );
+/// ```
+#[derive(Clone)]
+pub struct FheAesKey {
+    round_keys: Vec<State<Ciphertext>>,
+}
+
+impl FheAesKey {
+    /// Expands the encrypted key, which must be 16 bytes long for AES-128, or 32 bytes long for
+    /// AES-256
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server key is not set, if it is not a CPU server key, or if its parameters
+    /// have less carry space than message space.
+    pub fn new(key: &[FheUint8]) -> crate::Result<Self> {
+        if key.len() != 16 && key.len() != 32 {
+            return Err(crate::Error::new(format!(
+                "AES keys must be 16 or 32 bytes long, got {} bytes",
+                key.len()
+            )));
+        }
+
+        with_cpu_internal_keys(|keys| {
+            let ops = EncryptedBits::new(keys.pbs_key());
+            let key = key
+                .par_iter()
+                .map(|byte| fhe_uint8_to_bits(&ops, keys.pbs_key(), byte))
+                .collect::<Vec<_>>();
+            Ok(Self {
+                round_keys: expand_key(&ops, &key),
+            })
+        })
+    }
+
+    /// Returns the number of bytes of the key, 16 for AES-128 and 32 for AES-256
+    pub fn key_len(&self) -> usize {
+        (self.round_keys.len() - 7) * 4
+    }
+
+    pub fn encrypt_block(&self, block: &[FheUint8; BLOCK_LEN]) -> [FheUint8; BLOCK_LEN] {
+        self.apply(block, false)
+    }
+
+    pub fn decrypt_block(&self, block: &[FheUint8; BLOCK_LEN]) -> [FheUint8; BLOCK_LEN] {
+        self.apply(block, true)
+    }
+
+    fn apply(&self, block: &[FheUint8; BLOCK_LEN], decrypt: bool) -> [FheUint8; BLOCK_LEN] {
+        with_cpu_internal_keys(|keys| {
+            let sks = keys.pbs_key();
+            let ops = EncryptedBits::new(sks);
+
+            let bits = block
+                .par_iter()
+                .map(|byte| fhe_uint8_to_bits(&ops, sks, byte))
+                .collect::<Vec<_>>();
+            let state = array::from_fn(|i| bits[i].clone());
+            let state = if decrypt {
+                decrypt_block(&ops, &self.round_keys, &state)
+            } else {
+                encrypt_block(&ops, &self.round_keys, &state)
+            };
+            state.map(|byte| bits_to_fhe_uint8(&ops, &byte))
+        })
+    }
+}
+
+/// Extracts the bits of the blocks of a [FheUint8], each in a ciphertext of the same parameters
+fn fhe_uint8_to_bits(
+    ops: &EncryptedBits,
+    sks: &crate::integer::ServerKey,
+    byte: &FheUint8,
+) -> Byte<Ciphertext> {
+    let mut ciphertext = byte.ciphertext.on_cpu().into_owned();
+    if !ciphertext.block_carries_are_empty() {
+        sks.full_propagate_parallelized(&mut ciphertext);
+    }
+
+    let bits = ops.blocks_to_bits(&ciphertext.blocks);
+    array::from_fn(|i| bits[i].clone())
+}
+
+/// Packs bits into the blocks of a [FheUint8]
+fn bits_to_fhe_uint8(ops: &EncryptedBits, bits: &Byte<Ciphertext>) -> FheUint8 {
+    FheUint8::new(RadixCiphertext::from(ops.bits_to_blocks(bits)))
+}
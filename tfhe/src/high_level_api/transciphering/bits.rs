@@ -0,0 +1,183 @@
+//! Boolean operations over clear or encrypted bits, on which the AES circuit is built.
+
+use crate::integer::{IntegerCiphertext, RadixCiphertext, ServerKey};
+use crate::shortint::ciphertext::NoiseLevel;
+use crate::shortint::server_key::LookupTableOwned;
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+use std::array;
+
+pub(super) trait BitOps: Sync {
+    type Bit: Clone + Send + Sync;
+
+    fn trivial(&self, value: bool) -> Self::Bit;
+
+    fn xor(&self, lhs: &Self::Bit, rhs: &Self::Bit) -> Self::Bit;
+
+    /// Returns the bits of the entry of the table at the index given by the bits of a byte,
+    /// least significant bit first
+    fn lookup_byte(&self, byte: &[Self::Bit; 8], table: &[u8; 256]) -> [Self::Bit; 8];
+
+    fn not(&self, bit: &Self::Bit) -> Self::Bit {
+        self.xor(bit, &self.trivial(true))
+    }
+
+    /// Returns the bit in its canonical form, encrypting either 0 or 1
+    fn clean(&self, bit: &Self::Bit) -> Self::Bit;
+}
+
+/// Clear bits, used by the client and to check the circuit
+pub(super) struct ClearBits;
+
+impl BitOps for ClearBits {
+    type Bit = bool;
+
+    fn trivial(&self, value: bool) -> bool {
+        value
+    }
+
+    fn xor(&self, lhs: &bool, rhs: &bool) -> bool {
+        lhs ^ rhs
+    }
+
+    fn lookup_byte(&self, byte: &[bool; 8], table: &[u8; 256]) -> [bool; 8] {
+        let index = byte
+            .iter()
+            .enumerate()
+            .fold(0, |index, (i, bit)| index | (usize::from(*bit) << i));
+        array::from_fn(|i| (table[index] >> i) & 1 == 1)
+    }
+
+    fn clean(&self, bit: &bool) -> bool {
+        *bit
+    }
+}
+
+/// Bits encrypted in the shortint ciphertexts of a server key having at least as much carry
+/// space as message space
+///
+/// A bit is represented by a sum of bits, whose parity is the value of the bit, so that xors
+/// are additions. The sums are only bootstrapped back to a single bit when their degree or
+/// noise level would exceed what the parameters allow, or when they are used in a lookup.
+///
+/// Lookups on bytes pack the bits into radix blocks, on which the function is evaluated with a
+/// tree of lookup tables.
+pub(super) struct EncryptedBits<'a> {
+    server_key: &'a ServerKey,
+    parity_lut: LookupTableOwned,
+}
+
+impl<'a> EncryptedBits<'a> {
+    pub(super) fn new(server_key: &'a ServerKey) -> Self {
+        Self {
+            parity_lut: server_key.key.generate_lookup_table(|x| x & 1),
+            server_key,
+        }
+    }
+
+    fn bits_per_block(&self) -> usize {
+        self.server_key.message_modulus().0.ilog2() as usize
+    }
+
+    /// Packs bits, least significant bit first, into the blocks of a radix ciphertext
+    ///
+    /// The blocks have no carries and a nominal noise level.
+    pub(super) fn bits_to_blocks(&self, bits: &[Ciphertext]) -> Vec<Ciphertext> {
+        let sk = &self.server_key.key;
+        bits.par_chunks(self.bits_per_block())
+            .map(|bits| {
+                let mut block = self.clean(&bits[0]);
+                for (i, bit) in bits.iter().enumerate().skip(1) {
+                    sk.unchecked_add_assign(
+                        &mut block,
+                        &sk.unchecked_scalar_mul(&self.clean(bit), 1 << i),
+                    );
+                }
+                if block.noise_level() > NoiseLevel::NOMINAL {
+                    sk.message_extract_assign(&mut block);
+                }
+                block
+            })
+            .collect()
+    }
+
+    /// Extracts the bits of blocks without carries, least significant bit first
+    pub(super) fn blocks_to_bits(&self, blocks: &[Ciphertext]) -> Vec<Ciphertext> {
+        let functions = (0..self.bits_per_block())
+            .map(|i| move |x: u64| (x >> i) & 1)
+            .collect::<Vec<_>>();
+        let functions = functions
+            .iter()
+            .map(|function| function as &dyn Fn(u64) -> u64)
+            .collect::<Vec<_>>();
+
+        self.server_key
+            .apply_many_functions_to_blocks(blocks, &functions)
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    fn can_add(&self, lhs: &Ciphertext, rhs: &Ciphertext) -> bool {
+        let sk = &self.server_key.key;
+        sk.max_degree
+            .validate(lhs.degree + rhs.degree)
+            .and_then(|()| {
+                sk.max_noise_level
+                    .validate(lhs.noise_level() + rhs.noise_level())
+            })
+            .is_ok()
+    }
+
+    fn is_clean(bit: &Ciphertext) -> bool {
+        bit.degree.get() <= 1 && bit.noise_level() <= NoiseLevel::NOMINAL
+    }
+}
+
+impl BitOps for EncryptedBits<'_> {
+    type Bit = Ciphertext;
+
+    fn trivial(&self, value: bool) -> Ciphertext {
+        self.server_key.key.create_trivial(u64::from(value))
+    }
+
+    fn xor(&self, lhs: &Ciphertext, rhs: &Ciphertext) -> Ciphertext {
+        let sk = &self.server_key.key;
+        if self.can_add(lhs, rhs) {
+            return sk.unchecked_add(lhs, rhs);
+        }
+
+        // Cleaning the largest sum is usually enough to make room for the other one
+        let (lhs, rhs) = if lhs.degree >= rhs.degree {
+            (lhs, rhs)
+        } else {
+            (rhs, lhs)
+        };
+        let lhs = self.clean(lhs);
+        if self.can_add(&lhs, rhs) {
+            sk.unchecked_add(&lhs, rhs)
+        } else {
+            sk.unchecked_add(&lhs, &self.clean(rhs))
+        }
+    }
+
+    fn lookup_byte(&self, byte: &[Ciphertext; 8], table: &[u8; 256]) -> [Ciphertext; 8] {
+        let blocks = RadixCiphertext::from_blocks(self.bits_to_blocks(byte));
+        let output = self
+            .server_key
+            .unchecked_map_parallelized(&blocks, |x| u64::from(table[usize::from(x as u8)]));
+
+        let bits = self.blocks_to_bits(output.blocks());
+        array::from_fn(|i| bits[i].clone())
+    }
+
+    fn clean(&self, bit: &Ciphertext) -> Ciphertext {
+        if Self::is_clean(bit) {
+            bit.clone()
+        } else {
+            self.server_key
+                .key
+                .apply_lookup_table(bit, &self.parity_lut)
+        }
+    }
+}
@@ -0,0 +1,534 @@
+//! Transciphering of symmetric ciphertexts into FHE ciphertexts.
+//!
+//! FHE ciphertexts are much larger than the messages they encrypt, transciphering lets a client
+//! send its data encrypted with a stream cipher ([Trivium](StreamCipher::Trivium),
+//! [Kreyvium](StreamCipher::Kreyvium) or [AES-CTR](StreamCipher::Aes128Ctr)), which does not
+//! expand it. The server then evaluates the
+//! stream cipher homomorphically, using the FHE encrypted symmetric key, to get the data
+//! encrypted under the FHE key.
+//!
+//! - The client creates a [SymmetricKey], and sends once the [TranscipheringKey] generated from
+//!   it and its [ClientKey] to the server.
+//! - The client encrypts values with a [SymmetricCiphertextListBuilder], each list being encrypted
+//!   with a new nonce.
+//! - The server converts the [SymmetricCiphertextList] into FHE ciphertexts with
+//!   [TranscipheringKey::transcipher], the values can then be extracted with their types, as for a
+//!   [CompactCiphertextList](crate::CompactCiphertextList).
+//!
+//! The Trivium and Kreyvium keystreams are generated with FHE ciphertexts having 1 bit of message
+//! and 1 bit of carry, and cast to the parameters of the server key. AES is evaluated with the
+//! server key itself, on the round keys cast to its parameters, its S-box being computed with
+//! lookup tables on whole bytes. The server key parameters must be
+//! [PARAM_MESSAGE_2_CARRY_2_KS_PBS](crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+//! (the default parameters).
+//!
+//! AES blocks can also be encrypted and decrypted directly on [FheUint8](crate::FheUint8) with a
+//! [FheAesKey].
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::prelude::*;
+//! use tfhe::transciphering::{
+//!     StreamCipher, SymmetricCiphertextListBuilder, SymmetricKey, TranscipheringKey,
+//! };
+//! use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+//!
+//! let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+//!
+//! // Client side
+//! let symmetric_key = SymmetricKey::new(StreamCipher::Trivium, &[7u8; 10]).unwrap();
+//! let transciphering_key = TranscipheringKey::new(&symmetric_key, &client_key).unwrap();
+//!
+//! let nonce = [1u8; 10];
+//! let list = SymmetricCiphertextListBuilder::new()
+//!     .push(42u8)
+//!     .push(1234u16)
+//!     .build(&symmetric_key, &nonce)
+//!     .unwrap();
+//!
+//! // Server side
+//! set_server_key(server_key);
+//! let expander = transciphering_key.transcipher(&list).unwrap();
+//! let a: FheUint8 = expander.get(0).unwrap().unwrap();
+//! let b: FheUint16 = expander.get(1).unwrap().unwrap();
+//!
+//! // Client side
+//! let a: u8 = a.decrypt(&client_key);
+//! let b: u16 = b.decrypt(&client_key);
+//! assert_eq!((a, b), (42, 1234));
+//! ```
+mod aes;
+mod bits;
+mod register;
+mod stream;
+#[cfg(test)]
+mod tests;
+
+use crate::core_crypto::prelude::{keyswitch_lwe_ciphertext, LweKeyswitchKeyOwned};
+use crate::high_level_api::global_state::with_cpu_internal_keys;
+use crate::integer::ciphertext::{Compactable, DataKind};
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::key_switching::PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS;
+use crate::shortint::parameters::{
+    PBSParameters, PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+};
+use crate::shortint::{Ciphertext, MessageModulus};
+use crate::{ClientKey, CompactCiphertextListExpander};
+use bits::{ClearBits, EncryptedBits};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use stream::{ClearKeyStream, EncryptedKeyStream};
+
+pub use aes::FheAesKey;
+
+/// Number of bits of the messages of the symmetric ciphertexts, it has to match the
+/// message modulus of the FHE parameters
+const BITS_PER_MESSAGE: usize = 2;
+const MESSAGE_MODULUS: MessageModulus = MessageModulus(1 << BITS_PER_MESSAGE);
+
+/// The FHE parameters the keystream is key switched to, the key switching parameters are only
+/// valid for these
+const FHE_PARAMETERS: PBSParameters = PBSParameters::PBS(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+/// The stream ciphers available for transciphering
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StreamCipher {
+    /// Trivium, with 80 bits keys and nonces
+    Trivium,
+    /// Kreyvium, a variant of Trivium with 128 bits keys and nonces
+    Kreyvium,
+    /// AES-128 in counter mode, the 128 bits nonce being the initial counter block
+    Aes128Ctr,
+    /// AES-256 in counter mode, the 128 bits nonce being the initial counter block
+    Aes256Ctr,
+}
+
+impl StreamCipher {
+    /// Returns the length of the keys in bytes
+    pub fn key_len(self) -> usize {
+        match self {
+            Self::Trivium => 10,
+            Self::Kreyvium | Self::Aes128Ctr => 16,
+            Self::Aes256Ctr => 32,
+        }
+    }
+
+    /// Returns the length of the nonces in bytes
+    pub fn nonce_len(self) -> usize {
+        match self {
+            Self::Trivium => 10,
+            Self::Kreyvium | Self::Aes128Ctr | Self::Aes256Ctr => 16,
+        }
+    }
+
+    fn check_nonce(self, nonce: &[u8]) -> crate::Result<()> {
+        if nonce.len() == self.nonce_len() {
+            Ok(())
+        } else {
+            Err(crate::Error::new(format!(
+                "{self:?} nonces must be {} bytes long, got {} bytes",
+                self.nonce_len(),
+                nonce.len()
+            )))
+        }
+    }
+}
+
+/// The clear symmetric key of a stream cipher, kept by the client
+#[derive(Clone)]
+pub struct SymmetricKey {
+    cipher: StreamCipher,
+    key: Vec<u8>,
+}
+
+impl SymmetricKey {
+    pub fn new(cipher: StreamCipher, key: &[u8]) -> crate::Result<Self> {
+        if key.len() != cipher.key_len() {
+            return Err(crate::Error::new(format!(
+                "{cipher:?} keys must be {} bytes long, got {} bytes",
+                cipher.key_len(),
+                key.len()
+            )));
+        }
+        Ok(Self {
+            cipher,
+            key: key.to_vec(),
+        })
+    }
+
+    pub fn cipher(&self) -> StreamCipher {
+        self.cipher
+    }
+
+    /// Encrypts bytes, to be transciphered into [FheUint8](crate::FheUint8)
+    ///
+    /// A nonce must never be used twice with the same key.
+    pub fn encrypt_bytes(
+        &self,
+        bytes: &[u8],
+        nonce: &[u8],
+    ) -> crate::Result<SymmetricCiphertextList> {
+        SymmetricCiphertextListBuilder::new()
+            .extend(bytes.iter().copied())
+            .build(self, nonce)
+    }
+
+    /// Returns the first `num_bits` bits of the keystream
+    fn key_stream(&self, nonce: &[u8], num_bits: usize) -> Vec<bool> {
+        match self.cipher {
+            StreamCipher::Trivium | StreamCipher::Kreyvium => {
+                let mut key_stream = ClearKeyStream::new(self.cipher, &self.key, nonce);
+                (0..num_bits).map(|_| key_stream.next_bit()).collect()
+            }
+            StreamCipher::Aes128Ctr | StreamCipher::Aes256Ctr => {
+                let round_keys = stream::bytes_to_bits(&aes::clear_expand_key(&self.key));
+                let round_keys = aes::bits_to_states(&round_keys);
+                let mut bits = aes::ctr_key_stream(
+                    &ClearBits,
+                    &round_keys,
+                    nonce.try_into().unwrap(),
+                    num_bits.div_ceil(aes::BLOCK_LEN * 8),
+                );
+                bits.truncate(num_bits);
+                bits
+            }
+        }
+    }
+}
+
+/// Values encrypted with a stream cipher, along with the nonce used to encrypt them
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SymmetricCiphertextList {
+    cipher: StreamCipher,
+    nonce: Vec<u8>,
+    /// The encrypted messages, packed 4 per byte, least significant bits first
+    data: Vec<u8>,
+    info: Vec<DataKind>,
+}
+
+impl SymmetricCiphertextList {
+    pub fn cipher(&self) -> StreamCipher {
+        self.cipher
+    }
+
+    pub fn nonce(&self) -> &[u8] {
+        &self.nonce
+    }
+
+    /// Returns the number of values in the list
+    pub fn len(&self) -> usize {
+        self.info.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of encrypted messages
+    ///
+    /// Returns an error if the list holds CRT integers (which cannot be transciphered), or if
+    /// the number of messages does not match the length of the data, which can only happen for
+    /// a malformed deserialized list.
+    fn num_messages(&self) -> crate::Result<usize> {
+        let num_messages = self
+            .info
+            .iter()
+            .try_fold(0usize, |num_messages, kind| match kind {
+                DataKind::Crt(_) => None,
+                kind => num_messages.checked_add(kind.num_blocks()),
+            })
+            .ok_or_else(|| {
+                crate::Error::new("Invalid symmetric ciphertext list info".to_string())
+            })?;
+
+        if self.data.len() != num_messages.div_ceil(4) {
+            return Err(crate::Error::new(format!(
+                "Invalid symmetric ciphertext list: {num_messages} messages require {} bytes \
+                of data, got {}",
+                num_messages.div_ceil(4),
+                self.data.len()
+            )));
+        }
+
+        Ok(num_messages)
+    }
+
+    fn message(&self, index: usize) -> u64 {
+        let shift = (index % 4) * BITS_PER_MESSAGE;
+        u64::from((self.data[index / 4] >> shift) & 0b11)
+    }
+}
+
+/// Builds a [SymmetricCiphertextList] from clear values of different types
+#[derive(Default)]
+pub struct SymmetricCiphertextListBuilder {
+    messages: Vec<u64>,
+    info: Vec<DataKind>,
+}
+
+impl SymmetricCiphertextListBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Compactable,
+    {
+        let kind = value.compact_into(&mut self.messages, MESSAGE_MODULUS, None);
+        self.info.push(kind);
+        self
+    }
+
+    pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
+    where
+        T: Compactable,
+    {
+        for value in values {
+            self.push(value);
+        }
+        self
+    }
+
+    /// Encrypts the values with the key and the nonce
+    ///
+    /// A nonce must never be used twice with the same key.
+    pub fn build(
+        &self,
+        key: &SymmetricKey,
+        nonce: &[u8],
+    ) -> crate::Result<SymmetricCiphertextList> {
+        key.cipher.check_nonce(nonce)?;
+
+        let key_stream = key.key_stream(nonce, self.messages.len() * BITS_PER_MESSAGE);
+        let mut data = vec![0u8; self.messages.len().div_ceil(4)];
+        for (i, (message, bits)) in self
+            .messages
+            .iter()
+            .zip(key_stream.chunks_exact(BITS_PER_MESSAGE))
+            .enumerate()
+        {
+            let mask = u64::from(bits[0]) | (u64::from(bits[1]) << 1);
+            let shift = (i % 4) * BITS_PER_MESSAGE;
+            data[i / 4] |= ((message ^ mask) as u8) << shift;
+        }
+
+        Ok(SymmetricCiphertextList {
+            cipher: key.cipher,
+            nonce: nonce.to_vec(),
+            data,
+            info: self.info.clone(),
+        })
+    }
+}
+
+/// The key used by the server to transcipher [SymmetricCiphertextList]
+///
+/// It contains the symmetric key encrypted bit by bit, the server key used to evaluate Trivium
+/// and Kreyvium on these bits, and the key switching key casting them to the FHE [ClientKey].
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TranscipheringKey {
+    cipher: StreamCipher,
+    encrypted_key: Vec<Ciphertext>,
+    server_key: crate::shortint::ServerKey,
+    key_switching_key: LweKeyswitchKeyOwned<u64>,
+}
+
+impl TranscipheringKey {
+    /// Generates the transciphering key of the symmetric key, for data to be transciphered to
+    /// ciphertexts of the client key
+    ///
+    /// Returns an error if the client key parameters are not
+    /// [PARAM_MESSAGE_2_CARRY_2_KS_PBS], the parameters the key switching key is generated for.
+    pub fn new(symmetric_key: &SymmetricKey, client_key: &ClientKey) -> crate::Result<Self> {
+        let fhe_client_key = &client_key.key.key.key;
+        if fhe_client_key.parameters.pbs_parameters() != Some(FHE_PARAMETERS) {
+            return Err(crate::Error::new(format!(
+                "Transciphering requires the {FHE_PARAMETERS:?} parameters, got {:?}",
+                fhe_client_key.parameters.pbs_parameters()
+            )));
+        }
+
+        let (bit_client_key, server_key) =
+            crate::shortint::gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+        // The AES keys are expanded by the client, the server only needs the round keys
+        let key_bytes = match symmetric_key.cipher {
+            StreamCipher::Trivium | StreamCipher::Kreyvium => symmetric_key.key.clone(),
+            StreamCipher::Aes128Ctr | StreamCipher::Aes256Ctr => {
+                aes::clear_expand_key(&symmetric_key.key)
+            }
+        };
+        let encrypted_key = stream::bytes_to_bits(&key_bytes)
+            .into_iter()
+            .map(|bit| bit_client_key.encrypt(u64::from(bit)))
+            .collect();
+        let key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_key_switching_key(
+                &bit_client_key,
+                fhe_client_key,
+                PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+            )
+        });
+
+        Ok(Self {
+            cipher: symmetric_key.cipher,
+            encrypted_key,
+            server_key,
+            key_switching_key,
+        })
+    }
+
+    pub fn cipher(&self) -> StreamCipher {
+        self.cipher
+    }
+
+    /// Returns the masks of the first `num_messages` messages, encrypted under the FHE server key,
+    /// and the shift of the masks in their ciphertexts
+    ///
+    /// Trivium and Kreyvium are evaluated with the bit server key and their keystream is key
+    /// switched, which puts the masks in the upper bits of the ciphertexts. AES is evaluated with
+    /// the FHE server key on the key switched round keys, as its S-box lookups pack several bits
+    /// in each block.
+    fn masks(
+        &self,
+        fhe_server_key: &crate::integer::ServerKey,
+        nonce: &[u8],
+        num_messages: usize,
+    ) -> (Vec<Ciphertext>, usize) {
+        let sk = &fhe_server_key.key;
+        let num_bits = num_messages * BITS_PER_MESSAGE;
+
+        // Once key switched, the messages are in the upper bits of the ciphertexts
+        let full_modulus = sk.message_modulus.0 * sk.carry_modulus.0;
+        let key_switched_shift = full_modulus.ilog2() as usize - BITS_PER_MESSAGE;
+        let key_switch = |ct: &Ciphertext| {
+            let mut block = sk.create_trivial(0);
+            keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut block.ct);
+            block.degree = Degree::new(full_modulus - 1);
+            block.set_noise_level(NoiseLevel::NOMINAL);
+            block.set_noise_variance(None);
+            block
+        };
+
+        match self.cipher {
+            StreamCipher::Trivium | StreamCipher::Kreyvium => {
+                let mut key_stream = EncryptedKeyStream::new(
+                    self.cipher,
+                    &self.encrypted_key,
+                    nonce,
+                    &self.server_key,
+                );
+                let mut bits = Vec::with_capacity(num_bits + 63);
+                while bits.len() < num_bits {
+                    bits.extend(key_stream.next_64());
+                }
+
+                let masks = bits
+                    .par_chunks_exact(BITS_PER_MESSAGE)
+                    .take(num_messages)
+                    .map(|bits| {
+                        key_switch(&self.server_key.unchecked_add(
+                            &bits[0],
+                            &self.server_key.unchecked_scalar_mul(&bits[1], 2),
+                        ))
+                    })
+                    .collect();
+                (masks, key_switched_shift)
+            }
+            StreamCipher::Aes128Ctr | StreamCipher::Aes256Ctr => {
+                let bit_lut = sk.generate_lookup_table(|x| (x >> key_switched_shift) & 1);
+                let round_keys = self
+                    .encrypted_key
+                    .par_iter()
+                    .map(|bit| {
+                        let mut bit = key_switch(bit);
+                        sk.apply_lookup_table_assign(&mut bit, &bit_lut);
+                        bit
+                    })
+                    .collect::<Vec<_>>();
+
+                let bits = aes::ctr_key_stream(
+                    &EncryptedBits::new(fhe_server_key),
+                    &aes::bits_to_states(&round_keys),
+                    nonce.try_into().unwrap(),
+                    num_bits.div_ceil(aes::BLOCK_LEN * 8),
+                );
+
+                let masks = bits
+                    .par_chunks_exact(BITS_PER_MESSAGE)
+                    .take(num_messages)
+                    .map(|bits| sk.unchecked_add(&bits[0], &sk.unchecked_scalar_mul(&bits[1], 2)))
+                    .collect();
+                (masks, 0)
+            }
+        }
+    }
+
+    /// Transciphers the list into FHE ciphertexts, using the server key that is set
+    ///
+    /// Returns an error if the list was not encrypted with the cipher of the key, or if it is
+    /// malformed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the server key is not set, or if it is not a CPU server key.
+    pub fn transcipher(
+        &self,
+        list: &SymmetricCiphertextList,
+    ) -> crate::Result<CompactCiphertextListExpander> {
+        if list.cipher != self.cipher {
+            return Err(crate::Error::new(format!(
+                "Cannot transcipher a {:?} ciphertext list with a {:?} transciphering key",
+                list.cipher, self.cipher
+            )));
+        }
+        self.cipher.check_nonce(&list.nonce)?;
+
+        let num_messages = list.num_messages()?;
+
+        let blocks = with_cpu_internal_keys(|key| {
+            let fhe_server_key = &key.pbs_key().key;
+            if fhe_server_key.message_modulus != MESSAGE_MODULUS {
+                return Err(crate::Error::new(format!(
+                    "Transciphering requires a server key with a message modulus of {}, got {}",
+                    MESSAGE_MODULUS.0, fhe_server_key.message_modulus.0
+                )));
+            }
+            if fhe_server_key.ciphertext_lwe_dimension()
+                != self.key_switching_key.output_key_lwe_dimension()
+            {
+                return Err(crate::Error::new(
+                    "The server key does not match the client key of the transciphering key"
+                        .to_string(),
+                ));
+            }
+
+            let (masks, shift) = self.masks(key.pbs_key(), &list.nonce, num_messages);
+
+            // The lookup table removing the shift also xors the keystream with the encrypted
+            // message, which is known in clear
+            let lookup_tables = (0..MESSAGE_MODULUS.0 as u64)
+                .map(|message| fhe_server_key.generate_lookup_table(|x| (x >> shift) ^ message))
+                .collect::<Vec<_>>();
+
+            Ok(masks
+                .into_par_iter()
+                .enumerate()
+                .map(|(i, mut block)| {
+                    let message = list.message(i) as usize;
+                    fhe_server_key.apply_lookup_table_assign(&mut block, &lookup_tables[message]);
+                    block
+                })
+                .collect::<Vec<_>>())
+        })?;
+
+        Ok(CompactCiphertextListExpander {
+            inner: crate::integer::ciphertext::CompactCiphertextListExpander::new(
+                blocks,
+                list.info.clone(),
+            ),
+        })
+    }
+}
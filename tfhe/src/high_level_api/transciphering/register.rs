@@ -0,0 +1,41 @@
+use std::ops::Index;
+
+/// A shift register of fixed size
+///
+/// Pushing a value drops the oldest one, index 0 is the youngest value and
+/// `N - 1` the oldest.
+#[derive(Clone)]
+pub(super) struct Register<const N: usize, T> {
+    values: [T; N],
+    /// Index of the oldest value, which is the next one to be overwritten
+    cursor: usize,
+}
+
+impl<const N: usize, T> Register<N, T> {
+    /// Creates a register from its values, the first one being the oldest
+    pub(super) fn new(values: [T; N]) -> Self {
+        Self { values, cursor: 0 }
+    }
+
+    pub(super) fn push(&mut self, value: T) {
+        self.values[self.cursor] = value;
+        self.shift(1);
+    }
+
+    /// Rotates the register, as if its `n` oldest values were pushed again
+    pub(super) fn shift(&mut self, n: usize) {
+        self.cursor = (self.cursor + n) % N;
+    }
+}
+
+impl<const N: usize, T> Index<usize> for Register<N, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        assert!(
+            index < N,
+            "Index {index} out of range for a register of size {N}"
+        );
+        &self.values[(N + self.cursor - index - 1) % N]
+    }
+}
@@ -0,0 +1,256 @@
+//! Trivium and Kreyvium keystream generators, in clear and over encrypted bits.
+
+use super::register::Register;
+use super::StreamCipher;
+use crate::shortint::{Ciphertext, ServerKey};
+use rayon::prelude::*;
+
+/// The specifications run 1152 (= 18 * 64) steps to mix the registers
+/// before outputting the keystream
+const INIT_ROUNDS: usize = 18;
+
+/// Returns the bits of the bytes, least significant bit first
+pub(super) fn bytes_to_bits(bytes: &[u8]) -> Vec<bool> {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+        .collect()
+}
+
+/// The internal state of Trivium, extended with the key and iv registers of Kreyvium
+struct Registers<T> {
+    a: Register<93, T>,
+    b: Register<84, T>,
+    c: Register<111, T>,
+    kreyvium: Option<(Register<128, T>, Register<128, T>)>,
+}
+
+impl<T: Clone> Registers<T> {
+    fn new(cipher: StreamCipher, key: &[T], iv: &[bool], trivial: impl Fn(bool) -> T) -> Self {
+        assert_eq!(key.len(), cipher.key_len() * 8);
+        assert_eq!(iv.len(), cipher.nonce_len() * 8);
+
+        match cipher {
+            StreamCipher::Trivium => {
+                // a has the key, b the iv, and c a few ones
+                let a = std::array::from_fn(|i| {
+                    i.checked_sub(93 - 80)
+                        .map_or_else(|| trivial(false), |i| key[i].clone())
+                });
+                let b = std::array::from_fn(|i| trivial(i >= 84 - 80 && iv[i - (84 - 80)]));
+                let c = std::array::from_fn(|i| trivial(i < 3));
+
+                Self {
+                    a: Register::new(a),
+                    b: Register::new(b),
+                    c: Register::new(c),
+                    kreyvium: None,
+                }
+            }
+            StreamCipher::Kreyvium => {
+                let a = std::array::from_fn(|i| key[128 - 93 + i].clone());
+                let b = std::array::from_fn(|i| trivial(iv[128 - 84 + i]));
+                let c = std::array::from_fn(|i| {
+                    if i >= 111 - 44 {
+                        trivial(iv[i - (111 - 44)])
+                    } else {
+                        trivial((1..=66).contains(&i))
+                    }
+                });
+                let k = std::array::from_fn(|i| key[127 - i].clone());
+                let iv = std::array::from_fn(|i| trivial(iv[127 - i]));
+
+                Self {
+                    a: Register::new(a),
+                    b: Register::new(b),
+                    c: Register::new(c),
+                    kreyvium: Some((Register::new(k), Register::new(iv))),
+                }
+            }
+            StreamCipher::Aes128Ctr | StreamCipher::Aes256Ctr => {
+                unreachable!("{cipher:?} is not a variant of Trivium")
+            }
+        }
+    }
+
+    /// Updates the registers with the values computed by the `values.len()` next steps,
+    /// and returns the output bits of these steps
+    fn apply_steps(&mut self, values: Vec<[T; 4]>) -> Vec<T> {
+        let num_steps = values.len();
+        let output = values
+            .into_iter()
+            .map(|[o, a, b, c]| {
+                self.a.push(a);
+                self.b.push(b);
+                self.c.push(c);
+                o
+            })
+            .collect();
+
+        if let Some((k, iv)) = &mut self.kreyvium {
+            k.shift(num_steps);
+            iv.shift(num_steps);
+        }
+        output
+    }
+}
+
+/// Generates the keystream in clear, used by the client to encrypt its data
+pub(super) struct ClearKeyStream {
+    registers: Registers<bool>,
+}
+
+impl ClearKeyStream {
+    pub(super) fn new(cipher: StreamCipher, key: &[u8], iv: &[u8]) -> Self {
+        let mut stream = Self {
+            registers: Registers::new(cipher, &bytes_to_bits(key), &bytes_to_bits(iv), |bit| bit),
+        };
+        for _ in 0..INIT_ROUNDS * 64 {
+            stream.next_bit();
+        }
+        stream
+    }
+
+    pub(super) fn next_bit(&mut self) -> bool {
+        let values = self.step();
+        self.registers.apply_steps(vec![values])[0]
+    }
+
+    fn step(&self) -> [bool; 4] {
+        let Registers { a, b, c, kreyvium } = &self.registers;
+
+        let (k, iv) = kreyvium
+            .as_ref()
+            .map_or((false, false), |(k, iv)| (k[127], iv[127]));
+
+        let temp_a = a[65] ^ a[92];
+        let temp_b = b[68] ^ b[83];
+        let temp_c = c[65] ^ c[110] ^ k;
+
+        let o = temp_a ^ temp_b ^ temp_c;
+        let new_a = temp_c ^ (c[109] & c[108]) ^ a[68];
+        let new_b = temp_a ^ (a[91] & a[90]) ^ iv ^ b[77];
+        let new_c = temp_b ^ (b[82] & b[81]) ^ c[86];
+
+        [o, new_a, new_b, new_c]
+    }
+}
+
+/// Generates the keystream homomorphically, from the encrypted bits of the key
+///
+/// The bits are encrypted with 1 bit of message and 1 bit of carry, so that the xors can be
+/// computed as additions, with a single bootstrap to clean the result.
+pub(super) struct EncryptedKeyStream<'a> {
+    registers: Registers<Ciphertext>,
+    server_key: &'a ServerKey,
+}
+
+impl<'a> EncryptedKeyStream<'a> {
+    pub(super) fn new(
+        cipher: StreamCipher,
+        key: &[Ciphertext],
+        iv: &[u8],
+        server_key: &'a ServerKey,
+    ) -> Self {
+        let registers = Registers::new(cipher, key, &bytes_to_bits(iv), |bit| {
+            server_key.create_trivial(u64::from(bit))
+        });
+        let mut stream = Self {
+            registers,
+            server_key,
+        };
+        for _ in 0..INIT_ROUNDS {
+            stream.next_64();
+        }
+        stream
+    }
+
+    /// Computes the next 64 bits of the keystream, the first one being the oldest
+    pub(super) fn next_64(&mut self) -> Vec<Ciphertext> {
+        // The next 64 steps only depend on the current registers, so they are computed in
+        // parallel
+        let values = (0..64)
+            .into_par_iter()
+            .map(|n| self.step(n))
+            .collect::<Vec<_>>();
+        self.registers.apply_steps(values)
+    }
+
+    /// Returns the output and the new register values of the step `n` steps in the future,
+    /// without updating the registers
+    fn step(&self, n: usize) -> [Ciphertext; 4] {
+        let sk = self.server_key;
+        let Registers { a, b, c, kreyvium } = &self.registers;
+
+        let temp_a = sk.unchecked_add(&a[65 - n], &a[92 - n]);
+        let temp_b = sk.unchecked_add(&b[68 - n], &b[83 - n]);
+        let mut temp_c = sk.unchecked_add(&c[65 - n], &c[110 - n]);
+
+        let ((new_a, new_b), (new_c, o)) = match kreyvium {
+            None => rayon::join(
+                || {
+                    rayon::join(
+                        || xor_and(sk, (&c[109 - n], &c[108 - n]), &[&a[68 - n], &temp_c]),
+                        || xor_and(sk, (&a[91 - n], &a[90 - n]), &[&b[77 - n], &temp_a]),
+                    )
+                },
+                || {
+                    rayon::join(
+                        || xor_and(sk, (&b[82 - n], &b[81 - n]), &[&c[86 - n], &temp_b]),
+                        || sk.bitxor(&sk.unchecked_add(&temp_a, &temp_b), &temp_c),
+                    )
+                },
+            ),
+            Some((k, iv)) => {
+                sk.unchecked_add_assign(&mut temp_c, &k[127 - n]);
+                let iv = &iv[127 - n];
+
+                rayon::join(
+                    || {
+                        rayon::join(
+                            || {
+                                // temp_c already has 3 bits, the sum is cleaned in two steps
+                                let mut new_a = sk.unchecked_bitand(&c[109 - n], &c[108 - n]);
+                                sk.unchecked_add_assign(&mut new_a, &a[68 - n]);
+                                sk.add_assign(&mut new_a, &temp_c);
+                                new_a
+                            },
+                            || {
+                                let mut new_b = sk.unchecked_bitand(&a[91 - n], &a[90 - n]);
+                                sk.unchecked_add_assign(&mut new_b, &b[77 - n]);
+                                sk.unchecked_add_assign(&mut new_b, &temp_a);
+                                sk.add_assign(&mut new_b, iv);
+                                new_b
+                            },
+                        )
+                    },
+                    || {
+                        rayon::join(
+                            || xor_and(sk, (&b[82 - n], &b[81 - n]), &[&c[86 - n], &temp_b]),
+                            || sk.bitxor(&sk.unchecked_add(&temp_a, &temp_b), &temp_c),
+                        )
+                    },
+                )
+            }
+        };
+
+        [o, new_a, new_b, new_c]
+    }
+}
+
+/// Computes `(lhs & rhs) ^ terms`, the terms being sums of bits
+///
+/// The sum may exceed the carry space by one, which is fine as the bootstrap of the
+/// negacyclic padding region maps it back to 0, the parity of 4.
+fn xor_and(
+    sk: &ServerKey,
+    (lhs, rhs): (&Ciphertext, &Ciphertext),
+    terms: &[&Ciphertext],
+) -> Ciphertext {
+    let mut result = sk.unchecked_bitand(lhs, rhs);
+    for term in terms {
+        sk.unchecked_add_assign(&mut result, term);
+    }
+    sk.message_extract_assign(&mut result);
+    result
+}
@@ -0,0 +1,265 @@
+use super::aes::{self, bits_to_byte, byte_to_bits};
+use super::bits::ClearBits;
+use super::stream::ClearKeyStream;
+use super::{
+    FheAesKey, StreamCipher, SymmetricCiphertextList, SymmetricCiphertextListBuilder, SymmetricKey,
+    TranscipheringKey,
+};
+use crate::high_level_api::prelude::*;
+use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder};
+use crate::integer::ciphertext::DataKind;
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_PBS_KS;
+use crate::{ClientKey, FheBool, FheInt32, FheTypes, FheUint16, FheUint64, FheUint8};
+
+/// Returns the first bytes of the keystream, the bits of each byte being
+/// the successive bits of the stream
+fn clear_key_stream_bytes(cipher: StreamCipher, key: &[u8], iv: &[u8], len: usize) -> Vec<u8> {
+    let mut stream = ClearKeyStream::new(cipher, key, iv);
+    (0..len)
+        .map(|_| (0..8).fold(0u8, |byte, i| byte | (u8::from(stream.next_bit()) << i)))
+        .collect()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02X}")).collect()
+}
+
+fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+    std::array::from_fn(|i| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap())
+}
+
+fn clear_aes(key: &[u8], block: [u8; 16], decrypt: bool) -> [u8; 16] {
+    let key = key.iter().copied().map(byte_to_bits).collect::<Vec<_>>();
+    let round_keys = aes::expand_key(&ClearBits, &key);
+    let block = block.map(byte_to_bits);
+    let output = if decrypt {
+        aes::decrypt_block(&ClearBits, &round_keys, &block)
+    } else {
+        aes::encrypt_block(&ClearBits, &round_keys, &block)
+    };
+    output.map(bits_to_byte)
+}
+
+#[test]
+fn test_clear_trivium_key_stream() {
+    // Test vectors of the eSTREAM project
+    let key_stream = clear_key_stream_bytes(StreamCipher::Trivium, &[0; 10], &[0; 10], 64);
+    assert_eq!(
+        hex(&key_stream),
+        "FBE0BF265859051B517A2E4E239FC97F563203161907CF2DE7A8790FA1B2E9CD\
+        F75292030268B7382B4C1A759AA2599A285549986E74805903801A4CB5A5D4F2"
+    );
+
+    let mut key = [0; 10];
+    key[0] = 0x80;
+    let key_stream = clear_key_stream_bytes(StreamCipher::Trivium, &key, &[0; 10], 16);
+    assert_eq!(hex(&key_stream), "38EB86FF730D7A9CAF8DF13A4420540D");
+
+    let key = [0x00, 0x53, 0xA6, 0xF9, 0x4C, 0x9F, 0xF2, 0x45, 0x98, 0xEB];
+    let iv = [0x0D, 0x74, 0xDB, 0x42, 0xA9, 0x10, 0x77, 0xDE, 0x45, 0xAC];
+    let key_stream = clear_key_stream_bytes(StreamCipher::Trivium, &key, &iv, 16);
+    assert_eq!(hex(&key_stream), "F4CD954A717F26A7D6930830C4E7CF08");
+}
+
+#[test]
+fn test_clear_kreyvium_key_stream() {
+    // Test vectors of the reference implementation
+    let key_stream = clear_key_stream_bytes(StreamCipher::Kreyvium, &[0; 16], &[0; 16], 8);
+    assert_eq!(hex(&key_stream), "26DCF1F4BC0F1922");
+
+    let mut key = [0; 16];
+    key[0] = 1;
+    let key_stream = clear_key_stream_bytes(StreamCipher::Kreyvium, &key, &[0; 16], 8);
+    assert_eq!(hex(&key_stream), "4FD421D4DA3D2C8A");
+
+    let mut iv = [0; 16];
+    iv[0] = 1;
+    let key_stream = clear_key_stream_bytes(StreamCipher::Kreyvium, &[0; 16], &iv, 8);
+    assert_eq!(hex(&key_stream), "C9217BA0D762ACA1");
+}
+
+#[test]
+fn test_clear_aes() {
+    // Test vectors of FIPS-197
+    let plaintext = from_hex("00112233445566778899AABBCCDDEEFF");
+    let cases = [
+        (
+            "000102030405060708090A0B0C0D0E0F",
+            "69C4E0D86A7B0430D8CDB78070B4C55A",
+        ),
+        (
+            "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F",
+            "8EA2B7CA516745BFEAFC49904B496089",
+        ),
+    ];
+    for (key, ciphertext) in cases {
+        let key = (0..key.len() / 2)
+            .map(|i| u8::from_str_radix(&key[2 * i..2 * i + 2], 16).unwrap())
+            .collect::<Vec<_>>();
+        let encrypted = clear_aes(&key, plaintext, false);
+        assert_eq!(hex(&encrypted), ciphertext);
+        assert_eq!(clear_aes(&key, encrypted, true), plaintext);
+    }
+
+    // The S-box and its inverse are checked on all bytes
+    let key = [0x5A; 16];
+    for byte in 0..=255u8 {
+        let block = std::array::from_fn(|i| byte.wrapping_add(i as u8));
+        assert_eq!(clear_aes(&key, clear_aes(&key, block, false), true), block);
+    }
+}
+
+#[test]
+fn test_symmetric_encryption() {
+    let key = SymmetricKey::new(StreamCipher::Trivium, &[0; 10]).unwrap();
+    let list = key.encrypt_bytes(&[0x00, 0xFF, 0x12], &[0; 10]).unwrap();
+    assert_eq!(list.len(), 3);
+    // The bytes are xored with the keystream
+    assert_eq!(list.data, vec![0xFB, 0xE0 ^ 0xFF, 0xBF ^ 0x12]);
+
+    assert!(SymmetricKey::new(StreamCipher::Trivium, &[0; 16]).is_err());
+    assert!(SymmetricKey::new(StreamCipher::Kreyvium, &[0; 16]).is_ok());
+    assert!(key.encrypt_bytes(&[1, 2], &[0; 9]).is_err());
+
+    // Test vector of NIST SP 800-38A for AES-128 in counter mode
+    let key = SymmetricKey::new(
+        StreamCipher::Aes128Ctr,
+        &from_hex::<16>("2B7E151628AED2A6ABF7158809CF4F3C"),
+    )
+    .unwrap();
+    let list = key
+        .encrypt_bytes(
+            &from_hex::<16>("6BC1BEE22E409F96E93D7E117393172A"),
+            &from_hex::<16>("F0F1F2F3F4F5F6F7F8F9FAFBFCFDFEFF"),
+        )
+        .unwrap();
+    assert_eq!(hex(&list.data), "874D6191B620E3261BEF6864990DB6CE");
+}
+
+fn test_transciphering(cipher: StreamCipher) {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    let symmetric_key = SymmetricKey::new(cipher, &vec![0xA5; cipher.key_len()]).unwrap();
+    let transciphering_key = TranscipheringKey::new(&symmetric_key, &client_key).unwrap();
+
+    let nonce = vec![0x3C; cipher.nonce_len()];
+    let list = SymmetricCiphertextListBuilder::new()
+        .push(200u8)
+        .push(true)
+        .push(-123456i32)
+        .push(0xDEAD_BEEF_CAFE_F00Du64)
+        .build(&symmetric_key, &nonce)
+        .unwrap();
+
+    let expander = transciphering_key.transcipher(&list).unwrap();
+    assert_eq!(expander.len(), 4);
+    assert_eq!(expander.get_kind_of(2), Some(FheTypes::Int32));
+
+    let a: FheUint8 = expander.get(0).unwrap().unwrap();
+    let b: FheBool = expander.get(1).unwrap().unwrap();
+    let c: FheInt32 = expander.get(2).unwrap().unwrap();
+    let d: FheUint64 = expander.get(3).unwrap().unwrap();
+    assert!(expander.get::<FheUint16>(0).unwrap().is_err());
+
+    // The transciphered ciphertexts can be used in computations
+    let a_plus_one: u8 = (&a + 1u8).decrypt(&client_key);
+    assert_eq!(a_plus_one, 201);
+
+    let a: u8 = a.decrypt(&client_key);
+    let c: i32 = c.decrypt(&client_key);
+    let d: u64 = d.decrypt(&client_key);
+    assert_eq!(a, 200);
+    assert!(b.decrypt(&client_key));
+    assert_eq!(c, -123456);
+    assert_eq!(d, 0xDEAD_BEEF_CAFE_F00D);
+
+    let other_cipher = match cipher {
+        StreamCipher::Trivium => StreamCipher::Kreyvium,
+        StreamCipher::Kreyvium | StreamCipher::Aes128Ctr | StreamCipher::Aes256Ctr => {
+            StreamCipher::Trivium
+        }
+    };
+    let other_key = SymmetricKey::new(other_cipher, &vec![0; other_cipher.key_len()]).unwrap();
+    let other_list = other_key
+        .encrypt_bytes(&[1], &vec![0; other_cipher.nonce_len()])
+        .unwrap();
+    assert!(transciphering_key.transcipher(&other_list).is_err());
+}
+
+#[test]
+fn test_transciphering_key_parameters() {
+    let symmetric_key = SymmetricKey::new(StreamCipher::Trivium, &[0; 10]).unwrap();
+
+    // Same message modulus, but the key switching parameters are not valid for these parameters
+    let config = ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_2_CARRY_2_PBS_KS, None);
+    let (client_key, _) = generate_keys(config);
+    assert!(TranscipheringKey::new(&symmetric_key, &client_key).is_err());
+}
+
+#[test]
+fn test_transciphering_malformed_list() {
+    let config = ConfigBuilder::default().build();
+    let client_key = ClientKey::generate(config);
+    let symmetric_key = SymmetricKey::new(StreamCipher::Trivium, &[0; 10]).unwrap();
+    let transciphering_key = TranscipheringKey::new(&symmetric_key, &client_key).unwrap();
+
+    let list = symmetric_key.encrypt_bytes(&[1, 2, 3], &[0; 10]).unwrap();
+
+    // Data too short for the info
+    let mut truncated = list.clone();
+    truncated.data.pop();
+    let serialized = bincode::serialize(&truncated).unwrap();
+    let truncated: SymmetricCiphertextList = bincode::deserialize(&serialized).unwrap();
+    assert!(transciphering_key.transcipher(&truncated).is_err());
+
+    // Info that does not match the data
+    let mut inconsistent = list.clone();
+    inconsistent.info.push(DataKind::Unsigned(usize::MAX));
+    assert!(transciphering_key.transcipher(&inconsistent).is_err());
+
+    let mut inconsistent = list;
+    inconsistent.info[0] = DataKind::Crt(4);
+    assert!(transciphering_key.transcipher(&inconsistent).is_err());
+}
+
+#[test]
+fn test_trivium_transciphering() {
+    test_transciphering(StreamCipher::Trivium);
+}
+
+#[test]
+fn test_kreyvium_transciphering() {
+    test_transciphering(StreamCipher::Kreyvium);
+}
+
+#[test]
+fn test_aes_transciphering() {
+    test_transciphering(StreamCipher::Aes128Ctr);
+}
+
+#[test]
+fn test_fhe_aes_key() {
+    let (client_key, server_key) = generate_keys(ConfigBuilder::default());
+    set_server_key(server_key);
+
+    let key = (0..16u8)
+        .map(|byte| FheUint8::encrypt(byte, &client_key))
+        .collect::<Vec<_>>();
+    let plaintext = from_hex::<16>("00112233445566778899AABBCCDDEEFF");
+    let block = plaintext.map(|byte| FheUint8::encrypt(byte, &client_key));
+
+    assert!(FheAesKey::new(&key[..10]).is_err());
+    let aes_key = FheAesKey::new(&key).unwrap();
+    assert_eq!(aes_key.key_len(), 16);
+
+    let encrypted = aes_key.encrypt_block(&block);
+    let clear_encrypted = encrypted
+        .each_ref()
+        .map(|byte| -> u8 { byte.decrypt(&client_key) });
+    assert_eq!(hex(&clear_encrypted), "69C4E0D86A7B0430D8CDB78070B4C55A");
+
+    let decrypted = aes_key.decrypt_block(&encrypted);
+    let decrypted = decrypted.map(|byte| -> u8 { byte.decrypt(&client_key) });
+    assert_eq!(decrypted, plaintext);
+}
@@ -0,0 +1,220 @@
+//! Versions of the serialized integer types, see [`crate::versionable`].
+
+use crate::integer::ciphertext::{
+    CompactCiphertextList, CompressedCiphertextList, CompressedCrtCiphertext,
+    CompressedModulusSwitchedRadixCiphertext, CompressedModulusSwitchedSignedRadixCiphertext,
+    CompressedRadixCiphertext, CompressedSignedRadixCiphertext,
+};
+use crate::integer::compression_keys::{
+    CompressedCompressionKey, CompressedDecompressionKey, CompressionKey, CompressionPrivateKeys,
+    DecompressionKey,
+};
+use crate::integer::key_switching_key::KeySwitchingKey;
+use crate::integer::wopbs::WopbsKey;
+use crate::integer::{
+    BooleanBlock, ClientKey, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey,
+    CompressedServerKey, CrtCiphertext, CrtClientKey, PublicKey, RadixCiphertext, RadixClientKey,
+    ServerKey, SignedRadixCiphertext,
+};
+use crate::versionable::versions_dispatch;
+
+versions_dispatch! {
+    pub enum RadixCiphertextVersions for RadixCiphertext {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum SignedRadixCiphertextVersions for SignedRadixCiphertext {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CrtCiphertextVersions for CrtCiphertext {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum BooleanBlockVersions for BooleanBlock {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedRadixCiphertextVersions for CompressedRadixCiphertext {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedSignedRadixCiphertextVersions for CompressedSignedRadixCiphertext {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedCrtCiphertextVersions for CompressedCrtCiphertext {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedModulusSwitchedRadixCiphertextVersions
+        for CompressedModulusSwitchedRadixCiphertext
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedModulusSwitchedSignedRadixCiphertextVersions
+        for CompressedModulusSwitchedSignedRadixCiphertext
+    {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompactCiphertextListVersions for CompactCiphertextList {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedCiphertextListVersions for CompressedCiphertextList {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum ClientKeyVersions for ClientKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum RadixClientKeyVersions for RadixClientKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CrtClientKeyVersions for CrtClientKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum ServerKeyVersions for ServerKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedServerKeyVersions for CompressedServerKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum PublicKeyVersions for PublicKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedPublicKeyVersions for CompressedPublicKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompactPublicKeyVersions for CompactPublicKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedCompactPublicKeyVersions for CompressedCompactPublicKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum KeySwitchingKeyVersions for KeySwitchingKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum WopbsKeyVersions for WopbsKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressionPrivateKeysVersions for CompressionPrivateKeys {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressionKeyVersions for CompressionKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum DecompressionKeyVersions for DecompressionKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedCompressionKeyVersions for CompressedCompressionKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedDecompressionKeyVersions for CompressedDecompressionKey {
+        #[current]
+        V0,
+    }
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+versions_dispatch! {
+    pub enum ProvenCompactCiphertextListVersions for crate::integer::ProvenCompactCiphertextList {
+        #[current]
+        V0,
+    }
+}
@@ -5,14 +5,17 @@ use crate::integer::block_decomposition::{BlockRecomposer, RecomposableFrom};
 use crate::integer::client_key::{sign_extend_partial_number, RecomposableSignedInteger};
 use crate::shortint::ciphertext::NotTrivialCiphertextError;
 use crate::shortint::parameters::CiphertextConformanceParams;
-use crate::shortint::Ciphertext;
+use crate::shortint::{CarryModulus, Ciphertext, MessageModulus};
+use crate::versionable::Versionize;
 use serde::{Deserialize, Serialize};
 
 /// Structure containing a ciphertext in radix decomposition
 /// holding an unsigned value.
 #[derive(Serialize, Clone, Deserialize, PartialEq, Eq, Debug)]
+#[serde(bound = "Block: Versionize")]
 pub struct BaseRadixCiphertext<Block> {
     /// The blocks are stored from LSB to MSB
+    #[serde(with = "crate::versionable::nested_vec")]
     pub(crate) blocks: Vec<Block>,
 }
 
@@ -25,6 +28,10 @@ impl<Block> From<Vec<Block>> for BaseRadixCiphertext<Block> {
 // Type alias to save some typing in implementation parts
 pub type RadixCiphertext = BaseRadixCiphertext<Ciphertext>;
 
+impl crate::named::Named for RadixCiphertext {
+    const NAME: &'static str = "integer::RadixCiphertext";
+}
+
 impl<T: ParameterSetConformant<ParameterSet = CiphertextConformanceParams>> ParameterSetConformant
     for BaseRadixCiphertext<T>
 {
@@ -111,8 +118,10 @@ impl RadixCiphertext {
 /// Structure containing a ciphertext in radix decomposition
 /// holding a signed value.
 #[derive(Serialize, Clone, Deserialize, PartialEq, Eq, Debug)]
+#[serde(bound = "Block: Versionize")]
 pub struct BaseSignedRadixCiphertext<Block> {
     /// The blocks are stored from LSB to MSB
+    #[serde(with = "crate::versionable::nested_vec")]
     pub(crate) blocks: Vec<Block>,
 }
 
@@ -125,6 +134,10 @@ impl<Block> From<Vec<Block>> for BaseSignedRadixCiphertext<Block> {
 // Type alias to save some typing in implementation parts
 pub type SignedRadixCiphertext = BaseSignedRadixCiphertext<Ciphertext>;
 
+impl crate::named::Named for SignedRadixCiphertext {
+    const NAME: &'static str = "integer::SignedRadixCiphertext";
+}
+
 impl<T: ParameterSetConformant<ParameterSet = CiphertextConformanceParams>> ParameterSetConformant
     for BaseSignedRadixCiphertext<T>
 {
@@ -215,7 +228,9 @@ impl SignedRadixCiphertext {
 /// For this CRT decomposition, each block is encrypted using
 /// the same parameters.
 #[derive(Serialize, Clone, Deserialize)]
+#[serde(bound = "Block: Versionize")]
 pub struct BaseCrtCiphertext<Block> {
+    #[serde(with = "crate::versionable::nested_vec")]
     pub(crate) blocks: Vec<Block>,
     pub(crate) moduli: Vec<u64>,
 }
@@ -223,8 +238,21 @@ pub struct BaseCrtCiphertext<Block> {
 /// Structure containing a ciphertext in CRT decomposition.
 pub type CrtCiphertext = BaseCrtCiphertext<Ciphertext>;
 
+impl crate::named::Named for CrtCiphertext {
+    const NAME: &'static str = "integer::CrtCiphertext";
+}
+
 impl<Block> From<(Vec<Block>, Vec<u64>)> for BaseCrtCiphertext<Block> {
     fn from((blocks, moduli): (Vec<Block>, Vec<u64>)) -> Self {
         Self { blocks, moduli }
     }
 }
+
+/// Returns the largest CRT modulus that can be used with blocks of the given moduli
+///
+/// Radix server keys limit the degree of a block to
+/// message_modulus * carry_modulus - carry_modulus, the sum of two residues must not exceed it.
+pub(crate) fn max_crt_modulus(message_modulus: MessageModulus, carry_modulus: CarryModulus) -> u64 {
+    let max_degree = message_modulus.0 * carry_modulus.0 - carry_modulus.0;
+    (max_degree / 2 + 1) as u64
+}
@@ -43,7 +43,11 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(u8::from(a >= b), dec);
 /// ```
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
-pub struct BooleanBlock(pub(crate) Ciphertext);
+pub struct BooleanBlock(#[serde(with = "crate::versionable::nested")] pub(crate) Ciphertext);
+
+impl crate::named::Named for BooleanBlock {
+    const NAME: &'static str = "integer::BooleanBlock";
+}
 
 impl BooleanBlock {
     /// Creates a new BooleanBlock without checks.
@@ -1,12 +1,13 @@
-use super::IntegerRadixCiphertext;
+use super::{max_crt_modulus, IntegerRadixCiphertext};
 use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
 use crate::core_crypto::prelude::Numeric;
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::encryption::{create_clear_radix_block_iterator, KnowsMessageModulus};
 use crate::integer::parameters::CompactCiphertextListConformanceParams;
-use crate::integer::{BooleanBlock, CompactPublicKey, ServerKey};
+use crate::integer::{BooleanBlock, CompactPublicKey, CrtCiphertext, ServerKey};
+use crate::shortint::ciphertext::Degree;
 use crate::shortint::parameters::CiphertextConformanceParams;
-use crate::shortint::{Ciphertext, MessageModulus};
+use crate::shortint::{CarryModulus, Ciphertext, MessageModulus};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -39,17 +40,34 @@ pub enum DataKind {
     Unsigned(usize),
     Signed(usize),
     Boolean,
+    /// A CRT integer, with the number of moduli of its basis
+    Crt(usize),
 }
 
 impl DataKind {
     pub fn num_blocks(self) -> usize {
         match self {
-            Self::Unsigned(n) | Self::Signed(n) => n,
+            Self::Unsigned(n) | Self::Signed(n) | Self::Crt(n) => n,
             Self::Boolean => 1,
         }
     }
 }
 
+/// Lists holding CRT integers cannot be packed, they are encrypted with the full plaintext
+/// modulus (message_modulus * carry_modulus) so that their degree does not depend on the moduli
+fn contains_crt(info: &[DataKind]) -> bool {
+    info.iter().any(|kind| matches!(kind, DataKind::Crt(_)))
+}
+
+/// Sets the degree of blocks expanded from a list holding CRT integers back to the one of
+/// radix blocks, the residues get the degree of their modulus once given to
+/// [crt_from_expanded_blocks]
+fn reset_crt_list_degrees(blocks: &mut [Ciphertext]) {
+    for block in blocks {
+        block.degree = Degree::new(block.message_modulus.0 - 1);
+    }
+}
+
 pub trait Compactable {
     fn compact_into(
         self,
@@ -111,6 +129,16 @@ impl CompactCiphertextListBuilder {
         }
     }
 
+    /// Residues of CRT integers may need a larger modulus than the radix blocks
+    fn encryption_modulus(&self) -> u64 {
+        let params = &self.pk.key.parameters;
+        if contains_crt(&self.info) {
+            (params.message_modulus.0 * params.carry_modulus.0) as u64
+        } else {
+            params.message_modulus.0 as u64
+        }
+    }
+
     pub fn push<T>(&mut self, data: T) -> &mut Self
     where
         T: Compactable,
@@ -146,6 +174,35 @@ impl CompactCiphertextListBuilder {
         self
     }
 
+    /// Pushes a CRT integer, decomposed in residues modulo each modulus of the basis
+    ///
+    /// The moduli are not stored in the list, the same moduli must be given to
+    /// [CompactCiphertextListExpander::get_crt] to get the integer back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a modulus is smaller than 2 or too large for the sum of two residues to fit in a
+    /// block (at most 7 with 2 bits of message and 2 bits of carry).
+    pub fn push_crt(&mut self, value: u64, moduli: &[u64]) -> &mut Self {
+        if moduli.is_empty() {
+            return self;
+        }
+
+        let max_modulus = max_crt_modulus(
+            self.pk.key.parameters.message_modulus,
+            self.pk.key.parameters.carry_modulus,
+        );
+        for &modulus in moduli {
+            assert!(
+                (2..=max_modulus).contains(&modulus),
+                "CRT moduli must be in [2, {max_modulus}], got {modulus}"
+            );
+            self.messages.push(value % modulus);
+        }
+        self.info.push(DataKind::Crt(moduli.len()));
+        self
+    }
+
     pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
     where
         T: Compactable,
@@ -171,7 +228,10 @@ impl CompactCiphertextListBuilder {
     }
 
     pub fn build(&self) -> CompactCiphertextList {
-        let ct_list = self.pk.key.encrypt_slice(self.messages.as_slice());
+        let ct_list = self
+            .pk
+            .key
+            .encrypt_slice_with_modulus(self.messages.as_slice(), self.encryption_modulus());
         CompactCiphertextList {
             ct_list,
             info: self.info.clone(),
@@ -182,6 +242,11 @@ impl CompactCiphertextListBuilder {
         if self.pk.key.parameters.carry_modulus.0 < self.pk.key.parameters.message_modulus.0 {
             return Err(crate::Error::new("In order to build a packed compact ciphertext list, parameters must have CarryModulus >= MessageModulus".to_string()));
         }
+        if contains_crt(&self.info) {
+            return Err(crate::Error::new(
+                "A compact ciphertext list holding CRT integers cannot be packed".to_string(),
+            ));
+        }
 
         // Here self.messages are decomposed blocks in range [0..message_modulus[
         let msg_mod = self.pk.key.message_modulus().0 as u64;
@@ -210,7 +275,7 @@ impl CompactCiphertextListBuilder {
             self.messages.as_slice(),
             public_params,
             load,
-            self.pk.key.parameters.message_modulus.0 as u64,
+            self.encryption_modulus(),
         )?;
         Ok(ProvenCompactCiphertextList {
             ct_list,
@@ -231,6 +296,11 @@ impl CompactCiphertextListBuilder {
                     .to_string(),
             ));
         }
+        if contains_crt(&self.info) {
+            return Err(crate::Error::new(
+                "A ProvenCompactCiphertextList holding CRT integers cannot be packed".to_string(),
+            ));
+        }
 
         let msg_mod = self.pk.key.parameters.message_modulus.0 as u64;
         let packed_messages = self
@@ -276,6 +346,12 @@ where
             (DataKind::Signed(_), false) => Err(crate::Error::new(
                 "Tried to expand an unsigned radix while a signed radix is stored".to_string(),
             )),
+            (DataKind::Crt(_), _) => {
+                let signed_or_unsigned_str = if T::IS_SIGNED { "signed" } else { "unsigned" };
+                Err(crate::Error::new(format!(
+                    "Tried to expand a {signed_or_unsigned_str} radix while a CRT integer is stored"
+                )))
+            }
         }
     }
 }
@@ -290,17 +366,61 @@ impl Expandable for BooleanBlock {
                 "Tried to expand a boolean block while a signed radix was stored".to_string(),
             )),
             DataKind::Boolean => Ok(Self::new_unchecked(blocks[0].clone())),
+            DataKind::Crt(_) => Err(crate::Error::new(
+                "Tried to expand a boolean block while a CRT integer was stored".to_string(),
+            )),
         }
     }
 }
 
+/// Creates a CRT integer from expanded blocks, whose residues are modulo the given moduli
+///
+/// Expanded blocks have the message and carry moduli of the parameters, they are set to the ones
+/// of CRT blocks.
+pub(crate) fn crt_from_expanded_blocks(
+    blocks: &[Ciphertext],
+    kind: DataKind,
+    moduli: &[u64],
+) -> crate::Result<CrtCiphertext> {
+    match kind {
+        DataKind::Crt(n) if n == moduli.len() => {
+            let blocks = blocks
+                .iter()
+                .zip(moduli.iter())
+                .map(|(block, &modulus)| {
+                    let plaintext_modulus = block.message_modulus.0 * block.carry_modulus.0;
+                    let mut block = block.clone();
+                    block.message_modulus = MessageModulus(modulus as usize);
+                    block.carry_modulus = CarryModulus(plaintext_modulus / modulus as usize);
+                    block.degree = Degree::new(modulus as usize - 1);
+                    block
+                })
+                .collect();
+            Ok(CrtCiphertext::from((blocks, moduli.to_vec())))
+        }
+        DataKind::Crt(n) => Err(crate::Error::new(format!(
+            "Tried to expand a CRT integer with {} moduli while one with {n} moduli is stored",
+            moduli.len()
+        ))),
+        DataKind::Unsigned(_) => Err(crate::Error::new(
+            "Tried to expand a CRT integer while an unsigned radix is stored".to_string(),
+        )),
+        DataKind::Signed(_) => Err(crate::Error::new(
+            "Tried to expand a CRT integer while a signed radix is stored".to_string(),
+        )),
+        DataKind::Boolean => Err(crate::Error::new(
+            "Tried to expand a CRT integer while a boolean block is stored".to_string(),
+        )),
+    }
+}
+
 pub struct CompactCiphertextListExpander {
     expanded_blocks: Vec<Ciphertext>,
     info: Vec<DataKind>,
 }
 
 impl CompactCiphertextListExpander {
-    fn new(expanded_blocks: Vec<Ciphertext>, info: Vec<DataKind>) -> Self {
+    pub(crate) fn new(expanded_blocks: Vec<Ciphertext>, info: Vec<DataKind>) -> Self {
         Self {
             expanded_blocks,
             info,
@@ -315,7 +435,7 @@ impl CompactCiphertextListExpander {
         self.len() == 0
     }
 
-    fn blocks_of(&self, index: usize) -> Option<(&[Ciphertext], DataKind)> {
+    pub(crate) fn blocks_of(&self, index: usize) -> Option<(&[Ciphertext], DataKind)> {
         let preceding_infos = self.info.get(..index)?;
         let current_info = self.info.get(index).copied()?;
 
@@ -343,6 +463,17 @@ impl CompactCiphertextListExpander {
             .map(|(blocks, kind)| T::from_expanded_blocks(blocks, kind))
     }
 
+    /// Returns the CRT integer stored at `index`
+    ///
+    /// The moduli must be the ones given when the integer was pushed in the list.
+    ///
+    /// Returns `None` if `index` is out of bounds and an error if the stored element is not a
+    /// CRT integer with as many moduli.
+    pub fn get_crt(&self, index: usize, moduli: &[u64]) -> Option<crate::Result<CrtCiphertext>> {
+        self.blocks_of(index)
+            .map(|(blocks, kind)| crt_from_expanded_blocks(blocks, kind, moduli))
+    }
+
     pub(crate) fn message_modulus(&self) -> MessageModulus {
         self.expanded_blocks[0].message_modulus
     }
@@ -350,12 +481,17 @@ impl CompactCiphertextListExpander {
 
 #[derive(Clone, Serialize, Deserialize)]
 pub struct CompactCiphertextList {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) ct_list: crate::shortint::ciphertext::CompactCiphertextList,
     // Integers stored can have a heterogeneous number of blocks and signedness
     // We store this info to safeguard the expansion
     pub(crate) info: Vec<DataKind>,
 }
 
+impl crate::named::Named for CompactCiphertextList {
+    const NAME: &'static str = "integer::CompactCiphertextList";
+}
+
 impl ParameterSetConformant for CompactCiphertextList {
     type ParameterSet = CompactCiphertextListConformanceParams;
 
@@ -370,7 +506,7 @@ impl ParameterSetConformant for CompactCiphertextList {
 
 impl CompactCiphertextList {
     pub fn is_packed(&self) -> bool {
-        self.ct_list.degree.get() > self.ct_list.message_modulus.0
+        !contains_crt(&self.info) && self.ct_list.degree.get() > self.ct_list.message_modulus.0
     }
 
     pub fn builder(pk: &CompactPublicKey) -> CompactCiphertextListBuilder {
@@ -414,7 +550,7 @@ impl CompactCiphertextList {
     }
 
     pub fn expand(&self, sks: &ServerKey) -> crate::Result<CompactCiphertextListExpander> {
-        let expanded_blocks = self.ct_list.expand();
+        let mut expanded_blocks = self.ct_list.expand();
 
         let mut conformance_params = sks.key.conformance_params();
         conformance_params.degree = self.ct_list.degree;
@@ -424,7 +560,10 @@ impl CompactCiphertextList {
             ));
         }
 
-        let expanded_blocks = if self.ct_list.degree.get() > self.ct_list.message_modulus.0 - 1 {
+        let expanded_blocks = if contains_crt(&self.info) {
+            reset_crt_list_degrees(&mut expanded_blocks);
+            expanded_blocks
+        } else if self.ct_list.degree.get() > self.ct_list.message_modulus.0 - 1 {
             extract_message_and_carries(expanded_blocks, sks)
         } else {
             expanded_blocks
@@ -439,7 +578,10 @@ impl CompactCiphertextList {
     /// This simply expands the list without splitting messages if they were packed
     /// caller should check using [Self::is_packed] to know if data is packed
     pub(crate) fn expand_without_unpacking(&self) -> CompactCiphertextListExpander {
-        let expanded_blocks = self.ct_list.expand();
+        let mut expanded_blocks = self.ct_list.expand();
+        if contains_crt(&self.info) {
+            reset_crt_list_degrees(&mut expanded_blocks);
+        }
         CompactCiphertextListExpander::new(expanded_blocks, self.info.clone())
     }
 
@@ -453,12 +595,16 @@ impl CompactCiphertextList {
 
     fn is_conformant_with_shortint_params(
         &self,
-        shortint_params: CiphertextConformanceParams,
+        mut shortint_params: CiphertextConformanceParams,
     ) -> bool {
         let mut num_blocks: usize = self.info.iter().copied().map(DataKind::num_blocks).sum();
-        if shortint_params.degree.get()
-            == (shortint_params.message_modulus.0 * shortint_params.carry_modulus.0) - 1
-        {
+        let full_max_degree =
+            (shortint_params.message_modulus.0 * shortint_params.carry_modulus.0) - 1;
+        if contains_crt(&self.info) {
+            // Lists holding CRT integers are never packed, whatever the expected degree of
+            // radix blocks is
+            shortint_params.degree = Degree::new(full_max_degree);
+        } else if shortint_params.degree.get() == full_max_degree {
             num_blocks = num_blocks.div_ceil(2);
         }
         let shortint_list_params = shortint_params
@@ -470,12 +616,18 @@ impl CompactCiphertextList {
 #[cfg(feature = "zk-pok-experimental")]
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ProvenCompactCiphertextList {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) ct_list: crate::shortint::ciphertext::ProvenCompactCiphertextList,
     // Integers stored can have a heterogeneous number of blocks and signedness
     // We store this info to safeguard the expansion
     pub(crate) info: Vec<DataKind>,
 }
 
+#[cfg(feature = "zk-pok-experimental")]
+impl crate::named::Named for ProvenCompactCiphertextList {
+    const NAME: &'static str = "integer::ProvenCompactCiphertextList";
+}
+
 #[cfg(feature = "zk-pok-experimental")]
 impl ProvenCompactCiphertextList {
     pub fn builder(pk: &CompactPublicKey) -> CompactCiphertextListBuilder {
@@ -488,7 +640,7 @@ impl ProvenCompactCiphertextList {
         public_key: &CompactPublicKey,
         sks: &ServerKey,
     ) -> crate::Result<CompactCiphertextListExpander> {
-        let expanded_ct = self
+        let mut expanded_ct = self
             .ct_list
             .verify_and_expand(public_params, &public_key.key)?;
 
@@ -505,7 +657,10 @@ impl ProvenCompactCiphertextList {
             }
         }
 
-        let expanded_ct = if degree.get() > message_modulus - 1 {
+        let expanded_ct = if contains_crt(&self.info) {
+            reset_crt_list_degrees(&mut expanded_ct);
+            expanded_ct
+        } else if degree.get() > message_modulus - 1 {
             extract_message_and_carries(expanded_ct, sks)
         } else {
             expanded_ct
@@ -518,8 +673,9 @@ impl ProvenCompactCiphertextList {
     }
 
     pub fn is_packed(&self) -> bool {
-        self.ct_list.proved_lists[0].0.degree.get()
-            > self.ct_list.proved_lists[0].0.message_modulus.0
+        !contains_crt(&self.info)
+            && self.ct_list.proved_lists[0].0.degree.get()
+                > self.ct_list.proved_lists[0].0.message_modulus.0
     }
 
     /// This simply expands the list without splitting messages if they were packed
@@ -529,9 +685,12 @@ impl ProvenCompactCiphertextList {
         public_params: &CompactPkePublicParams,
         public_key: &CompactPublicKey,
     ) -> crate::Result<CompactCiphertextListExpander> {
-        let expanded_ct = self
+        let mut expanded_ct = self
             .ct_list
             .verify_and_expand(public_params, &public_key.key)?;
+        if contains_crt(&self.info) {
+            reset_crt_list_degrees(&mut expanded_ct);
+        }
 
         Ok(CompactCiphertextListExpander::new(
             expanded_ct,
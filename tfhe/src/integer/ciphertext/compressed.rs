@@ -7,6 +7,10 @@ use crate::shortint::CompressedCiphertext;
 /// Structure containing a **compressed** ciphertext in radix decomposition.
 pub type CompressedRadixCiphertext = BaseRadixCiphertext<CompressedCiphertext>;
 
+impl crate::named::Named for CompressedRadixCiphertext {
+    const NAME: &'static str = "integer::CompressedRadixCiphertext";
+}
+
 impl CompressedRadixCiphertext {
     pub fn decompress(&self) -> RadixCiphertext {
         RadixCiphertext::from(
@@ -22,6 +26,10 @@ impl CompressedRadixCiphertext {
 /// holding a signed valued
 pub type CompressedSignedRadixCiphertext = BaseSignedRadixCiphertext<CompressedCiphertext>;
 
+impl crate::named::Named for CompressedSignedRadixCiphertext {
+    const NAME: &'static str = "integer::CompressedSignedRadixCiphertext";
+}
+
 impl CompressedSignedRadixCiphertext {
     pub fn decompress(&self) -> SignedRadixCiphertext {
         SignedRadixCiphertext::from(
@@ -36,6 +44,10 @@ impl CompressedSignedRadixCiphertext {
 /// Structure containing a **compressed** ciphertext in CRT decomposition.
 pub type CompressedCrtCiphertext = BaseCrtCiphertext<CompressedCiphertext>;
 
+impl crate::named::Named for CompressedCrtCiphertext {
+    const NAME: &'static str = "integer::CompressedCrtCiphertext";
+}
+
 impl CompressedCrtCiphertext {
     pub fn decompress(&self) -> CrtCiphertext {
         let blocks = self
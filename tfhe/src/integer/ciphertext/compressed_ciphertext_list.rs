@@ -0,0 +1,285 @@
+use super::{DataKind, Expandable};
+use crate::integer::compression_keys::{CompressionKey, DecompressionKey};
+use crate::integer::{BooleanBlock, RadixCiphertext, SignedRadixCiphertext};
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::list_compression::CompressedCiphertextList as ShortintCompressedCiphertextList;
+use crate::shortint::{Ciphertext, MessageModulus};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub trait Compressible {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind;
+}
+
+impl Compressible for BooleanBlock {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        messages.push(self.0);
+        DataKind::Boolean
+    }
+}
+
+impl Compressible for RadixCiphertext {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        let n = self.blocks.len();
+        messages.extend(self.blocks);
+        DataKind::Unsigned(n)
+    }
+}
+
+impl Compressible for SignedRadixCiphertext {
+    fn compress_into(self, messages: &mut Vec<Ciphertext>) -> DataKind {
+        let n = self.blocks.len();
+        messages.extend(self.blocks);
+        DataKind::Signed(n)
+    }
+}
+
+/// Builder used to create a [`CompressedCiphertextList`] from radix ciphertexts and boolean
+/// blocks.
+pub struct CompressedCiphertextListBuilder {
+    pub(crate) ciphertexts: Vec<Ciphertext>,
+    pub(crate) info: Vec<DataKind>,
+}
+
+impl CompressedCiphertextListBuilder {
+    pub fn new() -> Self {
+        Self {
+            ciphertexts: vec![],
+            info: vec![],
+        }
+    }
+
+    pub fn push<T>(&mut self, data: T) -> &mut Self
+    where
+        T: Compressible,
+    {
+        let n = self.ciphertexts.len();
+        let kind = data.compress_into(&mut self.ciphertexts);
+        assert_eq!(n + kind.num_blocks(), self.ciphertexts.len());
+
+        if kind.num_blocks() != 0 {
+            self.info.push(kind);
+        }
+
+        self
+    }
+
+    pub fn extend<T>(&mut self, values: impl Iterator<Item = T>) -> &mut Self
+    where
+        T: Compressible,
+    {
+        for value in values {
+            self.push(value);
+        }
+        self
+    }
+
+    /// Compress the pushed ciphertexts into a [`CompressedCiphertextList`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if no ciphertext was pushed, or if one of the pushed ciphertexts has non empty
+    /// carries.
+    pub fn build(&self, comp_key: &CompressionKey) -> CompressedCiphertextList {
+        let packed_list = comp_key
+            .key
+            .compress_ciphertexts_into_list(&self.ciphertexts);
+
+        CompressedCiphertextList {
+            packed_list,
+            info: self.info.clone(),
+        }
+    }
+}
+
+impl Default for CompressedCiphertextListBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A list of radix ciphertexts and boolean blocks stored in a compressed form.
+///
+/// ```rust
+/// use tfhe::integer::ciphertext::CompressedCiphertextListBuilder;
+/// use tfhe::integer::{gen_keys_radix, BooleanBlock, RadixCiphertext, SignedRadixCiphertext};
+/// use tfhe::shortint::parameters::{
+///     COMP_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+/// };
+///
+/// let num_blocks = 4;
+/// let (cks, _sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+///
+/// let private_compression_key = cks
+///     .as_ref()
+///     .new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+///
+/// let (compression_key, decompression_key) = cks
+///     .as_ref()
+///     .new_compression_decompression_keys(&private_compression_key);
+///
+/// let ct1 = cks.encrypt(3_u32);
+/// let ct2 = cks.encrypt_signed(-2);
+/// let ct3 = cks.encrypt_bool(true);
+///
+/// let compressed = CompressedCiphertextListBuilder::new()
+///     .push(ct1)
+///     .push(ct2)
+///     .push(ct3)
+///     .build(&compression_key);
+///
+/// let a: RadixCiphertext = compressed.get(0, &decompression_key).unwrap().unwrap();
+/// let b: SignedRadixCiphertext = compressed.get(1, &decompression_key).unwrap().unwrap();
+/// let c: BooleanBlock = compressed.get(2, &decompression_key).unwrap().unwrap();
+///
+/// let a: u32 = cks.decrypt(&a);
+/// let b: i32 = cks.decrypt_signed(&b);
+/// let c = cks.decrypt_bool(&c);
+///
+/// assert_eq!(a, 3);
+/// assert_eq!(b, -2);
+/// assert!(c);
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CompressedCiphertextList {
+    #[serde(with = "crate::versionable::nested")]
+    pub(crate) packed_list: ShortintCompressedCiphertextList,
+    info: Vec<DataKind>,
+}
+
+impl crate::named::Named for CompressedCiphertextList {
+    const NAME: &'static str = "integer::CompressedCiphertextList";
+}
+
+impl CompressedCiphertextList {
+    /// Returns the number of elements stored in the list
+    pub fn len(&self) -> usize {
+        self.info.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the kind of the element stored at `index`, `None` if `index` is out of bounds
+    pub fn get_kind_of(&self, index: usize) -> Option<DataKind> {
+        self.info.get(index).copied()
+    }
+
+    pub fn message_modulus(&self) -> MessageModulus {
+        self.packed_list.message_modulus
+    }
+
+    fn blocks_of(
+        &self,
+        index: usize,
+        decompression_key: &DecompressionKey,
+    ) -> Option<(Vec<Ciphertext>, DataKind)> {
+        let preceding_infos = self.info.get(..index)?;
+        let current_info = self.info.get(index).copied()?;
+
+        let start_block_index: usize = preceding_infos
+            .iter()
+            .copied()
+            .map(DataKind::num_blocks)
+            .sum();
+
+        let end_block_index = start_block_index + current_info.num_blocks();
+
+        Some((
+            (start_block_index..end_block_index)
+                .into_par_iter()
+                .map(|i| decompression_key.key.unpack(&self.packed_list, i).unwrap())
+                .collect(),
+            current_info,
+        ))
+    }
+
+    /// Decompress the element stored at `index`
+    ///
+    /// Returns `None` if `index` is out of bounds and an error if the stored element is not
+    /// compatible with the requested type.
+    pub fn get<T>(
+        &self,
+        index: usize,
+        decompression_key: &DecompressionKey,
+    ) -> Option<crate::Result<T>>
+    where
+        T: Expandable,
+    {
+        self.blocks_of(index, decompression_key)
+            .map(|(mut blocks, kind)| {
+                if kind == DataKind::Boolean {
+                    blocks[0].degree = Degree::new(1);
+                }
+                T::from_expanded_blocks(&blocks, kind)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::ClientKey;
+    use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    use rand::random;
+
+    const NB_TESTS: usize = 10;
+
+    #[test]
+    fn test_heterogeneous_ciphertext_compression_ci_run_filter() {
+        let num_blocks = 32;
+
+        let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+        let private_compression_key = cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+
+        let (compression_key, decompression_key) =
+            cks.new_compression_decompression_keys(&private_compression_key);
+
+        for _ in 0..NB_TESTS {
+            let message = random::<u64>();
+            let ct = cks.encrypt_radix(message, num_blocks);
+
+            let signed_message = random::<i64>();
+            let ct_signed = cks.encrypt_signed_radix(signed_message, num_blocks);
+
+            let bool_message = random::<bool>();
+            let ct_bool = cks.encrypt_bool(bool_message);
+
+            let compressed = CompressedCiphertextListBuilder::new()
+                .push(ct)
+                .push(ct_signed)
+                .push(ct_bool)
+                .build(&compression_key);
+
+            assert_eq!(compressed.len(), 3);
+
+            let decompressed: RadixCiphertext =
+                compressed.get(0, &decompression_key).unwrap().unwrap();
+            assert_eq!(cks.decrypt_radix::<u64>(&decompressed), message);
+
+            let decompressed: SignedRadixCiphertext =
+                compressed.get(1, &decompression_key).unwrap().unwrap();
+            assert_eq!(
+                cks.decrypt_signed_radix::<i64>(&decompressed),
+                signed_message
+            );
+
+            let decompressed: BooleanBlock =
+                compressed.get(2, &decompression_key).unwrap().unwrap();
+            assert_eq!(cks.decrypt_bool(&decompressed), bool_message);
+
+            // Requesting the wrong type is an error
+            assert!(compressed
+                .get::<BooleanBlock>(0, &decompression_key)
+                .unwrap()
+                .is_err());
+
+            assert!(compressed
+                .get::<RadixCiphertext>(3, &decompression_key)
+                .is_none());
+        }
+    }
+}
@@ -34,6 +34,10 @@ pub struct CompressedModulusSwitchedRadixCiphertext(
     pub(crate) CompressedModulusSwitchedRadixCiphertextGeneric,
 );
 
+impl crate::named::Named for CompressedModulusSwitchedRadixCiphertext {
+    const NAME: &'static str = "integer::CompressedModulusSwitchedRadixCiphertext";
+}
+
 impl ParameterSetConformant for CompressedModulusSwitchedRadixCiphertext {
     type ParameterSet = RadixCiphertextConformanceParams;
 
@@ -73,6 +77,10 @@ pub struct CompressedModulusSwitchedSignedRadixCiphertext(
     pub(crate) CompressedModulusSwitchedRadixCiphertextGeneric,
 );
 
+impl crate::named::Named for CompressedModulusSwitchedSignedRadixCiphertext {
+    const NAME: &'static str = "integer::CompressedModulusSwitchedSignedRadixCiphertext";
+}
+
 impl ParameterSetConformant for CompressedModulusSwitchedSignedRadixCiphertext {
     type ParameterSet = RadixCiphertextConformanceParams;
 
@@ -83,7 +91,9 @@ impl ParameterSetConformant for CompressedModulusSwitchedSignedRadixCiphertext {
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub(crate) struct CompressedModulusSwitchedRadixCiphertextGeneric {
+    #[serde(with = "crate::versionable::nested_vec")]
     pub paired_blocks: Vec<CompressedModulusSwitchedCiphertext>,
+    #[serde(with = "crate::versionable::nested_option")]
     pub last_block: Option<CompressedModulusSwitchedCiphertext>,
 }
 
@@ -2,6 +2,7 @@ mod base;
 pub mod boolean_value;
 mod compact_list;
 mod compressed;
+mod compressed_ciphertext_list;
 mod compressed_modulus_switched_ciphertext;
 mod integer_ciphertext;
 
@@ -9,5 +10,6 @@ pub use base::*;
 pub use boolean_value::*;
 pub use compact_list::*;
 pub use compressed::*;
+pub use compressed_ciphertext_list::*;
 pub use compressed_modulus_switched_ciphertext::*;
 pub use integer_ciphertext::*;
@@ -27,10 +27,15 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct CrtClientKey {
+    #[serde(with = "crate::versionable::nested")]
     key: ClientKey,
     moduli: Vec<u64>,
 }
 
+impl crate::named::Named for CrtClientKey {
+    const NAME: &'static str = "integer::CrtClientKey";
+}
+
 impl AsRef<ClientKey> for CrtClientKey {
     fn as_ref(&self) -> &ClientKey {
         &self.key
@@ -84,9 +84,14 @@ where
 /// use the same crypto parameters.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct ClientKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) key: ShortintClientKey,
 }
 
+impl crate::named::Named for ClientKey {
+    const NAME: &'static str = "integer::ClientKey";
+}
+
 impl From<ShortintClientKey> for ClientKey {
     fn from(key: ShortintClientKey) -> Self {
         Self { key }
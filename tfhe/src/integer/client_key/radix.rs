@@ -32,10 +32,15 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct RadixClientKey {
+    #[serde(with = "crate::versionable::nested")]
     key: ClientKey,
     num_blocks: usize,
 }
 
+impl crate::named::Named for RadixClientKey {
+    const NAME: &'static str = "integer::RadixClientKey";
+}
+
 impl AsRef<ClientKey> for RadixClientKey {
     fn as_ref(&self) -> &ClientKey {
         &self.key
@@ -0,0 +1,161 @@
+use super::ClientKey;
+use crate::shortint::list_compression::{
+    CompressedCompressionKey as ShortintCompressedCompressionKey,
+    CompressedDecompressionKey as ShortintCompressedDecompressionKey,
+    CompressionKey as ShortintCompressionKey,
+    CompressionPrivateKeys as ShortintCompressionPrivateKeys,
+    DecompressionKey as ShortintDecompressionKey,
+};
+use crate::shortint::parameters::list_compression::CompressionParameters;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressionPrivateKeys {
+    #[serde(with = "crate::versionable::nested")]
+    pub(crate) key: ShortintCompressionPrivateKeys,
+}
+
+impl crate::named::Named for CompressionPrivateKeys {
+    const NAME: &'static str = "integer::CompressionPrivateKeys";
+}
+
+impl CompressionPrivateKeys {
+    pub fn into_raw_parts(self) -> ShortintCompressionPrivateKeys {
+        self.key
+    }
+
+    pub fn from_raw_parts(key: ShortintCompressionPrivateKeys) -> Self {
+        Self { key }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressionKey {
+    #[serde(with = "crate::versionable::nested")]
+    pub(crate) key: ShortintCompressionKey,
+}
+
+impl crate::named::Named for CompressionKey {
+    const NAME: &'static str = "integer::CompressionKey";
+}
+
+impl CompressionKey {
+    pub fn into_raw_parts(self) -> ShortintCompressionKey {
+        self.key
+    }
+
+    pub fn from_raw_parts(key: ShortintCompressionKey) -> Self {
+        Self { key }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecompressionKey {
+    #[serde(with = "crate::versionable::nested")]
+    pub(crate) key: ShortintDecompressionKey,
+}
+
+impl crate::named::Named for DecompressionKey {
+    const NAME: &'static str = "integer::DecompressionKey";
+}
+
+impl DecompressionKey {
+    pub fn into_raw_parts(self) -> ShortintDecompressionKey {
+        self.key
+    }
+
+    pub fn from_raw_parts(key: ShortintDecompressionKey) -> Self {
+        Self { key }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedCompressionKey {
+    #[serde(with = "crate::versionable::nested")]
+    pub(crate) key: ShortintCompressedCompressionKey,
+}
+
+impl crate::named::Named for CompressedCompressionKey {
+    const NAME: &'static str = "integer::CompressedCompressionKey";
+}
+
+impl CompressedCompressionKey {
+    pub fn decompress(&self) -> CompressionKey {
+        CompressionKey {
+            key: self.key.decompress(),
+        }
+    }
+
+    pub fn into_raw_parts(self) -> ShortintCompressedCompressionKey {
+        self.key
+    }
+
+    pub fn from_raw_parts(key: ShortintCompressedCompressionKey) -> Self {
+        Self { key }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedDecompressionKey {
+    #[serde(with = "crate::versionable::nested")]
+    pub(crate) key: ShortintCompressedDecompressionKey,
+}
+
+impl crate::named::Named for CompressedDecompressionKey {
+    const NAME: &'static str = "integer::CompressedDecompressionKey";
+}
+
+impl CompressedDecompressionKey {
+    pub fn decompress(&self) -> DecompressionKey {
+        DecompressionKey {
+            key: self.key.decompress(),
+        }
+    }
+
+    pub fn into_raw_parts(self) -> ShortintCompressedDecompressionKey {
+        self.key
+    }
+
+    pub fn from_raw_parts(key: ShortintCompressedDecompressionKey) -> Self {
+        Self { key }
+    }
+}
+
+impl ClientKey {
+    pub fn new_compression_private_key(
+        &self,
+        params: CompressionParameters,
+    ) -> CompressionPrivateKeys {
+        CompressionPrivateKeys {
+            key: self.key.new_compression_private_key(params),
+        }
+    }
+
+    pub fn new_compression_decompression_keys(
+        &self,
+        private_compression_key: &CompressionPrivateKeys,
+    ) -> (CompressionKey, DecompressionKey) {
+        let (comp_key, decomp_key) = self
+            .key
+            .new_compression_decompression_keys(&private_compression_key.key);
+
+        (
+            CompressionKey { key: comp_key },
+            DecompressionKey { key: decomp_key },
+        )
+    }
+
+    pub fn new_compressed_compression_decompression_keys(
+        &self,
+        private_compression_key: &CompressionPrivateKeys,
+    ) -> (CompressedCompressionKey, CompressedDecompressionKey) {
+        let (comp_key, decomp_key) = self
+            .key
+            .new_compressed_compression_decompression_keys(&private_compression_key.key);
+
+        (
+            CompressedCompressionKey { key: comp_key },
+            CompressedDecompressionKey { key: decomp_key },
+        )
+    }
+}
@@ -234,6 +234,7 @@ impl CudaUnsignedRadixCiphertext {
                 message_modulus: i.message_modulus,
                 carry_modulus: i.carry_modulus,
                 pbs_order: i.pbs_order,
+                noise_variance: None,
             })
             .collect();
 
@@ -387,6 +388,7 @@ impl CudaSignedRadixCiphertext {
                 message_modulus: i.message_modulus,
                 carry_modulus: i.carry_modulus,
                 pbs_order: i.pbs_order,
+                noise_variance: None,
             })
             .collect();
 
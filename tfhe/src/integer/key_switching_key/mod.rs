@@ -1,6 +1,8 @@
 use super::{ClientKey, ServerKey};
-use crate::integer::IntegerCiphertext;
+use crate::integer::ciphertext::{CompactCiphertextListExpander, DataKind};
+use crate::integer::{IntegerCiphertext, IntegerRadixCiphertext};
 use crate::shortint::parameters::ShortintKeySwitchingParameters;
+use crate::shortint::Ciphertext;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -9,9 +11,14 @@ mod test;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct KeySwitchingKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) key: crate::shortint::KeySwitchingKey,
 }
 
+impl crate::named::Named for KeySwitchingKey {
+    const NAME: &'static str = "integer::KeySwitchingKey";
+}
+
 impl KeySwitchingKey {
     pub fn new<ClientKeyType>(
         key_pair_1: (&ClientKeyType, &ServerKey),
@@ -34,6 +41,27 @@ impl KeySwitchingKey {
         ret
     }
 
+    /// Creates a key switching key between two radix key pairs
+    ///
+    /// Unlike [Self::new], the parameters of the key pairs may have different message and carry
+    /// moduli, such a key has to be used with [Self::cast_radix].
+    pub fn new_radix<ClientKeyType>(
+        key_pair_1: (&ClientKeyType, &ServerKey),
+        key_pair_2: (&ClientKeyType, &ServerKey),
+        params: ShortintKeySwitchingParameters,
+    ) -> Self
+    where
+        ClientKeyType: AsRef<ClientKey>,
+    {
+        Self {
+            key: crate::shortint::KeySwitchingKey::new(
+                (&key_pair_1.0.as_ref().key, &key_pair_1.1.key),
+                (&key_pair_2.0.as_ref().key, &key_pair_2.1.key),
+                params,
+            ),
+        }
+    }
+
     /// Deconstruct a [`KeySwitchingKey`] into its constituents.
     pub fn into_raw_parts(self) -> crate::shortint::KeySwitchingKey {
         self.key
@@ -69,4 +97,206 @@ impl KeySwitchingKey {
                 .collect::<Vec<_>>(),
         )
     }
+
+    /// Returns the number of blocks a radix integer of `num_blocks` blocks has once cast by
+    /// [Self::cast_radix]
+    pub fn num_blocks_after_cast(&self, num_blocks: usize) -> usize {
+        let input_bits = self.key.src_server_key.message_modulus.0.ilog2() as usize;
+        let output_bits = self.key.dest_server_key.message_modulus.0.ilog2() as usize;
+        (num_blocks * input_bits).div_ceil(output_bits)
+    }
+
+    /// Casts a radix ciphertext from the source parameter set to the destination parameter set
+    ///
+    /// If the message moduli of the two parameter sets are different, the blocks are re-encoded:
+    /// the output has as many blocks as needed to store the bits of the input (see
+    /// [Self::num_blocks_after_cast]), signed ciphertexts being sign extended.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::key_switching_key::KeySwitchingKey;
+    /// use tfhe::integer::{gen_keys_radix, RadixCiphertext};
+    /// use tfhe::shortint::parameters::{
+    ///     ShortintKeySwitchingParameters, PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    ///
+    /// let (cks1, sks1) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, 4);
+    /// let (cks2, sks2) = gen_keys_radix(PARAM_MESSAGE_1_CARRY_1_KS_PBS, 8);
+    ///
+    /// let params = ShortintKeySwitchingParameters::new(
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_base_log,
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS.ks_level,
+    /// );
+    /// let ksk = KeySwitchingKey::new_radix((&cks1, &sks1), (&cks2, &sks2), params);
+    ///
+    /// let ct1: RadixCiphertext = cks1.encrypt(228u64);
+    /// let ct2 = ksk.cast_radix(&ct1);
+    /// assert_eq!(ct2.blocks().len(), 8);
+    ///
+    /// let clear: u64 = cks2.decrypt(&ct2);
+    /// assert_eq!(clear, 228);
+    /// ```
+    pub fn cast_radix<T: IntegerRadixCiphertext>(&self, ct: &T) -> T {
+        if self.key.src_server_key.message_modulus == self.key.dest_server_key.message_modulus {
+            return self.cast(ct);
+        }
+
+        // The re-encoding works on the bits of the message, so the carries have to be empty
+        let blocks = if ct.block_carries_are_empty() {
+            self.cast_radix_blocks(ct.blocks(), T::IS_SIGNED)
+        } else {
+            // Carries are propagated as in ServerKey::full_propagate, directly with the shortint
+            // source key
+            let src_server_key = &self.key.src_server_key;
+            let mut blocks = ct.blocks().to_vec();
+            for index in 0..blocks.len() {
+                let carry = src_server_key.carry_extract(&blocks[index]);
+                src_server_key.message_extract_assign(&mut blocks[index]);
+                if let Some(next_block) = blocks.get_mut(index + 1) {
+                    src_server_key.unchecked_add_assign(next_block, &carry);
+                }
+            }
+            self.cast_radix_blocks(&blocks, T::IS_SIGNED)
+        };
+
+        T::from_blocks(blocks)
+    }
+
+    /// Re-encodes the blocks of a radix integer, whose carries are empty, into blocks of the
+    /// destination parameter set
+    pub(crate) fn cast_radix_blocks(
+        &self,
+        blocks: &[Ciphertext],
+        is_signed: bool,
+    ) -> Vec<Ciphertext> {
+        let src_server_key = &self.key.src_server_key;
+        let dest_server_key = &self.key.dest_server_key;
+
+        if blocks.is_empty() {
+            return vec![];
+        }
+
+        if src_server_key.message_modulus == dest_server_key.message_modulus {
+            return blocks
+                .par_iter()
+                .map(|block| self.key.cast(block))
+                .collect();
+        }
+
+        let input_bits = src_server_key.message_modulus.0.ilog2() as usize;
+        let output_bits = dest_server_key.message_modulus.0.ilog2() as usize;
+        let total_bits = blocks.len() * input_bits;
+        let num_output_blocks = self.num_blocks_after_cast(blocks.len());
+
+        let mut output_blocks = (0..num_output_blocks)
+            .into_par_iter()
+            .map(|output_index| {
+                let output_start = output_index * output_bits;
+                let output_end = (output_start + output_bits).min(total_bits);
+
+                // Each output block is made of the bits of the input blocks it overlaps
+                let pieces = (output_start / input_bits..output_end.div_ceil(input_bits))
+                    .into_par_iter()
+                    .map(|input_index| {
+                        let input_start = input_index * input_bits;
+                        let start = output_start.max(input_start);
+                        let end = output_end.min(input_start + input_bits);
+
+                        let right_shift = start - input_start;
+                        let mask = (1u64 << (end - start)) - 1;
+                        let block = &blocks[input_index];
+                        let piece = if right_shift == 0 && end - start == input_bits {
+                            self.key.cast(block)
+                        } else {
+                            let lut =
+                                src_server_key.generate_lookup_table(|x| (x >> right_shift) & mask);
+                            self.key
+                                .cast(&src_server_key.apply_lookup_table(block, &lut))
+                        };
+
+                        let left_shift = start - output_start;
+                        if left_shift == 0 {
+                            piece
+                        } else {
+                            dest_server_key.unchecked_scalar_mul(&piece, 1u8 << left_shift)
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut pieces = pieces.into_iter();
+                let mut output_block = pieces.next().unwrap();
+                let mut is_clean = true;
+                for piece in pieces {
+                    dest_server_key.unchecked_add_assign(&mut output_block, &piece);
+                    is_clean = false;
+                }
+                if !is_clean {
+                    dest_server_key.message_extract_assign(&mut output_block);
+                }
+                output_block
+            })
+            .collect::<Vec<_>>();
+
+        // The last output block may have more bits than what remains of the input
+        let last_block_bits = total_bits - (num_output_blocks - 1) * output_bits;
+        if is_signed && last_block_bits < output_bits {
+            let sign_bit_position = last_block_bits - 1;
+            let message_mask = dest_server_key.message_modulus.0 as u64 - 1;
+            let extension = message_mask & !((1u64 << last_block_bits) - 1);
+            let lut = dest_server_key.generate_lookup_table(|x| {
+                if (x >> sign_bit_position) & 1 == 1 {
+                    x | extension
+                } else {
+                    x
+                }
+            });
+            let last_block = output_blocks.last_mut().unwrap();
+            dest_server_key.apply_lookup_table_assign(last_block, &lut);
+        }
+
+        output_blocks
+    }
+
+    /// Casts the ciphertexts of an expanded compact list, the integers are cast as in
+    /// [Self::cast_radix]
+    pub fn cast_expander(
+        &self,
+        expander: &CompactCiphertextListExpander,
+    ) -> CompactCiphertextListExpander {
+        let mut expanded_blocks = vec![];
+        let mut info = Vec::with_capacity(expander.len());
+
+        for index in 0..expander.len() {
+            let (blocks, kind) = expander.blocks_of(index).unwrap();
+            let (blocks, kind) = match kind {
+                DataKind::Unsigned(_) => {
+                    let blocks = self.cast_radix_blocks(blocks, false);
+                    let num_blocks = blocks.len();
+                    (blocks, DataKind::Unsigned(num_blocks))
+                }
+                DataKind::Signed(_) => {
+                    let blocks = self.cast_radix_blocks(blocks, true);
+                    let num_blocks = blocks.len();
+                    (blocks, DataKind::Signed(num_blocks))
+                }
+                DataKind::Boolean => (vec![self.key.cast(&blocks[0])], DataKind::Boolean),
+                // Residues are smaller than their modulus, so their values are kept as long as
+                // the moduli fit in the destination parameters
+                DataKind::Crt(n) => (
+                    blocks
+                        .par_iter()
+                        .map(|block| self.key.cast(block))
+                        .collect(),
+                    DataKind::Crt(n),
+                ),
+            };
+
+            expanded_blocks.extend(blocks);
+            info.push(kind);
+        }
+
+        CompactCiphertextListExpander::new(expanded_blocks, info)
+    }
 }
@@ -1,6 +1,6 @@
 use crate::integer::key_switching_key::KeySwitchingKey;
 use crate::integer::keycache::KEY_CACHE;
-use crate::integer::{CrtClientKey, IntegerKeyKind, RadixClientKey};
+use crate::integer::{CrtClientKey, IntegerKeyKind, RadixCiphertext, RadixClientKey};
 use crate::shortint::parameters::ShortintKeySwitchingParameters;
 use crate::shortint::prelude::{PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS};
 
@@ -128,3 +128,34 @@ fn gen_multi_keys_test_integer_to_integer_ci_run_filter() {
     let clear: u8 = client_key_2.decrypt_radix(&ct2);
     assert_eq!(clear, 228);
 }
+
+#[test]
+fn gen_multi_keys_test_cast_radix_ci_run_filter() {
+    let (client_key_1, server_key_1) =
+        KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+    let (client_key_2, server_key_2) =
+        KEY_CACHE.get_from_params(PARAM_MESSAGE_1_CARRY_1_KS_PBS, IntegerKeyKind::Radix);
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        client_key_2.parameters().ks_base_log(),
+        client_key_2.parameters().ks_level(),
+    );
+    let ksk = KeySwitchingKey::new_radix(
+        (&client_key_1, &server_key_1),
+        (&client_key_2, &server_key_2),
+        ksk_params,
+    );
+
+    // Input with non empty carries
+    let ct1 = client_key_1.encrypt_radix(200u8, 4);
+    let ct1 = server_key_1.unchecked_scalar_add(&ct1, 28u8);
+    let ct2 = ksk.cast_radix(&ct1);
+    assert_eq!(ct2.blocks.len(), ksk.num_blocks_after_cast(4));
+
+    let clear: u8 = client_key_2.decrypt_radix(&ct2);
+    assert_eq!(clear, 228);
+
+    // Empty input
+    let empty = RadixCiphertext::from(vec![]);
+    assert!(ksk.cast_radix(&empty).blocks.is_empty());
+}
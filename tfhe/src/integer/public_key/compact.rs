@@ -1,18 +1,24 @@
 use crate::integer::block_decomposition::DecomposableInto;
-use crate::integer::ciphertext::CompactCiphertextList;
+use crate::integer::ciphertext::{CompactCiphertextList, IntegerCiphertext};
 use crate::integer::ClientKey;
 use crate::shortint::{
     CompactPublicKey as ShortintCompactPublicKey,
     CompressedCompactPublicKey as ShortintCompressedCompactPublicKey,
 };
 use crate::Error;
+use concrete_csprng::seeders::Seed;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CompactPublicKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) key: ShortintCompactPublicKey,
 }
 
+impl crate::named::Named for CompactPublicKey {
+    const NAME: &'static str = "integer::CompactPublicKey";
+}
+
 impl CompactPublicKey {
     pub fn new(client_key: &ClientKey) -> Self {
         let key = ShortintCompactPublicKey::new(&client_key.key);
@@ -66,6 +72,38 @@ impl CompactPublicKey {
         builder.build()
     }
 
+    /// Encrypts a CRT integer in a compact list
+    ///
+    /// The integer is retrieved with
+    /// [CompactCiphertextListExpander::get_crt](crate::integer::ciphertext::CompactCiphertextListExpander::get_crt),
+    /// using the same moduli.
+    pub fn encrypt_crt_compact(&self, message: u64, moduli: &[u64]) -> CompactCiphertextList {
+        CompactCiphertextList::builder(self)
+            .push_crt(message, moduli)
+            .build()
+    }
+
+    /// Adds a fresh encryption of zero to each block of the ciphertext
+    ///
+    /// See [ShortintCompactPublicKey::re_randomize_ciphertexts]
+    pub fn re_randomize_ciphertext<T: IntegerCiphertext>(
+        &self,
+        ct: &mut T,
+        seed: Seed,
+    ) -> crate::Result<()> {
+        self.key.re_randomize_ciphertexts(ct.blocks_mut(), seed)
+    }
+
+    /// See [ShortintCompactPublicKey::re_randomize_compact_ciphertext_list]
+    pub fn re_randomize_compact_ciphertext_list(
+        &self,
+        list: &mut CompactCiphertextList,
+        seed: Seed,
+    ) -> crate::Result<()> {
+        self.key
+            .re_randomize_compact_ciphertext_list(&mut list.ct_list, seed)
+    }
+
     pub fn size_elements(&self) -> usize {
         self.key.size_elements()
     }
@@ -77,9 +115,14 @@ impl CompactPublicKey {
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CompressedCompactPublicKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) key: ShortintCompressedCompactPublicKey,
 }
 
+impl crate::named::Named for CompressedCompactPublicKey {
+    const NAME: &'static str = "integer::CompressedCompactPublicKey";
+}
+
 impl CompressedCompactPublicKey {
     pub fn new(client_key: &ClientKey) -> Self {
         let key = ShortintCompressedCompactPublicKey::new(&client_key.key);
@@ -8,9 +8,14 @@ use crate::shortint::parameters::MessageModulus;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CompressedPublicKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) key: crate::shortint::CompressedPublicKey,
 }
 
+impl crate::named::Named for CompressedPublicKey {
+    const NAME: &'static str = "integer::CompressedPublicKey";
+}
+
 impl CompressedPublicKey {
     pub fn new<C>(client_key: &C) -> Self
     where
@@ -11,9 +11,14 @@ use crate::shortint::PublicKey as ShortintPublicKey;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct PublicKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) key: ShortintPublicKey,
 }
 
+impl crate::named::Named for PublicKey {
+    const NAME: &'static str = "integer::PublicKey";
+}
+
 impl PublicKey {
     pub fn new<C>(client_key: &C) -> Self
     where
@@ -45,6 +45,10 @@ fn reduce_two_orderings_function(x: u64) -> u64 {
 ///
 /// This struct keeps in memory the LUTs that are used
 /// during the comparisons and min/max algorithms
+///
+/// Each block goes through a single lookup table (the functions applied to a block are fused
+/// into one, possibly bivariate, lookup table), so the comparisons do not use the many lookup
+/// table evaluation.
 pub struct Comparator<'a> {
     pub(crate) server_key: &'a ServerKey,
     // lut to get the sign of (a - b), used as the backbone of comparisons
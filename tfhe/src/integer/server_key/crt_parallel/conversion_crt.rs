@@ -0,0 +1,224 @@
+use crate::integer::ciphertext::{max_crt_modulus, CrtCiphertext, RadixCiphertext};
+use crate::integer::{IntegerCiphertext, ServerKey};
+use crate::shortint::{CarryModulus, Ciphertext, MessageModulus};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Sets the moduli of a block so that it is a CRT block with the given modulus,
+    /// the same way a block encrypted with `encrypt_crt` would have them
+    fn set_crt_block_moduli(&self, block: &mut Ciphertext, modulus: u64) {
+        let total_modulus = self.key.message_modulus.0 * self.key.carry_modulus.0;
+        block.message_modulus = MessageModulus(modulus as usize);
+        block.carry_modulus = CarryModulus(total_modulus / modulus as usize);
+    }
+
+    /// Sets the moduli of a block back to the ones of the radix blocks
+    fn set_radix_block_moduli(&self, block: &mut Ciphertext) {
+        block.message_modulus = self.key.message_modulus;
+        block.carry_modulus = self.key.carry_modulus;
+    }
+
+    /// Converts a radix ciphertext to a CRT ciphertext with the given moduli
+    ///
+    /// The value of the radix ciphertext is reduced modulo the product of the moduli.
+    ///
+    /// If the blocks of the radix ciphertext have carries, they are propagated first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a modulus is smaller than 2 or too large for the sum of two residues to fit in a
+    /// block (at most 7 with 2 bits of message and 2 bits of carry).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 213u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let basis = [3, 4, 5, 7];
+    /// let ct_crt = sks.radix_to_crt_parallelized(&ct, &basis);
+    ///
+    /// let dec = cks.as_ref().decrypt_crt(&ct_crt);
+    /// assert_eq!(dec, msg % 420);
+    /// ```
+    pub fn radix_to_crt_parallelized(&self, ct: &RadixCiphertext, moduli: &[u64]) -> CrtCiphertext {
+        let mut propagated_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            propagated_ct = ct.clone();
+            self.full_propagate_parallelized(&mut propagated_ct);
+            &propagated_ct
+        };
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let max_modulus = max_crt_modulus(self.key.message_modulus, self.key.carry_modulus);
+
+        let blocks = moduli
+            .par_iter()
+            .map(|&modulus| {
+                assert!(
+                    (2..=max_modulus).contains(&modulus),
+                    "CRT moduli must be in [2, {max_modulus}], got {modulus}"
+                );
+
+                // The block of index i weights message_modulus^i, so its residue is
+                // its value multiplied by message_modulus^i mod modulus
+                let mut weight = 1;
+                let luts = ct
+                    .blocks()
+                    .iter()
+                    .map(|_| {
+                        let block_weight = weight;
+                        weight = (weight * message_modulus) % modulus;
+                        self.key
+                            .generate_lookup_table(|x| (x * block_weight) % modulus)
+                    })
+                    .collect::<Vec<_>>();
+
+                let terms = ct
+                    .blocks()
+                    .par_iter()
+                    .zip(luts.par_iter())
+                    .map(|(block, lut)| {
+                        let mut term = self.key.apply_lookup_table(block, lut);
+                        self.set_crt_block_moduli(&mut term, modulus);
+                        term
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut terms = terms.into_iter();
+                let mut residue = terms.next().unwrap_or_else(|| {
+                    let mut zero = self.key.create_trivial(0);
+                    self.set_crt_block_moduli(&mut zero, modulus);
+                    zero
+                });
+                for term in terms {
+                    if self
+                        .key
+                        .is_add_possible(residue.noise_degree(), term.noise_degree())
+                        .is_err()
+                    {
+                        self.key.message_extract_assign(&mut residue);
+                    }
+                    self.key.unchecked_add_assign(&mut residue, &term);
+                }
+                if residue.degree.get() >= modulus as usize {
+                    self.key.message_extract_assign(&mut residue);
+                }
+                residue
+            })
+            .collect::<Vec<_>>();
+
+        CrtCiphertext::from((blocks, moduli.to_vec()))
+    }
+
+    /// Converts a CRT ciphertext to a radix ciphertext with the given number of blocks
+    ///
+    /// The result is the value of the CRT ciphertext, in the range [0, product of the moduli),
+    /// reduced modulo the modulus of the radix ciphertext.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the product of the moduli does not fit in a u64.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 6;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 613u64;
+    /// let basis = vec![3, 5, 7, 8];
+    /// let ct_crt = cks.as_ref().encrypt_crt(msg, basis);
+    ///
+    /// let ct = sks.crt_to_radix_parallelized(&ct_crt, num_blocks);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct);
+    /// assert_eq!(dec, msg);
+    /// ```
+    pub fn crt_to_radix_parallelized(
+        &self,
+        ct: &CrtCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let message_modulus = self.key.message_modulus.0 as u128;
+        let bits_per_block = self.key.message_modulus.0.ilog2();
+
+        let modulus = ct
+            .moduli
+            .iter()
+            .try_fold(1u64, |product, modulus| product.checked_mul(*modulus))
+            .expect("The product of the CRT moduli must fit in a u64");
+
+        // The sum of the terms of the reconstruction is less than num_moduli * modulus
+        let max_sum = (modulus as u128 - 1) * ct.moduli.len() as u128;
+        let num_sum_blocks = (u128::BITS - max_sum.leading_zeros())
+            .div_ceil(bits_per_block)
+            .max(1) as usize;
+
+        // x = sum(r_i * c_i) mod modulus, where c_i = m_i * (m_i^-1 mod b_i) and m_i = modulus /
+        // b_i, so each residue is mapped to the radix decomposition of r_i * c_i mod modulus
+        let terms = ct
+            .blocks
+            .par_iter()
+            .zip(ct.moduli.par_iter())
+            .map(|(residue, &residue_modulus)| {
+                let cofactor = modulus / residue_modulus;
+                let inverse = (1..residue_modulus)
+                    .find(|candidate| {
+                        (cofactor % residue_modulus) * candidate % residue_modulus == 1
+                    })
+                    .expect("CRT moduli must be pairwise coprime");
+                let coefficient = cofactor as u128 * inverse as u128;
+
+                let functions = (0..num_sum_blocks)
+                    .map(|block_index| {
+                        move |x: u64| {
+                            let term =
+                                (x % residue_modulus) as u128 * coefficient % modulus as u128;
+                            ((term / message_modulus.pow(block_index as u32)) % message_modulus)
+                                as u64
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let functions = functions
+                    .iter()
+                    .map(|function| function as &dyn Fn(u64) -> u64)
+                    .collect::<Vec<_>>();
+
+                let blocks = self
+                    .apply_many_functions_to_blocks(std::slice::from_ref(residue), &functions)
+                    .pop()
+                    .unwrap()
+                    .into_iter()
+                    .map(|mut block| {
+                        self.set_radix_block_moduli(&mut block);
+                        block
+                    })
+                    .collect();
+                RadixCiphertext::from_blocks(blocks)
+            })
+            .collect::<Vec<_>>();
+
+        let sum = self
+            .sum_ciphertexts_parallelized(&terms)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_sum_blocks));
+        let value = if num_sum_blocks as u32 * bits_per_block <= u64::BITS {
+            self.scalar_rem_parallelized(&sum, modulus)
+        } else {
+            self.scalar_rem_parallelized(&sum, modulus as u128)
+        };
+
+        self.cast_to_unsigned(value, num_blocks)
+    }
+}
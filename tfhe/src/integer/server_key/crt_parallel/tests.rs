@@ -206,3 +206,63 @@ fn integer_unchecked_crt_scalar_sub_parallelized_32_bits() {
         );
     }
 }
+
+#[test]
+fn integer_radix_to_crt_parallelized() {
+    let basis = [3u64, 4, 5, 7];
+    let crt_modulus = basis.iter().product::<u64>();
+
+    let num_blocks = 6;
+    let radix_modulus = 1u64 << (2 * num_blocks);
+    let (cks, sks) =
+        KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TESTS {
+        let clear = rng.gen::<u64>() % radix_modulus;
+        let mut ct = cks.encrypt_radix(clear, num_blocks);
+
+        // The blocks of the input may have carries, which can overflow the radix modulus
+        let add = rng.gen::<u64>() % radix_modulus;
+        sks.unchecked_scalar_add_assign(&mut ct, add);
+        let clear = (clear + add) % radix_modulus;
+
+        let ct_res = sks.radix_to_crt_parallelized(&ct, &basis);
+        assert_eq!(ct_res.moduli, basis);
+
+        let dec_res = cks.decrypt_crt(&ct_res);
+        assert_eq!(clear % crt_modulus, dec_res);
+    }
+}
+
+#[test]
+fn integer_crt_to_radix_parallelized() {
+    let basis = [3u64, 4, 5, 7];
+    let crt_modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) =
+        KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<u64>() % crt_modulus;
+        let clear_1 = rng.gen::<u64>() % crt_modulus;
+
+        // The residues of the input may have carries
+        let mut ct = cks.encrypt_crt(clear_0, basis.to_vec());
+        sks.unchecked_crt_scalar_add_assign_parallelized(&mut ct, clear_1);
+        let clear = (clear_0 + clear_1) % crt_modulus;
+
+        // The result is truncated or extended to the requested number of blocks
+        for num_blocks in [3, 5, 8] {
+            let radix_modulus = 1u64 << (2 * num_blocks);
+
+            let ct_res = sks.crt_to_radix_parallelized(&ct, num_blocks);
+            assert_eq!(ct_res.blocks.len(), num_blocks);
+            assert!(ct_res.block_carries_are_empty());
+
+            let dec_res: u64 = cks.decrypt_radix(&ct_res);
+            assert_eq!(clear % radix_modulus, dec_res);
+        }
+    }
+}
@@ -8,8 +8,10 @@ mod crt_parallel;
 pub(crate) mod radix;
 pub(crate) mod radix_parallel;
 
+use crate::integer::ciphertext::IntegerCiphertext;
 use crate::integer::client_key::ClientKey;
 use crate::shortint::ciphertext::MaxDegree;
+use crate::shortint::parameters::PBSParameters;
 use serde::{Deserialize, Serialize};
 
 /// Error returned when the carry buffer is full.
@@ -25,9 +27,14 @@ pub use radix_parallel::{MatchValues, MiniUnsignedInteger, Reciprocable};
 /// sends it to the server so it can compute homomorphic integer circuits.
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ServerKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) key: crate::shortint::ServerKey,
 }
 
+impl crate::named::Named for ServerKey {
+    const NAME: &'static str = "integer::ServerKey";
+}
+
 impl From<ServerKey> for crate::shortint::ServerKey {
     fn from(key: ServerKey) -> Self {
         key.key
@@ -200,6 +207,19 @@ impl ServerKey {
             .set_deterministic_pbs_execution(new_deterministic_execution);
     }
 
+    /// See [`crate::shortint::ServerKey::enable_32_bits_keyswitch`]
+    pub fn enable_32_bits_keyswitch(&mut self) {
+        self.key.enable_32_bits_keyswitch();
+    }
+
+    pub fn disable_32_bits_keyswitch(&mut self) {
+        self.key.disable_32_bits_keyswitch();
+    }
+
+    pub fn is_32_bits_keyswitch_enabled(&self) -> bool {
+        self.key.is_32_bits_keyswitch_enabled()
+    }
+
     pub fn message_modulus(&self) -> MessageModulus {
         self.key.message_modulus
     }
@@ -207,6 +227,66 @@ impl ServerKey {
     pub fn carry_modulus(&self) -> CarryModulus {
         self.key.carry_modulus
     }
+
+    /// See [`crate::shortint::ServerKey::enable_noise_tracking`]
+    pub fn enable_noise_tracking<P: Into<PBSParameters>>(&mut self, parameters: P) {
+        self.key.enable_noise_tracking(parameters);
+    }
+
+    pub fn disable_noise_tracking(&mut self) {
+        self.key.disable_noise_tracking();
+    }
+
+    /// Returns the estimated probability that applying a lookup table on each block of the
+    /// ciphertext gives at least one wrong result.
+    ///
+    /// The probability is bounded by the sum of the failure probabilities of each block.
+    ///
+    /// Returns `None` if noise tracking is not enabled or if the noise of one of the blocks is not
+    /// known.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, mut sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    /// sks.enable_noise_tracking(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct1 = cks.encrypt(13u64);
+    /// let ct2 = cks.encrypt(42u64);
+    ///
+    /// let ct = sks.unchecked_add(&ct1, &ct2);
+    /// let p_fail = sks.apply_lookup_table_failure_probability(&ct).unwrap();
+    /// assert!(p_fail < 2f64.powi(-40));
+    /// ```
+    pub fn apply_lookup_table_failure_probability<T>(&self, ct: &T) -> Option<f64>
+    where
+        T: IntegerCiphertext,
+    {
+        ct.blocks()
+            .iter()
+            .map(|block| self.key.apply_lookup_table_failure_probability(block))
+            .sum()
+    }
+
+    /// Returns the estimated probability that decrypting the ciphertext gives a wrong result.
+    ///
+    /// The probability is bounded by the sum of the failure probabilities of each block.
+    ///
+    /// Returns `None` if noise tracking is not enabled or if the noise of one of the blocks is not
+    /// known.
+    pub fn decryption_failure_probability<T>(&self, ct: &T) -> Option<f64>
+    where
+        T: IntegerCiphertext,
+    {
+        ct.blocks()
+            .iter()
+            .map(|block| self.key.decryption_failure_probability(block))
+            .sum()
+    }
 }
 
 impl AsRef<crate::shortint::ServerKey> for ServerKey {
@@ -217,9 +297,14 @@ impl AsRef<crate::shortint::ServerKey> for ServerKey {
 
 #[derive(Clone, Serialize, Deserialize)]
 pub struct CompressedServerKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) key: crate::shortint::CompressedServerKey,
 }
 
+impl crate::named::Named for CompressedServerKey {
+    const NAME: &'static str = "integer::CompressedServerKey";
+}
+
 impl CompressedServerKey {
     pub fn new_radix_compressed_server_key(client_key: &ClientKey) -> Self {
         let max_degree = MaxDegree::integer_radix_server_key(
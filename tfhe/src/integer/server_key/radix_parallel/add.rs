@@ -857,10 +857,12 @@ impl ServerKey {
                             }
 
                             if column_index < num_columns - 1 {
-                                rayon::join(
-                                    || self.key.message_extract(&result),
-                                    || Some(self.key.carry_extract(&result)),
-                                )
+                                let (message, carry) = self.apply_two_functions_to_block(
+                                    &result,
+                                    &|x| x % message_modulus as u64,
+                                    &|x| x / message_modulus as u64,
+                                );
+                                (message, Some(carry))
                             } else {
                                 (self.key.message_extract(&result), None)
                             }
@@ -1,11 +1,14 @@
 use crate::integer::ServerKey;
-use crate::shortint::server_key::LookupTableOwned;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::server_key::{LookupTableOwned, ManyLookupTableOwned};
 use crate::shortint::Ciphertext;
 use itertools::iproduct;
 use rayon::prelude::*;
 
 pub(crate) struct BitExtractor<'a> {
     bit_extract_luts: Vec<LookupTableOwned>,
+    // Extracts all the bits of a block with a single PBS, when the parameters allow it
+    bit_extract_many_lut: Option<ManyLookupTableOwned>,
     bits_per_block: usize,
     server_key: &'a ServerKey,
 }
@@ -37,8 +40,23 @@ impl<'a> BitExtractor<'a> {
             })
             .collect::<Vec<_>>();
 
+        let clean_block_degree = Degree::new(server_key.key.message_modulus.0 - 1);
+        let bit_extract_many_lut = (bits_per_block > 1
+            && server_key.max_many_lut_function_count(clean_block_degree) >= bits_per_block)
+            .then(|| {
+                let bit_extract_fns = (0..bits_per_block)
+                    .map(|i| move |x: u64| ((x >> i) & 1) << final_offset)
+                    .collect::<Vec<_>>();
+                let fns = bit_extract_fns
+                    .iter()
+                    .map(|func| func as &dyn Fn(u64) -> u64)
+                    .collect::<Vec<_>>();
+                server_key.key.generate_many_lookup_table(&fns)
+            });
+
         Self {
             bit_extract_luts,
+            bit_extract_many_lut,
             bits_per_block,
             server_key,
         }
@@ -50,6 +68,23 @@ impl<'a> BitExtractor<'a> {
     }
 
     pub(crate) fn extract_n_bits(&self, blocks: &[Ciphertext], n: usize) -> Vec<Ciphertext> {
+        if let Some(many_lut) = &self.bit_extract_many_lut {
+            let blocks = &blocks[..n.div_ceil(self.bits_per_block).min(blocks.len())];
+            if blocks
+                .iter()
+                .all(|block| block.degree.get() <= many_lut.input_max_degree.get())
+            {
+                let mut bits = blocks
+                    .par_iter()
+                    .flat_map_iter(|block| {
+                        self.server_key.key.apply_many_lookup_table(block, many_lut)
+                    })
+                    .collect::<Vec<_>>();
+                bits.truncate(n);
+                return bits;
+            }
+        }
+
         let num_blocks = blocks.len();
         let mut bits = Vec::with_capacity(n);
         let jobs = iproduct!(0..num_blocks, 0..self.bits_per_block)
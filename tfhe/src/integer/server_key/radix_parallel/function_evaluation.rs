@@ -0,0 +1,333 @@
+use crate::integer::{IntegerCiphertext, RadixCiphertext, ServerKey};
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+
+/// Maximum number of input bits a function can be evaluated on with lookup tables trees
+///
+/// The number of PBS (and lookup tables) needed grows exponentially with the number of bits
+const MAX_LUT_TREE_INPUT_BITS: u32 = 16;
+
+impl ServerKey {
+    /// Evaluates a function on the value encrypted by the blocks
+    ///
+    /// The blocks must not have carries, the value they encrypt is the radix decomposition
+    /// of the input of the function (least significant block first).
+    ///
+    /// The lowest blocks are packed together and each block of the output is computed, for each
+    /// possible value of the remaining blocks, with a lookup table. Then, the remaining blocks
+    /// are used one after the other to select the right candidates, like in a tree of
+    /// multiplexers.
+    fn evaluate_function_with_lut_tree(
+        &self,
+        blocks: &[Ciphertext],
+        num_output_blocks: usize,
+        f: &dyn Fn(u64) -> u64,
+    ) -> Vec<Ciphertext> {
+        let message_modulus = self.key.message_modulus.0 as u64;
+
+        let output_block = |value: u64, block_index: usize| -> u64 {
+            message_modulus
+                .checked_pow(block_index as u32)
+                .map_or(0, |shift| (value / shift) % message_modulus)
+        };
+
+        if blocks.is_empty() {
+            let value = f(0);
+            return (0..num_output_blocks)
+                .map(|i| self.key.create_trivial(output_block(value, i)))
+                .collect();
+        }
+
+        let num_input_bits = blocks.len() as u32 * message_modulus.ilog2();
+        assert!(
+            num_input_bits <= MAX_LUT_TREE_INPUT_BITS,
+            "Cannot evaluate a function on {num_input_bits} bits, \
+            at most {MAX_LUT_TREE_INPUT_BITS} input bits are supported"
+        );
+
+        let low_block;
+        let num_low_blocks = if blocks.len() == 1 {
+            low_block = blocks[0].clone();
+            1
+        } else {
+            // The candidates are selected with bivariate lookup tables where they are
+            // the left operand, so they need as much carry space as message space
+            assert!(
+                self.key.carry_modulus.0 >= self.key.message_modulus.0,
+                "Function evaluation on more than one block requires at least as much carry \
+                space as message space"
+            );
+            // Pack the two lowest blocks in one, the same way bivariate lookup tables do
+            let mut packed = self
+                .key
+                .unchecked_scalar_mul(&blocks[1], message_modulus as u8);
+            self.key.unchecked_add_assign(&mut packed, &blocks[0]);
+            low_block = packed;
+            2
+        };
+        let low_modulus = message_modulus.pow(num_low_blocks as u32);
+        let num_candidates = message_modulus.pow((blocks.len() - num_low_blocks) as u32);
+
+        let selection_luts = (0..message_modulus)
+            .map(|selected| {
+                self.key
+                    .generate_lookup_table_bivariate(move |x, y| if y == selected { x } else { 0 })
+            })
+            .collect::<Vec<_>>();
+
+        // Output blocks are computed one after the other to bound the number of lookup tables
+        // that are alive at the same time
+        (0..num_output_blocks)
+            .map(|block_index| {
+                let functions = (0..num_candidates)
+                    .map(|high_value| {
+                        move |low_value: u64| {
+                            output_block(f(high_value * low_modulus + low_value), block_index)
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let functions = functions
+                    .iter()
+                    .map(|function| function as &dyn Fn(u64) -> u64)
+                    .collect::<Vec<_>>();
+
+                let mut candidates = self
+                    .apply_many_functions_to_blocks(std::slice::from_ref(&low_block), &functions)
+                    .pop()
+                    .unwrap();
+
+                for selector in &blocks[num_low_blocks..] {
+                    candidates = candidates
+                        .par_chunks_exact(message_modulus as usize)
+                        .map(|group| {
+                            // Only the candidate at the index encrypted by the selector is kept,
+                            // all the others become 0
+                            let mut selected = group
+                                .par_iter()
+                                .zip(selection_luts.par_iter())
+                                .map(|(candidate, lut)| {
+                                    self.key.unchecked_apply_lookup_table_bivariate(
+                                        candidate, selector, lut,
+                                    )
+                                })
+                                .reduce_with(|mut lhs, rhs| {
+                                    self.key.unchecked_add_assign(&mut lhs, &rhs);
+                                    lhs
+                                })
+                                .unwrap();
+                            self.key.message_extract_assign(&mut selected);
+                            selected
+                        })
+                        .collect();
+                }
+
+                candidates.pop().unwrap()
+            })
+            .collect()
+    }
+
+    /// Computes homomorphically the application of a clear function to an encrypted integer
+    ///
+    /// The function is evaluated using a tree of lookup tables, so any parameter set with at least
+    /// as much carry space as message space can be used, however the number of PBS grows
+    /// exponentially with the number of bits of the input, so this is only meant for small
+    /// integers (16 bits at most).
+    ///
+    /// - The output of the function is reduced modulo the modulus of the ciphertext
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext encrypts more than 16 bits.
+    ///
+    /// # Requirements
+    ///
+    /// - The blocks of the input must not have carries
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 3;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 37u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.unchecked_map_parallelized(&ct, |x| x * x + 3);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, (msg * msg + 3) % 64);
+    /// ```
+    pub fn unchecked_map_parallelized<F>(&self, ct: &RadixCiphertext, f: F) -> RadixCiphertext
+    where
+        F: Fn(u64) -> u64,
+    {
+        let blocks = self.evaluate_function_with_lut_tree(ct.blocks(), ct.blocks().len(), &f);
+        RadixCiphertext::from_blocks(blocks)
+    }
+
+    /// Computes homomorphically the application of a clear function to an encrypted integer
+    ///
+    /// See [Self::unchecked_map_parallelized]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 3;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 37u64;
+    /// let mut ct = cks.encrypt(msg);
+    /// sks.unchecked_scalar_add_assign(&mut ct, 5);
+    ///
+    /// let ct_res = sks.map_parallelized(&ct, |x| x.count_ones() as u64);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, (msg + 5).count_ones() as u64);
+    /// ```
+    pub fn map_parallelized<F>(&self, ct: &RadixCiphertext, f: F) -> RadixCiphertext
+    where
+        F: Fn(u64) -> u64,
+    {
+        let mut tmp_ct: RadixCiphertext;
+
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        self.unchecked_map_parallelized(ct, f)
+    }
+
+    /// Computes homomorphically the application of a clear bivariate function to two encrypted
+    /// integers
+    ///
+    /// The function is evaluated on the concatenation of both inputs, using a tree of lookup
+    /// tables, see [Self::unchecked_map_parallelized].
+    ///
+    /// - The result has as many blocks as `lhs`
+    /// - The output of the function is reduced modulo the modulus of `lhs`
+    ///
+    /// # Panics
+    ///
+    /// Panics if both ciphertexts together encrypt more than 16 bits.
+    ///
+    /// # Requirements
+    ///
+    /// - The blocks of the inputs must not have carries
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 2;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 13u64;
+    /// let msg2 = 6u64;
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.unchecked_bivariate_function_parallelized(&ct1, &ct2, |x, y| x * 3 + y);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, (msg1 * 3 + msg2) % 16);
+    /// ```
+    pub fn unchecked_bivariate_function_parallelized<F>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        f: F,
+    ) -> RadixCiphertext
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        let lhs_modulus = (self.key.message_modulus.0 as u64)
+            .checked_pow(lhs.blocks().len() as u32)
+            .expect("Cannot evaluate a function on more than 64 bits");
+        let concatenated_f = |x: u64| f(x % lhs_modulus, x / lhs_modulus);
+
+        let blocks = lhs
+            .blocks()
+            .iter()
+            .chain(rhs.blocks().iter())
+            .cloned()
+            .collect::<Vec<_>>();
+        let blocks =
+            self.evaluate_function_with_lut_tree(&blocks, lhs.blocks().len(), &concatenated_f);
+        RadixCiphertext::from_blocks(blocks)
+    }
+
+    /// Computes homomorphically the application of a clear bivariate function to two encrypted
+    /// integers
+    ///
+    /// See [Self::unchecked_bivariate_function_parallelized]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 2;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 13u64;
+    /// let msg2 = 6u64;
+    /// let ct1 = cks.encrypt(msg1);
+    /// let mut ct2 = cks.encrypt(msg2);
+    /// sks.unchecked_scalar_add_assign(&mut ct2, 1);
+    ///
+    /// let ct_res = sks.bivariate_function_parallelized(&ct1, &ct2, |x, y| x.max(y) - x.min(y));
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg1 - (msg2 + 1));
+    /// ```
+    pub fn bivariate_function_parallelized<F>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        f: F,
+    ) -> RadixCiphertext
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        let mut tmp_lhs: RadixCiphertext;
+        let mut tmp_rhs: RadixCiphertext;
+
+        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
+            (true, true) => (lhs, rhs),
+            (true, false) => {
+                tmp_rhs = rhs.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (lhs, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = lhs.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, rhs)
+            }
+            (false, false) => {
+                tmp_lhs = lhs.clone();
+                tmp_rhs = rhs.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_bivariate_function_parallelized(lhs, rhs, f)
+    }
+}
@@ -291,41 +291,16 @@ impl ServerKey {
 
         // This is the part where we extract message and carry blocks
         // while inverting their bits
-        let (message_blocks, carry_blocks) = rayon::join(
-            || {
-                let lut = self.key.generate_lookup_table(|x| {
-                    // extract message
-                    let x = x % self.key.message_modulus.0 as u64;
-                    // bitnot the message
-                    (!x) % self.key.message_modulus.0 as u64
-                });
-                result
-                    .blocks()
-                    .par_iter()
-                    .map(|block| self.key.apply_lookup_table(block, &lut))
-                    .collect::<Vec<_>>()
-            },
-            || {
-                let lut = self.key.generate_lookup_table(|x| {
-                    // extract carry
-                    let x = x / self.key.message_modulus.0 as u64;
-                    // bitnot the carry
-                    (!x) % self.key.message_modulus.0 as u64
-                });
-                let mut carry_blocks = Vec::with_capacity(counter_num_blocks);
-                result.blocks()[..counter_num_blocks - 1] // last carry is not interesting
-                    .par_iter()
-                    .map(|block| self.key.apply_lookup_table(block, &lut))
-                    .collect_into_vec(&mut carry_blocks);
-                // Normally this would be 0, but we want the bitnot of 0, which is msg_mod-1
-                carry_blocks.insert(
-                    0,
-                    self.key
-                        .create_trivial((self.message_modulus().0 - 1) as u64),
-                );
-                carry_blocks
-            },
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let (message_blocks, mut carry_blocks) = self.apply_message_and_carry_functions(
+            result.blocks(),
+            // extract message, then bitnot it
+            |x| (!(x % message_modulus)) % message_modulus,
+            // extract carry, then bitnot it
+            |x| (!(x / message_modulus)) % message_modulus,
         );
+        // Normally this would be 0, but we want the bitnot of 0, which is msg_mod-1
+        carry_blocks.insert(0, self.key.create_trivial(message_modulus - 1));
 
         let message = SignedRadixCiphertext::from(message_blocks);
         let carry = SignedRadixCiphertext::from(carry_blocks);
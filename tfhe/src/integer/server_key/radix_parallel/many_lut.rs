@@ -0,0 +1,207 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Returns how many functions a single PBS can evaluate on a block of the given degree
+    ///
+    /// The many LUT technique splits the lookup table in as many parts as there are functions,
+    /// so each part must be large enough to hold all the values the block can take.
+    pub(crate) fn max_many_lut_function_count(&self, degree: Degree) -> usize {
+        let total_modulus = self.key.message_modulus.0 * self.key.carry_modulus.0;
+        (total_modulus / (degree.get() + 1)).clamp(1, total_modulus / 2)
+    }
+
+    /// Applies each function to each block
+    ///
+    /// The functions receive the whole value of the block (message and carry), and as many
+    /// functions as the highest degree of the blocks allows are evaluated by a single PBS.
+    ///
+    /// Returns, for each block, the output of each function.
+    pub(crate) fn apply_many_functions_to_blocks(
+        &self,
+        blocks: &[Ciphertext],
+        functions: &[&dyn Fn(u64) -> u64],
+    ) -> Vec<Vec<Ciphertext>> {
+        let Some(max_degree) = blocks.iter().map(|block| block.degree).max() else {
+            return vec![];
+        };
+        if functions.is_empty() {
+            return vec![vec![]; blocks.len()];
+        }
+
+        let functions_per_lut = self.max_many_lut_function_count(max_degree);
+        if functions_per_lut == 1 {
+            // No room to share PBS, regular lookup tables are used
+            let luts = functions
+                .iter()
+                .map(|function| self.key.generate_lookup_table(function))
+                .collect::<Vec<_>>();
+
+            return blocks
+                .par_iter()
+                .map(|block| {
+                    luts.par_iter()
+                        .map(|lut| self.key.apply_lookup_table(block, lut))
+                        .collect()
+                })
+                .collect();
+        }
+
+        let luts = functions
+            .chunks(functions_per_lut)
+            .map(|chunk| self.key.generate_many_lookup_table(chunk))
+            .collect::<Vec<_>>();
+
+        blocks
+            .par_iter()
+            .map(|block| {
+                luts.par_iter()
+                    .flat_map_iter(|lut| self.key.apply_many_lookup_table(block, lut))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Applies both functions to the block
+    ///
+    /// See [Self::apply_many_functions_to_blocks]
+    pub(crate) fn apply_two_functions_to_block(
+        &self,
+        block: &Ciphertext,
+        first_fn: &dyn Fn(u64) -> u64,
+        second_fn: &dyn Fn(u64) -> u64,
+    ) -> (Ciphertext, Ciphertext) {
+        let outputs = self
+            .apply_many_functions_to_blocks(std::slice::from_ref(block), &[first_fn, second_fn])
+            .pop()
+            .unwrap();
+        <[Ciphertext; 2]>::try_from(outputs).unwrap().into()
+    }
+
+    /// Returns the message of each block, and the carry of each block but the last one
+    ///
+    /// See [Self::apply_message_and_carry_functions]
+    pub(crate) fn extract_message_and_carry_blocks(
+        &self,
+        blocks: &[Ciphertext],
+    ) -> (Vec<Ciphertext>, Vec<Ciphertext>) {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        self.apply_message_and_carry_functions(
+            blocks,
+            |x| x % message_modulus,
+            |x| x / message_modulus,
+        )
+    }
+
+    /// Applies the message function to each block, and the carry function to each block but the
+    /// last one
+    ///
+    /// The carry of the last block is usually thrown away, so it is only computed when the degree
+    /// of the blocks allows evaluating both functions with a single PBS.
+    pub(crate) fn apply_message_and_carry_functions<F1, F2>(
+        &self,
+        blocks: &[Ciphertext],
+        message_fn: F1,
+        carry_fn: F2,
+    ) -> (Vec<Ciphertext>, Vec<Ciphertext>)
+    where
+        F1: Fn(u64) -> u64,
+        F2: Fn(u64) -> u64,
+    {
+        let num_blocks = blocks.len();
+        let Some(max_degree) = blocks.iter().map(|block| block.degree).max() else {
+            return (vec![], vec![]);
+        };
+
+        if self.max_many_lut_function_count(max_degree) >= 2 {
+            let (message_blocks, mut carry_blocks): (Vec<_>, Vec<_>) = self
+                .apply_many_functions_to_blocks(blocks, &[&message_fn, &carry_fn])
+                .into_iter()
+                .map(|outputs| <[Ciphertext; 2]>::try_from(outputs).unwrap().into())
+                .unzip();
+            carry_blocks.pop();
+            (message_blocks, carry_blocks)
+        } else {
+            let message_lut = self.key.generate_lookup_table(message_fn);
+            let carry_lut = self.key.generate_lookup_table(carry_fn);
+            rayon::join(
+                || {
+                    blocks
+                        .par_iter()
+                        .map(|block| self.key.apply_lookup_table(block, &message_lut))
+                        .collect()
+                },
+                || {
+                    let mut carry_blocks = Vec::with_capacity(num_blocks);
+                    blocks[..num_blocks - 1]
+                        .par_iter()
+                        .map(|block| self.key.apply_lookup_table(block, &carry_lut))
+                        .collect_into_vec(&mut carry_blocks);
+                    carry_blocks
+                },
+            )
+        }
+    }
+
+    /// Applies each function to each block of the ciphertext
+    ///
+    /// Returns one ciphertext per function, whose blocks are the outputs of this function on the
+    /// blocks of the input.
+    ///
+    /// The functions receive the whole value of the block (message and carry), and are evaluated
+    /// using as few PBS as possible: when the blocks have enough free space, a single PBS
+    /// evaluates several functions on a block.
+    ///
+    /// - The outputs of the functions are not reduced, if they do not fit in the message space,
+    ///   the resulting blocks have carries
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0b10_01_11_00u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Each block holds 2 bits, extract both with a single PBS per block
+    /// let low_bit = |x: u64| x & 1;
+    /// let high_bit = |x: u64| (x >> 1) & 1;
+    /// let results = sks.apply_many_block_functions_parallelized(&ct, &[&low_bit, &high_bit]);
+    ///
+    /// let low_bits: u64 = cks.decrypt(&results[0]);
+    /// let high_bits: u64 = cks.decrypt(&results[1]);
+    /// assert_eq!(low_bits, 0b00_01_01_00);
+    /// assert_eq!(high_bits, 0b01_00_01_00);
+    /// ```
+    pub fn apply_many_block_functions_parallelized<T>(
+        &self,
+        ct: &T,
+        functions: &[&dyn Fn(u64) -> u64],
+    ) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut outputs_per_block = self
+            .apply_many_functions_to_blocks(ct.blocks(), functions)
+            .into_iter()
+            .map(Vec::into_iter)
+            .collect::<Vec<_>>();
+
+        (0..functions.len())
+            .map(|_| {
+                let blocks = outputs_per_block
+                    .iter_mut()
+                    .map(|outputs| outputs.next().unwrap())
+                    .collect();
+                T::from_blocks(blocks)
+            })
+            .collect()
+    }
+}
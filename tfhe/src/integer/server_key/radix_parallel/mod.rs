@@ -5,9 +5,11 @@ mod bitwise_op;
 pub(crate) mod cmux;
 mod comparison;
 mod div_mod;
+mod function_evaluation;
 mod modulus_switch_compression;
 mod mul;
 mod neg;
+mod re_randomization;
 mod rotate;
 mod scalar_add;
 mod scalar_bitwise_op;
@@ -21,6 +23,7 @@ mod shift;
 pub(crate) mod sub;
 
 mod ilog2;
+mod many_lut;
 #[cfg(test)]
 pub(crate) mod tests_cases_unsigned;
 #[cfg(test)]
@@ -29,10 +32,11 @@ pub(crate) mod tests_signed;
 pub(crate) mod tests_unsigned;
 mod vector_comparisons;
 mod vector_find;
+mod vector_index;
+mod vector_sort;
 
 use super::ServerKey;
 use crate::integer::ciphertext::IntegerRadixCiphertext;
-use rayon::prelude::*;
 pub use scalar_div_mod::{MiniUnsignedInteger, Reciprocable};
 pub use vector_find::MatchValues;
 
@@ -71,9 +75,11 @@ impl ServerKey {
     where
         T: IntegerRadixCiphertext,
     {
-        let (carry, message) = rayon::join(
-            || self.key.carry_extract(&ctxt.blocks()[index]),
-            || self.key.message_extract(&ctxt.blocks()[index]),
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let (message, carry) = self.apply_two_functions_to_block(
+            &ctxt.blocks()[index],
+            &|x| x % message_modulus,
+            &|x| x / message_modulus,
         );
         ctxt.blocks_mut()[index] = message;
 
@@ -97,31 +103,6 @@ impl ServerKey {
             return;
         }
 
-        // Extract message blocks and carry blocks from the
-        // input block slice.
-        // Carries Vec has one less block than message Vec
-        let extract_message_and_carry_blocks = |blocks: &[crate::shortint::Ciphertext]| {
-            let num_blocks = blocks.len();
-
-            rayon::join(
-                || {
-                    blocks
-                        .par_iter()
-                        .map(|block| self.key.message_extract(block))
-                        .collect::<Vec<_>>()
-                },
-                || {
-                    let mut carry_blocks = Vec::with_capacity(num_blocks);
-                    // No need to compute the carry of the last block, we would just throw it away
-                    blocks[..num_blocks - 1]
-                        .par_iter()
-                        .map(|block| self.key.carry_extract(block))
-                        .collect_into_vec(&mut carry_blocks);
-                    carry_blocks
-                },
-            )
-        };
-
         if self.is_eligible_for_parallel_single_carry_propagation(ctxt) {
             let highest_degree = ctxt.blocks()[start_index..]
                 .iter()
@@ -136,7 +117,7 @@ impl ServerKey {
                 // At least one of the blocks has more than one carry,
                 // we need to extract message and carries, then add + propagate
                 let (mut message_blocks, carry_blocks) =
-                    extract_message_and_carry_blocks(&ctxt.blocks()[start_index..]);
+                    self.extract_message_and_carry_blocks(&ctxt.blocks()[start_index..]);
 
                 ctxt.blocks_mut()[start_index..].swap_with_slice(&mut message_blocks);
                 for (block, carry) in ctxt.blocks_mut()[start_index + 1..]
@@ -162,7 +143,7 @@ impl ServerKey {
                 // At least one of the blocks than can receive a carry, won't be able too
                 // so we need to do a first 'partial' round
                 let (mut message_blocks, carry_blocks) =
-                    extract_message_and_carry_blocks(&ctxt.blocks()[start_index..]);
+                    self.extract_message_and_carry_blocks(&ctxt.blocks()[start_index..]);
                 ctxt.blocks_mut()[start_index..].swap_with_slice(&mut message_blocks);
                 for (block, carry) in ctxt.blocks_mut()[start_index + 1..]
                     .iter_mut()
@@ -0,0 +1,66 @@
+use super::ServerKey;
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::public_key::CompactPublicKey;
+use concrete_csprng::seeders::Seed;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Re-randomizes the ciphertext, so that it cannot be linked to the ciphertexts it was
+    /// computed from
+    ///
+    /// A fresh encryption of zero, made with the compact public key, is added to each block, then
+    /// the blocks are bootstrapped (keyswitch followed by a PBS) so that the noise of the result
+    /// does not depend on the input.
+    ///
+    /// The randomness of the encryptions of zero is derived from the seed, so the same seed must
+    /// never be used twice.
+    ///
+    /// Returns an error if the LWE dimension of the blocks differs from the one of the compact
+    /// public key. The secret key itself cannot be checked, re-randomizing a ciphertext with the
+    /// public key of another secret key corrupts it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::public_key::CompactPublicKey;
+    /// use tfhe::integer::{gen_keys_radix, RadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::Seed;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    /// let cpk = CompactPublicKey::new(&cks);
+    ///
+    /// let msg = 213u64;
+    /// let ct: RadixCiphertext = cks.encrypt(msg);
+    ///
+    /// let mut re_randomized = ct.clone();
+    /// sks.re_randomize_parallelized(&mut re_randomized, &cpk, Seed(0))
+    ///     .unwrap();
+    /// assert_ne!(re_randomized, ct);
+    ///
+    /// let dec: u64 = cks.decrypt(&re_randomized);
+    /// assert_eq!(dec, msg);
+    /// ```
+    pub fn re_randomize_parallelized<T>(
+        &self,
+        ct: &mut T,
+        compact_public_key: &CompactPublicKey,
+        seed: Seed,
+    ) -> crate::Result<()>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+
+        compact_public_key.re_randomize_ciphertext(ct, seed)?;
+
+        ct.blocks_mut()
+            .par_iter_mut()
+            .for_each(|block| self.key.message_extract_assign(block));
+
+        Ok(())
+    }
+}
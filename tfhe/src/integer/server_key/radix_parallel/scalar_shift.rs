@@ -171,10 +171,10 @@ impl ServerKey {
         // In the arithmetic shift case we have to pad with the value of the sign bit.
         //
         // This creates the need for a different shifting lut than in the logical shift case
-        // and also we need another PBS to create the padding block.
-        // We use rayon::join to hopefully make this parallel.
+        // and also we need another function to create the padding block.
+        // When the parameters allow it, both are computed by the same PBS.
         let shift_last_block = || {
-            let last_block_lut = self.key.generate_lookup_table(|x| {
+            let last_block_fn = |x: u64| {
                 let x = x % message_modulus;
                 let x_sign_bit = x >> (num_bits_in_block - 1) & 1;
                 let shifted = x >> shift_within_block;
@@ -188,24 +188,18 @@ impl ServerKey {
                 padding %= message_modulus;
 
                 shifted | padding
-            });
+            };
             let last_block = &ct.blocks()[num_blocks - rotations - 1];
 
-            let pad_block_creator_lut = self.key.generate_lookup_table(|x| {
+            let pad_block_creator_fn = |x: u64| {
                 let x = x % message_modulus;
                 let x_sign_bit = x >> (num_bits_in_block - 1) & 1;
                 // padding is a message full of 1 if sign bit is one
                 // else padding is a zero message
                 (message_modulus - 1) * x_sign_bit
-            });
+            };
 
-            rayon::join(
-                || self.key.apply_lookup_table(last_block, &last_block_lut),
-                || {
-                    self.key
-                        .apply_lookup_table(last_block, &pad_block_creator_lut)
-                },
-            )
+            self.apply_two_functions_to_block(last_block, &last_block_fn, &pad_block_creator_fn)
         };
 
         let (partial_blocks, (last_shifted_block, padding_block)) = rayon::join(
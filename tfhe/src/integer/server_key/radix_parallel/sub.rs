@@ -426,9 +426,8 @@ impl ServerKey {
 
             // If the block does not have a carry after the subtraction, it means it needs to
             // borrow from the next block
-            let compute_borrow_lut =
-                self.key
-                    .generate_lookup_table(|x| if x < modulus { 1 } else { 0 });
+            let extract_message = |x: u64| x % modulus;
+            let compute_borrow = |x: u64| if x < modulus { 1 } else { 0 };
 
             let mut borrow = self.key.create_trivial(0);
             for block in ct.blocks.iter_mut() {
@@ -441,10 +440,8 @@ impl ServerKey {
                     &borrow.ct,
                 );
                 block.set_noise_level(block.noise_level() + borrow.noise_level());
-                let (msg, new_borrow) = rayon::join(
-                    || self.key.message_extract(block),
-                    || self.key.apply_lookup_table(block, &compute_borrow_lut),
-                );
+                let (msg, new_borrow) =
+                    self.apply_two_functions_to_block(block, &extract_message, &compute_borrow);
                 *block = msg;
                 borrow = new_borrow;
             }
@@ -4,6 +4,8 @@ pub(crate) mod test_bitwise_op;
 pub(crate) mod test_cmux;
 pub(crate) mod test_comparison;
 pub(crate) mod test_div_mod;
+pub(crate) mod test_function_evaluation;
+pub(crate) mod test_many_lut;
 pub(crate) mod test_mul;
 pub(crate) mod test_neg;
 pub(crate) mod test_rotate;
@@ -19,6 +21,8 @@ pub(crate) mod test_shift;
 pub(crate) mod test_sub;
 pub(crate) mod test_vector_comparisons;
 pub(crate) mod test_vector_find;
+pub(crate) mod test_vector_index;
+pub(crate) mod test_vector_sort;
 
 use super::tests_cases_unsigned::*;
 use crate::integer::keycache::KEY_CACHE;
@@ -568,6 +572,38 @@ where
     }
 }
 
+impl<'a, F> FunctionExecutor<&'a [RadixCiphertext], Vec<RadixCiphertext>> for CpuFunctionExecutor<F>
+where
+    F: Fn(&ServerKey, &[RadixCiphertext]) -> Vec<RadixCiphertext>,
+{
+    fn setup(&mut self, _cks: &RadixClientKey, sks: Arc<ServerKey>) {
+        self.sks = Some(sks);
+    }
+
+    fn execute(&mut self, input: &'a [RadixCiphertext]) -> Vec<RadixCiphertext> {
+        let sks = self.sks.as_ref().expect("setup was not properly called");
+        (self.func)(sks, input)
+    }
+}
+
+impl<'a, F> FunctionExecutor<&'a [RadixCiphertext], Option<(RadixCiphertext, RadixCiphertext)>>
+    for CpuFunctionExecutor<F>
+where
+    F: Fn(&ServerKey, &[RadixCiphertext]) -> Option<(RadixCiphertext, RadixCiphertext)>,
+{
+    fn setup(&mut self, _cks: &RadixClientKey, sks: Arc<ServerKey>) {
+        self.sks = Some(sks);
+    }
+
+    fn execute(
+        &mut self,
+        input: &'a [RadixCiphertext],
+    ) -> Option<(RadixCiphertext, RadixCiphertext)> {
+        let sks = self.sks.as_ref().expect("setup was not properly called");
+        (self.func)(sks, input)
+    }
+}
+
 /// Unary assign fn
 impl<'a, F> FunctionExecutor<&'a mut RadixCiphertext, ()> for CpuFunctionExecutor<F>
 where
@@ -0,0 +1,118 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey};
+
+use super::{nb_tests_smaller_for_params, unsigned_modulus, NB_CTXT};
+use crate::integer::tests::create_parametrized_test;
+use rand::prelude::*;
+
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+
+create_parametrized_test!(integer_default_map);
+create_parametrized_test!(integer_default_bivariate_function);
+
+/// The number of PBS grows exponentially with the number of input bits,
+/// so inputs are kept small
+const MAX_INPUT_BITS: u32 = 8;
+
+fn integer_default_map<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    let block_bits = cks.parameters().message_modulus().0.ilog2();
+    let num_blocks = (MAX_INPUT_BITS / block_bits).clamp(1, NB_CTXT as u32);
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), num_blocks);
+
+    let functions: [&dyn Fn(u64) -> u64; 3] =
+        [&|x| x * x + 3, &|x| u64::from(x.count_ones()), &|x| {
+            if x % 3 == 0 {
+                x / 3
+            } else {
+                modulus - 1 - x
+            }
+        }];
+
+    for _ in 0..nb_tests {
+        let clear = rng.gen_range(0..modulus);
+        let mut ct: RadixCiphertext = cks.as_ref().encrypt_radix(clear, num_blocks as usize);
+
+        let add = rng.gen_range(0..modulus);
+        sks.unchecked_scalar_add_assign(&mut ct, add);
+        let clear = (clear + add) % modulus;
+
+        let function = functions.choose(&mut rng).unwrap();
+        let ct_res = sks.map_parallelized(&ct, function);
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec,
+            function(clear) % modulus,
+            "Invalid function result for input {clear}"
+        );
+    }
+}
+
+fn integer_default_bivariate_function<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    let block_bits = cks.parameters().message_modulus().0.ilog2();
+    let lhs_num_blocks = (MAX_INPUT_BITS / 2 / block_bits).max(1);
+    let rhs_num_blocks = (MAX_INPUT_BITS / block_bits)
+        .saturating_sub(lhs_num_blocks)
+        .max(1);
+    let lhs_modulus = unsigned_modulus(cks.parameters().message_modulus(), lhs_num_blocks);
+    let rhs_modulus = unsigned_modulus(cks.parameters().message_modulus(), rhs_num_blocks);
+
+    let functions: [&dyn Fn(u64, u64) -> u64; 3] =
+        [&|x, y| x * 3 + y, &|x, y| x.max(y) - x.min(y), &|x, y| {
+            if y == 0 {
+                x
+            } else {
+                x % y
+            }
+        }];
+
+    for _ in 0..nb_tests {
+        let clear_lhs = rng.gen_range(0..lhs_modulus);
+        let clear_rhs = rng.gen_range(0..rhs_modulus);
+        let ct_lhs: RadixCiphertext = cks
+            .as_ref()
+            .encrypt_radix(clear_lhs, lhs_num_blocks as usize);
+        let mut ct_rhs: RadixCiphertext = cks
+            .as_ref()
+            .encrypt_radix(clear_rhs, rhs_num_blocks as usize);
+
+        let add = rng.gen_range(0..rhs_modulus);
+        sks.unchecked_scalar_add_assign(&mut ct_rhs, add);
+        let clear_rhs = (clear_rhs + add) % rhs_modulus;
+
+        let function = functions.choose(&mut rng).unwrap();
+        let ct_res = sks.bivariate_function_parallelized(&ct_lhs, &ct_rhs, function);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res.blocks.len(), lhs_num_blocks as usize);
+
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec,
+            function(clear_lhs, clear_rhs) % lhs_modulus,
+            "Invalid function result for inputs {clear_lhs} and {clear_rhs}"
+        );
+    }
+}
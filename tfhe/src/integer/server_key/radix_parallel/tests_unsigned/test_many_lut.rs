@@ -0,0 +1,65 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey};
+
+use super::{nb_tests_smaller_for_params, unsigned_modulus, NB_CTXT};
+use crate::integer::tests::create_parametrized_test;
+use rand::prelude::*;
+
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+
+create_parametrized_test!(integer_apply_many_block_functions);
+
+fn integer_apply_many_block_functions<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    let message_modulus = cks.parameters().message_modulus().0 as u64;
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    let functions: [&dyn Fn(u64) -> u64; 4] = [
+        &|x| x % message_modulus,
+        &|x| x / message_modulus,
+        &|x| x & 1,
+        &|x| u64::from(x.count_ones()),
+    ];
+
+    for _ in 0..nb_tests {
+        let clear = rng.gen_range(0..modulus);
+        let mut ct: RadixCiphertext = cks.encrypt(clear);
+        // Blocks with carries leave less room to evaluate several functions with one PBS
+        if rng.gen_bool(0.5) {
+            sks.unchecked_scalar_add_assign(&mut ct, rng.gen_range(0..modulus));
+        }
+        let block_values = ct
+            .blocks
+            .iter()
+            .map(|block| cks.as_ref().key.decrypt_message_and_carry(block))
+            .collect::<Vec<_>>();
+
+        let num_functions = rng.gen_range(1..=functions.len());
+        let results = sks.apply_many_block_functions_parallelized(&ct, &functions[..num_functions]);
+        assert_eq!(results.len(), num_functions);
+
+        for (i, (result, function)) in results.iter().zip(functions).enumerate() {
+            assert_eq!(result.blocks.len(), NB_CTXT);
+            for (block, value) in result.blocks.iter().zip(block_values.iter()) {
+                let decrypted = cks.as_ref().key.decrypt_message_and_carry(block);
+                assert_eq!(
+                    decrypted,
+                    function(*value),
+                    "Invalid result of function {i} on the block value {value}"
+                );
+                assert!(decrypted <= block.degree.get() as u64);
+            }
+        }
+    }
+}
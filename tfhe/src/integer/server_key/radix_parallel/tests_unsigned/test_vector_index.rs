@@ -0,0 +1,214 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+use std::sync::Arc;
+
+use super::{
+    nb_tests_smaller_for_params, unsigned_modulus, CpuFunctionExecutor, MAX_VEC_LEN, NB_CTXT,
+};
+use crate::integer::tests::create_parametrized_test;
+use rand::prelude::*;
+
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+
+create_parametrized_test!(integer_unchecked_read_at_index);
+create_parametrized_test!(integer_default_read_at_index);
+create_parametrized_test!(integer_default_write_at_index);
+create_parametrized_test!(integer_default_lookup_in_clears);
+
+fn integer_unchecked_read_at_index<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::unchecked_read_at_index_parallelized);
+    read_at_index_test_case(param, executor, false);
+}
+
+fn integer_default_read_at_index<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::read_at_index_parallelized);
+    read_at_index_test_case(param, executor, true);
+}
+
+fn integer_default_write_at_index<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::write_at_index_parallelized);
+    default_write_at_index_test_case(param, executor);
+}
+
+fn integer_default_lookup_in_clears<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::lookup_in_clears_parallelized::<u64>);
+    default_lookup_in_clears_test_case(param, executor);
+}
+
+/// Encrypts the value as the unchecked sum of a ciphertext and a scalar, so that it may have
+/// carries
+fn encrypt_with_carries(
+    clear: u64,
+    rng: &mut ThreadRng,
+    cks: &RadixClientKey,
+    sks: &ServerKey,
+) -> RadixCiphertext {
+    if clear == 0 {
+        return cks.encrypt(clear);
+    }
+    let scalar = rng.gen_range(1..=clear);
+    let mut ct = cks.encrypt(clear - scalar);
+    sks.unchecked_scalar_add_assign(&mut ct, scalar);
+    ct
+}
+
+pub(crate) fn read_at_index_test_case<P, E>(param: P, mut executor: E, with_carries: bool)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<(&'a [RadixCiphertext], &'a RadixCiphertext), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..MAX_VEC_LEN);
+        let clears = (0..num_values)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+        let encrypt = |clear: u64, rng: &mut ThreadRng| {
+            if with_carries && rng.gen_bool(0.5) {
+                encrypt_with_carries(clear, rng, &cks, &sks)
+            } else {
+                cks.encrypt(clear)
+            }
+        };
+        let cts = clears
+            .iter()
+            .map(|clear| encrypt(*clear, &mut rng))
+            .collect::<Vec<_>>();
+
+        // Also test indices that are out of bounds
+        let clear_index = rng.gen_range(0..num_values as u64 + 2);
+        let index = encrypt(clear_index, &mut rng);
+        let expected = clears.get(clear_index as usize).copied().unwrap_or(0);
+
+        let result = executor.execute((&cts, &index));
+        let result_2 = executor.execute((&cts, &index));
+        assert_eq!(result, result_2, "Failed determinism test");
+
+        let result: u64 = cks.decrypt(&result);
+        assert_eq!(
+            result, expected,
+            "Invalid read at index {clear_index} of {clears:?}"
+        );
+    }
+}
+
+pub(crate) fn default_write_at_index_test_case<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<
+        (
+            &'a mut [RadixCiphertext],
+            &'a RadixCiphertext,
+            &'a RadixCiphertext,
+        ),
+        (),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..MAX_VEC_LEN);
+        let mut clears = (0..num_values)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+        let mut cts = clears
+            .iter()
+            .map(|clear| encrypt_with_carries(*clear, &mut rng, &cks, &sks))
+            .collect::<Vec<_>>();
+
+        let clear_index = rng.gen_range(0..num_values as u64 + 2);
+        let index = cks.encrypt(clear_index);
+        let clear_value = rng.gen_range(0..modulus);
+        let value = encrypt_with_carries(clear_value, &mut rng, &cks, &sks);
+
+        executor.execute((&mut cts, &index, &value));
+
+        if let Some(clear) = clears.get_mut(clear_index as usize) {
+            *clear = clear_value;
+        }
+        let decrypted = cts
+            .iter()
+            .map(|ct| cks.decrypt::<u64>(ct))
+            .collect::<Vec<_>>();
+        assert_eq!(decrypted, clears, "Invalid write at index {clear_index}");
+    }
+}
+
+pub(crate) fn default_lookup_in_clears_test_case<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a [u64], usize), RadixCiphertext>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        // The table has more entries than a single lookup table, and its values need more blocks
+        // than the index
+        let num_output_blocks = 2 * NB_CTXT;
+        let output_modulus =
+            unsigned_modulus(cks.parameters().message_modulus(), num_output_blocks as u32);
+        let table_len = rng.gen_range(1..=modulus as usize);
+        let table = (0..table_len)
+            .map(|_| rng.gen_range(0..output_modulus))
+            .collect::<Vec<_>>();
+
+        let clear_index = rng.gen_range(0..modulus);
+        let index = encrypt_with_carries(clear_index, &mut rng, &cks, &sks);
+        let expected = table.get(clear_index as usize).copied().unwrap_or(0);
+
+        let result = executor.execute((&index, &table, num_output_blocks));
+        assert_eq!(result.blocks.len(), num_output_blocks);
+        let result: u64 = cks.decrypt(&result);
+        assert_eq!(
+            result, expected,
+            "Invalid lookup at index {clear_index} of a table of {table_len} entries"
+        );
+    }
+}
@@ -0,0 +1,385 @@
+use crate::integer::keycache::KEY_CACHE;
+use crate::integer::server_key::radix_parallel::tests_cases_unsigned::FunctionExecutor;
+use crate::integer::server_key::radix_parallel::vector_sort::{
+    odd_even_merge_sort_layers, top_k_layers,
+};
+use crate::integer::{IntegerKeyKind, RadixCiphertext, RadixClientKey, ServerKey};
+use std::sync::Arc;
+
+use super::{
+    nb_tests_smaller_for_params, random_non_zero_value, unsigned_modulus, CpuFunctionExecutor,
+    NB_CTXT,
+};
+use crate::integer::tests::create_parametrized_test;
+use rand::prelude::*;
+
+#[cfg(tarpaulin)]
+use crate::shortint::parameters::coverage_parameters::*;
+use crate::shortint::parameters::*;
+
+/// Sorting needs `O(n log²(n))` comparisons, so the vectors are kept shorter than in the other
+/// vector tests
+const MAX_SORTED_VEC_LEN: usize = 8;
+
+create_parametrized_test!(integer_unchecked_sort);
+create_parametrized_test!(integer_default_sort);
+create_parametrized_test!(integer_default_sort_by_key);
+create_parametrized_test!(integer_default_argmin);
+create_parametrized_test!(integer_default_argmax);
+create_parametrized_test!(integer_default_top_k);
+
+fn integer_unchecked_sort<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::unchecked_sort_parallelized);
+    unchecked_sort_test_case(param, executor);
+}
+
+fn integer_default_sort<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::sort_parallelized);
+    default_sort_test_case(param, executor);
+}
+
+fn integer_default_sort_by_key<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::sort_by_key_parallelized);
+    default_sort_by_key_test_case(param, executor);
+}
+
+fn integer_default_argmin<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::argmin_parallelized);
+    default_arg_extremum_test_case(param, executor, false);
+}
+
+fn integer_default_argmax<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::argmax_parallelized);
+    default_arg_extremum_test_case(param, executor, true);
+}
+
+fn integer_default_top_k<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::top_k_parallelized);
+    default_top_k_test_case(param, executor);
+}
+
+/// Applies the layers of a network on clear values
+fn apply_layers(values: &mut [u64], layers: &[Vec<(usize, usize)>]) {
+    for layer in layers {
+        for &(i, j) in layer {
+            if values[i] > values[j] {
+                values.swap(i, j);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_odd_even_merge_sort_layers() {
+    for len in 0..=12usize {
+        let layers = odd_even_merge_sort_layers(len);
+
+        for layer in &layers {
+            let mut indices = layer.iter().flat_map(|&(i, j)| [i, j]).collect::<Vec<_>>();
+            assert!(layer.iter().all(|&(i, j)| i < j && j < len));
+            indices.sort_unstable();
+            indices.dedup();
+            assert_eq!(
+                indices.len(),
+                2 * layer.len(),
+                "A layer has overlapping comparators"
+            );
+        }
+
+        // By the 0-1 principle, a network sorting all the sequences of 0s and 1s sorts all the
+        // sequences
+        for bits in 0..1u64 << len {
+            let mut values = (0..len).map(|i| (bits >> i) & 1).collect::<Vec<_>>();
+            apply_layers(&mut values, &layers);
+            assert!(
+                values.windows(2).all(|pair| pair[0] <= pair[1]),
+                "Failed to sort {bits:#b} of length {len}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_top_k_layers() {
+    for len in 0..=12usize {
+        let num_comparators = odd_even_merge_sort_layers(len).iter().map(Vec::len).sum();
+
+        for k in 0..=len {
+            let layers = top_k_layers(len, k);
+            assert!(layers.iter().map(Vec::len).sum::<usize>() <= num_comparators);
+
+            for bits in 0..1u64 << len {
+                let mut values = (0..len).map(|i| (bits >> i) & 1).collect::<Vec<_>>();
+                let mut expected = values.clone();
+                expected.sort_unstable();
+
+                apply_layers(&mut values, &layers);
+                assert_eq!(values[len - k..], expected[len - k..]);
+            }
+        }
+    }
+}
+
+/// Encrypts the values, adding a non zero scalar to one of them so that it has carries
+fn encrypt_with_carries(
+    clears: &mut [u64],
+    rng: &mut ThreadRng,
+    cks: &RadixClientKey,
+    sks: &ServerKey,
+    modulus: u64,
+) -> Vec<RadixCiphertext> {
+    let mut cts = clears
+        .iter()
+        .map(|clear| cks.encrypt(*clear))
+        .collect::<Vec<_>>();
+
+    if !clears.is_empty() {
+        let i = rng.gen_range(0..clears.len());
+        let clear_0 = random_non_zero_value(rng, modulus);
+        sks.unchecked_scalar_add_assign(&mut cts[i], clear_0);
+        clears[i] = clears[i].wrapping_add(clear_0) % modulus;
+    }
+
+    cts
+}
+
+pub(crate) fn unchecked_sort_test_case<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<&'a [RadixCiphertext], Vec<RadixCiphertext>>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks);
+
+    assert!(executor.execute(&[]).is_empty());
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..=MAX_SORTED_VEC_LEN);
+        // A small range, to have duplicates
+        let mut clears = (0..num_values)
+            .map(|_| rng.gen_range(0..modulus.min(8)))
+            .collect::<Vec<_>>();
+        let cts = clears
+            .iter()
+            .map(|clear| cks.encrypt(*clear))
+            .collect::<Vec<_>>();
+
+        let sorted = executor.execute(&cts);
+        let sorted = sorted
+            .iter()
+            .map(|ct| cks.decrypt::<u64>(ct))
+            .collect::<Vec<_>>();
+
+        clears.sort_unstable();
+        assert_eq!(sorted, clears);
+    }
+}
+
+pub(crate) fn default_sort_test_case<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<&'a [RadixCiphertext], Vec<RadixCiphertext>>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..=MAX_SORTED_VEC_LEN);
+        let mut clears = (0..num_values)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+        let cts = encrypt_with_carries(&mut clears, &mut rng, &cks, &sks, modulus);
+
+        let sorted = executor.execute(&cts);
+        let sorted_2 = executor.execute(&cts);
+        assert_eq!(sorted, sorted_2, "Failed determinism test");
+
+        for ct in &sorted {
+            assert!(ct.block_carries_are_empty());
+        }
+        let sorted = sorted
+            .iter()
+            .map(|ct| cks.decrypt::<u64>(ct))
+            .collect::<Vec<_>>();
+
+        clears.sort_unstable();
+        assert_eq!(sorted, clears);
+    }
+}
+
+pub(crate) fn default_sort_by_key_test_case<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<
+        (&'a [RadixCiphertext], &'a [RadixCiphertext]),
+        (Vec<RadixCiphertext>, Vec<RadixCiphertext>),
+    >,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..=MAX_SORTED_VEC_LEN);
+        // The keys are unique, so that the order of the values is fully determined
+        let mut clear_keys = (0..modulus).choose_multiple(&mut rng, num_values);
+        clear_keys.shuffle(&mut rng);
+        let mut clear_values = (0..num_values)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+
+        let keys = clear_keys
+            .iter()
+            .map(|clear| cks.encrypt(*clear))
+            .collect::<Vec<_>>();
+        let values = encrypt_with_carries(&mut clear_values, &mut rng, &cks, &sks, modulus);
+
+        let (sorted_keys, sorted_values) = executor.execute((&keys, &values));
+        let sorted_keys = sorted_keys
+            .iter()
+            .map(|ct| cks.decrypt::<u64>(ct))
+            .collect::<Vec<_>>();
+        let sorted_values = sorted_values
+            .iter()
+            .map(|ct| cks.decrypt::<u64>(ct))
+            .collect::<Vec<_>>();
+
+        let mut expected = clear_keys.into_iter().zip(clear_values).collect::<Vec<_>>();
+        expected.sort_unstable();
+        let (expected_keys, expected_values): (Vec<_>, Vec<_>) = expected.into_iter().unzip();
+        assert_eq!(sorted_keys, expected_keys);
+        assert_eq!(sorted_values, expected_values);
+    }
+}
+
+pub(crate) fn default_arg_extremum_test_case<P, E>(param: P, mut executor: E, is_max: bool)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<&'a [RadixCiphertext], Option<(RadixCiphertext, RadixCiphertext)>>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    assert!(executor.execute(&[]).is_none());
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..=2 * MAX_SORTED_VEC_LEN);
+        // A small range, to check that the first index is returned on ties
+        let mut clears = (0..num_values)
+            .map(|_| rng.gen_range(0..modulus.min(4)))
+            .collect::<Vec<_>>();
+        let cts = encrypt_with_carries(&mut clears, &mut rng, &cks, &sks, modulus);
+
+        let (index, extremum) = executor.execute(&cts).unwrap();
+        let (index_2, extremum_2) = executor.execute(&cts).unwrap();
+        assert_eq!(index, index_2, "Failed determinism test");
+        assert_eq!(extremum, extremum_2, "Failed determinism test");
+
+        let expected = if is_max {
+            *clears.iter().max().unwrap()
+        } else {
+            *clears.iter().min().unwrap()
+        };
+        let expected_index = clears.iter().position(|v| *v == expected).unwrap();
+
+        let index: u64 = cks.decrypt(&index);
+        let extremum: u64 = cks.decrypt(&extremum);
+        assert_eq!(
+            index, expected_index as u64,
+            "Invalid index for {clears:?}, is_max: {is_max}"
+        );
+        assert_eq!(extremum, expected);
+    }
+}
+
+pub(crate) fn default_top_k_test_case<P, E>(param: P, mut executor: E)
+where
+    P: Into<PBSParameters>,
+    E: for<'a> FunctionExecutor<(&'a [RadixCiphertext], usize), Vec<RadixCiphertext>>,
+{
+    let param = param.into();
+    let nb_tests = nb_tests_smaller_for_params(param);
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let sks = Arc::new(sks);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = thread_rng();
+
+    let modulus = unsigned_modulus(cks.parameters().message_modulus(), NB_CTXT as u32);
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..nb_tests {
+        let num_values = rng.gen_range(1..=MAX_SORTED_VEC_LEN);
+        let k = rng.gen_range(0..=num_values + 1);
+        let mut clears = (0..num_values)
+            .map(|_| rng.gen_range(0..modulus))
+            .collect::<Vec<_>>();
+        let cts = encrypt_with_carries(&mut clears, &mut rng, &cks, &sks, modulus);
+
+        let top = executor.execute((&cts, k));
+        let top = top
+            .iter()
+            .map(|ct| cks.decrypt::<u64>(ct))
+            .collect::<Vec<_>>();
+
+        clears.sort_unstable_by(|a, b| b.cmp(a));
+        clears.truncate(k);
+        assert_eq!(top, clears);
+    }
+}
@@ -1055,7 +1055,7 @@ impl ServerKey {
     /// otherwise it will be 0.
     ///
     /// Requires ct to have empty carries
-    fn compute_equality_selectors<T, Iter, Clear>(
+    pub(super) fn compute_equality_selectors<T, Iter, Clear>(
         &self,
         ct: &T,
         possible_input_values: Iter,
@@ -1127,7 +1127,7 @@ impl ServerKey {
     ///
     /// - Resulting radix ciphertexts have their block packed, thus they will have ceil
     /// (numb_blocks / 2) elements
-    fn create_possible_results<T, Iter, Clear>(
+    pub(super) fn create_possible_results<T, Iter, Clear>(
         &self,
         num_blocks: usize,
         possible_outputs: Iter,
@@ -1199,7 +1199,7 @@ impl ServerKey {
     /// The elements in the one hot vector have their block packed.
     ///
     /// The returned result has non packed blocks
-    fn aggregate_one_hot_vector<T>(&self, mut one_hot_vector: Vec<T>) -> T
+    pub(super) fn aggregate_one_hot_vector<T>(&self, mut one_hot_vector: Vec<T>) -> T
     where
         T: IntegerRadixCiphertext,
     {
@@ -1244,16 +1244,14 @@ impl ServerKey {
             }
         }
 
-        let unpacked_blocks = result
-            .blocks()
-            .par_iter()
-            .flat_map(|block| -> [Ciphertext; 2] {
-                rayon::join(
-                    || self.key.message_extract(block),
-                    || self.key.carry_extract(block),
-                )
-                .into()
-            })
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let unpacked_blocks = self
+            .apply_many_functions_to_blocks(
+                result.blocks(),
+                &[&|x| x % message_modulus, &|x| x / message_modulus],
+            )
+            .into_iter()
+            .flatten()
             .collect::<Vec<_>>();
         T::from_blocks(unpacked_blocks)
     }
@@ -0,0 +1,373 @@
+use crate::integer::block_decomposition::Decomposable;
+use crate::integer::{BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey};
+use crate::prelude::CastInto;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Returns the number of elements of a slice that can be addressed by the index, the other
+    /// ones can never be selected
+    fn num_addressable_elements(&self, index: &RadixCiphertext, len: usize) -> usize {
+        let num_bits = self.message_modulus().0.ilog2() * index.blocks.len() as u32;
+        if num_bits >= usize::BITS {
+            len
+        } else {
+            len.min(1 << num_bits)
+        }
+    }
+
+    /// Returns one encrypted boolean per element of a slice of `len` elements, encrypting `true`
+    /// only for the element at `index`
+    fn compute_index_selectors(&self, index: &RadixCiphertext, len: usize) -> Vec<BooleanBlock> {
+        let num_addressable = self.num_addressable_elements(index, len);
+        let mut selectors =
+            self.compute_equality_selectors(index, (0..num_addressable as u64).into_par_iter());
+        selectors.resize_with(len, || self.create_trivial_boolean_block(false));
+        selectors
+    }
+
+    /// Sums ciphertexts of which at most one is not zero, so that their blocks can be added
+    /// without any carry
+    fn unchecked_sum_one_hot_ciphertexts<T>(&self, mut one_hot_cts: Vec<T>) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        // The ciphertexts are summed by chunks, which are only guaranteed to hold more than one
+        // ciphertext with at least as much carry space as message space
+        assert!(
+            self.carry_modulus().0 >= self.message_modulus().0,
+            "Summing one hot ciphertexts requires at least as much carry space as message space"
+        );
+
+        let total_modulus = self.message_modulus().0 * self.carry_modulus().0;
+        let chunk_size = (total_modulus - 1) / (self.message_modulus().0 - 1);
+
+        while one_hot_cts.len() > 1 {
+            one_hot_cts = one_hot_cts
+                .par_chunks(chunk_size)
+                .map(|chunk| {
+                    let mut result = chunk[0].clone();
+                    if chunk.len() > 1 {
+                        for ct in &chunk[1..] {
+                            self.unchecked_add_assign(&mut result, ct);
+                        }
+                        result
+                            .blocks_mut()
+                            .par_iter_mut()
+                            .for_each(|block| self.key.message_extract_assign(block));
+                    }
+                    result
+                })
+                .collect();
+        }
+
+        one_hot_cts.pop().expect("Cannot sum an empty vector")
+    }
+
+    /// Returns the ciphertext at the encrypted `index` of the slice
+    ///
+    /// Each element is multiplied by the encrypted boolean telling whether its index is the
+    /// requested one, and the results are summed, so the index stays hidden.
+    ///
+    /// - If the index is out of bounds, the returned ciphertext encrypts 0
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is empty, or if the parameters do not have at least as much carry
+    /// space as message space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let cts = [200u8, 3, 17]
+    ///     .iter()
+    ///     .map(|v| cks.encrypt(*v))
+    ///     .collect::<Vec<_>>();
+    /// let index = cks.encrypt(2u8);
+    ///
+    /// let result = sks.unchecked_read_at_index_parallelized(&cts, &index);
+    /// let decrypted: u8 = cks.decrypt(&result);
+    /// assert_eq!(decrypted, 17);
+    /// ```
+    pub fn unchecked_read_at_index_parallelized<T>(&self, cts: &[T], index: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert!(!cts.is_empty(), "Cannot read in an empty slice");
+
+        let selectors = self.compute_index_selectors(index, cts.len());
+        let selected = cts
+            .par_iter()
+            .zip(selectors.par_iter())
+            .map(|(ct, selector)| {
+                let mut ct = ct.clone();
+                self.zero_out_if_condition_is_false(&mut ct, &selector.0);
+                ct
+            })
+            .collect::<Vec<_>>();
+
+        self.unchecked_sum_one_hot_ciphertexts(selected)
+    }
+
+    /// Returns the ciphertext at the encrypted `index` of the slice
+    ///
+    /// See [Self::unchecked_read_at_index_parallelized]
+    pub fn smart_read_at_index_parallelized<T>(
+        &self,
+        cts: &mut [T],
+        index: &mut RadixCiphertext,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !index.block_carries_are_empty() {
+            self.full_propagate_parallelized(index);
+        }
+
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        self.unchecked_read_at_index_parallelized(cts, index)
+    }
+
+    /// Returns the ciphertext at the encrypted `index` of the slice
+    ///
+    /// See [Self::unchecked_read_at_index_parallelized]
+    pub fn read_at_index_parallelized<T>(&self, cts: &[T], index: &RadixCiphertext) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_cts;
+        let mut tmp_index;
+
+        let cts = if cts.iter().any(|ct| !ct.block_carries_are_empty()) {
+            tmp_cts = cts.to_vec();
+            tmp_cts
+                .par_iter_mut()
+                .filter(|ct| !ct.block_carries_are_empty())
+                .for_each(|ct| self.full_propagate_parallelized(ct));
+            &tmp_cts
+        } else {
+            cts
+        };
+
+        let index = if index.block_carries_are_empty() {
+            index
+        } else {
+            tmp_index = index.clone();
+            self.full_propagate_parallelized(&mut tmp_index);
+            &tmp_index
+        };
+
+        self.unchecked_read_at_index_parallelized(cts, index)
+    }
+
+    /// Replaces the ciphertext at the encrypted `index` of the slice by `value`
+    ///
+    /// Every element is replaced by an encrypted selection between itself and `value`, so the
+    /// index stays hidden.
+    ///
+    /// - If the index is out of bounds, the slice is left unchanged
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters do not have at least as much carry space as message space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let mut cts = [200u8, 3, 17]
+    ///     .iter()
+    ///     .map(|v| cks.encrypt(*v))
+    ///     .collect::<Vec<_>>();
+    /// let index = cks.encrypt(1u8);
+    /// let value = cks.encrypt(42u8);
+    ///
+    /// sks.unchecked_write_at_index_parallelized(&mut cts, &index, &value);
+    /// let decrypted = cts
+    ///     .iter()
+    ///     .map(|ct| cks.decrypt::<u8>(ct))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(decrypted, vec![200, 42, 17]);
+    /// ```
+    pub fn unchecked_write_at_index_parallelized<T>(
+        &self,
+        cts: &mut [T],
+        index: &RadixCiphertext,
+        value: &T,
+    ) where
+        T: IntegerRadixCiphertext,
+    {
+        let selectors = self.compute_index_selectors(index, cts.len());
+        cts.par_iter_mut()
+            .zip(selectors.par_iter())
+            .for_each(|(ct, selector)| {
+                *ct = self.unchecked_if_then_else_parallelized(selector, value, ct);
+            });
+    }
+
+    /// Replaces the ciphertext at the encrypted `index` of the slice by `value`
+    ///
+    /// See [Self::unchecked_write_at_index_parallelized]
+    pub fn smart_write_at_index_parallelized<T>(
+        &self,
+        cts: &mut [T],
+        index: &mut RadixCiphertext,
+        value: &mut T,
+    ) where
+        T: IntegerRadixCiphertext,
+    {
+        if !index.block_carries_are_empty() {
+            self.full_propagate_parallelized(index);
+        }
+        if !value.block_carries_are_empty() {
+            self.full_propagate_parallelized(value);
+        }
+
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        self.unchecked_write_at_index_parallelized(cts, index, value);
+    }
+
+    /// Replaces the ciphertext at the encrypted `index` of the slice by `value`
+    ///
+    /// See [Self::unchecked_write_at_index_parallelized]
+    pub fn write_at_index_parallelized<T>(&self, cts: &mut [T], index: &RadixCiphertext, value: &T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_index;
+        let mut tmp_value;
+
+        let index = if index.block_carries_are_empty() {
+            index
+        } else {
+            tmp_index = index.clone();
+            self.full_propagate_parallelized(&mut tmp_index);
+            &tmp_index
+        };
+
+        let value = if value.block_carries_are_empty() {
+            value
+        } else {
+            tmp_value = value.clone();
+            self.full_propagate_parallelized(&mut tmp_value);
+            &tmp_value
+        };
+
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        self.unchecked_write_at_index_parallelized(cts, index, value);
+    }
+
+    /// Returns the encryption of `table[index]`, the result has `num_blocks` blocks
+    ///
+    /// The table can have more entries than what a single lookup table can hold: the index is
+    /// one-hot encoded, and the encrypted booleans of this encoding select the clear entries.
+    ///
+    /// - If the index is out of bounds, the returned ciphertext encrypts 0
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters do not have at least as much carry space as message space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// // The squares of 0..100, which do not fit in 8 bits
+    /// let table = (0..100u64).map(|i| i * i).collect::<Vec<_>>();
+    /// let index = cks.encrypt(42u8);
+    ///
+    /// let result = sks.unchecked_lookup_in_clears_parallelized(&index, &table, 8);
+    /// let decrypted: u64 = cks.decrypt(&result);
+    /// assert_eq!(decrypted, 42 * 42);
+    /// ```
+    pub fn unchecked_lookup_in_clears_parallelized<Clear>(
+        &self,
+        index: &RadixCiphertext,
+        table: &[Clear],
+        num_blocks: usize,
+    ) -> RadixCiphertext
+    where
+        Clear: Decomposable + CastInto<usize> + Copy + Send + Sync,
+    {
+        let num_addressable = self.num_addressable_elements(index, table.len());
+        if num_addressable == 0 || num_blocks == 0 {
+            return self.create_trivial_zero_radix(num_blocks);
+        }
+
+        let selectors =
+            self.compute_equality_selectors(index, (0..num_addressable as u64).into_par_iter());
+        let possible_results = self.create_possible_results(
+            num_blocks,
+            selectors
+                .into_par_iter()
+                .zip(table[..num_addressable].par_iter().copied()),
+        );
+
+        let result: RadixCiphertext = self.aggregate_one_hot_vector(possible_results);
+        self.cast_to_unsigned(result, num_blocks)
+    }
+
+    /// Returns the encryption of `table[index]`, the result has `num_blocks` blocks
+    ///
+    /// See [Self::unchecked_lookup_in_clears_parallelized]
+    pub fn smart_lookup_in_clears_parallelized<Clear>(
+        &self,
+        index: &mut RadixCiphertext,
+        table: &[Clear],
+        num_blocks: usize,
+    ) -> RadixCiphertext
+    where
+        Clear: Decomposable + CastInto<usize> + Copy + Send + Sync,
+    {
+        if !index.block_carries_are_empty() {
+            self.full_propagate_parallelized(index);
+        }
+
+        self.unchecked_lookup_in_clears_parallelized(index, table, num_blocks)
+    }
+
+    /// Returns the encryption of `table[index]`, the result has `num_blocks` blocks
+    ///
+    /// See [Self::unchecked_lookup_in_clears_parallelized]
+    pub fn lookup_in_clears_parallelized<Clear>(
+        &self,
+        index: &RadixCiphertext,
+        table: &[Clear],
+        num_blocks: usize,
+    ) -> RadixCiphertext
+    where
+        Clear: Decomposable + CastInto<usize> + Copy + Send + Sync,
+    {
+        if index.block_carries_are_empty() {
+            self.unchecked_lookup_in_clears_parallelized(index, table, num_blocks)
+        } else {
+            let mut clone = index.clone();
+            self.full_propagate_parallelized(&mut clone);
+            self.unchecked_lookup_in_clears_parallelized(&clone, table, num_blocks)
+        }
+    }
+}
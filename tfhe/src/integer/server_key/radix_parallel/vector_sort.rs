@@ -0,0 +1,569 @@
+use crate::integer::{BooleanBlock, IntegerRadixCiphertext, RadixCiphertext, ServerKey};
+
+use rayon::prelude::*;
+
+/// A layer of a sorting network, the comparators of a layer operate on disjoint indices
+type Layer = Vec<(usize, usize)>;
+
+/// Returns the layers of Batcher's odd-even merge sort network for `len` elements
+///
+/// Each comparator `(i, j)` has `i < j` and puts the smallest value at index `i`.
+///
+/// Contrary to the bitonic sort, all the comparators go in the same direction, so the network
+/// for a length that is not a power of two is the one of the next power of two without the
+/// comparators involving the missing elements (which would be greater than all the others).
+pub(crate) fn odd_even_merge_sort_layers(len: usize) -> Vec<Layer> {
+    let mut layers = Vec::new();
+
+    let mut p = 1;
+    while p < len {
+        let mut k = p;
+        while k >= 1 {
+            let mut layer = Vec::new();
+            let mut j = k % p;
+            while j + k < len {
+                for i in 0..k.min(len - j - k) {
+                    if (i + j) / (2 * p) == (i + j + k) / (2 * p) {
+                        layer.push((i + j, i + j + k));
+                    }
+                }
+                j += 2 * k;
+            }
+            if !layer.is_empty() {
+                layers.push(layer);
+            }
+            k /= 2;
+        }
+        p *= 2;
+    }
+
+    layers
+}
+
+/// Returns the layers of the odd-even merge sort network for `len` elements, restricted to the
+/// comparators that have an influence on the `k` last (i.e. greatest) outputs
+pub(crate) fn top_k_layers(len: usize, k: usize) -> Vec<Layer> {
+    let mut is_needed = vec![false; len];
+    is_needed[len - k.min(len)..].fill(true);
+
+    let mut layers = odd_even_merge_sort_layers(len);
+    for layer in layers.iter_mut().rev() {
+        layer.retain(|&(i, j)| is_needed[i] || is_needed[j]);
+        for &(i, j) in layer.iter() {
+            is_needed[i] = true;
+            is_needed[j] = true;
+        }
+    }
+    layers.retain(|layer| !layer.is_empty());
+
+    layers
+}
+
+/// Applies the layers of a sorting network, the comparators of a layer are computed in
+/// parallel
+fn apply_sorting_network<T, F>(mut items: Vec<T>, layers: &[Layer], compare_swap: F) -> Vec<T>
+where
+    T: Send + Sync,
+    F: Fn(&T, &T) -> (T, T) + Sync,
+{
+    for layer in layers {
+        let outputs = layer
+            .par_iter()
+            .map(|&(i, j)| compare_swap(&items[i], &items[j]))
+            .collect::<Vec<_>>();
+
+        for (&(i, j), (lower, upper)) in layer.iter().zip(outputs) {
+            items[i] = lower;
+            items[j] = upper;
+        }
+    }
+
+    items
+}
+
+impl ServerKey {
+    /// Returns `(rhs, lhs)` if `swap` encrypts `true`, `(lhs, rhs)` otherwise
+    fn unchecked_conditional_swap<T>(&self, swap: &BooleanBlock, lhs: &T, rhs: &T) -> (T, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        rayon::join(
+            || self.unchecked_if_then_else_parallelized(swap, rhs, lhs),
+            || self.unchecked_if_then_else_parallelized(swap, lhs, rhs),
+        )
+    }
+
+    /// Returns the ciphertexts sorted in ascending order
+    ///
+    /// The ciphertexts are sorted with Batcher's odd-even merge sort network, which needs
+    /// `O(n log²(n))` comparisons done in `O(log²(n))` layers, the comparisons of a layer being
+    /// computed in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let cts = [200u8, 3, 17]
+    ///     .iter()
+    ///     .map(|v| cks.encrypt(*v))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let sorted = sks.unchecked_sort_parallelized(&cts);
+    /// let sorted = sorted
+    ///     .iter()
+    ///     .map(|ct| cks.decrypt::<u8>(ct))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(sorted, vec![3, 17, 200]);
+    /// ```
+    pub fn unchecked_sort_parallelized<T>(&self, cts: &[T]) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let layers = odd_even_merge_sort_layers(cts.len());
+        apply_sorting_network(cts.to_vec(), &layers, |lhs, rhs| {
+            let swap = self.unchecked_gt_parallelized(lhs, rhs);
+            self.unchecked_conditional_swap(&swap, lhs, rhs)
+        })
+    }
+
+    /// Returns the ciphertexts sorted in ascending order
+    ///
+    /// See [Self::unchecked_sort_parallelized]
+    pub fn smart_sort_parallelized<T>(&self, cts: &mut [T]) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        self.unchecked_sort_parallelized(cts)
+    }
+
+    /// Returns the ciphertexts sorted in ascending order
+    ///
+    /// See [Self::unchecked_sort_parallelized]
+    pub fn sort_parallelized<T>(&self, cts: &[T]) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_cts;
+
+        let cts = if cts.iter().any(|ct| !ct.block_carries_are_empty()) {
+            tmp_cts = cts.to_vec();
+            tmp_cts
+                .par_iter_mut()
+                .filter(|ct| !ct.block_carries_are_empty())
+                .for_each(|ct| self.full_propagate_parallelized(ct));
+            &tmp_cts
+        } else {
+            cts
+        };
+
+        self.unchecked_sort_parallelized(cts)
+    }
+
+    /// Sorts the key-value pairs in ascending order of their keys
+    ///
+    /// Returns the sorted keys and the values in the same order, the values are moved with
+    /// their keys but never compared. The order of the pairs having equal keys is unspecified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices do not have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// // The bids, and the identifiers of the bidders
+    /// let bids = [30u8, 10, 20].iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    /// let bidders = [1u8, 2, 3].iter().map(|v| cks.encrypt(*v)).collect::<Vec<_>>();
+    ///
+    /// let (_, bidders) = sks.unchecked_sort_by_key_parallelized(&bids, &bidders);
+    /// let bidders = bidders
+    ///     .iter()
+    ///     .map(|ct| cks.decrypt::<u8>(ct))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(bidders, vec![2, 3, 1]);
+    /// ```
+    pub fn unchecked_sort_by_key_parallelized<K, V>(
+        &self,
+        keys: &[K],
+        values: &[V],
+    ) -> (Vec<K>, Vec<V>)
+    where
+        K: IntegerRadixCiphertext,
+        V: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            keys.len(),
+            values.len(),
+            "Cannot sort {} keys with {} values",
+            keys.len(),
+            values.len()
+        );
+
+        let layers = odd_even_merge_sort_layers(keys.len());
+        let pairs = keys.iter().cloned().zip(values.iter().cloned()).collect();
+        apply_sorting_network(
+            pairs,
+            &layers,
+            |(lhs_key, lhs_value), (rhs_key, rhs_value)| {
+                let swap = self.unchecked_gt_parallelized(lhs_key, rhs_key);
+                let ((lower_key, upper_key), (lower_value, upper_value)) = rayon::join(
+                    || self.unchecked_conditional_swap(&swap, lhs_key, rhs_key),
+                    || self.unchecked_conditional_swap(&swap, lhs_value, rhs_value),
+                );
+                ((lower_key, lower_value), (upper_key, upper_value))
+            },
+        )
+        .into_iter()
+        .unzip()
+    }
+
+    /// Sorts the key-value pairs in ascending order of their keys
+    ///
+    /// See [Self::unchecked_sort_by_key_parallelized]
+    pub fn smart_sort_by_key_parallelized<K, V>(
+        &self,
+        keys: &mut [K],
+        values: &mut [V],
+    ) -> (Vec<K>, Vec<V>)
+    where
+        K: IntegerRadixCiphertext,
+        V: IntegerRadixCiphertext,
+    {
+        rayon::join(
+            || {
+                keys.par_iter_mut()
+                    .filter(|ct| !ct.block_carries_are_empty())
+                    .for_each(|ct| self.full_propagate_parallelized(ct));
+            },
+            || {
+                values
+                    .par_iter_mut()
+                    .filter(|ct| !ct.block_carries_are_empty())
+                    .for_each(|ct| self.full_propagate_parallelized(ct));
+            },
+        );
+
+        self.unchecked_sort_by_key_parallelized(keys, values)
+    }
+
+    /// Sorts the key-value pairs in ascending order of their keys
+    ///
+    /// See [Self::unchecked_sort_by_key_parallelized]
+    pub fn sort_by_key_parallelized<K, V>(&self, keys: &[K], values: &[V]) -> (Vec<K>, Vec<V>)
+    where
+        K: IntegerRadixCiphertext,
+        V: IntegerRadixCiphertext,
+    {
+        let mut tmp_keys = vec![];
+        let mut tmp_values = vec![];
+
+        let (keys, values) = rayon::join(
+            || {
+                if keys.iter().any(|ct| !ct.block_carries_are_empty()) {
+                    tmp_keys = keys.to_vec();
+                    tmp_keys
+                        .par_iter_mut()
+                        .filter(|ct| !ct.block_carries_are_empty())
+                        .for_each(|ct| self.full_propagate_parallelized(ct));
+                    &tmp_keys
+                } else {
+                    keys
+                }
+            },
+            || {
+                if values.iter().any(|ct| !ct.block_carries_are_empty()) {
+                    tmp_values = values.to_vec();
+                    tmp_values
+                        .par_iter_mut()
+                        .filter(|ct| !ct.block_carries_are_empty())
+                        .for_each(|ct| self.full_propagate_parallelized(ct));
+                    &tmp_values
+                } else {
+                    values
+                }
+            },
+        );
+
+        self.unchecked_sort_by_key_parallelized(keys, values)
+    }
+
+    /// Reduces the ciphertexts with a tournament, keeping the element for which `is_better`
+    /// returns an encryption of `true` when compared to the current one
+    ///
+    /// The current element is always the one with the lowest index, so ties are resolved in
+    /// favor of the first element.
+    fn unchecked_arg_extremum_parallelized<T, F>(
+        &self,
+        cts: &[T],
+        is_better: F,
+    ) -> Option<(RadixCiphertext, T)>
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(&T, &T) -> BooleanBlock + Sync,
+    {
+        if cts.is_empty() {
+            return None;
+        }
+
+        let num_blocks_index =
+            (cts.len().ilog2() + 1).div_ceil(self.message_modulus().0.ilog2()) as usize;
+
+        let mut candidates = cts
+            .par_iter()
+            .enumerate()
+            .map(|(i, ct)| {
+                let index: RadixCiphertext = self.create_trivial_radix(i as u64, num_blocks_index);
+                (index, ct.clone())
+            })
+            .collect::<Vec<_>>();
+
+        while candidates.len() > 1 {
+            candidates = candidates
+                .par_chunks(2)
+                .map(|chunk| match chunk {
+                    [(current_index, current), (other_index, other)] => {
+                        let select_other = is_better(other, current);
+                        rayon::join(
+                            || {
+                                self.unchecked_if_then_else_parallelized(
+                                    &select_other,
+                                    other_index,
+                                    current_index,
+                                )
+                            },
+                            || {
+                                self.unchecked_if_then_else_parallelized(
+                                    &select_other,
+                                    other,
+                                    current,
+                                )
+                            },
+                        )
+                    }
+                    [last] => last.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        candidates.pop()
+    }
+
+    /// Returns the encrypted index of the smallest ciphertext, and the smallest ciphertext
+    ///
+    /// - If several ciphertexts are equal to the minimum, the index of the first one is returned
+    /// - Returns `None` if the slice is empty
+    ///
+    /// The minimum is computed with a tournament of `log2(n)` rounds, the comparisons of a round
+    /// being computed in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let cts = [200u8, 3, 17, 3]
+    ///     .iter()
+    ///     .map(|v| cks.encrypt(*v))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let (index, min) = sks.unchecked_argmin_parallelized(&cts).unwrap();
+    /// assert_eq!(cks.decrypt::<u8>(&index), 1);
+    /// assert_eq!(cks.decrypt::<u8>(&min), 3);
+    /// ```
+    pub fn unchecked_argmin_parallelized<T>(&self, cts: &[T]) -> Option<(RadixCiphertext, T)>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.unchecked_arg_extremum_parallelized(cts, |other, current| {
+            self.unchecked_lt_parallelized(other, current)
+        })
+    }
+
+    /// Returns the encrypted index of the smallest ciphertext, and the smallest ciphertext
+    ///
+    /// See [Self::unchecked_argmin_parallelized]
+    pub fn smart_argmin_parallelized<T>(&self, cts: &mut [T]) -> Option<(RadixCiphertext, T)>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        self.unchecked_argmin_parallelized(cts)
+    }
+
+    /// Returns the encrypted index of the smallest ciphertext, and the smallest ciphertext
+    ///
+    /// See [Self::unchecked_argmin_parallelized]
+    pub fn argmin_parallelized<T>(&self, cts: &[T]) -> Option<(RadixCiphertext, T)>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_cts;
+
+        let cts = if cts.iter().any(|ct| !ct.block_carries_are_empty()) {
+            tmp_cts = cts.to_vec();
+            tmp_cts
+                .par_iter_mut()
+                .filter(|ct| !ct.block_carries_are_empty())
+                .for_each(|ct| self.full_propagate_parallelized(ct));
+            &tmp_cts
+        } else {
+            cts
+        };
+
+        self.unchecked_argmin_parallelized(cts)
+    }
+
+    /// Returns the encrypted index of the greatest ciphertext, and the greatest ciphertext
+    ///
+    /// - If several ciphertexts are equal to the maximum, the index of the first one is returned
+    /// - Returns `None` if the slice is empty
+    ///
+    /// The maximum is computed with a tournament of `log2(n)` rounds, the comparisons of a round
+    /// being computed in parallel.
+    pub fn unchecked_argmax_parallelized<T>(&self, cts: &[T]) -> Option<(RadixCiphertext, T)>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.unchecked_arg_extremum_parallelized(cts, |other, current| {
+            self.unchecked_gt_parallelized(other, current)
+        })
+    }
+
+    /// Returns the encrypted index of the greatest ciphertext, and the greatest ciphertext
+    ///
+    /// See [Self::unchecked_argmax_parallelized]
+    pub fn smart_argmax_parallelized<T>(&self, cts: &mut [T]) -> Option<(RadixCiphertext, T)>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        self.unchecked_argmax_parallelized(cts)
+    }
+
+    /// Returns the encrypted index of the greatest ciphertext, and the greatest ciphertext
+    ///
+    /// See [Self::unchecked_argmax_parallelized]
+    pub fn argmax_parallelized<T>(&self, cts: &[T]) -> Option<(RadixCiphertext, T)>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_cts;
+
+        let cts = if cts.iter().any(|ct| !ct.block_carries_are_empty()) {
+            tmp_cts = cts.to_vec();
+            tmp_cts
+                .par_iter_mut()
+                .filter(|ct| !ct.block_carries_are_empty())
+                .for_each(|ct| self.full_propagate_parallelized(ct));
+            &tmp_cts
+        } else {
+            cts
+        };
+
+        self.unchecked_argmax_parallelized(cts)
+    }
+
+    /// Returns the `k` greatest ciphertexts, in descending order
+    ///
+    /// If `k` is greater than the number of ciphertexts, all of them are returned.
+    ///
+    /// Only the comparators of the sorting network that have an influence on the `k` greatest
+    /// outputs are computed, so the smaller `k` is, the fewer comparisons are needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let cts = [200u8, 3, 17, 42]
+    ///     .iter()
+    ///     .map(|v| cks.encrypt(*v))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let top = sks.unchecked_top_k_parallelized(&cts, 2);
+    /// let top = top.iter().map(|ct| cks.decrypt::<u8>(ct)).collect::<Vec<_>>();
+    /// assert_eq!(top, vec![200, 42]);
+    /// ```
+    pub fn unchecked_top_k_parallelized<T>(&self, cts: &[T], k: usize) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let k = k.min(cts.len());
+        let layers = top_k_layers(cts.len(), k);
+        let mut sorted = apply_sorting_network(cts.to_vec(), &layers, |lhs, rhs| {
+            let swap = self.unchecked_gt_parallelized(lhs, rhs);
+            self.unchecked_conditional_swap(&swap, lhs, rhs)
+        });
+
+        let mut top = sorted.split_off(cts.len() - k);
+        top.reverse();
+        top
+    }
+
+    /// Returns the `k` greatest ciphertexts, in descending order
+    ///
+    /// See [Self::unchecked_top_k_parallelized]
+    pub fn smart_top_k_parallelized<T>(&self, cts: &mut [T], k: usize) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        cts.par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        self.unchecked_top_k_parallelized(cts, k)
+    }
+
+    /// Returns the `k` greatest ciphertexts, in descending order
+    ///
+    /// See [Self::unchecked_top_k_parallelized]
+    pub fn top_k_parallelized<T>(&self, cts: &[T], k: usize) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_cts;
+
+        let cts = if cts.iter().any(|ct| !ct.block_carries_are_empty()) {
+            tmp_cts = cts.to_vec();
+            tmp_cts
+                .par_iter_mut()
+                .filter(|ct| !ct.block_carries_are_empty())
+                .for_each(|ct| self.full_propagate_parallelized(ct));
+            &tmp_cts
+        } else {
+            cts
+        };
+
+        self.unchecked_top_k_parallelized(cts, k)
+    }
+}
@@ -19,9 +19,14 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Serialize, Deserialize)]
 pub struct WopbsKey {
+    #[serde(with = "crate::versionable::nested")]
     wopbs_key: crate::shortint::wopbs::WopbsKey,
 }
 
+impl crate::named::Named for WopbsKey {
+    const NAME: &'static str = "integer::WopbsKey";
+}
+
 #[must_use]
 pub struct IntegerWopbsLUT {
     inner: WopbsLUTBase,
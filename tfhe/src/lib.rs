@@ -98,6 +98,13 @@ pub mod integer;
 /// cbindgen:ignore
 pub mod shortint;
 
+#[cfg(feature = "strings")]
+/// Welcome to the TFHE-rs [`strings`](`crate::strings`) module documentation!
+///
+/// # Special module attributes
+/// cbindgen:ignore
+pub mod strings;
+
 #[cfg(feature = "pbs-stats")]
 pub use shortint::server_key::pbs_stats::*;
 
@@ -127,6 +134,8 @@ pub mod keycache;
 
 pub mod safe_deserialization;
 
+pub mod versionable;
+
 pub mod conformance;
 
 pub mod named;
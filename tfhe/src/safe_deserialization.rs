@@ -1,19 +1,29 @@
 use crate::conformance::ParameterSetConformant;
 use crate::named::Named;
+use crate::versionable::{with_legacy_deserialization, Versionize, VersionsDispatch};
 use bincode::Options;
 use serde::de::DeserializeOwned;
-use serde::Serialize;
+use std::io::Read;
 
 // The `SERIALIZATION_VERSION` is serialized along objects serialized with `safe_serialize`.
-// This `SERIALIZATION_VERSION` should be changed on each release where any object serialization
-// details changes (this can happen when adding a field or reorderging fields of a struct).
+// This `SERIALIZATION_VERSION` should be changed on each release where the format written by
+// `safe_serialize` itself changes.
+// Changes in the layout of the serialized types do not require a new `SERIALIZATION_VERSION`
+// anymore, they are handled by the versions dispatch of each type (see the `versionable` module).
 // When a object is deserialized using `safe_deserialize`, the deserialized version is checked
-// to be equal to SERIALIZATION_VERSION.
-// This helps prevent users from inadvertently deserializaing an object serialized in another
-// release.
-// When this happens, it also gives a clear version mismatch error rather than a generic
+// to be one that this release knows how to read.
+// When this is not the case, it gives a clear version mismatch error rather than a generic
 // deserialization error or worse, a garbage object.
-const SERIALIZATION_VERSION: &str = "0.3";
+const SERIALIZATION_VERSION: &str = "0.4";
+
+// Objects serialized with this version were serialized without any version tag, neither on the
+// outermost type nor on the nested ones, the layout used at that time is the first variant (`V0`)
+// of the versions dispatch of each type.
+const LEGACY_UNVERSIONED_SERIALIZATION_VERSION: &str = "0.3";
+
+// Serialized enum variant index of the first variant, with the fixint encoding used by
+// `safe_serialize`
+const LEGACY_VERSION_TAG: [u8; 4] = 0u32.to_le_bytes();
 
 // `VERSION_LENGTH_LIMIT` is the maximum `SERIALIZATION_VERSION` size which `safe_deserialization`
 // is going to try to read (it returns an error if it's too big).
@@ -25,9 +35,12 @@ const TYPE_NAME_LENGTH_LIMIT: u64 = 1000;
 /// Serializes an object into a [writer](std::io::Write).
 /// The result contains a version of the serialization and the name of the
 /// serialized type to provide checks on deserialization with [safe_deserialize].
+///
+/// The object itself is serialized along its version tag (see [Versionize]) so that it can be
+/// upgraded when it is deserialized by a future release.
 /// `serialized_size_limit` is the size limit (in number of byte) of the serialized object
 /// (excluding version and name serialization).
-pub fn safe_serialize<T: Serialize + Named>(
+pub fn safe_serialize<T: Versionize + Named>(
     object: &T,
     mut writer: impl std::io::Write,
     serialized_size_limit: u64,
@@ -46,7 +59,7 @@ pub fn safe_serialize<T: Serialize + Named>(
 
     options
         .with_limit(serialized_size_limit)
-        .serialize_into(&mut writer, object)?;
+        .serialize_into(&mut writer, &object.versionize())?;
 
     Ok(())
 }
@@ -54,9 +67,12 @@ pub fn safe_serialize<T: Serialize + Named>(
 /// Deserializes an object serialized by `safe_serialize` from a [reader](std::io::Read).
 /// Checks that the serialization version and the name of the
 /// deserialized type are correct.
+///
+/// Objects serialized with an older layout of their type, including objects serialized
+/// by releases that did not version them, are upgraded to the current layout.
 /// `serialized_size_limit` is the size limit (in number of byte) of the serialized object
 /// (excluding version and name serialization).
-pub fn safe_deserialize<T: DeserializeOwned + Named>(
+pub fn safe_deserialize<T: Versionize + Named>(
     mut reader: impl std::io::Read,
     serialized_size_limit: u64,
 ) -> Result<T, String> {
@@ -69,9 +85,12 @@ pub fn safe_deserialize<T: DeserializeOwned + Named>(
         .deserialize_from::<_, String>(&mut reader)
         .map_err(|err| err.to_string())?;
 
-    if deserialized_version != SERIALIZATION_VERSION {
+    if deserialized_version != SERIALIZATION_VERSION
+        && deserialized_version != LEGACY_UNVERSIONED_SERIALIZATION_VERSION
+    {
         return Err(format!(
-            "On deserialization, expected serialization version {SERIALIZATION_VERSION}, got version {deserialized_version}"
+            "On deserialization, expected serialization version {SERIALIZATION_VERSION} \
+            or {LEGACY_UNVERSIONED_SERIALIZATION_VERSION}, got version {deserialized_version}"
         ));
     }
 
@@ -88,15 +107,37 @@ pub fn safe_deserialize<T: DeserializeOwned + Named>(
         ));
     }
 
+    let versioned: T::Versions<'_> = if deserialized_version == SERIALIZATION_VERSION {
+        deserialize_versioned(options, reader, serialized_size_limit)?
+    } else {
+        // Legacy objects have no version tag at any level, they and their nested versioned types
+        // are read as the first version of their type
+        with_legacy_deserialization(|| {
+            deserialize_versioned(
+                options,
+                LEGACY_VERSION_TAG.as_slice().chain(reader),
+                serialized_size_limit + LEGACY_VERSION_TAG.len() as u64,
+            )
+        })?
+    };
+
+    versioned.unversionize().map_err(|err| err.to_string())
+}
+
+fn deserialize_versioned<V: DeserializeOwned>(
+    options: impl Options,
+    reader: impl std::io::Read,
+    serialized_size_limit: u64,
+) -> Result<V, String> {
     options
         .with_limit(serialized_size_limit)
-        .deserialize_from(&mut reader)
+        .deserialize_from(reader)
         .map_err(|err| err.to_string())
 }
 
 /// Deserializes an object with [safe_deserialize] and checks than it is conformant with the given
 /// parameter set
-pub fn safe_deserialize_conformant<T: DeserializeOwned + Named + ParameterSetConformant>(
+pub fn safe_deserialize_conformant<T: Versionize + Named + ParameterSetConformant>(
     reader: impl std::io::Read,
     serialized_size_limit: u64,
     parameter_set: &T::ParameterSet,
@@ -115,11 +156,19 @@ pub fn safe_deserialize_conformant<T: DeserializeOwned + Named + ParameterSetCon
 
 #[cfg(all(test, feature = "shortint"))]
 mod test_shortint {
-    use crate::safe_deserialization::{safe_deserialize_conformant, safe_serialize};
+    use crate::core_crypto::entities::LweCiphertextOwned;
+    use crate::named::Named;
+    use crate::safe_deserialization::{
+        safe_deserialize_conformant, safe_serialize, LEGACY_UNVERSIONED_SERIALIZATION_VERSION,
+    };
+    use crate::shortint::ciphertext::{Degree, NoiseLevel};
     use crate::shortint::parameters::{
-        PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+        CarryModulus, MessageModulus, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MESSAGE_3_CARRY_3_KS_PBS,
     };
-    use crate::shortint::{gen_keys, Ciphertext};
+    use crate::shortint::{gen_keys, Ciphertext, PBSOrder};
+    use bincode::Options;
+    use serde::Serialize;
 
     #[test]
     fn safe_desererialization_ct() {
@@ -150,6 +199,62 @@ mod test_shortint {
         let dec = ck.decrypt(&ct2);
         assert_eq!(msg, dec);
     }
+
+    #[test]
+    fn safe_desererialization_legacy_ct() {
+        // Layout of the ciphertext before the versioning, neither the ciphertext nor the inner
+        // LWE ciphertext have a version tag
+        #[derive(Serialize)]
+        struct LegacyCiphertext<'a> {
+            ct: &'a LweCiphertextOwned<u64>,
+            degree: Degree,
+            noise_level: NoiseLevel,
+            message_modulus: MessageModulus,
+            carry_modulus: CarryModulus,
+            pbs_order: PBSOrder,
+        }
+
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+        let msg = 2_u64;
+
+        let ct = ck.encrypt(msg);
+
+        let options = bincode::DefaultOptions::new().with_fixint_encoding();
+
+        let mut buffer = vec![];
+
+        options
+            .serialize_into(&mut buffer, LEGACY_UNVERSIONED_SERIALIZATION_VERSION)
+            .unwrap();
+        options
+            .serialize_into(&mut buffer, Ciphertext::NAME)
+            .unwrap();
+        options
+            .serialize_into(
+                &mut buffer,
+                &LegacyCiphertext {
+                    ct: &ct.ct,
+                    degree: ct.degree,
+                    noise_level: ct.noise_level(),
+                    message_modulus: ct.message_modulus,
+                    carry_modulus: ct.carry_modulus,
+                    pbs_order: ct.pbs_order,
+                },
+            )
+            .unwrap();
+
+        let ct2: Ciphertext = safe_deserialize_conformant(
+            buffer.as_slice(),
+            1 << 20,
+            &PARAM_MESSAGE_2_CARRY_2_KS_PBS.to_shortint_conformance_param(),
+        )
+        .unwrap();
+
+        let dec = ck.decrypt(&ct2);
+        assert_eq!(msg, dec);
+        assert_eq!(ct2.degree, ct.degree);
+    }
 }
 
 #[cfg(all(test, feature = "integer"))]
@@ -246,4 +351,61 @@ mod test_integer {
 
         assert_eq!(&msg[..], &dec);
     }
+
+    fn legacy_serialize<T: serde::Serialize>(name: &str, object: &T) -> Vec<u8> {
+        use bincode::Options;
+
+        let options = bincode::DefaultOptions::new().with_fixint_encoding();
+        let mut buffer = vec![];
+
+        options
+            .serialize_into(&mut buffer, super::LEGACY_UNVERSIONED_SERIALIZATION_VERSION)
+            .unwrap();
+        options.serialize_into(&mut buffer, name).unwrap();
+        options.serialize_into(&mut buffer, object).unwrap();
+
+        buffer
+    }
+
+    #[test]
+    fn safe_deserialization_legacy_unversioned() {
+        use crate::high_level_api::backward_compatibility::{ClientKeyV0, IntegerClientKeyV0};
+        use crate::named::Named;
+        use crate::safe_deserialization::safe_deserialize;
+        use crate::ClientKey;
+
+        let client_key = ClientKey::generate(ConfigBuilder::default().build());
+
+        // Ciphertexts serialized without version tag
+        let ct = FheUint8::encrypt(27u8, &client_key);
+        let buffer = legacy_serialize(FheUint8::NAME, &ct);
+        let ct2: FheUint8 = safe_deserialize(buffer.as_slice(), 1 << 20).unwrap();
+        let dec: u8 = ct2.decrypt(&client_key);
+        assert_eq!(dec, 27);
+
+        // Keys serialized with their layout before compression was added
+        let legacy_key = ClientKeyV0 {
+            key: IntegerClientKeyV0 {
+                key: client_key.key.key.clone(),
+                wopbs_block_parameters: client_key.key.wopbs_block_parameters,
+            },
+        };
+        let buffer = legacy_serialize(ClientKey::NAME, &legacy_key);
+        let client_key2: ClientKey = safe_deserialize(buffer.as_slice(), 1 << 30).unwrap();
+        assert!(client_key2.key.compression_key.is_none());
+        let dec: u8 = ct.decrypt(&client_key2);
+        assert_eq!(dec, 27);
+
+        // Current layout
+        let mut buffer = vec![];
+        safe_serialize(&client_key, &mut buffer, 1 << 30).unwrap();
+        let client_key3: ClientKey = safe_deserialize(buffer.as_slice(), 1 << 30).unwrap();
+        let dec: u8 = ct.decrypt(&client_key3);
+        assert_eq!(dec, 27);
+
+        // Unknown serialization versions are still rejected
+        let mut buffer = legacy_serialize(FheUint8::NAME, &ct);
+        buffer[8..11].copy_from_slice(b"0.2");
+        assert!(safe_deserialize::<FheUint8>(buffer.as_slice(), 1 << 20).is_err());
+    }
 }
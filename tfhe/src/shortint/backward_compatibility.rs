@@ -0,0 +1,158 @@
+//! Versions of the serialized shortint types, see [`crate::versionable`].
+
+use crate::shortint::ciphertext::{
+    CompactCiphertextList, CompressedCiphertext, CompressedModulusSwitchedCiphertext,
+};
+use crate::shortint::list_compression::{
+    CompressedCiphertextList, CompressedCompressionKey, CompressedDecompressionKey, CompressionKey,
+    CompressionPrivateKeys, DecompressionKey,
+};
+use crate::shortint::wopbs::WopbsKey;
+use crate::shortint::{
+    Ciphertext, ClientKey, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey,
+    CompressedServerKey, KeySwitchingKey, PublicKey, ServerKey,
+};
+use crate::versionable::versions_dispatch;
+
+versions_dispatch! {
+    pub enum CiphertextVersions for Ciphertext {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedCiphertextVersions for CompressedCiphertext {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedModulusSwitchedCiphertextVersions for CompressedModulusSwitchedCiphertext {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompactCiphertextListVersions for CompactCiphertextList {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum ClientKeyVersions for ClientKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum ServerKeyVersions for ServerKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedServerKeyVersions for CompressedServerKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum PublicKeyVersions for PublicKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedPublicKeyVersions for CompressedPublicKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompactPublicKeyVersions for CompactPublicKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedCompactPublicKeyVersions for CompressedCompactPublicKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum KeySwitchingKeyVersions for KeySwitchingKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum WopbsKeyVersions for WopbsKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressionPrivateKeysVersions for CompressionPrivateKeys {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressionKeyVersions for CompressionKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum DecompressionKeyVersions for DecompressionKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedCompressionKeyVersions for CompressedCompressionKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedDecompressionKeyVersions for CompressedDecompressionKey {
+        #[current]
+        V0,
+    }
+}
+
+versions_dispatch! {
+    pub enum CompressedCiphertextListVersions for CompressedCiphertextList {
+        #[current]
+        V0,
+    }
+}
+
+#[cfg(feature = "zk-pok-experimental")]
+versions_dispatch! {
+    pub enum ProvenCompactCiphertextListVersions
+        for crate::shortint::ciphertext::ProvenCompactCiphertextList
+    {
+        #[current]
+        V0,
+    }
+}
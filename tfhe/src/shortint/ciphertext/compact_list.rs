@@ -12,6 +12,7 @@ use std::fmt::Debug;
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CompactCiphertextList {
+    #[serde(with = "crate::versionable::nested")]
     pub ct_list: LweCompactCiphertextListOwned<u64>,
     pub degree: Degree,
     pub message_modulus: MessageModulus,
@@ -20,6 +21,10 @@ pub struct CompactCiphertextList {
     pub noise_level: NoiseLevel,
 }
 
+impl crate::named::Named for CompactCiphertextList {
+    const NAME: &'static str = "shortint::CompactCiphertextList";
+}
+
 impl ParameterSetConformant for CompactCiphertextList {
     type ParameterSet = CiphertextListConformanceParams;
 
@@ -101,6 +106,7 @@ impl CompactCiphertextList {
                     carry_modulus: self.carry_modulus,
                     pbs_order,
                     noise_level: self.noise_level,
+                    noise_variance: None,
                 }
             })
             .collect::<Vec<_>>()
@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 /// Internally, it uses a LWE ciphertext.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct CompressedCiphertext {
+    #[serde(with = "crate::versionable::nested")]
     pub ct: SeededLweCiphertext<u64>,
     pub degree: Degree,
     pub message_modulus: MessageModulus,
@@ -20,6 +21,10 @@ pub struct CompressedCiphertext {
     pub noise_level: NoiseLevel,
 }
 
+impl crate::named::Named for CompressedCiphertext {
+    const NAME: &'static str = "shortint::CompressedCiphertext";
+}
+
 impl ParameterSetConformant for CompressedCiphertext {
     type ParameterSet = CiphertextConformanceParams;
 
@@ -51,6 +56,7 @@ impl CompressedCiphertext {
             carry_modulus: *carry_modulus,
             pbs_order: *pbs_order,
             noise_level: *noise_level,
+            noise_variance: None,
         }
     }
 
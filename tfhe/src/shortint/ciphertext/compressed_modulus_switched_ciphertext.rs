@@ -2,7 +2,7 @@ use super::common::*;
 use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::prelude::compressed_modulus_switched_lwe_ciphertext::CompressedModulusSwitchedLweCiphertext;
 use crate::core_crypto::prelude::compressed_modulus_switched_multi_bit_lwe_ciphertext::CompressedModulusSwitchedMultiBitLweCiphertext;
-use crate::core_crypto::prelude::LweCiphertextParameters;
+use crate::core_crypto::prelude::{CiphertextModulus, LweCiphertextParameters};
 use crate::shortint::parameters::CiphertextConformanceParams;
 use crate::shortint::{CarryModulus, MessageModulus};
 
@@ -41,6 +41,10 @@ pub struct CompressedModulusSwitchedCiphertext {
     pub(crate) pbs_order: PBSOrder,
 }
 
+impl crate::named::Named for CompressedModulusSwitchedCiphertext {
+    const NAME: &'static str = "shortint::CompressedModulusSwitchedCiphertext";
+}
+
 impl ParameterSetConformant for CompressedModulusSwitchedCiphertext {
     type ParameterSet = CiphertextConformanceParams;
 
@@ -56,17 +60,34 @@ impl ParameterSetConformant for CompressedModulusSwitchedCiphertext {
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub(crate) enum InternalCompressedModulusSwitchedCiphertext {
-    Classic(CompressedModulusSwitchedLweCiphertext<u64>),
+    Classic(
+        #[serde(with = "crate::versionable::nested")] CompressedModulusSwitchedLweCiphertext<u64>,
+    ),
     MultiBit(CompressedModulusSwitchedMultiBitLweCiphertext<u64>),
+    // Compressed after a keyswitch to 32 bits, see ServerKey::enable_32_bits_keyswitch
+    Classic32(
+        #[serde(with = "crate::versionable::nested")] CompressedModulusSwitchedLweCiphertext<u32>,
+    ),
+    MultiBit32(CompressedModulusSwitchedMultiBitLweCiphertext<u32>),
 }
 
 impl ParameterSetConformant for InternalCompressedModulusSwitchedCiphertext {
     type ParameterSet = LweCiphertextParameters<u64>;
 
     fn is_conformant(&self, param: &LweCiphertextParameters<u64>) -> bool {
+        // The 32 bits variants are compressed from ciphertexts keyswitched to the native u32
+        // modulus
+        let param_32 = LweCiphertextParameters {
+            lwe_dim: param.lwe_dim,
+            ct_modulus: CiphertextModulus::<u32>::new_native(),
+            ms_decompression_method: param.ms_decompression_method,
+        };
+
         match self {
             Self::Classic(a) => a.is_conformant(param),
             Self::MultiBit(a) => a.is_conformant(param),
+            Self::Classic32(a) => a.is_conformant(&param_32),
+            Self::MultiBit32(a) => a.is_conformant(&param_32),
         }
     }
 }
@@ -2,20 +2,26 @@
 use super::super::parameters::CiphertextConformanceParams;
 use super::common::*;
 use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::commons::dispersion::Variance;
 use crate::core_crypto::entities::*;
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[must_use]
 pub struct Ciphertext {
+    #[serde(with = "crate::versionable::nested")]
     pub ct: LweCiphertextOwned<u64>,
     pub degree: Degree,
     pub(crate) noise_level: NoiseLevel,
     pub message_modulus: MessageModulus,
     pub carry_modulus: CarryModulus,
     pub pbs_order: PBSOrder,
+    // Estimation of the noise variance, only available when noise tracking is enabled on the
+    // ServerKey, it is not serialized
+    #[serde(skip)]
+    pub(crate) noise_variance: Option<Variance>,
 }
 
 impl crate::named::Named for Ciphertext {
@@ -35,6 +41,31 @@ impl ParameterSetConformant for Ciphertext {
     }
 }
 
+// The noise variance is an estimation which is not serialized, it is not taken into account when
+// comparing ciphertexts
+impl PartialEq for Ciphertext {
+    fn eq(&self, other: &Self) -> bool {
+        let Self {
+            ct,
+            degree,
+            noise_level,
+            message_modulus,
+            carry_modulus,
+            pbs_order,
+            noise_variance: _,
+        } = self;
+
+        *ct == other.ct
+            && *degree == other.degree
+            && *noise_level == other.noise_level
+            && *message_modulus == other.message_modulus
+            && *carry_modulus == other.carry_modulus
+            && *pbs_order == other.pbs_order
+    }
+}
+
+impl Eq for Ciphertext {}
+
 // Use destructuring to also have a compile error
 // if ever a new member is added to Ciphertext
 // and is not handled here.
@@ -49,6 +80,7 @@ impl Clone for Ciphertext {
             carry_modulus: src_carry_modulus,
             pbs_order: src_pbs_order,
             noise_level: src_noise_level,
+            noise_variance: src_noise_variance,
         } = self;
 
         Self {
@@ -58,6 +90,7 @@ impl Clone for Ciphertext {
             carry_modulus: *src_carry_modulus,
             pbs_order: *src_pbs_order,
             noise_level: *src_noise_level,
+            noise_variance: *src_noise_variance,
         }
     }
 
@@ -69,6 +102,7 @@ impl Clone for Ciphertext {
             carry_modulus: dst_carry_modulus,
             pbs_order: dst_pbs_order,
             noise_level: dst_noise_level,
+            noise_variance: dst_noise_variance,
         } = self;
 
         let Self {
@@ -78,6 +112,7 @@ impl Clone for Ciphertext {
             carry_modulus: src_carry_modulus,
             pbs_order: src_pbs_order,
             noise_level: src_noise_level,
+            noise_variance: src_noise_variance,
         } = source;
 
         if dst_ct.ciphertext_modulus() != src_ct.ciphertext_modulus()
@@ -92,6 +127,7 @@ impl Clone for Ciphertext {
         *dst_carry_modulus = *src_carry_modulus;
         *dst_pbs_order = *src_pbs_order;
         *dst_noise_level = *src_noise_level;
+        *dst_noise_variance = *src_noise_variance;
     }
 }
 
@@ -104,6 +140,9 @@ impl Ciphertext {
         carry_modulus: CarryModulus,
         pbs_order: PBSOrder,
     ) -> Self {
+        // Trivial ciphertexts have no noise, otherwise the noise is unknown
+        let noise_variance = (noise_level == NoiseLevel::ZERO).then_some(Variance(0.0));
+
         Self {
             ct,
             degree,
@@ -111,6 +150,7 @@ impl Ciphertext {
             message_modulus,
             carry_modulus,
             pbs_order,
+            noise_variance,
         }
     }
     pub fn carry_is_empty(&self) -> bool {
@@ -130,6 +170,20 @@ impl Ciphertext {
         self.noise_level = noise_level;
     }
 
+    /// Returns the estimated variance of the noise of the ciphertext, on the torus.
+    ///
+    /// The estimation is only available for ciphertexts encrypted by a
+    /// [`ClientKey`](crate::shortint::ClientKey), trivial ciphertexts and ciphertexts which
+    /// went through operations of a [`ServerKey`](crate::shortint::ServerKey) with noise tracking
+    /// enabled (see [`ServerKey::enable_noise_tracking`](crate::shortint::ServerKey::enable_noise_tracking)).
+    pub fn noise_variance(&self) -> Option<Variance> {
+        self.noise_variance
+    }
+
+    pub fn set_noise_variance(&mut self, noise_variance: Option<Variance>) {
+        self.noise_variance = noise_variance;
+    }
+
     /// Decrypts a trivial ciphertext
     ///
     /// Trivial ciphertexts are ciphertexts which are not encrypted
@@ -230,6 +284,7 @@ mod tests {
             carry_modulus: CarryModulus(1),
             pbs_order: PBSOrder::KeyswitchBootstrap,
             noise_level: NoiseLevel::NOMINAL,
+            noise_variance: None,
         };
 
         let c2 = Ciphertext {
@@ -242,12 +297,14 @@ mod tests {
             carry_modulus: CarryModulus(2),
             pbs_order: PBSOrder::BootstrapKeyswitch,
             noise_level: NoiseLevel::NOMINAL,
+            noise_variance: Some(Variance(1.0)),
         };
 
         assert_ne!(c1, c2);
 
         c1.clone_from(&c2);
         assert_eq!(c1, c2);
+        assert_eq!(c1.noise_variance(), c2.noise_variance());
     }
 
     #[test]
@@ -262,6 +319,7 @@ mod tests {
             carry_modulus: CarryModulus(1),
             pbs_order: PBSOrder::KeyswitchBootstrap,
             noise_level: NoiseLevel::NOMINAL,
+            noise_variance: None,
         };
 
         let c2 = Ciphertext {
@@ -274,12 +332,14 @@ mod tests {
             carry_modulus: CarryModulus(2),
             pbs_order: PBSOrder::BootstrapKeyswitch,
             noise_level: NoiseLevel::NOMINAL,
+            noise_variance: Some(Variance(1.0)),
         };
 
         assert_ne!(c1, c2);
 
         c1.clone_from(&c2);
         assert_eq!(c1, c2);
+        assert_eq!(c1.noise_variance(), c2.noise_variance());
     }
 
     #[test]
@@ -294,6 +354,7 @@ mod tests {
             carry_modulus: CarryModulus(1),
             pbs_order: PBSOrder::KeyswitchBootstrap,
             noise_level: NoiseLevel::NOMINAL,
+            noise_variance: None,
         };
 
         let c2 = Ciphertext {
@@ -306,11 +367,13 @@ mod tests {
             carry_modulus: CarryModulus(2),
             pbs_order: PBSOrder::BootstrapKeyswitch,
             noise_level: NoiseLevel::NOMINAL,
+            noise_variance: Some(Variance(1.0)),
         };
 
         assert_ne!(c1, c2);
 
         c1.clone_from(&c2);
         assert_eq!(c1, c2);
+        assert_eq!(c1.noise_variance(), c2.noise_variance());
     }
 }
@@ -77,6 +77,10 @@ pub struct ProvenCompactCiphertextList {
     pub(crate) proved_lists: Vec<(CompactCiphertextList, CompactPkeProof)>,
 }
 
+impl crate::named::Named for ProvenCompactCiphertextList {
+    const NAME: &'static str = "shortint::ProvenCompactCiphertextList";
+}
+
 impl ProvenCompactCiphertextList {
     pub fn ciphertext_count(&self) -> usize {
         self.proved_lists
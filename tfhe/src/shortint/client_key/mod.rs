@@ -1,6 +1,7 @@
 //! Module with the definition of the ClientKey.
 
 use super::PBSOrder;
+use crate::core_crypto::commons::dispersion::Variance;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::prelude::decrypt_lwe_ciphertext;
 use crate::shortint::ciphertext::{Ciphertext, CompressedCiphertext};
@@ -8,6 +9,7 @@ use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::{
     DynamicDistribution, EncryptionKeyChoice, MessageModulus, ShortintParameterSet,
 };
+use crate::shortint::server_key::ciphertext_modulus_log2;
 use crate::shortint::CarryModulus;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -22,12 +24,18 @@ use std::fmt::Debug;
 /// * `parameters` - the cryptographic parameter set.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClientKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) glwe_secret_key: GlweSecretKeyOwned<u64>,
     /// Key used as the output of the keyswitch operation
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) lwe_secret_key: LweSecretKeyOwned<u64>,
     pub parameters: ShortintParameterSet,
 }
 
+impl crate::named::Named for ClientKey {
+    const NAME: &'static str = "shortint::ClientKey";
+}
+
 impl ClientKey {
     /// Generate a client key.
     ///
@@ -76,6 +84,14 @@ impl ClientKey {
         }
     }
 
+    /// Returns the variance, on the torus, of the noise of ciphertexts encrypted with this key
+    pub(crate) fn encryption_noise_variance(&self) -> Variance {
+        let (_, encryption_noise_distribution) = self.encryption_key_and_noise();
+        encryption_noise_distribution.variance(ciphertext_modulus_log2(
+            self.parameters.ciphertext_modulus(),
+        ))
+    }
+
     /// Deconstruct a [`ClientKey`] into its constituents.
     ///
     /// # Example
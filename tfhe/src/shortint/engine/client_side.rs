@@ -111,14 +111,16 @@ impl ShortintEngine {
             * client_key.parameters.carry_modulus().0)
             / message_modulus.0;
 
-        Ciphertext::new(
+        let mut ciphertext = Ciphertext::new(
             ct,
             Degree::new(message_modulus.0 - 1),
             NoiseLevel::NOMINAL,
             message_modulus,
             CarryModulus(carry_modulus),
             params_op_order,
-        )
+        );
+        ciphertext.set_noise_variance(Some(client_key.encryption_noise_variance()));
+        ciphertext
     }
 
     pub(crate) fn encrypt_with_message_and_carry_modulus(
@@ -149,14 +151,16 @@ impl ShortintEngine {
             message_modulus,
         );
 
-        Ciphertext::new(
+        let mut ciphertext = Ciphertext::new(
             ct,
             Degree::new(message_modulus.0 - 1),
             NoiseLevel::NOMINAL,
             message_modulus,
             carry_modulus,
             params_op_order,
-        )
+        );
+        ciphertext.set_noise_variance(Some(client_key.encryption_noise_variance()));
+        ciphertext
     }
 
     pub(crate) fn encrypt_with_message_modulus_compressed(
@@ -227,7 +231,7 @@ impl ShortintEngine {
             &mut self.encryption_generator,
         );
 
-        Ciphertext::new(
+        let mut ciphertext = Ciphertext::new(
             ct,
             Degree::new(
                 client_key.parameters.message_modulus().0 * client_key.parameters.carry_modulus().0
@@ -237,7 +241,9 @@ impl ShortintEngine {
             client_key.parameters.message_modulus(),
             client_key.parameters.carry_modulus(),
             params_op_order,
-        )
+        );
+        ciphertext.set_noise_variance(Some(client_key.encryption_noise_variance()));
+        ciphertext
     }
 
     pub(crate) fn encrypt_without_padding(
@@ -268,14 +274,16 @@ impl ShortintEngine {
             &mut self.encryption_generator,
         );
 
-        Ciphertext::new(
+        let mut ciphertext = Ciphertext::new(
             ct,
             Degree::new(client_key.parameters.message_modulus().0 - 1),
             NoiseLevel::NOMINAL,
             client_key.parameters.message_modulus(),
             client_key.parameters.carry_modulus(),
             params_op_order,
-        )
+        );
+        ciphertext.set_noise_variance(Some(client_key.encryption_noise_variance()));
+        ciphertext
     }
 
     pub(crate) fn encrypt_without_padding_compressed(
@@ -341,14 +349,16 @@ impl ShortintEngine {
             &mut self.encryption_generator,
         );
 
-        Ciphertext::new(
+        let mut ciphertext = Ciphertext::new(
             ct,
             Degree::new(message_modulus as usize - 1),
             NoiseLevel::NOMINAL,
             MessageModulus(message_modulus as usize),
             CarryModulus(carry_modulus),
             params_op_order,
-        )
+        );
+        ciphertext.set_noise_variance(Some(client_key.encryption_noise_variance()));
+        ciphertext
     }
 
     pub(crate) fn encrypt_native_crt_compressed(
@@ -11,7 +11,7 @@ use crate::core_crypto::commons::generators::{
 use crate::core_crypto::commons::math::random::RandomGenerator;
 use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seeder};
 use crate::core_crypto::entities::*;
-use crate::core_crypto::prelude::{ContainerMut, GlweSize};
+use crate::core_crypto::prelude::{CiphertextModulus, ContainerMut, GlweSize};
 use crate::core_crypto::seeders::new_seeder;
 use crate::shortint::ciphertext::{Degree, MaxDegree};
 use crate::shortint::prelude::PolynomialSize;
@@ -33,11 +33,14 @@ pub struct BuffersRef<'a> {
     pub(crate) buffer_lwe_after_ks: LweCiphertextMutView<'a, u64>,
     // For the intermediate PBS result in the case of a smallciphertext
     pub(crate) buffer_lwe_after_pbs: LweCiphertextMutView<'a, u64>,
+    // For the intermediate keyswitch result when keyswitching to 32 bits ciphertexts
+    pub(crate) buffer_lwe_after_ks_32: LweCiphertextMutView<'a, u32>,
 }
 
 #[derive(Default)]
 struct Memory {
     buffer: Vec<u64>,
+    buffer_32: Vec<u32>,
 }
 
 impl Memory {
@@ -66,9 +69,19 @@ impl Memory {
         let buffer_lwe_after_pbs =
             LweCiphertextMutView::from_container(after_pbs_elements, server_key.ciphertext_modulus);
 
+        if self.buffer_32.len() < num_elem_in_lwe_after_ks {
+            self.buffer_32.resize(num_elem_in_lwe_after_ks, 0u32);
+        }
+
+        let buffer_lwe_after_ks_32 = LweCiphertextMutView::from_container(
+            &mut self.buffer_32[..num_elem_in_lwe_after_ks],
+            CiphertextModulus::new_native(),
+        );
+
         BuffersRef {
             buffer_lwe_after_ks,
             buffer_lwe_after_pbs,
+            buffer_lwe_after_ks_32,
         }
     }
 }
@@ -150,6 +150,8 @@ impl ShortintEngine {
             max_noise_level: cks.parameters.max_noise_level(),
             ciphertext_modulus: cks.parameters.ciphertext_modulus(),
             pbs_order: cks.parameters.encryption_key_choice().into(),
+            noise_model: None,
+            key_switching_key_32: None,
         }
     }
 
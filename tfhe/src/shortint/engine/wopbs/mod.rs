@@ -151,6 +151,8 @@ impl ShortintEngine {
             max_noise_level: max_noise_level_wopbs,
             ciphertext_modulus: parameters.ciphertext_modulus,
             pbs_order: cks.parameters.encryption_key_choice().into(),
+            noise_model: None,
+            key_switching_key_32: None,
         };
 
         let max_noise_level_pbs = MaxNoiseLevel::from_msg_carry_modulus(
@@ -170,6 +172,8 @@ impl ShortintEngine {
             max_noise_level: max_noise_level_pbs,
             ciphertext_modulus: cks.parameters.ciphertext_modulus(),
             pbs_order: cks.parameters.encryption_key_choice().into(),
+            noise_model: None,
+            key_switching_key_32: None,
         };
 
         WopbsKey {
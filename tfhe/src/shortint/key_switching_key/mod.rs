@@ -3,6 +3,7 @@
 //! - [KeySwitchingKey] allows switching the keys of a ciphertext, from a cleitn key to another.
 
 use crate::core_crypto::prelude::{keyswitch_lwe_ciphertext, LweKeyswitchKeyOwned};
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::ShortintKeySwitchingParameters;
 use crate::shortint::{Ciphertext, ClientKey, ServerKey};
@@ -17,12 +18,19 @@ mod test;
 /// sends it to the server so it can cast from one set of parameters to another.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct KeySwitchingKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) key_switching_key: LweKeyswitchKeyOwned<u64>,
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) dest_server_key: ServerKey,
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) src_server_key: ServerKey,
     pub cast_rshift: i8,
 }
 
+impl crate::named::Named for KeySwitchingKey {
+    const NAME: &'static str = "shortint::KeySwitchingKey";
+}
+
 impl KeySwitchingKey {
     /// Generate a casting key. This can cast to several kinds of keys (shortint, integer, hlapi),
     /// depending on input.
@@ -218,6 +226,18 @@ impl KeySwitchingKey {
 
             _ => unreachable!(),
         };
+
+        // The value is kept as long as it fits in the destination, the bits above are lost
+        // when casting to a smaller bit length
+        let dest_full_modulus =
+            self.dest_server_key.message_modulus.0 * self.dest_server_key.carry_modulus.0;
+        ct_dest.degree = Degree::new(ct.degree.get().min(dest_full_modulus - 1));
+        ct_dest.set_noise_level(NoiseLevel::NOMINAL);
+        // The noise of the key switching key is unknown, only a PBS done with the destination key
+        // gives a known noise
+        if self.cast_rshift <= 0 {
+            ct_dest.set_noise_variance(None);
+        }
     }
 
     /// Cast a ciphertext from the source parameter set to the dest parameter set,
@@ -0,0 +1,142 @@
+use super::{CompressionKey, CompressionPrivateKeys, DecompressionKey};
+use crate::core_crypto::prelude::{
+    allocate_and_generate_new_seeded_lwe_packing_keyswitch_key,
+    par_allocate_and_generate_new_seeded_lwe_bootstrap_key,
+    par_convert_standard_lwe_bootstrap_key_to_fourier, CiphertextModulusLog,
+    FourierLweBootstrapKeyOwned, LweCiphertextCount, SeededLweBootstrapKeyOwned,
+    SeededLwePackingKeyswitchKeyOwned,
+};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::ClientKey;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// A seeded version of a [`CompressionKey`], smaller in size but which needs to be decompressed
+/// before use.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedCompressionKey {
+    #[serde(with = "crate::versionable::nested")]
+    pub packing_key_switching_key: SeededLwePackingKeyswitchKeyOwned<u64>,
+    pub lwe_per_glwe: LweCiphertextCount,
+    pub storage_log_modulus: CiphertextModulusLog,
+}
+
+impl crate::named::Named for CompressedCompressionKey {
+    const NAME: &'static str = "shortint::CompressedCompressionKey";
+}
+
+/// A seeded version of a [`DecompressionKey`], smaller in size but which needs to be
+/// decompressed before use.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedDecompressionKey {
+    #[serde(with = "crate::versionable::nested")]
+    pub blind_rotate_key: SeededLweBootstrapKeyOwned<u64>,
+    pub lwe_per_glwe: LweCiphertextCount,
+}
+
+impl crate::named::Named for CompressedDecompressionKey {
+    const NAME: &'static str = "shortint::CompressedDecompressionKey";
+}
+
+impl CompressedCompressionKey {
+    pub fn decompress(&self) -> CompressionKey {
+        let packing_key_switching_key = self
+            .packing_key_switching_key
+            .as_view()
+            .decompress_into_lwe_packing_keyswitch_key();
+
+        CompressionKey {
+            packing_key_switching_key,
+            lwe_per_glwe: self.lwe_per_glwe,
+            storage_log_modulus: self.storage_log_modulus,
+        }
+    }
+}
+
+impl CompressedDecompressionKey {
+    pub fn decompress(&self) -> DecompressionKey {
+        let blind_rotate_key = self
+            .blind_rotate_key
+            .as_view()
+            .par_decompress_into_lwe_bootstrap_key();
+
+        let mut fourier_bsk = FourierLweBootstrapKeyOwned::new(
+            blind_rotate_key.input_lwe_dimension(),
+            blind_rotate_key.glwe_size(),
+            blind_rotate_key.polynomial_size(),
+            blind_rotate_key.decomposition_base_log(),
+            blind_rotate_key.decomposition_level_count(),
+        );
+
+        par_convert_standard_lwe_bootstrap_key_to_fourier(&blind_rotate_key, &mut fourier_bsk);
+
+        DecompressionKey {
+            blind_rotate_key: fourier_bsk,
+            lwe_per_glwe: self.lwe_per_glwe,
+        }
+    }
+}
+
+impl ClientKey {
+    /// Generate a [`CompressedCompressionKey`] and a [`CompressedDecompressionKey`] associated to
+    /// the given [`CompressionPrivateKeys`].
+    pub fn new_compressed_compression_decompression_keys(
+        &self,
+        private_compression_key: &CompressionPrivateKeys,
+    ) -> (CompressedCompressionKey, CompressedDecompressionKey) {
+        let cks_params: crate::shortint::ClassicPBSParameters =
+            match self.parameters.pbs_parameters() {
+                Some(crate::shortint::PBSParameters::PBS(a)) => a,
+                _ => {
+                    panic!("Compression is currently only compatible with ClassicPBSParameters")
+                }
+            };
+
+        let params = &private_compression_key.params;
+
+        assert_eq!(
+            cks_params.encryption_key_choice,
+            crate::shortint::EncryptionKeyChoice::Big,
+            "Compression is only compatible with ciphertext in post PBS dimension"
+        );
+
+        let blind_rotate_key = ShortintEngine::with_thread_local_mut(|engine| {
+            par_allocate_and_generate_new_seeded_lwe_bootstrap_key(
+                &private_compression_key
+                    .post_packing_ks_key
+                    .as_lwe_secret_key(),
+                &self.glwe_secret_key,
+                params.br_base_log,
+                params.br_level,
+                cks_params.glwe_noise_distribution,
+                cks_params.ciphertext_modulus,
+                &mut engine.seeder,
+            )
+        });
+
+        let packing_key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
+            allocate_and_generate_new_seeded_lwe_packing_keyswitch_key(
+                &self.large_lwe_secret_key(),
+                &private_compression_key.post_packing_ks_key,
+                params.packing_ks_base_log,
+                params.packing_ks_level,
+                params.packing_ks_key_noise_distribution,
+                cks_params.ciphertext_modulus,
+                &mut engine.seeder,
+            )
+        });
+
+        let compression_key = CompressedCompressionKey {
+            packing_key_switching_key,
+            lwe_per_glwe: params.lwe_per_glwe,
+            storage_log_modulus: params.storage_log_modulus,
+        };
+
+        let decompression_key = CompressedDecompressionKey {
+            blind_rotate_key,
+            lwe_per_glwe: params.lwe_per_glwe,
+        };
+
+        (compression_key, decompression_key)
+    }
+}
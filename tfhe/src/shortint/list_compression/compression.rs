@@ -0,0 +1,260 @@
+use super::{CompressionKey, DecompressionKey};
+use crate::core_crypto::prelude::{
+    blind_rotate_assign_mem_optimized, extract_lwe_sample_from_glwe_ciphertext,
+    par_keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext,
+    programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement, CiphertextCount,
+    CompressedModulusSwitchedGlweCiphertext, ComputationBuffers, Fft, GlweCiphertextOwned,
+    LweCiphertextCount, LweCiphertextListOwned, LweCiphertextOwned, LweSize, MonomialDegree,
+};
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use crate::shortint::server_key::generate_lookup_table;
+use crate::shortint::{Ciphertext, PBSOrder};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A list of shortint ciphertexts packed in a few GLWE ciphertexts and stored with a reduced
+/// modulus.
+///
+/// Built using a [`CompressionKey`], ciphertexts can be extracted using a [`DecompressionKey`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CompressedCiphertextList {
+    #[serde(with = "crate::versionable::nested_vec")]
+    pub modulus_switched_glwe_ciphertext_list: Vec<CompressedModulusSwitchedGlweCiphertext<u64>>,
+    pub ciphertext_modulus: CiphertextModulus,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub pbs_order: PBSOrder,
+    pub lwe_per_glwe: LweCiphertextCount,
+    pub count: CiphertextCount,
+}
+
+impl crate::named::Named for CompressedCiphertextList {
+    const NAME: &'static str = "shortint::CompressedCiphertextList";
+}
+
+impl CompressedCiphertextList {
+    /// Returns the number of ciphertexts stored in the list
+    pub fn len(&self) -> usize {
+        self.count.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count.0 == 0
+    }
+}
+
+impl CompressionKey {
+    /// Pack the given ciphertexts into a [`CompressedCiphertextList`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not all share the same parameters, if one of them does not
+    /// have a noise level above nominal or if one of them has a non empty carry.
+    pub fn compress_ciphertexts_into_list(
+        &self,
+        ciphertexts: &[Ciphertext],
+    ) -> CompressedCiphertextList {
+        let lwe_pksk = &self.packing_key_switching_key;
+
+        let polynomial_size = lwe_pksk.output_polynomial_size();
+        let ciphertext_modulus = lwe_pksk.ciphertext_modulus();
+        let lwe_size = lwe_pksk.input_key_lwe_dimension().to_lwe_size();
+
+        let lwe_per_glwe = self.lwe_per_glwe;
+
+        assert!(
+            lwe_per_glwe.0 <= polynomial_size.0,
+            "Cannot pack more than polynomial_size(={}) elements per glwe, {} requested",
+            polynomial_size.0,
+            lwe_per_glwe.0,
+        );
+
+        let first_ct = ciphertexts.first().expect("Cannot compress an empty list");
+
+        let message_modulus = first_ct.message_modulus;
+        let carry_modulus = first_ct.carry_modulus;
+        let pbs_order = first_ct.pbs_order;
+
+        assert_eq!(
+            pbs_order,
+            PBSOrder::KeyswitchBootstrap,
+            "Compression is only compatible with ciphertext in post PBS dimension"
+        );
+
+        for ct in ciphertexts {
+            assert_eq!(
+                lwe_size,
+                ct.ct.lwe_size(),
+                "All ciphertexts do not have the same lwe size as the packing keyswitch key"
+            );
+
+            assert_eq!(
+                message_modulus, ct.message_modulus,
+                "All ciphertexts do not have the same message modulus"
+            );
+            assert_eq!(
+                carry_modulus, ct.carry_modulus,
+                "All ciphertexts do not have the same carry modulus"
+            );
+            assert_eq!(
+                pbs_order, ct.pbs_order,
+                "All ciphertexts do not have the same pbs order"
+            );
+
+            assert!(
+                ct.noise_level() <= NoiseLevel::NOMINAL,
+                "Ciphertexts must have at most a nominal noise level to be compressed"
+            );
+            assert!(
+                ct.carry_is_empty(),
+                "Ciphertexts must have empty carries to be compressed"
+            );
+        }
+
+        let modulus_switched_glwe_ciphertext_list = ciphertexts
+            .par_chunks(lwe_per_glwe.0)
+            .map(|ct_list| {
+                let mut list: Vec<_> = vec![];
+
+                for ct in ct_list {
+                    list.extend(ct.ct.as_ref());
+                }
+
+                let list = LweCiphertextListOwned::from_container(
+                    list,
+                    lwe_size,
+                    ct_list[0].ct.ciphertext_modulus(),
+                );
+
+                let bodies_count = LweCiphertextCount(ct_list.len());
+
+                let mut out = GlweCiphertextOwned::new(
+                    0,
+                    lwe_pksk.output_glwe_size(),
+                    polynomial_size,
+                    ciphertext_modulus,
+                );
+
+                par_keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext(
+                    lwe_pksk, &list, &mut out,
+                );
+
+                CompressedModulusSwitchedGlweCiphertext::compress(
+                    &out,
+                    self.storage_log_modulus,
+                    bodies_count,
+                )
+            })
+            .collect();
+
+        CompressedCiphertextList {
+            modulus_switched_glwe_ciphertext_list,
+            ciphertext_modulus,
+            message_modulus,
+            carry_modulus,
+            pbs_order,
+            lwe_per_glwe,
+            count: CiphertextCount(ciphertexts.len()),
+        }
+    }
+}
+
+impl DecompressionKey {
+    /// Extract the ciphertext stored at `index` in the given [`CompressedCiphertextList`].
+    ///
+    /// A blind rotation is applied, the output ciphertext has a nominal noise level.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn unpack(&self, packed: &CompressedCiphertextList, index: usize) -> Option<Ciphertext> {
+        if index >= packed.count.0 {
+            return None;
+        }
+
+        let carry_modulus = packed.carry_modulus;
+        let message_modulus = packed.message_modulus;
+
+        let lwe_per_glwe = packed.lwe_per_glwe.0;
+
+        let glwe_index = index / lwe_per_glwe;
+
+        let packed_glwe = packed.modulus_switched_glwe_ciphertext_list[glwe_index].extract();
+
+        let monomial_degree = MonomialDegree(index % lwe_per_glwe);
+
+        let mut intermediate_lwe = LweCiphertextOwned::new(
+            0,
+            LweSize(
+                packed_glwe
+                    .glwe_size()
+                    .to_glwe_dimension()
+                    .to_equivalent_lwe_dimension(packed_glwe.polynomial_size())
+                    .0
+                    + 1,
+            ),
+            packed.ciphertext_modulus,
+        );
+
+        extract_lwe_sample_from_glwe_ciphertext(
+            &packed_glwe,
+            &mut intermediate_lwe,
+            monomial_degree,
+        );
+
+        let encryption_cleartext_modulus = message_modulus.0 * carry_modulus.0;
+        // Identity: the input carries are empty when the list is built
+        let function = |x| x % encryption_cleartext_modulus as u64;
+
+        let fourier_bsk = &self.blind_rotate_key;
+
+        let lut = generate_lookup_table(
+            fourier_bsk.glwe_size(),
+            fourier_bsk.polynomial_size(),
+            packed.ciphertext_modulus,
+            message_modulus,
+            carry_modulus,
+            function,
+        );
+
+        let mut output_lwe = LweCiphertextOwned::new(
+            0,
+            fourier_bsk.output_lwe_dimension().to_lwe_size(),
+            packed.ciphertext_modulus,
+        );
+
+        let mut glwe_out = lut.acc;
+
+        let fft = Fft::new(fourier_bsk.polynomial_size());
+        let fft = fft.as_view();
+
+        let mut buffers = ComputationBuffers::new();
+        buffers.resize(
+            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+                fourier_bsk.glwe_size(),
+                fourier_bsk.polynomial_size(),
+                fft,
+            )
+            .unwrap()
+            .unaligned_bytes_required(),
+        );
+
+        blind_rotate_assign_mem_optimized(
+            &intermediate_lwe,
+            &mut glwe_out,
+            fourier_bsk,
+            fft,
+            buffers.stack(),
+        );
+
+        extract_lwe_sample_from_glwe_ciphertext(&glwe_out, &mut output_lwe, MonomialDegree(0));
+
+        Some(Ciphertext::new(
+            output_lwe,
+            Degree::new(message_modulus.0 - 1),
+            NoiseLevel::NOMINAL,
+            message_modulus,
+            carry_modulus,
+            PBSOrder::KeyswitchBootstrap,
+        ))
+    }
+}
@@ -0,0 +1,43 @@
+//! This module implements the compression of a list of shortint ciphertexts.
+//!
+//! A [`CompressionKey`] packs several LWE ciphertexts into a few GLWE ciphertexts using a packing
+//! keyswitch, the GLWE ciphertexts are then modulus switched and stored in a compact way in a
+//! [`CompressedCiphertextList`].
+//!
+//! A [`DecompressionKey`] uses a blind rotation to unpack any ciphertext of the list back under
+//! the key of the compute parameters, with a nominal noise level.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::shortint::gen_keys;
+//! use tfhe::shortint::parameters::{COMP_PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_KS_PBS};
+//!
+//! let (cks, _sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+//!
+//! let private_compression_key = cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+//!
+//! let (compression_key, decompression_key) =
+//!     cks.new_compression_decompression_keys(&private_compression_key);
+//!
+//! let cts: Vec<_> = (0..4).map(|msg| cks.encrypt(msg)).collect();
+//!
+//! let compressed = compression_key.compress_ciphertexts_into_list(&cts);
+//!
+//! for msg in 0..4 {
+//!     let ct = decompression_key.unpack(&compressed, msg as usize).unwrap();
+//!     assert_eq!(cks.decrypt(&ct), msg);
+//! }
+//! ```
+mod compressed_server_keys;
+mod compression;
+mod private_key;
+mod server_keys;
+
+#[cfg(test)]
+mod test;
+
+pub use compressed_server_keys::{CompressedCompressionKey, CompressedDecompressionKey};
+pub use compression::CompressedCiphertextList;
+pub use private_key::CompressionPrivateKeys;
+pub use server_keys::{CompressionKey, DecompressionKey};
@@ -0,0 +1,109 @@
+use crate::core_crypto::prelude::{
+    allocate_and_generate_new_binary_glwe_secret_key, GlweSecretKeyOwned,
+};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::list_compression::CompressionParameters;
+use crate::shortint::ClientKey;
+use serde::{Deserialize, Serialize};
+
+/// The secret keys used to generate the [`CompressionKey`](super::CompressionKey) and
+/// [`DecompressionKey`](super::DecompressionKey).
+///
+/// The `post_packing_ks_key` is the GLWE secret key under which compressed lists are encrypted.
+///
+/// This key **MUST NOT** be shared.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressionPrivateKeys {
+    #[serde(with = "crate::versionable::nested")]
+    pub post_packing_ks_key: GlweSecretKeyOwned<u64>,
+    pub params: CompressionParameters,
+}
+
+impl crate::named::Named for CompressionPrivateKeys {
+    const NAME: &'static str = "shortint::CompressionPrivateKeys";
+}
+
+impl CompressionPrivateKeys {
+    /// Deconstruct a [`CompressionPrivateKeys`] into its constituents.
+    pub fn into_raw_parts(self) -> (GlweSecretKeyOwned<u64>, CompressionParameters) {
+        let Self {
+            post_packing_ks_key,
+            params,
+        } = self;
+
+        (post_packing_ks_key, params)
+    }
+
+    /// Construct a [`CompressionPrivateKeys`] from its constituents.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not compatible with the parameters.
+    pub fn from_raw_parts(
+        post_packing_ks_key: GlweSecretKeyOwned<u64>,
+        params: CompressionParameters,
+    ) -> Self {
+        assert_eq!(
+            post_packing_ks_key.glwe_dimension(),
+            params.packing_ks_glwe_dimension,
+            "Mismatch between the GlweSecretKey GlweDimension ({:?}) \
+            and the CompressionParameters packing_ks_glwe_dimension ({:?})",
+            post_packing_ks_key.glwe_dimension(),
+            params.packing_ks_glwe_dimension,
+        );
+
+        assert_eq!(
+            post_packing_ks_key.polynomial_size(),
+            params.packing_ks_polynomial_size,
+            "Mismatch between the GlweSecretKey PolynomialSize ({:?}) \
+            and the CompressionParameters packing_ks_polynomial_size ({:?})",
+            post_packing_ks_key.polynomial_size(),
+            params.packing_ks_polynomial_size,
+        );
+
+        Self {
+            post_packing_ks_key,
+            params,
+        }
+    }
+}
+
+impl ClientKey {
+    /// Generate the secret keys needed to create a [`CompressionKey`](super::CompressionKey) and a
+    /// [`DecompressionKey`](super::DecompressionKey).
+    ///
+    /// See [`crate::shortint::list_compression`] for usage.
+    pub fn new_compression_private_key(
+        &self,
+        params: CompressionParameters,
+    ) -> CompressionPrivateKeys {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_compression_private_key(self, params)
+        })
+    }
+}
+
+impl ShortintEngine {
+    pub(crate) fn new_compression_private_key(
+        &mut self,
+        cks: &ClientKey,
+        params: CompressionParameters,
+    ) -> CompressionPrivateKeys {
+        assert_eq!(
+            cks.parameters.encryption_key_choice(),
+            crate::shortint::EncryptionKeyChoice::Big,
+            "Compression is only compatible with ciphertext in post PBS dimension"
+        );
+
+        let post_packing_ks_key = allocate_and_generate_new_binary_glwe_secret_key(
+            params.packing_ks_glwe_dimension,
+            params.packing_ks_polynomial_size,
+            &mut self.secret_generator,
+        );
+
+        CompressionPrivateKeys {
+            post_packing_ks_key,
+            params,
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use super::CompressionPrivateKeys;
+use crate::core_crypto::prelude::{
+    allocate_and_generate_new_lwe_packing_keyswitch_key,
+    par_allocate_and_generate_new_lwe_bootstrap_key,
+    par_convert_standard_lwe_bootstrap_key_to_fourier, CiphertextModulusLog,
+    FourierLweBootstrapKeyOwned, LweBootstrapKeyOwned, LweCiphertextCount,
+    LwePackingKeyswitchKeyOwned,
+};
+use crate::core_crypto::prelude::{GlweSize, PolynomialSize};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::ClientKey;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// A key used to pack shortint ciphertexts into a
+/// [`CompressedCiphertextList`](super::CompressedCiphertextList).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressionKey {
+    #[serde(with = "crate::versionable::nested")]
+    pub packing_key_switching_key: LwePackingKeyswitchKeyOwned<u64>,
+    pub lwe_per_glwe: LweCiphertextCount,
+    pub storage_log_modulus: CiphertextModulusLog,
+}
+
+impl crate::named::Named for CompressionKey {
+    const NAME: &'static str = "shortint::CompressionKey";
+}
+
+/// A key used to unpack shortint ciphertexts from a
+/// [`CompressedCiphertextList`](super::CompressedCiphertextList).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecompressionKey {
+    #[serde(with = "crate::versionable::nested")]
+    pub blind_rotate_key: FourierLweBootstrapKeyOwned,
+    pub lwe_per_glwe: LweCiphertextCount,
+}
+
+impl crate::named::Named for DecompressionKey {
+    const NAME: &'static str = "shortint::DecompressionKey";
+}
+
+impl DecompressionKey {
+    pub fn out_glwe_size(&self) -> GlweSize {
+        self.blind_rotate_key.glwe_size()
+    }
+
+    pub fn out_polynomial_size(&self) -> PolynomialSize {
+        self.blind_rotate_key.polynomial_size()
+    }
+}
+
+impl ClientKey {
+    /// Generate the [`CompressionKey`] and [`DecompressionKey`] associated to the given
+    /// [`CompressionPrivateKeys`].
+    ///
+    /// See [`crate::shortint::list_compression`] for usage.
+    pub fn new_compression_decompression_keys(
+        &self,
+        private_compression_key: &CompressionPrivateKeys,
+    ) -> (CompressionKey, DecompressionKey) {
+        let cks_params: crate::shortint::ClassicPBSParameters =
+            match self.parameters.pbs_parameters() {
+                Some(crate::shortint::PBSParameters::PBS(a)) => a,
+                _ => {
+                    panic!("Compression is currently only compatible with ClassicPBSParameters")
+                }
+            };
+
+        let params = &private_compression_key.params;
+
+        assert_eq!(
+            cks_params.encryption_key_choice,
+            crate::shortint::EncryptionKeyChoice::Big,
+            "Compression is only compatible with ciphertext in post PBS dimension"
+        );
+
+        let blind_rotate_key = ShortintEngine::with_thread_local_mut(|engine| {
+            let bootstrap_key: LweBootstrapKeyOwned<u64> =
+                par_allocate_and_generate_new_lwe_bootstrap_key(
+                    &private_compression_key
+                        .post_packing_ks_key
+                        .as_lwe_secret_key(),
+                    &self.glwe_secret_key,
+                    params.br_base_log,
+                    params.br_level,
+                    cks_params.glwe_noise_distribution,
+                    cks_params.ciphertext_modulus,
+                    &mut engine.encryption_generator,
+                );
+
+            let mut fourier_bsk = FourierLweBootstrapKeyOwned::new(
+                bootstrap_key.input_lwe_dimension(),
+                bootstrap_key.glwe_size(),
+                bootstrap_key.polynomial_size(),
+                bootstrap_key.decomposition_base_log(),
+                bootstrap_key.decomposition_level_count(),
+            );
+
+            par_convert_standard_lwe_bootstrap_key_to_fourier(&bootstrap_key, &mut fourier_bsk);
+
+            fourier_bsk
+        });
+
+        let packing_key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
+            allocate_and_generate_new_lwe_packing_keyswitch_key(
+                &self.large_lwe_secret_key(),
+                &private_compression_key.post_packing_ks_key,
+                params.packing_ks_base_log,
+                params.packing_ks_level,
+                params.packing_ks_key_noise_distribution,
+                cks_params.ciphertext_modulus,
+                &mut engine.encryption_generator,
+            )
+        });
+
+        assert!(
+            private_compression_key.params.storage_log_modulus.0
+                <= cks_params
+                    .polynomial_size
+                    .to_blind_rotation_input_modulus_log()
+                    .0,
+            "Compression parameters say to store more bits than useful"
+        );
+
+        assert!(
+            params.lwe_per_glwe.0 <= params.packing_ks_polynomial_size.0,
+            "Cannot pack more LWE ciphertexts ({}) than the packing polynomial size ({})",
+            params.lwe_per_glwe.0,
+            params.packing_ks_polynomial_size.0,
+        );
+
+        let compression_key = CompressionKey {
+            packing_key_switching_key,
+            lwe_per_glwe: params.lwe_per_glwe,
+            storage_log_modulus: private_compression_key.params.storage_log_modulus,
+        };
+
+        let decompression_key = DecompressionKey {
+            blind_rotate_key,
+            lwe_per_glwe: params.lwe_per_glwe,
+        };
+
+        (compression_key, decompression_key)
+    }
+}
@@ -0,0 +1,69 @@
+use crate::shortint::keycache::KEY_CACHE;
+use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2;
+use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+use crate::shortint::ShortintParameterSet;
+
+#[test]
+fn test_packing_compression_decompression_ci_run_filter() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let private_compression_key = cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+
+    let (compression_key, decompression_key) =
+        cks.new_compression_decompression_keys(&private_compression_key);
+
+    let params: ShortintParameterSet = PARAM_MESSAGE_2_CARRY_2_KS_PBS.into();
+    let msg_modulus = params.message_modulus().0 as u64;
+
+    // Lists larger than lwe_per_glwe span several GLWE ciphertexts
+    for number_to_pack in [0, 1, 128, 300] {
+        let cts: Vec<_> = (0..number_to_pack)
+            .map(|i| cks.encrypt(i as u64 % msg_modulus))
+            .collect();
+
+        if cts.is_empty() {
+            continue;
+        }
+
+        let packed = compression_key.compress_ciphertexts_into_list(&cts);
+
+        assert_eq!(packed.len(), number_to_pack);
+
+        for (i, ct) in cts.iter().enumerate() {
+            let unpacked = decompression_key.unpack(&packed, i).unwrap();
+
+            assert_eq!(unpacked.degree, ct.degree);
+            assert_eq!(cks.decrypt(&unpacked), i as u64 % msg_modulus);
+
+            // The output can be used in computations
+            let res = sks.unchecked_add(&unpacked, &unpacked);
+            assert_eq!(cks.decrypt(&res), (2 * i as u64) % msg_modulus);
+        }
+
+        assert!(decompression_key.unpack(&packed, number_to_pack).is_none());
+    }
+}
+
+#[test]
+fn test_compressed_packing_keys_ci_run_filter() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let cks = keys.client_key();
+
+    let private_compression_key = cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+
+    let (compressed_compression_key, compressed_decompression_key) =
+        cks.new_compressed_compression_decompression_keys(&private_compression_key);
+
+    let compression_key = compressed_compression_key.decompress();
+    let decompression_key = compressed_decompression_key.decompress();
+
+    let cts: Vec<_> = (0..4).map(|i| cks.encrypt(i)).collect();
+
+    let packed = compression_key.compress_ciphertexts_into_list(&cts);
+
+    for i in 0..4 {
+        let unpacked = decompression_key.unpack(&packed, i as usize).unwrap();
+        assert_eq!(cks.decrypt(&unpacked), i);
+    }
+}
@@ -47,12 +47,14 @@
 //! let output = client_key.decrypt(&ct_3);
 //! assert_eq!(output, 1);
 //! ```
+pub mod backward_compatibility;
 pub mod ciphertext;
 pub mod client_key;
 pub mod engine;
 pub mod key_switching_key;
 #[cfg(any(test, doctest, feature = "internal-keycache"))]
 pub mod keycache;
+pub mod list_compression;
 pub mod oprf;
 pub mod parameters;
 pub mod prelude;
@@ -148,6 +148,7 @@ impl ServerKey {
             message_modulus: self.message_modulus,
             carry_modulus: self.carry_modulus,
             pbs_order: self.pbs_order,
+            noise_variance: self.bootstrapped_noise_variance(),
         }
     }
 }
@@ -0,0 +1,47 @@
+use crate::core_crypto::prelude::{CiphertextModulusLog, LweCiphertextCount};
+use crate::shortint::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, DynamicDistribution, GlweDimension,
+    PolynomialSize,
+};
+use serde::{Deserialize, Serialize};
+
+/// A set of cryptographic parameters used to pack several shortint ciphertexts into a few GLWE
+/// ciphertexts and to unpack them back.
+///
+/// * `packing_ks_*` parameters describe the LWE to GLWE packing keyswitch and the GLWE secret key
+///   the packed ciphertexts are encrypted under.
+/// * `lwe_per_glwe` is the number of LWE ciphertexts packed in each GLWE ciphertext.
+/// * `storage_log_modulus` is the log2 of the modulus the packed GLWE ciphertexts are switched to
+///   before being stored.
+/// * `br_*` parameters describe the blind rotation used to unpack a ciphertext and bring it back
+///   under the key of the compute parameters.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressionParameters {
+    pub br_level: DecompositionLevelCount,
+    pub br_base_log: DecompositionBaseLog,
+    pub packing_ks_level: DecompositionLevelCount,
+    pub packing_ks_base_log: DecompositionBaseLog,
+    pub packing_ks_polynomial_size: PolynomialSize,
+    pub packing_ks_glwe_dimension: GlweDimension,
+    pub lwe_per_glwe: LweCiphertextCount,
+    pub storage_log_modulus: CiphertextModulusLog,
+    pub packing_ks_key_noise_distribution: DynamicDistribution<u64>,
+}
+
+/// Compression parameters to use with
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS`](super::PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+pub const COMP_PARAM_MESSAGE_2_CARRY_2: CompressionParameters =
+    COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64;
+
+pub const COMP_PARAM_MESSAGE_2_CARRY_2_KS_PBS_TUNIFORM_2M64: CompressionParameters =
+    CompressionParameters {
+        br_level: DecompositionLevelCount(1),
+        br_base_log: DecompositionBaseLog(23),
+        packing_ks_level: DecompositionLevelCount(4),
+        packing_ks_base_log: DecompositionBaseLog(4),
+        packing_ks_polynomial_size: PolynomialSize(256),
+        packing_ks_glwe_dimension: GlweDimension(4),
+        lwe_per_glwe: LweCiphertextCount(256),
+        storage_log_modulus: CiphertextModulusLog(12),
+        packing_ks_key_noise_distribution: DynamicDistribution::new_t_uniform(42),
+    };
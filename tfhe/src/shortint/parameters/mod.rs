@@ -21,7 +21,9 @@ pub mod compact_public_key_only;
 #[cfg(tarpaulin)]
 pub mod coverage_parameters;
 pub mod key_switching;
+pub mod list_compression;
 pub mod multi_bit;
+pub mod optimizer;
 pub mod parameters_wopbs;
 pub mod parameters_wopbs_message_carry;
 pub mod parameters_wopbs_only;
@@ -35,6 +37,7 @@ pub use compact_public_key_only::CompactCiphertextListExpansionKind;
 #[cfg(tarpaulin)]
 pub use coverage_parameters::*;
 pub use key_switching::ShortintKeySwitchingParameters;
+pub use list_compression::{CompressionParameters, COMP_PARAM_MESSAGE_2_CARRY_2};
 pub use multi_bit::*;
 pub use parameters_wopbs::*;
 
@@ -310,6 +313,12 @@ impl PBSParameters {
             Self::MultiBitPBS(params) => params.max_noise_level,
         }
     }
+    pub const fn log2_p_fail(&self) -> f64 {
+        match self {
+            Self::PBS(params) => params.log2_p_fail,
+            Self::MultiBitPBS(params) => params.log2_p_fail,
+        }
+    }
     pub const fn ciphertext_modulus(&self) -> CiphertextModulus {
         match self {
             Self::PBS(params) => params.ciphertext_modulus,
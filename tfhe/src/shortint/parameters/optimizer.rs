@@ -0,0 +1,648 @@
+//! Module searching cryptographic parameters for a given message space, failure probability and
+//! security level.
+//!
+//! The parameters provided by **TFHE-rs** come from an external optimizer, this module allows to
+//! derive comparable parameters for configurations which are not provided:
+//!
+//! + the noise distribution of each secret key is the one with the smallest variance reaching the
+//!   security level according to [`estimate_lwe_security_level`];
+//! + the failure probability of a PBS applied on a ciphertext with the maximum noise level is
+//!   estimated with the formulas of the [`dispersion`](crate::core_crypto::commons::dispersion)
+//!   module;
+//! + among the parameters reaching the failure probability, the ones minimizing an estimation of
+//!   the number of operations of a keyswitch followed by a PBS are returned.
+//!
+//! Only the [`EncryptionKeyChoice::Big`] order and the native ciphertext modulus are supported.
+//!
+//! ```rust
+//! use tfhe::core_crypto::commons::security::check_lwe_security;
+//! use tfhe::shortint::parameters::optimizer::{optimize_parameters, OptimizationConstraints};
+//! use tfhe::shortint::parameters::{CarryModulus, MessageModulus};
+//!
+//! let constraints = OptimizationConstraints::new(MessageModulus(4), CarryModulus(4));
+//! let parameters = optimize_parameters(&constraints).unwrap();
+//!
+//! assert!(parameters.log2_p_fail() <= -64.0);
+//! assert!(check_lwe_security(
+//!     parameters.lwe_dimension(),
+//!     parameters.lwe_noise_distribution().variance(64),
+//!     64,
+//!     128
+//! )
+//! .is_ok());
+//! ```
+
+use super::{
+    CarryModulus, CiphertextModulus, ClassicPBSParameters, DecompositionBaseLog,
+    DecompositionLevelCount, DynamicDistribution, EncryptionKeyChoice, GlweDimension,
+    LweBskGroupingFactor, LweDimension, MaxNoiseLevel, MessageModulus, MultiBitPBSParameters,
+    PBSParameters, PolynomialSize, StandardDev,
+};
+use crate::core_crypto::commons::dispersion::{
+    failure_probability, lwe_keyswitch_additive_variance, modulus_switch_additive_variance,
+    multi_bit_pbs_output_variance, DispersionParameter, LogStandardDev, Variance,
+};
+use crate::core_crypto::commons::security::{
+    estimate_lwe_security_level, DEFAULT_SECURITY_LEVEL, MINIMUM_LWE_DIMENSION,
+    MINIMUM_MODULAR_STANDARD_DEV,
+};
+use crate::shortint::server_key::NoiseModel;
+use crate::Error;
+
+const LOG2_MODULUS: u32 = u64::BITS;
+
+const MAXIMUM_LWE_DIMENSION: usize = 2048;
+const MAXIMUM_GLWE_DIMENSION: usize = 6;
+const MINIMUM_POLYNOMIAL_SIZE_LOG2: usize = 8;
+const MAXIMUM_POLYNOMIAL_SIZE_LOG2: usize = 16;
+const MAXIMUM_DECOMPOSITION_LEVEL_COUNT: usize = 16;
+const MINIMUM_GROUPING_FACTOR: usize = 2;
+const MAXIMUM_GROUPING_FACTOR: usize = 4;
+
+const BISECTION_ITERATIONS: usize = 32;
+
+/// The kind of programmable bootstrap the parameters are optimized for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PBSKind {
+    Classic,
+    MultiBit(LweBskGroupingFactor),
+}
+
+/// The kind of distribution used to sample the noise of the encryptions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NoiseDistributionKind {
+    Gaussian,
+    TUniform,
+}
+
+impl NoiseDistributionKind {
+    /// Return the distribution of this kind with the smallest variance for which an LWE instance
+    /// of the given dimension reaches the security level.
+    ///
+    /// Distributions with a variance greater than `maximum_variance` are not considered, as the
+    /// decoding of the messages would fail anyway.
+    fn minimal_secure_distribution(
+        self,
+        lwe_dimension: LweDimension,
+        security_level: u32,
+        maximum_variance: Variance,
+    ) -> Option<DynamicDistribution<u64>> {
+        let is_secure = |distribution: DynamicDistribution<u64>| {
+            let variance = distribution.variance(LOG2_MODULUS);
+            variance.get_modular_standard_dev(LOG2_MODULUS) >= MINIMUM_MODULAR_STANDARD_DEV
+                && estimate_lwe_security_level(lwe_dimension, variance, LOG2_MODULUS)
+                    >= security_level as f64
+        };
+
+        match self {
+            Self::Gaussian => {
+                let gaussian = |log_std: f64| {
+                    DynamicDistribution::new_gaussian_from_std_dev(StandardDev(
+                        LogStandardDev(log_std).get_standard_dev(),
+                    ))
+                };
+
+                // Invariant: low is not secure, high is
+                let mut low = MINIMUM_MODULAR_STANDARD_DEV.log2() - LOG2_MODULUS as f64;
+                let mut high = maximum_variance.get_log_standard_dev();
+                if !is_secure(gaussian(high)) {
+                    return None;
+                }
+                if is_secure(gaussian(low)) {
+                    return Some(gaussian(low));
+                }
+                for _ in 0..BISECTION_ITERATIONS {
+                    let middle = (low + high) / 2.0;
+                    if is_secure(gaussian(middle)) {
+                        high = middle;
+                    } else {
+                        low = middle;
+                    }
+                }
+
+                Some(gaussian(high))
+            }
+            Self::TUniform => {
+                let bound_logs: Vec<u32> = (0..=LOG2_MODULUS - 2)
+                    .take_while(|&bound_log2| {
+                        DynamicDistribution::<u64>::new_t_uniform(bound_log2)
+                            .variance(LOG2_MODULUS)
+                            .0
+                            <= maximum_variance.0
+                    })
+                    .collect();
+                let index = bound_logs.partition_point(|&bound_log2| {
+                    !is_secure(DynamicDistribution::new_t_uniform(bound_log2))
+                });
+
+                bound_logs
+                    .get(index)
+                    .map(|&bound_log2| DynamicDistribution::new_t_uniform(bound_log2))
+            }
+        }
+    }
+}
+
+/// The constraints the parameters returned by [`optimize_parameters`] must satisfy.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OptimizationConstraints {
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    /// Maximum base 2 logarithm of the probability that a PBS gives a wrong result, when applied
+    /// on a ciphertext with the maximum noise level.
+    pub log2_p_fail: f64,
+    /// Security level in bits of the secret keys.
+    pub security_level: u32,
+    pub pbs_kind: PBSKind,
+    pub noise_distribution_kind: NoiseDistributionKind,
+}
+
+impl OptimizationConstraints {
+    /// Constraints matching the ones of the parameters provided by **TFHE-rs**: a failure
+    /// probability of $2^{-64}$, 128 bits of security, a classic PBS and a TUniform noise.
+    pub const fn new(message_modulus: MessageModulus, carry_modulus: CarryModulus) -> Self {
+        Self {
+            message_modulus,
+            carry_modulus,
+            log2_p_fail: -64.0,
+            security_level: DEFAULT_SECURITY_LEVEL,
+            pbs_kind: PBSKind::Classic,
+            noise_distribution_kind: NoiseDistributionKind::TUniform,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Decomposition {
+    base_log: DecompositionBaseLog,
+    level_count: DecompositionLevelCount,
+    variance: f64,
+}
+
+/// Return, for each level count, the decomposition base minimizing the variance.
+fn best_decompositions(
+    variance: impl Fn(DecompositionBaseLog, DecompositionLevelCount) -> Variance,
+) -> Vec<Decomposition> {
+    (1..=MAXIMUM_DECOMPOSITION_LEVEL_COUNT)
+        .map(|level_count| {
+            (1..=LOG2_MODULUS as usize / level_count)
+                .map(|base_log| {
+                    let base_log = DecompositionBaseLog(base_log);
+                    let level_count = DecompositionLevelCount(level_count);
+                    Decomposition {
+                        base_log,
+                        level_count,
+                        variance: variance(base_log, level_count).0,
+                    }
+                })
+                .min_by(|lhs, rhs| lhs.variance.total_cmp(&rhs.variance))
+                .unwrap()
+        })
+        .collect()
+}
+
+/// Return the largest variance for which decoding fails with a probability smaller than
+/// $2^{log2\_p\_fail}$.
+fn maximum_variance(bound: f64, log2_p_fail: f64) -> Variance {
+    let log2_failure_probability =
+        |log_std: f64| failure_probability(LogStandardDev(log_std), bound).log2();
+
+    // Invariant: low reaches the failure probability, high does not
+    let mut low = bound.log2() - 64.0;
+    let mut high = bound.log2();
+    for _ in 0..BISECTION_ITERATIONS {
+        let middle = (low + high) / 2.0;
+        if log2_failure_probability(middle) <= log2_p_fail {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    Variance(LogStandardDev(low).get_variance())
+}
+
+/// Estimation of the number of operations of a keyswitch followed by a PBS.
+fn ks_pbs_cost(
+    lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    pbs_level: DecompositionLevelCount,
+    ks_level: DecompositionLevelCount,
+    grouping_factor: LweBskGroupingFactor,
+) -> f64 {
+    let glwe_size = glwe_dimension.to_glwe_size().0 as f64;
+    let polynomial_size_f64 = polynomial_size.0 as f64;
+    let pbs_level = pbs_level.0 as f64;
+    let fft_cost = polynomial_size_f64 * polynomial_size_f64.log2();
+
+    // The decomposed polynomials are converted to the Fourier domain, multiplied by the GGSW and
+    // the output polynomials are converted back
+    let external_product_cost = glwe_size * pbs_level * fft_cost
+        + glwe_size * glwe_size * pbs_level * polynomial_size_f64
+        + glwe_size * fft_cost;
+    // The multi bit PBS sums 2^grouping_factor - 1 GGSW before each external product
+    let ggsw_sum_cost = ((1u64 << grouping_factor.0) - 2) as f64
+        * glwe_size
+        * glwe_size
+        * pbs_level
+        * polynomial_size_f64;
+    let pbs_cost =
+        (lwe_dimension.0 / grouping_factor.0) as f64 * (external_product_cost + ggsw_sum_cost);
+
+    let ks_cost = glwe_dimension
+        .to_equivalent_lwe_dimension(polynomial_size)
+        .0 as f64
+        * ks_level.0 as f64
+        * lwe_dimension.to_lwe_size().0 as f64;
+
+    pbs_cost + ks_cost
+}
+
+struct LweCandidate {
+    lwe_dimension: LweDimension,
+    noise_distribution: DynamicDistribution<u64>,
+    // The keyswitch variance is proportional to the input dimension, the variances are given for
+    // an input dimension of 1
+    ks_decompositions: Vec<Decomposition>,
+}
+
+struct GlweCandidate {
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    noise_distribution: DynamicDistribution<u64>,
+    // The PBS variance is proportional to the number of external products, the variances are
+    // given for a single external product
+    pbs_decompositions: Vec<Decomposition>,
+}
+
+/// Search the parameters satisfying the constraints, minimizing the cost of a keyswitch followed
+/// by a PBS.
+///
+/// The returned parameters use the [`EncryptionKeyChoice::Big`] order, their `log2_p_fail` is the
+/// estimation of the failure probability of a PBS applied on a ciphertext with the maximum noise
+/// level.
+///
+/// Returns an error if the message space or the multi bit grouping factor (between 2 and 4) is not
+/// supported, or if no parameters satisfy the constraints.
+pub fn optimize_parameters(constraints: &OptimizationConstraints) -> Result<PBSParameters, Error> {
+    let OptimizationConstraints {
+        message_modulus,
+        carry_modulus,
+        log2_p_fail,
+        security_level,
+        pbs_kind,
+        noise_distribution_kind,
+    } = *constraints;
+
+    if message_modulus.0 < 2 || !message_modulus.0.is_power_of_two() {
+        return Err(Error::new(format!(
+            "The message modulus must be a power of two greater than 1, got {}",
+            message_modulus.0
+        )));
+    }
+    if !carry_modulus.0.is_power_of_two() {
+        return Err(Error::new(format!(
+            "The carry modulus must be a power of two, got {}",
+            carry_modulus.0
+        )));
+    }
+
+    let grouping_factor = match pbs_kind {
+        PBSKind::Classic => LweBskGroupingFactor(1),
+        PBSKind::MultiBit(grouping_factor) => {
+            if !(MINIMUM_GROUPING_FACTOR..=MAXIMUM_GROUPING_FACTOR).contains(&grouping_factor.0) {
+                return Err(Error::new(format!(
+                    "The multi bit PBS grouping factor must be between {MINIMUM_GROUPING_FACTOR} \
+                    and {MAXIMUM_GROUPING_FACTOR}, got {}",
+                    grouping_factor.0
+                )));
+            }
+            grouping_factor
+        }
+    };
+
+    let max_noise_level = MaxNoiseLevel::from_msg_carry_modulus(message_modulus, carry_modulus);
+    // Messages are encoded with a padding bit, decoding fails when the noise is greater than
+    // half the scaling factor
+    let full_modulus = (message_modulus.0 * carry_modulus.0) as f64;
+    let maximum_variance = maximum_variance(1.0 / (4.0 * full_modulus), log2_p_fail);
+
+    let lwe_candidates: Vec<_> = (MINIMUM_LWE_DIMENSION.0 + 1..=MAXIMUM_LWE_DIMENSION)
+        .filter(|lwe_dimension| lwe_dimension % grouping_factor.0 == 0)
+        .filter_map(|lwe_dimension| {
+            let lwe_dimension = LweDimension(lwe_dimension);
+            let noise_distribution = noise_distribution_kind.minimal_secure_distribution(
+                lwe_dimension,
+                security_level,
+                maximum_variance,
+            )?;
+            let ks_decompositions = best_decompositions(|base_log, level_count| {
+                lwe_keyswitch_additive_variance(
+                    LweDimension(1),
+                    base_log,
+                    level_count,
+                    noise_distribution.variance(LOG2_MODULUS),
+                    LOG2_MODULUS,
+                )
+            });
+
+            Some(LweCandidate {
+                lwe_dimension,
+                noise_distribution,
+                ks_decompositions,
+            })
+        })
+        .collect();
+
+    let glwe_candidates: Vec<_> = (1..=MAXIMUM_GLWE_DIMENSION)
+        .flat_map(|glwe_dimension| {
+            (MINIMUM_POLYNOMIAL_SIZE_LOG2..=MAXIMUM_POLYNOMIAL_SIZE_LOG2).map(
+                move |polynomial_size_log2| {
+                    (
+                        GlweDimension(glwe_dimension),
+                        PolynomialSize(1 << polynomial_size_log2),
+                    )
+                },
+            )
+        })
+        .filter(|(glwe_dimension, polynomial_size)| {
+            glwe_dimension.0 * polynomial_size.0 <= 1 << MAXIMUM_POLYNOMIAL_SIZE_LOG2
+        })
+        .filter_map(|(glwe_dimension, polynomial_size)| {
+            let noise_distribution = noise_distribution_kind.minimal_secure_distribution(
+                glwe_dimension.to_equivalent_lwe_dimension(polynomial_size),
+                security_level,
+                maximum_variance,
+            )?;
+            let pbs_decompositions = best_decompositions(|base_log, level_count| {
+                multi_bit_pbs_output_variance(
+                    LweDimension(grouping_factor.0),
+                    glwe_dimension,
+                    polynomial_size,
+                    base_log,
+                    level_count,
+                    grouping_factor,
+                    noise_distribution.variance(LOG2_MODULUS),
+                    LOG2_MODULUS,
+                )
+            });
+
+            Some(GlweCandidate {
+                glwe_dimension,
+                polynomial_size,
+                noise_distribution,
+                pbs_decompositions,
+            })
+        })
+        .collect();
+
+    let mut best_candidate = None;
+    let mut best_cost = f64::INFINITY;
+    for glwe in &glwe_candidates {
+        let glwe_lwe_dimension = glwe
+            .glwe_dimension
+            .to_equivalent_lwe_dimension(glwe.polynomial_size)
+            .0 as f64;
+
+        for lwe in &lwe_candidates {
+            let external_product_count = (lwe.lwe_dimension.0 / grouping_factor.0) as f64;
+            let modulus_switch_variance = modulus_switch_additive_variance(
+                lwe.lwe_dimension,
+                glwe.polynomial_size,
+                LOG2_MODULUS,
+            )
+            .0;
+
+            for pbs in &glwe.pbs_decompositions {
+                let bootstrapped_variance = pbs.variance * external_product_count;
+                let remaining_variance = maximum_variance.0
+                    - max_noise_level.get() as f64 * bootstrapped_variance
+                    - modulus_switch_variance;
+
+                for ks in &lwe.ks_decompositions {
+                    if ks.variance * glwe_lwe_dimension > remaining_variance {
+                        continue;
+                    }
+
+                    let cost = ks_pbs_cost(
+                        lwe.lwe_dimension,
+                        glwe.glwe_dimension,
+                        glwe.polynomial_size,
+                        pbs.level_count,
+                        ks.level_count,
+                        grouping_factor,
+                    );
+                    if cost < best_cost {
+                        best_cost = cost;
+                        best_candidate = Some((glwe, lwe, *pbs, *ks));
+                    }
+                }
+            }
+        }
+    }
+
+    let Some((glwe, lwe, pbs, ks)) = best_candidate else {
+        return Err(Error::new(format!(
+            "No parameters reach a failure probability of 2^{log2_p_fail} with \
+            {security_level} bits of security for a message modulus of {} and a carry modulus \
+            of {}",
+            message_modulus.0, carry_modulus.0
+        )));
+    };
+
+    let classic_parameters = ClassicPBSParameters {
+        lwe_dimension: lwe.lwe_dimension,
+        glwe_dimension: glwe.glwe_dimension,
+        polynomial_size: glwe.polynomial_size,
+        lwe_noise_distribution: lwe.noise_distribution,
+        glwe_noise_distribution: glwe.noise_distribution,
+        pbs_base_log: pbs.base_log,
+        pbs_level: pbs.level_count,
+        ks_base_log: ks.base_log,
+        ks_level: ks.level_count,
+        message_modulus,
+        carry_modulus,
+        max_noise_level,
+        log2_p_fail: 0.0,
+        ciphertext_modulus: CiphertextModulus::new_native(),
+        encryption_key_choice: EncryptionKeyChoice::Big,
+    };
+
+    let mut parameters = match pbs_kind {
+        PBSKind::Classic => PBSParameters::PBS(classic_parameters),
+        PBSKind::MultiBit(grouping_factor) => PBSParameters::MultiBitPBS(MultiBitPBSParameters {
+            lwe_dimension: classic_parameters.lwe_dimension,
+            glwe_dimension: classic_parameters.glwe_dimension,
+            polynomial_size: classic_parameters.polynomial_size,
+            lwe_noise_distribution: classic_parameters.lwe_noise_distribution,
+            glwe_noise_distribution: classic_parameters.glwe_noise_distribution,
+            pbs_base_log: classic_parameters.pbs_base_log,
+            pbs_level: classic_parameters.pbs_level,
+            ks_base_log: classic_parameters.ks_base_log,
+            ks_level: classic_parameters.ks_level,
+            message_modulus,
+            carry_modulus,
+            max_noise_level,
+            log2_p_fail: 0.0,
+            ciphertext_modulus: classic_parameters.ciphertext_modulus,
+            encryption_key_choice: classic_parameters.encryption_key_choice,
+            grouping_factor,
+            deterministic_execution: false,
+        }),
+    };
+
+    let noise_model = NoiseModel::new(parameters);
+    let worst_case_variance =
+        Variance(noise_model.bootstrapped_variance().0 * max_noise_level.get() as f64);
+    let estimated_log2_p_fail = noise_model
+        .pbs_failure_probability(worst_case_variance)
+        .log2();
+    match &mut parameters {
+        PBSParameters::PBS(parameters) => parameters.log2_p_fail = estimated_log2_p_fail,
+        PBSParameters::MultiBitPBS(parameters) => parameters.log2_p_fail = estimated_log2_p_fail,
+    }
+
+    Ok(parameters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::commons::security::check_lwe_security;
+    use crate::shortint::gen_keys;
+    use crate::shortint::parameters::{
+        ShortintParameterSet, ALL_MULTI_BIT_PARAMETER_VEC, ALL_PARAMETER_VEC,
+        ALL_PARAMETER_VEC_COMPACT_PK,
+    };
+
+    fn assert_secure(parameters: ShortintParameterSet, security_level: u32) {
+        let glwe_lwe_dimension = parameters
+            .glwe_dimension()
+            .to_equivalent_lwe_dimension(parameters.polynomial_size());
+
+        for (lwe_dimension, noise_distribution) in [
+            (
+                parameters.lwe_dimension(),
+                parameters.lwe_noise_distribution(),
+            ),
+            (glwe_lwe_dimension, parameters.glwe_noise_distribution()),
+        ] {
+            let result = check_lwe_security(
+                lwe_dimension,
+                noise_distribution.variance(LOG2_MODULUS),
+                LOG2_MODULUS,
+                security_level,
+            );
+            assert!(
+                result.is_ok(),
+                "Insecure parameters {parameters:?}: {}",
+                result.unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_parameters_security_ci_run_filter() {
+        let classic_parameters = ALL_PARAMETER_VEC
+            .iter()
+            .chain(ALL_PARAMETER_VEC_COMPACT_PK.iter())
+            .map(|parameters| ShortintParameterSet::from(*parameters));
+        let multi_bit_parameters = ALL_MULTI_BIT_PARAMETER_VEC
+            .iter()
+            .map(|parameters| ShortintParameterSet::from(*parameters));
+
+        for parameters in classic_parameters.chain(multi_bit_parameters) {
+            assert_secure(parameters, DEFAULT_SECURITY_LEVEL);
+        }
+    }
+
+    #[test]
+    fn test_optimized_parameters_ci_run_filter() {
+        let default_constraints = OptimizationConstraints::new(MessageModulus(4), CarryModulus(4));
+
+        for constraints in [
+            default_constraints,
+            OptimizationConstraints {
+                message_modulus: MessageModulus(2),
+                carry_modulus: CarryModulus(2),
+                noise_distribution_kind: NoiseDistributionKind::Gaussian,
+                log2_p_fail: -80.0,
+                ..default_constraints
+            },
+            OptimizationConstraints {
+                pbs_kind: PBSKind::MultiBit(LweBskGroupingFactor(3)),
+                security_level: 132,
+                ..default_constraints
+            },
+        ] {
+            let parameters = optimize_parameters(&constraints).unwrap();
+
+            assert_eq!(parameters.message_modulus(), constraints.message_modulus);
+            assert_eq!(parameters.carry_modulus(), constraints.carry_modulus);
+            assert!(parameters.log2_p_fail() <= constraints.log2_p_fail);
+            assert_secure(parameters.into(), constraints.security_level);
+
+            match (constraints.pbs_kind, parameters) {
+                (PBSKind::Classic, PBSParameters::PBS(_)) => {}
+                (PBSKind::MultiBit(grouping_factor), PBSParameters::MultiBitPBS(parameters)) => {
+                    assert_eq!(parameters.grouping_factor, grouping_factor);
+                    assert_eq!(parameters.lwe_dimension.0 % grouping_factor.0, 0);
+                }
+                _ => panic!("Unexpected parameters kind {parameters:?} for {constraints:?}"),
+            }
+
+            let noise_distribution_kind = match parameters.lwe_noise_distribution() {
+                DynamicDistribution::Gaussian(_) => NoiseDistributionKind::Gaussian,
+                DynamicDistribution::TUniform(_) => NoiseDistributionKind::TUniform,
+            };
+            assert_eq!(noise_distribution_kind, constraints.noise_distribution_kind);
+        }
+
+        // The optimized parameters can be used for actual computations
+        let parameters = optimize_parameters(&default_constraints).unwrap();
+        let (cks, sks) = gen_keys(parameters);
+        let modulus = cks.parameters.message_modulus().0 as u64;
+
+        let acc = sks.generate_lookup_table(|x| (x * 3) % modulus);
+        for msg in 0..modulus {
+            let ct = cks.encrypt(msg);
+            let res = sks.apply_lookup_table(&ct, &acc);
+            assert_eq!(cks.decrypt(&res), (msg * 3) % modulus);
+        }
+    }
+
+    #[test]
+    fn test_optimizer_invalid_constraints_ci_run_filter() {
+        let default_constraints = OptimizationConstraints::new(MessageModulus(4), CarryModulus(4));
+
+        for constraints in [
+            OptimizationConstraints {
+                message_modulus: MessageModulus(1),
+                ..default_constraints
+            },
+            OptimizationConstraints {
+                message_modulus: MessageModulus(3),
+                ..default_constraints
+            },
+            OptimizationConstraints {
+                carry_modulus: CarryModulus(6),
+                ..default_constraints
+            },
+            OptimizationConstraints {
+                pbs_kind: PBSKind::MultiBit(LweBskGroupingFactor(1)),
+                ..default_constraints
+            },
+            OptimizationConstraints {
+                pbs_kind: PBSKind::MultiBit(LweBskGroupingFactor(5)),
+                ..default_constraints
+            },
+            OptimizationConstraints {
+                pbs_kind: PBSKind::MultiBit(LweBskGroupingFactor(64)),
+                ..default_constraints
+            },
+        ] {
+            assert!(optimize_parameters(&constraints).is_err());
+        }
+    }
+}
@@ -1,18 +1,22 @@
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_add_assign;
+use crate::core_crypto::commons::generators::DeterministicSeeder;
 use crate::core_crypto::prelude::{
     allocate_and_generate_new_binary_lwe_secret_key,
     allocate_and_generate_new_seeded_lwe_compact_public_key, generate_lwe_compact_public_key,
-    Container, LweCiphertextCount, LweCompactCiphertextListOwned, LweCompactPublicKeyOwned,
-    LweSecretKey, Plaintext, PlaintextList, SeededLweCompactPublicKeyOwned,
+    lwe_ciphertext_add_assign, ActivatedRandomGenerator, Container, LweCiphertext,
+    LweCiphertextCount, LweCiphertextList, LweCompactCiphertextListOwned, LweCompactPublicKeyOwned,
+    LweSecretKey, Plaintext, PlaintextCount, PlaintextList, SeededLweCompactPublicKeyOwned,
 };
 #[cfg(feature = "zk-pok-experimental")]
 use crate::shortint::ciphertext::ProvenCompactCiphertextList;
-use crate::shortint::ciphertext::{CompactCiphertextList, Degree, NoiseLevel};
+use crate::shortint::ciphertext::{Ciphertext, CompactCiphertextList, Degree, NoiseLevel};
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::compact_public_key_only::CompactPublicKeyEncryptionParameters;
 use crate::shortint::{CarryModulus, ClientKey, MessageModulus};
 #[cfg(feature = "zk-pok-experimental")]
 use crate::zk::{CompactPkePublicParams, ZkComputeLoad};
 use crate::Error;
+use concrete_csprng::seeders::Seed;
 use serde::{Deserialize, Serialize};
 
 /// Private key from which a [`CompactPublicKey`] can be built.
@@ -91,10 +95,15 @@ impl<'res, 'key: 'res> TryFrom<&'key ClientKey> for CompactPrivateKey<&'res [u64
 /// Public key construction described in <https://eprint.iacr.org/2023/603> by M. Joye.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CompactPublicKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) key: LweCompactPublicKeyOwned<u64>,
     pub parameters: CompactPublicKeyEncryptionParameters,
 }
 
+impl crate::named::Named for CompactPublicKey {
+    const NAME: &'static str = "shortint::CompactPublicKey";
+}
+
 fn to_plaintext_iterator(
     message_iter: impl Iterator<Item = u64>,
     encryption_modulus: u64,
@@ -420,6 +429,156 @@ impl CompactPublicKey {
         Ok(ProvenCompactCiphertextList { proved_lists })
     }
 
+    /// Encrypts `count` zeros into a compact list, the randomness used by the encryption is
+    /// derived from the seed
+    fn encrypt_zeros_with_seed(
+        &self,
+        count: LweCiphertextCount,
+        seed: Seed,
+    ) -> LweCompactCiphertextListOwned<u64> {
+        let plaintext_list = PlaintextList::new(0u64, PlaintextCount(count.0));
+        let mut ct_list = LweCompactCiphertextListOwned::new(
+            0u64,
+            self.key.lwe_dimension().to_lwe_size(),
+            count,
+            self.parameters.ciphertext_modulus,
+        );
+
+        let encryption_noise_distribution = self.parameters.encryption_noise_distribution;
+        let mut engine = ShortintEngine::new_from_seeder(&mut DeterministicSeeder::<
+            ActivatedRandomGenerator,
+        >::new(seed));
+
+        // No parallelism allowed
+        #[cfg(all(feature = "__wasm_api", not(feature = "parallel-wasm-api")))]
+        {
+            use crate::core_crypto::prelude::encrypt_lwe_compact_ciphertext_list_with_compact_public_key;
+            encrypt_lwe_compact_ciphertext_list_with_compact_public_key(
+                &self.key,
+                &mut ct_list,
+                &plaintext_list,
+                encryption_noise_distribution,
+                encryption_noise_distribution,
+                &mut engine.secret_generator,
+                &mut engine.encryption_generator,
+            );
+        }
+
+        // Parallelism allowed
+        #[cfg(any(not(feature = "__wasm_api"), feature = "parallel-wasm-api"))]
+        {
+            use crate::core_crypto::prelude::par_encrypt_lwe_compact_ciphertext_list_with_compact_public_key;
+            par_encrypt_lwe_compact_ciphertext_list_with_compact_public_key(
+                &self.key,
+                &mut ct_list,
+                &plaintext_list,
+                encryption_noise_distribution,
+                encryption_noise_distribution,
+                &mut engine.secret_generator,
+                &mut engine.encryption_generator,
+            );
+        }
+
+        ct_list
+    }
+
+    /// Re-randomizes the ciphertexts by adding a fresh encryption of zero to each of them
+    ///
+    /// The randomness of the encryptions of zero is derived from the seed, so the same seed must
+    /// never be used twice.
+    ///
+    /// The noise of the ciphertexts is increased, they should be bootstrapped before being
+    /// used in further computations, see
+    /// [crate::shortint::ServerKey::message_extract_assign].
+    ///
+    /// Returns an error if the LWE dimension of the ciphertexts differs from the one of the public
+    /// key. The secret key itself cannot be checked, re-randomizing ciphertexts with the public
+    /// key of another secret key corrupts them.
+    pub fn re_randomize_ciphertexts(
+        &self,
+        cts: &mut [Ciphertext],
+        seed: Seed,
+    ) -> crate::Result<()> {
+        let lwe_size = self.key.lwe_dimension().to_lwe_size();
+        if cts.iter().any(|ct| ct.ct.lwe_size() != lwe_size) {
+            return Err(Error::new(String::from(
+                "Cannot re-randomize ciphertexts whose LWE dimension differs from the one \
+                of the CompactPublicKey",
+            )));
+        }
+
+        if cts.is_empty() {
+            return Ok(());
+        }
+
+        let zeros = self.encrypt_zeros_with_seed(LweCiphertextCount(cts.len()), seed);
+        let mut expanded_zeros = LweCiphertextList::new(
+            0u64,
+            lwe_size,
+            LweCiphertextCount(cts.len()),
+            self.parameters.ciphertext_modulus,
+        );
+
+        // No parallelism allowed
+        #[cfg(all(feature = "__wasm_api", not(feature = "parallel-wasm-api")))]
+        {
+            use crate::core_crypto::prelude::expand_lwe_compact_ciphertext_list;
+            expand_lwe_compact_ciphertext_list(&mut expanded_zeros, &zeros);
+        }
+
+        // Parallelism allowed
+        #[cfg(any(not(feature = "__wasm_api"), feature = "parallel-wasm-api"))]
+        {
+            use crate::core_crypto::prelude::par_expand_lwe_compact_ciphertext_list;
+            par_expand_lwe_compact_ciphertext_list(&mut expanded_zeros, &zeros);
+        }
+
+        for (ct, zero) in cts
+            .iter_mut()
+            .zip(expanded_zeros.as_ref().chunks_exact(lwe_size.0))
+        {
+            lwe_ciphertext_add_assign(
+                &mut ct.ct,
+                &LweCiphertext::from_container(zero, self.parameters.ciphertext_modulus),
+            );
+            ct.set_noise_level(ct.noise_level() + NoiseLevel::NOMINAL);
+            ct.set_noise_variance(None);
+        }
+
+        Ok(())
+    }
+
+    /// Re-randomizes the compact list by adding to it a fresh compact encryption of zeros
+    ///
+    /// The randomness of the encryptions of zero is derived from the seed, so the same seed must
+    /// never be used twice.
+    ///
+    /// Returns an error if the LWE dimension or the message and carry moduli of the list differ
+    /// from the ones of the public key.
+    pub fn re_randomize_compact_ciphertext_list(
+        &self,
+        list: &mut CompactCiphertextList,
+        seed: Seed,
+    ) -> crate::Result<()> {
+        if list.ct_list.lwe_size() != self.key.lwe_dimension().to_lwe_size()
+            || list.message_modulus != self.parameters.message_modulus
+            || list.carry_modulus != self.parameters.carry_modulus
+        {
+            return Err(Error::new(String::from(
+                "Cannot re-randomize a CompactCiphertextList that was not encrypted \
+                with the parameters of the CompactPublicKey",
+            )));
+        }
+
+        let zeros = self.encrypt_zeros_with_seed(list.ct_list.lwe_ciphertext_count(), seed);
+        // The expansion of compact lists is linear, adding the compact representations is the
+        // same as adding the expanded ciphertexts
+        slice_wrapping_add_assign(list.ct_list.as_mut(), zeros.as_ref());
+        list.noise_level += NoiseLevel::NOMINAL;
+
+        Ok(())
+    }
+
     pub fn size_elements(&self) -> usize {
         self.key.size_elements()
     }
@@ -431,10 +590,15 @@ impl CompactPublicKey {
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CompressedCompactPublicKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) key: SeededLweCompactPublicKeyOwned<u64>,
     pub parameters: CompactPublicKeyEncryptionParameters,
 }
 
+impl crate::named::Named for CompressedCompactPublicKey {
+    const NAME: &'static str = "shortint::CompressedCompactPublicKey";
+}
+
 impl CompressedCompactPublicKey {
     pub fn new<'data, C>(input_key: C) -> Self
     where
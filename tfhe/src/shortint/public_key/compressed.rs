@@ -10,11 +10,16 @@ use std::fmt::Debug;
 /// A structure containing a compressed public key.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CompressedPublicKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) lwe_public_key: SeededLwePublicKeyOwned<u64>,
     pub parameters: ShortintParameterSet,
     pub pbs_order: PBSOrder,
 }
 
+impl crate::named::Named for CompressedPublicKey {
+    const NAME: &'static str = "shortint::CompressedPublicKey";
+}
+
 impl CompressedPublicKey {
     /// Generate a public key.
     ///
@@ -10,11 +10,16 @@ use std::fmt::Debug;
 /// A structure containing a public key.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PublicKey {
+    #[serde(with = "crate::versionable::nested")]
     pub(crate) lwe_public_key: LwePublicKeyOwned<u64>,
     pub parameters: ShortintParameterSet,
     pub pbs_order: PBSOrder,
 }
 
+impl crate::named::Named for PublicKey {
+    const NAME: &'static str = "shortint::PublicKey";
+}
+
 impl PublicKey {
     /// Generate a public key.
     ///
@@ -11,8 +11,9 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ShortintCompressedBootstrappingKey {
-    Classic(SeededLweBootstrapKeyOwned<u64>),
+    Classic(#[serde(with = "crate::versionable::nested")] SeededLweBootstrapKeyOwned<u64>),
     MultiBit {
+        #[serde(with = "crate::versionable::nested")]
         seeded_bsk: SeededLweMultiBitBootstrapKeyOwned<u64>,
         deterministic_execution: bool,
     },
@@ -107,6 +108,7 @@ impl ShortintCompressedBootstrappingKey {
 /// sends it to the server so it can compute homomorphic circuits.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CompressedServerKey {
+    #[serde(with = "crate::versionable::nested")]
     pub key_switching_key: SeededLweKeyswitchKeyOwned<u64>,
     pub bootstrapping_key: ShortintCompressedBootstrappingKey,
     // Size of the message buffer
@@ -120,6 +122,10 @@ pub struct CompressedServerKey {
     pub pbs_order: PBSOrder,
 }
 
+impl crate::named::Named for CompressedServerKey {
+    const NAME: &'static str = "shortint::CompressedServerKey";
+}
+
 impl CompressedServerKey {
     /// Generate a compressed server key.
     ///
@@ -273,6 +279,8 @@ impl CompressedServerKey {
             max_noise_level,
             ciphertext_modulus,
             pbs_order,
+            noise_model: None,
+            key_switching_key_32: None,
         }
     }
 
@@ -0,0 +1,163 @@
+use super::{apply_blind_rotate, ServerKey};
+use crate::core_crypto::algorithms::{
+    keyswitch_lwe_ciphertext, keyswitch_lwe_ciphertext_with_scalar_change,
+};
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::prelude::CiphertextModulus;
+use crate::shortint::engine::BuffersRef;
+use crate::shortint::PBSOrder;
+
+impl ServerKey {
+    /// Enable the keyswitch to 32 bits ciphertexts before the programmable bootstraps.
+    ///
+    /// A copy of the keyswitching key with its coefficients rounded to their 32 most significant
+    /// bits is computed. It is then used by the lookup table applications and the modulus switch
+    /// compression, halving the memory read by the keyswitch and the size of the keyswitched
+    /// ciphertexts. The rounding adds a noise that is negligible compared to the one of the
+    /// keyswitch.
+    ///
+    /// The 32 bits keyswitching key is not serialized, it has to be enabled again after
+    /// deserializing the key. It is not taken into account when comparing keys either.
+    ///
+    /// Only the output of the keyswitch and the modulus switch compressed ciphertexts are 32
+    /// bits: [`Ciphertext`](crate::shortint::Ciphertext)s are still stored with 64 bits
+    /// coefficients, keys using the [`PBSOrder::BootstrapKeyswitch`] order are not supported, and
+    /// the boolean layer is not affected.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - the key does not use the [`PBSOrder::KeyswitchBootstrap`] order
+    /// - the ciphertext modulus is not a power of two greater or equal to $2^{32}$
+    /// - the keyswitch decomposition uses more than 32 bits
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, mut sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// sks.enable_32_bits_keyswitch();
+    /// assert!(sks.is_32_bits_keyswitch_enabled());
+    ///
+    /// let ct = cks.encrypt(3);
+    ///
+    /// let acc = sks.generate_lookup_table(|x| (x + 1) % 4);
+    /// let res = sks.apply_lookup_table(&ct, &acc);
+    /// assert_eq!(cks.decrypt(&res), 0);
+    ///
+    /// // The compression is done on the ciphertext keyswitched to 32 bits
+    /// let compressed_ct = sks.switch_modulus_and_compress(&ct);
+    /// let decompressed_ct = sks.decompress(&compressed_ct);
+    /// assert_eq!(cks.decrypt(&decompressed_ct), 3);
+    /// ```
+    pub fn enable_32_bits_keyswitch(&mut self) {
+        assert_eq!(
+            self.pbs_order,
+            PBSOrder::KeyswitchBootstrap,
+            "The 32 bits keyswitch is only supported with the KeyswitchBootstrap PBSOrder"
+        );
+
+        let ciphertext_modulus = self.key_switching_key.ciphertext_modulus();
+
+        assert!(
+            ciphertext_modulus.is_native_modulus()
+                || (ciphertext_modulus.is_power_of_two()
+                    && ciphertext_modulus.get_custom_modulus().ilog2() >= u32::BITS),
+            "The 32 bits keyswitch requires a power of two CiphertextModulus \
+            greater or equal to 2^32, got {ciphertext_modulus:?}"
+        );
+
+        let decomp_base_log = self.key_switching_key.decomposition_base_log();
+        let decomp_level_count = self.key_switching_key.decomposition_level_count();
+
+        assert!(
+            decomp_base_log.0 * decomp_level_count.0 <= u32::BITS as usize,
+            "The 32 bits keyswitch requires a DecompositionBaseLog and DecompositionLevelCount \
+            product smaller than 32, got {decomp_base_log:?} and {decomp_level_count:?}"
+        );
+
+        // Power of two moduli are encoded in the MSBs, the 32 MSBs of the key coefficients are
+        // still an encryption of the decomposition terms as those fit in 32 bits
+        let rounding_decomposer = SignedDecomposer::<u64>::new(
+            DecompositionBaseLog(u32::BITS as usize),
+            DecompositionLevelCount(1),
+        );
+        let shift = u64::BITS - u32::BITS;
+
+        let key_switching_key_32_data: Vec<u32> = self
+            .key_switching_key
+            .as_ref()
+            .iter()
+            .map(|&coefficient| {
+                (rounding_decomposer.closest_representable(coefficient) >> shift) as u32
+            })
+            .collect();
+
+        self.key_switching_key_32 = Some(LweKeyswitchKey::from_container(
+            key_switching_key_32_data,
+            decomp_base_log,
+            decomp_level_count,
+            self.key_switching_key.output_lwe_size(),
+            CiphertextModulus::new_native(),
+        ));
+    }
+
+    pub fn disable_32_bits_keyswitch(&mut self) {
+        self.key_switching_key_32 = None;
+    }
+
+    pub fn is_32_bits_keyswitch_enabled(&self) -> bool {
+        self.key_switching_key_32.is_some()
+    }
+
+    /// Keyswitch the input ciphertext and blind rotate the accumulator with the result.
+    ///
+    /// The keyswitch outputs a 32 bits ciphertext if the 32 bits keyswitch is enabled.
+    pub(crate) fn keyswitch_and_blind_rotate<InputCont, AccCont>(
+        &self,
+        input: &LweCiphertext<InputCont>,
+        acc: &mut GlweCiphertext<AccCont>,
+        ciphertext_buffers: &mut BuffersRef<'_>,
+        buffers: &mut ComputationBuffers,
+    ) where
+        InputCont: Container<Element = u64>,
+        AccCont: ContainerMut<Element = u64>,
+    {
+        match &self.key_switching_key_32 {
+            Some(key_switching_key_32) => {
+                keyswitch_lwe_ciphertext_with_scalar_change(
+                    key_switching_key_32,
+                    input,
+                    &mut ciphertext_buffers.buffer_lwe_after_ks_32,
+                );
+
+                apply_blind_rotate(
+                    &self.bootstrapping_key,
+                    &ciphertext_buffers.buffer_lwe_after_ks_32,
+                    acc,
+                    buffers,
+                );
+            }
+            None => {
+                keyswitch_lwe_ciphertext(
+                    &self.key_switching_key,
+                    input,
+                    &mut ciphertext_buffers.buffer_lwe_after_ks,
+                );
+
+                apply_blind_rotate(
+                    &self.bootstrapping_key,
+                    &ciphertext_buffers.buffer_lwe_after_ks,
+                    acc,
+                    buffers,
+                );
+            }
+        }
+    }
+}
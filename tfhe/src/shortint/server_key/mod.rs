@@ -7,9 +7,11 @@ mod bitwise_op;
 mod bivariate_pbs;
 mod comp_op;
 mod div_mod;
+mod keyswitch_32;
 mod modulus_switched_compression;
 mod mul;
 mod neg;
+mod noise_tracking;
 mod scalar_add;
 mod scalar_bitwise_op;
 mod scalar_mul;
@@ -22,11 +24,16 @@ pub use bivariate_pbs::{
     BivariateLookupTableMutView, BivariateLookupTableOwned, BivariateLookupTableView,
 };
 pub use compressed::{CompressedServerKey, ShortintCompressedBootstrappingKey};
+pub use noise_tracking::NoiseModel;
+pub(crate) use noise_tracking::{
+    add_noise_variances, ciphertext_modulus_log2, scale_noise_variance,
+};
 
 #[cfg(test)]
 pub(crate) mod tests;
 
 use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::dispersion::Variance;
 use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, MonomialDegree,
     PolynomialSize, ThreadCount,
@@ -328,8 +335,9 @@ impl ShortintBootstrappingKey {
 ///
 /// The server key is generated by the client and is meant to be published: the client
 /// sends it to the server so it can compute homomorphic circuits.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ServerKey {
+    #[serde(with = "crate::versionable::nested")]
     pub key_switching_key: LweKeyswitchKeyOwned<u64>,
     pub bootstrapping_key: ShortintBootstrappingKey,
     // Size of the message buffer
@@ -342,6 +350,44 @@ pub struct ServerKey {
     // Modulus use for computations on the ciphertext
     pub ciphertext_modulus: CiphertextModulus,
     pub pbs_order: PBSOrder,
+    // Only used to estimate the noise of ciphertexts, see ServerKey::enable_noise_tracking
+    #[serde(skip)]
+    pub(crate) noise_model: Option<NoiseModel>,
+    // Only used to keyswitch to 32 bits ciphertexts, see ServerKey::enable_32_bits_keyswitch
+    #[serde(skip)]
+    pub(crate) key_switching_key_32: Option<LweKeyswitchKeyOwned<u32>>,
+}
+
+impl crate::named::Named for ServerKey {
+    const NAME: &'static str = "shortint::ServerKey";
+}
+
+// The noise model and the 32 bits keyswitching key are derived from the other members and are not
+// serialized, they are not taken into account when comparing keys
+impl PartialEq for ServerKey {
+    fn eq(&self, other: &Self) -> bool {
+        let Self {
+            key_switching_key,
+            bootstrapping_key,
+            message_modulus,
+            carry_modulus,
+            max_degree,
+            max_noise_level,
+            ciphertext_modulus,
+            pbs_order,
+            noise_model: _,
+            key_switching_key_32: _,
+        } = self;
+
+        *key_switching_key == other.key_switching_key
+            && *bootstrapping_key == other.bootstrapping_key
+            && *message_modulus == other.message_modulus
+            && *carry_modulus == other.carry_modulus
+            && *max_degree == other.max_degree
+            && *max_noise_level == other.max_noise_level
+            && *ciphertext_modulus == other.ciphertext_modulus
+            && *pbs_order == other.pbs_order
+    }
 }
 
 impl ServerKey {
@@ -457,6 +503,8 @@ impl ServerKey {
             max_noise_level,
             ciphertext_modulus,
             pbs_order,
+            noise_model: _,
+            key_switching_key_32: _,
         } = self;
 
         (
@@ -530,6 +578,8 @@ impl ServerKey {
             max_noise_level,
             ciphertext_modulus,
             pbs_order,
+            noise_model: None,
+            key_switching_key_32: None,
         }
     }
 
@@ -561,25 +611,14 @@ impl ServerKey {
     where
         F: Fn(u64) -> u64,
     {
-        let mut acc = GlweCiphertext::new(
-            0,
+        generate_lookup_table(
             self.bootstrapping_key.glwe_size(),
             self.bootstrapping_key.polynomial_size(),
             self.ciphertext_modulus,
-        );
-        let max_value = fill_accumulator(
-            &mut acc,
-            self.bootstrapping_key.polynomial_size(),
-            self.bootstrapping_key.glwe_size(),
             self.message_modulus,
             self.carry_modulus,
             f,
-        );
-
-        LookupTableOwned {
-            acc,
-            degree: Degree::new(max_value as usize),
-        }
+        )
     }
 
     pub(crate) fn generate_lookup_table_no_encode<F>(&self, f: F) -> LookupTableOwned
@@ -756,18 +795,19 @@ impl ServerKey {
             let (mut ciphertext_buffers, buffers) = engine.get_buffers(self);
             match self.pbs_order {
                 PBSOrder::KeyswitchBootstrap => {
-                    keyswitch_lwe_ciphertext(
-                        &self.key_switching_key,
+                    let mut glwe_out = acc.acc.clone();
+
+                    self.keyswitch_and_blind_rotate(
                         &ct.ct,
-                        &mut ciphertext_buffers.buffer_lwe_after_ks,
+                        &mut glwe_out,
+                        &mut ciphertext_buffers,
+                        buffers,
                     );
 
-                    apply_programmable_bootstrap(
-                        &self.bootstrapping_key,
-                        &ciphertext_buffers.buffer_lwe_after_ks,
+                    extract_lwe_sample_from_glwe_ciphertext(
+                        &glwe_out,
                         &mut ct.ct,
-                        acc,
-                        buffers,
+                        MonomialDegree(0),
                     );
                 }
                 PBSOrder::BootstrapKeyswitch => {
@@ -790,6 +830,7 @@ impl ServerKey {
 
         ct.degree = acc.degree;
         ct.set_noise_level(NoiseLevel::NOMINAL);
+        ct.set_noise_variance(self.bootstrapped_noise_variance());
     }
 
     /// Compute a keyswitch and programmable bootstrap applying several functions on an input
@@ -1125,6 +1166,7 @@ impl ServerKey {
 
         ct.degree = Degree::new(modular_value);
         ct.set_noise_level(NoiseLevel::ZERO);
+        ct.set_noise_variance(Some(Variance(0.0)));
     }
 
     pub fn bootstrapping_key_size_elements(&self) -> usize {
@@ -1245,19 +1287,8 @@ impl ServerKey {
             // Compute the programmable bootstrapping with fixed test polynomial
             let (mut ciphertext_buffers, buffers) = engine.get_buffers(self);
 
-            // Compute a key switch
-            keyswitch_lwe_ciphertext(
-                &self.key_switching_key,
-                &ct.ct,
-                &mut ciphertext_buffers.buffer_lwe_after_ks,
-            );
-
-            apply_blind_rotate(
-                &self.bootstrapping_key,
-                &ciphertext_buffers.buffer_lwe_after_ks.as_view(),
-                &mut acc,
-                buffers,
-            );
+            // Compute a key switch followed by the blind rotation
+            self.keyswitch_and_blind_rotate(&ct.ct, &mut acc, &mut ciphertext_buffers, buffers);
         });
 
         // The accumulator has been rotated, we can now proceed with the various sample extractions
@@ -1276,6 +1307,7 @@ impl ServerKey {
 
             output_shortint_ct.degree = *output_degree;
             output_shortint_ct.set_noise_level(NoiseLevel::NOMINAL);
+            output_shortint_ct.set_noise_variance(self.bootstrapped_noise_variance());
             outputs.push(output_shortint_ct);
         }
 
@@ -1327,6 +1359,7 @@ impl ServerKey {
 
             output_shortint_ct.degree = *output_degree;
             output_shortint_ct.set_noise_level(NoiseLevel::NOMINAL);
+            output_shortint_ct.set_noise_variance(self.bootstrapped_noise_variance());
             outputs.push(output_shortint_ct);
         }
 
@@ -1412,15 +1445,43 @@ impl ServerKey {
     }
 }
 
-pub(crate) fn apply_blind_rotate<Scalar, InputCont, OutputCont>(
+pub(crate) fn generate_lookup_table<F>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    ciphertext_modulus: CiphertextModulus,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    f: F,
+) -> LookupTableOwned
+where
+    F: Fn(u64) -> u64,
+{
+    let mut acc = GlweCiphertext::new(0, glwe_size, polynomial_size, ciphertext_modulus);
+    let max_value = fill_accumulator(
+        &mut acc,
+        polynomial_size,
+        glwe_size,
+        message_modulus,
+        carry_modulus,
+        f,
+    );
+
+    LookupTableOwned {
+        acc,
+        degree: Degree::new(max_value as usize),
+    }
+}
+
+pub(crate) fn apply_blind_rotate<InputScalar, OutputScalar, InputCont, OutputCont>(
     bootstrapping_key: &ShortintBootstrappingKey,
     in_buffer: &LweCiphertext<InputCont>,
     acc: &mut GlweCiphertext<OutputCont>,
     buffers: &mut ComputationBuffers,
 ) where
-    Scalar: UnsignedTorus + CastInto<usize> + CastFrom<usize> + Sync,
-    InputCont: Container<Element = Scalar>,
-    OutputCont: ContainerMut<Element = Scalar>,
+    InputScalar: UnsignedTorus + CastInto<usize> + CastFrom<usize> + Sync,
+    OutputScalar: UnsignedTorus + Sync,
+    InputCont: Container<Element = InputScalar>,
+    OutputCont: ContainerMut<Element = OutputScalar>,
 {
     #[cfg(feature = "pbs-stats")]
     let _ = PBS_COUNT.fetch_add(1, Ordering::Relaxed);
@@ -1430,7 +1491,7 @@ pub(crate) fn apply_blind_rotate<Scalar, InputCont, OutputCont>(
             let fft = Fft::new(fourier_bsk.polynomial_size());
             let fft = fft.as_view();
             buffers.resize(
-                programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+                programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<OutputScalar>(
                     fourier_bsk.glwe_size(),
                     fourier_bsk.polynomial_size(),
                     fft,
@@ -1459,14 +1520,15 @@ pub(crate) fn apply_blind_rotate<Scalar, InputCont, OutputCont>(
     };
 }
 
-pub(crate) fn apply_programmable_bootstrap<InputCont, OutputCont>(
+pub(crate) fn apply_programmable_bootstrap<InputScalar, InputCont, OutputCont>(
     bootstrapping_key: &ShortintBootstrappingKey,
     in_buffer: &LweCiphertext<InputCont>,
     out_buffer: &mut LweCiphertext<OutputCont>,
     acc: &LookupTableOwned,
     buffers: &mut ComputationBuffers,
 ) where
-    InputCont: Container<Element = u64>,
+    InputScalar: UnsignedTorus + CastInto<usize> + CastFrom<usize> + Sync,
+    InputCont: Container<Element = InputScalar>,
     OutputCont: ContainerMut<Element = u64>,
 {
     let mut glwe_out = acc.acc.clone();
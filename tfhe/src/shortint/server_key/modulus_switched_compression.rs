@@ -5,7 +5,9 @@ use super::{
 };
 use crate::core_crypto::commons::parameters::MonomialDegree;
 use crate::core_crypto::prelude::compressed_modulus_switched_lwe_ciphertext::CompressedModulusSwitchedLweCiphertext;
-use crate::core_crypto::prelude::{keyswitch_lwe_ciphertext, LweCiphertext};
+use crate::core_crypto::prelude::{
+    keyswitch_lwe_ciphertext, keyswitch_lwe_ciphertext_with_scalar_change, LweCiphertext,
+};
 use crate::shortint::ciphertext::{
     CompressedModulusSwitchedCiphertext, InternalCompressedModulusSwitchedCiphertext, NoiseLevel,
 };
@@ -26,6 +28,39 @@ impl ServerKey {
                 let (mut ciphertext_buffers, _) = engine.get_buffers(self);
                 match self.pbs_order {
                     PBSOrder::KeyswitchBootstrap => {
+                        if let Some(key_switching_key_32) = &self.key_switching_key_32 {
+                            keyswitch_lwe_ciphertext_with_scalar_change(
+                                key_switching_key_32,
+                                &ct.ct,
+                                &mut ciphertext_buffers.buffer_lwe_after_ks_32,
+                            );
+
+                            let log_modulus = self
+                                .bootstrapping_key
+                                .polynomial_size()
+                                .to_blind_rotation_input_modulus_log();
+
+                            return match &self.bootstrapping_key {
+                                ShortintBootstrappingKey::Classic(_) => {
+                                    InternalCompressedModulusSwitchedCiphertext::Classic32(
+                                        CompressedModulusSwitchedLweCiphertext::compress(
+                                            &ciphertext_buffers.buffer_lwe_after_ks_32,
+                                            log_modulus,
+                                        ),
+                                    )
+                                }
+                                ShortintBootstrappingKey::MultiBit { fourier_bsk, .. } => {
+                                    InternalCompressedModulusSwitchedCiphertext::MultiBit32(
+                                        CompressedModulusSwitchedMultiBitLweCiphertext::compress(
+                                            &ciphertext_buffers.buffer_lwe_after_ks_32,
+                                            log_modulus,
+                                            fourier_bsk.grouping_factor(),
+                                        ),
+                                    )
+                                }
+                            };
+                        }
+
                         keyswitch_lwe_ciphertext(
                             &self.key_switching_key,
                             &ct.ct,
@@ -133,19 +168,30 @@ impl ServerKey {
 
             match &self.bootstrapping_key {
                 ShortintBootstrappingKey::Classic(_) => {
-                    let ct = match &compressed_ct.compressed_modulus_switched_lwe_ciphertext {
-                        InternalCompressedModulusSwitchedCiphertext::Classic(a) => a.extract(),
-                        InternalCompressedModulusSwitchedCiphertext::MultiBit(_) => {
+                    match &compressed_ct.compressed_modulus_switched_lwe_ciphertext {
+                        InternalCompressedModulusSwitchedCiphertext::Classic(a) => {
+                            apply_programmable_bootstrap(
+                                &self.bootstrapping_key,
+                                &a.extract(),
+                                &mut ciphertext_buffers.buffer_lwe_after_pbs,
+                                acc,
+                                buffers,
+                            );
+                        }
+                        InternalCompressedModulusSwitchedCiphertext::Classic32(a) => {
+                            apply_programmable_bootstrap(
+                                &self.bootstrapping_key,
+                                &a.extract(),
+                                &mut ciphertext_buffers.buffer_lwe_after_pbs,
+                                acc,
+                                buffers,
+                            );
+                        }
+                        InternalCompressedModulusSwitchedCiphertext::MultiBit(_)
+                        | InternalCompressedModulusSwitchedCiphertext::MultiBit32(_) => {
                             panic!("Compression was done targeting a MultiBit bootstrap decompression, cannot decompress with a Classic bootstrapping key")
                         }
-                    };
-                    apply_programmable_bootstrap(
-                        &self.bootstrapping_key,
-                        &ct,
-                        &mut ciphertext_buffers.buffer_lwe_after_pbs,
-                        acc,
-                        buffers,
-                    );
+                    }
                 }
                 ShortintBootstrappingKey::MultiBit {
                     fourier_bsk,
@@ -154,7 +200,9 @@ impl ServerKey {
                 } => {
                     let ct = match &compressed_ct.compressed_modulus_switched_lwe_ciphertext {
                         InternalCompressedModulusSwitchedCiphertext::MultiBit(a) => a.extract(),
-                        InternalCompressedModulusSwitchedCiphertext::Classic(_) => {
+                        InternalCompressedModulusSwitchedCiphertext::MultiBit32(a) => a.extract(),
+                        InternalCompressedModulusSwitchedCiphertext::Classic(_)
+                        | InternalCompressedModulusSwitchedCiphertext::Classic32(_) => {
                             panic!("Compression was done targeting a Classic bootstrap decompression, cannot decompress with a MultiBit bootstrapping key")
                         }
                     };
@@ -0,0 +1,278 @@
+use super::{BivariateLookupTableOwned, ServerKey};
+use crate::core_crypto::commons::dispersion::{
+    failure_probability, lwe_keyswitch_additive_variance, modulus_switch_additive_variance,
+    multi_bit_pbs_output_variance, Variance,
+};
+use crate::core_crypto::prelude::{CiphertextModulus, LweBskGroupingFactor};
+use crate::shortint::ciphertext::Ciphertext;
+use crate::shortint::parameters::{CarryModulus, MessageModulus, PBSParameters};
+use crate::shortint::PBSOrder;
+
+/// Estimations of the noise added by the primitives of a parameter set.
+///
+/// All variances are expressed on the torus.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NoiseModel {
+    fresh_encryption_variance: Variance,
+    keyswitch_additive_variance: Variance,
+    modulus_switch_additive_variance: Variance,
+    blind_rotation_output_variance: Variance,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    pbs_order: PBSOrder,
+}
+
+pub(crate) fn ciphertext_modulus_log2(ciphertext_modulus: CiphertextModulus<u64>) -> u32 {
+    if ciphertext_modulus.is_native_modulus() {
+        u64::BITS
+    } else {
+        (ciphertext_modulus.get_custom_modulus() as f64)
+            .log2()
+            .ceil() as u32
+    }
+}
+
+impl NoiseModel {
+    pub fn new<P: Into<PBSParameters>>(parameters: P) -> Self {
+        let parameters = parameters.into();
+        let log2_modulus = ciphertext_modulus_log2(parameters.ciphertext_modulus());
+
+        let grouping_factor = match parameters {
+            PBSParameters::PBS(_) => LweBskGroupingFactor(1),
+            PBSParameters::MultiBitPBS(params) => params.grouping_factor,
+        };
+
+        let lwe_noise_variance = parameters.lwe_noise_distribution().variance(log2_modulus);
+        let glwe_noise_variance = parameters.glwe_noise_distribution().variance(log2_modulus);
+
+        let pbs_order = PBSOrder::from(parameters.encryption_key_choice());
+        let fresh_encryption_variance = match pbs_order {
+            PBSOrder::KeyswitchBootstrap => glwe_noise_variance,
+            PBSOrder::BootstrapKeyswitch => lwe_noise_variance,
+        };
+
+        // The keyswitch always goes from the big key to the small key
+        let keyswitch_additive_variance = lwe_keyswitch_additive_variance(
+            parameters
+                .glwe_dimension()
+                .to_equivalent_lwe_dimension(parameters.polynomial_size()),
+            parameters.ks_base_log(),
+            parameters.ks_level(),
+            lwe_noise_variance,
+            log2_modulus,
+        );
+
+        let modulus_switch_additive_variance = modulus_switch_additive_variance(
+            parameters.lwe_dimension(),
+            parameters.polynomial_size(),
+            log2_modulus,
+        );
+
+        let blind_rotation_output_variance = multi_bit_pbs_output_variance(
+            parameters.lwe_dimension(),
+            parameters.glwe_dimension(),
+            parameters.polynomial_size(),
+            parameters.pbs_base_log(),
+            parameters.pbs_level(),
+            grouping_factor,
+            glwe_noise_variance,
+            log2_modulus,
+        );
+
+        Self {
+            fresh_encryption_variance,
+            keyswitch_additive_variance,
+            modulus_switch_additive_variance,
+            blind_rotation_output_variance,
+            message_modulus: parameters.message_modulus(),
+            carry_modulus: parameters.carry_modulus(),
+            pbs_order,
+        }
+    }
+
+    /// Variance of a ciphertext freshly encrypted with a secret key
+    pub fn fresh_encryption_variance(&self) -> Variance {
+        self.fresh_encryption_variance
+    }
+
+    /// Variance added by a keyswitch
+    pub fn keyswitch_additive_variance(&self) -> Variance {
+        self.keyswitch_additive_variance
+    }
+
+    /// Variance added by the modulus switch at the beginning of a PBS
+    pub fn modulus_switch_additive_variance(&self) -> Variance {
+        self.modulus_switch_additive_variance
+    }
+
+    /// Variance of the ciphertext resulting from a lookup table application, i.e. after the PBS
+    /// and the keyswitch, whatever the [`PBSOrder`].
+    pub fn bootstrapped_variance(&self) -> Variance {
+        match self.pbs_order {
+            PBSOrder::KeyswitchBootstrap => self.blind_rotation_output_variance,
+            PBSOrder::BootstrapKeyswitch => {
+                Variance(self.blind_rotation_output_variance.0 + self.keyswitch_additive_variance.0)
+            }
+        }
+    }
+
+    /// Variance seen by the blind rotation when applying a lookup table on a ciphertext with the
+    /// given variance.
+    pub fn blind_rotation_input_variance(&self, input_variance: Variance) -> Variance {
+        let before_modulus_switch = match self.pbs_order {
+            PBSOrder::KeyswitchBootstrap => input_variance.0 + self.keyswitch_additive_variance.0,
+            PBSOrder::BootstrapKeyswitch => input_variance.0,
+        };
+
+        Variance(before_modulus_switch + self.modulus_switch_additive_variance.0)
+    }
+
+    /// Probability that a noise with the given variance makes the decoding of a message fail.
+    ///
+    /// Messages are encoded with a scaling factor $\Delta = \frac{1}{2 \cdot p}$ on the torus
+    /// (the extra 2 being the padding bit), with $p$ the product of the message and carry moduli.
+    /// Decoding fails as soon as the noise is greater than $\frac{\Delta}{2}$.
+    pub fn failure_probability(&self, variance: Variance) -> f64 {
+        let full_modulus = (self.message_modulus.0 * self.carry_modulus.0) as f64;
+        failure_probability(variance, 1.0 / (4.0 * full_modulus))
+    }
+
+    /// Probability that a lookup table application on a ciphertext with the given variance gives
+    /// a wrong result.
+    pub fn pbs_failure_probability(&self, input_variance: Variance) -> f64 {
+        self.failure_probability(self.blind_rotation_input_variance(input_variance))
+    }
+}
+
+impl ServerKey {
+    /// Enable the noise tracking of the ciphertexts going through this key.
+    ///
+    /// The [`NoiseModel`] is computed from the given parameters which must be the ones used to
+    /// generate the key.
+    ///
+    /// Once enabled, the operations (linear operations and lookup table applications) update the
+    /// estimation of the noise variance of the ciphertexts, see [`Ciphertext::noise_variance`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters do not match the ones of the key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, mut sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// sks.enable_noise_tracking(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct1 = cks.encrypt(1);
+    /// let ct2 = cks.encrypt(2);
+    ///
+    /// // Query the failure probability before running the PBS
+    /// let mut ct = sks.unchecked_add(&ct1, &ct2);
+    /// let p_fail = sks.apply_lookup_table_failure_probability(&ct).unwrap();
+    /// assert!(p_fail < 2f64.powi(-40));
+    ///
+    /// sks.message_extract_assign(&mut ct);
+    /// let noise_model = sks.noise_model().unwrap();
+    /// assert_eq!(ct.noise_variance(), Some(noise_model.bootstrapped_variance()));
+    /// assert_eq!(cks.decrypt(&ct), 3);
+    /// ```
+    pub fn enable_noise_tracking<P: Into<PBSParameters>>(&mut self, parameters: P) {
+        let parameters = parameters.into();
+
+        assert_eq!(
+            self.bootstrapping_key.input_lwe_dimension(),
+            parameters.lwe_dimension(),
+            "The parameters LweDimension does not match the ServerKey"
+        );
+        assert_eq!(
+            self.bootstrapping_key.polynomial_size(),
+            parameters.polynomial_size(),
+            "The parameters PolynomialSize does not match the ServerKey"
+        );
+        assert_eq!(
+            self.bootstrapping_key.glwe_size(),
+            parameters.glwe_dimension().to_glwe_size(),
+            "The parameters GlweDimension does not match the ServerKey"
+        );
+        assert_eq!(
+            (self.message_modulus, self.carry_modulus),
+            (parameters.message_modulus(), parameters.carry_modulus()),
+            "The parameters message and carry moduli do not match the ServerKey"
+        );
+
+        self.noise_model = Some(NoiseModel::new(parameters));
+    }
+
+    pub fn disable_noise_tracking(&mut self) {
+        self.noise_model = None;
+    }
+
+    pub fn noise_model(&self) -> Option<&NoiseModel> {
+        self.noise_model.as_ref()
+    }
+
+    /// Returns the estimated probability that applying a lookup table on the ciphertext gives a
+    /// wrong result.
+    ///
+    /// Returns `None` if noise tracking is not enabled or if the noise of the ciphertext is not
+    /// known.
+    pub fn apply_lookup_table_failure_probability(&self, ct: &Ciphertext) -> Option<f64> {
+        let noise_model = self.noise_model.as_ref()?;
+        let variance = ct.noise_variance()?;
+
+        Some(noise_model.pbs_failure_probability(variance))
+    }
+
+    /// Returns the estimated probability that applying a bivariate lookup table on the
+    /// ciphertexts gives a wrong result.
+    ///
+    /// Returns `None` if noise tracking is not enabled or if the noise of one of the ciphertexts
+    /// is not known.
+    pub fn apply_lookup_table_bivariate_failure_probability(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+        acc: &BivariateLookupTableOwned,
+    ) -> Option<f64> {
+        let noise_model = self.noise_model.as_ref()?;
+        let variance = add_noise_variances(
+            scale_noise_variance(ct_left.noise_variance(), acc.ct_right_modulus.0 as u64),
+            ct_right.noise_variance(),
+        )?;
+
+        Some(noise_model.pbs_failure_probability(variance))
+    }
+
+    /// Returns the estimated probability that decrypting the ciphertext gives a wrong result.
+    ///
+    /// Returns `None` if noise tracking is not enabled or if the noise of the ciphertext is not
+    /// known.
+    pub fn decryption_failure_probability(&self, ct: &Ciphertext) -> Option<f64> {
+        let noise_model = self.noise_model.as_ref()?;
+        let variance = ct.noise_variance()?;
+
+        Some(noise_model.failure_probability(variance))
+    }
+
+    /// Variance of the output of a lookup table application, if noise tracking is enabled
+    pub(crate) fn bootstrapped_noise_variance(&self) -> Option<Variance> {
+        self.noise_model
+            .as_ref()
+            .map(NoiseModel::bootstrapped_variance)
+    }
+}
+
+pub(crate) fn add_noise_variances(
+    lhs: Option<Variance>,
+    rhs: Option<Variance>,
+) -> Option<Variance> {
+    Some(Variance(lhs?.0 + rhs?.0))
+}
+
+pub(crate) fn scale_noise_variance(variance: Option<Variance>, scalar: u64) -> Option<Variance> {
+    let scalar = scalar as f64;
+    variance.map(|variance| Variance(variance.0 * scalar * scalar))
+}
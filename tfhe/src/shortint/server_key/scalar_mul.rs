@@ -1,4 +1,4 @@
-use super::CiphertextNoiseDegree;
+use super::{scale_noise_variance, CiphertextNoiseDegree};
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::entities::*;
 use crate::shortint::ciphertext::Degree;
@@ -518,6 +518,7 @@ impl ServerKey {
 
 pub(crate) fn unchecked_scalar_mul_assign(ct: &mut Ciphertext, scalar: u8) {
     ct.set_noise_level(ct.noise_level() * scalar as usize);
+    ct.set_noise_variance(scale_noise_variance(ct.noise_variance(), scalar as u64));
     ct.degree = Degree::new(ct.degree.get() * scalar as usize);
 
     match scalar {
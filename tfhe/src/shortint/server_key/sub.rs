@@ -1,4 +1,4 @@
-use super::{CiphertextNoiseDegree, SmartCleaningOperation};
+use super::{add_noise_variances, CiphertextNoiseDegree, SmartCleaningOperation};
 use crate::core_crypto::algorithms::*;
 use crate::shortint::ciphertext::Degree;
 use crate::shortint::server_key::CheckError;
@@ -403,6 +403,10 @@ impl ServerKey {
         lwe_ciphertext_add_assign(&mut ct_left.ct, &neg_right.ct);
 
         ct_left.set_noise_level(ct_left.noise_level() + ct_right.noise_level());
+        ct_left.set_noise_variance(add_noise_variances(
+            ct_left.noise_variance(),
+            ct_right.noise_variance(),
+        ));
         ct_left.degree = Degree::new(ct_left.degree.get() + z as usize);
 
         z
@@ -0,0 +1,82 @@
+use super::NB_TESTS;
+use crate::conformance::ParameterSetConformant;
+use crate::shortint::keycache::KEY_CACHE;
+use crate::shortint::parameters::{
+    PBSParameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+};
+use crate::shortint::ServerKey;
+use rand::Rng;
+
+#[test]
+fn test_32_bits_keyswitch_ci_run_filter() {
+    for params in [
+        PBSParameters::from(PARAM_MESSAGE_2_CARRY_2_KS_PBS),
+        PBSParameters::from(PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS),
+    ] {
+        let keys = KEY_CACHE.get_from_param(params);
+        let (cks, mut sks) = (keys.client_key(), keys.server_key().clone());
+
+        assert!(!sks.is_32_bits_keyswitch_enabled());
+        sks.enable_32_bits_keyswitch();
+        assert!(sks.is_32_bits_keyswitch_enabled());
+
+        let mut rng = rand::thread_rng();
+
+        let modulus = cks.parameters.message_modulus().0 as u64;
+
+        let f1 = |x: u64| (x + 1) % modulus;
+        let f2 = |x: u64| (x * x) % modulus;
+
+        // The ciphertexts are compressed after the keyswitch
+        let mut compressed_params = sks.conformance_params();
+        compressed_params.ct_params.lwe_dim = sks.bootstrapping_key.input_lwe_dimension();
+
+        let lut = sks.generate_lookup_table(f1);
+        let many_lut = sks.generate_many_lookup_table(&[&f1, &f2]);
+
+        for _ in 0..NB_TESTS {
+            let clear = rng.gen::<u64>() % modulus;
+
+            let ct = cks.encrypt(clear);
+
+            let res = sks.apply_lookup_table(&ct, &lut);
+            assert_eq!(cks.decrypt(&res), f1(clear));
+
+            let many_res = sks.apply_many_lookup_table(&ct, &many_lut);
+            assert_eq!(cks.decrypt(&many_res[0]), f1(clear));
+            assert_eq!(cks.decrypt(&many_res[1]), f2(clear));
+
+            let compressed_ct = sks.switch_modulus_and_compress(&ct);
+            assert!(compressed_ct.is_conformant(&compressed_params));
+
+            let decompressed_ct = sks.decompress(&compressed_ct);
+            assert_eq!(cks.decrypt(&decompressed_ct), clear);
+            assert_eq!(ct.degree, decompressed_ct.degree);
+
+            let decompressed_ct = sks.decompress_and_apply_lookup_table(&compressed_ct, &lut);
+            assert_eq!(cks.decrypt(&decompressed_ct), f1(clear));
+        }
+
+        // The 32 bits keyswitching key is neither serialized nor compared
+        let serialized = bincode::serialize(&sks).unwrap();
+        let mut deserialized: ServerKey = bincode::deserialize(&serialized).unwrap();
+        assert!(!deserialized.is_32_bits_keyswitch_enabled());
+        assert_eq!(deserialized, sks);
+
+        deserialized.enable_32_bits_keyswitch();
+        assert_eq!(deserialized.key_switching_key_32, sks.key_switching_key_32);
+
+        sks.disable_32_bits_keyswitch();
+        assert!(!sks.is_32_bits_keyswitch_enabled());
+    }
+}
+
+#[test]
+#[should_panic(expected = "KeyswitchBootstrap")]
+fn test_32_bits_keyswitch_pbs_ks_panics() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_PBS_KS);
+    let mut sks = keys.server_key().clone();
+
+    sks.enable_32_bits_keyswitch();
+}
@@ -1,5 +1,7 @@
+pub mod keyswitch_32;
 pub mod modulus_switch_compression;
 pub mod noise_level;
+pub mod noise_tracking;
 pub mod parametrized_test;
 pub mod parametrized_test_bivariate_pbs_compliant;
 pub mod shortint_compact_pk;
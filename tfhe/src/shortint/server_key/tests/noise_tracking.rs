@@ -0,0 +1,118 @@
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::shortint::keycache::KEY_CACHE;
+use crate::shortint::parameters::{
+    PBSParameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+};
+use crate::shortint::server_key::NoiseModel;
+
+#[test]
+fn test_noise_variance_propagation_ci_run_filter() {
+    for params in [
+        PBSParameters::from(PARAM_MESSAGE_2_CARRY_2_KS_PBS),
+        PBSParameters::from(PARAM_MESSAGE_2_CARRY_2_PBS_KS),
+    ] {
+        let keys = KEY_CACHE.get_from_param(params);
+        let (cks, mut sks) = (keys.client_key(), keys.server_key().clone());
+
+        let ct = cks.encrypt(1);
+        let noise_model = NoiseModel::new(params);
+        let fresh_variance = noise_model.fresh_encryption_variance();
+        assert_eq!(ct.noise_variance(), Some(fresh_variance));
+
+        // Without tracking the noise after a PBS is unknown
+        let message_modulus = sks.message_modulus.0 as u64;
+        let acc = sks.generate_lookup_table(|x| x % message_modulus);
+        let res = sks.apply_lookup_table(&ct, &acc);
+        assert_eq!(res.noise_variance(), None);
+        assert_eq!(sks.apply_lookup_table_failure_probability(&ct), None);
+
+        sks.enable_noise_tracking(params);
+        assert_eq!(sks.noise_model(), Some(&noise_model));
+
+        let res = sks.unchecked_add(&ct, &ct);
+        assert_eq!(res.noise_variance(), Some(Variance(2.0 * fresh_variance.0)));
+
+        let res = sks.unchecked_sub(&res, &ct);
+        assert_eq!(res.noise_variance(), Some(Variance(3.0 * fresh_variance.0)));
+
+        let res = sks.unchecked_scalar_mul(&ct, 3);
+        assert_eq!(res.noise_variance(), Some(Variance(9.0 * fresh_variance.0)));
+
+        let res = sks.unchecked_neg(&ct);
+        assert_eq!(res.noise_variance(), Some(fresh_variance));
+
+        let res = sks.unchecked_scalar_add(&ct, 1);
+        assert_eq!(res.noise_variance(), Some(fresh_variance));
+
+        let trivial = sks.create_trivial(2);
+        assert_eq!(trivial.noise_variance(), Some(Variance(0.0)));
+        let res = sks.apply_lookup_table(&trivial, &acc);
+        assert_eq!(res.noise_variance(), Some(Variance(0.0)));
+
+        let bootstrapped = sks.apply_lookup_table(&ct, &acc);
+        let bootstrapped_variance = noise_model.bootstrapped_variance();
+        assert_eq!(bootstrapped.noise_variance(), Some(bootstrapped_variance));
+        assert_eq!(cks.decrypt(&bootstrapped), 1);
+
+        let res = sks.unchecked_add(&bootstrapped, &ct);
+        assert_eq!(
+            res.noise_variance(),
+            Some(Variance(bootstrapped_variance.0 + fresh_variance.0))
+        );
+
+        let bivariate_acc = sks.generate_lookup_table_bivariate(|x, y| x + y);
+        let p_fail = sks
+            .apply_lookup_table_bivariate_failure_probability(
+                &bootstrapped,
+                &bootstrapped,
+                &bivariate_acc,
+            )
+            .unwrap();
+        let scale = message_modulus as f64;
+        let expected_p_fail = noise_model
+            .pbs_failure_probability(Variance(bootstrapped_variance.0 * (scale * scale + 1.0)));
+        assert_eq!(p_fail, expected_p_fail);
+
+        let res = sks.unchecked_apply_lookup_table_bivariate(
+            &bootstrapped,
+            &bootstrapped,
+            &bivariate_acc,
+        );
+        assert_eq!(res.noise_variance(), Some(bootstrapped_variance));
+        assert_eq!(cks.decrypt(&res), 2);
+
+        sks.disable_noise_tracking();
+        let res = sks.apply_lookup_table(&ct, &acc);
+        assert_eq!(res.noise_variance(), None);
+    }
+}
+
+#[test]
+fn test_failure_probability_matches_parameters_ci_run_filter() {
+    // The parameters are optimized for a failure probability of the PBS when the input has the
+    // maximum noise level, i.e. the sum of max_noise_level bootstrapped ciphertexts
+    for params in [
+        PBSParameters::from(PARAM_MESSAGE_2_CARRY_2_KS_PBS),
+        PBSParameters::from(PARAM_MESSAGE_3_CARRY_3_KS_PBS),
+    ] {
+        let noise_model = NoiseModel::new(params);
+        let max_noise_level = params.max_noise_level().get() as f64;
+
+        let worst_case_variance = Variance(noise_model.bootstrapped_variance().0 * max_noise_level);
+        let log2_p_fail = noise_model
+            .pbs_failure_probability(worst_case_variance)
+            .log2();
+
+        let expected_log2_p_fail = match params {
+            PBSParameters::PBS(params) => params.log2_p_fail,
+            PBSParameters::MultiBitPBS(params) => params.log2_p_fail,
+        };
+
+        assert!(
+            (log2_p_fail - expected_log2_p_fail).abs() < 2.0,
+            "Estimated log2 p_fail {log2_p_fail} is too far from the expected \
+            {expected_log2_p_fail}"
+        );
+    }
+}
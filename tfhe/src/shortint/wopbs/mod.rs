@@ -43,13 +43,20 @@ mod test;
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WopbsKey {
     //Key for the private functional keyswitch
+    #[serde(with = "crate::versionable::nested")]
     pub wopbs_server_key: ServerKey,
+    #[serde(with = "crate::versionable::nested")]
     pub pbs_server_key: ServerKey,
     pub cbs_pfpksk: LwePrivateFunctionalPackingKeyswitchKeyListOwned<u64>,
+    #[serde(with = "crate::versionable::nested")]
     pub ksk_pbs_to_wopbs: LweKeyswitchKeyOwned<u64>,
     pub param: WopbsParameters,
 }
 
+impl crate::named::Named for WopbsKey {
+    const NAME: &'static str = "shortint::WopbsKey";
+}
+
 #[must_use]
 pub struct WopbsLUTBase {
     // Flattened Wopbs LUT
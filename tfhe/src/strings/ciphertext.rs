@@ -1,16 +1,20 @@
-use crate::client_key::{ClientKey, EncU16, EncryptOutput};
-use crate::server_key::ServerKey;
-use crate::N;
-use tfhe::integer::{IntegerCiphertext, IntegerRadixCiphertext, RadixCiphertext};
+use crate::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+use crate::integer::{IntegerCiphertext, IntegerRadixCiphertext, RadixCiphertext};
+use crate::strings::client_key::{ClientKey, EncU16, EncryptOutput};
+use crate::strings::server_key::ServerKey;
+use crate::strings::N;
+use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
 
 /// Represents a encrypted ASCII character.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct FheAsciiChar {
+    #[serde(with = "crate::versionable::nested")]
     enc_char: RadixCiphertext,
 }
 
 /// Represents a encrypted string made up of [`FheAsciiChar`]s.
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct FheString {
     enc_string: Vec<FheAsciiChar>,
     padded: bool,
@@ -32,7 +36,7 @@ impl ClearString {
         assert!(str.is_ascii() && !str.contains('\0'));
         assert!(str.len() <= N * 8);
 
-        ClearString { str }
+        Self { str }
     }
 
     pub fn str(&self) -> &str {
@@ -55,8 +59,8 @@ impl FheAsciiChar {
         &mut self.enc_char
     }
 
-    pub fn null(sk: &ServerKey) -> Self {
-        FheAsciiChar {
+    pub fn null<T: Borrow<IntegerServerKey> + Sync>(sk: &ServerKey<T>) -> Self {
+        Self {
             enc_char: sk.key().create_trivial_zero_radix(4),
         }
     }
@@ -71,17 +75,24 @@ impl FheString {
     /// # Panics
     ///
     /// This function will panic if the provided string is not ASCII.
-    pub fn new(client_key: &ClientKey, str: &str, padding: Option<u32>) -> Self {
+    pub fn new<T: Borrow<IntegerClientKey>>(
+        client_key: &ClientKey<T>,
+        str: &str,
+        padding: Option<u32>,
+    ) -> Self {
         let enc_output = client_key.encrypt_ascii(str, padding);
 
-        FheString::from(enc_output)
+        Self::from(enc_output)
     }
 
     /// Constructs a trivial `FheString` from a plaintext string and a [`ServerKey`].
     ///
     /// ## WARNING:
     /// This only formats the value to fit the ciphertext. The result is NOT encrypted.
-    pub fn trivial(server_key: &ServerKey, str: &str) -> Self {
+    pub fn trivial<T: Borrow<IntegerServerKey> + Sync>(
+        server_key: &ServerKey<T>,
+        str: &str,
+    ) -> Self {
         let trivial = server_key
             .trivial_encrypt_ascii(str)
             .value()
@@ -130,7 +141,7 @@ impl FheString {
 
     // Converts a `RadixCiphertext` to a `FheString`, building a `FheAsciiChar` for each 4 blocks.
     // Panics if the uint doesn't have a number of blocks that is multiple of 4.
-    pub fn from_uint(uint: RadixCiphertext) -> FheString {
+    pub fn from_uint(uint: RadixCiphertext) -> Self {
         let blocks_len = uint.blocks().len();
         assert_eq!(blocks_len % 4, 0);
 
@@ -147,7 +158,7 @@ impl FheString {
             ascii_vec.push(FheAsciiChar { enc_char: byte })
         }
 
-        FheString {
+        Self {
             enc_string: ascii_vec,
             // We are assuming here there's no padding, so this isn't safe if we don't know it!
             padded: false,
@@ -156,11 +167,17 @@ impl FheString {
 
     // Converts a `FheString` to a `RadixCiphertext`, taking 4 blocks for each `FheAsciiChar`.
     // We can then use a single large uint, that represents a string, in tfhe-rs operations.
-    pub fn to_uint(&self, sk: &ServerKey) -> RadixCiphertext {
+    pub fn to_uint<T: Borrow<IntegerServerKey> + Sync>(
+        &self,
+        sk: &ServerKey<T>,
+    ) -> RadixCiphertext {
         self.clone().into_uint(sk)
     }
 
-    pub fn into_uint(self, sk: &ServerKey) -> RadixCiphertext {
+    pub fn into_uint<T: Borrow<IntegerServerKey> + Sync>(
+        self,
+        sk: &ServerKey<T>,
+    ) -> RadixCiphertext {
         let blocks: Vec<_> = self
             .enc_string
             .into_iter()
@@ -180,7 +197,7 @@ impl FheString {
 
     /// Makes the string padded. Useful for when a string is potentially padded and we need to
     /// ensure it's actually padded.
-    pub fn append_null(&mut self, sk: &ServerKey) {
+    pub fn append_null<T: Borrow<IntegerServerKey> + Sync>(&mut self, sk: &ServerKey<T>) {
         let null = FheAsciiChar::null(sk);
 
         self.enc_string.push(null);
@@ -188,8 +205,8 @@ impl FheString {
         self.padded = true;
     }
 
-    pub fn empty() -> FheString {
-        FheString {
+    pub fn empty() -> Self {
+        Self {
             enc_string: vec![],
             padded: false,
         }
@@ -199,11 +216,16 @@ impl FheString {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::server_key::gen_keys;
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::integer::IntegerKeyKind;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
 
     #[test]
     fn test_uint_conversion() {
-        let (ck, sk) = gen_keys();
+        let (ck, sk) =
+            KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS, IntegerKeyKind::Radix);
+        let ck = ClientKey::new(ck);
+        let sk = ServerKey::new(sk);
 
         let str =
             "Los Sheikah fueron originalmente criados de la Diosa Hylia antes del sellado del \
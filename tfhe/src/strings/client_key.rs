@@ -1,11 +1,18 @@
-use crate::ciphertext::FheString;
-use tfhe::integer::{ClientKey as FheClientKey, RadixCiphertext};
-use tfhe::shortint::prelude::PARAM_MESSAGE_2_CARRY_2;
+use crate::integer::{ClientKey as IntegerClientKey, RadixCiphertext};
+use crate::strings::ciphertext::FheString;
+use std::borrow::Borrow;
 
 /// Represents a client key for encryption and decryption of strings.
-#[derive(serde::Serialize, serde::Deserialize, Clone)]
-pub struct ClientKey {
-    key: FheClientKey,
+///
+/// It wraps an integer [`ClientKey`](IntegerClientKey), either owned or borrowed, whose
+/// parameters must encrypt 2 bits of message per block, so that each character is made of 4
+/// blocks.
+#[derive(Clone)]
+pub struct ClientKey<T>
+where
+    T: Borrow<IntegerClientKey>,
+{
+    key: T,
 }
 
 /// Encrypted u16 value. It contains an optional `max` to restrict the range of the value.
@@ -15,6 +22,10 @@ pub struct EncU16 {
 }
 
 impl EncU16 {
+    pub(crate) fn new(cipher: RadixCiphertext, max: Option<u16>) -> Self {
+        Self { cipher, max }
+    }
+
     pub fn cipher(&self) -> &RadixCiphertext {
         &self.cipher
     }
@@ -33,6 +44,10 @@ pub struct EncryptOutput {
 }
 
 impl EncryptOutput {
+    pub(crate) fn new(output: Vec<RadixCiphertext>, padded: bool) -> Self {
+        Self { output, padded }
+    }
+
     /// Extracts the value from the `EncryptOutput`.
     pub fn value(self) -> Vec<RadixCiphertext> {
         self.output
@@ -43,15 +58,22 @@ impl EncryptOutput {
     }
 }
 
-impl ClientKey {
-    pub fn new() -> Self {
-        Self {
-            key: FheClientKey::new(PARAM_MESSAGE_2_CARRY_2),
-        }
+impl<T: Borrow<IntegerClientKey>> ClientKey<T> {
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus of the key is not 4.
+    pub fn new(key: T) -> Self {
+        assert_eq!(
+            key.borrow().parameters().message_modulus().0,
+            4,
+            "Strings require parameters with a message modulus of 4"
+        );
+
+        Self { key }
     }
 
-    pub fn key(&self) -> &FheClientKey {
-        &self.key
+    pub fn key(&self) -> &IntegerClientKey {
+        self.key.borrow()
     }
 
     /// Encrypts an ASCII string, optionally padding it with the specified amount of 0s, and returns
@@ -64,16 +86,16 @@ impl ClientKey {
     pub fn encrypt_ascii(&self, str: &str, padding: Option<u32>) -> EncryptOutput {
         assert!(str.is_ascii() & !str.contains('\0'));
 
-        let padded = padding.map_or(false, |p| p != 0);
+        let padded = padding.is_some_and(|p| p != 0);
 
         let mut enc_chars: Vec<_> = str
             .bytes()
-            .map(|char| self.key.encrypt_radix(char, 4))
+            .map(|char| self.key().encrypt_radix(char, 4))
             .collect();
 
         // Optional padding
         if let Some(count) = padding {
-            let null = (0..count).map(|_| self.key.encrypt_radix(0u8, 4));
+            let null = (0..count).map(|_| self.key().encrypt_radix(0u8, 4));
 
             enc_chars.extend(null);
         }
@@ -98,7 +120,7 @@ impl ClientKey {
             .chars()
             .iter()
             .filter_map(|enc_char| {
-                let byte = self.key.decrypt_radix(enc_char.ciphertext());
+                let byte = self.key().decrypt_radix(enc_char.ciphertext());
 
                 if byte == 0 {
                     prev_was_null = true;
@@ -140,7 +162,7 @@ impl ClientKey {
         }
 
         EncU16 {
-            cipher: self.key.encrypt_radix(val, 8),
+            cipher: self.key().encrypt_radix(val, 8),
             max,
         }
     }
@@ -0,0 +1,18 @@
+//! Operations on encrypted ASCII strings.
+//!
+//! An [`FheString`](ciphertext::FheString) is a sequence of encrypted ASCII characters, each of
+//! them stored as a radix ciphertext of 4 blocks. Strings can optionally be padded with encrypted
+//! null characters at their end, to hide their actual length.
+//!
+//! The operations are implemented on the string [`ServerKey`], which wraps an integer server key.
+
+pub mod ciphertext;
+pub mod client_key;
+pub mod server_key;
+
+pub use client_key::ClientKey;
+pub use server_key::{ServerKey, ServerKeyRef};
+
+// Used as the const argument for StaticUnsignedBigInt, specifying the max u64 length of a
+// ClearString
+const N: usize = 4;
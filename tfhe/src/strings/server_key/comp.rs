@@ -1,8 +1,10 @@
-use crate::ciphertext::{FheString, GenericPattern};
-use crate::server_key::{FheStringIsEmpty, ServerKey};
-use tfhe::integer::BooleanBlock;
+use crate::integer::BooleanBlock;
+use crate::integer::ServerKey as IntegerServerKey;
+use crate::strings::ciphertext::{FheString, GenericPattern};
+use crate::strings::server_key::{FheStringIsEmpty, ServerKey};
+use std::borrow::Borrow;
 
-impl ServerKey {
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
     fn eq_length_checks(&self, lhs: &FheString, rhs: &FheString) -> Option<BooleanBlock> {
         let lhs_len = lhs.chars().len();
         let rhs_len = rhs.chars().len();
@@ -13,7 +15,7 @@ impl ServerKey {
             return match self.is_empty(rhs) {
                 FheStringIsEmpty::Padding(enc_val) => Some(enc_val),
                 FheStringIsEmpty::NoPadding(val) => {
-                    Some(self.key.create_trivial_boolean_block(val))
+                    Some(self.key().create_trivial_boolean_block(val))
                 }
             };
         }
@@ -23,13 +25,15 @@ impl ServerKey {
         if rhs_len == 0 || (rhs.is_padded() && rhs_len == 1) {
             return match self.is_empty(lhs) {
                 FheStringIsEmpty::Padding(enc_val) => Some(enc_val),
-                _ => Some(self.key.create_trivial_boolean_block(false)),
+                FheStringIsEmpty::NoPadding(_) => {
+                    Some(self.key().create_trivial_boolean_block(false))
+                }
             };
         }
 
         // Two strings without padding that have different lengths cannot be equal
         if (!lhs.is_padded() && !rhs.is_padded()) && (lhs.chars().len() != rhs.chars().len()) {
-            return Some(self.key.create_trivial_boolean_block(false));
+            return Some(self.key().create_trivial_boolean_block(false));
         }
 
         // A string without padding cannot be equal to a string with padding that has the same or
@@ -37,7 +41,7 @@ impl ServerKey {
         if (!lhs.is_padded() && rhs.is_padded()) && (rhs.chars().len() <= lhs.chars().len())
             || (!rhs.is_padded() && lhs.is_padded()) && (lhs.chars().len() <= rhs.chars().len())
         {
-            return Some(self.key.create_trivial_boolean_block(false));
+            return Some(self.key().create_trivial_boolean_block(false));
         }
 
         None
@@ -53,10 +57,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, GenericPattern};
-    /// use crate::server_key::gen_keys;
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s1, s2) = ("hello", "hello");
     ///
     /// let enc_s1 = FheString::new(&ck, &s1, None);
@@ -84,14 +93,14 @@ impl ServerKey {
             GenericPattern::Clear(rhs) => {
                 let rhs_clear_uint = self.pad_cipher_and_cleartext_lsb(&mut lhs_uint, rhs.str());
 
-                self.key.scalar_eq_parallelized(&lhs_uint, rhs_clear_uint)
+                self.key().scalar_eq_parallelized(&lhs_uint, rhs_clear_uint)
             }
             GenericPattern::Enc(rhs) => {
                 let mut rhs_uint = rhs.to_uint(self);
 
                 self.pad_ciphertexts_lsb(&mut lhs_uint, &mut rhs_uint);
 
-                self.key.eq_parallelized(&lhs_uint, &rhs_uint)
+                self.key().eq_parallelized(&lhs_uint, &rhs_uint)
             }
         }
     }
@@ -107,10 +116,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, GenericPattern};
-    /// use crate::server_key::gen_keys;
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s1, s2) = ("hello", "world");
     ///
     /// let enc_s1 = FheString::new(&ck, &s1, None);
@@ -124,7 +138,7 @@ impl ServerKey {
     pub fn ne(&self, lhs: &FheString, rhs: &GenericPattern) -> BooleanBlock {
         let eq = self.eq(lhs, rhs);
 
-        self.key.boolean_bitnot(&eq)
+        self.key().boolean_bitnot(&eq)
     }
 
     /// Returns `true` if the first encrypted string is less than the second encrypted string.
@@ -134,10 +148,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::FheString;
-    /// use crate::server_key::gen_keys;
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s1, s2) = ("apple", "banana");
     ///
     /// let enc_s1 = FheString::new(&ck, &s1, None);
@@ -154,7 +173,7 @@ impl ServerKey {
 
         self.pad_ciphertexts_lsb(&mut lhs_uint, &mut rhs_uint);
 
-        self.key.lt_parallelized(&lhs_uint, &rhs_uint)
+        self.key().lt_parallelized(&lhs_uint, &rhs_uint)
     }
 
     /// Returns `true` if the first encrypted string is greater than the second encrypted string.
@@ -164,10 +183,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::FheString;
-    /// use crate::server_key::gen_keys;
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s1, s2) = ("banana", "apple");
     ///
     /// let enc_s1 = FheString::new(&ck, &s1, None);
@@ -184,7 +208,7 @@ impl ServerKey {
 
         self.pad_ciphertexts_lsb(&mut lhs_uint, &mut rhs_uint);
 
-        self.key.gt_parallelized(&lhs_uint, &rhs_uint)
+        self.key().gt_parallelized(&lhs_uint, &rhs_uint)
     }
 
     /// Returns `true` if the first encrypted string is less than or equal to the second encrypted
@@ -195,10 +219,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::FheString;
-    /// use crate::server_key::gen_keys;
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s1, s2) = ("apple", "banana");
     ///
     /// let enc_s1 = FheString::new(&ck, &s1, None);
@@ -215,7 +244,7 @@ impl ServerKey {
 
         self.pad_ciphertexts_lsb(&mut lhs_uint, &mut rhs_uint);
 
-        self.key.le_parallelized(&lhs_uint, &rhs_uint)
+        self.key().le_parallelized(&lhs_uint, &rhs_uint)
     }
 
     /// Returns `true` if the first encrypted string is greater than or equal to the second
@@ -226,10 +255,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::FheString;
-    /// use crate::server_key::gen_keys;
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s1, s2) = ("banana", "apple");
     ///
     /// let enc_s1 = FheString::new(&ck, &s1, None);
@@ -246,6 +280,6 @@ impl ServerKey {
 
         self.pad_ciphertexts_lsb(&mut lhs_uint, &mut rhs_uint);
 
-        self.key.ge_parallelized(&lhs_uint, &rhs_uint)
+        self.key().ge_parallelized(&lhs_uint, &rhs_uint)
     }
 }
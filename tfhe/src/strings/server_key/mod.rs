@@ -3,37 +3,52 @@ mod no_patterns;
 mod pattern;
 mod trim;
 
-use crate::ciphertext::{FheAsciiChar, FheString};
-use crate::client_key::ClientKey;
-use crate::N;
+pub use pattern::{
+    RSplit, RSplitN, RSplitTerminator, Split, SplitInclusive, SplitN, SplitTerminator,
+};
+pub use trim::SplitAsciiWhitespace;
+
+use crate::integer::bigint::static_unsigned::StaticUnsignedBigInt;
+use crate::integer::prelude::*;
+use crate::integer::{BooleanBlock, RadixCiphertext, ServerKey as IntegerServerKey};
+use crate::strings::ciphertext::{FheAsciiChar, FheString};
+use crate::strings::N;
 use rayon::prelude::*;
+use std::borrow::Borrow;
 use std::cmp::Ordering;
-use tfhe::integer::bigint::static_unsigned::StaticUnsignedBigInt;
-use tfhe::integer::prelude::*;
-use tfhe::integer::{BooleanBlock, RadixCiphertext, ServerKey as FheServerKey};
 
 /// Represents a server key to operate homomorphically on [`FheString`].
-#[derive(serde::Serialize, serde::Deserialize, Clone)]
-pub struct ServerKey {
-    key: FheServerKey,
+///
+/// It wraps an integer [`ServerKey`](IntegerServerKey), either owned or borrowed, whose
+/// parameters must encrypt 2 bits of message per block, so that each character is made of 4
+/// blocks.
+#[derive(Clone)]
+pub struct ServerKey<T>
+where
+    T: Borrow<IntegerServerKey> + Sync,
+{
+    key: T,
 }
 
-pub fn gen_keys() -> (ClientKey, ServerKey) {
-    let ck = ClientKey::new();
-    let sk = ServerKey::new(&ck);
-
-    (ck, sk)
-}
+/// A [`ServerKey`] borrowing its integer server key.
+pub type ServerKeyRef<'a> = ServerKey<&'a IntegerServerKey>;
+
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
+    /// # Panics
+    ///
+    /// This function will panic if the message modulus of the key is not 4.
+    pub fn new(key: T) -> Self {
+        assert_eq!(
+            key.borrow().message_modulus().0,
+            4,
+            "Strings require parameters with a message modulus of 4"
+        );
 
-impl ServerKey {
-    pub fn new(from: &ClientKey) -> Self {
-        Self {
-            key: FheServerKey::new_radix_server_key(from.key()),
-        }
+        Self { key }
     }
 
-    pub fn key(&self) -> &FheServerKey {
-        &self.key
+    pub fn key(&self) -> &IntegerServerKey {
+        self.key.borrow()
     }
 
     pub fn trivial_encrypt_ascii(&self, str: &str) -> TrivialEncryptOutput {
@@ -41,7 +56,7 @@ impl ServerKey {
 
         let enc_chars: Vec<_> = str
             .bytes()
-            .map(|char| self.key.create_trivial_radix(char, 4))
+            .map(|char| self.key().create_trivial_radix(char, 4))
             .collect();
 
         TrivialEncryptOutput { output: enc_chars }
@@ -71,7 +86,7 @@ pub enum FheStringIsEmpty {
 }
 
 // A few helper functions for the implementations
-impl ServerKey {
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
     // If an iterator is longer than the other, the "excess" characters are ignored. This function
     // performs the equality check by transforming the `str` and `pat` chars into two UInts
     fn asciis_eq<'a, I, U>(&self, str: I, pat: U) -> BooleanBlock
@@ -96,7 +111,7 @@ impl ServerKey {
 
         self.trim_ciphertexts_lsb(&mut uint_str, &mut uint_pat);
 
-        self.key.eq_parallelized(&uint_str, &uint_pat)
+        self.key().eq_parallelized(&uint_str, &uint_pat)
     }
 
     fn clear_asciis_eq<'a, I>(&self, str: I, pat: &str) -> BooleanBlock
@@ -123,41 +138,44 @@ impl ServerKey {
             }
             Ordering::Greater => {
                 let diff = str_block_len - pat_block_len;
-                self.key.trim_radix_blocks_lsb_assign(&mut uint_str, diff);
+                self.key().trim_radix_blocks_lsb_assign(&mut uint_str, diff);
             }
-            _ => (),
+            Ordering::Equal => (),
         }
 
         let clear_pat_uint = self.pad_cipher_and_cleartext_lsb(&mut uint_str, clear_pat);
 
-        self.key.scalar_eq_parallelized(&uint_str, clear_pat_uint)
+        self.key().scalar_eq_parallelized(&uint_str, clear_pat_uint)
     }
 
     fn asciis_eq_ignore_pat_pad<'a, I>(&self, str_pat: I) -> BooleanBlock
     where
         I: ParallelIterator<Item = (&'a FheAsciiChar, &'a FheAsciiChar)>,
     {
-        let mut result = self.key.create_trivial_boolean_block(true);
+        let mut result = self.key().create_trivial_boolean_block(true);
 
         let eq_or_null_pat: Vec<_> = str_pat
             .map(|(str_char, pat_char)| {
                 let (are_eq, pat_is_null) = rayon::join(
                     || {
-                        self.key
+                        self.key()
                             .eq_parallelized(str_char.ciphertext(), pat_char.ciphertext())
                     },
-                    || self.key.scalar_eq_parallelized(pat_char.ciphertext(), 0u8),
+                    || {
+                        self.key()
+                            .scalar_eq_parallelized(pat_char.ciphertext(), 0u8)
+                    },
                 );
 
                 // If `pat_char` is null then `are_eq` is set to true. Hence if ALL `pat_char`s are
                 // null, the result is always true, which is correct since the pattern is empty
-                self.key.boolean_bitor(&are_eq, &pat_is_null)
+                self.key().boolean_bitor(&are_eq, &pat_is_null)
             })
             .collect();
 
         for eq_or_null in eq_or_null_pat {
             // Will be false if `str_char` != `pat_char` and `pat_char` isn't null
-            self.key.boolean_bitand_assign(&mut result, &eq_or_null);
+            self.key().boolean_bitand_assign(&mut result, &eq_or_null);
         }
 
         result
@@ -180,7 +198,7 @@ impl ServerKey {
         // Also fill the lhs with null blocks at the end
         if lhs.blocks().len() < N * 8 * 4 {
             let diff = N * 8 * 4 - lhs.blocks().len();
-            self.key
+            self.key()
                 .extend_radix_with_trivial_zero_blocks_lsb_assign(lhs, diff);
         }
 
@@ -194,15 +212,15 @@ impl ServerKey {
         match lhs_blocks.cmp(&rhs_blocks) {
             Ordering::Less => {
                 let diff = rhs_blocks - lhs_blocks;
-                self.key
+                self.key()
                     .extend_radix_with_trivial_zero_blocks_lsb_assign(lhs, diff);
             }
             Ordering::Greater => {
                 let diff = lhs_blocks - rhs_blocks;
-                self.key
+                self.key()
                     .extend_radix_with_trivial_zero_blocks_lsb_assign(rhs, diff);
             }
-            _ => (),
+            Ordering::Equal => (),
         }
     }
 
@@ -212,14 +230,14 @@ impl ServerKey {
         match cipher_len.cmp(&len) {
             Ordering::Less => {
                 let diff = len - cipher_len;
-                self.key
+                self.key()
                     .extend_radix_with_trivial_zero_blocks_msb_assign(cipher, diff);
             }
             Ordering::Greater => {
                 let diff = cipher_len - len;
-                self.key.trim_radix_blocks_msb_assign(cipher, diff);
+                self.key().trim_radix_blocks_msb_assign(cipher, diff);
             }
-            _ => (),
+            Ordering::Equal => (),
         }
     }
 
@@ -230,13 +248,13 @@ impl ServerKey {
         match lhs_blocks.cmp(&rhs_blocks) {
             Ordering::Less => {
                 let diff = rhs_blocks - lhs_blocks;
-                self.key.trim_radix_blocks_lsb_assign(rhs, diff);
+                self.key().trim_radix_blocks_lsb_assign(rhs, diff);
             }
             Ordering::Greater => {
                 let diff = lhs_blocks - rhs_blocks;
-                self.key.trim_radix_blocks_lsb_assign(lhs, diff);
+                self.key().trim_radix_blocks_lsb_assign(lhs, diff);
             }
-            _ => (),
+            Ordering::Equal => (),
         }
     }
 
@@ -255,7 +273,7 @@ impl ServerKey {
         self.pad_ciphertexts_lsb(&mut true_ct_uint, &mut false_ct_uint);
 
         let result_uint =
-            self.key
+            self.key()
                 .if_then_else_parallelized(condition, &true_ct_uint, &false_ct_uint);
 
         let mut result = FheString::from_uint(result_uint);
@@ -272,21 +290,21 @@ impl ServerKey {
 
     fn left_shift_chars(&self, str: &FheString, shift: &RadixCiphertext) -> FheString {
         let uint = str.to_uint(self);
-        let mut shift_bits = self.key.scalar_left_shift_parallelized(shift, 3);
+        let mut shift_bits = self.key().scalar_left_shift_parallelized(shift, 3);
 
         // `shift_bits` needs to have the same block len as `uint` for the tfhe-rs shift to work
         self.pad_or_trim_ciphertext(&mut shift_bits, uint.blocks().len());
 
-        let shifted = self.key.left_shift_parallelized(&uint, &shift_bits);
+        let shifted = self.key().left_shift_parallelized(&uint, &shift_bits);
 
         // If the shifting amount is >= than the str length we get zero i.e. all chars are out of
         // range (instead of wrapping, which is the behavior of Rust and tfhe-rs)
         let bit_len = (str.chars().len() * 8) as u32;
-        let shift_ge_than_str = self.key.scalar_ge_parallelized(&shift_bits, bit_len);
+        let shift_ge_than_str = self.key().scalar_ge_parallelized(&shift_bits, bit_len);
 
-        let result = self.key.if_then_else_parallelized(
+        let result = self.key().if_then_else_parallelized(
             &shift_ge_than_str,
-            &self.key.create_trivial_zero_radix(uint.blocks().len()),
+            &self.key().create_trivial_zero_radix(uint.blocks().len()),
             &shifted,
         );
 
@@ -295,21 +313,21 @@ impl ServerKey {
 
     fn right_shift_chars(&self, str: &FheString, shift: &RadixCiphertext) -> FheString {
         let uint = str.to_uint(self);
-        let mut shift_bits = self.key.scalar_left_shift_parallelized(shift, 3);
+        let mut shift_bits = self.key().scalar_left_shift_parallelized(shift, 3);
 
         // `shift_bits` needs to have the same block len as `uint` for the tfhe-rs shift to work
         self.pad_or_trim_ciphertext(&mut shift_bits, uint.blocks().len());
 
-        let shifted = self.key.right_shift_parallelized(&uint, &shift_bits);
+        let shifted = self.key().right_shift_parallelized(&uint, &shift_bits);
 
         // If the shifting amount is >= than the str length we get zero i.e. all chars are out of
         // range (instead of wrapping, which is the behavior of Rust and tfhe-rs)
         let bit_len = (str.chars().len() * 8) as u32;
-        let shift_ge_than_str = self.key.scalar_ge_parallelized(&shift_bits, bit_len);
+        let shift_ge_than_str = self.key().scalar_ge_parallelized(&shift_bits, bit_len);
 
-        let result = self.key.if_then_else_parallelized(
+        let result = self.key().if_then_else_parallelized(
             &shift_ge_than_str,
-            &self.key.create_trivial_zero_radix(uint.blocks().len()),
+            &self.key().create_trivial_zero_radix(uint.blocks().len()),
             &shifted,
         );
 
@@ -318,7 +336,10 @@ impl ServerKey {
 }
 
 pub trait FheStringIterator {
-    fn next(&mut self, sk: &ServerKey) -> (FheString, BooleanBlock);
+    fn next<T: Borrow<IntegerServerKey> + Sync>(
+        &mut self,
+        sk: &ServerKey<T>,
+    ) -> (FheString, BooleanBlock);
 }
 
 #[derive(Clone)]
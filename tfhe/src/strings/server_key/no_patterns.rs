@@ -1,9 +1,11 @@
-use crate::ciphertext::{ClearString, FheString, GenericPattern, UIntArg};
-use crate::server_key::{FheStringIsEmpty, FheStringLen, ServerKey};
+use crate::integer::BooleanBlock;
+use crate::integer::ServerKey as IntegerServerKey;
+use crate::strings::ciphertext::{ClearString, FheString, GenericPattern, UIntArg};
+use crate::strings::server_key::{FheStringIsEmpty, FheStringLen, ServerKey};
 use rayon::prelude::*;
-use tfhe::integer::BooleanBlock;
+use std::borrow::Borrow;
 
-impl ServerKey {
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
     /// Returns the length of an encrypted string as an `FheStringLen` enum.
     ///
     /// If the encrypted string has no padding, the length is the clear length of the char vector.
@@ -13,10 +15,16 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::FheString;
-    /// use crate::server_key::{gen_keys, FheStringLen};
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::server_key::FheStringLen;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let s = "hello";
     /// let number_of_nulls = 3;
     ///
@@ -46,14 +54,14 @@ impl ServerKey {
                 .chars()
                 .par_iter()
                 .map(|char| {
-                    let bool = self.key.scalar_ne_parallelized(char.ciphertext(), 0u8);
-                    bool.into_radix(16, &self.key)
+                    let bool = self.key().scalar_ne_parallelized(char.ciphertext(), 0u8);
+                    bool.into_radix(16, self.key())
                 })
                 .collect();
 
             // If we add the number of non-zero elements we get the actual length, without padding
             let len = self
-                .key
+                .key()
                 .sum_ciphertexts_parallelized(non_zero_chars.iter())
                 .expect("There's at least one padding character");
 
@@ -72,10 +80,16 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::FheString;
-    /// use crate::server_key::{gen_keys, FheStringIsEmpty};
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::server_key::FheStringIsEmpty;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let s = "";
     /// let number_of_nulls = 2;
     ///
@@ -102,11 +116,11 @@ impl ServerKey {
     pub fn is_empty(&self, str: &FheString) -> FheStringIsEmpty {
         if str.is_padded() {
             if str.chars().len() == 1 {
-                return FheStringIsEmpty::Padding(self.key.create_trivial_boolean_block(true));
+                return FheStringIsEmpty::Padding(self.key().create_trivial_boolean_block(true));
             }
 
             let str_uint = str.to_uint(self);
-            let result = self.key.scalar_eq_parallelized(&str_uint, 0u8);
+            let result = self.key().scalar_eq_parallelized(&str_uint, 0u8);
 
             FheStringIsEmpty::Padding(result)
         } else {
@@ -119,10 +133,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::FheString;
-    /// use crate::server_key::gen_keys;
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let s = "Hello World";
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -141,11 +160,11 @@ impl ServerKey {
             .par_iter()
             .map(|char| {
                 let (ge_97, le_122) = rayon::join(
-                    || self.key.scalar_ge_parallelized(char.ciphertext(), 97u8),
-                    || self.key.scalar_le_parallelized(char.ciphertext(), 122u8),
+                    || self.key().scalar_ge_parallelized(char.ciphertext(), 97u8),
+                    || self.key().scalar_le_parallelized(char.ciphertext(), 122u8),
                 );
 
-                self.key.boolean_bitand(&ge_97, &le_122)
+                self.key().boolean_bitand(&ge_97, &le_122)
             })
             .collect();
 
@@ -156,12 +175,14 @@ impl ServerKey {
             .zip(lowercase_chars)
             .par_bridge()
             .for_each(|(char, is_lowercase)| {
-                let mut subtract = self.key.create_trivial_radix(32, 4);
+                let mut subtract = self.key().create_trivial_radix(32, 4);
 
-                self.key
-                    .mul_assign_parallelized(&mut subtract, &is_lowercase.into_radix(1, &self.key));
+                self.key().mul_assign_parallelized(
+                    &mut subtract,
+                    &is_lowercase.into_radix(1, self.key()),
+                );
 
-                self.key
+                self.key()
                     .sub_assign_parallelized(char.ciphertext_mut(), &subtract);
             });
 
@@ -173,10 +194,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::FheString;
-    /// use crate::server_key::gen_keys;
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let s = "Hello World";
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -195,11 +221,11 @@ impl ServerKey {
             .par_iter()
             .map(|char| {
                 let (ge_65, le_90) = rayon::join(
-                    || self.key.scalar_ge_parallelized(char.ciphertext(), 65u8),
-                    || self.key.scalar_le_parallelized(char.ciphertext(), 90u8),
+                    || self.key().scalar_ge_parallelized(char.ciphertext(), 65u8),
+                    || self.key().scalar_le_parallelized(char.ciphertext(), 90u8),
                 );
 
-                self.key.boolean_bitand(&ge_65, &le_90)
+                self.key().boolean_bitand(&ge_65, &le_90)
             })
             .collect();
 
@@ -210,12 +236,12 @@ impl ServerKey {
             .zip(uppercase_chars)
             .par_bridge()
             .for_each(|(char, is_uppercase)| {
-                let mut add = self.key.create_trivial_radix(32, 4);
+                let mut add = self.key().create_trivial_radix(32, 4);
 
-                self.key
-                    .mul_assign_parallelized(&mut add, &is_uppercase.into_radix(1, &self.key));
+                self.key()
+                    .mul_assign_parallelized(&mut add, &is_uppercase.into_radix(1, self.key()));
 
-                self.key
+                self.key()
                     .add_assign_parallelized(char.ciphertext_mut(), &add);
             });
 
@@ -233,10 +259,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, GenericPattern};
-    /// use crate::server_key::gen_keys;
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s1, s2) = ("Hello", "hello");
     ///
     /// let enc_s1 = FheString::new(&ck, &s1, None);
@@ -268,10 +299,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::FheString;
-    /// use crate::server_key::gen_keys;
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (lhs, rhs) = ("Hello, ", "world!");
     ///
     /// let enc_lhs = FheString::new(&ck, &lhs, None);
@@ -295,8 +331,10 @@ impl ServerKey {
             // If lhs is padded we can shift it right such that all nulls move to the start, then
             // we append the rhs and shift it left again to move the nulls to the new end
             FheStringLen::Padding(len) => {
-                let padded_len = self.key.create_trivial_radix(lhs.chars().len() as u32, 16);
-                let number_of_nulls = self.key.sub_parallelized(&padded_len, &len);
+                let padded_len = self
+                    .key()
+                    .create_trivial_radix(lhs.chars().len() as u32, 16);
+                let number_of_nulls = self.key().sub_parallelized(&padded_len, &len);
 
                 result = self.right_shift_chars(&result, &number_of_nulls);
 
@@ -319,10 +357,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, UIntArg};
-    /// use crate::server_key::gen_keys;
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, UIntArg};
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let s = "hi";
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -344,7 +387,7 @@ impl ServerKey {
     /// assert_eq!(repeated_enc, "hihihi");
     /// ```
     pub fn repeat(&self, str: &FheString, n: &UIntArg) -> FheString {
-        if let UIntArg::Clear(0) = n {
+        if matches!(n, UIntArg::Clear(0)) {
             return FheString::empty();
         }
 
@@ -363,11 +406,11 @@ impl ServerKey {
                 }
             }
             UIntArg::Enc(enc_n) => {
-                let n_is_zero = self.key.scalar_eq_parallelized(enc_n.cipher(), 0);
+                let n_is_zero = self.key().scalar_eq_parallelized(enc_n.cipher(), 0);
                 result = self.conditional_string(&n_is_zero, FheString::empty(), &result);
 
                 for i in 0..enc_n.max().unwrap_or(u16::MAX) - 1 {
-                    let n_is_exceeded = self.key.scalar_le_parallelized(enc_n.cipher(), i + 1);
+                    let n_is_exceeded = self.key().scalar_le_parallelized(enc_n.cipher(), i + 1);
                     let append = self.conditional_string(&n_is_exceeded, FheString::empty(), str);
 
                     result = self.concat(&result, &append);
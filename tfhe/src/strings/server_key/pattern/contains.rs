@@ -1,11 +1,13 @@
-use crate::ciphertext::{FheAsciiChar, FheString, GenericPattern};
-use crate::server_key::pattern::{CharIter, IsMatch};
-use crate::server_key::ServerKey;
+use crate::integer::ServerKey as IntegerServerKey;
+use crate::integer::{BooleanBlock, IntegerRadixCiphertext, RadixCiphertext};
+use crate::strings::ciphertext::{FheAsciiChar, FheString, GenericPattern};
+use crate::strings::server_key::pattern::{CharIter, IsMatch};
+use crate::strings::server_key::ServerKey;
 use rayon::prelude::*;
 use rayon::range::Iter;
-use tfhe::integer::{BooleanBlock, IntegerRadixCiphertext, RadixCiphertext};
+use std::borrow::Borrow;
 
-impl ServerKey {
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
     // Compare pat with str, with pat shifted right (in relation to str) the number given by iter
     fn compare_shifted(
         &self,
@@ -36,7 +38,7 @@ impl ServerKey {
         let block_vec: Vec<_> = matched
             .into_iter()
             .map(|bool| {
-                let radix: RadixCiphertext = bool.into_radix(1, &self.key);
+                let radix: RadixCiphertext = bool.into_radix(1, self.key());
                 radix.into_blocks()[0].clone()
             })
             .collect();
@@ -44,7 +46,7 @@ impl ServerKey {
         // This will be 0 if there was no match, non-zero otherwise
         let combined_radix = RadixCiphertext::from(block_vec);
 
-        self.key.scalar_ne_parallelized(&combined_radix, 0)
+        self.key().scalar_ne_parallelized(&combined_radix, 0)
     }
 
     fn clear_compare_shifted(
@@ -66,7 +68,7 @@ impl ServerKey {
         let block_vec: Vec<_> = matched
             .into_iter()
             .map(|bool| {
-                let radix: RadixCiphertext = bool.into_radix(1, &self.key);
+                let radix: RadixCiphertext = bool.into_radix(1, self.key());
                 radix.into_blocks()[0].clone()
             })
             .collect();
@@ -74,7 +76,7 @@ impl ServerKey {
         // This will be 0 if there was no match, non-zero otherwise
         let combined_radix = RadixCiphertext::from(block_vec);
 
-        self.key.scalar_ne_parallelized(&combined_radix, 0)
+        self.key().scalar_ne_parallelized(&combined_radix, 0)
     }
 
     /// Returns `true` if the given pattern (either encrypted or clear) matches a substring of this
@@ -88,10 +90,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{ClearString, FheString, GenericPattern};
-    /// use crate::server_key::gen_keys;
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{ClearString, FheString, GenericPattern};
+    /// use tfhe::strings::{ClientKey, ServerKey};
     ///
-    /// let (ck, sk) = gen_keys();
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (bananas, nana, apples) = ("bananas", "nana", "apples");
     ///
     /// let enc_bananas = FheString::new(&ck, &bananas, None);
@@ -114,9 +121,9 @@ impl ServerKey {
         };
 
         match self.length_checks(str, &trivial_or_enc_pat) {
-            IsMatch::Clear(val) => return self.key.create_trivial_boolean_block(val),
+            IsMatch::Clear(val) => return self.key().create_trivial_boolean_block(val),
             IsMatch::Cipher(val) => return val,
-            _ => (),
+            IsMatch::None => (),
         }
 
         let ignore_pat_pad = trivial_or_enc_pat.is_padded();
@@ -125,7 +132,7 @@ impl ServerKey {
             .then_some(FheAsciiChar::null(self));
 
         let (str_iter, pat_iter, iter) =
-            self.contains_cases(str, &trivial_or_enc_pat, null.as_ref());
+            Self::contains_cases(str, &trivial_or_enc_pat, null.as_ref());
 
         match pat {
             GenericPattern::Clear(pat) => {
@@ -148,10 +155,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{ClearString, FheString, GenericPattern};
-    /// use crate::server_key::gen_keys;
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{ClearString, FheString, GenericPattern};
+    /// use tfhe::strings::{ClientKey, ServerKey};
     ///
-    /// let (ck, sk) = gen_keys();
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (bananas, ba, nan) = ("bananas", "ba", "nan");
     ///
     /// let enc_bananas = FheString::new(&ck, &bananas, None);
@@ -174,9 +186,9 @@ impl ServerKey {
         };
 
         match self.length_checks(str, &trivial_or_enc_pat) {
-            IsMatch::Clear(val) => return self.key.create_trivial_boolean_block(val),
+            IsMatch::Clear(val) => return self.key().create_trivial_boolean_block(val),
             IsMatch::Cipher(val) => return val,
-            _ => (),
+            IsMatch::None => (),
         }
 
         if !trivial_or_enc_pat.is_padded() {
@@ -217,10 +229,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{ClearString, FheString, GenericPattern};
-    /// use crate::server_key::gen_keys;
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{ClearString, FheString, GenericPattern};
+    /// use tfhe::strings::{ClientKey, ServerKey};
     ///
-    /// let (ck, sk) = gen_keys();
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (bananas, anas, nana) = ("bananas", "anas", "nana");
     ///
     /// let enc_bananas = FheString::new(&ck, &bananas, None);
@@ -243,21 +260,21 @@ impl ServerKey {
         };
 
         match self.length_checks(str, &trivial_or_enc_pat) {
-            IsMatch::Clear(val) => return self.key.create_trivial_boolean_block(val),
+            IsMatch::Clear(val) => return self.key().create_trivial_boolean_block(val),
             IsMatch::Cipher(val) => return val,
-            _ => (),
+            IsMatch::None => (),
         }
 
         match pat {
             GenericPattern::Clear(pat) => {
-                let (str_iter, clear_pat, iter) = self.clear_ends_with_cases(str, pat.str());
+                let (str_iter, clear_pat, iter) = Self::clear_ends_with_cases(str, pat.str());
 
                 self.clear_compare_shifted((str_iter, &clear_pat), iter.into_par_iter())
             }
             GenericPattern::Enc(pat) => {
                 let null = (str.is_padded() ^ pat.is_padded()).then_some(FheAsciiChar::null(self));
 
-                let (str_iter, pat_iter, iter) = self.ends_with_cases(str, pat, null.as_ref());
+                let (str_iter, pat_iter, iter) = Self::ends_with_cases(str, pat, null.as_ref());
 
                 self.compare_shifted((str_iter, pat_iter), iter.into_par_iter(), false)
             }
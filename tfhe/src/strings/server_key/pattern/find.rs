@@ -1,12 +1,14 @@
-use crate::ciphertext::{FheAsciiChar, FheString, GenericPattern};
-use crate::server_key::pattern::IsMatch;
-use crate::server_key::{CharIter, FheStringIsEmpty, FheStringLen, ServerKey};
+use crate::integer::prelude::*;
+use crate::integer::ServerKey as IntegerServerKey;
+use crate::integer::{BooleanBlock, RadixCiphertext};
+use crate::strings::ciphertext::{FheAsciiChar, FheString, GenericPattern};
+use crate::strings::server_key::pattern::IsMatch;
+use crate::strings::server_key::{CharIter, FheStringIsEmpty, FheStringLen, ServerKey};
 use rayon::prelude::*;
 use rayon::vec::IntoIter;
-use tfhe::integer::prelude::*;
-use tfhe::integer::{BooleanBlock, RadixCiphertext};
+use std::borrow::Borrow;
 
-impl ServerKey {
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
     // Compare pat with str, with pat shifted right (in relation to str) the number of times given
     // by iter. Returns the first character index of the last match, or the first character index
     // of the first match if the range is reversed. If there's no match defaults to 0
@@ -16,8 +18,8 @@ impl ServerKey {
         par_iter: IntoIter<usize>,
         ignore_pat_pad: bool,
     ) -> (RadixCiphertext, BooleanBlock) {
-        let mut result = self.key.create_trivial_boolean_block(false);
-        let mut last_match_index = self.key.create_trivial_zero_radix(16);
+        let mut result = self.key().create_trivial_boolean_block(false);
+        let mut last_match_index = self.key().create_trivial_zero_radix(16);
         let (str, pat) = str_pat;
 
         let matched: Vec<_> = par_iter
@@ -41,16 +43,16 @@ impl ServerKey {
             .collect();
 
         for (i, is_matched) in matched {
-            let index = self.key.create_trivial_radix(i as u32, 16);
+            let index = self.key().create_trivial_radix(i as u32, 16);
 
             rayon::join(
                 || {
                     last_match_index =
-                        self.key
+                        self.key()
                             .if_then_else_parallelized(&is_matched, &index, &last_match_index)
                 },
                 // One of the possible values of the padded pat must match the str
-                || self.key.boolean_bitor_assign(&mut result, &is_matched),
+                || self.key().boolean_bitor_assign(&mut result, &is_matched),
             );
         }
 
@@ -62,15 +64,15 @@ impl ServerKey {
         str_pat: (CharIter, &str),
         par_iter: IntoIter<usize>,
     ) -> (RadixCiphertext, BooleanBlock) {
-        let mut result = self.key.create_trivial_boolean_block(false);
-        let mut last_match_index = self.key.create_trivial_zero_radix(16);
+        let mut result = self.key().create_trivial_boolean_block(false);
+        let mut last_match_index = self.key().create_trivial_zero_radix(16);
         let (str, pat) = str_pat;
 
         let matched: Vec<_> = par_iter
             .map(|start| {
                 let str_chars = str.clone().skip(start);
-
                 let a: Vec<&FheAsciiChar> = str_chars.collect();
+
                 let is_matched = self.clear_asciis_eq(a.into_iter(), pat);
 
                 (start, is_matched)
@@ -78,16 +80,16 @@ impl ServerKey {
             .collect();
 
         for (i, is_matched) in matched {
-            let index = self.key.create_trivial_radix(i as u32, 16);
+            let index = self.key().create_trivial_radix(i as u32, 16);
 
             rayon::join(
                 || {
                     last_match_index =
-                        self.key
+                        self.key()
                             .if_then_else_parallelized(&is_matched, &index, &last_match_index)
                 },
                 // One of the possible values of the padded pat must match the str
-                || self.key.boolean_bitor_assign(&mut result, &is_matched),
+                || self.key().boolean_bitor_assign(&mut result, &is_matched),
             );
         }
 
@@ -107,10 +109,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, GenericPattern};
-    /// use crate::server_key::gen_keys;
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    /// use tfhe::strings::{ClientKey, ServerKey};
     ///
-    /// let (ck, sk) = gen_keys();
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (haystack, needle) = ("hello world", "world");
     ///
     /// let enc_haystack = FheString::new(&ck, &haystack, None);
@@ -130,15 +137,15 @@ impl ServerKey {
             GenericPattern::Enc(pat) => pat.clone(),
         };
 
-        let zero = self.key.create_trivial_zero_radix(16);
+        let zero = self.key().create_trivial_zero_radix(16);
         match self.length_checks(str, &trivial_or_enc_pat) {
             // bool is true if pattern is empty, in which the first match index is 0. If it's false
             // we default to 0 as well
-            IsMatch::Clear(bool) => return (zero, self.key.create_trivial_boolean_block(bool)),
+            IsMatch::Clear(bool) => return (zero, self.key().create_trivial_boolean_block(bool)),
 
             // This variant is only returned in the empty string case so in any case index is 0
             IsMatch::Cipher(val) => return (zero, val),
-            _ => (),
+            IsMatch::None => (),
         }
 
         let ignore_pat_pad = trivial_or_enc_pat.is_padded();
@@ -147,7 +154,7 @@ impl ServerKey {
             .then_some(FheAsciiChar::null(self));
 
         let (str_iter, pat_iter, iter) =
-            self.contains_cases(str, &trivial_or_enc_pat, null.as_ref());
+            Self::contains_cases(str, &trivial_or_enc_pat, null.as_ref());
 
         let iter_values: Vec<_> = iter.rev().collect();
 
@@ -176,10 +183,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, GenericPattern};
-    /// use crate::server_key::gen_keys;
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    /// use tfhe::strings::{ClientKey, ServerKey};
     ///
-    /// let (ck, sk) = gen_keys();
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (haystack, needle) = ("hello world world", "world");
     ///
     /// let enc_haystack = FheString::new(&ck, &haystack, None);
@@ -199,7 +211,7 @@ impl ServerKey {
             GenericPattern::Enc(pat) => pat.clone(),
         };
 
-        let zero = self.key.create_trivial_zero_radix(16);
+        let zero = self.key().create_trivial_zero_radix(16);
         match self.length_checks(str, &trivial_or_enc_pat) {
             IsMatch::Clear(val) => {
                 // val = true if pattern is empty, in which the last match index = str.len()
@@ -207,19 +219,19 @@ impl ServerKey {
                     match self.len(str) {
                         FheStringLen::Padding(cipher_len) => cipher_len,
                         FheStringLen::NoPadding(len) => {
-                            self.key.create_trivial_radix(len as u32, 16)
+                            self.key().create_trivial_radix(len as u32, 16)
                         }
                     }
                 } else {
                     zero
                 };
 
-                return (index, self.key.create_trivial_boolean_block(val));
+                return (index, self.key().create_trivial_boolean_block(val));
             }
 
             // This variant is only returned in the empty string case so in any case index is 0
             IsMatch::Cipher(val) => return (zero, val),
-            _ => (),
+            IsMatch::None => (),
         }
 
         let ignore_pat_pad = trivial_or_enc_pat.is_padded();
@@ -232,7 +244,7 @@ impl ServerKey {
         };
 
         let (str_iter, pat_iter, iter) =
-            self.contains_cases(str, &trivial_or_enc_pat, null.as_ref());
+            Self::contains_cases(str, &trivial_or_enc_pat, null.as_ref());
 
         let iter_values: Vec<_> = ext_iter.unwrap_or(iter).collect();
 
@@ -253,29 +265,32 @@ impl ServerKey {
                 // (the actual length) which doesn't correspond to our `last_match_index`
                 let padded_pat_is_empty = match self.is_empty(&trivial_or_enc_pat) {
                     FheStringIsEmpty::Padding(is_empty) => Some(is_empty),
-                    _ => None,
+                    FheStringIsEmpty::NoPadding(_) => None,
                 };
 
                 // The non padded str case was handled thanks to + 1 in the ext_iter
-                if str.is_padded() && padded_pat_is_empty.is_some() {
-                    let str_true_len = match self.len(str) {
-                        FheStringLen::Padding(cipher_len) => cipher_len,
-                        FheStringLen::NoPadding(len) => {
-                            self.key.create_trivial_radix(len as u32, 16)
-                        }
-                    };
-
-                    Some((padded_pat_is_empty.unwrap(), str_true_len))
-                } else {
-                    None
+                match padded_pat_is_empty {
+                    Some(pat_is_empty) if str.is_padded() => {
+                        let str_true_len = match self.len(str) {
+                            FheStringLen::Padding(cipher_len) => cipher_len,
+                            FheStringLen::NoPadding(len) => {
+                                self.key().create_trivial_radix(len as u32, 16)
+                            }
+                        };
+
+                        Some((pat_is_empty, str_true_len))
+                    }
+                    _ => None,
                 }
             },
         );
 
         if let Some((pat_is_empty, str_true_len)) = option {
-            last_match_index =
-                self.key
-                    .if_then_else_parallelized(&pat_is_empty, &str_true_len, &last_match_index);
+            last_match_index = self.key().if_then_else_parallelized(
+                &pat_is_empty,
+                &str_true_len,
+                &last_match_index,
+            );
         }
 
         (last_match_index, result)
@@ -4,10 +4,16 @@ mod replace;
 mod split;
 mod strip;
 
-use crate::ciphertext::{FheAsciiChar, FheString};
-use crate::server_key::{CharIter, FheStringIsEmpty, ServerKey};
+pub use split::{
+    RSplit, RSplitN, RSplitTerminator, Split, SplitInclusive, SplitN, SplitTerminator,
+};
+
+use crate::integer::BooleanBlock;
+use crate::integer::ServerKey as IntegerServerKey;
+use crate::strings::ciphertext::{FheAsciiChar, FheString};
+use crate::strings::server_key::{CharIter, FheStringIsEmpty, ServerKey};
+use std::borrow::Borrow;
 use std::ops::Range;
-use tfhe::integer::BooleanBlock;
 
 // Useful for handling cases in which we know if there is or there isn't a match just by looking at
 // the lengths
@@ -19,7 +25,7 @@ enum IsMatch {
 
 // `length_checks` allow us to return early in the pattern matching functions, while the other
 // methods below contain logic for the different cases
-impl ServerKey {
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
     fn length_checks(&self, str: &FheString, pat: &FheString) -> IsMatch {
         let pat_len = pat.chars().len();
         let str_len = str.chars().len();
@@ -36,7 +42,7 @@ impl ServerKey {
             return match self.is_empty(pat) {
                 FheStringIsEmpty::Padding(value) => IsMatch::Cipher(value),
 
-                _ => IsMatch::Clear(false),
+                FheStringIsEmpty::NoPadding(_) => IsMatch::Clear(false),
             };
         }
 
@@ -57,11 +63,10 @@ impl ServerKey {
     }
 
     fn ends_with_cases<'a>(
-        &'a self,
         str: &'a FheString,
         pat: &'a FheString,
         null: Option<&'a FheAsciiChar>,
-    ) -> (CharIter, CharIter, Range<usize>) {
+    ) -> (CharIter<'a>, CharIter<'a>, Range<usize>) {
         let pat_len = pat.chars().len();
         let str_len = str.chars().len();
 
@@ -135,10 +140,9 @@ impl ServerKey {
     }
 
     fn clear_ends_with_cases<'a>(
-        &'a self,
         str: &'a FheString,
         pat: &str,
-    ) -> (CharIter, String, Range<usize>) {
+    ) -> (CharIter<'a>, String, Range<usize>) {
         let pat_len = pat.len();
         let str_len = str.chars().len();
 
@@ -161,11 +165,10 @@ impl ServerKey {
     }
 
     fn contains_cases<'a>(
-        &'a self,
         str: &'a FheString,
         pat: &'a FheString,
         null: Option<&'a FheAsciiChar>,
-    ) -> (CharIter, CharIter, Range<usize>) {
+    ) -> (CharIter<'a>, CharIter<'a>, Range<usize>) {
         let pat_len = pat.chars().len();
         let str_len = str.chars().len();
 
@@ -1,10 +1,12 @@
-use crate::ciphertext::{FheString, GenericPattern, UIntArg};
-use crate::server_key::pattern::IsMatch;
-use crate::server_key::{FheStringIsEmpty, FheStringLen, ServerKey};
-use tfhe::integer::prelude::*;
-use tfhe::integer::{BooleanBlock, RadixCiphertext};
-
-impl ServerKey {
+use crate::integer::prelude::*;
+use crate::integer::ServerKey as IntegerServerKey;
+use crate::integer::{BooleanBlock, RadixCiphertext};
+use crate::strings::ciphertext::{FheString, GenericPattern, UIntArg};
+use crate::strings::server_key::pattern::IsMatch;
+use crate::strings::server_key::{FheStringIsEmpty, FheStringLen, ServerKey};
+use std::borrow::Borrow;
+
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
     // Replaces the pattern ignoring the first `start` chars (i.e. these are not replaced)
     // Also returns the length up to the end of `to` in the replaced str, or 0 if there's no match
     fn replace_once(
@@ -30,10 +32,12 @@ impl ServerKey {
 
         let (mut replaced, rhs) = rayon::join(
             || {
-                let str_len = self.key.create_trivial_radix(str.chars().len() as u32, 16);
+                let str_len = self
+                    .key()
+                    .create_trivial_radix(str.chars().len() as u32, 16);
 
                 // Get the [lhs] shifting right by [from, rhs].len()
-                let shift_right = self.key.sub_parallelized(&str_len, find_index);
+                let shift_right = self.key().sub_parallelized(&str_len, find_index);
                 let mut lhs = self.right_shift_chars(str, &shift_right);
                 // As lhs is shifted right we know there aren't nulls on the right, unless empty
                 lhs.set_is_padded(false);
@@ -50,10 +54,10 @@ impl ServerKey {
                 // Get the [rhs] shifting left by [lhs, from].len()
                 let shift_left = match from_len {
                     FheStringLen::NoPadding(len) => {
-                        self.key.scalar_add_parallelized(find_index, *len as u32)
+                        self.key().scalar_add_parallelized(find_index, *len as u32)
                     }
                     FheStringLen::Padding(enc_len) => {
-                        self.key.add_parallelized(find_index, enc_len)
+                        self.key().add_parallelized(find_index, enc_len)
                     }
                 };
 
@@ -71,12 +75,12 @@ impl ServerKey {
             || self.conditional_string(replace, replaced, str),
             || {
                 // If there's match we return [lhs, to].len(), else we return 0 (index default)
-                let add_to_index = self.key.if_then_else_parallelized(
+                let add_to_index = self.key().if_then_else_parallelized(
                     replace,
                     enc_to_len,
-                    &self.key.create_trivial_zero_radix(16),
+                    &self.key().create_trivial_zero_radix(16),
                 );
-                self.key.add_parallelized(find_index, &add_to_index)
+                self.key().add_parallelized(find_index, &add_to_index)
             },
         )
     }
@@ -89,7 +93,7 @@ impl ServerKey {
         to: &FheString,
         enc_n: Option<&RadixCiphertext>,
     ) {
-        let mut skip = self.key.create_trivial_zero_radix(16);
+        let mut skip = self.key().create_trivial_zero_radix(16);
         let trivial_or_enc_from = match from {
             GenericPattern::Clear(from) => FheString::trivial(self, from.str()),
             GenericPattern::Enc(from) => from.clone(),
@@ -108,7 +112,7 @@ impl ServerKey {
                     || match self.len(to) {
                         FheStringLen::Padding(enc_val) => enc_val,
                         FheStringLen::NoPadding(val) => {
-                            self.key.create_trivial_radix(val as u32, 16)
+                            self.key().create_trivial_radix(val as u32, 16)
                         }
                     },
                 )
@@ -127,7 +131,7 @@ impl ServerKey {
                     let (mut index, is_match) = self.find(&shifted_str, from);
 
                     // We add `skip` to get the actual index of the pattern (in the non shifted str)
-                    self.key.add_assign_parallelized(&mut index, &skip);
+                    self.key().add_assign_parallelized(&mut index, &skip);
 
                     (*result, skip) =
                         self.replace_once(&is_match, &index, &from_len, &enc_to_len, result, to);
@@ -141,11 +145,11 @@ impl ServerKey {
                 // we need to move to the next space between letters by adding 1 to the skip value
                 || match &from_is_empty {
                     FheStringIsEmpty::Padding(enc) => self
-                        .key
-                        .add_assign_parallelized(&mut skip, &enc.clone().into_radix(1, &self.key)),
+                        .key()
+                        .add_assign_parallelized(&mut skip, &enc.clone().into_radix(1, self.key())),
 
                     FheStringIsEmpty::NoPadding(clear) => {
-                        self.key
+                        self.key()
                             .scalar_add_assign_parallelized(&mut skip, *clear as u8);
                     }
                 },
@@ -169,49 +173,47 @@ impl ServerKey {
             || {
                 let no_more_matches = match &str_len {
                     FheStringLen::Padding(enc) => {
-                        self.key.scalar_lt_parallelized(enc, current_iteration)
+                        self.key().scalar_lt_parallelized(enc, current_iteration)
                     }
                     FheStringLen::NoPadding(clear) => self
-                        .key
+                        .key()
                         .create_trivial_boolean_block(*clear < current_iteration as usize),
                 };
 
                 match &from_is_empty {
                     FheStringIsEmpty::Padding(enc) => {
-                        self.key.boolean_bitand(&no_more_matches, enc)
+                        self.key().boolean_bitand(&no_more_matches, enc)
                     }
                     FheStringIsEmpty::NoPadding(clear) => {
-                        let trivial = self.key.create_trivial_boolean_block(*clear);
-                        self.key.boolean_bitand(&no_more_matches, &trivial)
+                        let trivial = self.key().create_trivial_boolean_block(*clear);
+                        self.key().boolean_bitand(&no_more_matches, &trivial)
                     }
                 }
             },
-            || enc_n.map(|n| self.key.scalar_le_parallelized(n, current_iteration)),
+            || enc_n.map(|n| self.key().scalar_le_parallelized(n, current_iteration)),
         );
 
         if let Some(exceeded) = enc_n_is_exceeded {
-            self.key
+            self.key()
                 .boolean_bitor_assign(&mut no_more_matches, &exceeded);
         }
 
         no_more_matches
     }
 
-    fn max_matches(&self, str: &FheString, pat: &FheString) -> u16 {
+    fn max_matches(str: &FheString, pat: &FheString) -> u16 {
         let str_len = str.chars().len() - if str.is_padded() { 1 } else { 0 };
 
-        // Max number of matches is str_len + 1 when pattern is empty
-        let mut max: u16 = (str_len + 1).try_into().expect("str should be shorter");
-
         // If we know the actual `from` length, the max number of matches can be computed as
         // str_len - pat_len + 1. For instance "xx" matches "xxxx" at most 4 - 2 + 1 = 3 times.
         // This works as long as str_len >= pat_len (guaranteed due to the outer length checks)
-        if !pat.is_padded() {
+        if pat.is_padded() {
+            // Max number of matches is str_len + 1 when pattern is empty
+            (str_len + 1).try_into().expect("str should be shorter")
+        } else {
             let pat_len = pat.chars().len() as u16;
-            max = str_len as u16 - pat_len + 1;
+            str_len as u16 - pat_len + 1
         }
-
-        max
     }
 
     /// Returns a new encrypted string with a specified number of non-overlapping occurrences of a
@@ -231,10 +233,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, GenericPattern, UIntArg};
-    /// use crate::server_key::gen_keys;
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern, UIntArg};
+    /// use tfhe::strings::{ClientKey, ServerKey};
     ///
-    /// let (ck, sk) = gen_keys();
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s, from, to) = ("hello", "l", "r");
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -266,7 +273,7 @@ impl ServerKey {
     ) -> FheString {
         let mut result = str.clone();
 
-        if let UIntArg::Clear(0) = count {
+        if matches!(count, UIntArg::Clear(0)) {
             return result;
         }
 
@@ -278,25 +285,25 @@ impl ServerKey {
         match self.length_checks(str, &trivial_or_enc_from) {
             IsMatch::Clear(false) => return result,
 
-            IsMatch::Clear(true) => {
-                // If `from` is empty and str too, there's only one match and one replacement
-                if str.chars().is_empty() || (str.is_padded() && str.chars().len() == 1) {
-                    if let UIntArg::Clear(_) = count {
-                        return to.clone();
-                    }
+            // If `from` is empty and str too, there's only one match and one replacement
+            IsMatch::Clear(true)
+                if str.chars().is_empty() || (str.is_padded() && str.chars().len() == 1) =>
+            {
+                if let UIntArg::Clear(_) = count {
+                    return to.clone();
+                }
 
-                    // We have to take into account that encrypted n could be 0
-                    if let UIntArg::Enc(enc_n) = count {
-                        let n_is_zero = self.key.scalar_eq_parallelized(enc_n.cipher(), 0);
+                // We have to take into account that encrypted n could be 0
+                if let UIntArg::Enc(enc_n) = count {
+                    let n_is_zero = self.key().scalar_eq_parallelized(enc_n.cipher(), 0);
 
-                        let mut re = self.conditional_string(&n_is_zero, result, to);
+                    let mut re = self.conditional_string(&n_is_zero, result, to);
 
-                        // When result or to are empty we get padding via the conditional_string
-                        // (pad_ciphertexts_lsb). And the condition result may or may not have
-                        // padding in this case.
-                        re.append_null(self);
-                        return re;
-                    }
+                    // When result or to are empty we get padding via the conditional_string
+                    // (pad_ciphertexts_lsb). And the condition result may or may not have
+                    // padding in this case.
+                    re.append_null(self);
+                    return re;
                 }
             }
             // This happens when str is empty, so it's again one replacement if there's match or
@@ -307,8 +314,8 @@ impl ServerKey {
                 }
 
                 if let UIntArg::Enc(enc_n) = count {
-                    let n_not_zero = self.key.scalar_ne_parallelized(enc_n.cipher(), 0);
-                    let and_val = self.key.boolean_bitand(&n_not_zero, &val);
+                    let n_not_zero = self.key().scalar_ne_parallelized(enc_n.cipher(), 0);
+                    let and_val = self.key().boolean_bitand(&n_not_zero, &val);
 
                     let mut re = self.conditional_string(&and_val, to.clone(), str);
 
@@ -324,7 +331,7 @@ impl ServerKey {
 
         match count {
             UIntArg::Clear(n) => {
-                let max = self.max_matches(str, &trivial_or_enc_from);
+                let max = Self::max_matches(str, &trivial_or_enc_from);
 
                 // If n > max number of matches we use that max to avoid unnecessary iterations
                 let iterations = if *n > max { max } else { *n };
@@ -356,10 +363,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{ClearString, FheString, GenericPattern};
-    /// use crate::server_key::gen_keys;
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{ClearString, FheString, GenericPattern};
+    /// use tfhe::strings::{ClientKey, ServerKey};
     ///
-    /// let (ck, sk) = gen_keys();
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s, from, to) = ("hi", "i", "o");
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -386,11 +398,11 @@ impl ServerKey {
 
         match self.length_checks(str, &trivial_or_enc_from) {
             IsMatch::Clear(false) => return result,
-            IsMatch::Clear(true) => {
-                // If `from` is empty and str too, there's only one match and one replacement
-                if str.chars().is_empty() || (str.is_padded() && str.chars().len() == 1) {
-                    return to.clone();
-                }
+            // If `from` is empty and str too, there's only one match and one replacement
+            IsMatch::Clear(true)
+                if str.chars().is_empty() || (str.is_padded() && str.chars().len() == 1) =>
+            {
+                return to.clone();
             }
             // This happens when str is empty, so it's again one replacement if there's match or
             // if there isn't we return the str
@@ -398,7 +410,7 @@ impl ServerKey {
             _ => (),
         }
 
-        let max = self.max_matches(str, &trivial_or_enc_from);
+        let max = Self::max_matches(str, &trivial_or_enc_from);
 
         self.replace_n_times(max, &mut result, from, to, None);
 
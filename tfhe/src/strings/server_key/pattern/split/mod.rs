@@ -1,11 +1,15 @@
 mod split_iters;
 
-use crate::ciphertext::{FheString, GenericPattern, UIntArg};
-use crate::server_key::pattern::IsMatch;
-use crate::server_key::{FheStringIsEmpty, FheStringIterator, FheStringLen, ServerKey};
-use tfhe::integer::{BooleanBlock, RadixCiphertext};
+pub use split_iters::*;
 
-impl ServerKey {
+use crate::integer::ServerKey as IntegerServerKey;
+use crate::integer::{BooleanBlock, RadixCiphertext};
+use crate::strings::ciphertext::{FheString, GenericPattern, UIntArg};
+use crate::strings::server_key::pattern::IsMatch;
+use crate::strings::server_key::{FheStringIsEmpty, FheStringIterator, FheStringLen, ServerKey};
+use std::borrow::Borrow;
+
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
     fn split_pat_at_index(
         &self,
         str: &FheString,
@@ -13,17 +17,19 @@ impl ServerKey {
         index: &RadixCiphertext,
         inclusive: bool,
     ) -> (FheString, FheString) {
-        let str_len = self.key.create_trivial_radix(str.chars().len() as u32, 16);
+        let str_len = self
+            .key()
+            .create_trivial_radix(str.chars().len() as u32, 16);
         let trivial_or_enc_pat = match pat {
             GenericPattern::Clear(pat) => FheString::trivial(self, pat.str()),
             GenericPattern::Enc(pat) => pat.clone(),
         };
 
         let (mut shift_right, real_pat_len) = rayon::join(
-            || self.key.sub_parallelized(&str_len, index),
+            || self.key().sub_parallelized(&str_len, index),
             || match self.len(&trivial_or_enc_pat) {
                 FheStringLen::Padding(enc_val) => enc_val,
-                FheStringLen::NoPadding(val) => self.key.create_trivial_radix(val as u32, 16),
+                FheStringLen::NoPadding(val) => self.key().create_trivial_radix(val as u32, 16),
             },
         );
 
@@ -31,7 +37,7 @@ impl ServerKey {
             || {
                 if inclusive {
                     // Remove the real pattern length from the amount to shift
-                    self.key
+                    self.key()
                         .sub_assign_parallelized(&mut shift_right, &real_pat_len);
                 }
 
@@ -42,7 +48,7 @@ impl ServerKey {
                 self.left_shift_chars(&lhs, &shift_right)
             },
             || {
-                let shift_left = self.key.add_parallelized(&real_pat_len, index);
+                let shift_left = self.key().add_parallelized(&real_pat_len, index);
 
                 self.left_shift_chars(str, &shift_left)
             },
@@ -74,10 +80,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, GenericPattern};
-    /// use crate::server_key::gen_keys;
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    /// use tfhe::strings::{ClientKey, ServerKey};
     ///
-    /// let (ck, sk) = gen_keys();
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s, pat) = (" hello world", " ");
     /// let enc_s = FheString::new(&ck, &s, None);
     /// let enc_pat = GenericPattern::Enc(FheString::new(&ck, &pat, None));
@@ -109,20 +120,20 @@ impl ServerKey {
                     (
                         str.clone(),
                         FheString::empty(),
-                        self.key.create_trivial_boolean_block(true),
+                        self.key().create_trivial_boolean_block(true),
                     )
                 } else {
                     // There's no match so we default to empty string and str
                     (
                         FheString::empty(),
                         str.clone(),
-                        self.key.create_trivial_boolean_block(false),
+                        self.key().create_trivial_boolean_block(false),
                     )
                 };
             }
             // This is only returned when str is empty so both sub-strings are empty as well
             IsMatch::Cipher(enc_val) => return (FheString::empty(), FheString::empty(), enc_val),
-            _ => (),
+            IsMatch::None => (),
         }
 
         let (index, is_match) = self.rfind(str, pat);
@@ -144,10 +155,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, GenericPattern};
-    /// use crate::server_key::gen_keys;
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    /// use tfhe::strings::{ClientKey, ServerKey};
     ///
-    /// let (ck, sk) = gen_keys();
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s, pat) = (" hello world", " ");
     /// let enc_s = FheString::new(&ck, &s, None);
     /// let enc_pat = GenericPattern::Enc(FheString::new(&ck, &pat, None));
@@ -179,20 +195,20 @@ impl ServerKey {
                     (
                         FheString::empty(),
                         str.clone(),
-                        self.key.create_trivial_boolean_block(true),
+                        self.key().create_trivial_boolean_block(true),
                     )
                 } else {
                     // There's no match so we default to empty string and str
                     (
                         FheString::empty(),
                         str.clone(),
-                        self.key.create_trivial_boolean_block(false),
+                        self.key().create_trivial_boolean_block(false),
                     )
                 };
             }
             // This is only returned when str is empty so both sub-strings are empty as well
             IsMatch::Cipher(enc_val) => return (FheString::empty(), FheString::empty(), enc_val),
-            _ => (),
+            IsMatch::None => (),
         }
 
         let (index, is_match) = self.find(str, pat);
@@ -210,19 +226,20 @@ impl ServerKey {
     ) -> SplitInternal {
         let mut max_counter = match self.len(str) {
             FheStringLen::Padding(enc_val) => enc_val,
-            FheStringLen::NoPadding(val) => self.key.create_trivial_radix(val as u32, 16),
+            FheStringLen::NoPadding(val) => self.key().create_trivial_radix(val as u32, 16),
         };
 
-        self.key.scalar_add_assign_parallelized(&mut max_counter, 1);
+        self.key()
+            .scalar_add_assign_parallelized(&mut max_counter, 1);
 
         SplitInternal {
             split_type,
             state: str.clone(),
             pat: pat.clone(),
-            prev_was_some: self.key.create_trivial_boolean_block(true),
+            prev_was_some: self.key().create_trivial_boolean_block(true),
             counter: 0,
             max_counter,
-            counter_lt_max: self.key.create_trivial_boolean_block(true),
+            counter_lt_max: self.key().create_trivial_boolean_block(true),
         }
     }
 
@@ -233,19 +250,19 @@ impl ServerKey {
         n: UIntArg,
         split_type: SplitType,
     ) -> SplitNInternal {
-        if let SplitType::SplitInclusive = split_type {
+        if matches!(split_type, SplitType::SplitInclusive) {
             panic!("We have either SplitN or RSplitN")
         }
 
         let uint_not_0 = match &n {
             UIntArg::Clear(val) => {
                 if *val != 0 {
-                    self.key.create_trivial_boolean_block(true)
+                    self.key().create_trivial_boolean_block(true)
                 } else {
-                    self.key.create_trivial_boolean_block(false)
+                    self.key().create_trivial_boolean_block(false)
                 }
             }
-            UIntArg::Enc(enc) => self.key.scalar_ne_parallelized(enc.cipher(), 0),
+            UIntArg::Enc(enc) => self.key().scalar_ne_parallelized(enc.cipher(), 0),
         };
 
         let internal = self.split_internal(str, pat, split_type);
@@ -264,23 +281,23 @@ impl ServerKey {
         pat: &GenericPattern,
         split_type: SplitType,
     ) -> SplitNoTrailing {
-        if let SplitType::RSplit = split_type {
+        if matches!(split_type, SplitType::RSplit) {
             panic!("Only Split or SplitInclusive")
         }
 
         let max_counter = match self.len(str) {
             FheStringLen::Padding(enc_val) => enc_val,
-            FheStringLen::NoPadding(val) => self.key.create_trivial_radix(val as u32, 16),
+            FheStringLen::NoPadding(val) => self.key().create_trivial_radix(val as u32, 16),
         };
 
         let internal = SplitInternal {
             split_type,
             state: str.clone(),
             pat: pat.clone(),
-            prev_was_some: self.key.create_trivial_boolean_block(true),
+            prev_was_some: self.key().create_trivial_boolean_block(true),
             counter: 0,
             max_counter,
-            counter_lt_max: self.key.create_trivial_boolean_block(true),
+            counter_lt_max: self.key().create_trivial_boolean_block(true),
         };
 
         SplitNoTrailing { internal }
@@ -293,7 +310,7 @@ impl ServerKey {
 
         let leading_empty_str = match self.is_empty(&prev_return.0) {
             FheStringIsEmpty::Padding(enc) => enc,
-            FheStringIsEmpty::NoPadding(clear) => self.key.create_trivial_boolean_block(clear),
+            FheStringIsEmpty::NoPadding(clear) => self.key().create_trivial_boolean_block(clear),
         };
 
         SplitNoLeading {
@@ -338,7 +355,10 @@ struct SplitNoLeading {
 }
 
 impl FheStringIterator for SplitInternal {
-    fn next(&mut self, sk: &ServerKey) -> (FheString, BooleanBlock) {
+    fn next<T: Borrow<IntegerServerKey> + Sync>(
+        &mut self,
+        sk: &ServerKey<T>,
+    ) -> (FheString, BooleanBlock) {
         let trivial_or_enc_pat = match &self.pat {
             GenericPattern::Clear(pat) => FheString::trivial(sk, pat.str()),
             GenericPattern::Enc(pat) => pat.clone(),
@@ -346,15 +366,17 @@ impl FheStringIterator for SplitInternal {
 
         let ((mut index, mut is_some), pat_is_empty) = rayon::join(
             || {
-                if let SplitType::RSplit = self.split_type {
+                if matches!(self.split_type, SplitType::RSplit) {
                     sk.rfind(&self.state, &self.pat)
                 } else {
                     sk.find(&self.state, &self.pat)
                 }
             },
             || match sk.is_empty(&trivial_or_enc_pat) {
-                FheStringIsEmpty::Padding(enc) => enc.into_radix(16, &sk.key),
-                FheStringIsEmpty::NoPadding(clear) => sk.key.create_trivial_radix(clear as u32, 16),
+                FheStringIsEmpty::Padding(enc) => enc.into_radix(16, sk.key()),
+                FheStringIsEmpty::NoPadding(clear) => {
+                    sk.key().create_trivial_radix(clear as u32, 16)
+                }
             },
         );
 
@@ -366,14 +388,14 @@ impl FheStringIterator for SplitInternal {
             // to manually advance the match index as an empty pattern always matches at the very
             // start (or end in the rsplit case)
 
-            if let SplitType::RSplit = self.split_type {
-                sk.key.sub_assign_parallelized(&mut index, &pat_is_empty);
+            if matches!(self.split_type, SplitType::RSplit) {
+                sk.key().sub_assign_parallelized(&mut index, &pat_is_empty);
             } else {
-                sk.key.add_assign_parallelized(&mut index, &pat_is_empty);
+                sk.key().add_assign_parallelized(&mut index, &pat_is_empty);
             }
         }
 
-        let (lhs, rhs) = if let SplitType::SplitInclusive = self.split_type {
+        let (lhs, rhs) = if matches!(self.split_type, SplitType::SplitInclusive) {
             sk.split_pat_at_index(&self.state, &self.pat, &index, true)
         } else {
             sk.split_pat_at_index(&self.state, &self.pat, &index, false)
@@ -382,7 +404,7 @@ impl FheStringIterator for SplitInternal {
         let current_is_some = is_some.clone();
 
         // The moment it's None (no match) we return the remaining state
-        let result = if let SplitType::RSplit = self.split_type {
+        let result = if matches!(self.split_type, SplitType::RSplit) {
             let re = sk.conditional_string(&current_is_some, rhs, &self.state);
 
             self.state = lhs;
@@ -396,17 +418,17 @@ impl FheStringIterator for SplitInternal {
 
         // Even if there isn't match, we return Some if there was match in the previous next call,
         // as we are returning the remaining state "wrapped" in Some
-        sk.key
+        sk.key()
             .boolean_bitor_assign(&mut is_some, &self.prev_was_some);
 
         // If pattern is empty, `is_some` is always true, so we make it false when we have reached
         // the last possible counter value
-        sk.key
+        sk.key()
             .boolean_bitand_assign(&mut is_some, &self.counter_lt_max);
 
         self.prev_was_some = current_is_some;
         self.counter_lt_max = sk
-            .key
+            .key()
             .scalar_gt_parallelized(&self.max_counter, self.counter);
 
         self.counter += 1;
@@ -416,13 +438,16 @@ impl FheStringIterator for SplitInternal {
 }
 
 impl FheStringIterator for SplitNInternal {
-    fn next(&mut self, sk: &ServerKey) -> (FheString, BooleanBlock) {
+    fn next<T: Borrow<IntegerServerKey> + Sync>(
+        &mut self,
+        sk: &ServerKey<T>,
+    ) -> (FheString, BooleanBlock) {
         let state = self.internal.state.clone();
 
         let (mut result, mut is_some) = self.internal.next(sk);
 
         // This keeps the original `is_some` value unless we have exceeded n
-        sk.key
+        sk.key()
             .boolean_bitand_assign(&mut is_some, &self.not_exceeded);
 
         // The moment counter is at least one less than n we return the remaining state, and make
@@ -431,24 +456,24 @@ impl FheStringIterator for SplitNInternal {
             UIntArg::Clear(clear_n) => {
                 if self.counter >= clear_n - 1 {
                     result = state;
-                    self.not_exceeded = sk.key.create_trivial_boolean_block(false);
+                    self.not_exceeded = sk.key().create_trivial_boolean_block(false);
                 }
             }
             UIntArg::Enc(enc_n) => {
                 // Note that when `enc_n` is zero `n_minus_one` wraps to a very large number and so
                 // `exceeded` will be false. Nonetheless the initial value of `not_exceeded`
                 // was set to false in the n is zero case, so we return None
-                let n_minus_one = sk.key.scalar_sub_parallelized(enc_n.cipher(), 1);
-                let exceeded = sk.key.scalar_le_parallelized(&n_minus_one, self.counter);
+                let n_minus_one = sk.key().scalar_sub_parallelized(enc_n.cipher(), 1);
+                let exceeded = sk.key().scalar_le_parallelized(&n_minus_one, self.counter);
 
                 rayon::join(
                     || result = sk.conditional_string(&exceeded, state, &result),
                     || {
-                        let current_not_exceeded = sk.key.boolean_bitnot(&exceeded);
+                        let current_not_exceeded = sk.key().boolean_bitnot(&exceeded);
 
                         // If current is not exceeded we use the previous not_exceeded value,
                         // or false if it's exceeded
-                        sk.key
+                        sk.key()
                             .boolean_bitand_assign(&mut self.not_exceeded, &current_not_exceeded);
                     },
                 );
@@ -462,7 +487,10 @@ impl FheStringIterator for SplitNInternal {
 }
 
 impl FheStringIterator for SplitNoTrailing {
-    fn next(&mut self, sk: &ServerKey) -> (FheString, BooleanBlock) {
+    fn next<T: Borrow<IntegerServerKey> + Sync>(
+        &mut self,
+        sk: &ServerKey<T>,
+    ) -> (FheString, BooleanBlock) {
         let (result, mut is_some) = self.internal.next(sk);
 
         let (result_is_empty, prev_was_none) = rayon::join(
@@ -471,18 +499,18 @@ impl FheStringIterator for SplitNoTrailing {
             // string, we return None to remove it
             || match sk.is_empty(&result) {
                 FheStringIsEmpty::Padding(enc) => enc,
-                FheStringIsEmpty::NoPadding(clear) => sk.key.create_trivial_boolean_block(clear),
+                FheStringIsEmpty::NoPadding(clear) => sk.key().create_trivial_boolean_block(clear),
             },
-            || sk.key.boolean_bitnot(&self.internal.prev_was_some),
+            || sk.key().boolean_bitnot(&self.internal.prev_was_some),
         );
 
-        let trailing_empty_str = sk.key.boolean_bitand(&result_is_empty, &prev_was_none);
+        let trailing_empty_str = sk.key().boolean_bitand(&result_is_empty, &prev_was_none);
 
-        let not_trailing_empty_str = sk.key.boolean_bitnot(&trailing_empty_str);
+        let not_trailing_empty_str = sk.key().boolean_bitnot(&trailing_empty_str);
 
         // If there's no empty trailing string we get the previous `is_some`,
         // else we get false (None)
-        sk.key
+        sk.key()
             .boolean_bitand_assign(&mut is_some, &not_trailing_empty_str);
 
         (result, is_some)
@@ -490,7 +518,10 @@ impl FheStringIterator for SplitNoTrailing {
 }
 
 impl FheStringIterator for SplitNoLeading {
-    fn next(&mut self, sk: &ServerKey) -> (FheString, BooleanBlock) {
+    fn next<T: Borrow<IntegerServerKey> + Sync>(
+        &mut self,
+        sk: &ServerKey<T>,
+    ) -> (FheString, BooleanBlock) {
         // We want to remove the leading empty string i.e. the first returned substring should be
         // skipped if empty.
         //
@@ -504,18 +535,18 @@ impl FheStringIterator for SplitNoLeading {
             || {
                 let (lhs, rhs) = rayon::join(
                     // This is `is_some` if `leading_empty_str` is true, false otherwise
-                    || sk.key.boolean_bitand(&self.leading_empty_str, &is_some),
+                    || sk.key().boolean_bitand(&self.leading_empty_str, &is_some),
                     // This is the flag from the previous next call if `leading_empty_str` is true,
                     // false otherwise
                     || {
-                        sk.key.boolean_bitand(
-                            &sk.key.boolean_bitnot(&self.leading_empty_str),
+                        sk.key().boolean_bitand(
+                            &sk.key().boolean_bitnot(&self.leading_empty_str),
                             &self.prev_return.1,
                         )
                     },
                 );
 
-                sk.key.boolean_bitor(&lhs, &rhs)
+                sk.key().boolean_bitor(&lhs, &rhs)
             },
         );
 
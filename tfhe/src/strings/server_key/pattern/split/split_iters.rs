@@ -1,9 +1,11 @@
-use crate::ciphertext::{FheString, GenericPattern, UIntArg};
-use crate::server_key::pattern::split::{
+use crate::integer::BooleanBlock;
+use crate::integer::ServerKey as IntegerServerKey;
+use crate::strings::ciphertext::{FheString, GenericPattern, UIntArg};
+use crate::strings::server_key::pattern::split::{
     SplitInternal, SplitNInternal, SplitNoLeading, SplitNoTrailing, SplitType,
 };
-use crate::server_key::{FheStringIterator, ServerKey};
-use tfhe::integer::BooleanBlock;
+use crate::strings::server_key::{FheStringIterator, ServerKey};
+use std::borrow::Borrow;
 
 pub struct RSplit {
     internal: SplitInternal,
@@ -33,7 +35,7 @@ pub struct RSplitTerminator {
     internal: SplitNoLeading,
 }
 
-impl ServerKey {
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
     /// Creates an iterator of encrypted substrings by splitting the original encrypted string based
     /// on a specified pattern (either encrypted or clear).
     ///
@@ -47,10 +49,16 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, GenericPattern};
-    /// use crate::server_key::{gen_keys, FheStringIterator};
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    /// use tfhe::strings::server_key::FheStringIterator;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s, pat) = ("hello ", " ");
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -92,10 +100,16 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, GenericPattern};
-    /// use crate::server_key::{gen_keys, FheStringIterator};
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    /// use tfhe::strings::server_key::FheStringIterator;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s, pat) = ("hello ", " ");
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -138,10 +152,16 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, GenericPattern, UIntArg};
-    /// use crate::server_key::{gen_keys, FheStringIterator};
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern, UIntArg};
+    /// use tfhe::strings::server_key::FheStringIterator;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s, pat) = ("hello world", " ");
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -190,10 +210,16 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, GenericPattern, UIntArg};
-    /// use crate::server_key::{gen_keys, FheStringIterator};
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern, UIntArg};
+    /// use tfhe::strings::server_key::FheStringIterator;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s, pat) = ("hello world", " ");
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -240,10 +266,16 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, GenericPattern};
-    /// use crate::server_key::{gen_keys, FheStringIterator};
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    /// use tfhe::strings::server_key::FheStringIterator;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s, pat) = ("hello world ", " ");
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -288,10 +320,16 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, GenericPattern};
-    /// use crate::server_key::{gen_keys, FheStringIterator};
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    /// use tfhe::strings::server_key::FheStringIterator;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s, pat) = ("hello world ", " ");
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -335,10 +373,16 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{FheString, GenericPattern};
-    /// use crate::server_key::{gen_keys, FheStringIterator};
-    ///
-    /// let (ck, sk) = gen_keys();
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{FheString, GenericPattern};
+    /// use tfhe::strings::server_key::FheStringIterator;
+    /// use tfhe::strings::{ClientKey, ServerKey};
+    ///
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s, pat) = ("hello world ", " ");
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -369,43 +413,64 @@ impl ServerKey {
 }
 
 impl FheStringIterator for Split {
-    fn next(&mut self, sk: &ServerKey) -> (FheString, BooleanBlock) {
+    fn next<T: Borrow<IntegerServerKey> + Sync>(
+        &mut self,
+        sk: &ServerKey<T>,
+    ) -> (FheString, BooleanBlock) {
         self.internal.next(sk)
     }
 }
 
 impl FheStringIterator for RSplit {
-    fn next(&mut self, sk: &ServerKey) -> (FheString, BooleanBlock) {
+    fn next<T: Borrow<IntegerServerKey> + Sync>(
+        &mut self,
+        sk: &ServerKey<T>,
+    ) -> (FheString, BooleanBlock) {
         self.internal.next(sk)
     }
 }
 
 impl FheStringIterator for SplitN {
-    fn next(&mut self, sk: &ServerKey) -> (FheString, BooleanBlock) {
+    fn next<T: Borrow<IntegerServerKey> + Sync>(
+        &mut self,
+        sk: &ServerKey<T>,
+    ) -> (FheString, BooleanBlock) {
         self.internal.next(sk)
     }
 }
 
 impl FheStringIterator for RSplitN {
-    fn next(&mut self, sk: &ServerKey) -> (FheString, BooleanBlock) {
+    fn next<T: Borrow<IntegerServerKey> + Sync>(
+        &mut self,
+        sk: &ServerKey<T>,
+    ) -> (FheString, BooleanBlock) {
         self.internal.next(sk)
     }
 }
 
 impl FheStringIterator for SplitTerminator {
-    fn next(&mut self, sk: &ServerKey) -> (FheString, BooleanBlock) {
+    fn next<T: Borrow<IntegerServerKey> + Sync>(
+        &mut self,
+        sk: &ServerKey<T>,
+    ) -> (FheString, BooleanBlock) {
         self.internal.next(sk)
     }
 }
 
 impl FheStringIterator for RSplitTerminator {
-    fn next(&mut self, sk: &ServerKey) -> (FheString, BooleanBlock) {
+    fn next<T: Borrow<IntegerServerKey> + Sync>(
+        &mut self,
+        sk: &ServerKey<T>,
+    ) -> (FheString, BooleanBlock) {
         self.internal.next(sk)
     }
 }
 
 impl FheStringIterator for SplitInclusive {
-    fn next(&mut self, sk: &ServerKey) -> (FheString, BooleanBlock) {
+    fn next<T: Borrow<IntegerServerKey> + Sync>(
+        &mut self,
+        sk: &ServerKey<T>,
+    ) -> (FheString, BooleanBlock) {
         self.internal.next(sk)
     }
 }
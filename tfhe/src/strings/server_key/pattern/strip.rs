@@ -1,19 +1,21 @@
-use crate::ciphertext::{FheAsciiChar, FheString, GenericPattern};
-use crate::server_key::pattern::IsMatch;
-use crate::server_key::{CharIter, FheStringLen, ServerKey};
+use crate::integer::prelude::*;
+use crate::integer::BooleanBlock;
+use crate::integer::ServerKey as IntegerServerKey;
+use crate::strings::ciphertext::{FheAsciiChar, FheString, GenericPattern};
+use crate::strings::server_key::pattern::IsMatch;
+use crate::strings::server_key::{CharIter, FheStringLen, ServerKey};
 use rayon::prelude::*;
+use std::borrow::Borrow;
 use std::ops::Range;
-use tfhe::integer::prelude::*;
-use tfhe::integer::BooleanBlock;
 
-impl ServerKey {
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
     fn compare_shifted_strip(
         &self,
         strip_str: &mut FheString,
         str_pat: (CharIter, CharIter),
         iter: Range<usize>,
     ) -> BooleanBlock {
-        let mut result = self.key.create_trivial_boolean_block(false);
+        let mut result = self.key().create_trivial_boolean_block(false);
         let (str, pat) = str_pat;
 
         let pat_len = pat.clone().count();
@@ -27,10 +29,10 @@ impl ServerKey {
 
             let is_matched = self.asciis_eq(a.into_iter(), b.into_iter());
 
-            let mut mask = is_matched.clone().into_radix(4, &self.key);
+            let mut mask = is_matched.clone().into_radix(4, self.key());
 
             // If mask == 0u8, it will now be 255u8. If it was 1u8, it will now be 0u8
-            self.key.scalar_sub_assign_parallelized(&mut mask, 1);
+            self.key().scalar_sub_assign_parallelized(&mut mask, 1);
 
             let mutate_chars = if start + pat_len < str_len {
                 &mut strip_str.chars_mut()[start..start + pat_len]
@@ -41,12 +43,12 @@ impl ServerKey {
             rayon::join(
                 || {
                     mutate_chars.par_iter_mut().for_each(|char| {
-                        self.key
+                        self.key()
                             .bitand_assign_parallelized(char.ciphertext_mut(), &mask);
                     });
                 },
                 // One of the possible values of pat must match the str
-                || self.key.boolean_bitor_assign(&mut result, &is_matched),
+                || self.key().boolean_bitor_assign(&mut result, &is_matched),
             );
         }
 
@@ -59,7 +61,7 @@ impl ServerKey {
         str_pat: (CharIter, &str),
         iter: Range<usize>,
     ) -> BooleanBlock {
-        let mut result = self.key.create_trivial_boolean_block(false);
+        let mut result = self.key().create_trivial_boolean_block(false);
         let (str, pat) = str_pat;
 
         let pat_len = pat.len();
@@ -70,10 +72,10 @@ impl ServerKey {
 
             let is_matched = self.clear_asciis_eq(a.into_iter(), pat);
 
-            let mut mask = is_matched.clone().into_radix(4, &self.key);
+            let mut mask = is_matched.clone().into_radix(4, self.key());
 
             // If mask == 0u8, it will now be 255u8. If it was 1u8, it will now be 0u8
-            self.key.scalar_sub_assign_parallelized(&mut mask, 1);
+            self.key().scalar_sub_assign_parallelized(&mut mask, 1);
 
             let mutate_chars = if start + pat_len < str_len {
                 &mut strip_str.chars_mut()[start..start + pat_len]
@@ -84,12 +86,12 @@ impl ServerKey {
             rayon::join(
                 || {
                     mutate_chars.par_iter_mut().for_each(|char| {
-                        self.key
+                        self.key()
                             .bitand_assign_parallelized(char.ciphertext_mut(), &mask);
                     });
                 },
                 // One of the possible values of pat must match the str
-                || self.key.boolean_bitor_assign(&mut result, &is_matched),
+                || self.key().boolean_bitor_assign(&mut result, &is_matched),
             );
         }
 
@@ -109,10 +111,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{ClearString, FheString, GenericPattern};
-    /// use crate::server_key::gen_keys;
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{ClearString, FheString, GenericPattern};
+    /// use tfhe::strings::{ClientKey, ServerKey};
     ///
-    /// let (ck, sk) = gen_keys();
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s, prefix, not_prefix) = ("hello world", "hello", "world");
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -142,27 +149,27 @@ impl ServerKey {
 
         match self.length_checks(str, &trivial_or_enc_pat) {
             // If IsMatch is Clear we return the same string (a true means the pattern is empty)
-            IsMatch::Clear(bool) => return (result, self.key.create_trivial_boolean_block(bool)),
+            IsMatch::Clear(bool) => return (result, self.key().create_trivial_boolean_block(bool)),
 
             // If IsMatch is Cipher it means str is empty so in any case we return the same string
             IsMatch::Cipher(val) => return (result, val),
-            _ => (),
+            IsMatch::None => (),
         }
 
         let (starts_with, real_pat_len) = rayon::join(
             || self.starts_with(str, pat),
             || match self.len(&trivial_or_enc_pat) {
                 FheStringLen::Padding(enc_val) => enc_val,
-                FheStringLen::NoPadding(val) => self.key.create_trivial_radix(val as u32, 16),
+                FheStringLen::NoPadding(val) => self.key().create_trivial_radix(val as u32, 16),
             },
         );
 
         // If there's match we shift the str left by `real_pat_len` (removing the prefix and adding
         // nulls at the end), else we shift it left by 0
-        let shift_left = self.key.if_then_else_parallelized(
+        let shift_left = self.key().if_then_else_parallelized(
             &starts_with,
             &real_pat_len,
-            &self.key.create_trivial_zero_radix(16),
+            &self.key().create_trivial_zero_radix(16),
         );
 
         result = self.left_shift_chars(str, &shift_left);
@@ -170,10 +177,10 @@ impl ServerKey {
         // If str was not padded originally we don't know if result has nulls at the end or not (we
         // don't know if str was shifted or not) so we ensure it's padded in order to be
         // used in other functions safely
-        if !str.is_padded() {
-            result.append_null(self);
-        } else {
+        if str.is_padded() {
             result.set_is_padded(true);
+        } else {
+            result.append_null(self);
         }
 
         (result, starts_with)
@@ -192,10 +199,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::{ClearString, FheString, GenericPattern};
-    /// use crate::server_key::gen_keys;
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::{ClearString, FheString, GenericPattern};
+    /// use tfhe::strings::{ClientKey, ServerKey};
     ///
-    /// let (ck, sk) = gen_keys();
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let (s, suffix, not_suffix) = ("hello world", "world", "hello");
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -226,23 +238,23 @@ impl ServerKey {
 
         match self.length_checks(str, &trivial_or_enc_pat) {
             // If IsMatch is Clear we return the same string (a true means the pattern is empty)
-            IsMatch::Clear(bool) => return (result, self.key.create_trivial_boolean_block(bool)),
+            IsMatch::Clear(bool) => return (result, self.key().create_trivial_boolean_block(bool)),
 
             // If IsMatch is Cipher it means str is empty so in any case we return the same string
             IsMatch::Cipher(val) => return (result, val),
-            _ => (),
+            IsMatch::None => (),
         }
 
         let is_match = match pat {
             GenericPattern::Clear(pat) => {
-                let (str_iter, clear_pat, iter) = self.clear_ends_with_cases(str, pat.str());
+                let (str_iter, clear_pat, iter) = Self::clear_ends_with_cases(str, pat.str());
 
                 self.clear_compare_shifted_strip(&mut result, (str_iter, &clear_pat), iter)
             }
             GenericPattern::Enc(pat) => {
                 let null = (str.is_padded() ^ pat.is_padded()).then_some(FheAsciiChar::null(self));
 
-                let (str_iter, pat_iter, iter) = self.ends_with_cases(str, pat, null.as_ref());
+                let (str_iter, pat_iter, iter) = Self::ends_with_cases(str, pat, null.as_ref());
 
                 self.compare_shifted_strip(&mut result, (str_iter, pat_iter), iter)
             }
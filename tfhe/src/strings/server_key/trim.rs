@@ -1,8 +1,10 @@
-use crate::ciphertext::{FheAsciiChar, FheString};
-use crate::server_key::{FheStringIsEmpty, FheStringIterator, FheStringLen, ServerKey};
+use crate::integer::prelude::*;
+use crate::integer::BooleanBlock;
+use crate::integer::ServerKey as IntegerServerKey;
+use crate::strings::ciphertext::{FheAsciiChar, FheString};
+use crate::strings::server_key::{FheStringIsEmpty, FheStringIterator, FheStringLen, ServerKey};
 use rayon::prelude::*;
-use tfhe::integer::prelude::*;
-use tfhe::integer::BooleanBlock;
+use std::borrow::Borrow;
 
 pub struct SplitAsciiWhitespace {
     state: FheString,
@@ -10,13 +12,16 @@ pub struct SplitAsciiWhitespace {
 }
 
 impl FheStringIterator for SplitAsciiWhitespace {
-    fn next(&mut self, sk: &ServerKey) -> (FheString, BooleanBlock) {
+    fn next<T: Borrow<IntegerServerKey> + Sync>(
+        &mut self,
+        sk: &ServerKey<T>,
+    ) -> (FheString, BooleanBlock) {
         let str_len = self.state.chars().len();
 
         if str_len == 0 || (self.state.is_padded() && str_len == 1) {
             return (
                 FheString::empty(),
-                sk.key.create_trivial_boolean_block(false),
+                sk.key().create_trivial_boolean_block(false),
             );
         }
 
@@ -34,7 +39,7 @@ impl FheStringIterator for SplitAsciiWhitespace {
                 // If state after trim_start is empty it means the remaining string was either
                 // empty or only whitespace. Hence, there are no more elements to return
                 if let FheStringIsEmpty::Padding(val) = sk.is_empty(&state_after_trim) {
-                    sk.key.boolean_bitnot(&val)
+                    sk.key().boolean_bitnot(&val)
                 } else {
                     panic!("Empty str case was handled so 'state_after_trim' is padded")
                 }
@@ -45,20 +50,24 @@ impl FheStringIterator for SplitAsciiWhitespace {
 
 impl SplitAsciiWhitespace {
     // The mask contains 255u8 until we find some whitespace, then will be 0u8
-    fn create_and_apply_mask(&mut self, sk: &ServerKey) -> FheString {
+    fn create_and_apply_mask<T: Borrow<IntegerServerKey> + Sync>(
+        &mut self,
+        sk: &ServerKey<T>,
+    ) -> FheString {
         let mut mask = self.state.clone();
         let mut result = self.state.clone();
 
-        let mut prev_was_not = sk.key.create_trivial_boolean_block(true);
+        let mut prev_was_not = sk.key().create_trivial_boolean_block(true);
         for char in mask.chars_mut().iter_mut() {
             let mut is_not_ws = sk.is_not_whitespace(char);
-            sk.key.boolean_bitand_assign(&mut is_not_ws, &prev_was_not);
+            sk.key()
+                .boolean_bitand_assign(&mut is_not_ws, &prev_was_not);
 
-            let mut mask_u8 = is_not_ws.clone().into_radix(4, &sk.key);
+            let mut mask_u8 = is_not_ws.clone().into_radix(4, sk.key());
 
             // 0u8 is kept the same, but 1u8 is transformed into 255u8
-            sk.key.scalar_sub_assign_parallelized(&mut mask_u8, 1);
-            sk.key.bitnot_assign(&mut mask_u8);
+            sk.key().scalar_sub_assign_parallelized(&mut mask_u8, 1);
+            sk.key().bitnot_assign(&mut mask_u8);
 
             *char.ciphertext_mut() = mask_u8;
 
@@ -72,7 +81,7 @@ impl SplitAsciiWhitespace {
             .zip(mask.chars())
             .par_bridge()
             .for_each(|(char, mask_u8)| {
-                sk.key
+                sk.key()
                     .bitand_assign_parallelized(char.ciphertext_mut(), mask_u8.ciphertext());
             });
 
@@ -82,14 +91,14 @@ impl SplitAsciiWhitespace {
     }
 
     // Shifts the string left to get the remaining string (starting at the next first whitespace)
-    fn remaining_string(&mut self, sk: &ServerKey) {
+    fn remaining_string<T: Borrow<IntegerServerKey> + Sync>(&mut self, sk: &ServerKey<T>) {
         let mask = self.current_mask.as_ref().unwrap();
 
-        let mut number_of_trues = sk.key.create_trivial_zero_radix(16);
+        let mut number_of_trues = sk.key().create_trivial_zero_radix(16);
         for mask_u8 in mask.chars() {
-            let is_true = sk.key.scalar_eq_parallelized(mask_u8.ciphertext(), 255u8);
-            sk.key
-                .add_assign_parallelized(&mut number_of_trues, &is_true.into_radix(1, &sk.key));
+            let is_true = sk.key().scalar_eq_parallelized(mask_u8.ciphertext(), 255u8);
+            sk.key()
+                .add_assign_parallelized(&mut number_of_trues, &is_true.into_radix(1, sk.key()));
         }
 
         let padded = self.state.is_padded();
@@ -106,7 +115,7 @@ impl SplitAsciiWhitespace {
     }
 }
 
-impl ServerKey {
+impl<T: Borrow<IntegerServerKey> + Sync> ServerKey<T> {
     // As specified in https://doc.rust-lang.org/core/primitive.char.html#method.is_ascii_whitespace
     fn is_whitespace(&self, char: &FheAsciiChar, or_null: bool) -> BooleanBlock {
         let (((is_space, is_tab), (is_new_line, is_form_feed)), (is_carriage_return, op_is_null)) =
@@ -115,39 +124,41 @@ impl ServerKey {
                     rayon::join(
                         || {
                             rayon::join(
-                                || self.key.scalar_eq_parallelized(char.ciphertext(), 0x20u8),
-                                || self.key.scalar_eq_parallelized(char.ciphertext(), 0x09u8),
+                                || self.key().scalar_eq_parallelized(char.ciphertext(), 0x20u8),
+                                || self.key().scalar_eq_parallelized(char.ciphertext(), 0x09u8),
                             )
                         },
                         || {
                             rayon::join(
-                                || self.key.scalar_eq_parallelized(char.ciphertext(), 0x0Au8),
-                                || self.key.scalar_eq_parallelized(char.ciphertext(), 0x0Cu8),
+                                || self.key().scalar_eq_parallelized(char.ciphertext(), 0x0Au8),
+                                || self.key().scalar_eq_parallelized(char.ciphertext(), 0x0Cu8),
                             )
                         },
                     )
                 },
                 || {
                     rayon::join(
-                        || self.key.scalar_eq_parallelized(char.ciphertext(), 0x0Du8),
+                        || self.key().scalar_eq_parallelized(char.ciphertext(), 0x0Du8),
                         || {
-                            or_null
-                                .then_some(self.key.scalar_eq_parallelized(char.ciphertext(), 0u8))
+                            or_null.then_some(
+                                self.key().scalar_eq_parallelized(char.ciphertext(), 0u8),
+                            )
                         },
                     )
                 },
             );
 
-        let mut is_whitespace = self.key.boolean_bitor(&is_space, &is_tab);
-        self.key
+        let mut is_whitespace = self.key().boolean_bitor(&is_space, &is_tab);
+        self.key()
             .boolean_bitor_assign(&mut is_whitespace, &is_new_line);
-        self.key
+        self.key()
             .boolean_bitor_assign(&mut is_whitespace, &is_form_feed);
-        self.key
+        self.key()
             .boolean_bitor_assign(&mut is_whitespace, &is_carriage_return);
 
         if let Some(is_null) = op_is_null {
-            self.key.boolean_bitor_assign(&mut is_whitespace, &is_null);
+            self.key()
+                .boolean_bitor_assign(&mut is_whitespace, &is_null);
         }
 
         is_whitespace
@@ -156,22 +167,22 @@ impl ServerKey {
     fn is_not_whitespace(&self, char: &FheAsciiChar) -> BooleanBlock {
         let result = self.is_whitespace(char, false);
 
-        self.key.boolean_bitnot(&result)
+        self.key().boolean_bitnot(&result)
     }
 
     fn compare_and_trim<'a, I>(&self, strip_str: I, starts_with_null: bool)
     where
         I: Iterator<Item = &'a mut FheAsciiChar>,
     {
-        let mut prev_was_ws = self.key.create_trivial_boolean_block(true);
+        let mut prev_was_ws = self.key().create_trivial_boolean_block(true);
         for char in strip_str {
             let mut is_whitespace = self.is_whitespace(char, starts_with_null);
-            self.key
+            self.key()
                 .boolean_bitand_assign(&mut is_whitespace, &prev_was_ws);
 
-            *char.ciphertext_mut() = self.key.if_then_else_parallelized(
+            *char.ciphertext_mut() = self.key().if_then_else_parallelized(
                 &is_whitespace,
-                &self.key.create_trivial_zero_radix(4),
+                &self.key().create_trivial_zero_radix(4),
                 char.ciphertext(),
             );
 
@@ -185,10 +196,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::FheString;
-    /// use crate::server_key::gen_keys;
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::{ClientKey, ServerKey};
     ///
-    /// let (ck, sk) = gen_keys();
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let s = "  hello world";
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -214,11 +230,11 @@ impl ServerKey {
         if let FheStringLen::Padding(len_after_trim) = self.len(&result) {
             let original_str_len = match self.len(str) {
                 FheStringLen::Padding(enc_val) => enc_val,
-                FheStringLen::NoPadding(val) => self.key.create_trivial_radix(val as u32, 16),
+                FheStringLen::NoPadding(val) => self.key().create_trivial_radix(val as u32, 16),
             };
 
             let shift_left = self
-                .key
+                .key()
                 .sub_parallelized(&original_str_len, &len_after_trim);
 
             result = self.left_shift_chars(&result, &shift_left);
@@ -227,10 +243,10 @@ impl ServerKey {
         // If str was not padded originally we don't know if result has nulls at the end or not (we
         // don't know if str was shifted or not) so we ensure it's padded in order to be
         // used in other functions safely
-        if !str.is_padded() {
-            result.append_null(self);
-        } else {
+        if str.is_padded() {
             result.set_is_padded(true);
+        } else {
+            result.append_null(self);
         }
 
         result
@@ -241,10 +257,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::FheString;
-    /// use crate::server_key::gen_keys;
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::{ClientKey, ServerKey};
     ///
-    /// let (ck, sk) = gen_keys();
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let s = "hello world  ";
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -281,10 +302,15 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::FheString;
-    /// use crate::server_key::gen_keys;
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::{ClientKey, ServerKey};
     ///
-    /// let (ck, sk) = gen_keys();
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let s = "  hello world  ";
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
@@ -315,10 +341,16 @@ impl ServerKey {
     /// # Examples
     ///
     /// ```rust
-    /// use crate::ciphertext::FheString;
-    /// use crate::server_key::{gen_keys, FheStringIterator};
+    /// use tfhe::integer::{ClientKey as IntegerClientKey, ServerKey as IntegerServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::strings::ciphertext::FheString;
+    /// use tfhe::strings::server_key::FheStringIterator;
+    /// use tfhe::strings::{ClientKey, ServerKey};
     ///
-    /// let (ck, sk) = gen_keys();
+    /// let ck = IntegerClientKey::new(PARAM_MESSAGE_2_CARRY_2);
+    /// let sk = IntegerServerKey::new_radix_server_key(&ck);
+    /// let ck = ClientKey::new(ck);
+    /// let sk = ServerKey::new(sk);
     /// let s = "hello \t\nworld ";
     ///
     /// let enc_s = FheString::new(&ck, &s, None);
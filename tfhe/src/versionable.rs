@@ -0,0 +1,370 @@
+//! Versioning of the serialized types of the library.
+//!
+//! Every type that can be serialized with
+//! [`safe_serialize`](crate::safe_deserialization::safe_serialize) is associated with an enum, its
+//! *versions dispatch*, that has one variant per released layout of the type. The last variant
+//! always holds the current layout, the previous ones hold frozen copies of the older layouts.
+//!
+//! When an object is serialized, it is wrapped in the last variant of its dispatch enum so that the
+//! serialized data carries a version tag. When it is deserialized, the tag is used to pick the
+//! layout that was used to serialize it, and older layouts are upgraded to the current one.
+//!
+//! Versioned types stored inside other versioned types carry their own version tag as well: the
+//! fields holding them are serialized through the `nested`, `nested_option` or `nested_vec`
+//! serde adapters, e.g. `#[serde(with = "crate::versionable::nested")]`. This way, when the layout
+//! of an inner type changes, only the dispatch enum of that type needs a new variant, the types
+//! that contain it keep their current version.
+//!
+//! Objects serialized before the versioning was introduced have no tag at any level. They are
+//! deserialized in the *legacy* mode (see `with_legacy_deserialization`) where each versioned
+//! type, nested or not, is read with the layout of the first variant of its dispatch enum.
+//!
+//! Adding a new version of a type `Foo` is done by:
+//! - moving the current definition of `Foo` to a `FooV<n>` struct in the `backward_compatibility`
+//!   module of the layer that defines `Foo`
+//! - implementing [`Upgrade<Foo>`](Upgrade) for `FooV<n>`
+//! - adding the `V<n>(FooV<n>)` variant to the dispatch enum, before the current version.
+
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cell::Cell;
+use std::fmt::Display;
+
+/// A type that can be serialized along a version tag
+pub trait Versionize: Sized {
+    /// The enum that holds all the versions of the type
+    type Versions<'vers>: Serialize + DeserializeOwned + VersionsDispatch<Self>
+    where
+        Self: 'vers;
+
+    /// Wraps a reference to `self` in the variant of the current version
+    fn versionize(&self) -> Self::Versions<'_>;
+}
+
+/// Conversion from a versioned enum into the current version of a type
+pub trait VersionsDispatch<Unversioned>: Sized {
+    fn unversionize(self) -> Result<Unversioned, UnversionizeError>;
+
+    /// Deserializes the first version of the type from data that was serialized without its
+    /// version tag
+    fn deserialize_legacy<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// Conversion from an older version of a type to a newer one
+pub trait Upgrade<T> {
+    fn upgrade(self) -> Result<T, String>;
+}
+
+/// Converts a versioned object back into the current version of its type, upgrading it if needed
+pub fn unversionize<T: Versionize>(versioned: T::Versions<'_>) -> Result<T, UnversionizeError> {
+    versioned.unversionize()
+}
+
+/// Error returned when a versioned object cannot be converted to the current version of its type
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnversionizeError {
+    from_version: &'static str,
+    into_type: &'static str,
+    message: String,
+}
+
+impl UnversionizeError {
+    pub(crate) fn upgrade(
+        from_version: &'static str,
+        into_type: &'static str,
+        message: String,
+    ) -> Self {
+        Self {
+            from_version,
+            into_type,
+            message,
+        }
+    }
+}
+
+impl Display for UnversionizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Failed to upgrade version {} of type {}: {}",
+            self.from_version, self.into_type, self.message
+        )
+    }
+}
+
+impl std::error::Error for UnversionizeError {}
+
+thread_local! {
+    static LEGACY_DESERIALIZATION: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Restores the previous deserialization mode of the thread when dropped, even on panics
+struct LegacyDeserializationGuard {
+    previous: bool,
+}
+
+impl Drop for LegacyDeserializationGuard {
+    fn drop(&mut self) {
+        LEGACY_DESERIALIZATION.with(|legacy| legacy.set(self.previous));
+    }
+}
+
+/// Runs `f` with the nested versioned types deserialized in the legacy mode, i.e. without their
+/// version tag
+pub(crate) fn with_legacy_deserialization<R>(f: impl FnOnce() -> R) -> R {
+    let _guard = LegacyDeserializationGuard {
+        previous: LEGACY_DESERIALIZATION.with(|legacy| legacy.replace(true)),
+    };
+
+    f()
+}
+
+fn is_legacy_deserialization() -> bool {
+    LEGACY_DESERIALIZATION.with(Cell::get)
+}
+
+/// Serde adapter for fields holding a versioned type, the field is serialized along its own
+/// version tag and upgraded on deserialization
+pub(crate) mod nested {
+    use super::*;
+
+    pub(crate) fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Versionize,
+        S: Serializer,
+    {
+        value.versionize().serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Versionize,
+        D: Deserializer<'de>,
+    {
+        let versioned: T::Versions<'_> = if is_legacy_deserialization() {
+            <T::Versions<'_> as VersionsDispatch<T>>::deserialize_legacy(deserializer)?
+        } else {
+            Deserialize::deserialize(deserializer)?
+        };
+
+        versioned.unversionize().map_err(D::Error::custom)
+    }
+}
+
+/// Deserializes a versioned type through the [`nested`] adapter, used to build the adapters of
+/// the containers
+struct Nested<T>(T);
+
+impl<'de, T: Versionize> Deserialize<'de> for Nested<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        nested::deserialize(deserializer).map(Self)
+    }
+}
+
+/// Serde adapter for `Option` fields holding a versioned type, see [`nested`]
+pub(crate) mod nested_option {
+    use super::*;
+
+    pub(crate) fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Versionize,
+        S: Serializer,
+    {
+        value
+            .as_ref()
+            .map(Versionize::versionize)
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: Versionize,
+        D: Deserializer<'de>,
+    {
+        Option::<Nested<T>>::deserialize(deserializer).map(|value| value.map(|nested| nested.0))
+    }
+}
+
+/// Serde adapter for `Vec` fields holding a versioned type, see [`nested`]
+pub(crate) mod nested_vec {
+    use super::*;
+
+    pub(crate) fn serialize<T, S>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Versionize,
+        S: Serializer,
+    {
+        serializer.collect_seq(values.iter().map(Versionize::versionize))
+    }
+
+    pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        T: Versionize,
+        D: Deserializer<'de>,
+    {
+        Vec::<Nested<T>>::deserialize(deserializer)
+            .map(|values| values.into_iter().map(|nested| nested.0).collect())
+    }
+}
+
+/// Defines the versions dispatch enum of a type, and implements [`Versionize`] for the type.
+///
+/// The older versions are listed with their frozen layout and have to implement
+/// [`Upgrade`] to the current type. The current version is listed last, without a layout, and
+/// marked with `#[current]`.
+///
+/// ```ignore
+/// versions_dispatch! {
+///     pub enum ServerKeyVersions for ServerKey {
+///         V0(ServerKeyV0),
+///         #[current]
+///         V1,
+///     }
+/// }
+///
+/// versions_dispatch! {
+///     pub enum LweCiphertextVersions<Scalar: UnsignedInteger + Serialize + DeserializeOwned>
+///         for LweCiphertextOwned<Scalar>
+///     {
+///         #[current]
+///         V0,
+///     }
+/// }
+///
+/// versions_dispatch! {
+///     pub enum FheFixedUVersions<const INT: usize, const FRAC: usize> for FheFixedU<INT, FRAC> {
+///         #[current]
+///         V0,
+///     }
+/// }
+/// ```
+macro_rules! versions_dispatch {
+    // The first version of a type is the one used by the legacy serialized data
+    (@legacy $deserializer:ident; $first:ident $(, $old:ident)*; $current:ident) => {
+        serde::Deserialize::deserialize($deserializer).map(Self::$first)
+    };
+    (@legacy $deserializer:ident; ; $current_variant:ident) => {
+        serde::Deserialize::deserialize($deserializer)
+            .map(|current| Self::$current_variant(std::borrow::Cow::Owned(current)))
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident
+            $(<$($gen:ident: $first_bound:ident $(+ $bound:ident)*),+>)?
+            for $ty:ty
+        {
+            $($old_variant:ident($old_ty:ty),)*
+            #[current]
+            $current_variant:ident$(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(serde::Serialize, serde::Deserialize)]
+        // The bounds required by serde are part of the bounds of the generic parameters
+        #[serde(bound = "")]
+        // Older versions are only used transiently while deserializing
+        #[allow(clippy::large_enum_variant)]
+        $vis enum $name<'vers $($(, $gen: $first_bound $(+ $bound)*)+)?> {
+            $($old_variant($old_ty),)*
+            $current_variant(std::borrow::Cow<'vers, $ty>),
+        }
+
+        impl<'vers $($(, $gen: $first_bound $(+ $bound)*)+)?> $crate::versionable::VersionsDispatch<$ty>
+            for $name<'vers $($(, $gen)+)?>
+        {
+            fn unversionize(self) -> Result<$ty, $crate::versionable::UnversionizeError> {
+                match self {
+                    $(
+                        Self::$old_variant(old) => {
+                            $crate::versionable::Upgrade::<$ty>::upgrade(old).map_err(|message| {
+                                $crate::versionable::UnversionizeError::upgrade(
+                                    stringify!($old_variant),
+                                    std::any::type_name::<$ty>(),
+                                    message,
+                                )
+                            })
+                        }
+                    )*
+                    Self::$current_variant(current) => Ok(current.into_owned()),
+                }
+            }
+
+            fn deserialize_legacy<'de, D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                $crate::versionable::versions_dispatch!(
+                    @legacy deserializer; $($old_variant),*; $current_variant
+                )
+            }
+        }
+
+        impl$(<$($gen: $first_bound $(+ $bound)*),+>)? $crate::versionable::Versionize for $ty {
+            type Versions<'vers> = $name<'vers $($(, $gen)+)?>
+            where
+                Self: 'vers;
+
+            fn versionize(&self) -> Self::Versions<'_> {
+                $name::$current_variant(std::borrow::Cow::Borrowed(self))
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident<$(const $const_gen:ident: $const_ty:ty),+>
+            for $ty:ty
+        {
+            $($old_variant:ident($old_ty:ty),)*
+            #[current]
+            $current_variant:ident$(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #[allow(clippy::large_enum_variant)]
+        $vis enum $name<'vers, $(const $const_gen: $const_ty),+> {
+            $($old_variant($old_ty),)*
+            $current_variant(std::borrow::Cow<'vers, $ty>),
+        }
+
+        impl<'vers, $(const $const_gen: $const_ty),+> $crate::versionable::VersionsDispatch<$ty>
+            for $name<'vers, $($const_gen),+>
+        {
+            fn unversionize(self) -> Result<$ty, $crate::versionable::UnversionizeError> {
+                match self {
+                    $(
+                        Self::$old_variant(old) => {
+                            $crate::versionable::Upgrade::<$ty>::upgrade(old).map_err(|message| {
+                                $crate::versionable::UnversionizeError::upgrade(
+                                    stringify!($old_variant),
+                                    std::any::type_name::<$ty>(),
+                                    message,
+                                )
+                            })
+                        }
+                    )*
+                    Self::$current_variant(current) => Ok(current.into_owned()),
+                }
+            }
+
+            fn deserialize_legacy<'de, D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                $crate::versionable::versions_dispatch!(
+                    @legacy deserializer; $($old_variant),*; $current_variant
+                )
+            }
+        }
+
+        impl<$(const $const_gen: $const_ty),+> $crate::versionable::Versionize for $ty {
+            type Versions<'vers> = $name<'vers, $($const_gen),+>
+            where
+                Self: 'vers;
+
+            fn versionize(&self) -> Self::Versions<'_> {
+                $name::$current_variant(std::borrow::Cow::Borrowed(self))
+            }
+        }
+    };
+}
+
+pub(crate) use versions_dispatch;